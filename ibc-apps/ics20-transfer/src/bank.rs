@@ -0,0 +1,43 @@
+//! Implements the token movements of ICS-20 on top of a [`BankContext`], for
+//! hosts implementing the movement of fungible tokens once for all the IBC
+//! applications.
+
+use ibc_app_transfer_types::error::TokenTransferError;
+use ibc_app_transfer_types::PrefixedCoin;
+use ibc_core::host::types::identifiers::{ChannelId, PortId};
+use ibc_core::router::bank::BankContext;
+use ibc_core::router::types::module::ModuleAccount;
+
+/// Escrows `coin` from `from_account` into the escrow account of the channel
+/// it is sent over, as done by `escrow_coins_execute`.
+pub fn escrow_coins<Bank>(
+    bank: &mut Bank,
+    from_account: &Bank::AccountId,
+    port_id: &PortId,
+    channel_id: &ChannelId,
+    coin: &PrefixedCoin,
+) -> Result<(), TokenTransferError>
+where
+    Bank: BankContext<PrefixedCoin>,
+{
+    let escrow_account = bank.module_account(&ModuleAccount::escrow(port_id, channel_id))?;
+
+    Ok(bank.transfer(from_account, &escrow_account, coin)?)
+}
+
+/// Unescrows `coin` from the escrow account of the channel it is received
+/// over into `to_account`, as done by `unescrow_coins_execute`.
+pub fn unescrow_coins<Bank>(
+    bank: &mut Bank,
+    to_account: &Bank::AccountId,
+    port_id: &PortId,
+    channel_id: &ChannelId,
+    coin: &PrefixedCoin,
+) -> Result<(), TokenTransferError>
+where
+    Bank: BankContext<PrefixedCoin>,
+{
+    let escrow_account = bank.module_account(&ModuleAccount::escrow(port_id, channel_id))?;
+
+    Ok(bank.transfer(&escrow_account, to_account, coin)?)
+}
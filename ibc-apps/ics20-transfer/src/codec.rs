@@ -0,0 +1,96 @@
+//! Implements the [`PacketDataCodec`]s of the transfer packet data, as looked
+//! up with [`TokenTransferValidationContext::packet_data_codec`].
+
+use ibc_app_transfer_types::packet::PacketData;
+use ibc_app_transfer_types::proto::transfer::v2::FungibleTokenPacketData as RawPacketData;
+use ibc_core::primitives::prelude::*;
+use ibc_core::primitives::proto::Protobuf;
+use ibc_core::router::types::codec::PacketDataCodec;
+
+#[cfg(feature = "serde")]
+use crate::context::TokenTransferValidationContext;
+
+/// Encodes the packet data into JSON, byte-for-byte as ibc-go does, which is
+/// the encoding used by default.
+///
+/// Requires `serde` feature because of the decoding of the packet data.
+#[cfg(feature = "serde")]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct JsonPacketDataCodec;
+
+#[cfg(feature = "serde")]
+impl PacketDataCodec<PacketData> for JsonPacketDataCodec {
+    fn encode(&self, data: &PacketData) -> Vec<u8> {
+        data.encode_json().into_bytes()
+    }
+
+    fn decode(&self, bytes: &[u8]) -> Option<PacketData> {
+        serde_json::from_slice(bytes).ok()
+    }
+}
+
+/// Encodes the packet data into the `FungibleTokenPacketData` proto message,
+/// for hosts experimenting with proto-encoded payloads.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct ProtoPacketDataCodec;
+
+impl PacketDataCodec<PacketData> for ProtoPacketDataCodec {
+    fn encode(&self, data: &PacketData) -> Vec<u8> {
+        Protobuf::<RawPacketData>::encode_vec(data.clone())
+    }
+
+    fn decode(&self, bytes: &[u8]) -> Option<PacketData> {
+        Protobuf::<RawPacketData>::decode_vec(bytes).ok()
+    }
+}
+
+/// Returns the codec of the packet data of `ctx`, defaulting to
+/// [`JsonPacketDataCodec`].
+#[cfg(feature = "serde")]
+pub(crate) fn packet_data_codec<Ctx>(ctx: &Ctx) -> &dyn PacketDataCodec<PacketData>
+where
+    Ctx: TokenTransferValidationContext,
+{
+    ctx.packet_data_codec().unwrap_or(&JsonPacketDataCodec)
+}
+
+#[cfg(test)]
+mod tests {
+    use ibc_app_transfer_types::Memo;
+    use ibc_core::primitives::Signer;
+
+    use super::*;
+
+    fn packet_data() -> PacketData {
+        PacketData {
+            token: "100transfer/channel-1/uatom".parse().expect("valid coin"),
+            sender: Signer::from("cosmos1sender".to_string()),
+            receiver: Signer::from("cosmos1receiver".to_string()),
+            memo: Memo::from("memo".to_string()),
+        }
+    }
+
+    #[test]
+    fn proto_packet_data_codec_roundtrip() {
+        let data = packet_data();
+        let bytes = ProtoPacketDataCodec.encode(&data);
+
+        assert_eq!(ProtoPacketDataCodec.decode(&bytes), Some(data));
+        assert_eq!(ProtoPacketDataCodec.decode(b"\xff"), None);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn json_packet_data_codec_roundtrip() {
+        let data = packet_data();
+        let bytes = JsonPacketDataCodec.encode(&data);
+
+        assert_eq!(bytes, data.encode_json().into_bytes());
+        assert_eq!(JsonPacketDataCodec.decode(&bytes), Some(data));
+        // The encodings of the codecs are not interchangeable.
+        assert_eq!(
+            JsonPacketDataCodec.decode(&ProtoPacketDataCodec.encode(&packet_data())),
+            None
+        );
+    }
+}
@@ -1,10 +1,54 @@
 //! Defines the main context traits and IBC module callbacks
 
+use ibc_app_transfer_types::authorization::TransferAuthorization;
 use ibc_app_transfer_types::error::TokenTransferError;
+use ibc_app_transfer_types::msgs::transfer::MsgTransfer;
+use ibc_app_transfer_types::packet::PacketData;
 use ibc_app_transfer_types::{Memo, PrefixedCoin, PrefixedDenom};
+use ibc_core::channel::types::packet::Packet;
 use ibc_core::host::types::identifiers::{ChannelId, PortId};
 use ibc_core::primitives::prelude::*;
 use ibc_core::primitives::Signer;
+use ibc_core::router::types::codec::PacketDataCodec;
+
+/// Maps the receiver of an incoming packet into a local account, for hosts
+/// whose accounts cannot be parsed from the receiver string as is, e.g. using
+/// a different bech32 prefix than the sending chain, or routing the tokens to a
+/// contract.
+pub trait ReceiverResolver {
+    type AccountId;
+
+    /// Returns the local account receiving the tokens of the given `packet`,
+    /// sent to `receiver`.
+    fn resolve_receiver(
+        &self,
+        packet: &Packet,
+        receiver: &Signer,
+    ) -> Result<Self::AccountId, TokenTransferError>;
+}
+
+/// Authorizes the transfers sent by a grantee on behalf of their sender, for
+/// hosts supporting delegated transfers, e.g. through the grants of the
+/// `x/authz` module of Cosmos-SDK.
+pub trait SendAuthorization {
+    /// Validates that the grantee is allowed to send `msg`, e.g. that the
+    /// token sent is within its spend limit.
+    fn authorize_send_validate(&self, msg: &MsgTransfer) -> Result<(), TokenTransferError>;
+
+    /// Records the sending of `msg` against the grant, e.g. deducting the
+    /// token sent from its spend limit.
+    fn authorize_send_execute(&mut self, msg: &MsgTransfer) -> Result<(), TokenTransferError>;
+}
+
+impl SendAuthorization for TransferAuthorization {
+    fn authorize_send_validate(&self, msg: &MsgTransfer) -> Result<(), TokenTransferError> {
+        self.accept(msg)
+    }
+
+    fn authorize_send_execute(&mut self, msg: &MsgTransfer) -> Result<(), TokenTransferError> {
+        self.consume(msg)
+    }
+}
 
 /// Methods required in token transfer validation, to be implemented by the host
 pub trait TokenTransferValidationContext {
@@ -64,6 +108,28 @@ pub trait TokenTransferValidationContext {
     fn denom_hash_string(&self, _denom: &PrefixedDenom) -> Option<String> {
         None
     }
+
+    /// Returns the resolver of the receivers of incoming packets.
+    /// Implement only if the receivers cannot be parsed into accounts with
+    /// `TryFrom<Signer>`, which is used by default.
+    fn receiver_resolver(&self) -> Option<&dyn ReceiverResolver<AccountId = Self::AccountId>> {
+        None
+    }
+
+    /// Returns the authorization under which the transfer being sent was
+    /// submitted by a grantee on behalf of its sender, if any.
+    /// Implement only if the host supports delegated transfers.
+    fn send_authorization(&self) -> Option<&dyn SendAuthorization> {
+        None
+    }
+
+    /// Returns the codec of the data of the packets sent and received.
+    /// Implement only if the host does not encode the packet data into JSON,
+    /// as [`JsonPacketDataCodec`](crate::codec::JsonPacketDataCodec) does by
+    /// default.
+    fn packet_data_codec(&self) -> Option<&dyn PacketDataCodec<PacketData>> {
+        None
+    }
 }
 
 /// Methods required in token transfer execution, to be implemented by the host.
@@ -107,4 +173,11 @@ pub trait TokenTransferExecutionContext: TokenTransferValidationContext {
         coin: &PrefixedCoin,
         memo: &Memo,
     ) -> Result<(), TokenTransferError>;
+
+    /// Returns the authorization returned by
+    /// [`TokenTransferValidationContext::send_authorization`], to record the
+    /// transfer being sent against it.
+    fn send_authorization_mut(&mut self) -> Option<&mut dyn SendAuthorization> {
+        None
+    }
 }
@@ -23,12 +23,15 @@ pub fn process_recv_packet_execute<Ctx: TokenTransferExecutionContext>(
         .can_receive_coins()
         .map_err(|err| (ModuleExtras::empty(), err))?;
 
-    let receiver_account = data.receiver.clone().try_into().map_err(|_| {
-        (
-            ModuleExtras::empty(),
-            TokenTransferError::ParseAccountFailure,
-        )
-    })?;
+    let receiver_account = match ctx_b.receiver_resolver() {
+        Some(resolver) => resolver.resolve_receiver(packet, &data.receiver),
+        None => data
+            .receiver
+            .clone()
+            .try_into()
+            .map_err(|_| TokenTransferError::ParseAccountFailure),
+    }
+    .map_err(|err| (ModuleExtras::empty(), err))?;
 
     let extras = if is_receiver_chain_source(
         packet.port_id_on_a.clone(),
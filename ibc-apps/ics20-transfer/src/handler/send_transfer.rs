@@ -10,9 +10,14 @@ use ibc_core::host::types::path::{ChannelEndPath, SeqSendPath};
 use ibc_core::primitives::prelude::*;
 use ibc_core::router::types::event::ModuleEvent;
 
+use crate::codec::packet_data_codec;
 use crate::context::{TokenTransferExecutionContext, TokenTransferValidationContext};
 
 /// Initiate a token transfer. Equivalent to calling [`send_transfer_validate`], followed by [`send_transfer_execute`].
+///
+/// To send a voucher back toward the chain it came from, without having to
+/// look up the channel it was received from, build `msg` with
+/// [`MsgTransfer::unwind`].
 pub fn send_transfer<SendPacketCtx, TokenCtx>(
     send_packet_ctx_a: &mut SendPacketCtx,
     token_ctx_a: &mut TokenCtx,
@@ -38,6 +43,10 @@ where
 {
     token_ctx_a.can_send_coins()?;
 
+    if let Some(authorization) = token_ctx_a.send_authorization() {
+        authorization.authorize_send_validate(&msg)?;
+    }
+
     let chan_end_path_on_a = ChannelEndPath::new(&msg.port_id_on_a, &msg.chan_id_on_a);
     let chan_end_on_a = send_packet_ctx_a.channel_end(&chan_end_path_on_a)?;
 
@@ -80,8 +89,7 @@ where
     }
 
     let packet = {
-        let data = serde_json::to_vec(&msg.packet_data)
-            .expect("PacketData's infallible Serialize impl failed");
+        let data = packet_data_codec(token_ctx_a).encode(&msg.packet_data);
 
         Packet {
             seq_on_a: sequence,
@@ -127,6 +135,10 @@ where
     let seq_send_path_on_a = SeqSendPath::new(&msg.port_id_on_a, &msg.chan_id_on_a);
     let sequence = send_packet_ctx_a.get_next_sequence_send(&seq_send_path_on_a)?;
 
+    if let Some(authorization) = token_ctx_a.send_authorization_mut() {
+        authorization.authorize_send_execute(&msg)?;
+    }
+
     let token = &msg.packet_data.token;
 
     let sender = msg
@@ -153,10 +165,7 @@ where
     }
 
     let packet = {
-        let data = {
-            serde_json::to_vec(&msg.packet_data)
-                .expect("PacketData's infallible Serialize impl failed")
-        };
+        let data = packet_data_codec(&*token_ctx_a).encode(&msg.packet_data);
 
         Packet {
             seq_on_a: sequence,
@@ -0,0 +1,85 @@
+//! Checks the invariants of the ICS-20 state, e.g. in tests, or upon every
+//! block as the `x/crisis` module of Cosmos-SDK does.
+//!
+//! The native tokens sent over a channel are escrowed on this chain, while
+//! the counterparty mints vouchers for them. The escrow accounts must thus
+//! hold, for each native denomination, at least the amount of the vouchers
+//! still in circulation, so that all of them can be sent back and unescrowed.
+
+use core::fmt::{Display, Error as FmtError, Formatter};
+
+use ibc_app_transfer_types::error::TokenTransferError;
+use ibc_app_transfer_types::{Amount, PrefixedDenom};
+use ibc_core::primitives::prelude::*;
+
+/// Methods required to check the invariants of ICS-20, to be implemented by
+/// the host.
+pub trait TokenTransferInvariantContext {
+    /// Returns the denominations of the native tokens escrowed over any
+    /// channel.
+    fn escrowed_denoms(&self) -> Result<Vec<PrefixedDenom>, TokenTransferError>;
+
+    /// Returns the total balance in `denom` of the escrow accounts of all the
+    /// channels.
+    fn total_escrow_balance(&self, denom: &PrefixedDenom) -> Result<Amount, TokenTransferError>;
+
+    /// Returns the total amount of `denom` sent over all the channels, less
+    /// the amount sent back or refunded, which is the amount of the vouchers
+    /// of `denom` in circulation on the counterparty chains.
+    ///
+    /// Hosts typically track it on every escrow and unescrow, as the total
+    /// escrow of ibc-go does.
+    fn total_outstanding_vouchers(
+        &self,
+        denom: &PrefixedDenom,
+    ) -> Result<Amount, TokenTransferError>;
+}
+
+/// A native denomination whose escrow accounts hold less than the amount of
+/// the vouchers of it in circulation.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EscrowInvariantViolation {
+    pub denom: PrefixedDenom,
+    pub escrow_balance: Amount,
+    pub outstanding_vouchers: Amount,
+}
+
+impl Display for EscrowInvariantViolation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), FmtError> {
+        write!(
+            f,
+            "escrow balance of {} ({}) is lower than its outstanding vouchers ({})",
+            self.denom, self.escrow_balance, self.outstanding_vouchers
+        )
+    }
+}
+
+/// Checks that, for each native denomination escrowed over any channel, the
+/// escrow accounts hold at least the amount of the outstanding vouchers of
+/// it, and returns the denominations for which they do not.
+///
+/// An empty list means the invariant holds, while an error means it could
+/// not be checked.
+pub fn check_total_escrow<Ctx>(
+    ctx: &Ctx,
+) -> Result<Vec<EscrowInvariantViolation>, TokenTransferError>
+where
+    Ctx: TokenTransferInvariantContext,
+{
+    let mut violations = Vec::new();
+
+    for denom in ctx.escrowed_denoms()? {
+        let escrow_balance = ctx.total_escrow_balance(&denom)?;
+        let outstanding_vouchers = ctx.total_outstanding_vouchers(&denom)?;
+
+        if escrow_balance < outstanding_vouchers {
+            violations.push(EscrowInvariantViolation {
+                denom,
+                escrow_balance,
+                outstanding_vouchers,
+            });
+        }
+    }
+
+    Ok(violations)
+}
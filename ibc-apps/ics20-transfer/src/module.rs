@@ -1,6 +1,5 @@
 use ibc_app_transfer_types::error::TokenTransferError;
 use ibc_app_transfer_types::events::{AckEvent, AckStatusEvent, RecvEvent, TimeoutEvent};
-use ibc_app_transfer_types::packet::PacketData;
 use ibc_app_transfer_types::{ack_success_b64, VERSION};
 use ibc_core::channel::types::acknowledgement::{Acknowledgement, AcknowledgementStatus};
 use ibc_core::channel::types::channel::{Counterparty, Order};
@@ -12,6 +11,7 @@ use ibc_core::primitives::prelude::*;
 use ibc_core::primitives::Signer;
 use ibc_core::router::types::module::ModuleExtras;
 
+use crate::codec::packet_data_codec;
 use crate::context::{TokenTransferExecutionContext, TokenTransferValidationContext};
 use crate::handler::{
     process_recv_packet_execute, refund_packet_token_execute, refund_packet_token_validate,
@@ -170,7 +170,7 @@ pub fn on_recv_packet_execute(
     ctx_b: &mut impl TokenTransferExecutionContext,
     packet: &Packet,
 ) -> (ModuleExtras, Acknowledgement) {
-    let Ok(data) = serde_json::from_slice::<PacketData>(&packet.data) else {
+    let Some(data) = packet_data_codec(&*ctx_b).decode(&packet.data) else {
         let ack =
             AcknowledgementStatus::error(TokenTransferError::PacketDataDeserialization.into());
         return (ModuleExtras::empty(), ack.into());
@@ -203,10 +203,11 @@ pub fn on_acknowledgement_packet_validate<Ctx>(
 where
     Ctx: TokenTransferValidationContext,
 {
-    let data = serde_json::from_slice::<PacketData>(&packet.data)
-        .map_err(|_| TokenTransferError::PacketDataDeserialization)?;
+    let data = packet_data_codec(ctx)
+        .decode(&packet.data)
+        .ok_or(TokenTransferError::PacketDataDeserialization)?;
 
-    let acknowledgement = serde_json::from_slice::<AcknowledgementStatus>(acknowledgement.as_ref())
+    let acknowledgement = AcknowledgementStatus::try_from(acknowledgement)
         .map_err(|_| TokenTransferError::AckDeserialization)?;
 
     if !acknowledgement.is_successful() {
@@ -222,16 +223,14 @@ pub fn on_acknowledgement_packet_execute(
     acknowledgement: &Acknowledgement,
     _relayer: &Signer,
 ) -> (ModuleExtras, Result<(), TokenTransferError>) {
-    let Ok(data) = serde_json::from_slice::<PacketData>(&packet.data) else {
+    let Some(data) = packet_data_codec(&*ctx).decode(&packet.data) else {
         return (
             ModuleExtras::empty(),
             Err(TokenTransferError::PacketDataDeserialization),
         );
     };
 
-    let Ok(acknowledgement) =
-        serde_json::from_slice::<AcknowledgementStatus>(acknowledgement.as_ref())
-    else {
+    let Ok(acknowledgement) = AcknowledgementStatus::try_from(acknowledgement) else {
         return (
             ModuleExtras::empty(),
             Err(TokenTransferError::AckDeserialization),
@@ -269,8 +268,9 @@ pub fn on_timeout_packet_validate<Ctx>(
 where
     Ctx: TokenTransferValidationContext,
 {
-    let data = serde_json::from_slice::<PacketData>(&packet.data)
-        .map_err(|_| TokenTransferError::PacketDataDeserialization)?;
+    let data = packet_data_codec(ctx)
+        .decode(&packet.data)
+        .ok_or(TokenTransferError::PacketDataDeserialization)?;
 
     refund_packet_token_validate(ctx, packet, &data)?;
 
@@ -282,7 +282,7 @@ pub fn on_timeout_packet_execute(
     packet: &Packet,
     _relayer: &Signer,
 ) -> (ModuleExtras, Result<(), TokenTransferError>) {
-    let Ok(data) = serde_json::from_slice::<PacketData>(&packet.data) else {
+    let Some(data) = packet_data_codec(&*ctx).decode(&packet.data) else {
         return (
             ModuleExtras::empty(),
             Err(TokenTransferError::PacketDataDeserialization),
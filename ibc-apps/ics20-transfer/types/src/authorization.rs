@@ -0,0 +1,239 @@
+//! Defines the transfer authorization, through which a granter delegates to a
+//! grantee the sending of transfers on its behalf, as with the
+//! `TransferAuthorization` of the `x/authz` module of Cosmos-SDK.
+
+use ibc_core::host::types::identifiers::{ChannelId, PortId};
+use ibc_core::primitives::prelude::*;
+use ibc_core::primitives::Signer;
+
+use crate::error::TokenTransferError;
+use crate::msgs::transfer::MsgTransfer;
+use crate::{Amount, PrefixedCoin, U256};
+
+/// The entry of [`Allocation::allowed_packet_data`] allowing any memo.
+pub const ALLOW_ALL_PACKET_DATA: &str = "*";
+
+/// Returns the amount of a spend limit which is never exhausted.
+pub fn unbounded_spend_limit() -> Amount {
+    U256::MAX.into()
+}
+
+/// The transfers a grantee may send over a given channel.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Allocation {
+    pub source_port: PortId,
+    pub source_channel: ChannelId,
+    /// The coins that may still be sent, at most one per denomination. Coins
+    /// of [`unbounded_spend_limit`] are never exhausted.
+    pub spend_limit: Vec<PrefixedCoin>,
+    /// The receivers allowed, any receiver being allowed if empty.
+    pub allow_list: Vec<Signer>,
+    /// The memos allowed, any memo being allowed if it contains
+    /// [`ALLOW_ALL_PACKET_DATA`], and only the empty memo if empty.
+    pub allowed_packet_data: Vec<String>,
+}
+
+impl Allocation {
+    fn applies_to(&self, msg: &MsgTransfer) -> bool {
+        self.source_port == msg.port_id_on_a && self.source_channel == msg.chan_id_on_a
+    }
+
+    fn spend_limit_of(&self, msg: &MsgTransfer) -> Result<&PrefixedCoin, TokenTransferError> {
+        let token = &msg.packet_data.token;
+
+        self.spend_limit
+            .iter()
+            .find(|limit| limit.denom == token.denom)
+            .filter(|limit| token.amount <= limit.amount)
+            .ok_or_else(|| TokenTransferError::SpendLimitExceeded {
+                send_attempt: token.to_string(),
+            })
+    }
+
+    fn accept(&self, msg: &MsgTransfer) -> Result<(), TokenTransferError> {
+        self.spend_limit_of(msg)?;
+
+        let receiver = &msg.packet_data.receiver;
+        if !self.allow_list.is_empty() && !self.allow_list.contains(receiver) {
+            return Err(TokenTransferError::UnauthorizedReceiver {
+                receiver: receiver.clone(),
+            });
+        }
+
+        let memo = msg.packet_data.memo.as_ref();
+        let memo_allowed = if self.allowed_packet_data.is_empty() {
+            memo.is_empty()
+        } else {
+            self.allowed_packet_data
+                .iter()
+                .any(|allowed| allowed == ALLOW_ALL_PACKET_DATA || allowed == memo)
+        };
+        if !memo_allowed {
+            return Err(TokenTransferError::UnauthorizedMemo {
+                memo: memo.to_string(),
+            });
+        }
+
+        Ok(())
+    }
+}
+
+/// Authorizes a grantee to send transfers on behalf of a granter, within the
+/// allocations granted for each channel.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TransferAuthorization {
+    pub allocations: Vec<Allocation>,
+}
+
+impl TransferAuthorization {
+    pub fn new(allocations: Vec<Allocation>) -> Self {
+        Self { allocations }
+    }
+
+    /// Returns `true` if no allocation is left, in which case the host may
+    /// revoke the authorization.
+    pub fn is_empty(&self) -> bool {
+        self.allocations.is_empty()
+    }
+
+    /// Checks that `msg` is allowed by the allocation granted for its source
+    /// channel.
+    pub fn accept(&self, msg: &MsgTransfer) -> Result<(), TokenTransferError> {
+        self.allocations
+            .iter()
+            .find(|allocation| allocation.applies_to(msg))
+            .ok_or_else(|| TokenTransferError::UnauthorizedChannel {
+                port_id: msg.port_id_on_a.clone(),
+                channel_id: msg.chan_id_on_a.clone(),
+            })?
+            .accept(msg)
+    }
+
+    /// Deducts the token sent by `msg` from the spend limit of the allocation
+    /// granted for its source channel, which is removed once its spend limit
+    /// is exhausted.
+    ///
+    /// A prior call to [`TransferAuthorization::accept`] MUST have succeeded.
+    pub fn consume(&mut self, msg: &MsgTransfer) -> Result<(), TokenTransferError> {
+        self.accept(msg)?;
+
+        let token = &msg.packet_data.token;
+        let Some(index) = self
+            .allocations
+            .iter()
+            .position(|allocation| allocation.applies_to(msg))
+        else {
+            return Ok(());
+        };
+        let allocation = &mut self.allocations[index];
+
+        allocation.spend_limit.retain_mut(|limit| {
+            if limit.denom != token.denom || limit.amount == unbounded_spend_limit() {
+                return true;
+            }
+            // The amount sent was checked to be within the spend limit.
+            limit.amount = limit
+                .amount
+                .checked_sub(token.amount)
+                .unwrap_or_else(|| 0u64.into());
+            limit.amount != Amount::from(0u64)
+        });
+
+        if allocation.spend_limit.is_empty() {
+            self.allocations.remove(index);
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::str::FromStr;
+
+    use ibc_core::channel::types::timeout::TimeoutHeight;
+    use ibc_core::primitives::Timestamp;
+    use rstest::rstest;
+
+    use super::*;
+    use crate::packet::PacketData;
+    use crate::Coin;
+
+    fn transfer(channel_id: u64, amount: u64, receiver: &str, memo: &str) -> MsgTransfer {
+        MsgTransfer {
+            port_id_on_a: PortId::transfer(),
+            chan_id_on_a: ChannelId::new(channel_id),
+            packet_data: PacketData {
+                token: Coin::from_str(&format!("{amount}uatom")).expect("valid coin"),
+                sender: Signer::from("granter".to_string()),
+                receiver: Signer::from(receiver.to_string()),
+                memo: memo.to_string().into(),
+            },
+            timeout_height_on_b: TimeoutHeight::Never,
+            timeout_timestamp_on_b: Timestamp::none(),
+        }
+    }
+
+    fn authorization(spend_limit: Amount) -> TransferAuthorization {
+        TransferAuthorization::new(vec![Allocation {
+            source_port: PortId::transfer(),
+            source_channel: ChannelId::new(0),
+            spend_limit: vec![Coin {
+                denom: "uatom".parse().expect("valid denom"),
+                amount: spend_limit,
+            }],
+            allow_list: vec![Signer::from("receiver".to_string())],
+            allowed_packet_data: vec!["memo".to_string()],
+        }])
+    }
+
+    #[rstest]
+    #[case::allowed(transfer(0, 10, "receiver", "memo"), true)]
+    #[case::empty_memo_not_allowed(transfer(0, 10, "receiver", ""), false)]
+    #[case::other_channel(transfer(1, 10, "receiver", "memo"), false)]
+    #[case::other_receiver(transfer(0, 10, "mallory", "memo"), false)]
+    #[case::other_memo(transfer(0, 10, "receiver", "other"), false)]
+    #[case::above_spend_limit(transfer(0, 11, "receiver", "memo"), false)]
+    fn test_accept(#[case] msg: MsgTransfer, #[case] accepted: bool) {
+        assert_eq!(authorization(10u64.into()).accept(&msg).is_ok(), accepted);
+    }
+
+    #[test]
+    fn test_consume() {
+        let mut authorization = authorization(10u64.into());
+
+        authorization
+            .consume(&transfer(0, 4, "receiver", "memo"))
+            .expect("within the spend limit");
+        assert_eq!(
+            authorization.allocations[0].spend_limit[0].amount,
+            6u64.into()
+        );
+        assert!(authorization
+            .consume(&transfer(0, 7, "receiver", "memo"))
+            .is_err());
+
+        // The allocation is removed once its spend limit is exhausted.
+        authorization
+            .consume(&transfer(0, 6, "receiver", "memo"))
+            .expect("within the spend limit");
+        assert!(authorization.is_empty());
+    }
+
+    #[test]
+    fn test_consume_unbounded() {
+        let mut authorization = authorization(unbounded_spend_limit());
+
+        authorization
+            .consume(&transfer(0, 1_000_000, "receiver", "memo"))
+            .expect("unbounded spend limit");
+        assert_eq!(
+            authorization.allocations[0].spend_limit[0].amount,
+            unbounded_spend_limit()
+        );
+    }
+}
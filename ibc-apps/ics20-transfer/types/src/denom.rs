@@ -84,6 +84,14 @@ impl TracePrefix {
         }
     }
 
+    pub fn port_id(&self) -> &PortId {
+        &self.port_id
+    }
+
+    pub fn channel_id(&self) -> &ChannelId {
+        &self.channel_id
+    }
+
     /// Returns a string slice with [`TracePrefix`] removed.
     ///
     /// If the string starts with a [`TracePrefix`], i.e. `{port-id}/channel-{id}`,
@@ -146,6 +154,12 @@ impl TracePath {
         self.0.last().map(|p| p == prefix).unwrap_or(false)
     }
 
+    /// Returns the first prefix of the path, i.e. the one added by the last
+    /// hop of the token, if any.
+    pub fn first_prefix(&self) -> Option<&TracePrefix> {
+        self.0.last()
+    }
+
     /// Removes the specified prefix from the path if there is a match, otherwise does nothing.
     pub fn remove_prefix(&mut self, prefix: &TracePrefix) {
         if self.starts_with(prefix) {
@@ -186,16 +200,9 @@ impl TracePath {
         let mut trace_prefixes = vec![];
         let mut current_remaining_opt = Some(s);
 
-        loop {
-            let Some(current_remaining_s) = current_remaining_opt else {
-                break;
-            };
-
-            let Some((trace_prefix, next_remaining_opt)) = TracePrefix::strip(current_remaining_s)
-            else {
-                break;
-            };
-
+        while let Some((trace_prefix, next_remaining_opt)) =
+            current_remaining_opt.and_then(TracePrefix::strip)
+        {
             trace_prefixes.push(trace_prefix);
             current_remaining_opt = next_remaining_opt;
         }
@@ -205,6 +212,51 @@ impl TracePath {
         trace_prefixes.reverse();
         (Self(trace_prefixes), current_remaining_opt)
     }
+    /// Splits a prefixed denomination or class ID into its [`TracePath`] and
+    /// its base, as ibc-go does:
+    ///
+    /// - a lone `{port-id}/{channel-id}` pair is a base, without trace path;
+    /// - otherwise, the leading `{port-id}/{channel-id}` pairs are stripped as
+    ///   by [`TracePath::trim`], the base being `None` if nothing is left.
+    ///
+    /// Returns `None` if the trace is ambiguous, i.e. if ibc-go would strip a
+    /// pair which is not a [`TracePrefix`]: ibc-go reads any pair whose second
+    /// segment is of the `channel-{N}` format as a trace prefix, whichever its
+    /// first segment, e.g. the empty port identifier of `/channel-0/uatom`.
+    pub fn split_prefixed(s: &str) -> Option<(Self, Option<&str>)> {
+        let (trace_path, remaining) = Self::trim(s);
+
+        match remaining {
+            None if trace_path.0.len() == 1 => Some((Self::empty(), Some(s))),
+            None => Some((trace_path, None)),
+            Some(remaining) => (!starts_with_unparsed_prefix(remaining, trace_path.is_empty()))
+                .then_some((trace_path, Some(remaining))),
+        }
+    }
+}
+
+/// Returns whether `s`, from which [`TracePath::trim`] could not strip a
+/// [`TracePrefix`], starts with a pair ibc-go reads as a trace prefix.
+///
+/// As in ibc-go, the first pair of a prefixed denomination is only a trace
+/// prefix if anything follows it, even an empty base.
+fn starts_with_unparsed_prefix(s: &str, is_first: bool) -> bool {
+    let mut segments = s.splitn(3, '/');
+
+    let (Some(_), Some(channel_id_s)) = (segments.next(), segments.next()) else {
+        return false;
+    };
+    if is_first && segments.next().is_none() {
+        return false;
+    }
+
+    // Mirrors `IsChannelIDFormat` of ibc-go.
+    channel_id_s
+        .strip_prefix(ChannelId::prefix())
+        .and_then(|index| index.strip_prefix('-'))
+        .is_some_and(|index| {
+            (1..=20).contains(&index.len()) && index.bytes().all(|b| b.is_ascii_digit())
+        })
 }
 
 impl FromStr for TracePath {
@@ -271,6 +323,16 @@ impl PrefixedDenom {
     pub fn add_trace_prefix(&mut self, prefix: TracePrefix) {
         self.trace_path.add_prefix(prefix)
     }
+
+    /// Returns the prefix of the port and channel over which the token was
+    /// received on its last hop, over which it must be sent to unwind that
+    /// hop, or `None` if the token is native to the chain.
+    ///
+    /// Sending the token back over this channel burns it, rather than
+    /// wrapping it once more in a new trace prefix on the receiving chain.
+    pub fn unwind_prefix(&self) -> Option<&TracePrefix> {
+        self.trace_path.first_prefix()
+    }
 }
 
 /// Returns true if the denomination originally came from the sender chain and
@@ -346,17 +408,19 @@ impl FromStr for PrefixedDenom {
     /// valid [`PortId`], and `"stars16da2uus9zrsy83h23ur42v3lglg5rmyrpqnju4"`, an invalid [`ChannelId`].
     /// The loop breaks at this point, resulting in a [`TracePath`] of `"transfer/channel-75"`
     /// and a [`BaseDenom`] of `"factory/stars16da2uus9zrsy83h23ur42v3lglg5rmyrpqnju4/dust"`.
+    ///
+    /// As in ibc-go, a lone `{port-id}/{channel-id}` pair such as
+    /// `"transfer/channel-0"` is a [`BaseDenom`], while a denomination made of
+    /// several pairs only has an empty [`BaseDenom`]. Traces which ibc-go
+    /// would parse differently are rejected, see [`TracePath::split_prefixed`].
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        match TracePath::trim(s) {
-            (trace_path, Some(remaining_parts)) => Ok(Self {
-                trace_path,
-                base_denom: BaseDenom::from_str(remaining_parts)?,
-            }),
-            (_, None) => Ok(Self {
-                trace_path: TracePath::empty(),
-                base_denom: BaseDenom::from_str(s)?,
-            }),
-        }
+        let (trace_path, base_denom) = TracePath::split_prefixed(s)
+            .ok_or_else(|| TokenTransferError::MalformedTrace(s.to_string()))?;
+
+        Ok(Self {
+            trace_path,
+            base_denom: BaseDenom::from_str(base_denom.unwrap_or_default())?,
+        })
     }
 }
 
@@ -455,7 +519,8 @@ mod tests {
     #[case("", "/uatom")]
     #[case("", "//uatom")]
     #[case("", "transfer/")]
-    #[case("", "(transfer)/channel-0/uatom")]
+    #[case("", "/channel-0")]
+    #[case("", "(transfer)/channel-0")]
     #[case("", "transfer/(channel-0)/uatom")]
     // https://github.com/cosmos/ibc-go/blob/e2ad31975f2ede592912b86346b5ebf055c9e05f/modules/apps/transfer/types/trace_test.go#L17-L38
     #[case("", "uatom")]
@@ -505,11 +570,29 @@ mod tests {
     #[case("transfer/channel-1/transfer/channel-2/")]
     #[case("transfer/channel-21/transfer/channel-23/  ")]
     #[case("transfer/channel-0/")]
+    #[case("transfer/channel-0/transfer/channel-1")]
     #[should_panic(expected = "EmptyBaseDenom")]
     fn test_prefixed_empty_base_denom(#[case] pd_s: &str) {
         PrefixedDenom::from_str(pd_s).expect("error");
     }
 
+    /// ibc-go reads a pair as a trace prefix as soon as its second segment is
+    /// of the `channel-{N}` format, so that these traces would be parsed
+    /// differently than by [`TracePath::trim`].
+    #[rstest]
+    #[case("/channel-0/uatom")]
+    #[case("(transfer)/channel-0/uatom")]
+    #[case("transfer/channel-0//channel-1/uatom")]
+    #[case("transfer/channel-0/(transfer)/channel-1/uatom")]
+    #[case("transfer/channel-0/transfer/channel-18446744073709551616/uatom")]
+    #[case("transfer/channel-0//channel-1")]
+    fn test_ambiguous_prefixed_denom(#[case] pd_s: &str) {
+        assert!(matches!(
+            PrefixedDenom::from_str(pd_s),
+            Err(TokenTransferError::MalformedTrace(_))
+        ));
+    }
+
     #[rstest]
     fn test_trace_path_order() {
         let mut prefixed_denom =
@@ -655,4 +738,92 @@ mod tests {
 
         Ok(())
     }
+
+    #[rstest]
+    #[case("uatom", None)]
+    #[case("transfer/channel-1/uatom", Some("transfer/channel-1"))]
+    #[case(
+        "transfer/channel-0/customtransfer/channel-1/uatom",
+        Some("transfer/channel-0")
+    )]
+    fn test_unwind_prefix(#[case] denom: &str, #[case] unwind_prefix: Option<&str>) {
+        let denom = PrefixedDenom::from_str(denom).expect("no error");
+
+        assert_eq!(
+            denom.unwind_prefix().map(ToString::to_string).as_deref(),
+            unwind_prefix
+        );
+    }
+
+    #[test]
+    fn test_trace_path_many_hops() {
+        let hops = 10_000;
+        let trace_path_s = vec!["transfer/channel-0"; hops].join("/");
+        let pd_s = format!("{trace_path_s}/uatom");
+
+        let pd = PrefixedDenom::from_str(&pd_s).expect("no error");
+
+        assert_eq!(pd.trace_path.0.len(), hops);
+        assert_eq!(pd.base_denom.as_str(), "uatom");
+        assert_eq!(pd.to_string(), pd_s);
+        assert_eq!(
+            TracePath::from_str(&trace_path_s).expect("no error"),
+            pd.trace_path
+        );
+    }
+
+    /// Parses pseudo-random `/`-separated strings built out of segments that
+    /// are commonly found in (possibly malformed) traces, and checks that
+    /// parsing never panics and never loses or reorders any part of the input.
+    #[test]
+    fn test_trace_path_fuzz() {
+        const SEGMENTS: [&str; 12] = [
+            "",
+            " ",
+            "transfer",
+            "customtransfer",
+            "channel-0",
+            "channel-18446744073709551615",
+            "channel-18446744073709551616",
+            "channel-",
+            "channelToA",
+            "(transfer)",
+            "t",
+            "uatom",
+        ];
+
+        // A simple deterministic linear congruential generator keeps the test
+        // reproducible without pulling in a fuzzing dependency.
+        let mut seed: u64 = 0x2545_f491_4f6c_dd1d;
+        let mut next = |bound: usize| {
+            seed = seed
+                .wrapping_mul(6_364_136_223_846_793_005)
+                .wrapping_add(1_442_695_040_888_963_407);
+            ((seed >> 33) as usize) % bound
+        };
+
+        for _ in 0..5_000 {
+            let len = next(8);
+            let s = (0..len)
+                .map(|_| SEGMENTS[next(SEGMENTS.len())])
+                .collect::<Vec<_>>()
+                .join("/");
+
+            let (trace_path, remaining) = TracePath::trim(&s);
+            let reconstructed = match (trace_path.is_empty(), remaining) {
+                (true, remaining) => remaining.map(ToString::to_string),
+                (false, None) => Some(trace_path.to_string()),
+                (false, Some(remaining)) => Some(format!("{trace_path}/{remaining}")),
+            };
+            assert_eq!(reconstructed.as_deref(), Some(s.as_str()), "trim: {s:?}");
+
+            if let Ok(trace_path) = TracePath::from_str(&s) {
+                assert_eq!(trace_path.to_string(), s, "trace path: {s:?}");
+            }
+
+            if let Ok(pd) = PrefixedDenom::from_str(&s) {
+                assert_eq!(pd.to_string(), s, "prefixed denom: {s:?}");
+            }
+        }
+    }
 }
@@ -0,0 +1,121 @@
+//! Helpers normalizing the denominations exchanged over ICS-20, for transfer
+//! applications and wallets alike.
+//!
+//! Denominations are either traced, i.e. of the form
+//! `{port-id}/{channel-id}/.../{base-denom}`, or hashed, i.e. of the form
+//! `ibc/{hash}`, where `{hash}` is the hex-encoded SHA-256 hash of the trace
+//! of the denomination, which the host resolves into it. Only the hex-encoded
+//! hash is case-insensitive: port and channel identifiers, as well as base
+//! denominations, are compared as is.
+
+use core::str::FromStr;
+
+use ibc_core::primitives::prelude::*;
+
+use crate::error::TokenTransferError;
+use crate::{BaseDenom, PrefixedDenom};
+
+/// The prefix of hashed denominations, i.e. of the form `ibc/{hash}`.
+pub const HASHED_DENOM_PREFIX: &str = "ibc/";
+
+/// Returns the hash of `denom`, as is, if it is a hashed denomination, i.e.
+/// a non-empty hex-encoded hash prefixed with `ibc/`.
+pub fn denom_hash(denom: &str) -> Option<&str> {
+    denom
+        .strip_prefix(HASHED_DENOM_PREFIX)
+        .filter(|hash| !hash.is_empty() && hash.bytes().all(|b| b.is_ascii_hexdigit()))
+}
+
+/// Returns the base denomination of the traced `denom`, stripped of its
+/// trace path.
+///
+/// The base denomination of a hashed denomination is only known to the host,
+/// which stores its trace: it is returned as is.
+pub fn base_denom(denom: &str) -> Result<BaseDenom, TokenTransferError> {
+    Ok(PrefixedDenom::from_str(denom)?.base_denom)
+}
+
+/// Returns `denom` in its canonical form, under which ibc-go displays it: its
+/// full trace path followed by its base denomination, e.g.
+/// `transfer/channel-0/uatom`, or, if it is a hashed denomination, `ibc/`
+/// followed by its uppercase hash.
+pub fn display_denom(denom: &str) -> Result<String, TokenTransferError> {
+    match denom_hash(denom) {
+        Some(hash) => Ok(format!(
+            "{HASHED_DENOM_PREFIX}{}",
+            hash.to_ascii_uppercase()
+        )),
+        None => Ok(PrefixedDenom::from_str(denom)?.to_string()),
+    }
+}
+
+/// Returns `true` if both denominations are valid and refer to the same
+/// denomination, once in their canonical form.
+///
+/// The hashes of hashed denominations are compared case-insensitively, and
+/// everything else as is. A hashed denomination never matches a traced one,
+/// as resolving a hash requires the traces stored by the host.
+pub fn denoms_match(a: &str, b: &str) -> bool {
+    match (display_denom(a), display_denom(b)) {
+        (Ok(a), Ok(b)) => a == b,
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case::hashed(
+        "ibc/27394FB092D2ECCD56123C74F36E4C1F926001CEADA9CA97EA622B25F41E5EB2",
+        Some("27394FB092D2ECCD56123C74F36E4C1F926001CEADA9CA97EA622B25F41E5EB2")
+    )]
+    #[case::lowercase_hash("ibc/27394fb0", Some("27394fb0"))]
+    #[case::empty_hash("ibc/", None)]
+    #[case::non_hex_hash("ibc/uatom", None)]
+    #[case::uppercase_prefix("IBC/27394FB0", None)]
+    #[case::traced("transfer/channel-0/uatom", None)]
+    #[case::base("uatom", None)]
+    fn test_denom_hash(#[case] denom: &str, #[case] hash: Option<&str>) {
+        assert_eq!(denom_hash(denom), hash);
+    }
+
+    #[rstest]
+    #[case::base("uatom", "uatom")]
+    #[case::traced("transfer/channel-0/uatom", "uatom")]
+    #[case::multiple_hops("transfer/channel-0/transfer/channel-1/uatom", "uatom")]
+    #[case::slashes("transfer/channel-0/gamm/pool/1", "gamm/pool/1")]
+    #[case::hashed("ibc/27394FB0", "ibc/27394FB0")]
+    fn test_base_denom(#[case] denom: &str, #[case] base: &str) {
+        assert_eq!(base_denom(denom).unwrap().as_str(), base);
+    }
+
+    #[rstest]
+    #[case::base("uatom", "uatom")]
+    #[case::traced("transfer/channel-0/uatom", "transfer/channel-0/uatom")]
+    #[case::lowercase_hash("ibc/27394fb0", "ibc/27394FB0")]
+    #[case::case_sensitive_base("transfer/channel-0/uAtom", "transfer/channel-0/uAtom")]
+    fn test_display_denom(#[case] denom: &str, #[case] displayed: &str) {
+        assert_eq!(display_denom(denom).unwrap(), displayed);
+    }
+
+    #[test]
+    fn test_display_denom_invalid() {
+        assert!(display_denom("").is_err());
+        assert!(display_denom("transfer/channel-0/").is_err());
+    }
+
+    #[rstest]
+    #[case::same("transfer/channel-0/uatom", "transfer/channel-0/uatom", true)]
+    #[case::hash_case("ibc/27394fb0", "ibc/27394FB0", true)]
+    #[case::base_case("uatom", "UATOM", false)]
+    #[case::channel_case("transfer/channel-0/uatom", "transfer/Channel-0/uatom", false)]
+    #[case::other_channel("transfer/channel-0/uatom", "transfer/channel-1/uatom", false)]
+    #[case::invalid("", "", false)]
+    fn test_denoms_match(#[case] a: &str, #[case] b: &str, #[case] expected: bool) {
+        assert_eq!(denoms_match(a, b), expected);
+    }
+}
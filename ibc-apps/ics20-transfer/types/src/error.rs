@@ -9,12 +9,16 @@ use ibc_core::handler::types::error::ContextError;
 use ibc_core::host::types::error::IdentifierError;
 use ibc_core::host::types::identifiers::{ChannelId, PortId};
 use ibc_core::primitives::prelude::*;
+use ibc_core::primitives::Signer;
+use ibc_core::router::types::error::BankError;
 use uint::FromDecStrErr;
 
 #[derive(Display, Debug)]
 pub enum TokenTransferError {
     /// context error: `{0}`
     ContextError(ContextError),
+    /// bank error: `{0}`
+    Bank(BankError),
     /// invalid identifier: `{0}`
     InvalidIdentifier(IdentifierError),
     /// insufficient funds: tried to send `{send_attempt}`, sender only has `{available_funds}`
@@ -77,6 +81,28 @@ pub enum TokenTransferError {
     InvalidCoin { coin: String },
     /// decoding raw bytes as UTF8 string error: `{0}`
     Utf8Decode(Utf8Error),
+    /// no transfer authorization granted for port `{port_id}` and channel `{channel_id}`
+    UnauthorizedChannel {
+        port_id: PortId,
+        channel_id: ChannelId,
+    },
+    /// receiver `{receiver}` is not allowed by the transfer authorization
+    UnauthorizedReceiver { receiver: Signer },
+    /// memo `{memo}` is not allowed by the transfer authorization
+    UnauthorizedMemo { memo: String },
+    /// sending `{send_attempt}` exceeds the spend limit of the transfer authorization
+    SpendLimitExceeded { send_attempt: String },
+    /// token `{denom}` is native to the chain and has no hop to unwind
+    NothingToUnwind { denom: String },
+    /// memo of `{length}` bytes exceeds the maximum length of `{max_length}` bytes
+    MemoTooLong { length: u64, max_length: u64 },
+    /// invalid memo: `{description}`
+    InvalidMemo { description: String },
+    /// invalid `{namespace}` namespace of the memo: {description}
+    InvalidMemoNamespace {
+        namespace: String,
+        description: String,
+    },
     /// other error: `{0}`
     Other(String),
 }
@@ -86,6 +112,7 @@ impl std::error::Error for TokenTransferError {
     fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
         match &self {
             Self::ContextError(e) => Some(e),
+            Self::Bank(e) => Some(e),
             Self::InvalidIdentifier(e)
             | Self::InvalidTracePortId {
                 validation_error: e,
@@ -114,6 +141,12 @@ impl From<ContextError> for TokenTransferError {
     }
 }
 
+impl From<BankError> for TokenTransferError {
+    fn from(err: BankError) -> Self {
+        Self::Bank(err)
+    }
+}
+
 impl From<IdentifierError> for TokenTransferError {
     fn from(err: IdentifierError) -> TokenTransferError {
         Self::InvalidIdentifier(err)
@@ -2,7 +2,7 @@
 use ibc_core::channel::types::acknowledgement::AcknowledgementStatus;
 use ibc_core::primitives::prelude::*;
 use ibc_core::primitives::Signer;
-use ibc_core::router::types::event::ModuleEvent;
+use ibc_core::router::types::event::{ModuleEvent, ModuleEventAttribute};
 
 use super::Memo;
 use crate::{Amount, PrefixedDenom, MODULE_ID_STR};
@@ -52,7 +52,7 @@ impl From<RecvEvent> for ModuleEvent {
                 ("denom", denom).into(),
                 ("amount", amount).into(),
                 ("memo", memo).into(),
-                ("success", success).into(),
+                ModuleEventAttribute::new("success", success),
             ],
         }
     }
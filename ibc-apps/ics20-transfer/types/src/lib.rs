@@ -23,6 +23,8 @@ mod memo;
 pub use amount::*;
 pub use coin::*;
 pub use denom::*;
+pub mod authorization;
+pub mod denom_utils;
 pub mod error;
 pub mod events;
 pub mod msgs;
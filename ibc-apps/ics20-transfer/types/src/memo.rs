@@ -1,13 +1,27 @@
 //! Defines the memo type, which represents the string that users can include
-//! with a token transfer
+//! with a token transfer, along with the parsing of the namespaces of it known
+//! to the middlewares of ICS-20.
 
 use core::convert::Infallible;
 use core::fmt::{
     Display, {self},
 };
 use core::str::FromStr;
+use core::time::Duration;
 
+use ibc_core::host::types::identifiers::{ChannelId, PortId};
 use ibc_core::primitives::prelude::*;
+use ibc_core::primitives::utils::{parse_json, JsonValue};
+
+use crate::error::TokenTransferError;
+
+/// The maximum length of the memos parsed by [`Memo::parse`], matching the
+/// maximum length of the memos of `MsgTransfer` in ibc-go.
+pub const MAX_MEMO_LENGTH: usize = 32768;
+
+/// The maximum length of the addresses read from a memo by [`Memo::parse`],
+/// matching the maximum length of the receivers of ICS-20 packets in ibc-go.
+pub const MAX_MEMO_ADDRESS_LENGTH: usize = 2048;
 
 /// Represents the token transfer memo
 #[cfg_attr(
@@ -58,3 +72,446 @@ impl FromStr for Memo {
         Ok(Self(memo.to_owned()))
     }
 }
+
+impl Memo {
+    /// Parses the namespaces of the memo known to the middlewares of ICS-20,
+    /// so that the memo of a packet is parsed once for all of them.
+    ///
+    /// Unknown keys are ignored, as are memos which are not JSON objects,
+    /// e.g. plain text ones, the memo being free-form. The memo is however
+    /// rejected if it exceeds [`MAX_MEMO_LENGTH`], if it starts as a JSON
+    /// array or object without being valid JSON whose arrays and objects are
+    /// nested at most [`MAX_JSON_DEPTH`] levels deep, or if any of the known
+    /// namespaces it holds is malformed.
+    ///
+    /// The handlers of ICS-20 pass the memo on as is, without parsing it: this
+    /// is left to the modules and middlewares given the memo by their context,
+    /// for which this API is provided.
+    ///
+    /// [`MAX_JSON_DEPTH`]: ibc_core::primitives::utils::MAX_JSON_DEPTH
+    pub fn parse(&self) -> Result<ParsedMemo, TokenTransferError> {
+        if self.0.len() > MAX_MEMO_LENGTH {
+            return Err(TokenTransferError::MemoTooLong {
+                length: self.0.len() as u64,
+                max_length: MAX_MEMO_LENGTH as u64,
+            });
+        }
+
+        let Some(memo) = parse_json(&self.0) else {
+            if self.0.trim_start().starts_with(['{', '[']) {
+                return Err(TokenTransferError::InvalidMemo {
+                    description: "invalid or too deeply nested JSON".to_string(),
+                });
+            }
+            return Ok(ParsedMemo::default());
+        };
+
+        Ok(ParsedMemo {
+            forward: memo
+                .get(FORWARD_NAMESPACE)
+                .map(ForwardMemo::parse)
+                .transpose()?,
+            src_callback: memo
+                .get(SRC_CALLBACK_NAMESPACE)
+                .map(|callback| CallbackMemo::parse(SRC_CALLBACK_NAMESPACE, callback))
+                .transpose()?,
+            dest_callback: memo
+                .get(DEST_CALLBACK_NAMESPACE)
+                .map(|callback| CallbackMemo::parse(DEST_CALLBACK_NAMESPACE, callback))
+                .transpose()?,
+            wasm: memo.get(WASM_NAMESPACE).map(WasmMemo::parse).transpose()?,
+        })
+    }
+}
+
+const FORWARD_NAMESPACE: &str = "forward";
+const SRC_CALLBACK_NAMESPACE: &str = "src_callback";
+const DEST_CALLBACK_NAMESPACE: &str = "dest_callback";
+const WASM_NAMESPACE: &str = "wasm";
+
+/// The namespaces of a memo known to the middlewares of ICS-20, as parsed by
+/// [`Memo::parse`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ParsedMemo {
+    /// The `forward` namespace of the packet forward middleware.
+    pub forward: Option<ForwardMemo>,
+    /// The `src_callback` namespace of the callbacks middleware, for the
+    /// callbacks on the chain sending the packet.
+    pub src_callback: Option<CallbackMemo>,
+    /// The `dest_callback` namespace of the callbacks middleware, for the
+    /// callbacks on the chain receiving the packet.
+    pub dest_callback: Option<CallbackMemo>,
+    /// The `wasm` namespace of the IBC hooks middleware.
+    pub wasm: Option<WasmMemo>,
+}
+
+/// The `forward` namespace of a memo, from which the packet forward
+/// middleware forwards the received tokens to another chain.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ForwardMemo {
+    /// The receiver of the tokens on the next chain.
+    pub receiver: String,
+    /// The port to forward the tokens over.
+    pub port: PortId,
+    /// The channel to forward the tokens over.
+    pub channel: ChannelId,
+    /// The timeout of the forwarded packet, relative to the time it is
+    /// forwarded at.
+    pub timeout: Option<Duration>,
+    /// The number of times forwarding is retried upon a timeout.
+    pub retries: Option<u8>,
+    /// The memo of the forwarded packet, as JSON.
+    pub next: Option<String>,
+}
+
+impl ForwardMemo {
+    fn parse(forward: &JsonValue) -> Result<Self, TokenTransferError> {
+        let namespace = FORWARD_NAMESPACE;
+        let forward = expect_object(namespace, forward, "the namespace")?;
+
+        let port = required_str(namespace, forward, "port")?;
+        let channel = required_str(namespace, forward, "channel")?;
+
+        let timeout = forward
+            .get("timeout")
+            .map(|timeout| {
+                match timeout {
+                    JsonValue::Number(nanos) => nanos.parse().ok().map(Duration::from_nanos),
+                    JsonValue::String(duration) => parse_go_duration(duration),
+                    _ => None,
+                }
+                .ok_or_else(|| {
+                    invalid(
+                        namespace,
+                        "`timeout` must be a number of nanoseconds or a duration",
+                    )
+                })
+            })
+            .transpose()?;
+
+        let retries = match forward.get("retries") {
+            None => None,
+            Some(JsonValue::Number(retries)) => Some(
+                retries
+                    .parse()
+                    .map_err(|_| invalid(namespace, "`retries` must fit in 8 bits"))?,
+            ),
+            Some(_) => return Err(invalid(namespace, "`retries` must be a number")),
+        };
+
+        let next = match forward.get("next") {
+            None => None,
+            Some(JsonValue::String(next)) => Some(next.clone()),
+            Some(next @ JsonValue::Object(_)) => Some(next.encode()),
+            Some(_) => return Err(invalid(namespace, "`next` must be an object or a string")),
+        };
+
+        Ok(Self {
+            receiver: required_address(namespace, forward, "receiver")?,
+            port: port
+                .parse()
+                .map_err(|e| invalid(namespace, format!("invalid `port`: {e}")))?,
+            channel: channel
+                .parse()
+                .map_err(|e| invalid(namespace, format!("invalid `channel`: {e}")))?,
+            timeout,
+            retries,
+            next,
+        })
+    }
+}
+
+/// The `src_callback` or `dest_callback` namespace of a memo, from which the
+/// callbacks middleware calls back a contract upon the lifecycle events of
+/// the packet.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CallbackMemo {
+    /// The address of the contract to call back.
+    pub address: String,
+    /// The maximum amount of gas the callbacks may consume, if given by the
+    /// user rather than left to the host.
+    pub gas_limit: Option<u64>,
+}
+
+impl CallbackMemo {
+    fn parse(namespace: &'static str, callback: &JsonValue) -> Result<Self, TokenTransferError> {
+        let callback = expect_object(namespace, callback, "the namespace")?;
+
+        // The gas limit is a string in ibc-go, numbers being accepted as well.
+        let gas_limit = match callback.get("gas_limit") {
+            None => None,
+            Some(JsonValue::String(gas_limit) | JsonValue::Number(gas_limit)) => Some(
+                gas_limit
+                    .parse()
+                    .map_err(|_| invalid(namespace, "`gas_limit` must be a 64-bit integer"))?,
+            ),
+            Some(_) => return Err(invalid(namespace, "`gas_limit` must be a string")),
+        };
+
+        Ok(Self {
+            address: required_address(namespace, callback, "address")?,
+            gas_limit,
+        })
+    }
+}
+
+/// The `wasm` namespace of a memo, from which the IBC hooks middleware
+/// executes a contract with the received tokens.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct WasmMemo {
+    /// The address of the contract to execute.
+    pub contract: String,
+    /// The message to execute the contract with, as JSON.
+    pub msg: String,
+}
+
+impl WasmMemo {
+    fn parse(wasm: &JsonValue) -> Result<Self, TokenTransferError> {
+        let namespace = WASM_NAMESPACE;
+        let wasm = expect_object(namespace, wasm, "the namespace")?;
+
+        let msg = wasm
+            .get("msg")
+            .ok_or_else(|| invalid(namespace, "missing `msg`"))?;
+
+        Ok(Self {
+            contract: required_address(namespace, wasm, "contract")?,
+            msg: expect_object(namespace, msg, "`msg`")?.encode(),
+        })
+    }
+}
+
+fn invalid(namespace: &'static str, description: impl Into<String>) -> TokenTransferError {
+    TokenTransferError::InvalidMemoNamespace {
+        namespace: namespace.to_string(),
+        description: description.into(),
+    }
+}
+
+fn expect_object<'a>(
+    namespace: &'static str,
+    value: &'a JsonValue,
+    what: &str,
+) -> Result<&'a JsonValue, TokenTransferError> {
+    match value {
+        JsonValue::Object(_) => Ok(value),
+        _ => Err(invalid(namespace, format!("{what} must be an object"))),
+    }
+}
+
+fn required_str<'a>(
+    namespace: &'static str,
+    object: &'a JsonValue,
+    key: &str,
+) -> Result<&'a str, TokenTransferError> {
+    object
+        .get(key)
+        .ok_or_else(|| invalid(namespace, format!("missing `{key}`")))?
+        .as_str()
+        .ok_or_else(|| invalid(namespace, format!("`{key}` must be a string")))
+}
+
+/// Returns the address under `key`, which must neither be empty nor exceed
+/// [`MAX_MEMO_ADDRESS_LENGTH`].
+fn required_address(
+    namespace: &'static str,
+    object: &JsonValue,
+    key: &str,
+) -> Result<String, TokenTransferError> {
+    let address = required_str(namespace, object, key)?.trim();
+
+    if address.is_empty() {
+        return Err(invalid(namespace, format!("`{key}` must not be empty")));
+    }
+    if address.len() > MAX_MEMO_ADDRESS_LENGTH {
+        return Err(invalid(
+            namespace,
+            format!("`{key}` exceeds {MAX_MEMO_ADDRESS_LENGTH} bytes"),
+        ));
+    }
+
+    Ok(address.to_string())
+}
+
+/// Parses a duration as formatted by Go's `time.Duration`, e.g. `1h30m`,
+/// `1.5h` or `500ms`. Unlike Go, negative durations are rejected.
+fn parse_go_duration(duration: &str) -> Option<Duration> {
+    if duration == "0" {
+        return Some(Duration::ZERO);
+    }
+
+    let mut rest = duration;
+    let mut total = Duration::ZERO;
+
+    while !rest.is_empty() {
+        let (int, tail) = rest.split_at(rest.bytes().take_while(u8::is_ascii_digit).count());
+        let (frac, tail) = match tail.strip_prefix('.') {
+            Some(tail) => tail.split_at(tail.bytes().take_while(u8::is_ascii_digit).count()),
+            None => ("", tail),
+        };
+        if int.is_empty() && frac.is_empty() {
+            return None;
+        }
+
+        let (unit, nanos) = ["ns", "us", "µs", "ms", "h", "m", "s"]
+            .into_iter()
+            .zip([
+                1,
+                1_000,
+                1_000,
+                1_000_000,
+                3_600_000_000_000,
+                60_000_000_000,
+                1_000_000_000,
+            ])
+            .find(|(unit, _)| tail.starts_with(unit))?;
+        rest = &tail[unit.len()..];
+
+        let int: u64 = if int.is_empty() { 0 } else { int.parse().ok()? };
+
+        // The fraction is truncated to nanoseconds, so that the digits beyond
+        // the 18th, which cannot matter, are ignored as with Go.
+        let frac = &frac[..frac.len().min(18)];
+        let frac_nanos = if frac.is_empty() {
+            0
+        } else {
+            let numerator: u128 = frac.parse().ok()?;
+            u64::try_from(numerator * u128::from(nanos) / 10u128.pow(frac.len() as u32)).ok()?
+        };
+
+        total = total.checked_add(Duration::from_nanos(
+            int.checked_mul(nanos)?.checked_add(frac_nanos)?,
+        ))?;
+    }
+
+    (!duration.is_empty()).then_some(total)
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[test]
+    fn test_parse_memo_namespaces() {
+        let memo = Memo::from(
+            r#"{
+                "forward": {
+                    "receiver": "osmo1receiver",
+                    "port": "transfer",
+                    "channel": "channel-7",
+                    "timeout": "1h30m",
+                    "retries": 2,
+                    "next": {"wasm": {"contract": "osmo1contract", "msg": {}}}
+                },
+                "src_callback": {"address": "cosmos1contract", "gas_limit": "100000"},
+                "dest_callback": {"address": "osmo1contract"},
+                "wasm": {"contract": "osmo1contract", "msg": {"swap": {"min": "1"}, "a": null}},
+                "unknown": [1, 2, 3]
+            }"#,
+        );
+
+        assert_eq!(
+            memo.parse().expect("valid memo"),
+            ParsedMemo {
+                forward: Some(ForwardMemo {
+                    receiver: "osmo1receiver".to_string(),
+                    port: PortId::transfer(),
+                    channel: ChannelId::new(7),
+                    timeout: Some(Duration::from_secs(5400)),
+                    retries: Some(2),
+                    next: Some(r#"{"wasm":{"contract":"osmo1contract","msg":{}}}"#.to_string()),
+                }),
+                src_callback: Some(CallbackMemo {
+                    address: "cosmos1contract".to_string(),
+                    gas_limit: Some(100_000),
+                }),
+                dest_callback: Some(CallbackMemo {
+                    address: "osmo1contract".to_string(),
+                    gas_limit: None,
+                }),
+                wasm: Some(WasmMemo {
+                    contract: "osmo1contract".to_string(),
+                    msg: r#"{"a":null,"swap":{"min":"1"}}"#.to_string(),
+                }),
+            }
+        );
+    }
+
+    #[rstest]
+    #[case("")]
+    #[case("a plain text memo")]
+    #[case("1")]
+    #[case(r#""a JSON string""#)]
+    #[case(r#"{"unknown":{"forward":1}}"#)]
+    fn test_parse_memo_without_namespaces(#[case] memo: &str) {
+        assert_eq!(
+            Memo::from(memo).parse().expect("valid memo"),
+            ParsedMemo::default()
+        );
+    }
+
+    #[rstest]
+    #[case(r#"{"forward":{"receiver":"osmo1receiver","port":"transfer"}}"#)]
+    #[case(r#"{"forward":{"receiver":"","port":"transfer","channel":"channel-7"}}"#)]
+    #[case(r#"{"forward":{"receiver":"r","port":"transfer","channel":"chan"}}"#)]
+    #[case(r#"{"forward":{"receiver":"r","port":"transfer","channel":"channel-7","retries":256}}"#)]
+    #[case(
+        r#"{"forward":{"receiver":"r","port":"transfer","channel":"channel-7","timeout":"1.5"}}"#
+    )]
+    #[case(r#"{"forward":{"receiver":"r","port":"transfer","channel":"channel-7","next":1}}"#)]
+    #[case(r#"{"src_callback":"cosmos1contract"}"#)]
+    #[case(r#"{"dest_callback":{"address":"cosmos1contract","gas_limit":"-1"}}"#)]
+    #[case(r#"{"wasm":{"contract":"osmo1contract","msg":"{}"}}"#)]
+    fn test_parse_memo_invalid_namespace(#[case] memo: &str) {
+        assert!(matches!(
+            Memo::from(memo).parse(),
+            Err(TokenTransferError::InvalidMemoNamespace { .. })
+        ));
+    }
+
+    #[test]
+    fn test_parse_memo_limits() {
+        let address = "a".repeat(MAX_MEMO_ADDRESS_LENGTH + 1);
+        assert!(matches!(
+            Memo::from(format!(r#"{{"dest_callback":{{"address":"{address}"}}}}"#)).parse(),
+            Err(TokenTransferError::InvalidMemoNamespace { .. })
+        ));
+
+        assert!(matches!(
+            Memo::from(" ".repeat(MAX_MEMO_LENGTH + 1)).parse(),
+            Err(TokenTransferError::MemoTooLong { .. })
+        ));
+
+        let nested = "[".repeat(65) + &"]".repeat(65);
+        assert!(matches!(
+            Memo::from(format!(r#"{{"wasm":{nested}}}"#)).parse(),
+            Err(TokenTransferError::InvalidMemo { .. })
+        ));
+    }
+
+    #[rstest]
+    #[case("0", Some(Duration::ZERO))]
+    #[case("10m", Some(Duration::from_secs(600)))]
+    #[case("1h2m3s4ms5us6ns", Some(Duration::new(3723, 4_005_006)))]
+    #[case("1µs", Some(Duration::from_micros(1)))]
+    #[case("1.5h", Some(Duration::from_secs(5400)))]
+    #[case("0.5s", Some(Duration::from_millis(500)))]
+    #[case(".5s", Some(Duration::from_millis(500)))]
+    #[case("1.s", Some(Duration::from_secs(1)))]
+    #[case("1m0.000000001s", Some(Duration::new(60, 1)))]
+    #[case("0.0000000009s", Some(Duration::ZERO))]
+    #[case("1.5ms2.25us", Some(Duration::from_nanos(1_502_250)))]
+    #[case("", None)]
+    #[case("10", None)]
+    #[case("m", None)]
+    #[case("1d", None)]
+    #[case("-1s", None)]
+    #[case(".s", None)]
+    #[case("1.5", None)]
+    #[case("1..5s", None)]
+    fn test_parse_go_duration(#[case] duration: &str, #[case] expected: Option<Duration>) {
+        assert_eq!(parse_go_duration(duration), expected);
+    }
+}
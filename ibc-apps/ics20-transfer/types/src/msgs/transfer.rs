@@ -5,7 +5,7 @@ use ibc_core::channel::types::timeout::TimeoutHeight;
 use ibc_core::handler::types::error::ContextError;
 use ibc_core::host::types::identifiers::{ChannelId, PortId};
 use ibc_core::primitives::prelude::*;
-use ibc_core::primitives::Timestamp;
+use ibc_core::primitives::{Msg, Signer, Timestamp};
 use ibc_proto::google::protobuf::Any;
 use ibc_proto::ibc::applications::transfer::v1::MsgTransfer as RawMsgTransfer;
 use ibc_proto::Protobuf;
@@ -48,6 +48,40 @@ pub struct MsgTransfer {
     pub timeout_timestamp_on_b: Timestamp,
 }
 
+impl MsgTransfer {
+    /// Builds a message sending the token of `packet_data` back over the
+    /// channel it was received from on its last hop, as derived from its
+    /// trace, which unwinds that hop rather than wrapping the token once more.
+    ///
+    /// Fails if the token is native to the chain, in which case there is no
+    /// hop to unwind.
+    pub fn unwind(
+        packet_data: PacketData,
+        timeout_height_on_b: TimeoutHeight,
+        timeout_timestamp_on_b: Timestamp,
+    ) -> Result<Self, TokenTransferError> {
+        let prefix = packet_data.token.denom.unwind_prefix().ok_or_else(|| {
+            TokenTransferError::NothingToUnwind {
+                denom: packet_data.token.denom.to_string(),
+            }
+        })?;
+
+        Ok(Self {
+            port_id_on_a: prefix.port_id().clone(),
+            chan_id_on_a: prefix.channel_id().clone(),
+            packet_data,
+            timeout_height_on_b,
+            timeout_timestamp_on_b,
+        })
+    }
+}
+
+impl Msg for MsgTransfer {
+    fn signer(&self) -> &Signer {
+        &self.packet_data.sender
+    }
+}
+
 impl TryFrom<RawMsgTransfer> for MsgTransfer {
     type Error = TokenTransferError;
 
@@ -3,8 +3,10 @@
 use core::str::FromStr;
 
 use ibc_core::primitives::prelude::*;
+use ibc_core::primitives::utils::SortedJsonObject;
 use ibc_core::primitives::Signer;
 use ibc_proto::ibc::applications::transfer::v2::FungibleTokenPacketData as RawPacketData;
+use ibc_proto::Protobuf;
 
 use super::error::TokenTransferError;
 use super::{Amount, Memo, PrefixedCoin, PrefixedDenom};
@@ -32,6 +34,27 @@ pub struct PacketData {
     pub memo: Memo,
 }
 
+impl PacketData {
+    /// Encodes the packet data into the bytes carried by packets,
+    /// byte-for-byte as ibc-go does, so that the commitments of the packets
+    /// match across implementations.
+    ///
+    /// Unlike the `Serialize` implementation, which keeps the order of the
+    /// fields of the proto message, the keys are sorted and an empty memo is
+    /// omitted.
+    pub fn encode_json(&self) -> String {
+        SortedJsonObject::new()
+            .string("amount", &self.token.amount.to_string())
+            .string("denom", &self.token.denom.to_string())
+            .string("memo", self.memo.as_ref())
+            .string("receiver", self.receiver.as_ref())
+            .string("sender", self.sender.as_ref())
+            .encode()
+    }
+}
+
+impl Protobuf<RawPacketData> for PacketData {}
+
 impl TryFrom<RawPacketData> for PacketData {
     type Error = TokenTransferError;
 
@@ -118,4 +141,17 @@ mod tests {
         PacketData::new_dummy().deser_json_assert_eq(dummy_json_packet_data());
         PacketData::new_dummy().deser_json_assert_eq(dummy_json_packet_data_without_memo());
     }
+
+    /// Ensures `PacketData` is encoded into packets with sorted keys and
+    /// without its empty memo, as ibc-go does, while staying decodable.
+    #[test]
+    fn test_packet_data_encode_json() {
+        let json = PacketData::new_dummy().encode_json();
+
+        assert_eq!(
+            json,
+            r#"{"amount":"10","denom":"uatom","receiver":"cosmos1wxeyh7zgn4tctjzs0vtqpc6p5cxq5t2muzl7ng","sender":"cosmos1wxeyh7zgn4tctjzs0vtqpc6p5cxq5t2muzl7ng"}"#
+        );
+        PacketData::new_dummy().deser_json_assert_eq(&json);
+    }
 }
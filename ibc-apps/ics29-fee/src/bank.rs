@@ -0,0 +1,82 @@
+//! Implements the fee movements of ICS-29 on top of a [`BankContext`], for
+//! hosts implementing the movement of fungible tokens once for all the IBC
+//! applications.
+
+use ibc_app_transfer_types::PrefixedCoin;
+use ibc_core::primitives::prelude::*;
+use ibc_core::primitives::Signer;
+use ibc_core::router::bank::BankContext;
+use ibc_core::router::types::error::BankError;
+use ibc_core::router::types::event::ModuleEvent;
+use ibc_core::router::types::module::{ModuleAccount, ModuleId};
+
+use crate::types::error::FeeError;
+use crate::types::events::DistributeFeeEvent;
+use crate::types::{FeePayout, PacketFee, MODULE_ID_STR};
+
+/// Returns the account of the fee middleware, which holds the fees escrowed
+/// for all the incentivized packets.
+pub fn fee_module_account() -> ModuleAccount {
+    ModuleAccount::Module(ModuleId::new(MODULE_ID_STR.to_string()))
+}
+
+/// Escrows all the coins of `packet_fee`, paid by `payer`, into the account of
+/// the fee middleware, as required before recording it with
+/// [`add_packet_fee_execute`](crate::handler::add_packet_fee_execute).
+pub fn escrow_packet_fee<Bank>(
+    bank: &mut Bank,
+    payer: &Bank::AccountId,
+    packet_fee: &PacketFee,
+) -> Result<(), FeeError>
+where
+    Bank: BankContext<PrefixedCoin>,
+{
+    let fee_account = bank.module_account(&fee_module_account())?;
+
+    let fee = &packet_fee.fee;
+    for coin in fee
+        .recv_fee
+        .iter()
+        .chain(&fee.ack_fee)
+        .chain(&fee.timeout_fee)
+    {
+        bank.transfer(payer, &fee_account, coin)?;
+    }
+
+    Ok(())
+}
+
+/// Pays out the fees distributed to relayers or refunded, e.g. as returned by
+/// [`distribute_packet_fees_on_ack_execute`](crate::handler::distribute_packet_fees_on_ack_execute),
+/// from the account of the fee middleware.
+///
+/// Returns a [`DistributeFeeEvent`] for each payout, to be emitted by the
+/// caller, which relayers rely on to reconcile the fees they were paid.
+pub fn pay_out_fees<Bank>(
+    bank: &mut Bank,
+    payouts: &[FeePayout],
+) -> Result<Vec<ModuleEvent>, FeeError>
+where
+    Bank: BankContext<PrefixedCoin>,
+    Bank::AccountId: TryFrom<Signer>,
+{
+    let fee_account = bank.module_account(&fee_module_account())?;
+
+    for payout in payouts {
+        let recipient = Bank::AccountId::try_from(payout.recipient.clone()).map_err(|_| {
+            BankError::InvalidAccount {
+                account: payout.recipient.to_string(),
+            }
+        })?;
+
+        for coin in &payout.coins {
+            bank.transfer(&fee_account, &recipient, coin)?;
+        }
+    }
+
+    Ok(payouts
+        .iter()
+        .cloned()
+        .map(|payout| DistributeFeeEvent::from(payout).into())
+        .collect())
+}
@@ -0,0 +1,59 @@
+//! Defines the required context traits for ICS-29 to interact with host
+//! machine.
+use ibc_core::channel::types::packet::PacketId;
+use ibc_core::host::types::identifiers::{ChannelId, PortId};
+use ibc_core::primitives::prelude::*;
+use ibc_core::primitives::Signer;
+
+use crate::types::error::FeeError;
+use crate::types::{IdentifiedPacketFees, PacketFees};
+
+/// Read-only methods required in fee middleware validation context.
+pub trait FeeValidationContext {
+    /// Returns the fees escrowed for the packet identified by `packet_id`, if
+    /// it is incentivized.
+    fn packet_fees(&self, packet_id: &PacketId) -> Result<Option<PacketFees>, FeeError>;
+
+    /// Returns the fees escrowed for all the incentivized packets sent on the
+    /// given channel, ordered by sequence.
+    ///
+    /// Used to serve the `IncentivizedPacketsForChannel` query.
+    fn incentivized_packets_for_channel(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<Vec<IdentifiedPacketFees>, FeeError>;
+
+    /// Returns the address on the counterparty chain to which `relayer` wants
+    /// the receive fees of the packets it relays on the given channel to be
+    /// paid, if it registered one.
+    fn counterparty_payee(
+        &self,
+        channel_id: &ChannelId,
+        relayer: &Signer,
+    ) -> Result<Option<Signer>, FeeError>;
+}
+
+/// Read-write methods required in fee middleware execution context.
+pub trait FeeExecutionContext: FeeValidationContext {
+    /// Stores the fees escrowed for the packet identified by `packet_id`,
+    /// replacing the ones stored previously, if any.
+    fn store_packet_fees(
+        &mut self,
+        packet_id: PacketId,
+        packet_fees: PacketFees,
+    ) -> Result<(), FeeError>;
+
+    /// Deletes the fees escrowed for the packet identified by `packet_id`,
+    /// once they are distributed to the relayers or refunded.
+    fn delete_packet_fees(&mut self, packet_id: &PacketId) -> Result<(), FeeError>;
+
+    /// Stores the counterparty payee of `relayer` on the given channel,
+    /// replacing the one stored previously, if any.
+    fn store_counterparty_payee(
+        &mut self,
+        channel_id: ChannelId,
+        relayer: Signer,
+        counterparty_payee: Signer,
+    ) -> Result<(), FeeError>;
+}
@@ -0,0 +1,166 @@
+//! Implements the bookkeeping of the fees escrowed for incentivized packets.
+use ibc_core::channel::types::packet::PacketId;
+use ibc_core::host::types::identifiers::ChannelId;
+use ibc_core::primitives::prelude::*;
+use ibc_core::primitives::Signer;
+
+use crate::context::{FeeExecutionContext, FeeValidationContext};
+use crate::types::error::FeeError;
+use crate::types::{FeePayout, PacketFee, PacketFees, PacketRelayers};
+
+/// Records `packet_fee` as escrowed for the packet identified by `packet_id`,
+/// along with the fees escrowed for it previously, if any.
+///
+/// Transferring the fee into escrow is left to the caller.
+pub fn add_packet_fee_execute<Ctx>(
+    ctx: &mut Ctx,
+    packet_id: PacketId,
+    packet_fee: PacketFee,
+) -> Result<(), FeeError>
+where
+    Ctx: FeeExecutionContext,
+{
+    let mut packet_fees = ctx.packet_fees(&packet_id)?.unwrap_or_default();
+
+    packet_fees.packet_fees.push(packet_fee);
+
+    ctx.store_packet_fees(packet_id, packet_fees)
+}
+
+/// Registers `counterparty_payee` as the address on the counterparty chain to
+/// which the receive fees of the packets relayed by `relayer` on the given
+/// channel are paid, as `MsgRegisterCounterpartyPayee` does.
+pub fn register_counterparty_payee_execute<Ctx>(
+    ctx: &mut Ctx,
+    channel_id: ChannelId,
+    relayer: Signer,
+    counterparty_payee: Signer,
+) -> Result<(), FeeError>
+where
+    Ctx: FeeExecutionContext,
+{
+    if counterparty_payee.is_empty() {
+        return Err(FeeError::EmptyCounterpartyPayee);
+    }
+
+    ctx.store_counterparty_payee(channel_id, relayer, counterparty_payee)
+}
+
+/// Returns the forward relayer to record in the acknowledgement of a packet
+/// received on the given channel from `relayer`, i.e. its counterparty payee,
+/// if it registered one.
+///
+/// The receive fees of the packet are refunded on the counterparty chain if
+/// the forward relayer is unknown, as they could not be paid there to the
+/// relayer, which has no known address on it.
+pub fn forward_relayer<Ctx>(
+    ctx: &Ctx,
+    channel_id: &ChannelId,
+    relayer: &Signer,
+) -> Result<Option<Signer>, FeeError>
+where
+    Ctx: FeeValidationContext,
+{
+    ctx.counterparty_payee(channel_id, relayer)
+}
+
+/// Removes and returns the fees escrowed for the packet identified by
+/// `packet_id`, for them to be distributed to the relayers of the packet or
+/// refunded, once the packet is acknowledged or timed out.
+pub fn take_packet_fees_execute<Ctx>(
+    ctx: &mut Ctx,
+    packet_id: &PacketId,
+) -> Result<PacketFees, FeeError>
+where
+    Ctx: FeeExecutionContext,
+{
+    let packet_fees = ctx
+        .packet_fees(packet_id)?
+        .ok_or_else(|| FeeError::PacketFeesNotFound {
+            packet_id: packet_id.clone(),
+        })?;
+
+    ctx.delete_packet_fees(packet_id)?;
+
+    Ok(packet_fees)
+}
+
+/// Removes the fees escrowed for the acknowledged packet identified by
+/// `packet_id`, and returns how to distribute them: the receive fees to the
+/// forward relayer, the acknowledgement fees to the reverse relayer, and the
+/// timeout fees back to the refund address of each fee.
+///
+/// The receive fees are refunded as well if the forward relayer is unknown.
+/// Transferring the coins out of escrow is left to the caller.
+pub fn distribute_packet_fees_on_ack_execute<Ctx>(
+    ctx: &mut Ctx,
+    packet_id: &PacketId,
+    relayers: &PacketRelayers,
+) -> Result<Vec<FeePayout>, FeeError>
+where
+    Ctx: FeeExecutionContext,
+{
+    let packet_fees = take_packet_fees_execute(ctx, packet_id)?;
+
+    let mut payouts = Vec::new();
+    let mut pay = |recipient: &Signer, coins: Vec<_>| {
+        if !coins.is_empty() {
+            payouts.push(FeePayout::new(recipient.clone(), coins));
+        }
+    };
+
+    for PacketFee {
+        fee,
+        refund_address,
+        ..
+    } in packet_fees.packet_fees
+    {
+        pay(
+            relayers.forward_relayer.as_ref().unwrap_or(&refund_address),
+            fee.recv_fee,
+        );
+        pay(&relayers.reverse_relayer, fee.ack_fee);
+        pay(&refund_address, fee.timeout_fee);
+    }
+
+    Ok(payouts)
+}
+
+/// Removes the fees escrowed for the timed out packet identified by
+/// `packet_id`, and returns how to distribute them: the timeout fees to the
+/// `timeout_relayer`, and the receive and acknowledgement fees back to the
+/// refund address of each fee, since the packet was never received.
+///
+/// Transferring the coins out of escrow is left to the caller.
+pub fn distribute_packet_fees_on_timeout_execute<Ctx>(
+    ctx: &mut Ctx,
+    packet_id: &PacketId,
+    timeout_relayer: &Signer,
+) -> Result<Vec<FeePayout>, FeeError>
+where
+    Ctx: FeeExecutionContext,
+{
+    let packet_fees = take_packet_fees_execute(ctx, packet_id)?;
+
+    let mut payouts = Vec::new();
+    let mut pay = |recipient: &Signer, coins: Vec<_>| {
+        if !coins.is_empty() {
+            payouts.push(FeePayout::new(recipient.clone(), coins));
+        }
+    };
+
+    for PacketFee {
+        fee,
+        refund_address,
+        ..
+    } in packet_fees.packet_fees
+    {
+        pay(timeout_relayer, fee.timeout_fee);
+        pay(
+            &refund_address,
+            fee.recv_fee.into_iter().chain(fee.ack_fee).collect(),
+        );
+    }
+
+    Ok(payouts)
+}
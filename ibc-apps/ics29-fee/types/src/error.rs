@@ -0,0 +1,94 @@
+//! Defines the fee middleware error type
+use displaydoc::Display;
+use ibc_app_transfer_types::error::TokenTransferError;
+use ibc_core::channel::types::error::PacketError;
+use ibc_core::channel::types::packet::PacketId;
+use ibc_core::handler::types::error::ContextError;
+use ibc_core::primitives::prelude::*;
+use ibc_core::primitives::AbciError;
+use ibc_core::router::types::error::BankError;
+
+#[derive(Display, Debug)]
+pub enum FeeError {
+    /// context error: `{0}`
+    ContextError(ContextError),
+    /// bank error: `{0}`
+    Bank(BankError),
+    /// invalid fee coin: `{0}`
+    InvalidCoin(TokenTransferError),
+    /// invalid packet identifier: `{0}`
+    InvalidPacketId(PacketError),
+    /// missing fee
+    MissingFee,
+    /// missing packet identifier
+    MissingPacketId,
+    /// empty refund address
+    EmptyRefundAddress,
+    /// empty counterparty payee address
+    EmptyCounterpartyPayee,
+    /// no fees found for packet `{packet_id}`
+    PacketFeesNotFound { packet_id: PacketId },
+    /// other error: `{0}`
+    Other(String),
+}
+
+impl AbciError for FeeError {
+    fn codespace(&self) -> &'static str {
+        match self {
+            Self::ContextError(e) => e.codespace(),
+            _ => "feeibc",
+        }
+    }
+
+    fn code(&self) -> u32 {
+        match self {
+            Self::ContextError(e) => e.code(),
+            Self::InvalidCoin(_) => 2,
+            Self::InvalidPacketId(_) => 3,
+            Self::MissingFee => 4,
+            Self::MissingPacketId => 5,
+            Self::EmptyRefundAddress => 6,
+            Self::PacketFeesNotFound { .. } => 7,
+            Self::Other(_) => 8,
+            Self::Bank(_) => 9,
+            Self::EmptyCounterpartyPayee => 10,
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for FeeError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match &self {
+            Self::ContextError(e) => Some(e),
+            Self::Bank(e) => Some(e),
+            Self::InvalidCoin(e) => Some(e),
+            Self::InvalidPacketId(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<ContextError> for FeeError {
+    fn from(err: ContextError) -> FeeError {
+        Self::ContextError(err)
+    }
+}
+
+impl From<BankError> for FeeError {
+    fn from(err: BankError) -> Self {
+        Self::Bank(err)
+    }
+}
+
+impl From<TokenTransferError> for FeeError {
+    fn from(err: TokenTransferError) -> FeeError {
+        Self::InvalidCoin(err)
+    }
+}
+
+impl From<PacketError> for FeeError {
+    fn from(err: PacketError) -> FeeError {
+        Self::InvalidPacketId(err)
+    }
+}
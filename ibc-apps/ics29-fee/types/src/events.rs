@@ -0,0 +1,68 @@
+//! Defines the events emitted by the fee middleware
+use ibc_app_transfer_types::PrefixedCoin;
+use ibc_core::primitives::prelude::*;
+use ibc_core::primitives::Signer;
+use ibc_core::router::types::event::ModuleEvent;
+
+use crate::FeePayout;
+
+const EVENT_TYPE_DISTRIBUTE_FEE: &str = "distribute_fee";
+
+/// Event emitted upon paying out of escrow the fees distributed to a relayer
+/// or refunded, as ibc-go does for each of the fees escrowed for a packet.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DistributeFeeEvent {
+    pub receiver: Signer,
+    pub fee: Vec<PrefixedCoin>,
+}
+
+impl From<FeePayout> for DistributeFeeEvent {
+    fn from(payout: FeePayout) -> Self {
+        Self {
+            receiver: payout.recipient,
+            fee: payout.coins,
+        }
+    }
+}
+
+impl From<DistributeFeeEvent> for ModuleEvent {
+    fn from(ev: DistributeFeeEvent) -> Self {
+        let DistributeFeeEvent { receiver, fee } = ev;
+        let fee = fee
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>()
+            .join(",");
+
+        Self {
+            kind: EVENT_TYPE_DISTRIBUTE_FEE.to_string(),
+            attributes: vec![("receiver", receiver).into(), ("fee", fee).into()],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ibc_core::router::types::event::ModuleEventAttribute;
+
+    use super::*;
+
+    #[test]
+    fn distribute_fee_event_attributes() {
+        let coin = |s: &str| s.parse::<PrefixedCoin>().expect("valid coin");
+
+        let event = ModuleEvent::from(DistributeFeeEvent::from(FeePayout::new(
+            Signer::from("cosmos1relayer".to_string()),
+            vec![coin("10stake"), coin("5transfer/channel-1/uatom")],
+        )));
+
+        assert_eq!(event.kind, "distribute_fee");
+        assert_eq!(
+            event.attributes,
+            vec![
+                ModuleEventAttribute::from(("receiver", "cosmos1relayer")),
+                ModuleEventAttribute::from(("fee", "10stake,5transfer/channel-1/uatom")),
+            ]
+        );
+    }
+}
@@ -0,0 +1,346 @@
+//! Defines the fees escrowed to incentivize the relaying of packets.
+use ibc_app_transfer_types::PrefixedCoin;
+use ibc_core::channel::types::packet::PacketId;
+use ibc_core::primitives::prelude::*;
+use ibc_core::primitives::Signer;
+use ibc_proto::ibc::apps::fee::v1::{
+    Fee as RawFee, IdentifiedPacketFees as RawIdentifiedPacketFees, PacketFee as RawPacketFee,
+    PacketFees as RawPacketFees,
+};
+use ibc_proto::Protobuf;
+
+use crate::error::FeeError;
+
+/// The fees paid to the relayers of a packet, for each step of its
+/// lifecycle.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(parity_scale_codec::Encode, parity_scale_codec::Decode,)
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Fee {
+    /// Paid to the relayer of the packet to the counterparty chain.
+    pub recv_fee: Vec<PrefixedCoin>,
+    /// Paid to the relayer of the acknowledgement of the packet.
+    pub ack_fee: Vec<PrefixedCoin>,
+    /// Paid to the relayer of the timeout of the packet.
+    pub timeout_fee: Vec<PrefixedCoin>,
+}
+
+impl Protobuf<RawFee> for Fee {}
+
+impl TryFrom<RawFee> for Fee {
+    type Error = FeeError;
+
+    fn try_from(raw: RawFee) -> Result<Self, Self::Error> {
+        let try_from_coins = |coins: Vec<_>| {
+            coins
+                .into_iter()
+                .map(PrefixedCoin::try_from)
+                .collect::<Result<Vec<_>, _>>()
+        };
+
+        Ok(Self {
+            recv_fee: try_from_coins(raw.recv_fee)?,
+            ack_fee: try_from_coins(raw.ack_fee)?,
+            timeout_fee: try_from_coins(raw.timeout_fee)?,
+        })
+    }
+}
+
+impl From<Fee> for RawFee {
+    fn from(fee: Fee) -> Self {
+        Self {
+            recv_fee: fee.recv_fee.into_iter().map(Into::into).collect(),
+            ack_fee: fee.ack_fee.into_iter().map(Into::into).collect(),
+            timeout_fee: fee.timeout_fee.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+/// A fee escrowed for a packet, refunded to `refund_address` for the steps
+/// of the lifecycle of the packet which do not happen.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(parity_scale_codec::Encode, parity_scale_codec::Decode,)
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PacketFee {
+    pub fee: Fee,
+    pub refund_address: Signer,
+    /// The relayers allowed to relay the packet, any relayer being allowed if
+    /// empty.
+    pub relayers: Vec<String>,
+}
+
+impl PacketFee {
+    pub fn new(fee: Fee, refund_address: Signer) -> Self {
+        Self {
+            fee,
+            refund_address,
+            relayers: Vec::new(),
+        }
+    }
+}
+
+impl Protobuf<RawPacketFee> for PacketFee {}
+
+impl TryFrom<RawPacketFee> for PacketFee {
+    type Error = FeeError;
+
+    fn try_from(raw: RawPacketFee) -> Result<Self, Self::Error> {
+        if raw.refund_address.is_empty() {
+            return Err(FeeError::EmptyRefundAddress);
+        }
+
+        Ok(Self {
+            fee: raw.fee.ok_or(FeeError::MissingFee)?.try_into()?,
+            refund_address: raw.refund_address.into(),
+            relayers: raw.relayers,
+        })
+    }
+}
+
+impl From<PacketFee> for RawPacketFee {
+    fn from(packet_fee: PacketFee) -> Self {
+        Self {
+            fee: Some(packet_fee.fee.into()),
+            refund_address: packet_fee.refund_address.to_string(),
+            relayers: packet_fee.relayers,
+        }
+    }
+}
+
+/// The fees escrowed for a packet, which may have been incentivized several
+/// times.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(parity_scale_codec::Encode, parity_scale_codec::Decode,)
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct PacketFees {
+    pub packet_fees: Vec<PacketFee>,
+}
+
+impl PacketFees {
+    pub fn new(packet_fees: Vec<PacketFee>) -> Self {
+        Self { packet_fees }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.packet_fees.is_empty()
+    }
+}
+
+impl Protobuf<RawPacketFees> for PacketFees {}
+
+impl TryFrom<RawPacketFees> for PacketFees {
+    type Error = FeeError;
+
+    fn try_from(raw: RawPacketFees) -> Result<Self, Self::Error> {
+        Ok(Self {
+            packet_fees: raw
+                .packet_fees
+                .into_iter()
+                .map(PacketFee::try_from)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+impl From<PacketFees> for RawPacketFees {
+    fn from(packet_fees: PacketFees) -> Self {
+        Self {
+            packet_fees: packet_fees
+                .packet_fees
+                .into_iter()
+                .map(Into::into)
+                .collect(),
+        }
+    }
+}
+
+/// The fees escrowed for the packet identified by `packet_id`, as returned by
+/// the queries of incentivized packets.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(parity_scale_codec::Encode, parity_scale_codec::Decode,)
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct IdentifiedPacketFees {
+    pub packet_id: PacketId,
+    pub packet_fees: PacketFees,
+}
+
+impl IdentifiedPacketFees {
+    pub fn new(packet_id: PacketId, packet_fees: PacketFees) -> Self {
+        Self {
+            packet_id,
+            packet_fees,
+        }
+    }
+}
+
+impl Protobuf<RawIdentifiedPacketFees> for IdentifiedPacketFees {}
+
+impl TryFrom<RawIdentifiedPacketFees> for IdentifiedPacketFees {
+    type Error = FeeError;
+
+    fn try_from(raw: RawIdentifiedPacketFees) -> Result<Self, Self::Error> {
+        Ok(Self {
+            packet_id: raw.packet_id.ok_or(FeeError::MissingPacketId)?.try_into()?,
+            packet_fees: RawPacketFees {
+                packet_fees: raw.packet_fees,
+            }
+            .try_into()?,
+        })
+    }
+}
+
+impl From<IdentifiedPacketFees> for RawIdentifiedPacketFees {
+    fn from(identified: IdentifiedPacketFees) -> Self {
+        Self {
+            packet_id: Some(identified.packet_id.into()),
+            packet_fees: RawPacketFees::from(identified.packet_fees).packet_fees,
+        }
+    }
+}
+
+/// The relayers of an acknowledged packet, entitled to the fees escrowed for
+/// its relaying.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(parity_scale_codec::Encode, parity_scale_codec::Decode,)
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PacketRelayers {
+    /// The relayer of the packet to the counterparty chain, i.e. the
+    /// `relayer` of its `recv_packet` event there, as recorded by the
+    /// counterparty in the acknowledgement, if any.
+    pub forward_relayer: Option<Signer>,
+    /// The relayer of the acknowledgement of the packet, i.e. the `relayer` of
+    /// its `acknowledge_packet` event.
+    pub reverse_relayer: Signer,
+}
+
+impl PacketRelayers {
+    pub fn new(forward_relayer: Option<Signer>, reverse_relayer: Signer) -> Self {
+        Self {
+            forward_relayer,
+            reverse_relayer,
+        }
+    }
+}
+
+/// Coins to pay out of escrow to `recipient`, as part of the distribution of
+/// the fees escrowed for a packet.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(parity_scale_codec::Encode, parity_scale_codec::Decode,)
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FeePayout {
+    pub recipient: Signer,
+    pub coins: Vec<PrefixedCoin>,
+}
+
+impl FeePayout {
+    pub fn new(recipient: Signer, coins: Vec<PrefixedCoin>) -> Self {
+        Self { recipient, coins }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ibc_core::host::types::identifiers::{ChannelId, PortId, Sequence};
+    use rstest::rstest;
+
+    use super::*;
+
+    fn dummy_packet_fee(relayers: Vec<String>) -> PacketFee {
+        let coin = |s: &str| s.parse::<PrefixedCoin>().expect("valid coin");
+
+        PacketFee {
+            fee: Fee {
+                recv_fee: vec![coin("100stake")],
+                ack_fee: vec![coin("50stake"), coin("10transfer/channel-1/uatom")],
+                timeout_fee: vec![],
+            },
+            refund_address: Signer::from("cosmos1refund".to_string()),
+            relayers,
+        }
+    }
+
+    #[rstest]
+    #[case(vec![])]
+    #[case(vec!["cosmos1relayer".to_string()])]
+    fn identified_packet_fees_roundtrip(#[case] relayers: Vec<String>) {
+        let identified = IdentifiedPacketFees::new(
+            PacketId::new(PortId::transfer(), ChannelId::new(0), Sequence::from(1)),
+            PacketFees::new(vec![dummy_packet_fee(relayers), dummy_packet_fee(vec![])]),
+        );
+
+        let raw = RawIdentifiedPacketFees::from(identified.clone());
+
+        assert_eq!(
+            IdentifiedPacketFees::try_from(raw).expect("valid packet fees"),
+            identified
+        );
+    }
+
+    #[test]
+    fn packet_fee_rejects_invalid_raw() {
+        let raw = RawPacketFee::from(dummy_packet_fee(vec![]));
+
+        assert!(matches!(
+            PacketFee::try_from(RawPacketFee {
+                fee: None,
+                ..raw.clone()
+            }),
+            Err(FeeError::MissingFee)
+        ));
+        assert!(matches!(
+            PacketFee::try_from(RawPacketFee {
+                refund_address: String::new(),
+                ..raw
+            }),
+            Err(FeeError::EmptyRefundAddress)
+        ));
+    }
+}
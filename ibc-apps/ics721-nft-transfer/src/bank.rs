@@ -0,0 +1,68 @@
+//! Implements the NFT movements of ICS-721 on top of a [`BankContext`], for
+//! hosts implementing the movement of non-fungible tokens once for all the
+//! IBC applications.
+
+use core::fmt::{Display, Error as FmtError, Formatter};
+
+use ibc_core::host::types::identifiers::{ChannelId, PortId};
+use ibc_core::router::bank::BankContext;
+use ibc_core::router::types::module::ModuleAccount;
+
+use crate::types::error::NftTransferError;
+use crate::types::{PrefixedClassId, TokenId};
+
+/// A non-fungible token, as moved by a [`BankContext`].
+///
+/// The URI and data of minted NFTs are recorded by the NFT transfer context,
+/// along with those of their class.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct NftAsset {
+    pub class_id: PrefixedClassId,
+    pub token_id: TokenId,
+}
+
+impl NftAsset {
+    pub fn new(class_id: PrefixedClassId, token_id: TokenId) -> Self {
+        Self { class_id, token_id }
+    }
+}
+
+impl Display for NftAsset {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), FmtError> {
+        write!(f, "{}/{}", self.class_id, self.token_id)
+    }
+}
+
+/// Escrows `nft` from `from_account` into the escrow account of the channel
+/// it is sent over, as done by `escrow_nft_execute`.
+pub fn escrow_nft<Bank>(
+    bank: &mut Bank,
+    from_account: &Bank::AccountId,
+    port_id: &PortId,
+    channel_id: &ChannelId,
+    nft: &NftAsset,
+) -> Result<(), NftTransferError>
+where
+    Bank: BankContext<NftAsset>,
+{
+    let escrow_account = bank.module_account(&ModuleAccount::escrow(port_id, channel_id))?;
+
+    Ok(bank.transfer(from_account, &escrow_account, nft)?)
+}
+
+/// Unescrows `nft` from the escrow account of the channel it is received over
+/// into `to_account`, as done by `unescrow_nft_execute`.
+pub fn unescrow_nft<Bank>(
+    bank: &mut Bank,
+    to_account: &Bank::AccountId,
+    port_id: &PortId,
+    channel_id: &ChannelId,
+    nft: &NftAsset,
+) -> Result<(), NftTransferError>
+where
+    Bank: BankContext<NftAsset>,
+{
+    let escrow_account = bank.module_account(&ModuleAccount::escrow(port_id, channel_id))?;
+
+    Ok(bank.transfer(&escrow_account, to_account, nft)?)
+}
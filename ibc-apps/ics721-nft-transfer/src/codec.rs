@@ -0,0 +1,32 @@
+//! Implements the default [`PacketDataCodec`] of the NFT transfer packet data,
+//! as looked up with [`NftTransferValidationContext::packet_data_codec`].
+
+use ibc_core::primitives::prelude::*;
+use ibc_core::router::types::codec::PacketDataCodec;
+
+use crate::context::NftTransferValidationContext;
+use crate::types::packet::PacketData;
+
+/// Encodes the packet data into JSON, byte-for-byte as the Go implementation
+/// of ICS-721 does, which is the encoding used by default.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct JsonPacketDataCodec;
+
+impl PacketDataCodec<PacketData> for JsonPacketDataCodec {
+    fn encode(&self, data: &PacketData) -> Vec<u8> {
+        data.encode_json().into_bytes()
+    }
+
+    fn decode(&self, bytes: &[u8]) -> Option<PacketData> {
+        serde_json::from_slice(bytes).ok()
+    }
+}
+
+/// Returns the codec of the packet data of `ctx`, defaulting to
+/// [`JsonPacketDataCodec`].
+pub(crate) fn packet_data_codec<Ctx>(ctx: &Ctx) -> &dyn PacketDataCodec<PacketData>
+where
+    Ctx: NftTransferValidationContext,
+{
+    ctx.packet_data_codec().unwrap_or(&JsonPacketDataCodec)
+}
@@ -1,12 +1,15 @@
 //! Defines the required context traits for ICS-721 to interact with host
 //! machine.
+use ibc_core::channel::types::packet::Packet;
 use ibc_core::host::types::identifiers::{ChannelId, PortId};
 use ibc_core::primitives::prelude::*;
 use ibc_core::primitives::Signer;
+use ibc_core::router::types::codec::PacketDataCodec;
 
 use crate::types::error::NftTransferError;
+use crate::types::packet::PacketData;
 use crate::types::{
-    ClassData, ClassId, ClassUri, Memo, PrefixedClassId, TokenData, TokenId, TokenUri,
+    ClassData, ClassId, ClassUri, Data, Memo, PrefixedClassId, TokenData, TokenId, TokenUri,
 };
 
 pub trait NftContext {
@@ -34,6 +37,95 @@ pub trait NftClassContext {
     fn get_data(&self) -> Option<&ClassData>;
 }
 
+/// Bounds of a page of the tokens of a class, which are ordered by token ID.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct TokenPagination {
+    /// The token ID after which the page starts, or `None` to start from the
+    /// first token of the class.
+    pub start_after: Option<TokenId>,
+    /// The maximum number of tokens in the page, or `None` to let the host
+    /// choose it.
+    pub limit: Option<u64>,
+}
+
+/// Maps the receiver of an incoming packet into a local account, for hosts
+/// whose accounts cannot be parsed from the receiver string as is, e.g. using
+/// a different bech32 prefix than the sending chain, or routing the NFTs to a
+/// contract.
+pub trait ReceiverResolver {
+    type AccountId;
+
+    /// Returns the local account receiving the NFTs of the given `packet`,
+    /// sent to `receiver`.
+    fn resolve_receiver(
+        &self,
+        packet: &Packet,
+        receiver: &Signer,
+    ) -> Result<Self::AccountId, NftTransferError>;
+}
+
+/// Decides which NFTs may be transferred, for hosts holding classes or tokens
+/// which must stay with their owner, e.g. soulbound tokens.
+///
+/// The policy is checked when sending NFTs, and when receiving NFTs whose
+/// class originates from the counterparty, before minting vouchers for them.
+/// The packets carrying rejected NFTs are then acknowledged with an error, so
+/// that the NFTs are refunded on the sending chain.
+pub trait NftTransferPolicy {
+    /// Returns [`NftTransferError::NonTransferable`] if the token `token_id`
+    /// of the class `class_id`, with the given class and token data, must not
+    /// be transferred.
+    fn check_transferable(
+        &self,
+        class_id: &PrefixedClassId,
+        class_data: Option<&ClassData>,
+        token_id: &TokenId,
+        token_data: Option<&TokenData>,
+    ) -> Result<(), NftTransferError>;
+}
+
+/// An [`NftTransferPolicy`] rejecting the tokens whose class data or token
+/// data, in the ICS-721 format, sets `key` to `"true"`, e.g.
+/// `{"soulbound":{"value":"true"}}`.
+///
+/// Data which is not in the ICS-721 format does not flag any token.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NonTransferablePolicy {
+    pub key: String,
+}
+
+impl NonTransferablePolicy {
+    pub fn new(key: impl Into<String>) -> Self {
+        Self { key: key.into() }
+    }
+
+    fn is_flagged(&self, data: Option<&Data>) -> bool {
+        data.and_then(|data| data.parse_as_ics721_data().ok())
+            .and_then(|data| data.get(&self.key).map(|value| value.value() == "true"))
+            .unwrap_or(false)
+    }
+}
+
+impl NftTransferPolicy for NonTransferablePolicy {
+    fn check_transferable(
+        &self,
+        class_id: &PrefixedClassId,
+        class_data: Option<&ClassData>,
+        token_id: &TokenId,
+        token_data: Option<&TokenData>,
+    ) -> Result<(), NftTransferError> {
+        if self.is_flagged(class_data.map(AsRef::as_ref))
+            || self.is_flagged(token_data.map(AsRef::as_ref))
+        {
+            return Err(NftTransferError::NonTransferable {
+                class_id: class_id.clone(),
+                token_id: token_id.clone(),
+            });
+        }
+        Ok(())
+    }
+}
+
 /// Read-only methods required in NFT transfer validation context.
 pub trait NftTransferValidationContext {
     type AccountId: TryFrom<Signer> + PartialEq;
@@ -66,7 +158,7 @@ pub trait NftTransferValidationContext {
 
     /// Validates that the tokens can be escrowed successfully.
     ///
-    /// The owner of the NFT should be checked in this validation.
+    /// The sender is checked to own the NFT with [`Self::owner_of`] beforehand.
     /// `memo` field allows to incorporate additional contextual details in the
     /// escrow validation.
     fn escrow_nft_validate(
@@ -108,7 +200,7 @@ pub trait NftTransferValidationContext {
 
     /// Validates the sender account and the coin input before burning.
     ///
-    /// The owner of the NFT should be checked in this validation.
+    /// The sender is checked to own the NFT with [`Self::owner_of`] beforehand.
     /// `memo` field allows to incorporate additional contextual details in the
     /// burn validation.
     fn burn_nft_validate(
@@ -119,6 +211,20 @@ pub trait NftTransferValidationContext {
         memo: &Memo,
     ) -> Result<(), NftTransferError>;
 
+    /// Returns a hash of the prefixed class ID, with which the host chain
+    /// refers to the class as `ibc/{hash}`.
+    /// Implement only if the host chain supports hashed class IDs.
+    fn class_hash_string(&self, _class_id: &PrefixedClassId) -> Option<String> {
+        None
+    }
+
+    /// Returns the prefixed class ID whose hash is `hash`, as stored on the
+    /// first receipt of the class, if any.
+    /// Implement only if the host chain supports hashed class IDs.
+    fn get_class_trace(&self, _hash: &str) -> Result<Option<PrefixedClassId>, NftTransferError> {
+        Ok(None)
+    }
+
     /// Returns a hash of the prefixed class ID and the token ID.
     /// Implement only if the host chain supports hashed class ID and token ID.
     fn token_hash_string(
@@ -139,6 +245,47 @@ pub trait NftTransferValidationContext {
     /// Returns the NFT class
     fn get_nft_class(&self, class_id: &PrefixedClassId)
         -> Result<Self::NftClass, NftTransferError>;
+
+    /// Returns the current owner of the NFT, which is the escrow account of
+    /// its channel while it is escrowed.
+    ///
+    /// Returns [`NftTransferError::NftNotFound`] if the NFT does not exist,
+    /// e.g. after it was burned.
+    fn owner_of(
+        &self,
+        class_id: &PrefixedClassId,
+        token_id: &TokenId,
+    ) -> Result<Self::AccountId, NftTransferError>;
+
+    /// Returns the IDs of the existing tokens of the class within the bounds
+    /// of `pagination`, in ascending order, escrowed tokens included.
+    fn tokens_of_class(
+        &self,
+        class_id: &PrefixedClassId,
+        pagination: &TokenPagination,
+    ) -> Result<Vec<TokenId>, NftTransferError>;
+
+    /// Returns the resolver of the receivers of incoming packets.
+    /// Implement only if the receivers cannot be parsed into accounts with
+    /// `TryFrom<Signer>`, which is used by default.
+    fn receiver_resolver(&self) -> Option<&dyn ReceiverResolver<AccountId = Self::AccountId>> {
+        None
+    }
+
+    /// Returns the policy deciding which NFTs may be transferred.
+    /// Implement only if some NFTs must not be transferred, all of them being
+    /// transferable by default.
+    fn transfer_policy(&self) -> Option<&dyn NftTransferPolicy> {
+        None
+    }
+
+    /// Returns the codec of the data of the packets sent and received.
+    /// Implement only if the host does not encode the packet data into JSON,
+    /// as [`JsonPacketDataCodec`](crate::codec::JsonPacketDataCodec) does by
+    /// default.
+    fn packet_data_codec(&self) -> Option<&dyn PacketDataCodec<PacketData>> {
+        None
+    }
 }
 
 /// Read-write methods required in NFT transfer execution context.
@@ -175,6 +322,17 @@ pub trait NftTransferExecutionContext: NftTransferValidationContext {
         token_id: &TokenId,
     ) -> Result<(), NftTransferError>;
 
+    /// Stores the mapping from `hash` to the prefixed class ID it is computed
+    /// from, on the first receipt of the class.
+    /// Implement only if the host chain supports hashed class IDs.
+    fn store_class_trace_execute(
+        &mut self,
+        _class_id: &PrefixedClassId,
+        _hash: &str,
+    ) -> Result<(), NftTransferError> {
+        Ok(())
+    }
+
     /// Executes minting of the NFT in a user account.
     fn mint_nft_execute(
         &mut self,
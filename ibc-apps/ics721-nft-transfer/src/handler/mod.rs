@@ -4,13 +4,38 @@ mod on_recv_packet;
 mod send_transfer;
 
 use ibc_core::channel::types::packet::Packet;
+use ibc_core::primitives::prelude::*;
 pub use on_recv_packet::*;
 pub use send_transfer::*;
 
 use crate::context::{NftTransferExecutionContext, NftTransferValidationContext};
 use crate::types::error::NftTransferError;
-use crate::types::is_sender_chain_source;
 use crate::types::packet::PacketData;
+use crate::types::{is_sender_chain_source, PrefixedClassId};
+
+/// The prefix of hashed class IDs, followed by the hash of the class trace.
+pub const HASHED_CLASS_ID_PREFIX: &str = "ibc/";
+
+/// Resolves a class ID given either as a prefixed class ID, or as a hashed
+/// class ID of the form `ibc/{hash}`, into the prefixed class ID.
+///
+/// Hashed class IDs are resolved with the class traces stored on the first
+/// receipt of each class, which allows e.g. indexers to resolve the class IDs
+/// used by the host chain.
+pub fn resolve_class_id(
+    ctx: &impl NftTransferValidationContext,
+    class_id: &str,
+) -> Result<PrefixedClassId, NftTransferError> {
+    match class_id.strip_prefix(HASHED_CLASS_ID_PREFIX) {
+        Some(hash) => {
+            ctx.get_class_trace(hash)?
+                .ok_or_else(|| NftTransferError::ClassTraceNotFound {
+                    hash: hash.to_string(),
+                })
+        }
+        None => class_id.parse(),
+    }
+}
 
 pub fn refund_packet_nft_execute(
     ctx_a: &mut impl NftTransferExecutionContext,
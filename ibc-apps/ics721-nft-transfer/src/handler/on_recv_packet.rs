@@ -4,7 +4,7 @@ use ibc_core::router::types::module::ModuleExtras;
 
 use crate::context::NftTransferExecutionContext;
 use crate::types::error::NftTransferError;
-use crate::types::events::TokenTraceEvent;
+use crate::types::events::{ClassTraceEvent, TokenTraceEvent};
 use crate::types::packet::PacketData;
 use crate::types::{is_receiver_chain_source, TracePrefix};
 
@@ -25,11 +25,15 @@ where
         .can_receive_nft()
         .map_err(|err| (ModuleExtras::empty(), err))?;
 
-    let receiver_account = data
-        .receiver
-        .clone()
-        .try_into()
-        .map_err(|_| (ModuleExtras::empty(), NftTransferError::ParseAccountFailure))?;
+    let receiver_account = match ctx_b.receiver_resolver() {
+        Some(resolver) => resolver.resolve_receiver(packet, &data.receiver),
+        None => data
+            .receiver
+            .clone()
+            .try_into()
+            .map_err(|_| NftTransferError::ParseAccountFailure),
+    }
+    .map_err(|err| (ModuleExtras::empty(), err))?;
 
     let extras = if is_receiver_chain_source(
         packet.port_id_on_a.clone(),
@@ -77,8 +81,14 @@ where
             c
         };
 
+        let trace_hash = ctx_b.class_hash_string(&class_id);
+
         let mut extras = ModuleExtras {
-            events: vec![],
+            events: vec![ClassTraceEvent {
+                trace_hash: trace_hash.clone(),
+                class: class_id.clone(),
+            }
+            .into()],
             log: Vec::new(),
         };
         for (i, token_id) in data.token_ids.0.iter().enumerate() {
@@ -92,6 +102,12 @@ where
             };
             extras.events.push(trace_event.into());
 
+            if let Some(policy) = ctx_b.transfer_policy() {
+                policy
+                    .check_transferable(&class_id, data.class_data.as_ref(), token_id, token_data)
+                    .map_err(|nft_error| (ModuleExtras::empty(), nft_error))?;
+            }
+
             // Note: the validation is called before the execution.
             // Refer to ICS-20 `process_recv_packet_execute()`.
 
@@ -130,6 +146,19 @@ where
                 .map_err(|nft_error| (extras.clone(), nft_error))?;
         }
 
+        // Store the class trace on the first receipt of the class, so that
+        // its hashed class ID can be resolved.
+        if let Some(hash) = trace_hash {
+            let stored = ctx_b
+                .get_class_trace(&hash)
+                .map_err(|nft_error| (extras.clone(), nft_error))?;
+            if stored.is_none() {
+                ctx_b
+                    .store_class_trace_execute(&class_id, &hash)
+                    .map_err(|nft_error| (extras.clone(), nft_error))?;
+            }
+        }
+
         extras
     };
 
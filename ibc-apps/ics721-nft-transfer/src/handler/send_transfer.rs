@@ -6,6 +6,7 @@ use ibc_core::host::types::path::{ChannelEndPath, SeqSendPath};
 use ibc_core::primitives::prelude::*;
 use ibc_core::router::types::event::ModuleEvent;
 
+use crate::codec::packet_data_codec;
 use crate::context::{
     NftClassContext, NftContext, NftTransferExecutionContext, NftTransferValidationContext,
 };
@@ -14,7 +15,14 @@ use crate::types::events::TransferEvent;
 use crate::types::msgs::transfer::MsgTransfer;
 use crate::types::{is_sender_chain_source, MODULE_ID_STR};
 
-/// Initiate a token transfer. Equivalent to calling [`send_nft_transfer_validate`], followed by [`send_nft_transfer_execute`].
+/// Initiate an NFT transfer. Equivalent to calling [`send_nft_transfer_validate`], followed by [`send_nft_transfer_execute`].
+///
+/// The NFTs are escrowed if their class originates from the host, and burned
+/// if it was received over the channel they are sent on, i.e. if its class
+/// trace starts with the port and channel of the message. The metadata of the
+/// class and of the tokens held by the host is then added to the packet data,
+/// overwriting the one set in the message, before the packet is sent through
+/// the core [`send_packet_execute`] handler.
 pub fn send_nft_transfer<SendPacketCtx, TransferCtx>(
     send_packet_ctx_a: &mut SendPacketCtx,
     transfer_ctx: &mut TransferCtx,
@@ -28,7 +36,11 @@ where
     send_nft_transfer_execute(send_packet_ctx_a, transfer_ctx, msg)
 }
 
-/// Validates the NFT transfer
+/// Validates the NFT transfer, along with the packet to be sent.
+///
+/// The sender must own each of the NFTs, as returned by
+/// [`NftTransferValidationContext::owner_of`], and each of them must be
+/// accepted by the [`NftTransferValidationContext::transfer_policy`], if any.
 pub fn send_nft_transfer_validate<SendPacketCtx, TransferCtx>(
     send_packet_ctx_a: &SendPacketCtx,
     transfer_ctx: &TransferCtx,
@@ -73,7 +85,19 @@ where
     if let Some(data) = &mut packet_data.token_data {
         data.clear();
     }
+    let nft_class = transfer_ctx.get_nft_class(class_id)?;
     for token_id in token_ids.as_ref() {
+        if transfer_ctx.owner_of(class_id, token_id)? != sender {
+            return Err(NftTransferError::InvalidOwner {
+                sender: packet_data.sender.to_string(),
+            });
+        }
+
+        let nft = transfer_ctx.get_nft(class_id, token_id)?;
+        if let Some(policy) = transfer_ctx.transfer_policy() {
+            policy.check_transferable(class_id, nft_class.get_data(), token_id, nft.get_data())?;
+        }
+
         if is_sender_chain_source(msg.port_id_on_a.clone(), msg.chan_id_on_a.clone(), class_id) {
             transfer_ctx.escrow_nft_validate(
                 &sender,
@@ -91,7 +115,6 @@ where
                 &packet_data.memo.clone().unwrap_or("".into()),
             )?;
         }
-        let nft = transfer_ctx.get_nft(class_id, token_id)?;
         // Set the URI and the data if both exists
         if let (Some(uri), Some(data)) = (nft.get_uri(), nft.get_data()) {
             match &mut packet_data.token_uris {
@@ -107,13 +130,11 @@ where
 
     packet_data.validate_basic()?;
 
-    let nft_class = transfer_ctx.get_nft_class(class_id)?;
     packet_data.class_uri = nft_class.get_uri().cloned();
     packet_data.class_data = nft_class.get_data().cloned();
 
     let packet = {
-        let data = serde_json::to_vec(&packet_data)
-            .expect("PacketData's infallible Serialize impl failed");
+        let data = packet_data_codec(transfer_ctx).encode(&packet_data);
 
         Packet {
             seq_on_a: sequence,
@@ -132,7 +153,7 @@ where
     Ok(())
 }
 
-/// Executes the token transfer. A prior call to [`send_nft_transfer_validate`] MUST have succeeded.
+/// Executes the NFT transfer. A prior call to [`send_nft_transfer_validate`] MUST have succeeded.
 pub fn send_nft_transfer_execute<SendPacketCtx, TransferCtx>(
     send_packet_ctx_a: &mut SendPacketCtx,
     transfer_ctx: &mut TransferCtx,
@@ -213,9 +234,7 @@ where
     packet_data.class_data = nft_class.get_data().cloned();
 
     let packet = {
-        let data = {
-            serde_json::to_vec(&packet_data).expect("PacketData's infallible Serialize impl failed")
-        };
+        let data = packet_data_codec(&*transfer_ctx).encode(&packet_data);
 
         Packet {
             seq_on_a: sequence,
@@ -17,6 +17,10 @@
 #[cfg(any(test, feature = "std"))]
 extern crate std;
 
+#[cfg(feature = "serde")]
+pub mod bank;
+#[cfg(feature = "serde")]
+pub mod codec;
 #[cfg(feature = "serde")]
 pub mod context;
 #[cfg(feature = "serde")]
@@ -9,13 +9,13 @@ use ibc_core::primitives::prelude::*;
 use ibc_core::primitives::Signer;
 use ibc_core::router::types::module::ModuleExtras;
 
+use crate::codec::packet_data_codec;
 use crate::context::{NftTransferExecutionContext, NftTransferValidationContext};
 use crate::handler::{
     process_recv_packet_execute, refund_packet_nft_execute, refund_packet_nft_validate,
 };
 use crate::types::error::NftTransferError;
 use crate::types::events::{AckEvent, AckStatusEvent, RecvEvent, TimeoutEvent};
-use crate::types::packet::PacketData;
 use crate::types::{ack_success_b64, VERSION};
 
 pub fn on_chan_open_init_validate(
@@ -171,7 +171,7 @@ pub fn on_recv_packet_execute(
     ctx_b: &mut impl NftTransferExecutionContext,
     packet: &Packet,
 ) -> (ModuleExtras, Acknowledgement) {
-    let Ok(data) = serde_json::from_slice::<PacketData>(&packet.data) else {
+    let Some(data) = packet_data_codec(&*ctx_b).decode(&packet.data) else {
         let ack = AcknowledgementStatus::error(NftTransferError::PacketDataDeserialization.into());
         return (ModuleExtras::empty(), ack.into());
     };
@@ -203,10 +203,11 @@ pub fn on_acknowledgement_packet_validate(
     acknowledgement: &Acknowledgement,
     _relayer: &Signer,
 ) -> Result<(), NftTransferError> {
-    let data = serde_json::from_slice::<PacketData>(&packet.data)
-        .map_err(|_| NftTransferError::PacketDataDeserialization)?;
+    let data = packet_data_codec(ctx)
+        .decode(&packet.data)
+        .ok_or(NftTransferError::PacketDataDeserialization)?;
 
-    let acknowledgement = serde_json::from_slice::<AcknowledgementStatus>(acknowledgement.as_ref())
+    let acknowledgement = AcknowledgementStatus::try_from(acknowledgement)
         .map_err(|_| NftTransferError::AckDeserialization)?;
 
     if !acknowledgement.is_successful() {
@@ -222,16 +223,14 @@ pub fn on_acknowledgement_packet_execute(
     acknowledgement: &Acknowledgement,
     _relayer: &Signer,
 ) -> (ModuleExtras, Result<(), NftTransferError>) {
-    let Ok(data) = serde_json::from_slice::<PacketData>(&packet.data) else {
+    let Some(data) = packet_data_codec(&*ctx).decode(&packet.data) else {
         return (
             ModuleExtras::empty(),
             Err(NftTransferError::PacketDataDeserialization),
         );
     };
 
-    let Ok(acknowledgement) =
-        serde_json::from_slice::<AcknowledgementStatus>(acknowledgement.as_ref())
-    else {
+    let Ok(acknowledgement) = AcknowledgementStatus::try_from(acknowledgement) else {
         return (
             ModuleExtras::empty(),
             Err(NftTransferError::AckDeserialization),
@@ -266,8 +265,9 @@ pub fn on_timeout_packet_validate(
     packet: &Packet,
     _relayer: &Signer,
 ) -> Result<(), NftTransferError> {
-    let data = serde_json::from_slice::<PacketData>(&packet.data)
-        .map_err(|_| NftTransferError::PacketDataDeserialization)?;
+    let data = packet_data_codec(ctx)
+        .decode(&packet.data)
+        .ok_or(NftTransferError::PacketDataDeserialization)?;
 
     refund_packet_nft_validate(ctx, packet, &data)?;
 
@@ -279,7 +279,7 @@ pub fn on_timeout_packet_execute(
     packet: &Packet,
     _relayer: &Signer,
 ) -> (ModuleExtras, Result<(), NftTransferError>) {
-    let Ok(data) = serde_json::from_slice::<PacketData>(&packet.data) else {
+    let Some(data) = packet_data_codec(&*ctx).decode(&packet.data) else {
         return (
             ModuleExtras::empty(),
             Err(NftTransferError::PacketDataDeserialization),
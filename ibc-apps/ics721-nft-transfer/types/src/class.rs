@@ -124,16 +124,13 @@ impl FromStr for PrefixedClassId {
     /// The parsing logic is same as [`FromStr`] impl of
     /// [`PrefixedDenom`](ibc_app_transfer_types::PrefixedDenom) from ICS-20.
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        match TracePath::trim(s) {
-            (trace_path, Some(remaining_parts)) => Ok(Self {
-                trace_path,
-                base_class_id: ClassId::from_str(remaining_parts)?,
-            }),
-            (_, None) => Ok(Self {
-                trace_path: TracePath::empty(),
-                base_class_id: ClassId::from_str(s)?,
-            }),
-        }
+        let (trace_path, base_class_id) = TracePath::split_prefixed(s)
+            .ok_or_else(|| NftTransferError::MalformedTrace(s.to_string()))?;
+
+        Ok(Self {
+            trace_path,
+            base_class_id: ClassId::from_str(base_class_id.unwrap_or_default())?,
+        })
     }
 }
 
@@ -327,9 +324,9 @@ mod tests {
     #[case("transfer/myclass")]
     #[case("transfer/channel-0/myclass")]
     #[case("transfer/channel-0/transfer/channel-1/myclass")]
-    #[case("(transfer)/channel-0/myclass")]
     #[case("transfer/(channel-0)/myclass")]
     #[case("transfer/channel-0///")]
+    #[case("(transfer)/channel-0")]
     fn test_valid_prefixed_class_id(#[case] class_id: &str) {
         PrefixedClassId::from_str(class_id).expect("success");
     }
@@ -339,6 +336,10 @@ mod tests {
     #[case("  ")]
     #[case("transfer/channel-0/")]
     #[case("transfer/channel-0/  ")]
+    #[case("transfer/channel-0/transfer/channel-1")]
+    #[case("/channel-0/myclass")]
+    #[case("(transfer)/channel-0/myclass")]
+    #[case("transfer/channel-0//channel-1/myclass")]
     fn test_invalid_prefixed_class_id(#[case] class_id: &str) {
         PrefixedClassId::from_str(class_id).expect_err("failure");
     }
@@ -91,6 +91,13 @@ impl<'de> serde::Deserialize<'de> for Data {
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct Ics721Data(BTreeMap<String, DataValue>);
 
+impl Ics721Data {
+    /// Returns the value of the given key, if any.
+    pub fn get(&self, key: &str) -> Option<&DataValue> {
+        self.0.get(key)
+    }
+}
+
 #[cfg(feature = "serde")]
 impl FromStr for Ics721Data {
     type Err = NftTransferError;
@@ -106,6 +113,18 @@ pub struct DataValue {
     mime: Option<Mime>,
 }
 
+impl DataValue {
+    /// Returns the value, as a string whatever its MIME type.
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+
+    /// Returns the MIME type of the value, if set.
+    pub fn mime(&self) -> Option<&Mime> {
+        self.mime.as_ref()
+    }
+}
+
 #[cfg(feature = "serde")]
 impl serde::Serialize for DataValue {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
@@ -9,11 +9,17 @@ use ibc_core::handler::types::error::ContextError;
 use ibc_core::host::types::error::IdentifierError;
 use ibc_core::host::types::identifiers::{ChannelId, PortId};
 use ibc_core::primitives::prelude::*;
+use ibc_core::router::types::error::BankError;
+
+use crate::class::PrefixedClassId;
+use crate::token::TokenId;
 
 #[derive(Display, Debug)]
 pub enum NftTransferError {
     /// context error: `{0}`
     ContextError(ContextError),
+    /// bank error: `{0}`
+    Bank(BankError),
     /// invalid identifier: `{0}`
     InvalidIdentifier(IdentifierError),
     /// invalid URI: `{uri}`, validation error: `{validation_error}``
@@ -40,6 +46,8 @@ pub enum NftTransferError {
     },
     /// trace length must be even but got: `{len}`
     InvalidTraceLength { len: u64 },
+    /// malformed trace: `{0}`
+    MalformedTrace(String),
     /// no token ID
     NoTokenId,
     /// invalid token ID
@@ -67,6 +75,13 @@ pub enum NftTransferError {
     NftNotFound,
     /// nft class is not found
     NftClassNotFound,
+    /// nft `{token_id}` of class `{class_id}` is not transferable
+    NonTransferable {
+        class_id: PrefixedClassId,
+        token_id: TokenId,
+    },
+    /// no class trace found for hash `{hash}`
+    ClassTraceNotFound { hash: String },
     /// failed to deserialize packet data
     PacketDataDeserialization,
     /// failed to deserialize acknowledgement
@@ -97,6 +112,7 @@ impl std::error::Error for NftTransferError {
     fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
         match &self {
             Self::ContextError(e) => Some(e),
+            Self::Bank(e) => Some(e),
             Self::InvalidUri {
                 validation_error: e,
                 ..
@@ -127,6 +143,12 @@ impl From<ContextError> for NftTransferError {
     }
 }
 
+impl From<BankError> for NftTransferError {
+    fn from(err: BankError) -> Self {
+        Self::Bank(err)
+    }
+}
+
 impl From<IdentifierError> for NftTransferError {
     fn from(err: IdentifierError) -> NftTransferError {
         Self::InvalidIdentifier(err)
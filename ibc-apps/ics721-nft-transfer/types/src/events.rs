@@ -2,13 +2,14 @@
 use ibc_core::channel::types::acknowledgement::AcknowledgementStatus;
 use ibc_core::primitives::prelude::*;
 use ibc_core::primitives::Signer;
-use ibc_core::router::types::event::ModuleEvent;
+use ibc_core::router::types::event::{ModuleEvent, ModuleEventAttribute};
 
 use super::Memo;
 use crate::{PrefixedClassId, TokenId, TokenIds, MODULE_ID_STR};
 
 const EVENT_TYPE_PACKET: &str = "non_fungible_token_packet";
 const EVENT_TYPE_TIMEOUT: &str = "timeout";
+const EVENT_TYPE_CLASS_TRACE: &str = "class_trace";
 const EVENT_TYPE_TOKEN_TRACE: &str = "token_trace";
 const EVENT_TYPE_TRANSFER: &str = "ibc_nft_transfer";
 
@@ -18,6 +19,7 @@ pub enum Event {
     Ack(AckEvent),
     AckStatus(AckStatusEvent),
     Timeout(TimeoutEvent),
+    ClassTrace(ClassTraceEvent),
     TokenTrace(TokenTraceEvent),
     Transfer(TransferEvent),
 }
@@ -52,7 +54,7 @@ impl From<RecvEvent> for ModuleEvent {
                 ("class", class).into(),
                 ("tokens", tokens).into(),
                 ("memo", memo).into(),
-                ("success", success).into(),
+                ModuleEventAttribute::new("success", success),
             ],
         }
     }
@@ -143,6 +145,28 @@ impl From<TimeoutEvent> for ModuleEvent {
     }
 }
 
+/// Event emitted in the `onRecvPacket` module callback when vouchers of a
+/// class are minted, carrying the hash of its class trace if the host chain
+/// supports hashed class IDs
+pub struct ClassTraceEvent {
+    pub trace_hash: Option<String>,
+    pub class: PrefixedClassId,
+}
+
+impl From<ClassTraceEvent> for ModuleEvent {
+    fn from(ev: ClassTraceEvent) -> Self {
+        let ClassTraceEvent { trace_hash, class } = ev;
+        let mut ev = Self {
+            kind: EVENT_TYPE_CLASS_TRACE.to_string(),
+            attributes: vec![("class", class).into()],
+        };
+        if let Some(hash) = trace_hash {
+            ev.attributes.push(("trace_hash", hash).into());
+        }
+        ev
+    }
+}
+
 /// Event emitted in the `onRecvPacket` module callback when new tokens are minted
 pub struct TokenTraceEvent {
     pub trace_hash: Option<String>,
@@ -207,6 +231,7 @@ impl From<Event> for ModuleEvent {
             Event::Ack(ev) => ev.into(),
             Event::AckStatus(ev) => ev.into(),
             Event::Timeout(ev) => ev.into(),
+            Event::ClassTrace(ev) => ev.into(),
             Event::TokenTrace(ev) => ev.into(),
             Event::Transfer(ev) => ev.into(),
         }
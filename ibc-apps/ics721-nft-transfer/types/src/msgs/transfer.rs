@@ -5,7 +5,7 @@ use ibc_core::channel::types::timeout::TimeoutHeight;
 use ibc_core::handler::types::error::ContextError;
 use ibc_core::host::types::identifiers::{ChannelId, PortId};
 use ibc_core::primitives::prelude::*;
-use ibc_core::primitives::Timestamp;
+use ibc_core::primitives::{Msg, Signer, Timestamp};
 use ibc_proto::google::protobuf::Any;
 use ibc_proto::ibc::applications::nft_transfer::v1::MsgTransfer as RawMsgTransfer;
 use ibc_proto::Protobuf;
@@ -48,6 +48,12 @@ pub struct MsgTransfer {
     pub timeout_timestamp_on_b: Timestamp,
 }
 
+impl Msg for MsgTransfer {
+    fn signer(&self) -> &Signer {
+        &self.packet_data.sender
+    }
+}
+
 impl TryFrom<RawMsgTransfer> for MsgTransfer {
     type Error = NftTransferError;
 
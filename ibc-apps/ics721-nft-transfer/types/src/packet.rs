@@ -5,6 +5,7 @@ use base64::Engine;
 use ibc_core::primitives::prelude::*;
 #[cfg(feature = "serde")]
 use ibc_core::primitives::serializers;
+use ibc_core::primitives::utils::SortedJsonObject;
 use ibc_core::primitives::Signer;
 use ibc_proto::ibc::applications::nft_transfer::v1::NonFungibleTokenPacketData as RawPacketData;
 
@@ -109,6 +110,28 @@ impl PacketData {
         }
         Ok(())
     }
+
+    /// Encodes the packet data into the bytes carried by packets,
+    /// byte-for-byte as the Go implementation of ICS-721 does, so that the
+    /// commitments of the packets match across implementations.
+    ///
+    /// Unlike the `Serialize` implementation, the keys are sorted and the
+    /// fields which are not set are omitted rather than set to `null`.
+    pub fn encode_json(&self) -> String {
+        let raw_pkt_data = RawPacketData::from(self.clone());
+
+        SortedJsonObject::new()
+            .string("classData", &raw_pkt_data.class_data)
+            .string("classId", &raw_pkt_data.class_id)
+            .string("classUri", &raw_pkt_data.class_uri)
+            .string("memo", &raw_pkt_data.memo)
+            .string("receiver", &raw_pkt_data.receiver)
+            .string("sender", &raw_pkt_data.sender)
+            .strings("tokenData", &raw_pkt_data.token_data)
+            .strings("tokenIds", &raw_pkt_data.token_ids)
+            .strings("tokenUris", &raw_pkt_data.token_uris)
+            .encode()
+    }
 }
 
 impl TryFrom<RawPacketData> for PacketData {
@@ -27,3 +27,12 @@ pub mod nft_transfer {
     #[cfg(feature = "nft-transfer")]
     pub use ibc_app_nft_transfer::*;
 }
+
+/// Re-exports the implementation of the IBC [Fee
+/// Middleware](https://github.com/cosmos/ibc/blob/main/spec/app/ics-029-fee-payment/README.md)
+/// (ICS-29) application logic.
+pub mod fee {
+    #[doc(inline)]
+    #[cfg(feature = "fee")]
+    pub use ibc_app_fee::*;
+}
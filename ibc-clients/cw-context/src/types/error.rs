@@ -23,6 +23,19 @@ pub enum ContractError {
     ProtoDecode(DecodeError),
 }
 
+#[cfg(feature = "std")]
+impl std::error::Error for ContractError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match &self {
+            Self::Context(e) => Some(e),
+            Self::Commitment(e) => Some(e),
+            Self::Identifier(e) => Some(e),
+            Self::Path(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
 impl From<ContractError> for StdError {
     fn from(err: ContractError) -> StdError {
         StdError::generic_err(err.to_string())
@@ -35,6 +35,10 @@ impl ClientStateCommon for ClientState {
         validate_proof_height(self.inner(), proof_height)
     }
 
+    fn validate(&self) -> Result<(), ClientError> {
+        Ok(self.inner().validate()?)
+    }
+
     fn verify_upgrade_client(
         &self,
         upgraded_client_state: Any,
@@ -166,6 +170,11 @@ pub fn verify_upgrade_client<H: HostFunctionsProvider>(
         })?
     }
 
+    // Make sure the client state the client is upgraded to, made of the
+    // chain-chosen parameters of the upgraded client state and of the
+    // client-chosen parameters of the current client, is valid
+    client_state.upgraded(upgraded_tm_client_state.0)?;
+
     // Check to see if the upgrade path is set
     let mut upgrade_path = client_state.upgrade_path.clone();
 
@@ -216,7 +225,8 @@ pub fn verify_membership<H: HostFunctionsProvider>(
     path: Path,
     value: Vec<u8>,
 ) -> Result<(), ClientError> {
-    let merkle_path = apply_prefix(prefix, vec![path.to_string()]);
+    let merkle_path = apply_prefix(prefix, vec![path.to_string()])
+        .map_err(ClientError::InvalidCommitmentPrefix)?;
     let merkle_proof = MerkleProof::try_from(proof).map_err(ClientError::InvalidCommitmentProof)?;
 
     merkle_proof
@@ -236,7 +246,8 @@ pub fn verify_non_membership<H: HostFunctionsProvider>(
     root: &CommitmentRoot,
     path: Path,
 ) -> Result<(), ClientError> {
-    let merkle_path = apply_prefix(prefix, vec![path.to_string()]);
+    let merkle_path = apply_prefix(prefix, vec![path.to_string()])
+        .map_err(ClientError::InvalidCommitmentPrefix)?;
     let merkle_proof = MerkleProof::try_from(proof).map_err(ClientError::InvalidCommitmentProof)?;
 
     merkle_proof
@@ -241,26 +241,14 @@ where
     E::ClientStateRef: From<ClientStateType>,
     ConsensusStateType: Convertible<E::ConsensusStateRef>,
 {
-    let mut upgraded_tm_client_state = ClientState::try_from(upgraded_client_state)?;
+    let upgraded_tm_client_state = ClientState::try_from(upgraded_client_state)?;
     let upgraded_tm_cons_state: ConsensusStateType = upgraded_consensus_state.try_into()?;
 
-    upgraded_tm_client_state.0.zero_custom_fields();
-
     // Construct new client state and consensus state relayer chosen client
     // parameters are ignored. All chain-chosen parameters come from
     // committed client, all client-chosen parameters come from current
     // client.
-    let new_client_state = ClientStateType::new(
-        upgraded_tm_client_state.0.chain_id,
-        client_state.trust_level,
-        client_state.trusting_period,
-        upgraded_tm_client_state.0.unbonding_period,
-        client_state.max_clock_drift,
-        upgraded_tm_client_state.0.latest_height,
-        upgraded_tm_client_state.0.proof_specs,
-        upgraded_tm_client_state.0.upgrade_path,
-        client_state.allow_update,
-    )?;
+    let new_client_state = client_state.upgraded(upgraded_tm_client_state.0)?;
 
     // The new consensus state is merely used as a trusted kernel against
     // which headers on the new chain can be verified. The root is just a
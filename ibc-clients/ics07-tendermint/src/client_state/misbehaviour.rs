@@ -1,13 +1,17 @@
+use core::cmp::max;
+use core::time::Duration;
+
 use ibc_client_tendermint_types::error::{Error, IntoResult};
 use ibc_client_tendermint_types::{
     ConsensusState as ConsensusStateType, Header as TmHeader, Misbehaviour as TmMisbehaviour,
 };
-use ibc_core_client::context::{Convertible, ExtClientValidationContext};
+use ibc_core_client::context::{Convertible, EvidenceAgeLimits, ExtClientValidationContext};
 use ibc_core_client::types::error::ClientError;
+use ibc_core_client::types::Height;
 use ibc_core_host::types::identifiers::{ChainId, ClientId};
 use ibc_core_host::types::path::ClientConsensusStatePath;
 use ibc_primitives::prelude::*;
-use ibc_primitives::Timestamp;
+use ibc_primitives::{Timestamp, ZERO_DURATION};
 use tendermint::crypto::Sha256;
 use tendermint::merkle::MerkleHash;
 use tendermint::{Hash, Time};
@@ -16,6 +20,47 @@ use tendermint_light_client_verifier::Verifier;
 
 use crate::types::Header;
 
+/// Checks that the given misbehaviour evidence is not expired under `limits`.
+///
+/// The age of the evidence is measured from its most recent header, both in
+/// blocks, up to the latest height of the client, and in time, up to the host
+/// timestamp.
+pub fn check_misbehaviour_age(
+    misbehaviour: &TmMisbehaviour,
+    client_latest_height: Height,
+    host_timestamp: Timestamp,
+    limits: &EvidenceAgeLimits,
+) -> Result<(), ClientError> {
+    let header_1 = misbehaviour.header1();
+    let header_2 = misbehaviour.header2();
+
+    let evidence_height = max(header_1.height(), header_2.height());
+    let evidence_timestamp: Timestamp = max(
+        header_1.signed_header.header.time,
+        header_2.signed_header.header.time,
+    )
+    .into();
+
+    let age_num_blocks = client_latest_height
+        .revision_height()
+        .saturating_sub(evidence_height.revision_height());
+    let age_duration = host_timestamp
+        .duration_since(&evidence_timestamp)
+        .unwrap_or(ZERO_DURATION);
+
+    if age_num_blocks > limits.max_age_num_blocks && age_duration > limits.max_age_duration {
+        return Err(Error::MisbehaviourEvidenceExpired {
+            age_num_blocks,
+            age_duration,
+            max_age_num_blocks: limits.max_age_num_blocks,
+            max_age_duration: limits.max_age_duration,
+        }
+        .into());
+    }
+
+    Ok(())
+}
+
 /// Determines whether or not two conflicting headers at the same height would
 /// have convinced the light client.
 pub fn verify_misbehaviour<V, H>(
@@ -96,22 +141,11 @@ where
     header.check_trusted_next_validator_set::<H>(&trusted_next_validator_hash)?;
 
     // ensure trusted consensus state is within trusting period
-    {
-        let duration_since_consensus_state = current_timestamp
-            .duration_since(&trusted_timestamp.into())
-            .ok_or_else(|| ClientError::InvalidConsensusStateTimestamp {
-                time1: trusted_timestamp.into(),
-                time2: current_timestamp,
-            })?;
-
-        if duration_since_consensus_state >= options.trusting_period {
-            return Err(Error::ConsensusStateTimestampGteTrustingPeriod {
-                duration_since_consensus_state,
-                trusting_period: options.trusting_period,
-            }
-            .into());
-        }
-    }
+    check_misbehaviour_trusted_state_within_trusting_period(
+        trusted_timestamp.into(),
+        current_timestamp,
+        options.trusting_period,
+    )?;
 
     // main header verification, delegated to the tendermint-light-client crate.
     let untrusted_state = header.as_untrusted_block_state();
@@ -140,6 +174,35 @@ where
     Ok(())
 }
 
+/// Checks that a trusted consensus state with the given `trusted_timestamp`,
+/// from which a misbehaviour header is verified, is not expired.
+///
+/// Unlike [`check_trusted_state_within_trusting_period`](super::check_trusted_state_within_trusting_period)
+/// upon client updates, a trusted timestamp ahead of the host's clock is
+/// rejected, as evidence must not rely on consensus states from the future.
+fn check_misbehaviour_trusted_state_within_trusting_period(
+    trusted_timestamp: Timestamp,
+    current_timestamp: Timestamp,
+    trusting_period: Duration,
+) -> Result<(), ClientError> {
+    let duration_since_consensus_state = current_timestamp
+        .duration_since(&trusted_timestamp)
+        .ok_or_else(|| ClientError::InvalidConsensusStateTimestamp {
+            time1: trusted_timestamp,
+            time2: current_timestamp,
+        })?;
+
+    if duration_since_consensus_state >= trusting_period {
+        return Err(Error::ConsensusStateTimestampGteTrustingPeriod {
+            duration_since_consensus_state,
+            trusting_period,
+        }
+        .into());
+    }
+
+    Ok(())
+}
+
 pub fn check_for_misbehaviour_on_misbehavior(
     header_1: &Header,
     header_2: &Header,
@@ -159,3 +222,51 @@ pub fn check_for_misbehaviour_on_misbehavior(
         Ok(header_1.signed_header.header.time <= header_2.signed_header.header.time)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TRUSTING_PERIOD: Duration = Duration::from_secs(64000);
+
+    fn timestamp_from_secs(secs: u64) -> Timestamp {
+        Timestamp::from_nanoseconds(secs * 1_000_000_000).expect("valid timestamp")
+    }
+
+    #[test]
+    fn misbehaviour_trusted_state_within_trusting_period() {
+        let trusted = timestamp_from_secs(1_000_000);
+        let host = timestamp_from_secs(1_000_000 + 600);
+
+        assert!(check_misbehaviour_trusted_state_within_trusting_period(
+            trusted,
+            host,
+            TRUSTING_PERIOD
+        )
+        .is_ok());
+    }
+
+    #[test]
+    fn misbehaviour_trusted_state_ahead_of_host() {
+        let host = timestamp_from_secs(1_000_000);
+        let trusted = timestamp_from_secs(1_000_000 + 3);
+
+        assert!(matches!(
+            check_misbehaviour_trusted_state_within_trusting_period(trusted, host, TRUSTING_PERIOD),
+            Err(ClientError::InvalidConsensusStateTimestamp { .. })
+        ));
+    }
+
+    #[test]
+    fn misbehaviour_trusted_state_expired() {
+        let trusted = timestamp_from_secs(1_000_000);
+        let host = timestamp_from_secs(1_000_000 + TRUSTING_PERIOD.as_secs());
+
+        assert!(check_misbehaviour_trusted_state_within_trusting_period(
+            trusted,
+            host,
+            TRUSTING_PERIOD
+        )
+        .is_err());
+    }
+}
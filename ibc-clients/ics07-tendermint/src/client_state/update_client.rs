@@ -1,11 +1,16 @@
+use core::time::Duration;
+
 use ibc_client_tendermint_types::error::{Error, IntoResult};
-use ibc_client_tendermint_types::{ConsensusState as ConsensusStateType, Header as TmHeader};
+use ibc_client_tendermint_types::{
+    ClientState as ClientStateType, ConsensusState as ConsensusStateType, Header as TmHeader,
+};
 use ibc_core_client::context::{Convertible, ExtClientValidationContext};
 use ibc_core_client::types::error::ClientError;
 use ibc_core_client::types::Height;
 use ibc_core_host::types::identifiers::{ChainId, ClientId};
 use ibc_core_host::types::path::ClientConsensusStatePath;
 use ibc_primitives::prelude::*;
+use ibc_primitives::{Expiry, Timestamp};
 use tendermint::crypto::Sha256;
 use tendermint::merkle::MerkleHash;
 use tendermint_light_client_verifier::options::Options;
@@ -33,72 +38,227 @@ where
     // to have the same revision number. We ensure this here.
     header.verify_chain_id_version_matches_height(chain_id)?;
 
+    let trusted_client_cons_state_path = ClientConsensusStatePath::new(
+        client_id.clone(),
+        header.trusted_height.revision_number(),
+        header.trusted_height.revision_height(),
+    );
+    let trusted_consensus_state: ConsensusStateType = ctx
+        .consensus_state(&trusted_client_cons_state_path)?
+        .try_into()
+        .map_err(Into::into)?;
+
+    verify_header_against_trusted_state::<H>(
+        header,
+        chain_id,
+        options,
+        &trusted_consensus_state,
+        ctx.host_timestamp()?,
+        verifier,
+    )
+}
+
+/// Verifies the header against the consensus state at its trusted height,
+/// provided by the caller rather than read from a context, at the given host
+/// timestamp.
+///
+/// The header is expected to be checked with [`TmHeader::validate_basic`] and
+/// against the revision number of the chain beforehand, as [`verify_header`]
+/// does.
+pub fn verify_header_against_trusted_state<H>(
+    header: &TmHeader,
+    chain_id: &ChainId,
+    options: &Options,
+    trusted_consensus_state: &ConsensusStateType,
+    host_timestamp: Timestamp,
+    verifier: &impl Verifier,
+) -> Result<(), ClientError>
+where
+    H: MerkleHash + Sha256 + Default,
+{
+    header.check_trusted_next_validator_set::<H>(&trusted_consensus_state.next_validators_hash)?;
+
     // Delegate to tendermint-light-client, which contains the required checks
     // of the new header against the trusted consensus state.
-    {
-        let trusted_state = {
-            let trusted_client_cons_state_path = ClientConsensusStatePath::new(
-                client_id.clone(),
-                header.trusted_height.revision_number(),
-                header.trusted_height.revision_height(),
-            );
-            let trusted_consensus_state: ConsensusStateType = ctx
-                .consensus_state(&trusted_client_cons_state_path)?
-                .try_into()
-                .map_err(Into::into)?;
-
-            header.check_trusted_next_validator_set::<H>(
-                &trusted_consensus_state.next_validators_hash,
-            )?;
-
-            TrustedBlockState {
-                chain_id: &chain_id
-                    .as_str()
-                    .try_into()
-                    .map_err(|e| ClientError::Other {
-                        description: format!("failed to parse chain id: {}", e),
-                    })?,
-                header_time: trusted_consensus_state.timestamp(),
-                height: header
-                    .trusted_height
-                    .revision_height()
-                    .try_into()
-                    .map_err(|_| ClientError::ClientSpecific {
-                        description: Error::InvalidHeaderHeight {
-                            height: header.trusted_height.revision_height(),
-                        }
-                        .to_string(),
-                    })?,
-                next_validators: &header.trusted_next_validator_set,
-                next_validators_hash: trusted_consensus_state.next_validators_hash,
-            }
-        };
-
-        let untrusted_state = UntrustedBlockState {
-            signed_header: &header.signed_header,
-            validators: &header.validator_set,
-            // NB: This will skip the
-            // VerificationPredicates::next_validators_match check for the
-            // untrusted state.
-            next_validators: None,
-        };
-
-        let now =
-            ctx.host_timestamp()?
-                .into_tm_time()
-                .ok_or_else(|| ClientError::ClientSpecific {
-                    description: "host timestamp is not a valid TM timestamp".to_string(),
-                })?;
-
-        // main header verification, delegated to the tendermint-light-client crate.
-        verifier
-            .verify_update_header(untrusted_state, trusted_state, options, now)
-            .into_result()?;
+    let trusted_state = TrustedBlockState {
+        chain_id: &chain_id
+            .as_str()
+            .try_into()
+            .map_err(|e| ClientError::Other {
+                description: format!("failed to parse chain id: {}", e),
+            })?,
+        header_time: trusted_consensus_state.timestamp(),
+        height: header
+            .trusted_height
+            .revision_height()
+            .try_into()
+            .map_err(|_| ClientError::ClientSpecific {
+                description: Error::InvalidHeaderHeight {
+                    height: header.trusted_height.revision_height(),
+                }
+                .to_string(),
+            })?,
+        next_validators: &header.trusted_next_validator_set,
+        next_validators_hash: trusted_consensus_state.next_validators_hash,
+    };
+
+    let untrusted_state = UntrustedBlockState {
+        signed_header: &header.signed_header,
+        validators: &header.validator_set,
+        // NB: This will skip the
+        // VerificationPredicates::next_validators_match check for the
+        // untrusted state.
+        next_validators: None,
+    };
+
+    // Perform the time-related checks upfront, so that a header from the
+    // future and an expired trusted state are reported with distinct
+    // errors. The light client verifier performs the same checks again.
+    check_header_not_from_future(header, host_timestamp, options.clock_drift)?;
+    check_trusted_state_within_trusting_period(
+        trusted_state.header_time.into(),
+        host_timestamp,
+        options.trusting_period,
+    )?;
+
+    let now = host_timestamp
+        .into_tm_time()
+        .ok_or_else(|| ClientError::ClientSpecific {
+            description: "host timestamp is not a valid TM timestamp".to_string(),
+        })?;
+
+    // main header verification, delegated to the tendermint-light-client crate.
+    verifier
+        .verify_update_header(untrusted_state, trusted_state, options, now)
+        .into_result()?;
+
+    Ok(())
+}
+
+/// Checks that the header is not from the future, i.e. that its timestamp does
+/// not exceed the host's current timestamp by more than `max_clock_drift`.
+///
+/// The `max_clock_drift` tolerance is configured per client through the
+/// client state, and accounts for the clocks of the host and the counterparty
+/// chain not being perfectly in sync.
+pub fn check_header_not_from_future(
+    header: &TmHeader,
+    host_timestamp: Timestamp,
+    max_clock_drift: Duration,
+) -> Result<(), ClientError> {
+    let max_allowed_timestamp = (host_timestamp + max_clock_drift)
+        .ok()
+        .filter(Timestamp::is_set)
+        .ok_or(Error::InvalidHostTimestamp {
+            host_timestamp,
+            max_clock_drift,
+        })?;
+
+    let header_timestamp = header.timestamp();
+
+    if header_timestamp.check_expiry(&max_allowed_timestamp) == Expiry::Expired {
+        return Err(Error::HeaderFromFuture {
+            header_timestamp,
+            max_allowed_timestamp,
+        }
+        .into());
     }
 
     Ok(())
 }
 
+/// Checks that a trusted consensus state with the given `trusted_timestamp` is
+/// not expired, i.e. that less than `trusting_period` has elapsed between it
+/// and the host's current timestamp.
+///
+/// A trusted timestamp ahead of the host's clock is considered within the
+/// trusting period: such consensus states are accepted up to the client's
+/// `max_clock_drift`, and cannot have expired yet.
+pub fn check_trusted_state_within_trusting_period(
+    trusted_timestamp: Timestamp,
+    host_timestamp: Timestamp,
+    trusting_period: Duration,
+) -> Result<(), ClientError> {
+    let Some(duration_since_consensus_state) = host_timestamp.duration_since(&trusted_timestamp)
+    else {
+        return Ok(());
+    };
+
+    if duration_since_consensus_state >= trusting_period {
+        return Err(Error::ConsensusStateTimestampGteTrustingPeriod {
+            duration_since_consensus_state,
+            trusting_period,
+        }
+        .into());
+    }
+
+    Ok(())
+}
+
+/// Selects the best trusted height from which a header at `target_height` can
+/// be verified.
+///
+/// Tendermint headers do not need to be adjacent to the trusted state they are
+/// verified against: as long as the trusted consensus state is still within
+/// the client's trusting period, the light client verifier skips ahead by
+/// checking that enough of the trusted validator power signed the new header.
+/// This function picks the highest stored consensus state height that is
+/// lower than `target_height`, shares its revision number, and is not yet
+/// expired relative to the host's current timestamp.
+///
+/// Returns `None` if no such consensus state exists, in which case the client
+/// cannot be updated to `target_height` without first installing an
+/// intermediate header.
+pub fn select_trusted_height<V>(
+    client_state: &ClientStateType,
+    ctx: &V,
+    client_id: &ClientId,
+    target_height: &Height,
+) -> Result<Option<Height>, ClientError>
+where
+    V: ExtClientValidationContext,
+    ConsensusStateType: Convertible<V::ConsensusStateRef>,
+    <ConsensusStateType as TryFrom<V::ConsensusStateRef>>::Error: Into<ClientError>,
+{
+    let mut candidate_heights: Vec<Height> = ctx
+        .consensus_state_heights(client_id)?
+        .into_iter()
+        .filter(|height| {
+            height < target_height && height.revision_number() == target_height.revision_number()
+        })
+        .collect();
+
+    // Try the candidates from the highest to the lowest, as the closer the
+    // trusted height is to the target, the more likely the validator set
+    // overlap is sufficient.
+    candidate_heights.sort_unstable_by(|a, b| b.cmp(a));
+
+    let now = ctx.host_timestamp()?;
+
+    for height in candidate_heights {
+        let consensus_state: ConsensusStateType = ctx
+            .consensus_state(&ClientConsensusStatePath::new(
+                client_id.clone(),
+                height.revision_number(),
+                height.revision_height(),
+            ))?
+            .try_into()
+            .map_err(Into::into)?;
+
+        // Note: if the `duration_since()` is `None`, the consensus state is
+        // in the future and is therefore not considered expired.
+        let is_expired = now
+            .duration_since(&consensus_state.timestamp().into())
+            .is_some_and(|elapsed| elapsed > client_state.trusting_period);
+
+        if !is_expired {
+            return Ok(Some(height));
+        }
+    }
+
+    Ok(None)
+}
+
 /// Checks for misbehaviour upon receiving a new consensus state as part
 /// of a client update.
 pub fn check_for_misbehaviour_on_update<V>(
@@ -169,3 +329,40 @@ where
         Ok(false)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TRUSTING_PERIOD: Duration = Duration::from_secs(64000);
+
+    fn timestamp_from_secs(secs: u64) -> Timestamp {
+        Timestamp::from_nanoseconds(secs * 1_000_000_000).expect("valid timestamp")
+    }
+
+    #[test]
+    fn trusted_state_within_trusting_period() {
+        let trusted = timestamp_from_secs(1_000_000);
+        let host = timestamp_from_secs(1_000_000 + 600);
+
+        assert!(check_trusted_state_within_trusting_period(trusted, host, TRUSTING_PERIOD).is_ok());
+    }
+
+    #[test]
+    fn trusted_state_slightly_ahead_of_host() {
+        let host = timestamp_from_secs(1_000_000);
+        let trusted = timestamp_from_secs(1_000_000 + 3);
+
+        assert!(check_trusted_state_within_trusting_period(trusted, host, TRUSTING_PERIOD).is_ok());
+    }
+
+    #[test]
+    fn trusted_state_expired() {
+        let trusted = timestamp_from_secs(1_000_000);
+        let host = timestamp_from_secs(1_000_000 + TRUSTING_PERIOD.as_secs());
+
+        assert!(
+            check_trusted_state_within_trusting_period(trusted, host, TRUSTING_PERIOD).is_err()
+        );
+    }
+}
@@ -3,7 +3,7 @@ use ibc_client_tendermint_types::{
     Misbehaviour as TmMisbehaviour, TENDERMINT_HEADER_TYPE_URL, TENDERMINT_MISBEHAVIOUR_TYPE_URL,
 };
 use ibc_core_client::context::client_state::ClientStateValidation;
-use ibc_core_client::context::{Convertible, ExtClientValidationContext};
+use ibc_core_client::context::{Convertible, EvidenceAgeLimits, ExtClientValidationContext};
 use ibc_core_client::types::error::ClientError;
 use ibc_core_client::types::Status;
 use ibc_core_host::types::identifiers::ClientId;
@@ -16,7 +16,7 @@ use tendermint::merkle::MerkleHash;
 use tendermint_light_client_verifier::{ProdVerifier, Verifier};
 
 use super::{check_for_misbehaviour_on_misbehavior, check_for_misbehaviour_on_update, ClientState};
-use crate::client_state::{verify_header, verify_misbehaviour};
+use crate::client_state::{check_misbehaviour_age, verify_header, verify_misbehaviour};
 
 impl<V> ClientStateValidation<V> for ClientState
 where
@@ -67,6 +67,15 @@ where
         check_for_misbehaviour(self.inner(), ctx, client_id, client_message)
     }
 
+    fn check_for_duplicate_header(
+        &self,
+        ctx: &V,
+        client_id: &ClientId,
+        header: Any,
+    ) -> Result<bool, ClientError> {
+        check_for_duplicate_header(ctx, client_id, header)
+    }
+
     fn status(&self, ctx: &V, client_id: &ClientId) -> Result<Status, ClientError> {
         status(self.inner(), ctx, client_id)
     }
@@ -85,6 +94,12 @@ where
 /// function, except for an additional `verifier` parameter that allows users
 /// who require custom verification logic to easily pass in their own verifier
 /// implementation.
+///
+/// Misbehaviour evidence is rejected once expired under the
+/// [`evidence_age_limits`](ExtClientValidationContext::evidence_age_limits) of
+/// the host or, if it sets none, once older than the unbonding period of the
+/// client; see [`verify_client_message_with_evidence_age_limits`] to pass
+/// other limits.
 pub fn verify_client_message<V, H>(
     client_state: &ClientStateType,
     ctx: &V,
@@ -92,6 +107,34 @@ pub fn verify_client_message<V, H>(
     client_message: Any,
     verifier: &impl Verifier,
 ) -> Result<(), ClientError>
+where
+    V: ExtClientValidationContext,
+    ConsensusStateType: Convertible<V::ConsensusStateRef>,
+    <ConsensusStateType as TryFrom<V::ConsensusStateRef>>::Error: Into<ClientError>,
+    H: MerkleHash + Sha256Trait + Default,
+{
+    verify_client_message_with_evidence_age_limits::<V, H>(
+        client_state,
+        ctx,
+        client_id,
+        client_message,
+        verifier,
+        &ctx.evidence_age_limits().unwrap_or_else(|| {
+            EvidenceAgeLimits::from_unbonding_period(client_state.unbonding_period)
+        }),
+    )
+}
+
+/// Same as [`verify_client_message`], rejecting misbehaviour evidence which is
+/// expired under the given `evidence_age_limits`.
+pub fn verify_client_message_with_evidence_age_limits<V, H>(
+    client_state: &ClientStateType,
+    ctx: &V,
+    client_id: &ClientId,
+    client_message: Any,
+    verifier: &impl Verifier,
+    evidence_age_limits: &EvidenceAgeLimits,
+) -> Result<(), ClientError>
 where
     V: ExtClientValidationContext,
     ConsensusStateType: Convertible<V::ConsensusStateRef>,
@@ -112,6 +155,12 @@ where
         }
         TENDERMINT_MISBEHAVIOUR_TYPE_URL => {
             let misbehaviour = TmMisbehaviour::try_from(client_message)?;
+            check_misbehaviour_age(
+                &misbehaviour,
+                client_state.latest_height,
+                ctx.host_timestamp()?,
+                evidence_age_limits,
+            )?;
             verify_misbehaviour::<V, H>(
                 ctx,
                 &misbehaviour,
@@ -181,6 +230,33 @@ where
     }
 }
 
+/// Checks whether the client already processed the given header, in which
+/// case the client already stores a consensus state at its height.
+///
+/// Note that this function is typically implemented as part of the
+/// [`ClientStateValidation`] trait, but has been made a standalone function
+/// in order to make the ClientState APIs more flexible.
+///
+/// It assumes the header has already been checked for misbehaviour, which
+/// ensures the stored consensus state is the one derived from the header.
+pub fn check_for_duplicate_header<V>(
+    ctx: &V,
+    client_id: &ClientId,
+    header: Any,
+) -> Result<bool, ClientError>
+where
+    V: ExtClientValidationContext,
+{
+    let header = TmHeader::try_from(header)?;
+    let path_at_header_height = ClientConsensusStatePath::new(
+        client_id.clone(),
+        header.height().revision_number(),
+        header.height().revision_height(),
+    );
+
+    Ok(ctx.consensus_state(&path_at_header_height).is_ok())
+}
+
 /// Query the status of the client state.
 ///
 /// Note that this function is typically implemented as part of the
@@ -0,0 +1,118 @@
+//! Defines the [`ClientUpdateProposal`] bundle, packaging a header along with
+//! the trusted state it is verified against, so that it can be verified
+//! without access to the host chain, e.g. by off-chain watchtowers monitoring
+//! the safety of Tendermint clients.
+
+use ibc_client_tendermint_types::{
+    ClientState as ClientStateType, ConsensusState as ConsensusStateType, Header as TmHeader,
+};
+use ibc_core_client::context::{Convertible, ExtClientValidationContext};
+use ibc_core_client::types::error::ClientError;
+use ibc_core_client::types::Height;
+use ibc_core_host::types::identifiers::ClientId;
+use ibc_core_host::types::path::ClientConsensusStatePath;
+use ibc_primitives::prelude::*;
+use ibc_primitives::Timestamp;
+use tendermint::crypto::default::Sha256;
+use tendermint::crypto::Sha256 as Sha256Trait;
+use tendermint::merkle::MerkleHash;
+use tendermint_light_client_verifier::{ProdVerifier, Verifier};
+
+use crate::client_state::verify_header_against_trusted_state;
+
+/// A proposed update of a Tendermint client: the header submitted to the
+/// client, along with the client state and the consensus state at the trusted
+/// height of the header, which are all the evidence needed to verify it.
+///
+/// Verifying a proposal performs the same checks as the client does when it
+/// is updated with the header, at the given timestamp.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ClientUpdateProposal {
+    pub client_state: ClientStateType,
+    pub trusted_consensus_state: ConsensusStateType,
+    pub header: TmHeader,
+}
+
+impl ClientUpdateProposal {
+    pub fn new(
+        client_state: ClientStateType,
+        trusted_consensus_state: ConsensusStateType,
+        header: TmHeader,
+    ) -> Self {
+        Self {
+            client_state,
+            trusted_consensus_state,
+            header,
+        }
+    }
+
+    /// Packages the `header` submitted to the client `client_id` with the
+    /// consensus state stored at its trusted height in `ctx`.
+    pub fn from_context<V>(
+        ctx: &V,
+        client_id: &ClientId,
+        client_state: ClientStateType,
+        header: TmHeader,
+    ) -> Result<Self, ClientError>
+    where
+        V: ExtClientValidationContext,
+        ConsensusStateType: Convertible<V::ConsensusStateRef>,
+        <ConsensusStateType as TryFrom<V::ConsensusStateRef>>::Error: Into<ClientError>,
+    {
+        let trusted_consensus_state: ConsensusStateType = ctx
+            .consensus_state(&ClientConsensusStatePath::new(
+                client_id.clone(),
+                header.trusted_height.revision_number(),
+                header.trusted_height.revision_height(),
+            ))?
+            .try_into()
+            .map_err(Into::into)?;
+
+        Ok(Self::new(client_state, trusted_consensus_state, header))
+    }
+
+    pub fn trusted_height(&self) -> Height {
+        self.header.trusted_height
+    }
+
+    pub fn height(&self) -> Height {
+        self.header.height()
+    }
+
+    /// Verifies the header against the trusted consensus state at the given
+    /// timestamp, standing for the host's, and returns the consensus state
+    /// the client would store upon the update.
+    ///
+    /// Uses the Tendermint [`ProdVerifier`], as the client itself does.
+    pub fn verify(&self, now: Timestamp) -> Result<ConsensusStateType, ClientError> {
+        self.verify_with::<Sha256>(now, &ProdVerifier::default())
+    }
+
+    /// Same as [`Self::verify`], with a custom verifier and hash function.
+    pub fn verify_with<H>(
+        &self,
+        now: Timestamp,
+        verifier: &impl Verifier,
+    ) -> Result<ConsensusStateType, ClientError>
+    where
+        H: MerkleHash + Sha256Trait + Default,
+    {
+        let chain_id = self.client_state.chain_id();
+
+        self.header.validate_basic::<H>()?;
+        self.header
+            .verify_chain_id_version_matches_height(chain_id)?;
+
+        verify_header_against_trusted_state::<H>(
+            &self.header,
+            chain_id,
+            &self.client_state.as_light_client_options()?,
+            &self.trusted_consensus_state,
+            now,
+            verifier,
+        )?;
+
+        Ok(ConsensusStateType::from(self.header.clone()))
+    }
+}
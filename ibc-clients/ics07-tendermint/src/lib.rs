@@ -16,11 +16,35 @@
 #[cfg(any(test, feature = "std"))]
 extern crate std;
 
+use ibc_client_tendermint_types::{
+    TENDERMINT_CLIENT_STATE_TYPE_URL, TENDERMINT_CONSENSUS_STATE_TYPE_URL,
+};
+use ibc_core_client::registry::ClientTypeEntry;
+
 pub mod client_state;
 pub mod consensus_state;
+pub mod evidence;
 
 pub const TENDERMINT_CLIENT_TYPE: &str = "07-tendermint";
 
+/// Returns the entry of the Tendermint client type in a
+/// [`ClientRegistry`](ibc_core_client::registry::ClientRegistry), decoding its
+/// states into the `CS` and `ConsS` types of the host.
+pub fn client_type_entry<CS, ConsS>() -> ClientTypeEntry<CS, ConsS>
+where
+    CS: From<client_state::ClientState>,
+    ConsS: From<consensus_state::ConsensusState>,
+{
+    ClientTypeEntry {
+        client_state_type_url: TENDERMINT_CLIENT_STATE_TYPE_URL,
+        consensus_state_type_url: TENDERMINT_CONSENSUS_STATE_TYPE_URL,
+        decode_client_state: |any| client_state::ClientState::try_from(any).map(Into::into),
+        decode_consensus_state: |any| {
+            consensus_state::ConsensusState::try_from(any).map(Into::into)
+        },
+    }
+}
+
 /// Re-exports Tendermint light client data structures from the
 /// `ibc-client-tendermint-type` crate.
 pub mod types {
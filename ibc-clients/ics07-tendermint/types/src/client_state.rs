@@ -14,6 +14,7 @@ use ibc_primitives::ZERO_DURATION;
 use ibc_proto::google::protobuf::Any;
 use ibc_proto::ibc::lightclients::tendermint::v1::ClientState as RawTmClientState;
 use ibc_proto::Protobuf;
+use tendermint::block::Header as TmBlockHeader;
 use tendermint::chain::id::MAX_LENGTH as MaxChainIdLen;
 use tendermint::trust_threshold::TrustThresholdFraction as TendermintTrustThresholdFraction;
 use tendermint_light_client_verifier::options::Options;
@@ -105,6 +106,40 @@ impl ClientState {
         Ok(client_state)
     }
 
+    /// Constructs a new Tendermint `ClientState` whose chain identifier and
+    /// latest height are those of the given block header, as fetched from a
+    /// Tendermint RPC endpoint, and checks if the parameters are valid.
+    #[allow(clippy::too_many_arguments)]
+    pub fn from_block_header(
+        header: &TmBlockHeader,
+        trust_level: TrustThreshold,
+        trusting_period: Duration,
+        unbonding_period: Duration,
+        max_clock_drift: Duration,
+        proof_specs: ProofSpecs,
+        upgrade_path: Vec<String>,
+        allow_update: AllowUpdate,
+    ) -> Result<Self, Error> {
+        let chain_id = ChainId::from_str(header.chain_id.as_str())?;
+
+        let latest_height = Height::new(chain_id.revision_number(), header.height.value())
+            .map_err(|e| Error::InvalidLatestHeight {
+                reason: e.to_string(),
+            })?;
+
+        Self::new(
+            chain_id,
+            trust_level,
+            trusting_period,
+            unbonding_period,
+            max_clock_drift,
+            latest_height,
+            proof_specs,
+            upgrade_path,
+            allow_update,
+        )
+    }
+
     pub fn with_header(self, header: TmHeader) -> Result<Self, Error> {
         Ok(Self {
             latest_height: max(header.height(), self.latest_height),
@@ -119,6 +154,25 @@ impl ClientState {
         }
     }
 
+    /// Constructs the client state this client state is upgraded to, whose
+    /// chain-chosen parameters are those of the given upgraded client state,
+    /// and whose client-chosen parameters are those of this client state, and
+    /// checks if the resulting parameters are valid.
+    pub fn upgraded(&self, upgraded_client_state: Self) -> Result<Self, Error> {
+        Self::new(
+            upgraded_client_state.chain_id,
+            self.trust_level,
+            self.trusting_period,
+            upgraded_client_state.unbonding_period,
+            self.max_clock_drift,
+            upgraded_client_state.latest_height,
+            upgraded_client_state.proof_specs,
+            upgraded_client_state.upgrade_path,
+            self.allow_update,
+        )
+    }
+
+    /// Checks if the parameters of the client state are valid.
     pub fn validate(&self) -> Result<(), Error> {
         self.chain_id.validate_length(3, MaxChainIdLen as u64)?;
 
@@ -138,28 +192,17 @@ impl ClientState {
 
         // Basic validation of trusting period and unbonding period: each should be non-zero.
         if self.trusting_period <= Duration::new(0, 0) {
-            return Err(Error::InvalidTrustThreshold {
-                reason: format!(
-                    "ClientState trusting period ({:?}) must be greater than zero",
-                    self.trusting_period
-                ),
-            });
+            return Err(Error::ZeroTrustingPeriod);
         }
 
         if self.unbonding_period <= Duration::new(0, 0) {
-            return Err(Error::InvalidTrustThreshold {
-                reason: format!(
-                    "ClientState unbonding period ({:?}) must be greater than zero",
-                    self.unbonding_period
-                ),
-            });
+            return Err(Error::ZeroUnbondingPeriod);
         }
 
         if self.trusting_period >= self.unbonding_period {
-            return Err(Error::InvalidTrustThreshold {
-                reason: format!(
-                "ClientState trusting period ({:?}) must be smaller than unbonding period ({:?})", self.trusting_period, self.unbonding_period
-            ),
+            return Err(Error::TrustingPeriodNotSmallerThanUnbondingPeriod {
+                trusting_period: self.trusting_period,
+                unbonding_period: self.unbonding_period,
             });
         }
 
@@ -583,4 +626,66 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn client_state_validate_periods() {
+        let client_state = ClientState::new_without_validation(
+            ChainId::new("ibc-0").unwrap(),
+            TrustThreshold::ONE_THIRD,
+            Duration::new(64000, 0),
+            Duration::new(128_000, 0),
+            Duration::new(3, 0),
+            Height::new(0, 10).expect("Never fails"),
+            ProofSpecs::cosmos(),
+            Vec::new(),
+            None,
+            AllowUpdate {
+                after_expiry: false,
+                after_misbehaviour: false,
+            },
+        );
+        assert!(client_state.validate().is_ok());
+
+        let zero_trusting_period = ClientState {
+            trusting_period: ZERO_DURATION,
+            ..client_state.clone()
+        };
+        assert!(matches!(
+            zero_trusting_period.validate(),
+            Err(Error::ZeroTrustingPeriod)
+        ));
+
+        let zero_unbonding_period = ClientState {
+            unbonding_period: ZERO_DURATION,
+            ..client_state.clone()
+        };
+        assert!(matches!(
+            zero_unbonding_period.validate(),
+            Err(Error::ZeroUnbondingPeriod)
+        ));
+
+        let equal_periods = ClientState {
+            trusting_period: client_state.unbonding_period,
+            ..client_state.clone()
+        };
+        assert!(matches!(
+            equal_periods.validate(),
+            Err(Error::TrustingPeriodNotSmallerThanUnbondingPeriod {
+                trusting_period,
+                unbonding_period,
+            }) if trusting_period == unbonding_period
+        ));
+
+        // The client-chosen trusting period of the client is kept upon an
+        // upgrade, and must stay smaller than the new unbonding period.
+        let upgraded_client_state = ClientState {
+            unbonding_period: Duration::new(32000, 0),
+            latest_height: Height::new(0, 11).expect("Never fails"),
+            ..client_state.clone()
+        };
+        assert!(matches!(
+            client_state.upgraded(upgraded_client_state),
+            Err(Error::TrustingPeriodNotSmallerThanUnbondingPeriod { .. })
+        ));
+    }
 }
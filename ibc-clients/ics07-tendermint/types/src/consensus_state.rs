@@ -6,8 +6,12 @@ use ibc_primitives::prelude::*;
 use ibc_proto::google::protobuf::Any;
 use ibc_proto::ibc::lightclients::tendermint::v1::ConsensusState as RawConsensusState;
 use ibc_proto::Protobuf;
+use tendermint::block::Header as TmBlockHeader;
+use tendermint::crypto::Sha256;
 use tendermint::hash::Algorithm;
+use tendermint::merkle::MerkleHash;
 use tendermint::time::Time;
+use tendermint::validator::Set as ValidatorSet;
 use tendermint::Hash;
 use tendermint_proto::google::protobuf as tpb;
 
@@ -35,6 +39,44 @@ impl ConsensusState {
         }
     }
 
+    /// Constructs the consensus state at the height of the given block
+    /// header, as fetched from a Tendermint RPC endpoint, checking that
+    /// `next_validators` is the validator set the header commits to for the
+    /// next height.
+    ///
+    /// The header must also carry a non-empty application hash, which becomes
+    /// the commitment root, and a timestamp after the Unix epoch.
+    pub fn from_block_header<H>(
+        header: &TmBlockHeader,
+        next_validators: &ValidatorSet,
+    ) -> Result<Self, Error>
+    where
+        H: MerkleHash + Sha256 + Default,
+    {
+        if header.app_hash.as_ref().is_empty() {
+            return Err(Error::InvalidConsensusState {
+                reason: "empty commitment root".into(),
+            });
+        }
+
+        if header.time <= Time::unix_epoch() {
+            return Err(Error::InvalidConsensusState {
+                reason: format!("timestamp `{}` is not after the Unix epoch", header.time),
+            });
+        }
+
+        let next_validators_hash = next_validators.hash_with::<H>();
+
+        if next_validators_hash != header.next_validators_hash {
+            return Err(Error::MismatchNextValidatorsHashes {
+                next_validators_hash,
+                header_next_validators_hash: header.next_validators_hash,
+            });
+        }
+
+        Ok(Self::from(header.clone()))
+    }
+
     pub fn timestamp(&self) -> Time {
         self.timestamp
     }
@@ -132,8 +174,8 @@ impl From<ConsensusState> for Any {
     }
 }
 
-impl From<tendermint::block::Header> for ConsensusState {
-    fn from(header: tendermint::block::Header) -> Self {
+impl From<TmBlockHeader> for ConsensusState {
+    fn from(header: TmBlockHeader) -> Self {
         Self {
             root: CommitmentRoot::from_bytes(header.app_hash.as_ref()),
             timestamp: header.time,
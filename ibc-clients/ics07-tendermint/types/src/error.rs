@@ -9,6 +9,7 @@ use ibc_core_commitment_types::error::CommitmentError;
 use ibc_core_host_types::error::IdentifierError;
 use ibc_core_host_types::identifiers::ClientId;
 use ibc_primitives::prelude::*;
+use ibc_primitives::Timestamp;
 use tendermint::{Error as TendermintError, Hash};
 use tendermint_light_client_verifier::errors::VerificationErrorDetail as LightClientErrorDetail;
 use tendermint_light_client_verifier::operations::VotingPowerTally;
@@ -28,6 +29,15 @@ pub enum Error {
     InvalidTrustThreshold { reason: String },
     /// invalid tendermint client state trust threshold error: `{0}`
     InvalidTendermintTrustThreshold(TendermintError),
+    /// invalid client state trusting period: it must be greater than zero
+    ZeroTrustingPeriod,
+    /// invalid client state unbonding period: it must be greater than zero
+    ZeroUnbondingPeriod,
+    /// invalid client state trusting period: it (`{trusting_period:?}`) must be smaller than the unbonding period (`{unbonding_period:?}`)
+    TrustingPeriodNotSmallerThanUnbondingPeriod {
+        trusting_period: Duration,
+        unbonding_period: Duration,
+    },
     /// invalid client state max clock drift: `{reason}`
     InvalidMaxClockDrift { reason: String },
     /// invalid client state latest height: `{reason}`
@@ -84,11 +94,35 @@ pub enum Error {
         validators_hash: Hash,
         signed_header_validators_hash: Hash,
     },
+    /// The given hash of the next validators does not match the given hash in the header. Expected: `{header_next_validators_hash}`, got: `{next_validators_hash}`
+    MismatchNextValidatorsHashes {
+        next_validators_hash: Hash,
+        header_next_validators_hash: Hash,
+    },
+    /// invalid consensus state: `{reason}`
+    InvalidConsensusState { reason: String },
     /// current timestamp minus the latest consensus state timestamp is greater than or equal to the trusting period (`{duration_since_consensus_state:?}` >= `{trusting_period:?}`)
     ConsensusStateTimestampGteTrustingPeriod {
         duration_since_consensus_state: Duration,
         trusting_period: Duration,
     },
+    /// header timestamp `{header_timestamp}` is from the future: it exceeds the host timestamp plus the max clock drift (`{max_allowed_timestamp}`)
+    HeaderFromFuture {
+        header_timestamp: Timestamp,
+        max_allowed_timestamp: Timestamp,
+    },
+    /// the host timestamp `{host_timestamp}` is invalid or cannot be combined with the max clock drift `{max_clock_drift:?}`
+    InvalidHostTimestamp {
+        host_timestamp: Timestamp,
+        max_clock_drift: Duration,
+    },
+    /// misbehaviour evidence is expired: it is `{age_num_blocks}` blocks and `{age_duration:?}` old, exceeding both the maximum of `{max_age_num_blocks}` blocks and `{max_age_duration:?}`
+    MisbehaviourEvidenceExpired {
+        age_num_blocks: u64,
+        age_duration: Duration,
+        max_age_num_blocks: u64,
+        max_age_duration: Duration,
+    },
     /// headers block hashes are equal
     MisbehaviourHeadersBlockHashesEqual,
     /// headers are not at same height and are monotonically increasing
@@ -103,6 +137,7 @@ impl std::error::Error for Error {
             Self::InvalidHeader { error: e, .. }
             | Self::InvalidTendermintTrustThreshold(e)
             | Self::InvalidRawHeader(e) => Some(e),
+            Self::InvalidProofSpec(e) => Some(e),
             _ => None,
         }
     }
@@ -1,6 +1,6 @@
 //! Defines the client message type for the ICS-08 Wasm light client.
 
-use ibc_primitives::proto::Protobuf;
+use ibc_primitives::impl_raw_conversions;
 use ibc_proto::ibc::lightclients::wasm::v1::ClientMessage as RawClientMessage;
 
 use crate::Bytes;
@@ -13,17 +13,9 @@ pub struct ClientMessage {
     pub data: Bytes,
 }
 
-impl Protobuf<RawClientMessage> for ClientMessage {}
-
-impl From<RawClientMessage> for ClientMessage {
-    fn from(raw: RawClientMessage) -> Self {
-        Self { data: raw.data }
-    }
-}
-
-impl From<ClientMessage> for RawClientMessage {
-    fn from(value: ClientMessage) -> Self {
-        RawClientMessage { data: value.data }
+impl_raw_conversions! {
+    ClientMessage => RawClientMessage {
+        data,
     }
 }
 
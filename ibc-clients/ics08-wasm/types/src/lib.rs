@@ -23,9 +23,14 @@ pub mod serializer;
 
 use core::str::FromStr;
 
+use ibc_core_client::registry::ClientTypeEntry;
+use ibc_core_client::types::error::ClientError;
 use ibc_core_host_types::identifiers::ClientType;
 #[cfg(not(feature = "std"))]
-use ibc_primitives::prelude::Vec;
+use ibc_primitives::prelude::{ToString, Vec};
+
+use crate::client_state::{ClientState, WASM_CLIENT_STATE_TYPE_URL};
+use crate::consensus_state::{ConsensusState, WASM_CONSENSUS_STATE_TYPE_URL};
 
 pub type Bytes = Vec<u8>;
 
@@ -44,6 +49,28 @@ pub fn client_type() -> ClientType {
     ClientType::from_str(WASM_CLIENT_TYPE).expect("Never fails because it's valid")
 }
 
+/// Returns the entry of the wasm client type in a
+/// [`ClientRegistry`](ibc_core_client::registry::ClientRegistry), decoding its
+/// states into the `CS` and `ConsS` types of the host.
+pub fn client_type_entry<CS, ConsS>() -> ClientTypeEntry<CS, ConsS>
+where
+    CS: From<ClientState>,
+    ConsS: From<ConsensusState>,
+{
+    ClientTypeEntry {
+        client_state_type_url: WASM_CLIENT_STATE_TYPE_URL,
+        consensus_state_type_url: WASM_CONSENSUS_STATE_TYPE_URL,
+        decode_client_state: |any| {
+            ClientState::try_from(any)
+                .map(Into::into)
+                .map_err(|e| ClientError::ClientSpecific {
+                    description: e.to_string(),
+                })
+        },
+        decode_consensus_state: |any| ConsensusState::try_from(any).map(Into::into),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
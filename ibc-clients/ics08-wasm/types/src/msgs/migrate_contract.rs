@@ -1,10 +1,6 @@
-use core::str::FromStr;
-
 use ibc_core_host_types::identifiers::ClientId;
-use ibc_primitives::prelude::*;
-use ibc_primitives::Signer;
+use ibc_primitives::{impl_raw_conversions, Signer};
 use ibc_proto::ibc::lightclients::wasm::v1::MsgMigrateContract as RawMsgMigrateContract;
-use ibc_proto::Protobuf;
 
 use crate::error::Error;
 use crate::Bytes;
@@ -20,29 +16,12 @@ pub struct MsgMigrateContract {
     pub msg: Bytes,
 }
 
-impl Protobuf<RawMsgMigrateContract> for MsgMigrateContract {}
-
-impl From<MsgMigrateContract> for RawMsgMigrateContract {
-    fn from(value: MsgMigrateContract) -> Self {
-        Self {
-            signer: value.signer.to_string(),
-            client_id: value.client_id.to_string(),
-            checksum: value.checksum,
-            msg: value.msg,
-        }
-    }
-}
-
-impl TryFrom<RawMsgMigrateContract> for MsgMigrateContract {
-    type Error = Error;
-
-    fn try_from(value: RawMsgMigrateContract) -> Result<Self, Self::Error> {
-        Ok(Self {
-            signer: Signer::from(value.signer),
-            client_id: ClientId::from_str(&value.client_id)?,
-            checksum: value.checksum,
-            msg: value.msg,
-        })
+impl_raw_conversions! {
+    MsgMigrateContract => RawMsgMigrateContract, Error = Error {
+        signer,
+        client_id: ClientId,
+        checksum,
+        msg,
     }
 }
 
@@ -71,4 +50,20 @@ mod tests {
             raw_msg,
         )
     }
+
+    #[rstest]
+    #[case("")]
+    #[case("invalid client id")]
+    fn test_invalid_client_id(#[case] client_id: &str) {
+        let raw_msg = RawMsgMigrateContract {
+            signer: "signer".to_string(),
+            client_id: client_id.to_string(),
+            checksum: b"checksum".to_vec(),
+            msg: b"msg".to_vec(),
+        };
+        assert!(matches!(
+            MsgMigrateContract::try_from(raw_msg),
+            Err(Error::InvalidIdentifier(_))
+        ));
+    }
 }
@@ -1,7 +1,5 @@
-use ibc_primitives::prelude::*;
-use ibc_primitives::Signer;
+use ibc_primitives::{impl_raw_conversions, Signer};
 use ibc_proto::ibc::lightclients::wasm::v1::MsgRemoveChecksum as RawMsgRemoveChecksum;
-use ibc_proto::Protobuf;
 
 use crate::Bytes;
 
@@ -14,23 +12,10 @@ pub struct MsgRemoveChecksum {
     pub checksum: Bytes,
 }
 
-impl Protobuf<RawMsgRemoveChecksum> for MsgRemoveChecksum {}
-
-impl From<MsgRemoveChecksum> for RawMsgRemoveChecksum {
-    fn from(value: MsgRemoveChecksum) -> Self {
-        Self {
-            signer: value.signer.to_string(),
-            checksum: value.checksum,
-        }
-    }
-}
-
-impl From<RawMsgRemoveChecksum> for MsgRemoveChecksum {
-    fn from(value: RawMsgRemoveChecksum) -> Self {
-        Self {
-            signer: Signer::from(value.signer),
-            checksum: value.checksum,
-        }
+impl_raw_conversions! {
+    MsgRemoveChecksum => RawMsgRemoveChecksum {
+        signer,
+        checksum,
     }
 }
 
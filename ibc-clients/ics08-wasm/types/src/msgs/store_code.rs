@@ -1,7 +1,5 @@
-use ibc_primitives::prelude::*;
-use ibc_primitives::Signer;
+use ibc_primitives::{impl_raw_conversions, Signer};
 use ibc_proto::ibc::lightclients::wasm::v1::MsgStoreCode as RawMsgStoreCode;
-use ibc_proto::Protobuf;
 
 use crate::Bytes;
 
@@ -14,23 +12,10 @@ pub struct MsgStoreCode {
     pub wasm_byte_code: Bytes,
 }
 
-impl Protobuf<RawMsgStoreCode> for MsgStoreCode {}
-
-impl From<MsgStoreCode> for RawMsgStoreCode {
-    fn from(value: MsgStoreCode) -> Self {
-        Self {
-            signer: value.signer.to_string(),
-            wasm_byte_code: value.wasm_byte_code,
-        }
-    }
-}
-
-impl From<RawMsgStoreCode> for MsgStoreCode {
-    fn from(value: RawMsgStoreCode) -> Self {
-        Self {
-            signer: Signer::from(value.signer),
-            wasm_byte_code: value.wasm_byte_code,
-        }
+impl_raw_conversions! {
+    MsgStoreCode => RawMsgStoreCode {
+        signer,
+        wasm_byte_code,
     }
 }
 
@@ -33,6 +33,14 @@ pub trait ClientStateCommon: Convertible<Any> {
     /// Validate that the client is at a sufficient height
     fn validate_proof_height(&self, proof_height: Height) -> Result<(), ClientError>;
 
+    /// Performs basic validation on the parameters of the client state, upon
+    /// the creation of a client with it.
+    ///
+    /// Does nothing by default.
+    fn validate(&self) -> Result<(), ClientError> {
+        Ok(())
+    }
+
     /// Verify the upgraded client and consensus states and validate proofs
     /// against the given root.
     ///
@@ -119,6 +127,22 @@ where
         client_message: Any,
     ) -> Result<bool, ClientError>;
 
+    /// Checks whether the client already processed the given header, e.g.
+    /// submitted by another relayer, in which case updating the client with it
+    /// leaves it unchanged. It assumes the header has already been verified,
+    /// and checked for misbehaviour.
+    ///
+    /// Returns `false` by default, in which case all the updates of the client
+    /// are reported as new headers.
+    fn check_for_duplicate_header(
+        &self,
+        _ctx: &V,
+        _client_id: &ClientId,
+        _header: Any,
+    ) -> Result<bool, ClientError> {
+        Ok(false)
+    }
+
     /// Returns the status of the client. Only Active clients are allowed to process packets.
     fn status(&self, ctx: &V, client_id: &ClientId) -> Result<Status, ClientError>;
 
@@ -163,7 +187,7 @@ where
     /// successful update, a list of consensus heights is returned. It assumes
     /// the client_message has already been verified.
     ///
-    /// Note that `header` is the field associated with `UpdateKind::UpdateClient`.
+    /// Note that `header` is the client message of a `MsgUpdateClient`.
     ///
     /// Post-condition: on success, the return value MUST contain at least one
     /// height.
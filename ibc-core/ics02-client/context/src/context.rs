@@ -1,3 +1,5 @@
+use core::time::Duration;
+
 use ibc_core_client_types::Height;
 use ibc_core_handler_types::error::ContextError;
 use ibc_core_host_types::identifiers::ClientId;
@@ -136,6 +138,41 @@ pub trait ExtClientValidationContext: ClientValidationContext {
         client_id: &ClientId,
         height: &Height,
     ) -> Result<Option<Self::ConsensusStateRef>, ContextError>;
+
+    /// Returns the limits past which misbehaviour evidence is expired, e.g.
+    /// the evidence parameters of the consensus of the host.
+    ///
+    /// Defaults to `None`, in which case light clients derive the limits from
+    /// their own parameters, such as their unbonding period.
+    fn evidence_age_limits(&self) -> Option<EvidenceAgeLimits> {
+        None
+    }
+}
+
+/// The age limits past which misbehaviour evidence is expired, and can no
+/// longer freeze the client, mirroring the `max_age_num_blocks` and
+/// `max_age_duration` evidence parameters of Tendermint.
+///
+/// As in Tendermint, evidence is expired only once it exceeds both limits.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct EvidenceAgeLimits {
+    /// The maximum number of blocks between the height of the evidence and the
+    /// latest height of the client.
+    pub max_age_num_blocks: u64,
+    /// The maximum duration between the timestamp of the evidence and the
+    /// host timestamp.
+    pub max_age_duration: Duration,
+}
+
+impl EvidenceAgeLimits {
+    /// Returns the limits under which evidence expires once it is older than
+    /// the given unbonding period, whatever the number of blocks since.
+    pub fn from_unbonding_period(unbonding_period: Duration) -> Self {
+        Self {
+            max_age_num_blocks: 0,
+            max_age_duration: unbonding_period,
+        }
+    }
 }
 
 /// An optional trait that extends the client context required during execution.
@@ -15,6 +15,8 @@ where
     Ctx: ValidationContext,
     <ClientStateRef<Ctx> as TryFrom<Any>>::Error: Into<ClientError>,
 {
+    msg.validate_basic()?;
+
     let MsgCreateClient {
         client_state,
         consensus_state,
@@ -30,7 +32,19 @@ where
 
     let client_state = ClientStateRef::<Ctx>::try_from(client_state).map_err(Into::into)?;
 
-    let client_id = client_state.client_type().build_client_id(id_counter);
+    let client_type = client_state.client_type();
+
+    if !ctx.allowed_clients().is_allowed(&client_type) {
+        return Err(ClientError::ClientTypeNotAllowed { client_type }.into());
+    }
+
+    let client_id = client_type.build_client_id(id_counter);
+
+    // Query layers resolve the client type of a client from its identifier,
+    // which must thus be of the canonical `{client_type}-{counter}` format.
+    client_id
+        .client_type()
+        .map_err(ClientError::InvalidClientIdentifier)?;
 
     let status = client_state.status(client_val_ctx, &client_id)?;
 
@@ -41,6 +55,8 @@ where
         .into());
     };
 
+    client_state.validate()?;
+
     client_state.verify_consensus_state(consensus_state)?;
 
     if client_val_ctx.client_state(&client_id).is_ok() {
@@ -2,8 +2,10 @@
 
 use ibc_core_client_context::prelude::*;
 use ibc_core_client_types::error::ClientError;
+use ibc_core_client_types::events::RecoverClient;
 use ibc_core_client_types::msgs::MsgRecoverClient;
 use ibc_core_handler_types::error::ContextError;
+use ibc_core_handler_types::events::{IbcEvent, MessageEvent};
 use ibc_core_host::types::path::ClientConsensusStatePath;
 use ibc_core_host::{ExecutionContext, ValidationContext};
 
@@ -15,6 +17,8 @@ pub fn validate<Ctx>(ctx: &Ctx, msg: MsgRecoverClient) -> Result<(), ContextErro
 where
     Ctx: ValidationContext,
 {
+    msg.validate_basic()?;
+
     let signer = msg.signer;
     let subject_client_id = msg.subject_client_id.clone();
     let substitute_client_id = msg.substitute_client_id.clone();
@@ -87,5 +91,12 @@ where
         substitute_consensus_state.into(),
     )?;
 
+    let event = IbcEvent::RecoverClient(RecoverClient::new(
+        subject_client_id,
+        subject_client_state.client_type(),
+    ));
+    ctx.emit_ibc_event(IbcEvent::Message(MessageEvent::Client))?;
+    ctx.emit_ibc_event(event)?;
+
     Ok(())
 }
@@ -15,6 +15,8 @@ pub fn validate<Ctx>(ctx: &Ctx, msg: MsgUpdateOrMisbehaviour) -> Result<(), Cont
 where
     Ctx: ValidationContext,
 {
+    msg.validate_basic()?;
+
     ctx.validate_message_signer(msg.signer())?;
 
     let client_id = msg.client_id().clone();
@@ -35,15 +37,14 @@ where
     Ok(())
 }
 
-pub fn execute<Ctx>(ctx: &mut Ctx, msg: MsgUpdateOrMisbehaviour) -> Result<(), ContextError>
+/// Executes the update, and returns its outcome, which is also reported by the
+/// emitted `UpdateClient` event unless misbehaviour was found.
+pub fn execute<Ctx>(ctx: &mut Ctx, msg: MsgUpdateOrMisbehaviour) -> Result<UpdateKind, ContextError>
 where
     Ctx: ExecutionContext,
 {
     let client_id = msg.client_id().clone();
-    let update_kind = match msg {
-        MsgUpdateOrMisbehaviour::UpdateClient(_) => UpdateKind::UpdateClient,
-        MsgUpdateOrMisbehaviour::Misbehaviour(_) => UpdateKind::SubmitMisbehaviour,
-    };
+    let is_misbehaviour_msg = matches!(msg, MsgUpdateOrMisbehaviour::Misbehaviour(_));
     let client_message = msg.client_message();
 
     let client_exec_ctx = ctx.get_client_execution_context();
@@ -62,8 +63,10 @@ where
         ));
         ctx.emit_ibc_event(IbcEvent::Message(MessageEvent::Client))?;
         ctx.emit_ibc_event(event)?;
+
+        Ok(UpdateKind::Misbehaviour)
     } else {
-        if !matches!(update_kind, UpdateKind::UpdateClient) {
+        if is_misbehaviour_msg {
             return Err(ClientError::MisbehaviourHandlingFailure {
                 reason: "misbehaviour submitted, but none found".to_string(),
             }
@@ -72,6 +75,16 @@ where
 
         let header = client_message;
 
+        let update_kind = if client_state.check_for_duplicate_header(
+            client_exec_ctx,
+            &client_id,
+            header.clone(),
+        )? {
+            UpdateKind::NoOp
+        } else {
+            UpdateKind::NewHeader
+        };
+
         let consensus_heights =
             client_state.update_state(client_exec_ctx, &client_id, header.clone())?;
 
@@ -81,18 +94,21 @@ where
                     description: "client update state returned no updated height".to_string(),
                 })?;
 
-                IbcEvent::UpdateClient(UpdateClient::new(
-                    client_id,
-                    client_state.client_type(),
-                    *consensus_height,
-                    consensus_heights,
-                    header.to_vec(),
-                ))
+                IbcEvent::UpdateClient(
+                    UpdateClient::new(
+                        client_id,
+                        client_state.client_type(),
+                        *consensus_height,
+                        consensus_heights,
+                        header.to_vec(),
+                    )
+                    .with_update_kind(update_kind),
+                )
             };
             ctx.emit_ibc_event(IbcEvent::Message(MessageEvent::Client))?;
             ctx.emit_ibc_event(event)?;
         }
-    }
 
-    Ok(())
+        Ok(update_kind)
+    }
 }
@@ -14,6 +14,8 @@ pub fn validate<Ctx>(ctx: &Ctx, msg: MsgUpgradeClient) -> Result<(), ContextErro
 where
     Ctx: ValidationContext,
 {
+    msg.validate_basic()?;
+
     let MsgUpgradeClient {
         client_id, signer, ..
     } = msg;
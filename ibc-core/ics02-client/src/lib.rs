@@ -16,6 +16,7 @@
 extern crate std;
 
 pub mod handler;
+pub mod registry;
 
 /// Re-exports ICS-02 traits from `ibc-core-client-context` for custom IBC
 /// client implementation.
@@ -0,0 +1,208 @@
+//! Defines the `ClientRegistry`, which maps client types to the functions
+//! decoding their `Any`-wrapped client and consensus states.
+//!
+//! Query servers and other RPC layers returning `Any`-wrapped states can use
+//! it to decode and validate them, instead of matching on the type URLs of the
+//! clients supported by the host. States are checked against the
+//! [`DecodingLimits`] of the registry before being decoded.
+
+use ibc_core_client_types::error::ClientError;
+use ibc_core_host::types::identifiers::{ClientId, ClientType};
+use ibc_primitives::prelude::*;
+use ibc_primitives::proto::Any;
+use ibc_primitives::DecodingLimits;
+
+/// Decodes the domain type `T` from an `Any`, validating it along the way.
+pub type DecodeFn<T> = fn(Any) -> Result<T, ClientError>;
+
+/// The type URLs and decoding functions of the client and consensus states of
+/// a client type.
+///
+/// `CS` and `ConsS` are the types into which the host decodes the client and
+/// consensus states of all its clients, typically enums wrapping those of each
+/// client type.
+#[derive(Debug)]
+pub struct ClientTypeEntry<CS, ConsS> {
+    pub client_state_type_url: &'static str,
+    pub consensus_state_type_url: &'static str,
+    pub decode_client_state: DecodeFn<CS>,
+    pub decode_consensus_state: DecodeFn<ConsS>,
+}
+
+impl<CS, ConsS> Clone for ClientTypeEntry<CS, ConsS> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<CS, ConsS> Copy for ClientTypeEntry<CS, ConsS> {}
+
+/// Maps the client types supported by the host to their [`ClientTypeEntry`].
+#[derive(Clone, Debug)]
+pub struct ClientRegistry<CS, ConsS> {
+    entries: BTreeMap<ClientType, ClientTypeEntry<CS, ConsS>>,
+    limits: DecodingLimits,
+}
+
+impl<CS, ConsS> Default for ClientRegistry<CS, ConsS> {
+    fn default() -> Self {
+        Self {
+            entries: BTreeMap::new(),
+            limits: DecodingLimits::default(),
+        }
+    }
+}
+
+impl<CS, ConsS> ClientRegistry<CS, ConsS> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers the given client type, replacing its previous entry if any.
+    pub fn with_client_type(
+        mut self,
+        client_type: ClientType,
+        entry: ClientTypeEntry<CS, ConsS>,
+    ) -> Self {
+        self.entries.insert(client_type, entry);
+        self
+    }
+
+    /// Sets the limits against which states are checked before being decoded,
+    /// which default to [`DecodingLimits::default`].
+    pub fn with_decoding_limits(mut self, limits: DecodingLimits) -> Self {
+        self.limits = limits;
+        self
+    }
+
+    /// Returns the registered client types.
+    pub fn client_types(&self) -> impl Iterator<Item = &ClientType> {
+        self.entries.keys()
+    }
+
+    /// Returns the entry of the given client type, if registered.
+    pub fn entry(&self, client_type: &ClientType) -> Option<&ClientTypeEntry<CS, ConsS>> {
+        self.entries.get(client_type)
+    }
+
+    /// Returns the client type whose client state has the given type URL.
+    pub fn client_type_of_client_state(&self, type_url: &str) -> Option<&ClientType> {
+        self.entries
+            .iter()
+            .find(|(_, entry)| entry.client_state_type_url == type_url)
+            .map(|(client_type, _)| client_type)
+    }
+
+    /// Returns the client type whose consensus state has the given type URL.
+    pub fn client_type_of_consensus_state(&self, type_url: &str) -> Option<&ClientType> {
+        self.entries
+            .iter()
+            .find(|(_, entry)| entry.consensus_state_type_url == type_url)
+            .map(|(client_type, _)| client_type)
+    }
+
+    /// Returns the client type of the given client, resolved from its
+    /// identifier, failing if the identifier is not of the canonical
+    /// `{client_type}-{counter}` format or if its client type is not
+    /// registered.
+    pub fn client_type_of_client(&self, client_id: &ClientId) -> Result<ClientType, ClientError> {
+        let client_type = client_id
+            .client_type()
+            .map_err(ClientError::InvalidClientIdentifier)?;
+
+        self.entry_or_err(&client_type)?;
+
+        Ok(client_type)
+    }
+
+    /// Decodes a client state of the given client type, failing if its type
+    /// URL is not the one registered for that client type.
+    pub fn decode_client_state(
+        &self,
+        client_type: &ClientType,
+        any: Any,
+    ) -> Result<CS, ClientError> {
+        let entry = self.entry_or_err(client_type)?;
+
+        if any.type_url != entry.client_state_type_url {
+            return Err(ClientError::UnknownClientStateType {
+                client_state_type: any.type_url,
+            });
+        }
+
+        self.check_limits(&any)?;
+
+        (entry.decode_client_state)(any)
+    }
+
+    /// Decodes a consensus state of the given client type, failing if its type
+    /// URL is not the one registered for that client type.
+    pub fn decode_consensus_state(
+        &self,
+        client_type: &ClientType,
+        any: Any,
+    ) -> Result<ConsS, ClientError> {
+        let entry = self.entry_or_err(client_type)?;
+
+        if any.type_url != entry.consensus_state_type_url {
+            return Err(ClientError::UnknownConsensusStateType {
+                consensus_state_type: any.type_url,
+            });
+        }
+
+        self.check_limits(&any)?;
+
+        (entry.decode_consensus_state)(any)
+    }
+
+    /// Decodes a client state of any registered client type, looked up from
+    /// its type URL.
+    pub fn decode_any_client_state(&self, any: Any) -> Result<CS, ClientError> {
+        let entry = self
+            .entries
+            .values()
+            .find(|entry| entry.client_state_type_url == any.type_url)
+            .ok_or_else(|| ClientError::UnknownClientStateType {
+                client_state_type: any.type_url.clone(),
+            })?;
+
+        self.check_limits(&any)?;
+
+        (entry.decode_client_state)(any)
+    }
+
+    /// Decodes a consensus state of any registered client type, looked up
+    /// from its type URL.
+    pub fn decode_any_consensus_state(&self, any: Any) -> Result<ConsS, ClientError> {
+        let entry = self
+            .entries
+            .values()
+            .find(|entry| entry.consensus_state_type_url == any.type_url)
+            .ok_or_else(|| ClientError::UnknownConsensusStateType {
+                consensus_state_type: any.type_url.clone(),
+            })?;
+
+        self.check_limits(&any)?;
+
+        (entry.decode_consensus_state)(any)
+    }
+
+    fn check_limits(&self, any: &Any) -> Result<(), ClientError> {
+        self.limits
+            .check_any(any)
+            .map_err(|error| ClientError::DecodingLimitExceeded {
+                type_url: any.type_url.clone(),
+                error,
+            })
+    }
+
+    fn entry_or_err(
+        &self,
+        client_type: &ClientType,
+    ) -> Result<&ClientTypeEntry<CS, ConsS>, ClientError> {
+        self.entry(client_type)
+            .ok_or_else(|| ClientError::UnknownClientType {
+                client_type: client_type.clone(),
+            })
+    }
+}
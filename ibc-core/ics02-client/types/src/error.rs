@@ -7,7 +7,7 @@ use ibc_core_commitment_types::error::CommitmentError;
 use ibc_core_host_types::error::IdentifierError;
 use ibc_core_host_types::identifiers::{ClientId, ClientType};
 use ibc_primitives::prelude::*;
-use ibc_primitives::Timestamp;
+use ibc_primitives::{AbciError, DecodingLimitError, Timestamp};
 
 use super::status::Status;
 use crate::height::Height;
@@ -34,6 +34,8 @@ pub enum ClientError {
     },
     /// Subject and substitute client state mismatch during client recovery
     ClientRecoveryStateMismatch,
+    /// Subject and substitute client identifiers must differ during client recovery: `{client_id}`
+    ClientRecoveryIdenticalClientIds { client_id: ClientId },
     /// consensus state not found at: `{client_id}` at height `{height}`
     ConsensusStateNotFound { client_id: ClientId, height: Height },
     /// Processed time or height for the client `{client_id}` at height `{height}` not found
@@ -46,6 +48,15 @@ pub enum ClientError {
     FailedTrustThresholdConversion { numerator: u64, denominator: u64 },
     /// unknown client state type: `{client_state_type}`
     UnknownClientStateType { client_state_type: String },
+    /// unknown client type: `{client_type}`
+    UnknownClientType { client_type: ClientType },
+    /// client type `{client_type}` is not allowed by the host
+    ClientTypeNotAllowed { client_type: ClientType },
+    /// state of type URL `{type_url}` exceeds the decoding limits: {error}
+    DecodingLimitExceeded {
+        type_url: String,
+        error: DecodingLimitError,
+    },
     /// empty prefix
     EmptyPrefix,
     /// unknown client consensus state type: `{consensus_state_type}`
@@ -95,6 +106,8 @@ pub enum ClientError {
     InvalidSigner { reason: String },
     /// ics23 verification failure error: `{0}`
     Ics23Verification(CommitmentError),
+    /// invalid commitment prefix: `{0}`
+    InvalidCommitmentPrefix(CommitmentError),
     /// misbehaviour handling failed with reason: `{reason}`
     MisbehaviourHandlingFailure { reason: String },
     /// client specific error: `{description}`
@@ -121,15 +134,81 @@ impl From<Infallible> for ClientError {
     }
 }
 
+impl AbciError for ClientError {
+    fn codespace(&self) -> &'static str {
+        match self {
+            Self::Upgrade(e) => e.codespace(),
+            _ => "client",
+        }
+    }
+
+    fn code(&self) -> u32 {
+        match self {
+            Self::Upgrade(e) => e.code(),
+            Self::ClientFrozen { .. } => 2,
+            Self::ClientNotActive { .. } => 3,
+            Self::ClientNotInactive { .. } => 4,
+            Self::ClientStateNotFound { .. } => 5,
+            Self::ClientStateAlreadyExists { .. } => 6,
+            Self::ClientRecoveryHeightMismatch { .. } => 7,
+            Self::ClientRecoveryStateMismatch => 8,
+            Self::ClientRecoveryIdenticalClientIds { .. } => 9,
+            Self::ConsensusStateNotFound { .. } => 10,
+            Self::UpdateMetaDataNotFound { .. } => 11,
+            Self::HeaderVerificationFailure { .. } => 12,
+            Self::InvalidTrustThreshold { .. } => 13,
+            Self::FailedTrustThresholdConversion { .. } => 14,
+            Self::UnknownClientStateType { .. } => 15,
+            Self::UnknownClientType { .. } => 16,
+            Self::ClientTypeNotAllowed { .. } => 17,
+            Self::DecodingLimitExceeded { .. } => 18,
+            Self::EmptyPrefix => 19,
+            Self::UnknownConsensusStateType { .. } => 20,
+            Self::UnknownHeaderType { .. } => 21,
+            Self::UnknownMisbehaviourType { .. } => 22,
+            Self::MissingRawClientState => 23,
+            Self::MissingRawConsensusState => 24,
+            Self::InvalidMsgUpdateClientId(_) => 25,
+            Self::InvalidMsgRecoverClientId(_) => 26,
+            Self::InvalidClientIdentifier(_) => 27,
+            Self::InvalidRawHeader { .. } => 28,
+            Self::MissingClientMessage => 29,
+            Self::InvalidRawMisbehaviour(_) => 30,
+            Self::MissingRawMisbehaviour => 31,
+            Self::InvalidHeight => 32,
+            Self::InvalidHeightResult => 33,
+            Self::InvalidProofHeight { .. } => 34,
+            Self::InvalidCommitmentProof(_) => 35,
+            Self::InvalidPacketTimestamp(_) => 36,
+            Self::ClientArgsTypeMismatch { .. } => 37,
+            Self::InvalidConsensusStateTimestamp { .. } => 38,
+            Self::MissingLocalConsensusState { .. } => 39,
+            Self::InvalidSigner { .. } => 40,
+            Self::Ics23Verification(_) => 41,
+            Self::MisbehaviourHandlingFailure { .. } => 42,
+            Self::ClientSpecific { .. } => 43,
+            Self::CounterOverflow => 44,
+            Self::InvalidUpdateClientMessage => 45,
+            Self::Other { .. } => 46,
+            Self::InvalidCommitmentPrefix(_) => 47,
+        }
+    }
+}
+
 #[cfg(feature = "std")]
 impl std::error::Error for ClientError {
     fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
         match &self {
+            Self::Upgrade(e) => Some(e),
             Self::InvalidMsgUpdateClientId(e)
+            | Self::InvalidMsgRecoverClientId(e)
             | Self::InvalidClientIdentifier(e)
             | Self::InvalidRawMisbehaviour(e) => Some(e),
-            Self::InvalidCommitmentProof(e) | Self::Ics23Verification(e) => Some(e),
+            Self::InvalidCommitmentProof(e)
+            | Self::Ics23Verification(e)
+            | Self::InvalidCommitmentPrefix(e) => Some(e),
             Self::InvalidPacketTimestamp(e) => Some(e),
+            Self::DecodingLimitExceeded { error, .. } => Some(error),
             _ => None,
         }
     }
@@ -161,6 +240,24 @@ impl From<UpgradeClientError> for ClientError {
     }
 }
 
+impl AbciError for UpgradeClientError {
+    fn codespace(&self) -> &'static str {
+        "client"
+    }
+
+    fn code(&self) -> u32 {
+        // Offset so as to share the codespace of the client errors.
+        match self {
+            Self::InvalidUpgradeClientProof(_) => 101,
+            Self::InvalidUpgradeConsensusStateProof(_) => 102,
+            Self::LowUpgradeHeight { .. } => 103,
+            Self::InvalidUpgradeProposal { .. } => 104,
+            Self::InvalidUpgradePlan { .. } => 105,
+            Self::Other { .. } => 106,
+        }
+    }
+}
+
 #[cfg(feature = "std")]
 impl std::error::Error for UpgradeClientError {
     fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
@@ -5,17 +5,23 @@ use ibc_primitives::prelude::*;
 use subtle_encoding::hex;
 use tendermint::abci;
 
-use crate::height::Height;
+use crate::height::{Height, HeightError};
+use crate::UpdateKind;
 
 /// Client event types
 pub const CREATE_CLIENT_EVENT: &str = "create_client";
 pub const UPDATE_CLIENT_EVENT: &str = "update_client";
 pub const CLIENT_MISBEHAVIOUR_EVENT: &str = "client_misbehaviour";
 pub const UPGRADE_CLIENT_EVENT: &str = "upgrade_client";
+pub const RECOVER_CLIENT_EVENT: &str = "recover_client";
 
 /// The content of the `key` field for the attribute containing the client identifier.
 pub const CLIENT_ID_ATTRIBUTE_KEY: &str = "client_id";
 
+/// The content of the `key` field for the attribute containing the identifier
+/// of the recovered client.
+pub const SUBJECT_CLIENT_ID_ATTRIBUTE_KEY: &str = "subject_client_id";
+
 /// The content of the `key` field for the attribute containing the client type.
 pub const CLIENT_TYPE_ATTRIBUTE_KEY: &str = "client_type";
 
@@ -28,6 +34,10 @@ pub const CONSENSUS_HEIGHTS_ATTRIBUTE_KEY: &str = "consensus_heights";
 /// The content of the `key` field for the header in update client event.
 pub const HEADER_ATTRIBUTE_KEY: &str = "header";
 
+/// The content of the `key` field for the attribute containing the outcome of
+/// the update in update client event.
+pub const UPDATE_KIND_ATTRIBUTE_KEY: &str = "update_kind";
+
 #[cfg_attr(
     feature = "parity-scale-codec",
     derive(
@@ -52,6 +62,34 @@ impl From<ClientIdAttribute> for abci::EventAttribute {
     }
 }
 
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, From, PartialEq, Eq)]
+struct SubjectClientIdAttribute {
+    subject_client_id: ClientId,
+}
+
+impl From<SubjectClientIdAttribute> for abci::EventAttribute {
+    fn from(attr: SubjectClientIdAttribute) -> Self {
+        (
+            SUBJECT_CLIENT_ID_ATTRIBUTE_KEY,
+            attr.subject_client_id.as_str(),
+        )
+            .into()
+    }
+}
+
 #[cfg_attr(
     feature = "parity-scale-codec",
     derive(
@@ -129,6 +167,21 @@ impl From<ConsensusHeightsAttribute> for abci::EventAttribute {
     }
 }
 
+/// Parses the value of the `consensus_heights` attribute of an `update_client`
+/// event, i.e. the comma-separated heights of all the consensus states stored
+/// by the update, as emitted by ibc-go.
+///
+/// Relayers tracking the heights of a client should rely on this attribute
+/// rather than on the deprecated `consensus_height`, which only holds the
+/// first height of an update storing several consensus states.
+pub fn parse_consensus_heights(value: &str) -> Result<Vec<Height>, HeightError> {
+    if value.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    value.split(',').map(str::parse).collect()
+}
+
 #[cfg_attr(
     feature = "parity-scale-codec",
     derive(
@@ -160,6 +213,30 @@ impl From<HeaderAttribute> for abci::EventAttribute {
     }
 }
 
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, From, PartialEq, Eq)]
+struct UpdateKindAttribute {
+    update_kind: UpdateKind,
+}
+
+impl From<UpdateKindAttribute> for abci::EventAttribute {
+    fn from(attr: UpdateKindAttribute) -> Self {
+        (UPDATE_KIND_ATTRIBUTE_KEY, attr.update_kind.as_str()).into()
+    }
+}
+
 /// CreateClient event signals the creation of a new on-chain client (IBC client).
 #[cfg_attr(
     feature = "parity-scale-codec",
@@ -243,6 +320,7 @@ pub struct UpdateClient {
     consensus_height: ConsensusHeightAttribute,
     consensus_heights: ConsensusHeightsAttribute,
     header: HeaderAttribute,
+    update_kind: UpdateKindAttribute,
 }
 
 impl UpdateClient {
@@ -250,6 +328,9 @@ impl UpdateClient {
     ///
     /// NOTE: the `header` is the encoded bytes of the
     /// [`Any`](ibc_proto::google::protobuf::Any) type.
+    ///
+    /// The update is reported as a [`UpdateKind::NewHeader`], unless set
+    /// otherwise with [`UpdateClient::with_update_kind`].
     pub fn new(
         client_id: ClientId,
         client_type: ClientType,
@@ -263,9 +344,16 @@ impl UpdateClient {
             consensus_height: ConsensusHeightAttribute::from(consensus_height),
             consensus_heights: ConsensusHeightsAttribute::from(consensus_heights),
             header: HeaderAttribute::from(header),
+            update_kind: UpdateKindAttribute::from(UpdateKind::NewHeader),
         }
     }
 
+    /// Sets the outcome of the update reported by the event.
+    pub fn with_update_kind(mut self, update_kind: UpdateKind) -> Self {
+        self.update_kind = UpdateKindAttribute::from(update_kind);
+        self
+    }
+
     pub fn client_id(&self) -> &ClientId {
         &self.client_id.client_id
     }
@@ -286,6 +374,10 @@ impl UpdateClient {
         &self.header.header
     }
 
+    pub fn update_kind(&self) -> &UpdateKind {
+        &self.update_kind.update_kind
+    }
+
     pub fn event_type(&self) -> &str {
         UPDATE_CLIENT_EVENT
     }
@@ -301,6 +393,7 @@ impl From<UpdateClient> for abci::Event {
                 u.consensus_height.into(),
                 u.consensus_heights.into(),
                 u.header.into(),
+                u.update_kind.into(),
             ],
         }
     }
@@ -416,3 +509,54 @@ impl From<UpgradeClient> for abci::Event {
         }
     }
 }
+
+/// Signals the recovery of a frozen or expired client (IBC Client) from a
+/// substitute client.
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RecoverClient {
+    subject_client_id: SubjectClientIdAttribute,
+    client_type: ClientTypeAttribute,
+}
+
+impl RecoverClient {
+    pub fn new(subject_client_id: ClientId, client_type: ClientType) -> Self {
+        Self {
+            subject_client_id: SubjectClientIdAttribute::from(subject_client_id),
+            client_type: ClientTypeAttribute::from(client_type),
+        }
+    }
+
+    pub fn subject_client_id(&self) -> &ClientId {
+        &self.subject_client_id.subject_client_id
+    }
+
+    pub fn client_type(&self) -> &ClientType {
+        &self.client_type.client_type
+    }
+
+    pub fn event_type(&self) -> &str {
+        RECOVER_CLIENT_EVENT
+    }
+}
+
+impl From<RecoverClient> for abci::Event {
+    fn from(r: RecoverClient) -> Self {
+        Self {
+            kind: RECOVER_CLIENT_EVENT.to_owned(),
+            attributes: vec![r.subject_client_id.into(), r.client_type.into()],
+        }
+    }
+}
@@ -1,7 +1,7 @@
 //! Definition of domain type message `MsgCreateClient`.
 
 use ibc_primitives::prelude::*;
-use ibc_primitives::Signer;
+use ibc_primitives::{Msg, Signer};
 use ibc_proto::google::protobuf::Any;
 use ibc_proto::ibc::core::client::v1::MsgCreateClient as RawMsgCreateClient;
 use ibc_proto::Protobuf;
@@ -17,6 +17,11 @@ pub const CREATE_CLIENT_TYPE_URL: &str = "/ibc.core.client.v1.MsgCreateClient";
 )]
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "builder",
+    derive(typed_builder::TypedBuilder),
+    builder(field_defaults(setter(into)))
+)]
 pub struct MsgCreateClient {
     pub client_state: Any,
     pub consensus_state: Any,
@@ -31,6 +36,28 @@ impl MsgCreateClient {
             signer,
         }
     }
+
+    /// Performs the stateless validation of the message, without access to the host state.
+    pub fn validate_basic(&self) -> Result<(), ClientError> {
+        if self.client_state.type_url.is_empty() {
+            return Err(ClientError::MissingRawClientState);
+        }
+        if self.consensus_state.type_url.is_empty() {
+            return Err(ClientError::MissingRawConsensusState);
+        }
+        if self.signer.is_empty() {
+            return Err(ClientError::InvalidSigner {
+                reason: "signer cannot be empty".to_string(),
+            });
+        }
+        Ok(())
+    }
+}
+
+impl Msg for MsgCreateClient {
+    fn signer(&self) -> &Signer {
+        &self.signer
+    }
 }
 
 impl Protobuf<RawMsgCreateClient> for MsgCreateClient {}
@@ -2,7 +2,7 @@
 
 use ibc_core_host_types::identifiers::ClientId;
 use ibc_primitives::prelude::*;
-use ibc_primitives::Signer;
+use ibc_primitives::{Msg, Signer};
 use ibc_proto::google::protobuf::Any as ProtoAny;
 use ibc_proto::ibc::core::client::v1::MsgSubmitMisbehaviour as RawMsgSubmitMisbehaviour;
 use ibc_proto::Protobuf;
@@ -25,6 +25,11 @@ pub const SUBMIT_MISBEHAVIOUR_TYPE_URL: &str = "/ibc.core.client.v1.MsgSubmitMis
 )]
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "builder",
+    derive(typed_builder::TypedBuilder),
+    builder(field_defaults(setter(into)))
+)]
 pub struct MsgSubmitMisbehaviour {
     /// client unique identifier
     pub client_id: ClientId,
@@ -34,6 +39,27 @@ pub struct MsgSubmitMisbehaviour {
     pub signer: Signer,
 }
 
+impl MsgSubmitMisbehaviour {
+    /// Performs the stateless validation of the message, without access to the host state.
+    pub fn validate_basic(&self) -> Result<(), ClientError> {
+        if self.misbehaviour.type_url.is_empty() {
+            return Err(ClientError::MissingRawMisbehaviour);
+        }
+        if self.signer.is_empty() {
+            return Err(ClientError::InvalidSigner {
+                reason: "signer cannot be empty".to_string(),
+            });
+        }
+        Ok(())
+    }
+}
+
+impl Msg for MsgSubmitMisbehaviour {
+    fn signer(&self) -> &Signer {
+        &self.signer
+    }
+}
+
 impl Protobuf<RawMsgSubmitMisbehaviour> for MsgSubmitMisbehaviour {}
 
 impl TryFrom<RawMsgSubmitMisbehaviour> for MsgSubmitMisbehaviour {
@@ -1,12 +1,27 @@
 #![allow(deprecated)]
 
 //! Defines the client message types that are sent to the chain by the relayer.
+//!
+//! With the `builder` feature enabled, each message can also be constructed
+//! through its `builder()`, whose `build()` method only compiles once all the
+//! required fields of the message are set, e.g.:
+//!
+//! ```ignore
+//! let msg = MsgUpdateClient::builder()
+//!     .client_id(client_id)
+//!     .client_message(header)
+//!     .signer(signer)
+//!     .build();
+//! msg.validate_basic()?;
+//! ```
 
 use ibc_core_host_types::identifiers::ClientId;
 use ibc_primitives::prelude::*;
-use ibc_primitives::Signer;
+use ibc_primitives::{Msg, Signer};
 use ibc_proto::google::protobuf::Any;
 
+use crate::error::ClientError;
+
 mod create_client;
 mod misbehaviour;
 mod recover_client;
@@ -35,6 +50,31 @@ pub enum ClientMsg {
     RecoverClient(MsgRecoverClient),
 }
 
+impl ClientMsg {
+    /// Performs the stateless validation of the wrapped message.
+    pub fn validate_basic(&self) -> Result<(), ClientError> {
+        match self {
+            ClientMsg::CreateClient(msg) => msg.validate_basic(),
+            ClientMsg::UpdateClient(msg) => msg.validate_basic(),
+            ClientMsg::Misbehaviour(msg) => msg.validate_basic(),
+            ClientMsg::UpgradeClient(msg) => msg.validate_basic(),
+            ClientMsg::RecoverClient(msg) => msg.validate_basic(),
+        }
+    }
+}
+
+impl Msg for ClientMsg {
+    fn signer(&self) -> &Signer {
+        match self {
+            ClientMsg::CreateClient(msg) => msg.signer(),
+            ClientMsg::UpdateClient(msg) => msg.signer(),
+            ClientMsg::Misbehaviour(msg) => msg.signer(),
+            ClientMsg::UpgradeClient(msg) => msg.signer(),
+            ClientMsg::RecoverClient(msg) => msg.signer(),
+        }
+    }
+}
+
 pub enum MsgUpdateOrMisbehaviour {
     UpdateClient(MsgUpdateClient),
     Misbehaviour(MsgSubmitMisbehaviour),
@@ -61,4 +101,11 @@ impl MsgUpdateOrMisbehaviour {
             MsgUpdateOrMisbehaviour::Misbehaviour(msg) => &msg.signer,
         }
     }
+
+    pub fn validate_basic(&self) -> Result<(), ClientError> {
+        match self {
+            MsgUpdateOrMisbehaviour::UpdateClient(msg) => msg.validate_basic(),
+            MsgUpdateOrMisbehaviour::Misbehaviour(msg) => msg.validate_basic(),
+        }
+    }
 }
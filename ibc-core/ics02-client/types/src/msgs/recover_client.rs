@@ -2,7 +2,7 @@
 
 use ibc_core_host_types::identifiers::ClientId;
 use ibc_primitives::prelude::*;
-use ibc_primitives::Signer;
+use ibc_primitives::{Msg, Signer};
 use ibc_proto::ibc::core::client::v1::MsgRecoverClient as RawMsgRecoverClient;
 use ibc_proto::Protobuf;
 
@@ -24,6 +24,11 @@ pub const RECOVER_CLIENT_TYPE_URL: &str = "/ibc.core.client.v1.MsgRecoverClient"
 )]
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "builder",
+    derive(typed_builder::TypedBuilder),
+    builder(field_defaults(setter(into)))
+)]
 pub struct MsgRecoverClient {
     /// Client identifier of the client to be updated if the proposal passes.
     pub subject_client_id: ClientId,
@@ -35,6 +40,29 @@ pub struct MsgRecoverClient {
     pub signer: Signer,
 }
 
+impl MsgRecoverClient {
+    /// Performs the stateless validation of the message, without access to the host state.
+    pub fn validate_basic(&self) -> Result<(), ClientError> {
+        if self.subject_client_id == self.substitute_client_id {
+            return Err(ClientError::ClientRecoveryIdenticalClientIds {
+                client_id: self.subject_client_id.clone(),
+            });
+        }
+        if self.signer.is_empty() {
+            return Err(ClientError::InvalidSigner {
+                reason: "signer cannot be empty".to_string(),
+            });
+        }
+        Ok(())
+    }
+}
+
+impl Msg for MsgRecoverClient {
+    fn signer(&self) -> &Signer {
+        &self.signer
+    }
+}
+
 impl Protobuf<RawMsgRecoverClient> for MsgRecoverClient {}
 
 impl TryFrom<RawMsgRecoverClient> for MsgRecoverClient {
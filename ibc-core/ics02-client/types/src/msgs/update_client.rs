@@ -2,7 +2,7 @@
 
 use ibc_core_host_types::identifiers::ClientId;
 use ibc_primitives::prelude::*;
-use ibc_primitives::Signer;
+use ibc_primitives::{Msg, Signer};
 use ibc_proto::google::protobuf::Any;
 use ibc_proto::ibc::core::client::v1::MsgUpdateClient as RawMsgUpdateClient;
 use ibc_proto::Protobuf;
@@ -21,12 +21,38 @@ pub const UPDATE_CLIENT_TYPE_URL: &str = "/ibc.core.client.v1.MsgUpdateClient";
 )]
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "builder",
+    derive(typed_builder::TypedBuilder),
+    builder(field_defaults(setter(into)))
+)]
 pub struct MsgUpdateClient {
     pub client_id: ClientId,
     pub client_message: Any,
     pub signer: Signer,
 }
 
+impl MsgUpdateClient {
+    /// Performs the stateless validation of the message, without access to the host state.
+    pub fn validate_basic(&self) -> Result<(), ClientError> {
+        if self.client_message.type_url.is_empty() {
+            return Err(ClientError::MissingClientMessage);
+        }
+        if self.signer.is_empty() {
+            return Err(ClientError::InvalidSigner {
+                reason: "signer cannot be empty".to_string(),
+            });
+        }
+        Ok(())
+    }
+}
+
+impl Msg for MsgUpdateClient {
+    fn signer(&self) -> &Signer {
+        &self.signer
+    }
+}
+
 impl Protobuf<RawMsgUpdateClient> for MsgUpdateClient {}
 
 impl TryFrom<RawMsgUpdateClient> for MsgUpdateClient {
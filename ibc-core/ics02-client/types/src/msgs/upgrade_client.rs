@@ -6,7 +6,7 @@ use ibc_core_commitment_types::commitment::CommitmentProofBytes;
 use ibc_core_commitment_types::error::CommitmentError;
 use ibc_core_host_types::identifiers::ClientId;
 use ibc_primitives::prelude::*;
-use ibc_primitives::Signer;
+use ibc_primitives::{Msg, Signer};
 use ibc_proto::google::protobuf::Any;
 use ibc_proto::ibc::core::client::v1::MsgUpgradeClient as RawMsgUpgradeClient;
 use ibc_proto::Protobuf;
@@ -22,6 +22,11 @@ pub const UPGRADE_CLIENT_TYPE_URL: &str = "/ibc.core.client.v1.MsgUpgradeClient"
 )]
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "builder",
+    derive(typed_builder::TypedBuilder),
+    builder(field_defaults(setter(into)))
+)]
 pub struct MsgUpgradeClient {
     // client unique identifier
     pub client_id: ClientId,
@@ -38,6 +43,42 @@ pub struct MsgUpgradeClient {
     pub signer: Signer,
 }
 
+impl MsgUpgradeClient {
+    /// Performs the stateless validation of the message, without access to the host state.
+    pub fn validate_basic(&self) -> Result<(), ClientError> {
+        if self.upgraded_client_state.type_url.is_empty() {
+            return Err(ClientError::MissingRawClientState);
+        }
+        if self.upgraded_consensus_state.type_url.is_empty() {
+            return Err(ClientError::MissingRawConsensusState);
+        }
+        if self.proof_upgrade_client.is_empty() {
+            return Err(UpgradeClientError::InvalidUpgradeClientProof(
+                CommitmentError::EmptyMerkleProof,
+            )
+            .into());
+        }
+        if self.proof_upgrade_consensus_state.is_empty() {
+            return Err(UpgradeClientError::InvalidUpgradeConsensusStateProof(
+                CommitmentError::EmptyMerkleProof,
+            )
+            .into());
+        }
+        if self.signer.is_empty() {
+            return Err(ClientError::InvalidSigner {
+                reason: "signer cannot be empty".to_string(),
+            });
+        }
+        Ok(())
+    }
+}
+
+impl Msg for MsgUpgradeClient {
+    fn signer(&self) -> &Signer {
+        &self.signer
+    }
+}
+
 impl Protobuf<RawMsgUpgradeClient> for MsgUpgradeClient {}
 
 impl From<MsgUpgradeClient> for RawMsgUpgradeClient {
@@ -5,17 +5,75 @@ use ibc_primitives::prelude::*;
 
 use crate::error::ClientError;
 
-/// `UpdateKind` represents the 2 ways that a client can be updated
-/// in IBC: either through a `MsgUpdateClient`, or a `MsgSubmitMisbehaviour`.
-#[derive(Clone, Debug, PartialEq, Eq)]
+/// `UpdateKind` represents the outcome of the handling of a
+/// `MsgUpdateClient` or a `MsgSubmitMisbehaviour`, which relayers and
+/// monitoring tools use to tell duplicate updates from freezes.
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum UpdateKind {
-    /// this is the typical scenario where a new header is submitted to the client
-    /// to update the client. Note that light clients are free to define the type
-    /// of the object used to update them (e.g. could be a list of headers).
-    UpdateClient,
-    /// this is the scenario where misbehaviour is submitted to the client
-    /// (e.g 2 headers with the same height in Tendermint)
-    SubmitMisbehaviour,
+    /// The client had already processed the submitted header, e.g. submitted
+    /// by another relayer, so the update left it unchanged.
+    NoOp,
+    /// This is the typical scenario where a new header is submitted to the
+    /// client to update it. Note that light clients are free to define the
+    /// type of the object used to update them (e.g. could be a list of
+    /// headers).
+    NewHeader,
+    /// Misbehaviour was found in the submitted client message (e.g 2 headers
+    /// with the same height in Tendermint), and the client was frozen.
+    Misbehaviour,
+}
+
+impl UpdateKind {
+    pub fn is_no_op(&self) -> bool {
+        *self == UpdateKind::NoOp
+    }
+
+    pub fn is_misbehaviour(&self) -> bool {
+        *self == UpdateKind::Misbehaviour
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::NoOp => "no_op",
+            Self::NewHeader => "new_header",
+            Self::Misbehaviour => "misbehaviour",
+        }
+    }
+}
+
+impl Display for UpdateKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl FromStr for UpdateKind {
+    type Err = ClientError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "no_op" => Ok(Self::NoOp),
+            "new_header" => Ok(Self::NewHeader),
+            "misbehaviour" => Ok(Self::Misbehaviour),
+            _ => Err(ClientError::Other {
+                description: format!("invalid update kind string: {s}"),
+            }),
+        }
+    }
 }
 
 /// Represents the status of a client
@@ -5,6 +5,14 @@ use ibc_core_connection_types::ConnectionEnd;
 use ibc_core_handler_types::error::ContextError;
 use ibc_core_host::ValidationContext;
 
+/// Verifies that the delay period of `connection_end` has passed since the
+/// client of the connection was updated to `packet_proof_height`, as recorded
+/// by the client update metadata of the host.
+///
+/// Both the delay period and the number of blocks it corresponds to, as given
+/// by [`ValidationContext::block_delay`], must have elapsed on the host. The
+/// packet handlers call it through
+/// [`ProofSpec::with_delay`](crate::verify::ProofSpec::with_delay).
 pub fn verify_conn_delay_passed<Ctx>(
     ctx: &Ctx,
     packet_proof_height: Height,
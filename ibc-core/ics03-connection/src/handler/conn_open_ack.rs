@@ -1,6 +1,5 @@
 //! Protocol logic specific to processing ICS3 messages of type `MsgConnectionOpenAck`.
 
-use ibc_core_client::context::prelude::*;
 use ibc_core_client::types::error::ClientError;
 use ibc_core_connection_types::error::ConnectionError;
 use ibc_core_connection_types::events::OpenAck;
@@ -8,18 +7,22 @@ use ibc_core_connection_types::msgs::MsgConnectionOpenAck;
 use ibc_core_connection_types::{ConnectionEnd, Counterparty, State};
 use ibc_core_handler_types::error::ContextError;
 use ibc_core_handler_types::events::{IbcEvent, MessageEvent};
-use ibc_core_host::types::identifiers::ClientId;
+use ibc_core_host::types::identifiers::{ClientId, ConnectionId};
 use ibc_core_host::types::path::{ClientConsensusStatePath, ClientStatePath, ConnectionPath, Path};
 use ibc_core_host::{ExecutionContext, ValidationContext};
 use ibc_primitives::prelude::*;
 use ibc_primitives::proto::{Any, Protobuf};
 use ibc_primitives::ToVec;
 
+use crate::verify::{verify_counterparty_state, ProofSpec};
+
 pub fn validate<Ctx>(ctx_a: &Ctx, msg: MsgConnectionOpenAck) -> Result<(), ContextError>
 where
     Ctx: ValidationContext,
     <Ctx::HostClientState as TryFrom<Any>>::Error: Into<ClientError>,
 {
+    msg.validate_basic()?;
+
     let vars = LocalVars::new(ctx_a, &msg)?;
     validate_impl(ctx_a, &msg, &vars)
 }
@@ -35,6 +38,14 @@ where
 {
     ctx_a.validate_message_signer(&msg.signer)?;
 
+    // Counterparty identifiers not of the form `connection-{counter}` are only
+    // accepted if the host opts in.
+    ConnectionId::from_str_with_mode(
+        msg.conn_id_on_b.as_str(),
+        ctx_a.counterparty_identifier_mode(),
+    )
+    .map_err(ConnectionError::InvalidIdentifier)?;
+
     let host_height = ctx_a.host_height().map_err(|_| ConnectionError::Other {
         description: "failed to get host height".to_string(),
     })?;
@@ -46,8 +57,6 @@ where
         .into());
     }
 
-    let client_val_ctx_a = ctx_a.get_client_validation_context();
-
     let client_state_of_a_on_b =
         Ctx::HostClientState::try_from(msg.client_state_of_a_on_b.clone()).map_err(Into::into)?;
 
@@ -58,26 +67,13 @@ where
 
     vars.conn_end_on_a.verify_state_matches(&State::Init)?;
 
+    vars.conn_end_on_a
+        .counterparty()
+        .verify_connection_id_matches(&msg.conn_id_on_b)?;
+
     // Proof verification.
     {
-        let client_state_of_b_on_a = client_val_ctx_a.client_state(vars.client_id_on_a())?;
-
-        client_state_of_b_on_a
-            .status(client_val_ctx_a, vars.client_id_on_a())?
-            .verify_is_active()?;
-        client_state_of_b_on_a.validate_proof_height(msg.proofs_height_on_b)?;
-
-        let client_cons_state_path_on_a = ClientConsensusStatePath::new(
-            vars.client_id_on_a().clone(),
-            msg.proofs_height_on_b.revision_number(),
-            msg.proofs_height_on_b.revision_height(),
-        );
-
-        let consensus_state_of_b_on_a =
-            client_val_ctx_a.consensus_state(&client_cons_state_path_on_a)?;
-
         let prefix_on_a = ctx_a.commitment_prefix();
-        let prefix_on_b = vars.conn_end_on_a.counterparty().prefix();
 
         {
             let expected_conn_end_on_b = ConnectionEnd::new(
@@ -92,29 +88,36 @@ where
                 vars.conn_end_on_a.delay_period(),
             )?;
 
-            client_state_of_b_on_a
-                .verify_membership(
-                    prefix_on_b,
+            verify_counterparty_state(
+                ctx_a,
+                ProofSpec::membership(
+                    &vars.conn_end_on_a,
                     &msg.proof_conn_end_on_b,
-                    consensus_state_of_b_on_a.root(),
+                    msg.proofs_height_on_b,
                     Path::Connection(ConnectionPath::new(&msg.conn_id_on_b)),
                     expected_conn_end_on_b.encode_vec(),
-                )
-                .map_err(ConnectionError::VerifyConnectionState)?;
+                ),
+                |e| ConnectionError::VerifyConnectionState(e).into(),
+            )?;
         }
 
-        client_state_of_b_on_a
-            .verify_membership(
-                prefix_on_b,
+        verify_counterparty_state(
+            ctx_a,
+            ProofSpec::membership(
+                &vars.conn_end_on_a,
                 &msg.proof_client_state_of_a_on_b,
-                consensus_state_of_b_on_a.root(),
+                msg.proofs_height_on_b,
                 Path::ClientState(ClientStatePath::new(vars.client_id_on_b().clone())),
                 msg.client_state_of_a_on_b.to_vec(),
-            )
-            .map_err(|e| ConnectionError::ClientStateVerificationFailure {
-                client_id: vars.client_id_on_b().clone(),
-                client_error: e,
-            })?;
+            ),
+            |e| {
+                ConnectionError::ClientStateVerificationFailure {
+                    client_id: vars.client_id_on_b().clone(),
+                    client_error: e,
+                }
+                .into()
+            },
+        )?;
 
         let expected_consensus_state_of_a_on_b =
             ctx_a.host_consensus_state(&msg.consensus_height_of_a_on_b)?;
@@ -125,18 +128,23 @@ where
             msg.consensus_height_of_a_on_b.revision_height(),
         );
 
-        client_state_of_b_on_a
-            .verify_membership(
-                prefix_on_b,
+        verify_counterparty_state(
+            ctx_a,
+            ProofSpec::membership(
+                &vars.conn_end_on_a,
                 &msg.proof_consensus_state_of_a_on_b,
-                consensus_state_of_b_on_a.root(),
+                msg.proofs_height_on_b,
                 Path::ClientConsensusState(client_cons_state_path_on_b),
                 expected_consensus_state_of_a_on_b.into().to_vec(),
-            )
-            .map_err(|e| ConnectionError::ConsensusStateVerificationFailure {
-                height: msg.proofs_height_on_b,
-                client_error: e,
-            })?;
+            ),
+            |e| {
+                ConnectionError::ConsensusStateVerificationFailure {
+                    height: msg.proofs_height_on_b,
+                    client_error: e,
+                }
+                .into()
+            },
+        )?;
     }
 
     Ok(())
@@ -1,6 +1,5 @@
 //! Protocol logic specific to processing ICS3 messages of type `MsgConnectionOpenConfirm`.
 
-use ibc_core_client::context::prelude::*;
 use ibc_core_connection_types::error::ConnectionError;
 use ibc_core_connection_types::events::OpenConfirm;
 use ibc_core_connection_types::msgs::MsgConnectionOpenConfirm;
@@ -8,15 +7,19 @@ use ibc_core_connection_types::{ConnectionEnd, Counterparty, State};
 use ibc_core_handler_types::error::ContextError;
 use ibc_core_handler_types::events::{IbcEvent, MessageEvent};
 use ibc_core_host::types::identifiers::{ClientId, ConnectionId};
-use ibc_core_host::types::path::{ClientConsensusStatePath, ConnectionPath, Path};
+use ibc_core_host::types::path::{ConnectionPath, Path};
 use ibc_core_host::{ExecutionContext, ValidationContext};
 use ibc_primitives::prelude::*;
 use ibc_primitives::proto::Protobuf;
 
+use crate::verify::{verify_counterparty_state, ProofSpec};
+
 pub fn validate<Ctx>(ctx_b: &Ctx, msg: &MsgConnectionOpenConfirm) -> Result<(), ContextError>
 where
     Ctx: ValidationContext,
 {
+    msg.validate_basic()?;
+
     let vars = LocalVars::new(ctx_b, msg)?;
     validate_impl(ctx_b, msg, &vars)
 }
@@ -41,24 +44,6 @@ where
 
     // Verify proofs
     {
-        let client_val_ctx_b = ctx_b.get_client_validation_context();
-
-        let client_state_of_a_on_b = client_val_ctx_b.client_state(client_id_on_b)?;
-
-        client_state_of_a_on_b
-            .status(client_val_ctx_b, client_id_on_b)?
-            .verify_is_active()?;
-        client_state_of_a_on_b.validate_proof_height(msg.proof_height_on_a)?;
-
-        let client_cons_state_path_on_b = ClientConsensusStatePath::new(
-            client_id_on_b.clone(),
-            msg.proof_height_on_a.revision_number(),
-            msg.proof_height_on_a.revision_height(),
-        );
-        let consensus_state_of_a_on_b =
-            client_val_ctx_b.consensus_state(&client_cons_state_path_on_b)?;
-
-        let prefix_on_a = conn_end_on_b.counterparty().prefix();
         let prefix_on_b = ctx_b.commitment_prefix();
 
         let expected_conn_end_on_a = ConnectionEnd::new(
@@ -73,15 +58,17 @@ where
             conn_end_on_b.delay_period(),
         )?;
 
-        client_state_of_a_on_b
-            .verify_membership(
-                prefix_on_a,
+        verify_counterparty_state(
+            ctx_b,
+            ProofSpec::membership(
+                conn_end_on_b,
                 &msg.proof_conn_end_on_a,
-                consensus_state_of_a_on_b.root(),
+                msg.proof_height_on_a,
                 Path::Connection(ConnectionPath::new(conn_id_on_a)),
                 expected_conn_end_on_a.encode_vec(),
-            )
-            .map_err(ConnectionError::VerifyConnectionState)?;
+            ),
+            |e| ConnectionError::VerifyConnectionState(e).into(),
+        )?;
     }
 
     Ok(())
@@ -14,6 +14,8 @@ pub fn validate<Ctx>(ctx_a: &Ctx, msg: MsgConnectionOpenInit) -> Result<(), Cont
 where
     Ctx: ValidationContext,
 {
+    msg.validate_basic()?;
+
     ctx_a.validate_message_signer(&msg.signer)?;
 
     let client_val_ctx_a = ctx_a.get_client_validation_context();
@@ -1,5 +1,4 @@
 //! Protocol logic specific to processing ICS3 messages of type `MsgConnectionOpenTry`.;
-use ibc_core_client::context::prelude::*;
 use ibc_core_client::types::error::ClientError;
 use ibc_core_connection_types::error::ConnectionError;
 use ibc_core_connection_types::events::OpenTry;
@@ -16,11 +15,15 @@ use ibc_primitives::prelude::*;
 use ibc_primitives::proto::{Any, Protobuf};
 use ibc_primitives::ToVec;
 
+use crate::verify::{verify_counterparty_state, ProofSpec};
+
 pub fn validate<Ctx>(ctx_b: &Ctx, msg: MsgConnectionOpenTry) -> Result<(), ContextError>
 where
     Ctx: ValidationContext,
     <Ctx::HostClientState as TryFrom<Any>>::Error: Into<ClientError>,
 {
+    msg.validate_basic()?;
+
     let vars = LocalVars::new(ctx_b, &msg)?;
     validate_impl(ctx_b, &msg, &vars)
 }
@@ -36,7 +39,15 @@ where
 {
     ctx_b.validate_message_signer(&msg.signer)?;
 
-    let client_val_ctx_b = ctx_b.get_client_validation_context();
+    if let Some(conn_id_on_a) = msg.counterparty.connection_id() {
+        // Counterparty identifiers not of the form `connection-{counter}` are
+        // only accepted if the host opts in.
+        ConnectionId::from_str_with_mode(
+            conn_id_on_a.as_str(),
+            ctx_b.counterparty_identifier_mode(),
+        )
+        .map_err(ConnectionError::InvalidIdentifier)?;
+    }
 
     let client_state_of_b_on_a =
         Ctx::HostClientState::try_from(msg.client_state_of_b_on_a.clone()).map_err(Into::into)?;
@@ -59,24 +70,6 @@ where
 
     // Verify proofs
     {
-        let client_state_of_a_on_b =
-            client_val_ctx_b.client_state(vars.conn_end_on_b.client_id())?;
-
-        client_state_of_a_on_b
-            .status(client_val_ctx_b, &msg.client_id_on_b)?
-            .verify_is_active()?;
-        client_state_of_a_on_b.validate_proof_height(msg.proofs_height_on_a)?;
-
-        let client_cons_state_path_on_b = ClientConsensusStatePath::new(
-            msg.client_id_on_b.clone(),
-            msg.proofs_height_on_a.revision_number(),
-            msg.proofs_height_on_a.revision_height(),
-        );
-
-        let consensus_state_of_a_on_b =
-            client_val_ctx_b.consensus_state(&client_cons_state_path_on_b)?;
-
-        let prefix_on_a = vars.conn_end_on_b.counterparty().prefix();
         let prefix_on_b = ctx_b.commitment_prefix();
 
         {
@@ -88,29 +81,36 @@ where
                 msg.delay_period,
             )?;
 
-            client_state_of_a_on_b
-                .verify_membership(
-                    prefix_on_a,
+            verify_counterparty_state(
+                ctx_b,
+                ProofSpec::membership(
+                    &vars.conn_end_on_b,
                     &msg.proof_conn_end_on_a,
-                    consensus_state_of_a_on_b.root(),
+                    msg.proofs_height_on_a,
                     Path::Connection(ConnectionPath::new(&vars.conn_id_on_a)),
                     expected_conn_end_on_a.encode_vec(),
-                )
-                .map_err(ConnectionError::VerifyConnectionState)?;
+                ),
+                |e| ConnectionError::VerifyConnectionState(e).into(),
+            )?;
         }
 
-        client_state_of_a_on_b
-            .verify_membership(
-                prefix_on_a,
+        verify_counterparty_state(
+            ctx_b,
+            ProofSpec::membership(
+                &vars.conn_end_on_b,
                 &msg.proof_client_state_of_b_on_a,
-                consensus_state_of_a_on_b.root(),
+                msg.proofs_height_on_a,
                 Path::ClientState(ClientStatePath::new(client_id_on_a.clone())),
                 msg.client_state_of_b_on_a.to_vec(),
-            )
-            .map_err(|e| ConnectionError::ClientStateVerificationFailure {
-                client_id: msg.client_id_on_b.clone(),
-                client_error: e,
-            })?;
+            ),
+            |e| {
+                ConnectionError::ClientStateVerificationFailure {
+                    client_id: msg.client_id_on_b.clone(),
+                    client_error: e,
+                }
+                .into()
+            },
+        )?;
 
         let expected_consensus_state_of_b_on_a =
             ctx_b.host_consensus_state(&msg.consensus_height_of_b_on_a)?;
@@ -121,18 +121,23 @@ where
             msg.consensus_height_of_b_on_a.revision_height(),
         );
 
-        client_state_of_a_on_b
-            .verify_membership(
-                prefix_on_a,
+        verify_counterparty_state(
+            ctx_b,
+            ProofSpec::membership(
+                &vars.conn_end_on_b,
                 &msg.proof_consensus_state_of_b_on_a,
-                consensus_state_of_a_on_b.root(),
+                msg.proofs_height_on_a,
                 Path::ClientConsensusState(client_cons_state_path_on_a),
                 expected_consensus_state_of_b_on_a.into().to_vec(),
-            )
-            .map_err(|e| ConnectionError::ConsensusStateVerificationFailure {
-                height: msg.proofs_height_on_a,
-                client_error: e,
-            })?;
+            ),
+            |e| {
+                ConnectionError::ConsensusStateVerificationFailure {
+                    height: msg.proofs_height_on_a,
+                    client_error: e,
+                }
+                .into()
+            },
+        )?;
     }
 
     Ok(())
@@ -0,0 +1,191 @@
+//! Defines the common verification steps of the counterparty state performed by
+//! the ICS-03 and ICS-04 handlers.
+//!
+//! The last of these steps, which verifies the proofs against a client, is
+//! also exposed on its own by [`verify_state`] and [`verify_connection_state`],
+//! for auditors and alternative handlers to verify the counterparty state the
+//! same way the handlers do.
+
+use ibc_core_client::context::prelude::*;
+use ibc_core_client::types::error::ClientError;
+use ibc_core_client::types::Height;
+use ibc_core_commitment_types::commitment::{
+    CommitmentPrefix, CommitmentProofBytes, CommitmentRoot,
+};
+use ibc_core_connection_types::ConnectionEnd;
+use ibc_core_handler_types::error::ContextError;
+use ibc_core_host::types::identifiers::ConnectionId;
+use ibc_core_host::types::path::{ClientConsensusStatePath, ConnectionPath, Path};
+use ibc_core_host::ValidationContext;
+use ibc_primitives::prelude::*;
+use ibc_primitives::proto::Protobuf;
+
+use crate::delay::verify_conn_delay_passed;
+
+/// Specifies a piece of the counterparty state to be verified against the
+/// client of a connection end on the host chain.
+pub struct ProofSpec<'a> {
+    /// The connection end on the host chain, whose client tracks the
+    /// counterparty chain.
+    pub conn_end: &'a ConnectionEnd,
+    /// The proof of the counterparty state.
+    pub proof: &'a CommitmentProofBytes,
+    /// The height of the counterparty chain at which the proof was generated.
+    pub proof_height: Height,
+    /// The path under which the counterparty state is stored.
+    pub path: Path,
+    /// The value expected to be stored under `path`, or `None` to verify that
+    /// nothing is stored under it.
+    pub value: Option<Vec<u8>>,
+    /// Whether the delay period of the connection must have passed since the
+    /// client was updated to `proof_height`, as required for packet proofs.
+    pub verify_delay: bool,
+}
+
+impl<'a> ProofSpec<'a> {
+    /// Specifies that `value` is stored under `path` on the counterparty.
+    pub fn membership(
+        conn_end: &'a ConnectionEnd,
+        proof: &'a CommitmentProofBytes,
+        proof_height: Height,
+        path: Path,
+        value: Vec<u8>,
+    ) -> Self {
+        Self {
+            conn_end,
+            proof,
+            proof_height,
+            path,
+            value: Some(value),
+            verify_delay: false,
+        }
+    }
+
+    /// Specifies that nothing is stored under `path` on the counterparty.
+    pub fn non_membership(
+        conn_end: &'a ConnectionEnd,
+        proof: &'a CommitmentProofBytes,
+        proof_height: Height,
+        path: Path,
+    ) -> Self {
+        Self {
+            conn_end,
+            proof,
+            proof_height,
+            path,
+            value: None,
+            verify_delay: false,
+        }
+    }
+
+    /// Additionally requires the delay period of the connection to have passed.
+    pub fn with_delay(self) -> Self {
+        Self {
+            verify_delay: true,
+            ..self
+        }
+    }
+}
+
+/// Verifies the counterparty state specified by `proof_spec`, which consists of:
+///
+/// 1. fetching the client of the connection end and checking that it is active,
+/// 2. validating the proof height and fetching the consensus state at it,
+/// 3. verifying the connection delay has passed, if requested,
+/// 4. verifying the (non-)membership proof under the counterparty prefix.
+///
+/// Failures of the last step are converted with `map_err`, letting handlers
+/// report them with their own error variants.
+pub fn verify_counterparty_state<Ctx, F>(
+    ctx: &Ctx,
+    proof_spec: ProofSpec<'_>,
+    map_err: F,
+) -> Result<(), ContextError>
+where
+    Ctx: ValidationContext,
+    F: FnOnce(ClientError) -> ContextError,
+{
+    let ProofSpec {
+        conn_end,
+        proof,
+        proof_height,
+        path,
+        value,
+        verify_delay,
+    } = proof_spec;
+
+    let client_id = conn_end.client_id();
+    let client_val_ctx = ctx.get_client_validation_context();
+    let client_state = client_val_ctx.client_state(client_id)?;
+
+    client_state
+        .status(client_val_ctx, client_id)?
+        .verify_is_active()?;
+
+    client_state.validate_proof_height(proof_height)?;
+
+    let client_cons_state_path = ClientConsensusStatePath::new(
+        client_id.clone(),
+        proof_height.revision_number(),
+        proof_height.revision_height(),
+    );
+    let consensus_state = client_val_ctx.consensus_state(&client_cons_state_path)?;
+
+    if verify_delay {
+        verify_conn_delay_passed(ctx, proof_height, conn_end)?;
+    }
+
+    verify_state(
+        &client_state,
+        conn_end.counterparty().prefix(),
+        proof,
+        consensus_state.root(),
+        path,
+        value,
+    )
+    .map_err(map_err)
+}
+
+/// Verifies, against the given client state and the commitment root of one of
+/// its consensus states, that `value` is stored under `path` in the store of
+/// the counterparty committed to under `prefix`, or that nothing is stored
+/// under it if `value` is `None`.
+pub fn verify_state<C>(
+    client_state: &C,
+    prefix: &CommitmentPrefix,
+    proof: &CommitmentProofBytes,
+    root: &CommitmentRoot,
+    path: Path,
+    value: Option<Vec<u8>>,
+) -> Result<(), ClientError>
+where
+    C: ClientStateCommon,
+{
+    match value {
+        Some(value) => client_state.verify_membership(prefix, proof, root, path, value),
+        None => client_state.verify_non_membership(prefix, proof, root, path),
+    }
+}
+
+/// Verifies that the counterparty stores `expected_conn_end` as the connection
+/// end of `conn_id`, as [`verify_state`] does.
+pub fn verify_connection_state<C>(
+    client_state: &C,
+    prefix: &CommitmentPrefix,
+    proof: &CommitmentProofBytes,
+    root: &CommitmentRoot,
+    conn_id: &ConnectionId,
+    expected_conn_end: ConnectionEnd,
+) -> Result<(), ClientError>
+where
+    C: ClientStateCommon,
+{
+    verify_state(
+        client_state,
+        prefix,
+        proof,
+        root,
+        Path::Connection(ConnectionPath::new(conn_id)),
+        Some(expected_conn_end.encode_vec()),
+    )
+}
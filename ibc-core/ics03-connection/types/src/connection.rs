@@ -6,7 +6,7 @@ use core::u64;
 
 use ibc_core_client_types::error::ClientError;
 use ibc_core_commitment_types::commitment::CommitmentPrefix;
-use ibc_core_host_types::identifiers::{ClientId, ConnectionId};
+use ibc_core_host_types::identifiers::{ClientId, ConnectionId, IdentifierMode};
 use ibc_primitives::prelude::*;
 use ibc_proto::ibc::core::connection::v1::{
     ConnectionEnd as RawConnectionEnd, Counterparty as RawCounterparty,
@@ -381,11 +381,14 @@ impl TryFrom<RawCounterparty> for Counterparty {
         let connection_id: Option<ConnectionId> = if raw_counterparty.connection_id.is_empty() {
             None
         } else {
+            // The counterparty assigns its own identifiers, which need not be
+            // of the form `connection-{counter}`.
             Some(
-                raw_counterparty
-                    .connection_id
-                    .parse()
-                    .map_err(ConnectionError::InvalidIdentifier)?,
+                ConnectionId::from_str_with_mode(
+                    &raw_counterparty.connection_id,
+                    IdentifierMode::Lenient,
+                )
+                .map_err(ConnectionError::InvalidIdentifier)?,
             )
         };
         Ok(Counterparty::new(
@@ -431,6 +434,40 @@ impl Counterparty {
         }
     }
 
+    /// Strict counterpart of [`Counterparty::new`], which rejects invalid
+    /// client and connection identifiers, as well as malformed commitment prefixes, i.e.
+    /// empty or not valid UTF-8.
+    pub fn try_new(
+        client_id: ClientId,
+        connection_id: Option<ConnectionId>,
+        prefix: CommitmentPrefix,
+    ) -> Result<Self, ConnectionError> {
+        // Identifiers are not validated when deserialized, so parse them again.
+        client_id
+            .as_str()
+            .parse::<ClientId>()
+            .map_err(ConnectionError::InvalidIdentifier)?;
+
+        if let Some(connection_id) = &connection_id {
+            connection_id
+                .as_str()
+                .parse::<ConnectionId>()
+                .map_err(ConnectionError::InvalidIdentifier)?;
+        }
+
+        if prefix.as_bytes().is_empty() {
+            return Err(ConnectionError::Client(ClientError::EmptyPrefix));
+        }
+
+        if core::str::from_utf8(prefix.as_bytes()).is_err() {
+            return Err(ConnectionError::MalformedCounterpartyPrefix {
+                prefix: prefix.into_vec(),
+            });
+        }
+
+        Ok(Self::new(client_id, connection_id, prefix))
+    }
+
     /// Getter for the client id.
     pub fn client_id(&self) -> &ClientId {
         &self.client_id
@@ -453,6 +490,23 @@ impl Counterparty {
         }
         Ok(())
     }
+
+    /// Verifies that the counterparty connection id, if already set, matches
+    /// the one given by the counterparty chain.
+    pub fn verify_connection_id_matches(
+        &self,
+        connection_id: &ConnectionId,
+    ) -> Result<(), ConnectionError> {
+        match self.connection_id() {
+            Some(expected) if expected != connection_id => {
+                Err(ConnectionError::MismatchedCounterpartyConnectionId {
+                    expected: expected.clone(),
+                    actual: connection_id.clone(),
+                })
+            }
+            _ => Ok(()),
+        }
+    }
 }
 
 #[cfg_attr(
@@ -5,7 +5,7 @@ use ibc_core_client_types::{error as client_error, Height};
 use ibc_core_host_types::error::IdentifierError;
 use ibc_core_host_types::identifiers::{ClientId, ConnectionId};
 use ibc_primitives::prelude::*;
-use ibc_primitives::{Timestamp, TimestampOverflowError};
+use ibc_primitives::{AbciError, Timestamp, TimestampOverflowError};
 
 use crate::version::Version;
 
@@ -54,6 +54,13 @@ pub enum ConnectionError {
     InvalidCounterparty,
     /// missing counterparty
     MissingCounterparty,
+    /// mismatched counterparty connection id: expected `{expected}`, actual `{actual}`
+    MismatchedCounterpartyConnectionId {
+        expected: ConnectionId,
+        actual: ConnectionId,
+    },
+    /// malformed counterparty commitment prefix `{prefix:?}`
+    MalformedCounterpartyPrefix { prefix: Vec<u8> },
     /// missing client state
     MissingClientState,
     /// the consensus proof verification failed (height: `{height}`), client error: `{client_error}`
@@ -87,6 +94,51 @@ pub enum ConnectionError {
     Other { description: String },
 }
 
+impl AbciError for ConnectionError {
+    fn codespace(&self) -> &'static str {
+        match self {
+            Self::Client(e) => e.codespace(),
+            _ => "connection",
+        }
+    }
+
+    fn code(&self) -> u32 {
+        match self {
+            Self::Client(e) => e.code(),
+            Self::InvalidState { .. } => 2,
+            Self::InvalidConsensusHeight { .. } => 3,
+            Self::InvalidIdentifier(_) => 4,
+            Self::EmptyProtoConnectionEnd => 5,
+            Self::EmptyVersions => 6,
+            Self::InvalidVersionLength => 7,
+            Self::VersionNotSupported { .. } => 8,
+            Self::NoCommonVersion => 9,
+            Self::EmptyFeatures => 10,
+            Self::FeatureNotSupported { .. } => 11,
+            Self::NoCommonFeatures => 12,
+            Self::MissingProofHeight => 13,
+            Self::MissingConsensusHeight => 14,
+            Self::InvalidProof => 15,
+            Self::VerifyConnectionState(_) => 16,
+            Self::InvalidSigner { .. } => 17,
+            Self::ConnectionNotFound { .. } => 18,
+            Self::InvalidCounterparty => 19,
+            Self::MissingCounterparty => 20,
+            Self::MismatchedCounterpartyConnectionId { .. } => 21,
+            Self::MalformedCounterpartyPrefix { .. } => 22,
+            Self::MissingClientState => 23,
+            Self::ConsensusStateVerificationFailure { .. } => 24,
+            Self::ClientStateVerificationFailure { .. } => 25,
+            Self::InvalidClientState { .. } => 26,
+            Self::NotEnoughBlocksElapsed { .. } => 27,
+            Self::NotEnoughTimeElapsed { .. } => 28,
+            Self::TimestampOverflow(_) => 29,
+            Self::CounterOverflow => 30,
+            Self::Other { .. } => 31,
+        }
+    }
+}
+
 #[cfg(feature = "std")]
 impl std::error::Error for ConnectionError {
     fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
@@ -1,8 +1,8 @@
 use ibc_core_client_types::Height;
 use ibc_core_commitment_types::commitment::CommitmentProofBytes;
-use ibc_core_host_types::identifiers::ConnectionId;
+use ibc_core_host_types::identifiers::{ConnectionId, IdentifierMode};
 use ibc_primitives::prelude::*;
-use ibc_primitives::Signer;
+use ibc_primitives::{Msg, Signer};
 use ibc_proto::google::protobuf::Any;
 use ibc_proto::ibc::core::connection::v1::MsgConnectionOpenAck as RawMsgConnectionOpenAck;
 use ibc_proto::Protobuf;
@@ -20,6 +20,11 @@ pub const CONN_OPEN_ACK_TYPE_URL: &str = "/ibc.core.connection.v1.MsgConnectionO
 )]
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "builder",
+    derive(typed_builder::TypedBuilder),
+    builder(field_defaults(setter(into)))
+)]
 pub struct MsgConnectionOpenAck {
     /// ConnectionId that chain A has chosen for it's ConnectionEnd
     pub conn_id_on_a: ConnectionId,
@@ -41,9 +46,40 @@ pub struct MsgConnectionOpenAck {
     pub signer: Signer,
     /// optional proof of host state machines (chain A) that are unable to
     /// introspect their own consensus state
+    #[cfg_attr(feature = "builder", builder(default))]
     pub proof_consensus_state_of_a: Option<CommitmentProofBytes>,
 }
 
+impl MsgConnectionOpenAck {
+    /// Performs the stateless validation of the message, without access to the host state.
+    pub fn validate_basic(&self) -> Result<(), ConnectionError> {
+        if self.client_state_of_a_on_b.type_url.is_empty() {
+            return Err(ConnectionError::MissingClientState);
+        }
+        if self.proof_conn_end_on_b.is_empty() {
+            return Err(ConnectionError::InvalidProof);
+        }
+        if self.proof_client_state_of_a_on_b.is_empty() {
+            return Err(ConnectionError::InvalidProof);
+        }
+        if self.proof_consensus_state_of_a_on_b.is_empty() {
+            return Err(ConnectionError::InvalidProof);
+        }
+        if self.signer.is_empty() {
+            return Err(ConnectionError::InvalidSigner {
+                reason: "signer cannot be empty".to_string(),
+            });
+        }
+        Ok(())
+    }
+}
+
+impl Msg for MsgConnectionOpenAck {
+    fn signer(&self) -> &Signer {
+        &self.signer
+    }
+}
+
 impl Protobuf<RawMsgConnectionOpenAck> for MsgConnectionOpenAck {}
 
 impl TryFrom<RawMsgConnectionOpenAck> for MsgConnectionOpenAck {
@@ -55,10 +91,11 @@ impl TryFrom<RawMsgConnectionOpenAck> for MsgConnectionOpenAck {
                 .connection_id
                 .parse()
                 .map_err(ConnectionError::InvalidIdentifier)?,
-            conn_id_on_b: msg
-                .counterparty_connection_id
-                .parse()
-                .map_err(ConnectionError::InvalidIdentifier)?,
+            conn_id_on_b: ConnectionId::from_str_with_mode(
+                &msg.counterparty_connection_id,
+                IdentifierMode::Lenient,
+            )
+            .map_err(ConnectionError::InvalidIdentifier)?,
             client_state_of_a_on_b: msg
                 .client_state
                 .ok_or(ConnectionError::MissingClientState)?,
@@ -2,7 +2,7 @@ use core::time::Duration;
 
 use ibc_core_host_types::identifiers::ClientId;
 use ibc_primitives::prelude::*;
-use ibc_primitives::Signer;
+use ibc_primitives::{Msg, Signer};
 use ibc_proto::ibc::core::connection::v1::MsgConnectionOpenInit as RawMsgConnectionOpenInit;
 use ibc_proto::Protobuf;
 
@@ -16,10 +16,16 @@ pub const CONN_OPEN_INIT_TYPE_URL: &str = "/ibc.core.connection.v1.MsgConnection
 /// The handler will check proofs of chain B.
 #[derive(Clone, Debug, PartialEq, Eq)]
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(
+    feature = "builder",
+    derive(typed_builder::TypedBuilder),
+    builder(field_defaults(setter(into)))
+)]
 pub struct MsgConnectionOpenInit {
     /// ClientId on chain A that the connection is being opened for
     pub client_id_on_a: ClientId,
     pub counterparty: Counterparty,
+    #[cfg_attr(feature = "builder", builder(default))]
     pub version: Option<Version>,
     pub delay_period: Duration,
     pub signer: Signer,
@@ -81,6 +87,25 @@ mod borsh_impls {
     }
 }
 
+impl MsgConnectionOpenInit {
+    /// Performs the stateless validation of the message, without access to the host state.
+    pub fn validate_basic(&self) -> Result<(), ConnectionError> {
+        self.counterparty.verify_empty_connection_id()?;
+        if self.signer.is_empty() {
+            return Err(ConnectionError::InvalidSigner {
+                reason: "signer cannot be empty".to_string(),
+            });
+        }
+        Ok(())
+    }
+}
+
+impl Msg for MsgConnectionOpenInit {
+    fn signer(&self) -> &Signer {
+        &self.signer
+    }
+}
+
 impl Protobuf<RawMsgConnectionOpenInit> for MsgConnectionOpenInit {}
 
 impl TryFrom<RawMsgConnectionOpenInit> for MsgConnectionOpenInit {
@@ -4,7 +4,7 @@ use ibc_core_client_types::Height;
 use ibc_core_commitment_types::commitment::CommitmentProofBytes;
 use ibc_core_host_types::identifiers::ClientId;
 use ibc_primitives::prelude::*;
-use ibc_primitives::Signer;
+use ibc_primitives::{Msg, Signer};
 use ibc_proto::google::protobuf::Any;
 use ibc_proto::ibc::core::connection::v1::MsgConnectionOpenTry as RawMsgConnectionOpenTry;
 use ibc_proto::Protobuf;
@@ -19,6 +19,11 @@ pub const CONN_OPEN_TRY_TYPE_URL: &str = "/ibc.core.connection.v1.MsgConnectionO
 /// The handler will check proofs of chain A.
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "builder",
+    derive(typed_builder::TypedBuilder),
+    builder(field_defaults(setter(into)))
+)]
 pub struct MsgConnectionOpenTry {
     /// ClientId on B that the connection is being opened for
     pub client_id_on_b: ClientId,
@@ -42,10 +47,12 @@ pub struct MsgConnectionOpenTry {
     pub signer: Signer,
     /// optional proof of host state machines (chain B) that are unable to
     /// introspect their own consensus state
+    #[cfg_attr(feature = "builder", builder(default))]
     pub proof_consensus_state_of_b: Option<CommitmentProofBytes>,
 
     #[deprecated(since = "0.22.0")]
     /// Only kept here for proper conversion to/from the raw type
+    #[cfg_attr(feature = "builder", builder(default, setter(skip)))]
     pub previous_connection_id: String,
 }
 
@@ -141,6 +148,42 @@ mod borsh_impls {
     }
 }
 
+impl MsgConnectionOpenTry {
+    /// Performs the stateless validation of the message, without access to the host state.
+    pub fn validate_basic(&self) -> Result<(), ConnectionError> {
+        if self.counterparty.connection_id().is_none() {
+            return Err(ConnectionError::InvalidCounterparty);
+        }
+        if self.client_state_of_b_on_a.type_url.is_empty() {
+            return Err(ConnectionError::MissingClientState);
+        }
+        if self.versions_on_a.is_empty() {
+            return Err(ConnectionError::EmptyVersions);
+        }
+        if self.proof_conn_end_on_a.is_empty() {
+            return Err(ConnectionError::InvalidProof);
+        }
+        if self.proof_client_state_of_b_on_a.is_empty() {
+            return Err(ConnectionError::InvalidProof);
+        }
+        if self.proof_consensus_state_of_b_on_a.is_empty() {
+            return Err(ConnectionError::InvalidProof);
+        }
+        if self.signer.is_empty() {
+            return Err(ConnectionError::InvalidSigner {
+                reason: "signer cannot be empty".to_string(),
+            });
+        }
+        Ok(())
+    }
+}
+
+impl Msg for MsgConnectionOpenTry {
+    fn signer(&self) -> &Signer {
+        &self.signer
+    }
+}
+
 impl Protobuf<RawMsgConnectionOpenTry> for MsgConnectionOpenTry {}
 
 impl TryFrom<RawMsgConnectionOpenTry> for MsgConnectionOpenTry {
@@ -4,6 +4,20 @@
 //! Each such message comprises the same fields as the datagrams defined in ICS3 English spec:
 //! <https://github.com/cosmos/ibc/tree/master/spec/core/ics-003-connection-semantics>.
 //!
+//! With the `builder` feature enabled, each message can also be constructed
+//! through its `builder()`, whose `build()` method only compiles once all the
+//! required fields of the message are set, e.g.:
+//!
+//! ```ignore
+//! let msg = MsgConnectionOpenConfirm::builder()
+//!     .conn_id_on_b(conn_id)
+//!     .proof_conn_end_on_a(proof)
+//!     .proof_height_on_a(proof_height)
+//!     .signer(signer)
+//!     .build();
+//! msg.validate_basic()?;
+//! ```
+//!
 //! One departure from ICS3 is that we abstract the three counterparty fields (connection id,
 //! prefix, and client id) into a single field of type `Counterparty`; this applies to messages
 //! `MsgConnectionOpenInit` and `MsgConnectionOpenTry`. One other difference with regards to
@@ -13,6 +27,9 @@
 //! `signer` which is specific to Cosmos-SDK.
 
 use ibc_primitives::prelude::*;
+use ibc_primitives::{Msg, Signer};
+
+use crate::error::ConnectionError;
 
 mod conn_open_ack;
 mod conn_open_confirm;
@@ -37,3 +54,26 @@ pub enum ConnectionMsg {
     OpenAck(MsgConnectionOpenAck),
     OpenConfirm(MsgConnectionOpenConfirm),
 }
+
+impl ConnectionMsg {
+    /// Performs the stateless validation of the wrapped message.
+    pub fn validate_basic(&self) -> Result<(), ConnectionError> {
+        match self {
+            ConnectionMsg::OpenInit(msg) => msg.validate_basic(),
+            ConnectionMsg::OpenTry(msg) => msg.validate_basic(),
+            ConnectionMsg::OpenAck(msg) => msg.validate_basic(),
+            ConnectionMsg::OpenConfirm(msg) => msg.validate_basic(),
+        }
+    }
+}
+
+impl Msg for ConnectionMsg {
+    fn signer(&self) -> &Signer {
+        match self {
+            ConnectionMsg::OpenInit(msg) => msg.signer(),
+            ConnectionMsg::OpenTry(msg) => msg.signer(),
+            ConnectionMsg::OpenAck(msg) => msg.signer(),
+            ConnectionMsg::OpenConfirm(msg) => msg.signer(),
+        }
+    }
+}
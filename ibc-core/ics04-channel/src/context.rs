@@ -26,6 +26,11 @@ pub trait SendPacketValidationContext {
 
     fn get_next_sequence_send(&self, seq_send_path: &SeqSendPath)
         -> Result<Sequence, ContextError>;
+
+    /// See [`ValidationContext::is_channel_frozen`].
+    fn is_channel_frozen(&self, _channel_end_path: &ChannelEndPath) -> Result<bool, ContextError> {
+        Ok(false)
+    }
 }
 
 impl<T> SendPacketValidationContext for T
@@ -52,6 +57,10 @@ where
     ) -> Result<Sequence, ContextError> {
         self.get_next_sequence_send(seq_send_path)
     }
+
+    fn is_channel_frozen(&self, channel_end_path: &ChannelEndPath) -> Result<bool, ContextError> {
+        self.is_channel_frozen(channel_end_path)
+    }
 }
 
 /// Methods required in send packet execution, to be implemented by the host
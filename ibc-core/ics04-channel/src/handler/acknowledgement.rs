@@ -3,14 +3,11 @@ use ibc_core_channel_types::commitment::{compute_ack_commitment, compute_packet_
 use ibc_core_channel_types::error::{ChannelError, PacketError};
 use ibc_core_channel_types::events::AcknowledgePacket;
 use ibc_core_channel_types::msgs::MsgAcknowledgement;
-use ibc_core_client::context::prelude::*;
-use ibc_core_connection::delay::verify_conn_delay_passed;
 use ibc_core_connection::types::State as ConnectionState;
+use ibc_core_connection::verify::{verify_counterparty_state, ProofSpec};
 use ibc_core_handler_types::error::ContextError;
 use ibc_core_handler_types::events::{IbcEvent, MessageEvent};
-use ibc_core_host::types::path::{
-    AckPath, ChannelEndPath, ClientConsensusStatePath, CommitmentPath, Path, SeqAckPath,
-};
+use ibc_core_host::types::path::{AckPath, ChannelEndPath, CommitmentPath, Path, SeqAckPath};
 use ibc_core_host::{ExecutionContext, ValidationContext};
 use ibc_core_router::module::Module;
 use ibc_primitives::prelude::*;
@@ -48,6 +45,8 @@ where
         msg.packet.clone(),
         chan_end_on_a.ordering,
         conn_id_on_a.clone(),
+        &msg.acknowledgement,
+        msg.signer.clone(),
     ));
     ctx_a.emit_ibc_event(IbcEvent::Message(MessageEvent::Channel))?;
     ctx_a.emit_ibc_event(event)?;
@@ -107,12 +106,22 @@ fn validate<Ctx>(ctx_a: &Ctx, msg: &MsgAcknowledgement) -> Result<(), ContextErr
 where
     Ctx: ValidationContext,
 {
+    msg.validate_basic()?;
+
     ctx_a.validate_message_signer(&msg.signer)?;
 
     let packet = &msg.packet;
     let chan_end_path_on_a = ChannelEndPath::new(&packet.port_id_on_a, &packet.chan_id_on_a);
     let chan_end_on_a = ctx_a.channel_end(&chan_end_path_on_a)?;
 
+    if ctx_a.is_channel_frozen(&chan_end_path_on_a)? {
+        return Err(ChannelError::FrozenChannel {
+            port_id: packet.port_id_on_a.clone(),
+            channel_id: packet.chan_id_on_a.clone(),
+        }
+        .into());
+    }
+
     chan_end_on_a.verify_state_matches(&ChannelState::Open)?;
 
     let counterparty = Counterparty::new(
@@ -166,44 +175,29 @@ where
 
     // Verify proofs
     {
-        let client_id_on_a = conn_end_on_a.client_id();
-
-        let client_val_ctx_a = ctx_a.get_client_validation_context();
-
-        let client_state_of_b_on_a = client_val_ctx_a.client_state(client_id_on_a)?;
-
-        client_state_of_b_on_a
-            .status(ctx_a.get_client_validation_context(), client_id_on_a)?
-            .verify_is_active()?;
-        client_state_of_b_on_a.validate_proof_height(msg.proof_height_on_b)?;
-
-        let client_cons_state_path_on_a = ClientConsensusStatePath::new(
-            client_id_on_a.clone(),
-            msg.proof_height_on_b.revision_number(),
-            msg.proof_height_on_b.revision_height(),
-        );
-        let consensus_state_of_b_on_a =
-            client_val_ctx_a.consensus_state(&client_cons_state_path_on_a)?;
         let ack_commitment = compute_ack_commitment(&msg.acknowledgement);
         let ack_path_on_b =
             AckPath::new(&packet.port_id_on_b, &packet.chan_id_on_b, packet.seq_on_a);
 
-        verify_conn_delay_passed(ctx_a, msg.proof_height_on_b, &conn_end_on_a)?;
-
         // Verify the proof for the packet against the chain store.
-        client_state_of_b_on_a
-            .verify_membership(
-                conn_end_on_a.counterparty().prefix(),
+        verify_counterparty_state(
+            ctx_a,
+            ProofSpec::membership(
+                &conn_end_on_a,
                 &msg.proof_acked_on_b,
-                consensus_state_of_b_on_a.root(),
+                msg.proof_height_on_b,
                 Path::Ack(ack_path_on_b),
                 ack_commitment.into_vec(),
             )
-            .map_err(|e| ChannelError::PacketVerificationFailed {
-                sequence: packet.seq_on_a,
-                client_error: e,
-            })
-            .map_err(PacketError::Channel)?;
+            .with_delay(),
+            |e| {
+                PacketError::Channel(ChannelError::PacketVerificationFailed {
+                    sequence: packet.seq_on_a,
+                    client_error: e,
+                })
+                .into()
+            },
+        )?;
     }
 
     Ok(())
@@ -4,7 +4,6 @@ use ibc_core_channel_types::error::ChannelError;
 use ibc_core_channel_types::events::CloseInit;
 use ibc_core_channel_types::msgs::MsgChannelCloseInit;
 use ibc_core_client::context::prelude::*;
-use ibc_core_connection::types::State as ConnectionState;
 use ibc_core_handler_types::error::ContextError;
 use ibc_core_handler_types::events::{IbcEvent, MessageEvent};
 use ibc_core_host::types::path::ChannelEndPath;
@@ -12,6 +11,8 @@ use ibc_core_host::{ExecutionContext, ValidationContext};
 use ibc_core_router::module::Module;
 use ibc_primitives::prelude::*;
 
+use super::{cleanup_channel_state, open_connection_hop_end};
+
 pub fn chan_close_init_validate<ValCtx>(
     ctx_a: &ValCtx,
     module: &dyn Module,
@@ -48,6 +49,8 @@ where
         };
 
         ctx_a.store_channel(&chan_end_path_on_a, chan_end_on_a)?;
+
+        cleanup_channel_state(ctx_a, &chan_end_path_on_a)?;
     }
 
     // emit events and logs
@@ -94,6 +97,8 @@ fn validate<Ctx>(ctx_a: &Ctx, msg: &MsgChannelCloseInit) -> Result<(), ContextEr
 where
     Ctx: ValidationContext,
 {
+    msg.validate_basic()?;
+
     ctx_a.validate_message_signer(&msg.signer)?;
 
     let chan_end_path_on_a = ChannelEndPath::new(&msg.port_id_on_a, &msg.chan_id_on_a);
@@ -103,11 +108,7 @@ where
     chan_end_on_a.verify_not_closed()?;
 
     // An OPEN IBC connection running on the local (host) chain should exist.
-    chan_end_on_a.verify_connection_hops_length()?;
-
-    let conn_end_on_a = ctx_a.connection_end(&chan_end_on_a.connection_hops()[0])?;
-
-    conn_end_on_a.verify_state_matches(&ConnectionState::Open)?;
+    let conn_end_on_a = open_connection_hop_end(ctx_a, chan_end_on_a.connection_hops())?;
 
     let client_id_on_a = conn_end_on_a.client_id();
 
@@ -3,16 +3,18 @@ use ibc_core_channel_types::channel::{ChannelEnd, Counterparty, State, State as
 use ibc_core_channel_types::error::ChannelError;
 use ibc_core_channel_types::events::OpenAck;
 use ibc_core_channel_types::msgs::MsgChannelOpenAck;
-use ibc_core_client::context::prelude::*;
-use ibc_core_connection::types::State as ConnectionState;
+use ibc_core_connection::verify::{verify_counterparty_state, ProofSpec};
 use ibc_core_handler_types::error::ContextError;
 use ibc_core_handler_types::events::{IbcEvent, MessageEvent};
-use ibc_core_host::types::path::{ChannelEndPath, ClientConsensusStatePath, Path};
+use ibc_core_host::types::identifiers::ChannelId;
+use ibc_core_host::types::path::{ChannelEndPath, Path};
 use ibc_core_host::{ExecutionContext, ValidationContext};
 use ibc_core_router::module::Module;
 use ibc_primitives::prelude::*;
 use ibc_primitives::proto::Protobuf;
 
+use super::open_connection_hop_end;
+
 pub fn chan_open_ack_validate<ValCtx>(
     ctx_a: &ValCtx,
     module: &dyn Module,
@@ -90,40 +92,33 @@ fn validate<Ctx>(ctx_a: &Ctx, msg: &MsgChannelOpenAck) -> Result<(), ContextErro
 where
     Ctx: ValidationContext,
 {
+    msg.validate_basic()?;
+
     ctx_a.validate_message_signer(&msg.signer)?;
 
+    // Counterparty identifiers not of the form `channel-{counter}` are only
+    // accepted if the host opts in.
+    ChannelId::from_str_with_mode(
+        msg.chan_id_on_b.as_str(),
+        ctx_a.counterparty_identifier_mode(),
+    )
+    .map_err(ChannelError::InvalidIdentifier)?;
+
     let chan_end_path_on_a = ChannelEndPath::new(&msg.port_id_on_a, &msg.chan_id_on_a);
     let chan_end_on_a = ctx_a.channel_end(&chan_end_path_on_a)?;
 
     // Validate that the channel end is in a state where it can be ack.
     chan_end_on_a.verify_state_matches(&ChannelState::Init)?;
 
-    // An OPEN IBC connection running on the local (host) chain should exist.
-    chan_end_on_a.verify_connection_hops_length()?;
+    chan_end_on_a
+        .counterparty()
+        .verify_channel_id_matches(&msg.chan_id_on_b)?;
 
-    let conn_end_on_a = ctx_a.connection_end(&chan_end_on_a.connection_hops()[0])?;
-
-    conn_end_on_a.verify_state_matches(&ConnectionState::Open)?;
+    // An OPEN IBC connection running on the local (host) chain should exist.
+    let conn_end_on_a = open_connection_hop_end(ctx_a, chan_end_on_a.connection_hops())?;
 
     // Verify proofs
     {
-        let client_id_on_a = conn_end_on_a.client_id();
-        let client_val_ctx_a = ctx_a.get_client_validation_context();
-        let client_state_of_b_on_a = client_val_ctx_a.client_state(client_id_on_a)?;
-
-        client_state_of_b_on_a
-            .status(ctx_a.get_client_validation_context(), client_id_on_a)?
-            .verify_is_active()?;
-        client_state_of_b_on_a.validate_proof_height(msg.proof_height_on_b)?;
-
-        let client_cons_state_path_on_a = ClientConsensusStatePath::new(
-            client_id_on_a.clone(),
-            msg.proof_height_on_b.revision_number(),
-            msg.proof_height_on_b.revision_height(),
-        );
-        let consensus_state_of_b_on_a =
-            client_val_ctx_a.consensus_state(&client_cons_state_path_on_a)?;
-        let prefix_on_b = conn_end_on_a.counterparty().prefix();
         let port_id_on_b = &chan_end_on_a.counterparty().port_id;
         let conn_id_on_b = conn_end_on_a.counterparty().connection_id().ok_or(
             ChannelError::UndefinedConnectionCounterparty {
@@ -144,15 +139,17 @@ where
 
         // Verify the proof for the channel state against the expected channel end.
         // A counterparty channel id of None in not possible, and is checked by validate_basic in msg.
-        client_state_of_b_on_a
-            .verify_membership(
-                prefix_on_b,
+        verify_counterparty_state(
+            ctx_a,
+            ProofSpec::membership(
+                &conn_end_on_a,
                 &msg.proof_chan_end_on_b,
-                consensus_state_of_b_on_a.root(),
+                msg.proof_height_on_b,
                 Path::ChannelEnd(chan_end_path_on_b),
                 expected_chan_end_on_b.encode_vec(),
-            )
-            .map_err(ChannelError::VerifyChannelFailed)?;
+            ),
+            |e| ChannelError::VerifyChannelFailed(e).into(),
+        )?;
     }
 
     Ok(())
@@ -12,6 +12,8 @@ use ibc_core_host::{ExecutionContext, ValidationContext};
 use ibc_core_router::module::Module;
 use ibc_primitives::prelude::*;
 
+use super::connection_hop_end;
+
 pub fn chan_open_init_validate<ValCtx>(
     ctx_a: &ValCtx,
     module: &dyn Module,
@@ -62,7 +64,7 @@ where
             msg.ordering,
             Counterparty::new(msg.port_id_on_b.clone(), None),
             msg.connection_hops_on_a.clone(),
-            msg.version_proposal.clone(),
+            version.clone(),
         )?;
         let chan_end_path_on_a = ChannelEndPath::new(&msg.port_id_on_a, &chan_id_on_a);
         ctx_a.store_channel(&chan_end_path_on_a, chan_end_on_a)?;
@@ -111,11 +113,19 @@ fn validate<Ctx>(ctx_a: &Ctx, msg: &MsgChannelOpenInit) -> Result<(), ContextErr
 where
     Ctx: ValidationContext,
 {
+    msg.validate_basic()?;
+
     ctx_a.validate_message_signer(&msg.signer)?;
 
-    msg.verify_connection_hops_length()?;
+    ctx_a.authorize_chan_open_init(
+        &msg.signer,
+        &msg.port_id_on_a,
+        &msg.connection_hops_on_a,
+        &Counterparty::new(msg.port_id_on_b.clone(), None),
+    )?;
+
     // An IBC connection running on the local (host) chain should exist.
-    let conn_end_on_a = ctx_a.connection_end(&msg.connection_hops_on_a[0])?;
+    let conn_end_on_a = connection_hop_end(ctx_a, &msg.connection_hops_on_a)?;
 
     // Note: Not needed check if the connection end is OPEN. Optimistic channel handshake is allowed.
 
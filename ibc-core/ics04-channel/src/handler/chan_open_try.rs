@@ -4,19 +4,18 @@ use ibc_core_channel_types::channel::{ChannelEnd, Counterparty, State as Channel
 use ibc_core_channel_types::error::ChannelError;
 use ibc_core_channel_types::events::OpenTry;
 use ibc_core_channel_types::msgs::MsgChannelOpenTry;
-use ibc_core_client::context::prelude::*;
-use ibc_core_connection::types::State as ConnectionState;
+use ibc_core_connection::verify::{verify_counterparty_state, ProofSpec};
 use ibc_core_handler_types::error::ContextError;
 use ibc_core_handler_types::events::{IbcEvent, MessageEvent};
 use ibc_core_host::types::identifiers::ChannelId;
-use ibc_core_host::types::path::{
-    ChannelEndPath, ClientConsensusStatePath, Path, SeqAckPath, SeqRecvPath, SeqSendPath,
-};
+use ibc_core_host::types::path::{ChannelEndPath, Path, SeqAckPath, SeqRecvPath, SeqSendPath};
 use ibc_core_host::{ExecutionContext, ValidationContext};
 use ibc_core_router::module::Module;
 use ibc_primitives::prelude::*;
 use ibc_primitives::proto::Protobuf;
 
+use super::open_connection_hop_end;
+
 pub fn chan_open_try_validate<ValCtx>(
     ctx_b: &ValCtx,
     module: &dyn Module,
@@ -119,13 +118,20 @@ fn validate<Ctx>(ctx_b: &Ctx, msg: &MsgChannelOpenTry) -> Result<(), ContextErro
 where
     Ctx: ValidationContext,
 {
-    ctx_b.validate_message_signer(&msg.signer)?;
+    msg.validate_basic()?;
 
-    msg.verify_connection_hops_length()?;
+    ctx_b.validate_message_signer(&msg.signer)?;
 
-    let conn_end_on_b = ctx_b.connection_end(&msg.connection_hops_on_b[0])?;
+    // Counterparty identifiers not of the form `channel-{counter}` are only
+    // accepted if the host opts in.
+    ChannelId::from_str_with_mode(
+        msg.chan_id_on_a.as_str(),
+        ctx_b.counterparty_identifier_mode(),
+    )
+    .map_err(ChannelError::InvalidIdentifier)?;
 
-    conn_end_on_b.verify_state_matches(&ConnectionState::Open)?;
+    // An OPEN IBC connection running on the local (host) chain should exist.
+    let conn_end_on_b = open_connection_hop_end(ctx_b, &msg.connection_hops_on_b)?;
 
     let conn_version = conn_end_on_b.versions();
 
@@ -133,24 +139,6 @@ where
 
     // Verify proofs
     {
-        let client_id_on_b = conn_end_on_b.client_id();
-        let client_val_ctx_b = ctx_b.get_client_validation_context();
-        let client_state_of_a_on_b = client_val_ctx_b.client_state(client_id_on_b)?;
-
-        client_state_of_a_on_b
-            .status(ctx_b.get_client_validation_context(), client_id_on_b)?
-            .verify_is_active()?;
-
-        client_state_of_a_on_b.validate_proof_height(msg.proof_height_on_a)?;
-
-        let client_cons_state_path_on_b = ClientConsensusStatePath::new(
-            client_id_on_b.clone(),
-            msg.proof_height_on_a.revision_number(),
-            msg.proof_height_on_a.revision_height(),
-        );
-        let consensus_state_of_a_on_b =
-            client_val_ctx_b.consensus_state(&client_cons_state_path_on_b)?;
-        let prefix_on_a = conn_end_on_b.counterparty().prefix();
         let port_id_on_a = msg.port_id_on_a.clone();
         let chan_id_on_a = msg.chan_id_on_a.clone();
         let conn_id_on_a = conn_end_on_b.counterparty().connection_id().ok_or(
@@ -170,15 +158,17 @@ where
 
         // Verify the proof for the channel state against the expected channel end.
         // A counterparty channel id of None in not possible, and is checked by validate_basic in msg.
-        client_state_of_a_on_b
-            .verify_membership(
-                prefix_on_a,
+        verify_counterparty_state(
+            ctx_b,
+            ProofSpec::membership(
+                &conn_end_on_b,
                 &msg.proof_chan_end_on_a,
-                consensus_state_of_a_on_b.root(),
+                msg.proof_height_on_a,
                 Path::ChannelEnd(chan_end_path_on_a),
                 expected_chan_end_on_a.encode_vec(),
-            )
-            .map_err(ChannelError::VerifyChannelFailed)?;
+            ),
+            |e| ChannelError::VerifyChannelFailed(e).into(),
+        )?;
     }
 
     Ok(())
@@ -0,0 +1,61 @@
+//! Deletion of the packet state of closed channels.
+
+use ibc_core_channel_types::channel::State;
+use ibc_core_channel_types::events::ChannelCleanup;
+use ibc_core_handler_types::error::ContextError;
+use ibc_core_handler_types::events::IbcEvent;
+use ibc_core_host::types::path::{AckPath, ChannelEndPath};
+use ibc_core_host::ExecutionContext;
+use ibc_primitives::prelude::*;
+
+/// Deletes the packet acknowledgements stored for the given channel end, as
+/// listed by [`ValidationContext::packet_acknowledgement_sequences`], and
+/// emits a [`ChannelCleanup`] event if any was deleted.
+///
+/// The channel end must be closed, which is the case once it was closed by a
+/// `MsgChannelCloseInit`, a `MsgChannelCloseConfirm` or the timeout of a
+/// packet sent on an ordered channel, upon which this is called. A
+/// counterparty which is still open can still prove the deleted
+/// acknowledgements at the heights at which they were stored.
+///
+/// The packet commitments and receipts are kept: the commitments are needed
+/// to time out, and thus refund, the packets still in flight, while the
+/// absence of a receipt is what proves to the counterparty that a packet was
+/// not received, so that deleting one would let the packet both be received
+/// and time out.
+///
+/// [`ValidationContext::packet_acknowledgement_sequences`]: ibc_core_host::ValidationContext::packet_acknowledgement_sequences
+pub fn cleanup_channel_state<ExecCtx>(
+    ctx: &mut ExecCtx,
+    chan_end_path: &ChannelEndPath,
+) -> Result<(), ContextError>
+where
+    ExecCtx: ExecutionContext,
+{
+    let chan_end = ctx.channel_end(chan_end_path)?;
+    chan_end.verify_state_matches(&State::Closed)?;
+
+    let sequences = ctx.packet_acknowledgement_sequences(chan_end_path)?;
+
+    if sequences.is_empty() {
+        return Ok(());
+    }
+
+    for seq in &sequences {
+        ctx.delete_packet_acknowledgement(&AckPath::new(&chan_end_path.0, &chan_end_path.1, *seq))?;
+    }
+
+    ctx.log_message(format!(
+        "success: deleted {} packet acknowledgements of closed channel {}/{}",
+        sequences.len(),
+        chan_end_path.0,
+        chan_end_path.1
+    ))?;
+    ctx.emit_ibc_event(IbcEvent::ChannelCleanup(ChannelCleanup::new(
+        chan_end_path.0.clone(),
+        chan_end_path.1.clone(),
+        sequences.len() as u64,
+    )))?;
+
+    Ok(())
+}
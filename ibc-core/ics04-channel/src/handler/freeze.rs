@@ -0,0 +1,74 @@
+//! Freezing of the channels of the host, e.g. to quarantine a compromised
+//! counterparty without halting all of IBC.
+
+use ibc_core_channel_types::events::{ChannelFrozen, ChannelUnfrozen};
+use ibc_core_handler_types::error::ContextError;
+use ibc_core_handler_types::events::IbcEvent;
+use ibc_core_host::types::path::ChannelEndPath;
+use ibc_core_host::ExecutionContext;
+use ibc_primitives::prelude::*;
+
+/// Freezes the given channel end, and emits a [`ChannelFrozen`] event unless
+/// it was already frozen.
+///
+/// The packets of a frozen channel end are no longer sent, received nor
+/// acknowledged, the handlers failing with
+/// [`ChannelError::FrozenChannel`](ibc_core_channel_types::error::ChannelError::FrozenChannel),
+/// while its packets in flight may still time out. Hosts call this outside
+/// of the handling of any message, e.g. upon a governance proposal, and the
+/// channel end must exist.
+pub fn freeze_channel<ExecCtx>(
+    ctx: &mut ExecCtx,
+    chan_end_path: &ChannelEndPath,
+) -> Result<(), ContextError>
+where
+    ExecCtx: ExecutionContext,
+{
+    ctx.channel_end(chan_end_path)?;
+
+    if ctx.is_channel_frozen(chan_end_path)? {
+        return Ok(());
+    }
+
+    ctx.store_channel_frozen(chan_end_path, true)?;
+
+    ctx.log_message(format!(
+        "success: froze channel {}/{}",
+        chan_end_path.0, chan_end_path.1
+    ))?;
+    ctx.emit_ibc_event(IbcEvent::ChannelFrozen(ChannelFrozen::new(
+        chan_end_path.0.clone(),
+        chan_end_path.1.clone(),
+    )))?;
+
+    Ok(())
+}
+
+/// Unfreezes the given channel end, and emits a [`ChannelUnfrozen`] event
+/// unless it was not frozen.
+pub fn unfreeze_channel<ExecCtx>(
+    ctx: &mut ExecCtx,
+    chan_end_path: &ChannelEndPath,
+) -> Result<(), ContextError>
+where
+    ExecCtx: ExecutionContext,
+{
+    ctx.channel_end(chan_end_path)?;
+
+    if !ctx.is_channel_frozen(chan_end_path)? {
+        return Ok(());
+    }
+
+    ctx.store_channel_frozen(chan_end_path, false)?;
+
+    ctx.log_message(format!(
+        "success: unfroze channel {}/{}",
+        chan_end_path.0, chan_end_path.1
+    ))?;
+    ctx.emit_ibc_event(IbcEvent::ChannelUnfrozen(ChannelUnfrozen::new(
+        chan_end_path.0.clone(),
+        chan_end_path.1.clone(),
+    )))?;
+
+    Ok(())
+}
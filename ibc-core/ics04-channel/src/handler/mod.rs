@@ -6,10 +6,13 @@ mod chan_open_ack;
 mod chan_open_confirm;
 mod chan_open_init;
 mod chan_open_try;
+mod cleanup;
+mod freeze;
 mod recv_packet;
 mod send_packet;
 mod timeout;
 mod timeout_on_close;
+mod write_acknowledgement;
 
 pub use acknowledgement::*;
 pub use chan_close_confirm::*;
@@ -18,7 +21,53 @@ pub use chan_open_ack::*;
 pub use chan_open_confirm::*;
 pub use chan_open_init::*;
 pub use chan_open_try::*;
+pub use cleanup::*;
+pub use freeze::*;
+use ibc_core_channel_types::channel::verify_connection_hops_length;
+use ibc_core_channel_types::error::ChannelError;
+use ibc_core_connection::types::ConnectionEnd;
+use ibc_core_handler_types::error::ContextError;
+use ibc_core_host::types::identifiers::ConnectionId;
+use ibc_core_host::ValidationContext;
 pub use recv_packet::*;
 pub use send_packet::*;
 pub use timeout::*;
 pub use timeout_on_close::*;
+pub use write_acknowledgement::*;
+
+/// Checks that a channel runs over a single connection hop, as multi-hop
+/// channels are not supported yet, and returns the end of that connection,
+/// which must exist on the host.
+fn connection_hop_end<Ctx>(
+    ctx: &Ctx,
+    connection_hops: &[ConnectionId],
+) -> Result<ConnectionEnd, ContextError>
+where
+    Ctx: ValidationContext,
+{
+    verify_connection_hops_length(connection_hops, 1)?;
+
+    ctx.connection_end(&connection_hops[0])
+}
+
+/// Same as [`connection_hop_end`], additionally checking that the connection
+/// is OPEN.
+fn open_connection_hop_end<Ctx>(
+    ctx: &Ctx,
+    connection_hops: &[ConnectionId],
+) -> Result<ConnectionEnd, ContextError>
+where
+    Ctx: ValidationContext,
+{
+    let conn_end = connection_hop_end(ctx, connection_hops)?;
+
+    if !conn_end.is_open() {
+        return Err(ChannelError::ConnectionHopNotOpen {
+            connection_id: connection_hops[0].clone(),
+            state: *conn_end.state(),
+        }
+        .into());
+    }
+
+    Ok(conn_end)
+}
@@ -1,23 +1,25 @@
+use ibc_core_channel_types::acknowledgement::AcknowledgementStatus;
 use ibc_core_channel_types::channel::{Counterparty, Order, State as ChannelState};
-use ibc_core_channel_types::commitment::{compute_ack_commitment, compute_packet_commitment};
+use ibc_core_channel_types::commitment::compute_packet_commitment;
 use ibc_core_channel_types::error::{ChannelError, PacketError};
-use ibc_core_channel_types::events::{ReceivePacket, WriteAcknowledgement};
+use ibc_core_channel_types::events::ReceivePacket;
 use ibc_core_channel_types::msgs::MsgRecvPacket;
 use ibc_core_channel_types::packet::Receipt;
-use ibc_core_client::context::prelude::*;
-use ibc_core_connection::delay::verify_conn_delay_passed;
 use ibc_core_connection::types::State as ConnectionState;
+use ibc_core_connection::verify::{verify_counterparty_state, ProofSpec};
 use ibc_core_handler_types::error::ContextError;
 use ibc_core_handler_types::events::{IbcEvent, MessageEvent};
 use ibc_core_host::types::path::{
-    AckPath, ChannelEndPath, ClientConsensusStatePath, CommitmentPath, Path, ReceiptPath,
-    SeqRecvPath,
+    AckPath, ChannelEndPath, CommitmentPath, Path, ReceiptPath, SeqRecvPath,
 };
 use ibc_core_host::{ExecutionContext, ValidationContext};
-use ibc_core_router::module::Module;
+use ibc_core_router::module::{Module, OnRecvPacketResult};
+use ibc_core_router::types::module::ModuleExtras;
 use ibc_primitives::prelude::*;
 use ibc_primitives::Expiry;
 
+use super::{send_outgoing_packet, write_acknowledgement_execute};
+
 pub fn recv_packet_validate<ValCtx>(ctx_b: &ValCtx, msg: MsgRecvPacket) -> Result<(), ContextError>
 where
     ValCtx: ValidationContext,
@@ -29,10 +31,21 @@ where
     // If any error occurs, then an "error acknowledgement" must be returned.
 }
 
+/// Executes the `MsgRecvPacket`.
+///
+/// If the application callback fails, a standardized error acknowledgement is
+/// written in place of the one of the application, with the error description
+/// omitted when `redact_error_acks` is set.
+///
+/// The core handler has no view of the application state, and so cannot
+/// discard the writes of a failing callback: applications must be atomic,
+/// i.e. only fail before committing any state changes, as the transaction is
+/// not reverted.
 pub fn recv_packet_execute<ExecCtx>(
     ctx_b: &mut ExecCtx,
     module: &mut dyn Module,
     msg: MsgRecvPacket,
+    redact_error_acks: bool,
 ) -> Result<(), ContextError>
 where
     ExecCtx: ExecutionContext,
@@ -69,7 +82,21 @@ where
         }
     }
 
-    let (extras, acknowledgement) = module.on_recv_packet_execute(&msg.packet, &msg.signer);
+    let OnRecvPacketResult {
+        ack,
+        extras,
+        packets,
+    } = match module.on_recv_packet_execute(&msg.packet, &msg.signer) {
+        Ok(result) => result,
+        Err(app_err) => {
+            ctx_b.log_message(format!("failure: application callback: {app_err}"))?;
+
+            let description = (!redact_error_acks).then_some(app_err.description.as_str());
+            let ack_status = AcknowledgementStatus::abci_error(app_err.code, description);
+
+            OnRecvPacketResult::sync(ModuleExtras::empty(), ack_status.into())
+        }
+    };
 
     // state changes
     {
@@ -92,39 +119,31 @@ where
             }
             _ => {}
         }
-        let ack_path_on_b = AckPath::new(
-            &msg.packet.port_id_on_b,
-            &msg.packet.chan_id_on_b,
-            msg.packet.seq_on_a,
-        );
-        // `writeAcknowledgement` handler state changes
-        ctx_b.store_packet_acknowledgement(
-            &ack_path_on_b,
-            compute_ack_commitment(&acknowledgement),
-        )?;
     }
 
     // emit events and logs
     {
         ctx_b.log_message("success: packet receive".to_string())?;
-        ctx_b.log_message("success: packet write acknowledgement".to_string())?;
 
         let conn_id_on_b = &chan_end_on_b.connection_hops()[0];
         let event = IbcEvent::ReceivePacket(ReceivePacket::new(
             msg.packet.clone(),
             chan_end_on_b.ordering,
             conn_id_on_b.clone(),
+            msg.signer.clone(),
         ));
         ctx_b.emit_ibc_event(IbcEvent::Message(MessageEvent::Channel))?;
         ctx_b.emit_ibc_event(event)?;
-        let event = IbcEvent::WriteAcknowledgement(WriteAcknowledgement::new(
-            msg.packet,
-            acknowledgement,
-            conn_id_on_b.clone(),
-        ));
-        ctx_b.emit_ibc_event(IbcEvent::Message(MessageEvent::Channel))?;
-        ctx_b.emit_ibc_event(event)?;
+    }
 
+    // `writeAcknowledgement` handler state changes, events and logs, unless
+    // the application acknowledges the packet asynchronously
+    if let Some(acknowledgement) = ack {
+        write_acknowledgement_execute(ctx_b, msg.packet, acknowledgement)?;
+    }
+
+    // emit module events and logs
+    {
         for module_event in extras.events {
             ctx_b.emit_ibc_event(IbcEvent::Module(module_event))?;
         }
@@ -134,6 +153,12 @@ where
         }
     }
 
+    // `sendPacket` handler state changes, events and logs, for each packet the
+    // application sends in response
+    for packet in packets {
+        send_outgoing_packet(ctx_b, packet)?;
+    }
+
     Ok(())
 }
 
@@ -141,12 +166,22 @@ fn validate<Ctx>(ctx_b: &Ctx, msg: &MsgRecvPacket) -> Result<(), ContextError>
 where
     Ctx: ValidationContext,
 {
+    msg.validate_basic()?;
+
     ctx_b.validate_message_signer(&msg.signer)?;
 
     let chan_end_path_on_b =
         ChannelEndPath::new(&msg.packet.port_id_on_b, &msg.packet.chan_id_on_b);
     let chan_end_on_b = ctx_b.channel_end(&chan_end_path_on_b)?;
 
+    if ctx_b.is_channel_frozen(&chan_end_path_on_b)? {
+        return Err(ChannelError::FrozenChannel {
+            port_id: msg.packet.port_id_on_b.clone(),
+            channel_id: msg.packet.chan_id_on_b.clone(),
+        }
+        .into());
+    }
+
     chan_end_on_b.verify_state_matches(&ChannelState::Open)?;
 
     let counterparty = Counterparty::new(
@@ -177,25 +212,6 @@ where
 
     // Verify proofs
     {
-        let client_id_on_b = conn_end_on_b.client_id();
-        let client_val_ctx_b = ctx_b.get_client_validation_context();
-        let client_state_of_a_on_b = client_val_ctx_b.client_state(client_id_on_b)?;
-
-        client_state_of_a_on_b
-            .status(ctx_b.get_client_validation_context(), client_id_on_b)?
-            .verify_is_active()?;
-
-        client_state_of_a_on_b.validate_proof_height(msg.proof_height_on_a)?;
-
-        let client_cons_state_path_on_b = ClientConsensusStatePath::new(
-            client_id_on_b.clone(),
-            msg.proof_height_on_a.revision_number(),
-            msg.proof_height_on_a.revision_height(),
-        );
-
-        let consensus_state_of_a_on_b =
-            client_val_ctx_b.consensus_state(&client_cons_state_path_on_b)?;
-
         let expected_commitment_on_a = compute_packet_commitment(
             &msg.packet.data,
             &msg.packet.timeout_height_on_b,
@@ -207,22 +223,25 @@ where
             msg.packet.seq_on_a,
         );
 
-        verify_conn_delay_passed(ctx_b, msg.proof_height_on_a, &conn_end_on_b)?;
-
         // Verify the proof for the packet against the chain store.
-        client_state_of_a_on_b
-            .verify_membership(
-                conn_end_on_b.counterparty().prefix(),
+        verify_counterparty_state(
+            ctx_b,
+            ProofSpec::membership(
+                &conn_end_on_b,
                 &msg.proof_commitment_on_a,
-                consensus_state_of_a_on_b.root(),
+                msg.proof_height_on_a,
                 Path::Commitment(commitment_path_on_a),
                 expected_commitment_on_a.into_vec(),
             )
-            .map_err(|e| ChannelError::PacketVerificationFailed {
-                sequence: msg.packet.seq_on_a,
-                client_error: e,
-            })
-            .map_err(PacketError::Channel)?;
+            .with_delay(),
+            |e| {
+                PacketError::Channel(ChannelError::PacketVerificationFailed {
+                    sequence: msg.packet.seq_on_a,
+                    client_error: e,
+                })
+                .into()
+            },
+        )?;
     }
 
     match chan_end_on_b.ordering {
@@ -1,8 +1,9 @@
 use ibc_core_channel_types::channel::Counterparty;
 use ibc_core_channel_types::commitment::compute_packet_commitment;
-use ibc_core_channel_types::error::PacketError;
+use ibc_core_channel_types::error::{ChannelError, PacketError};
 use ibc_core_channel_types::events::SendPacket;
-use ibc_core_channel_types::packet::Packet;
+use ibc_core_channel_types::packet::{OutgoingPacket, Packet};
+use ibc_core_channel_types::timeout::{Timeout, TimeoutMargins};
 use ibc_core_client::context::prelude::*;
 use ibc_core_handler_types::error::ContextError;
 use ibc_core_handler_types::events::{IbcEvent, MessageEvent};
@@ -10,7 +11,7 @@ use ibc_core_host::types::path::{
     ChannelEndPath, ClientConsensusStatePath, CommitmentPath, SeqSendPath,
 };
 use ibc_primitives::prelude::*;
-use ibc_primitives::Expiry;
+use ibc_primitives::{Expiry, Timestamp};
 
 use crate::context::{SendPacketExecutionContext, SendPacketValidationContext};
 
@@ -25,6 +26,35 @@ pub fn send_packet(
     send_packet_execute(ctx_a, packet)
 }
 
+/// Send the packet an application requested from one of its callbacks,
+/// including all necessary validation, and return the packet sent.
+///
+/// The packet is assigned the next sequence to send on its channel, and is
+/// sent to the counterparty of that channel. Packets sent one after the other
+/// are thus assigned consecutive sequences, in the order they are sent.
+pub fn send_outgoing_packet(
+    ctx_a: &mut impl SendPacketExecutionContext,
+    outgoing: OutgoingPacket,
+) -> Result<Packet, ContextError> {
+    let chan_end_path_on_a = ChannelEndPath::new(&outgoing.port_id_on_a, &outgoing.chan_id_on_a);
+    let chan_end_on_a = ctx_a.channel_end(&chan_end_path_on_a)?;
+
+    let counterparty = chan_end_on_a.counterparty();
+    let chan_id_on_b = counterparty
+        .channel_id()
+        .ok_or(ChannelError::MissingCounterparty)?
+        .clone();
+    let port_id_on_b = counterparty.port_id().clone();
+
+    let seq_send_path_on_a = SeqSendPath::new(&outgoing.port_id_on_a, &outgoing.chan_id_on_a);
+    let seq_on_a = ctx_a.get_next_sequence_send(&seq_send_path_on_a)?;
+
+    let packet = outgoing.into_packet(seq_on_a, port_id_on_b, chan_id_on_b);
+    send_packet(ctx_a, packet.clone())?;
+
+    Ok(packet)
+}
+
 /// Validate that sending the given packet would succeed.
 pub fn send_packet_validate(
     ctx_a: &impl SendPacketValidationContext,
@@ -37,6 +67,14 @@ pub fn send_packet_validate(
     let chan_end_path_on_a = ChannelEndPath::new(&packet.port_id_on_a, &packet.chan_id_on_a);
     let chan_end_on_a = ctx_a.channel_end(&chan_end_path_on_a)?;
 
+    if ctx_a.is_channel_frozen(&chan_end_path_on_a)? {
+        return Err(ChannelError::FrozenChannel {
+            port_id: packet.port_id_on_a.clone(),
+            channel_id: packet.chan_id_on_a.clone(),
+        }
+        .into());
+    }
+
     // Checks the channel end not be `Closed`.
     // This allows for optimistic packet processing before a channel opens
     chan_end_on_a.verify_not_closed()?;
@@ -99,6 +137,47 @@ pub fn send_packet_validate(
     Ok(())
 }
 
+/// Returns the recommended timeout of a packet sent on the given channel end,
+/// as computed by [`Timeout::recommended`] from the latest state of the
+/// counterparty chain known to its client on the host, and the current
+/// `host_timestamp`.
+pub fn recommended_timeout(
+    ctx_a: &impl SendPacketValidationContext,
+    chan_end_path_on_a: &ChannelEndPath,
+    host_timestamp: Timestamp,
+    margins: &TimeoutMargins,
+) -> Result<Timeout, ContextError> {
+    let chan_end_on_a = ctx_a.channel_end(chan_end_path_on_a)?;
+
+    let conn_id_on_a = &chan_end_on_a.connection_hops()[0];
+
+    let conn_end_on_a = ctx_a.connection_end(conn_id_on_a)?;
+
+    let client_id_on_a = conn_end_on_a.client_id();
+
+    let client_val_ctx_a = ctx_a.get_client_validation_context();
+
+    let latest_height_on_a = client_val_ctx_a
+        .client_state(client_id_on_a)?
+        .latest_height();
+
+    let client_cons_state_path_on_a = ClientConsensusStatePath::new(
+        client_id_on_a.clone(),
+        latest_height_on_a.revision_number(),
+        latest_height_on_a.revision_height(),
+    );
+    let latest_timestamp = client_val_ctx_a
+        .consensus_state(&client_cons_state_path_on_a)?
+        .timestamp();
+
+    Ok(Timeout::recommended(
+        latest_height_on_a,
+        latest_timestamp,
+        host_timestamp,
+        margins,
+    )?)
+}
+
 /// Send the packet without any validation.
 ///
 /// A prior call to [`send_packet_validate`] MUST have succeeded.
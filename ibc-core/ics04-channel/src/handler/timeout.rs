@@ -3,10 +3,15 @@ use ibc_core_channel_types::commitment::compute_packet_commitment;
 use ibc_core_channel_types::error::{ChannelError, PacketError};
 use ibc_core_channel_types::events::{ChannelClosed, TimeoutPacket};
 use ibc_core_channel_types::msgs::{MsgTimeout, MsgTimeoutOnClose};
+use ibc_core_channel_types::packet::Packet;
 use ibc_core_client::context::prelude::*;
-use ibc_core_connection::delay::verify_conn_delay_passed;
+use ibc_core_client::types::Height;
+use ibc_core_commitment_types::commitment::CommitmentProofBytes;
+use ibc_core_connection::types::ConnectionEnd;
+use ibc_core_connection::verify::{verify_counterparty_state, ProofSpec};
 use ibc_core_handler_types::error::ContextError;
 use ibc_core_handler_types::events::{IbcEvent, MessageEvent};
+use ibc_core_host::types::identifiers::Sequence;
 use ibc_core_host::types::path::{
     ChannelEndPath, ClientConsensusStatePath, CommitmentPath, Path, ReceiptPath, SeqRecvPath,
 };
@@ -14,7 +19,7 @@ use ibc_core_host::{ExecutionContext, ValidationContext};
 use ibc_core_router::module::Module;
 use ibc_primitives::prelude::*;
 
-use super::timeout_on_close;
+use super::{cleanup_channel_state, timeout_on_close};
 
 pub enum TimeoutMsgType {
     Timeout(MsgTimeout),
@@ -89,6 +94,8 @@ where
             chan_end_on_a.state = State::Closed;
             ctx_a.store_channel(&chan_end_path_on_a, chan_end_on_a.clone())?;
 
+            cleanup_channel_state(ctx_a, &chan_end_path_on_a)?;
+
             chan_end_on_a
         } else {
             chan_end_on_a
@@ -126,10 +133,68 @@ where
     Ok(())
 }
 
+/// Specifies the proof that `packet` was not received on the counterparty,
+/// which depends on the ordering of the channel:
+///
+/// - on an ordered channel, the packets are received in order, so that the
+///   packet was not received if the next sequence to be received,
+///   `next_seq_recv_on_b`, is not greater than its own sequence. The proof
+///   is then the one of `next_seq_recv_on_b` being stored under the
+///   `nextSequenceRecv` path of the channel.
+/// - on an unordered channel, the packet was not received if there is no
+///   receipt of it, the proof being the one of the absence of its receipt.
+///   `next_seq_recv_on_b` is then ignored.
+pub fn unreceived_packet_proof_spec<'a>(
+    conn_end_on_a: &'a ConnectionEnd,
+    ordering: Order,
+    packet: &Packet,
+    next_seq_recv_on_b: Sequence,
+    proof_unreceived_on_b: &'a CommitmentProofBytes,
+    proof_height_on_b: Height,
+) -> Result<ProofSpec<'a>, ContextError> {
+    match ordering {
+        Order::Ordered => {
+            if packet.seq_on_a < next_seq_recv_on_b {
+                return Err(PacketError::InvalidPacketSequence {
+                    given_sequence: packet.seq_on_a,
+                    next_sequence: next_seq_recv_on_b,
+                }
+                .into());
+            }
+            let seq_recv_path_on_b = SeqRecvPath::new(&packet.port_id_on_b, &packet.chan_id_on_b);
+
+            Ok(ProofSpec::membership(
+                conn_end_on_a,
+                proof_unreceived_on_b,
+                proof_height_on_b,
+                Path::SeqRecv(seq_recv_path_on_b),
+                next_seq_recv_on_b.to_vec(),
+            ))
+        }
+        Order::Unordered => {
+            let receipt_path_on_b =
+                ReceiptPath::new(&packet.port_id_on_b, &packet.chan_id_on_b, packet.seq_on_a);
+
+            Ok(ProofSpec::non_membership(
+                conn_end_on_a,
+                proof_unreceived_on_b,
+                proof_height_on_b,
+                Path::Receipt(receipt_path_on_b),
+            ))
+        }
+        Order::None => Err(ContextError::ChannelError(ChannelError::InvalidOrderType {
+            expected: "Channel ordering cannot be None".to_string(),
+            actual: ordering.to_string(),
+        })),
+    }
+}
+
 fn validate<Ctx>(ctx_a: &Ctx, msg: &MsgTimeout) -> Result<(), ContextError>
 where
     Ctx: ValidationContext,
 {
+    msg.validate_basic()?;
+
     ctx_a.validate_message_signer(&msg.signer)?;
 
     let chan_end_on_a = ctx_a.channel_end(&ChannelEndPath::new(
@@ -182,7 +247,7 @@ where
         let client_state_of_b_on_a = client_val_ctx_a.client_state(client_id_on_a)?;
 
         client_state_of_b_on_a
-            .status(ctx_a.get_client_validation_context(), client_id_on_a)?
+            .status(client_val_ctx_a, client_id_on_a)?
             .verify_is_active()?;
 
         client_state_of_b_on_a.validate_proof_height(msg.proof_height_on_b)?;
@@ -193,9 +258,9 @@ where
             msg.proof_height_on_b.revision_number(),
             msg.proof_height_on_b.revision_height(),
         );
-        let consensus_state_of_b_on_a =
-            client_val_ctx_a.consensus_state(&client_cons_state_path_on_a)?;
-        let timestamp_of_b = consensus_state_of_b_on_a.timestamp();
+        let timestamp_of_b = client_val_ctx_a
+            .consensus_state(&client_cons_state_path_on_a)?
+            .timestamp();
 
         if !msg.packet.timed_out(&timestamp_of_b, msg.proof_height_on_b) {
             return Err(PacketError::PacketTimeoutNotReached {
@@ -207,56 +272,22 @@ where
             .into());
         }
 
-        verify_conn_delay_passed(ctx_a, msg.proof_height_on_b, &conn_end_on_a)?;
-
-        let next_seq_recv_verification_result = match chan_end_on_a.ordering {
-            Order::Ordered => {
-                if msg.packet.seq_on_a < msg.next_seq_recv_on_b {
-                    return Err(PacketError::InvalidPacketSequence {
-                        given_sequence: msg.packet.seq_on_a,
-                        next_sequence: msg.next_seq_recv_on_b,
-                    }
-                    .into());
-                }
-                let seq_recv_path_on_b =
-                    SeqRecvPath::new(&msg.packet.port_id_on_b, &msg.packet.chan_id_on_b);
-
-                client_state_of_b_on_a.verify_membership(
-                    conn_end_on_a.counterparty().prefix(),
-                    &msg.proof_unreceived_on_b,
-                    consensus_state_of_b_on_a.root(),
-                    Path::SeqRecv(seq_recv_path_on_b),
-                    msg.packet.seq_on_a.to_vec(),
-                )
-            }
-            Order::Unordered => {
-                let receipt_path_on_b = ReceiptPath::new(
-                    &msg.packet.port_id_on_b,
-                    &msg.packet.chan_id_on_b,
-                    msg.packet.seq_on_a,
-                );
-
-                client_state_of_b_on_a.verify_non_membership(
-                    conn_end_on_a.counterparty().prefix(),
-                    &msg.proof_unreceived_on_b,
-                    consensus_state_of_b_on_a.root(),
-                    Path::Receipt(receipt_path_on_b),
-                )
-            }
-            Order::None => {
-                return Err(ContextError::ChannelError(ChannelError::InvalidOrderType {
-                    expected: "Channel ordering cannot be None".to_string(),
-                    actual: chan_end_on_a.ordering.to_string(),
-                }))
-            }
-        };
-
-        next_seq_recv_verification_result
-            .map_err(|e| ChannelError::PacketVerificationFailed {
+        let next_seq_recv_proof_spec = unreceived_packet_proof_spec(
+            &conn_end_on_a,
+            chan_end_on_a.ordering,
+            &msg.packet,
+            msg.next_seq_recv_on_b,
+            &msg.proof_unreceived_on_b,
+            msg.proof_height_on_b,
+        )?;
+
+        verify_counterparty_state(ctx_a, next_seq_recv_proof_spec.with_delay(), |e| {
+            PacketError::Channel(ChannelError::PacketVerificationFailed {
                 sequence: msg.next_seq_recv_on_b,
                 client_error: e,
             })
-            .map_err(PacketError::Channel)?;
+            .into()
+        })?;
     }
 
     Ok(())
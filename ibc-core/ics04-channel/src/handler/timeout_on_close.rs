@@ -1,21 +1,22 @@
-use ibc_core_channel_types::channel::{ChannelEnd, Counterparty, Order, State};
+use ibc_core_channel_types::channel::{ChannelEnd, Counterparty, State};
 use ibc_core_channel_types::commitment::compute_packet_commitment;
 use ibc_core_channel_types::error::{ChannelError, PacketError};
 use ibc_core_channel_types::msgs::MsgTimeoutOnClose;
-use ibc_core_client::context::prelude::*;
-use ibc_core_connection::delay::verify_conn_delay_passed;
+use ibc_core_connection::verify::{verify_counterparty_state, ProofSpec};
 use ibc_core_handler_types::error::ContextError;
-use ibc_core_host::types::path::{
-    ChannelEndPath, ClientConsensusStatePath, CommitmentPath, Path, ReceiptPath, SeqRecvPath,
-};
+use ibc_core_host::types::path::{ChannelEndPath, CommitmentPath, Path};
 use ibc_core_host::ValidationContext;
 use ibc_primitives::prelude::*;
 use ibc_primitives::proto::Protobuf;
 
+use super::unreceived_packet_proof_spec;
+
 pub fn validate<Ctx>(ctx_a: &Ctx, msg: &MsgTimeoutOnClose) -> Result<(), ContextError>
 where
     Ctx: ValidationContext,
 {
+    msg.validate_basic()?;
+
     ctx_a.validate_message_signer(&msg.signer)?;
 
     let packet = &msg.packet;
@@ -61,24 +62,6 @@ where
 
     // Verify proofs
     {
-        let client_id_on_a = conn_end_on_a.client_id();
-        let client_val_ctx_a = ctx_a.get_client_validation_context();
-        let client_state_of_b_on_a = client_val_ctx_a.client_state(client_id_on_a)?;
-
-        client_state_of_b_on_a
-            .status(ctx_a.get_client_validation_context(), client_id_on_a)?
-            .verify_is_active()?;
-
-        client_state_of_b_on_a.validate_proof_height(msg.proof_height_on_b)?;
-
-        let client_cons_state_path_on_a = ClientConsensusStatePath::new(
-            client_id_on_a.clone(),
-            msg.proof_height_on_b.revision_number(),
-            msg.proof_height_on_b.revision_height(),
-        );
-        let consensus_state_of_b_on_a =
-            client_val_ctx_a.consensus_state(&client_cons_state_path_on_a)?;
-        let prefix_on_b = conn_end_on_a.counterparty().prefix();
         let port_id_on_b = chan_end_on_a.counterparty().port_id.clone();
         let chan_id_on_b = chan_end_on_a
             .counterparty()
@@ -106,67 +89,34 @@ where
 
         // Verify the proof for the channel state against the expected channel end.
         // A counterparty channel id of None in not possible, and is checked by validate_basic in msg.
-        client_state_of_b_on_a
-            .verify_membership(
-                prefix_on_b,
+        verify_counterparty_state(
+            ctx_a,
+            ProofSpec::membership(
+                &conn_end_on_a,
                 &msg.proof_close_on_b,
-                consensus_state_of_b_on_a.root(),
+                msg.proof_height_on_b,
                 Path::ChannelEnd(chan_end_path_on_b),
                 expected_chan_end_on_b.encode_vec(),
-            )
-            .map_err(ChannelError::VerifyChannelFailed)
-            .map_err(PacketError::Channel)?;
-
-        verify_conn_delay_passed(ctx_a, msg.proof_height_on_b, &conn_end_on_a)?;
-
-        let next_seq_recv_verification_result = match chan_end_on_a.ordering {
-            Order::Ordered => {
-                if packet.seq_on_a < msg.next_seq_recv_on_b {
-                    return Err(PacketError::InvalidPacketSequence {
-                        given_sequence: packet.seq_on_a,
-                        next_sequence: msg.next_seq_recv_on_b,
-                    }
-                    .into());
-                }
-                let seq_recv_path_on_b =
-                    SeqRecvPath::new(&packet.port_id_on_b, &packet.chan_id_on_b);
-
-                client_state_of_b_on_a.verify_membership(
-                    conn_end_on_a.counterparty().prefix(),
-                    &msg.proof_unreceived_on_b,
-                    consensus_state_of_b_on_a.root(),
-                    Path::SeqRecv(seq_recv_path_on_b),
-                    packet.seq_on_a.to_vec(),
-                )
-            }
-            Order::Unordered => {
-                let receipt_path_on_b = ReceiptPath::new(
-                    &msg.packet.port_id_on_b,
-                    &msg.packet.chan_id_on_b,
-                    msg.packet.seq_on_a,
-                );
-
-                client_state_of_b_on_a.verify_non_membership(
-                    conn_end_on_a.counterparty().prefix(),
-                    &msg.proof_unreceived_on_b,
-                    consensus_state_of_b_on_a.root(),
-                    Path::Receipt(receipt_path_on_b),
-                )
-            }
-            Order::None => {
-                return Err(ContextError::ChannelError(ChannelError::InvalidOrderType {
-                    expected: "Channel ordering cannot be None".to_string(),
-                    actual: chan_end_on_a.ordering.to_string(),
-                }))
-            }
-        };
-
-        next_seq_recv_verification_result
-            .map_err(|e| ChannelError::PacketVerificationFailed {
+            ),
+            |e| PacketError::Channel(ChannelError::VerifyChannelFailed(e)).into(),
+        )?;
+
+        let next_seq_recv_proof_spec = unreceived_packet_proof_spec(
+            &conn_end_on_a,
+            chan_end_on_a.ordering,
+            packet,
+            msg.next_seq_recv_on_b,
+            &msg.proof_unreceived_on_b,
+            msg.proof_height_on_b,
+        )?;
+
+        verify_counterparty_state(ctx_a, next_seq_recv_proof_spec.with_delay(), |e| {
+            PacketError::Channel(ChannelError::PacketVerificationFailed {
                 sequence: msg.next_seq_recv_on_b,
                 client_error: e,
             })
-            .map_err(PacketError::Channel)?;
+            .into()
+        })?;
     };
 
     Ok(())
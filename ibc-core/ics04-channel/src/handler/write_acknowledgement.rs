@@ -0,0 +1,90 @@
+//! Protocol logic for writing the acknowledgement of a received packet.
+use ibc_core_channel_types::acknowledgement::Acknowledgement;
+use ibc_core_channel_types::channel::State as ChannelState;
+use ibc_core_channel_types::commitment::compute_ack_commitment;
+use ibc_core_channel_types::error::PacketError;
+use ibc_core_channel_types::events::WriteAcknowledgement;
+use ibc_core_channel_types::packet::Packet;
+use ibc_core_handler_types::error::ContextError;
+use ibc_core_handler_types::events::{IbcEvent, MessageEvent};
+use ibc_core_host::types::path::{AckPath, ChannelEndPath};
+use ibc_core_host::{ExecutionContext, ValidationContext};
+use ibc_primitives::prelude::*;
+
+/// Write the acknowledgement of a previously received packet, including all
+/// necessary validation.
+///
+/// Applications that acknowledge packets asynchronously, i.e. that return no
+/// acknowledgement from their `on_recv_packet_execute` callback, call this
+/// once the acknowledgement is available.
+///
+/// Equivalent to calling [`write_acknowledgement_validate`], followed by
+/// [`write_acknowledgement_execute`].
+pub fn write_acknowledgement<ExecCtx>(
+    ctx_b: &mut ExecCtx,
+    packet: Packet,
+    acknowledgement: Acknowledgement,
+) -> Result<(), ContextError>
+where
+    ExecCtx: ExecutionContext,
+{
+    write_acknowledgement_validate(ctx_b, &packet)?;
+    write_acknowledgement_execute(ctx_b, packet, acknowledgement)
+}
+
+/// Validate that writing the acknowledgement of the given packet would succeed.
+pub fn write_acknowledgement_validate<ValCtx>(
+    ctx_b: &ValCtx,
+    packet: &Packet,
+) -> Result<(), ContextError>
+where
+    ValCtx: ValidationContext,
+{
+    let chan_end_path_on_b = ChannelEndPath::new(&packet.port_id_on_b, &packet.chan_id_on_b);
+    let chan_end_on_b = ctx_b.channel_end(&chan_end_path_on_b)?;
+
+    chan_end_on_b.verify_state_matches(&ChannelState::Open)?;
+
+    let ack_path_on_b = AckPath::new(&packet.port_id_on_b, &packet.chan_id_on_b, packet.seq_on_a);
+    if ctx_b.get_packet_acknowledgement(&ack_path_on_b).is_ok() {
+        return Err(PacketError::AcknowledgementExists {
+            sequence: packet.seq_on_a,
+        }
+        .into());
+    }
+
+    Ok(())
+}
+
+/// Write the acknowledgement of the given packet without any validation.
+///
+/// A prior call to [`write_acknowledgement_validate`] MUST have succeeded.
+pub fn write_acknowledgement_execute<ExecCtx>(
+    ctx_b: &mut ExecCtx,
+    packet: Packet,
+    acknowledgement: Acknowledgement,
+) -> Result<(), ContextError>
+where
+    ExecCtx: ExecutionContext,
+{
+    let ack_path_on_b = AckPath::new(&packet.port_id_on_b, &packet.chan_id_on_b, packet.seq_on_a);
+    ctx_b.store_packet_acknowledgement(&ack_path_on_b, compute_ack_commitment(&acknowledgement))?;
+
+    // emit events and logs
+    {
+        let chan_end_path_on_b = ChannelEndPath::new(&packet.port_id_on_b, &packet.chan_id_on_b);
+        let chan_end_on_b = ctx_b.channel_end(&chan_end_path_on_b)?;
+        let conn_id_on_b = &chan_end_on_b.connection_hops()[0];
+
+        ctx_b.log_message("success: packet write acknowledgement".to_string())?;
+        let event = IbcEvent::WriteAcknowledgement(WriteAcknowledgement::new(
+            packet,
+            acknowledgement,
+            conn_id_on_b.clone(),
+        ));
+        ctx_b.emit_ibc_event(IbcEvent::Message(MessageEvent::Channel))?;
+        ctx_b.emit_ibc_event(event)?;
+    }
+
+    Ok(())
+}
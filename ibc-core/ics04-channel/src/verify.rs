@@ -0,0 +1,37 @@
+//! Verifies the channel state of the counterparty against a client, as the
+//! ICS-04 handlers do, for auditors and alternative handlers to reuse.
+
+use ibc_core_channel_types::channel::ChannelEnd;
+use ibc_core_client::context::prelude::*;
+use ibc_core_client::types::error::ClientError;
+use ibc_core_commitment_types::commitment::{
+    CommitmentPrefix, CommitmentProofBytes, CommitmentRoot,
+};
+use ibc_core_connection::verify::verify_state;
+use ibc_core_host::types::identifiers::{ChannelId, PortId};
+use ibc_core_host::types::path::{ChannelEndPath, Path};
+use ibc_primitives::proto::Protobuf;
+
+/// Verifies that the counterparty stores `expected_chan_end` as the channel
+/// end of `chan_id` on `port_id`, as [`verify_state`] does.
+pub fn verify_channel_state<C>(
+    client_state: &C,
+    prefix: &CommitmentPrefix,
+    proof: &CommitmentProofBytes,
+    root: &CommitmentRoot,
+    port_id: &PortId,
+    chan_id: &ChannelId,
+    expected_chan_end: ChannelEnd,
+) -> Result<(), ClientError>
+where
+    C: ClientStateCommon,
+{
+    verify_state(
+        client_state,
+        prefix,
+        proof,
+        root,
+        Path::ChannelEnd(ChannelEndPath::new(port_id, chan_id)),
+        Some(expected_chan_end.encode_vec()),
+    )
+}
@@ -4,6 +4,7 @@ use core::fmt::{Display, Error as FmtError, Formatter};
 
 use derive_more::Into;
 use ibc_primitives::prelude::*;
+use ibc_primitives::utils::{escape_json_str, parse_json_string_object};
 
 use super::error::PacketError;
 
@@ -105,10 +106,45 @@ impl AcknowledgementStatus {
         Self::Error(value)
     }
 
+    /// Creates the standardized error acknowledgement status for a failure
+    /// reported under the given ABCI error `code`, following the format of
+    /// ibc-go's `NewErrorAcknowledgement`, i.e. `ABCI code: <code>: <message>`.
+    ///
+    /// When no `description` is given, the error is redacted and the message
+    /// is the one used by ibc-go, which makes the acknowledgement (and hence
+    /// its commitment) identical across implementations.
+    pub fn abci_error(code: u32, description: Option<&str>) -> Self {
+        let message = description
+            .filter(|d| !d.is_empty())
+            .unwrap_or(REDACTED_ERROR_ACK_MESSAGE);
+
+        Self::Error(StatusValue(format!("ABCI code: {code}: {message}")))
+    }
+
     /// Returns true if the acknowledgement status is successful.
     pub fn is_successful(&self) -> bool {
         matches!(self, AcknowledgementStatus::Success(_))
     }
+
+    /// Encodes the acknowledgement status into its JSON envelope, without
+    /// consuming it. See the conversion into `Vec<u8>`.
+    pub fn encode_json(&self) -> String {
+        // WARNING: Make sure all branches always return a non-empty string.
+        // Otherwise, the conversion to `Acknowledgement` will panic.
+        let (key, value) = match self {
+            AcknowledgementStatus::Success(v) => (ACK_RESULT_KEY, v),
+            AcknowledgementStatus::Error(v) => (ACK_ERROR_KEY, v),
+        };
+
+        let mut json = String::with_capacity(value.0.len() + key.len() + 7);
+        json.push_str(r#"{""#);
+        json.push_str(key);
+        json.push_str(r#"":""#);
+        escape_json_str(&value.0, &mut json);
+        json.push_str(r#""}"#);
+
+        json
+    }
 }
 
 impl Display for AcknowledgementStatus {
@@ -120,14 +156,14 @@ impl Display for AcknowledgementStatus {
 }
 
 /// Converts an acknowledgement result into a vector of bytes.
+///
+/// The output is byte-for-byte identical to the JSON envelope produced by
+/// ibc-go (`sdk.MustSortJSON` over the proto-JSON encoded acknowledgement),
+/// including its escaping rules, so that the resulting acknowledgement
+/// commitments match across implementations.
 impl From<AcknowledgementStatus> for Vec<u8> {
     fn from(ack: AcknowledgementStatus) -> Self {
-        // WARNING: Make sure all branches always return a non-empty vector.
-        // Otherwise, the conversion to `Acknowledgement` will panic.
-        match ack {
-            AcknowledgementStatus::Success(v) => alloc::format!(r#"{{"result":"{v}"}}"#).into(),
-            AcknowledgementStatus::Error(v) => alloc::format!(r#"{{"error":"{v}"}}"#).into(),
-        }
+        ack.encode_json().into()
     }
 }
 
@@ -139,3 +175,123 @@ impl From<AcknowledgementStatus> for Acknowledgement {
             .expect("token transfer internal error: ack is never supposed to be empty")
     }
 }
+
+/// Decodes the standard JSON acknowledgement envelope, i.e. either
+/// `{"result":"<base64>"}` or `{"error":"<string>"}`.
+impl TryFrom<&[u8]> for AcknowledgementStatus {
+    type Error = PacketError;
+
+    fn try_from(bytes: &[u8]) -> Result<Self, PacketError> {
+        let json =
+            str::from_utf8(bytes).map_err(|_| PacketError::InvalidAcknowledgementStatus {
+                description: "acknowledgement is not valid UTF-8".to_string(),
+            })?;
+
+        let members = parse_json_string_object(json).ok_or_else(|| {
+            PacketError::InvalidAcknowledgementStatus {
+                description: "acknowledgement is not a JSON object of strings".to_string(),
+            }
+        })?;
+        let [(key, value)] =
+            <[_; 1]>::try_from(members).map_err(|_| PacketError::InvalidAcknowledgementStatus {
+                description: "acknowledgement must have exactly one field".to_string(),
+            })?;
+        let value = StatusValue::new(value)?;
+
+        match key.as_str() {
+            ACK_RESULT_KEY => Ok(Self::Success(value)),
+            ACK_ERROR_KEY => Ok(Self::Error(value)),
+            _ => Err(PacketError::InvalidAcknowledgementStatus {
+                description: format!("unknown acknowledgement field `{key}`"),
+            }),
+        }
+    }
+}
+
+impl TryFrom<&Acknowledgement> for AcknowledgementStatus {
+    type Error = PacketError;
+
+    fn try_from(ack: &Acknowledgement) -> Result<Self, PacketError> {
+        Self::try_from(ack.as_bytes())
+    }
+}
+
+impl TryFrom<Acknowledgement> for AcknowledgementStatus {
+    type Error = PacketError;
+
+    fn try_from(ack: Acknowledgement) -> Result<Self, PacketError> {
+        Self::try_from(ack.as_bytes())
+    }
+}
+
+/// JSON field name of a successful acknowledgement.
+const ACK_RESULT_KEY: &str = "result";
+
+/// JSON field name of an error acknowledgement.
+const ACK_ERROR_KEY: &str = "error";
+
+/// Message of the redacted error acknowledgements written by ibc-go, which
+/// discloses the error code only, since error descriptions are not guaranteed
+/// to be deterministic across nodes.
+const REDACTED_ERROR_ACK_MESSAGE: &str = "error handling packet: see events for details";
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case(AcknowledgementStatus::success(StatusValue::new("AQ==").unwrap()), br#"{"result":"AQ=="}"#.as_slice())]
+    #[case(AcknowledgementStatus::error(StatusValue::new("failed").unwrap()), br#"{"error":"failed"}"#.as_slice())]
+    #[case(
+        AcknowledgementStatus::error(StatusValue::new(r#"bad "denom" <a&b>"#).unwrap()),
+        br#"{"error":"bad \"denom\" \u003ca\u0026b\u003e"}"#.as_slice()
+    )]
+    #[case(
+        AcknowledgementStatus::error(StatusValue::new("line\nbreak\u{1}").unwrap()),
+        br#"{"error":"line\nbreak\u0001"}"#.as_slice()
+    )]
+    fn test_ack_status_json_roundtrip(#[case] status: AcknowledgementStatus, #[case] json: &[u8]) {
+        let encoded: Vec<u8> = status.clone().into();
+        assert_eq!(encoded, json);
+
+        let decoded = AcknowledgementStatus::try_from(json).unwrap();
+        assert_eq!(decoded, status);
+    }
+
+    #[rstest]
+    #[case(br#"{ "result" : "AQ==" }"#.as_slice(), true)]
+    #[case(r#"{"error":"é😀\ud83d\ude00"}"#.as_bytes(), false)]
+    fn test_ack_status_decode_lenient(#[case] json: &[u8], #[case] is_successful: bool) {
+        let decoded = AcknowledgementStatus::try_from(json).unwrap();
+        assert_eq!(decoded.is_successful(), is_successful);
+    }
+
+    #[rstest]
+    #[case(
+        None,
+        br#"{"error":"ABCI code: 7: error handling packet: see events for details"}"#.as_slice()
+    )]
+    #[case(Some(""), br#"{"error":"ABCI code: 7: error handling packet: see events for details"}"#.as_slice())]
+    #[case(Some("invalid <denom>"), br#"{"error":"ABCI code: 7: invalid \u003cdenom\u003e"}"#.as_slice())]
+    fn test_ack_status_abci_error(#[case] description: Option<&str>, #[case] json: &[u8]) {
+        let status = AcknowledgementStatus::abci_error(7, description);
+        assert!(!status.is_successful());
+
+        let encoded: Vec<u8> = status.into();
+        assert_eq!(encoded, json);
+    }
+
+    #[rstest]
+    #[case(br#"{"success":"AQ=="}"#.as_slice())]
+    #[case(br#"{"result":""}"#.as_slice())]
+    #[case(br#"{"result":"AQ==","error":"e"}"#.as_slice())]
+    #[case(br#"{"error":"\ud83d"}"#.as_slice())]
+    #[case(br#"{"error":"unterminated}"#.as_slice())]
+    #[case(br#"{"result":"AQ=="} trailing"#.as_slice())]
+    #[case(b"\xff".as_slice())]
+    fn test_ack_status_decode_invalid(#[case] json: &[u8]) {
+        assert!(AcknowledgementStatus::try_from(json).is_err());
+    }
+}
@@ -3,7 +3,7 @@
 use core::fmt::{Display, Error as FmtError, Formatter};
 use core::str::FromStr;
 
-use ibc_core_host_types::identifiers::{ChannelId, ConnectionId, PortId};
+use ibc_core_host_types::identifiers::{ChannelId, ConnectionId, IdentifierMode, PortId};
 use ibc_primitives::prelude::*;
 use ibc_primitives::utils::PrettySlice;
 use ibc_proto::ibc::core::channel::v1::{
@@ -325,7 +325,7 @@ impl ChannelEnd {
 }
 
 /// Checks if the `connection_hops` has a length of `expected`.
-pub(crate) fn verify_connection_hops_length(
+pub fn verify_connection_hops_length(
     connection_hops: &[ConnectionId],
     expected: u64,
 ) -> Result<(), ChannelError> {
@@ -366,6 +366,25 @@ impl Counterparty {
         }
     }
 
+    /// Strict counterpart of [`Counterparty::new`], which rejects invalid
+    /// port and channel identifiers.
+    pub fn try_new(port_id: PortId, channel_id: Option<ChannelId>) -> Result<Self, ChannelError> {
+        // Identifiers are not validated when deserialized, so parse them again.
+        port_id
+            .as_str()
+            .parse::<PortId>()
+            .map_err(ChannelError::InvalidIdentifier)?;
+
+        if let Some(channel_id) = &channel_id {
+            channel_id
+                .as_str()
+                .parse::<ChannelId>()
+                .map_err(ChannelError::InvalidIdentifier)?;
+        }
+
+        Ok(Self::new(port_id, channel_id))
+    }
+
     pub fn port_id(&self) -> &PortId {
         &self.port_id
     }
@@ -385,6 +404,18 @@ impl Counterparty {
         }
         Ok(())
     }
+
+    /// Verifies that the counterparty channel id, if already set, matches the
+    /// one given by the counterparty chain.
+    pub fn verify_channel_id_matches(&self, channel_id: &ChannelId) -> Result<(), ChannelError> {
+        match self.channel_id() {
+            Some(expected) if expected != channel_id => Err(ChannelError::InvalidChannelId {
+                expected: expected.to_string(),
+                actual: channel_id.to_string(),
+            }),
+            _ => Ok(()),
+        }
+    }
 }
 
 impl Display for Counterparty {
@@ -413,7 +444,12 @@ impl TryFrom<RawCounterparty> for Counterparty {
         let channel_id: Option<ChannelId> = if raw_counterparty.channel_id.is_empty() {
             None
         } else {
-            Some(raw_counterparty.channel_id.parse()?)
+            // The counterparty assigns its own identifiers, which need not be
+            // of the form `channel-{counter}`.
+            Some(ChannelId::from_str_with_mode(
+                &raw_counterparty.channel_id,
+                IdentifierMode::Lenient,
+            )?)
         };
 
         Ok(Counterparty::new(
@@ -487,12 +523,28 @@ impl Order {
     }
 }
 
+impl TryFrom<i32> for Order {
+    type Error = ChannelError;
+
+    fn try_from(nr: i32) -> Result<Self, Self::Error> {
+        Self::from_i32(nr)
+    }
+}
+
+impl From<Order> for i32 {
+    fn from(order: Order) -> Self {
+        order as i32
+    }
+}
+
+/// Parses the order, case-insensitively, out of its name, with or without the
+/// `ORDER_` prefix of [`Order::as_str`].
 impl FromStr for Order {
     type Err = ChannelError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s.to_lowercase().trim_start_matches("order_") {
-            "uninitialized" => Ok(Self::None),
+            "uninitialized" | "none" | "none_unspecified" => Ok(Self::None),
             "unordered" => Ok(Self::Unordered),
             "ordered" => Ok(Self::Ordered),
             _ => Err(ChannelError::InvalidOrderType {
@@ -579,3 +631,167 @@ impl Display for State {
         write!(f, "{}", self.as_string())
     }
 }
+
+impl TryFrom<i32> for State {
+    type Error = ChannelError;
+
+    fn try_from(s: i32) -> Result<Self, Self::Error> {
+        Self::from_i32(s)
+    }
+}
+
+impl From<State> for i32 {
+    fn from(state: State) -> Self {
+        state as i32
+    }
+}
+
+/// Parses the state, case-insensitively, out of its name as given by
+/// [`State::as_string`], or as defined in the protobuf, e.g. `STATE_TRYOPEN`.
+impl FromStr for State {
+    type Err = ChannelError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().trim_start_matches("state_") {
+            "uninitialized" | "uninitialized_unspecified" => Ok(Self::Uninitialized),
+            "init" => Ok(Self::Init),
+            "tryopen" => Ok(Self::TryOpen),
+            "open" => Ok(Self::Open),
+            "closed" => Ok(Self::Closed),
+            _ => Err(ChannelError::InvalidState {
+                expected: "Must be one of 'uninitialized', 'init', 'tryopen', 'open', 'closed'"
+                    .to_string(),
+                actual: s.to_string(),
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case(Order::None, 0)]
+    #[case(Order::Unordered, 1)]
+    #[case(Order::Ordered, 2)]
+    fn order_round_trips(#[case] order: Order, #[case] nr: i32) {
+        assert_eq!(i32::from(order), nr);
+        assert_eq!(Order::try_from(nr).unwrap(), order);
+        assert_eq!(Order::from_str(&order.to_string()).unwrap(), order);
+        assert_eq!(Order::from_str(order.as_str()).unwrap(), order);
+    }
+
+    #[rstest]
+    #[case("uninitialized", Order::None)]
+    #[case("ORDER_NONE_UNSPECIFIED", Order::None)]
+    #[case("unordered", Order::Unordered)]
+    #[case("ORDER_UNORDERED", Order::Unordered)]
+    #[case("Ordered", Order::Ordered)]
+    fn order_from_str(#[case] s: &str, #[case] order: Order) {
+        assert_eq!(Order::from_str(s).unwrap(), order);
+    }
+
+    #[rstest]
+    #[case::negative(-1)]
+    #[case::next(3)]
+    #[case::max(i32::MAX)]
+    fn order_from_unknown_i32(#[case] nr: i32) {
+        assert!(matches!(
+            Order::try_from(nr),
+            Err(ChannelError::InvalidOrderType { .. })
+        ));
+    }
+
+    #[rstest]
+    #[case("")]
+    #[case("order_")]
+    #[case("order_allow_timeout")]
+    fn order_from_unknown_str(#[case] s: &str) {
+        assert!(matches!(
+            Order::from_str(s),
+            Err(ChannelError::InvalidOrderType { .. })
+        ));
+    }
+
+    #[rstest]
+    #[case(State::Uninitialized, 0)]
+    #[case(State::Init, 1)]
+    #[case(State::TryOpen, 2)]
+    #[case(State::Open, 3)]
+    #[case(State::Closed, 4)]
+    fn state_round_trips(#[case] state: State, #[case] nr: i32) {
+        assert_eq!(i32::from(state), nr);
+        assert_eq!(State::try_from(nr).unwrap(), state);
+        assert_eq!(State::from_str(&state.to_string()).unwrap(), state);
+    }
+
+    #[rstest]
+    #[case("STATE_UNINITIALIZED_UNSPECIFIED", State::Uninitialized)]
+    #[case("STATE_INIT", State::Init)]
+    #[case("STATE_TRYOPEN", State::TryOpen)]
+    #[case("open", State::Open)]
+    #[case("Closed", State::Closed)]
+    fn state_from_str(#[case] s: &str, #[case] state: State) {
+        assert_eq!(State::from_str(s).unwrap(), state);
+    }
+
+    #[rstest]
+    #[case::negative(-1)]
+    #[case::flushing(5)]
+    #[case::max(i32::MAX)]
+    fn state_from_unknown_i32(#[case] nr: i32) {
+        assert!(matches!(
+            State::try_from(nr),
+            Err(ChannelError::InvalidState { .. })
+        ));
+
+        let raw_channel = RawChannel {
+            state: nr,
+            ordering: 1,
+            counterparty: Some(RawCounterparty {
+                port_id: "transfer".to_string(),
+                channel_id: "channel-0".to_string(),
+            }),
+            connection_hops: vec!["connection-0".to_string()],
+            version: "ics20-1".to_string(),
+            ..Default::default()
+        };
+        assert!(ChannelEnd::try_from(raw_channel).is_err());
+    }
+
+    #[rstest]
+    #[case("")]
+    #[case("try_open")]
+    #[case("STATE_FLUSHING")]
+    fn state_from_unknown_str(#[case] s: &str) {
+        assert!(matches!(
+            State::from_str(s),
+            Err(ChannelError::InvalidState { .. })
+        ));
+    }
+
+    #[rstest]
+    #[case::canonical("channel-7", true)]
+    #[case::alias("channel-to-osmosis", true)]
+    #[case::client_id("07-tendermint-0", true)]
+    #[case::too_short("chan-0", false)]
+    #[case::invalid_chars("channel/0", false)]
+    fn counterparty_channel_id_is_lenient(#[case] channel_id: &str, #[case] want_pass: bool) {
+        let raw_counterparty = RawCounterparty {
+            port_id: "transfer".to_string(),
+            channel_id: channel_id.to_string(),
+        };
+
+        let counterparty = Counterparty::try_from(raw_counterparty);
+        assert_eq!(counterparty.is_ok(), want_pass);
+        if let Ok(counterparty) = counterparty {
+            assert_eq!(
+                counterparty.channel_id().map(ChannelId::as_str),
+                Some(channel_id)
+            );
+        }
+    }
+}
@@ -1,9 +1,11 @@
 //! Types and utilities related to packet commitments.
 
+use ibc_core_commitment_types::proto::ics23::{HostFunctionsManager, HostFunctionsProvider};
 use ibc_primitives::prelude::*;
+use ibc_primitives::utils::constant_time_eq;
 use ibc_primitives::Timestamp;
 
-use super::acknowledgement::Acknowledgement;
+use super::acknowledgement::{Acknowledgement, AcknowledgementStatus};
 use crate::timeout::TimeoutHeight;
 
 /// Packet commitment
@@ -21,9 +23,17 @@ use crate::timeout::TimeoutHeight;
 )]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, Eq)]
 pub struct PacketCommitment(Vec<u8>);
 
+/// Compares the commitments in constant time, as they are checked against the
+/// commitments computed from the messages submitted by relayers.
+impl PartialEq for PacketCommitment {
+    fn eq(&self, other: &Self) -> bool {
+        constant_time_eq(&self.0, &other.0)
+    }
+}
+
 impl PacketCommitment {
     pub fn into_vec(self) -> Vec<u8> {
         self.0
@@ -57,9 +67,17 @@ impl From<Vec<u8>> for PacketCommitment {
 )]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, Eq)]
 pub struct AcknowledgementCommitment(Vec<u8>);
 
+/// Compares the commitments in constant time, as they are checked against the
+/// commitments computed from the messages submitted by relayers.
+impl PartialEq for AcknowledgementCommitment {
+    fn eq(&self, other: &Self) -> bool {
+        constant_time_eq(&self.0, &other.0)
+    }
+}
+
 impl AcknowledgementCommitment {
     pub fn into_vec(self) -> Vec<u8> {
         self.0
@@ -80,42 +98,93 @@ impl From<Vec<u8>> for AcknowledgementCommitment {
 
 /// Compute the commitment for a packet.
 ///
+/// This is the value stored by the sending chain under the packet commitment
+/// path, and against which the proofs of `MsgRecvPacket`, `MsgAcknowledgement`
+/// and `MsgTimeout` are verified. It is computed as in the v1 of the ICS-04
+/// specification, that is:
+///
+/// ```text
+/// sha256(
+///     timeout_timestamp (8 bytes, big-endian nanoseconds)
+///     || timeout_height.revision_number (8 bytes, big-endian)
+///     || timeout_height.revision_height (8 bytes, big-endian)
+///     || sha256(packet_data)
+/// )
+/// ```
+///
 /// Note that the absence of `timeout_height` is treated as
 /// `{revision_number: 0, revision_height: 0}` to be consistent with ibc-go,
 /// where this value is used to mean "no timeout height":
 /// <https://github.com/cosmos/ibc-go/blob/04791984b3d6c83f704c4f058e6ca0038d155d91/modules/core/04-channel/keeper/packet.go#L206>
+/// Likewise, the absence of `timeout_timestamp` is committed to as `0`.
 pub fn compute_packet_commitment(
     packet_data: &[u8],
     timeout_height: &TimeoutHeight,
     timeout_timestamp: &Timestamp,
+) -> PacketCommitment {
+    compute_packet_commitment_with::<HostFunctionsManager>(
+        packet_data,
+        timeout_height,
+        timeout_timestamp,
+    )
+}
+
+/// Same as [`compute_packet_commitment`], hashing with the SHA-256
+/// implementation of the given hash functions, e.g. for hosts computing
+/// commitments outside of the handlers, which always hash with
+/// [`HostFunctionsManager`].
+///
+/// Note that computing commitments with anything other than SHA-256 will
+/// break the Merkle proofs of the IBC provable store.
+pub fn compute_packet_commitment_with<H: HostFunctionsProvider>(
+    packet_data: &[u8],
+    timeout_height: &TimeoutHeight,
+    timeout_timestamp: &Timestamp,
 ) -> PacketCommitment {
     let mut hash_input = [0; 8 * 3 + 32];
 
     hash_input[..8].copy_from_slice(&timeout_timestamp.nanoseconds().to_be_bytes());
     hash_input[8..16].copy_from_slice(&timeout_height.commitment_revision_number().to_be_bytes());
     hash_input[16..24].copy_from_slice(&timeout_height.commitment_revision_height().to_be_bytes());
-    hash_input[24..].copy_from_slice(&hash(packet_data));
+    hash_input[24..].copy_from_slice(&H::sha2_256(packet_data));
 
-    hash(&hash_input).to_vec().into()
+    H::sha2_256(&hash_input).to_vec().into()
 }
 
 /// Compute the commitment for an acknowledgement.
 pub fn compute_ack_commitment(ack: &Acknowledgement) -> AcknowledgementCommitment {
-    hash(ack.as_ref()).to_vec().into()
+    compute_ack_commitment_with::<HostFunctionsManager>(ack)
 }
 
-/// Helper function to hash a byte slice using SHA256.
+/// Same as [`compute_ack_commitment`], hashing with the SHA-256
+/// implementation of the given hash functions, e.g. for hosts computing
+/// commitments outside of the handlers, which always hash with
+/// [`HostFunctionsManager`].
 ///
-/// Note that computing commitments with anything other than SHA256 will
+/// Note that computing commitments with anything other than SHA-256 will
 /// break the Merkle proofs of the IBC provable store.
-fn hash(data: &[u8]) -> [u8; 32] {
-    use sha2::Digest;
+pub fn compute_ack_commitment_with<H: HostFunctionsProvider>(
+    ack: &Acknowledgement,
+) -> AcknowledgementCommitment {
+    H::sha2_256(ack.as_ref()).to_vec().into()
+}
 
-    sha2::Sha256::digest(data).into()
+/// Compute the commitment for an acknowledgement built from the standard
+/// [`AcknowledgementStatus`] JSON envelope.
+///
+/// This is equivalent to encoding the status into an [`Acknowledgement`] and
+/// calling [`compute_ack_commitment`] on it.
+pub fn ack_commitment(ack_status: &AcknowledgementStatus) -> AcknowledgementCommitment {
+    HostFunctionsManager::sha2_256(ack_status.encode_json().as_bytes())
+        .to_vec()
+        .into()
 }
 
 #[cfg(test)]
 mod test {
+    use ibc_core_client_types::Height;
+    use rstest::rstest;
+
     use super::*;
 
     #[test]
@@ -127,12 +196,67 @@ mod test {
         ];
         let actual = compute_packet_commitment(
             b"packet data",
-            &TimeoutHeight::At(ibc_core_client_types::Height::new(42, 24).unwrap()),
+            &TimeoutHeight::At(Height::new(42, 24).unwrap()),
             &Timestamp::from_nanoseconds(0x42).unwrap(),
         );
         assert_eq!(&expected[..], actual.as_ref());
     }
 
+    /// Recomputes the commitment from the preimage documented on
+    /// [`compute_packet_commitment`], so that the known answers are not taken
+    /// from the implementation under test.
+    fn documented_packet_commitment(
+        packet_data: &[u8],
+        timeout_height: &TimeoutHeight,
+        timeout_timestamp: &Timestamp,
+    ) -> Vec<u8> {
+        let (revision_number, revision_height) = match timeout_height {
+            TimeoutHeight::Never => (0, 0),
+            TimeoutHeight::At(height) => (height.revision_number(), height.revision_height()),
+        };
+
+        let mut preimage = Vec::new();
+        preimage.extend(timeout_timestamp.nanoseconds().to_be_bytes());
+        preimage.extend(revision_number.to_be_bytes());
+        preimage.extend(revision_height.to_be_bytes());
+        preimage.extend(HostFunctionsManager::sha2_256(packet_data));
+
+        HostFunctionsManager::sha2_256(&preimage).to_vec()
+    }
+
+    #[rstest]
+    #[case(
+        b"packet data".as_slice(),
+        TimeoutHeight::At(Height::new(42, 24).unwrap()),
+        Timestamp::from_nanoseconds(0x42).unwrap()
+    )]
+    #[case(
+        b"packet data".as_slice(),
+        TimeoutHeight::At(Height::new(1, 100).unwrap()),
+        Timestamp::none()
+    )]
+    #[case(
+        br#"{"amount":"100","denom":"stake","receiver":"cosmos1receiver","sender":"cosmos1sender"}"#.as_slice(),
+        TimeoutHeight::Never,
+        Timestamp::from_nanoseconds(1_700_000_000_000_000_000).unwrap()
+    )]
+    #[case(
+        b"".as_slice(),
+        TimeoutHeight::At(Height::new(u64::MAX, u64::MAX).unwrap()),
+        Timestamp::from_nanoseconds(u64::MAX).unwrap()
+    )]
+    fn test_compute_packet_commitment_matches_documented_preimage(
+        #[case] packet_data: &[u8],
+        #[case] timeout_height: TimeoutHeight,
+        #[case] timeout_timestamp: Timestamp,
+    ) {
+        let actual = compute_packet_commitment(packet_data, &timeout_height, &timeout_timestamp);
+        let expected =
+            documented_packet_commitment(packet_data, &timeout_height, &timeout_timestamp);
+
+        assert_eq!(expected.as_slice(), actual.as_ref());
+    }
+
     #[test]
     fn test_compute_ack_commitment() {
         let expected: [u8; 32] = [
@@ -144,4 +268,14 @@ mod test {
         let actual = compute_ack_commitment(&ack);
         assert_eq!(&expected[..], actual.as_ref())
     }
+
+    #[test]
+    fn test_ack_commitment_matches_encoded_ack() {
+        let ack_status = AcknowledgementStatus::success(
+            crate::acknowledgement::StatusValue::new("AQ==").unwrap(),
+        );
+        let ack: Acknowledgement = ack_status.clone().into();
+
+        assert_eq!(ack_commitment(&ack_status), compute_ack_commitment(&ack));
+    }
 }
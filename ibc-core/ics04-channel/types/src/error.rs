@@ -2,11 +2,11 @@
 
 use displaydoc::Display;
 use ibc_core_client_types::{error as client_error, Height};
-use ibc_core_connection_types::error as connection_error;
+use ibc_core_connection_types::{error as connection_error, State as ConnectionState};
 use ibc_core_host_types::error::IdentifierError;
 use ibc_core_host_types::identifiers::{ChannelId, ConnectionId, PortId, Sequence};
 use ibc_primitives::prelude::*;
-use ibc_primitives::{ParseTimestampError, Timestamp};
+use ibc_primitives::{AbciError, ParseTimestampError, Signer, Timestamp, TimestampOverflowError};
 
 use super::channel::Counterparty;
 use super::timeout::TimeoutHeight;
@@ -37,6 +37,13 @@ pub enum ChannelError {
     UnsupportedChannelUpgradeSequence,
     /// version not supported: expected `{expected}`, actual `{actual}`
     VersionNotSupported { expected: Version, actual: Version },
+    /// invalid JSON-encoded version `{version}`: `{description}`
+    InvalidVersionEncoding {
+        version: Version,
+        description: String,
+    },
+    /// version of `{length}` bytes exceeds the maximum length of `{max_length}` bytes
+    InvalidVersionLength { length: u64, max_length: u64 },
     /// missing channel end
     MissingChannel,
     /// the channel end (`{port_id}`, `{channel_id}`) does not exist
@@ -73,6 +80,24 @@ pub enum ChannelError {
     CounterOverflow,
     /// other error: `{description}`
     Other { description: String },
+    /// connection hop `{connection_id}` is in state `{state}`, expected OPEN
+    ConnectionHopNotOpen {
+        connection_id: ConnectionId,
+        state: ConnectionState,
+    },
+    /// the channel end (`{port_id}`, `{channel_id}`) is frozen by the host
+    FrozenChannel {
+        port_id: PortId,
+        channel_id: ChannelId,
+    },
+    /// the host does not support freezing channels
+    UnsupportedChannelFreeze,
+    /// signer `{signer}` is not authorized to open a channel on port `{port_id}` with counterparty port `{counterparty_port_id}`
+    UnauthorizedChannelOpen {
+        signer: Signer,
+        port_id: PortId,
+        counterparty_port_id: PortId,
+    },
 }
 
 #[derive(Debug, Display)]
@@ -120,6 +145,8 @@ pub enum PacketError {
     InvalidAcknowledgement,
     /// Acknowledgment status cannot be empty
     EmptyAcknowledgementStatus,
+    /// invalid acknowledgement status: `{description}`
+    InvalidAcknowledgementStatus { description: String },
     /// Acknowledgment for the packet `{sequence}` not found
     PacketAcknowledgementNotFound { sequence: Sequence },
     /// invalid proof: missing height
@@ -142,6 +169,8 @@ pub enum PacketError {
     InvalidPacketTimestamp(ParseTimestampError),
     /// missing timeout
     MissingTimeout,
+    /// timeout timestamp overflow: `{0}`
+    TimestampOverflow(TimestampOverflowError),
     /// invalid identifier error: `{0}`
     InvalidIdentifier(IdentifierError),
     /// Missing sequence number for sending packets on port `{port_id}` and channel `{channel_id}`
@@ -182,6 +211,57 @@ impl From<IdentifierError> for PacketError {
     }
 }
 
+impl AbciError for PacketError {
+    fn codespace(&self) -> &'static str {
+        match self {
+            Self::Connection(e) => e.codespace(),
+            Self::Channel(e) => e.codespace(),
+            _ => "packet",
+        }
+    }
+
+    fn code(&self) -> u32 {
+        match self {
+            Self::Connection(e) => e.code(),
+            Self::Channel(e) => e.code(),
+            Self::LowPacketHeight { .. } => 2,
+            Self::LowPacketTimestamp => 3,
+            Self::InvalidPacketSequence { .. } => 4,
+            Self::InvalidChannelState { .. } => 5,
+            Self::ConnectionNotOpen { .. } => 6,
+            Self::PacketReceiptNotFound { .. } => 7,
+            Self::IncorrectPacketCommitment { .. } => 8,
+            Self::ImplementationSpecific => 9,
+            Self::UndefinedConnectionCounterparty { .. } => 10,
+            Self::InvalidProof => 11,
+            Self::PacketTimeoutNotReached { .. } => 12,
+            Self::AcknowledgementExists { .. } => 13,
+            Self::InvalidAcknowledgement => 14,
+            Self::EmptyAcknowledgementStatus => 15,
+            Self::InvalidAcknowledgementStatus { .. } => 16,
+            Self::PacketAcknowledgementNotFound { .. } => 17,
+            Self::MissingHeight => 18,
+            Self::MissingPacket => 19,
+            Self::InvalidSigner { .. } => 20,
+            Self::AppModule { .. } => 21,
+            Self::RouteNotFound => 22,
+            Self::ZeroPacketSequence => 23,
+            Self::ZeroPacketData => 24,
+            Self::InvalidTimeoutHeight => 25,
+            Self::InvalidPacketTimestamp(_) => 26,
+            Self::MissingTimeout => 27,
+            Self::TimestampOverflow(_) => 28,
+            Self::InvalidIdentifier(_) => 29,
+            Self::MissingNextSendSeq { .. } => 30,
+            Self::ChannelNotFound { .. } => 31,
+            Self::PacketCommitmentNotFound { .. } => 32,
+            Self::MissingNextRecvSeq { .. } => 33,
+            Self::MissingNextAckSeq { .. } => 34,
+            Self::Other { .. } => 35,
+        }
+    }
+}
+
 #[cfg(feature = "std")]
 impl std::error::Error for PacketError {
     fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
@@ -189,11 +269,53 @@ impl std::error::Error for PacketError {
             Self::Connection(e) => Some(e),
             Self::Channel(e) => Some(e),
             Self::InvalidIdentifier(e) => Some(e),
+            Self::InvalidPacketTimestamp(e) => Some(e),
+            Self::TimestampOverflow(e) => Some(e),
             _ => None,
         }
     }
 }
 
+impl AbciError for ChannelError {
+    fn codespace(&self) -> &'static str {
+        "channel"
+    }
+
+    fn code(&self) -> u32 {
+        match self {
+            Self::InvalidChannelEnd { .. } => 2,
+            Self::InvalidChannelId { .. } => 3,
+            Self::InvalidState { .. } => 4,
+            Self::InvalidOrderType { .. } => 5,
+            Self::InvalidConnectionHopsLength { .. } => 6,
+            Self::InvalidSigner { .. } => 7,
+            Self::MissingHeight => 8,
+            Self::NonUtf8PacketData => 9,
+            Self::MissingCounterparty => 10,
+            Self::UnsupportedChannelUpgradeSequence => 11,
+            Self::VersionNotSupported { .. } => 12,
+            Self::InvalidVersionEncoding { .. } => 13,
+            Self::MissingChannel => 14,
+            Self::ChannelNotFound { .. } => 15,
+            Self::PacketVerificationFailed { .. } => 16,
+            Self::VerifyChannelFailed(_) => 17,
+            Self::InvalidStringAsSequence { .. } => 18,
+            Self::InvalidCounterparty { .. } => 19,
+            Self::AppModule { .. } => 20,
+            Self::UndefinedConnectionCounterparty { .. } => 21,
+            Self::InvalidProof => 22,
+            Self::InvalidIdentifier(_) => 23,
+            Self::CounterOverflow => 24,
+            Self::Other { .. } => 25,
+            Self::ConnectionHopNotOpen { .. } => 26,
+            Self::InvalidVersionLength { .. } => 27,
+            Self::FrozenChannel { .. } => 28,
+            Self::UnsupportedChannelFreeze => 29,
+            Self::UnauthorizedChannelOpen { .. } => 30,
+        }
+    }
+}
+
 #[cfg(feature = "std")]
 impl std::error::Error for ChannelError {
     fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
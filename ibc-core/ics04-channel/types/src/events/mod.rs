@@ -5,7 +5,7 @@ mod packet_attributes;
 
 use ibc_core_host_types::identifiers::{ChannelId, ConnectionId, PortId, Sequence};
 use ibc_primitives::prelude::*;
-use ibc_primitives::Timestamp;
+use ibc_primitives::{Signer, Timestamp};
 use tendermint::abci;
 
 use self::channel_attributes::{
@@ -14,9 +14,11 @@ use self::channel_attributes::{
     COUNTERPARTY_CHANNEL_ID_ATTRIBUTE_KEY,
 };
 use self::packet_attributes::{
-    AcknowledgementAttribute, ChannelOrderingAttribute, DstChannelIdAttribute, DstPortIdAttribute,
-    PacketConnectionIdAttribute, PacketDataAttribute, SequenceAttribute, SrcChannelIdAttribute,
-    SrcPortIdAttribute, TimeoutHeightAttribute, TimeoutTimestampAttribute,
+    AcknowledgementAttribute, AcknowledgementSuccessAttribute, ChannelOrderingAttribute,
+    DeletedAcknowledgementsAttribute, DstChannelIdAttribute, DstPortIdAttribute,
+    NextSequenceAckAttribute, NextSequenceRecvAttribute, NextSequenceSendAttribute,
+    PacketConnectionIdAttribute, PacketDataAttribute, RelayerAttribute, SequenceAttribute,
+    SrcChannelIdAttribute, SrcPortIdAttribute, TimeoutHeightAttribute, TimeoutTimestampAttribute,
 };
 use super::acknowledgement::Acknowledgement;
 use super::channel::Order;
@@ -34,6 +36,9 @@ const CHANNEL_OPEN_CONFIRM_EVENT: &str = "channel_open_confirm";
 const CHANNEL_CLOSE_INIT_EVENT: &str = "channel_close_init";
 const CHANNEL_CLOSE_CONFIRM_EVENT: &str = "channel_close_confirm";
 const CHANNEL_CLOSED_EVENT: &str = "channel_close";
+const CHANNEL_CLEANUP_EVENT: &str = "channel_cleanup";
+const CHANNEL_FROZEN_EVENT: &str = "channel_frozen";
+const CHANNEL_UNFROZEN_EVENT: &str = "channel_unfrozen";
 
 /// Packet event types
 const SEND_PACKET_EVENT: &str = "send_packet";
@@ -582,6 +587,163 @@ impl From<ChannelClosed> for abci::Event {
     }
 }
 
+/// A `ChannelCleanup` event is emitted when the packet state of a closed
+/// channel end is deleted, along with the number of packet acknowledgements
+/// deleted.
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ChannelCleanup {
+    port_id_attr: PortIdAttribute,
+    chan_id_attr: ChannelIdAttribute,
+    deleted_acks_attr: DeletedAcknowledgementsAttribute,
+}
+
+impl ChannelCleanup {
+    pub fn new(port_id: PortId, chan_id: ChannelId, deleted_acks: u64) -> Self {
+        Self {
+            port_id_attr: port_id.into(),
+            chan_id_attr: chan_id.into(),
+            deleted_acks_attr: deleted_acks.into(),
+        }
+    }
+    pub fn port_id(&self) -> &PortId {
+        &self.port_id_attr.port_id
+    }
+    pub fn chan_id(&self) -> &ChannelId {
+        &self.chan_id_attr.channel_id
+    }
+    pub fn deleted_acks(&self) -> u64 {
+        self.deleted_acks_attr.deleted_acks
+    }
+
+    pub fn event_type(&self) -> &str {
+        CHANNEL_CLEANUP_EVENT
+    }
+}
+
+impl From<ChannelCleanup> for abci::Event {
+    fn from(ev: ChannelCleanup) -> Self {
+        abci::Event {
+            kind: CHANNEL_CLEANUP_EVENT.to_string(),
+            attributes: vec![
+                ev.port_id_attr.into(),
+                ev.chan_id_attr.into(),
+                ev.deleted_acks_attr.into(),
+            ],
+        }
+    }
+}
+
+/// A `ChannelFrozen` event is emitted when the host freezes a channel end,
+/// upon which its packets are no longer sent, received nor acknowledged.
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ChannelFrozen {
+    port_id_attr: PortIdAttribute,
+    chan_id_attr: ChannelIdAttribute,
+}
+
+impl ChannelFrozen {
+    pub fn new(port_id: PortId, chan_id: ChannelId) -> Self {
+        Self {
+            port_id_attr: port_id.into(),
+            chan_id_attr: chan_id.into(),
+        }
+    }
+    pub fn port_id(&self) -> &PortId {
+        &self.port_id_attr.port_id
+    }
+    pub fn chan_id(&self) -> &ChannelId {
+        &self.chan_id_attr.channel_id
+    }
+
+    pub fn event_type(&self) -> &str {
+        CHANNEL_FROZEN_EVENT
+    }
+}
+
+impl From<ChannelFrozen> for abci::Event {
+    fn from(ev: ChannelFrozen) -> Self {
+        abci::Event {
+            kind: CHANNEL_FROZEN_EVENT.to_string(),
+            attributes: vec![ev.port_id_attr.into(), ev.chan_id_attr.into()],
+        }
+    }
+}
+
+/// A `ChannelUnfrozen` event is emitted when the host unfreezes a channel
+/// end it froze.
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ChannelUnfrozen {
+    port_id_attr: PortIdAttribute,
+    chan_id_attr: ChannelIdAttribute,
+}
+
+impl ChannelUnfrozen {
+    pub fn new(port_id: PortId, chan_id: ChannelId) -> Self {
+        Self {
+            port_id_attr: port_id.into(),
+            chan_id_attr: chan_id.into(),
+        }
+    }
+    pub fn port_id(&self) -> &PortId {
+        &self.port_id_attr.port_id
+    }
+    pub fn chan_id(&self) -> &ChannelId {
+        &self.chan_id_attr.channel_id
+    }
+
+    pub fn event_type(&self) -> &str {
+        CHANNEL_UNFROZEN_EVENT
+    }
+}
+
+impl From<ChannelUnfrozen> for abci::Event {
+    fn from(ev: ChannelUnfrozen) -> Self {
+        abci::Event {
+            kind: CHANNEL_UNFROZEN_EVENT.to_string(),
+            attributes: vec![ev.port_id_attr.into(), ev.chan_id_attr.into()],
+        }
+    }
+}
+
 #[cfg_attr(
     feature = "parity-scale-codec",
     derive(
@@ -607,6 +769,7 @@ pub struct SendPacket {
     chan_id_attr_on_b: DstChannelIdAttribute,
     channel_ordering_attr: ChannelOrderingAttribute,
     conn_id_attr_on_a: PacketConnectionIdAttribute,
+    next_seq_send_attr_on_a: NextSequenceSendAttribute,
 }
 
 impl SendPacket {
@@ -622,6 +785,7 @@ impl SendPacket {
             chan_id_attr_on_b: packet.chan_id_on_b.into(),
             channel_ordering_attr: channel_ordering.into(),
             conn_id_attr_on_a: src_connection_id.into(),
+            next_seq_send_attr_on_a: packet.seq_on_a.increment().into(),
         }
     }
 
@@ -665,6 +829,12 @@ impl SendPacket {
         &self.conn_id_attr_on_a.connection_id
     }
 
+    /// Returns the next sequence to send on the channel, which follows the
+    /// sequence of the sent packet.
+    pub fn next_seq_send_on_a(&self) -> &Sequence {
+        &self.next_seq_send_attr_on_a.next_sequence_send
+    }
+
     pub fn event_type(&self) -> &str {
         SEND_PACKET_EVENT
     }
@@ -674,7 +844,7 @@ impl TryFrom<SendPacket> for abci::Event {
     type Error = ChannelError;
 
     fn try_from(v: SendPacket) -> Result<Self, Self::Error> {
-        let mut attributes = Vec::with_capacity(11);
+        let mut attributes = Vec::with_capacity(12);
         attributes.append(&mut v.packet_data_attr.try_into()?);
         attributes.push(v.timeout_height_attr_on_b.into());
         attributes.push(v.timeout_timestamp_attr_on_b.into());
@@ -685,6 +855,7 @@ impl TryFrom<SendPacket> for abci::Event {
         attributes.push(v.chan_id_attr_on_b.into());
         attributes.push(v.channel_ordering_attr.into());
         attributes.push(v.conn_id_attr_on_a.into());
+        attributes.push(v.next_seq_send_attr_on_a.into());
 
         Ok(abci::Event {
             kind: SEND_PACKET_EVENT.to_string(),
@@ -718,10 +889,17 @@ pub struct ReceivePacket {
     chan_id_attr_on_b: DstChannelIdAttribute,
     channel_ordering_attr: ChannelOrderingAttribute,
     conn_id_attr_on_b: PacketConnectionIdAttribute,
+    maybe_next_seq_recv_attr_on_b: Option<NextSequenceRecvAttribute>,
+    relayer_attr_on_b: RelayerAttribute,
 }
 
 impl ReceivePacket {
-    pub fn new(packet: Packet, channel_ordering: Order, dst_connection_id: ConnectionId) -> Self {
+    pub fn new(
+        packet: Packet,
+        channel_ordering: Order,
+        dst_connection_id: ConnectionId,
+        relayer: Signer,
+    ) -> Self {
         Self {
             packet_data_attr: packet.data.into(),
             timeout_height_attr_on_b: packet.timeout_height_on_b.into(),
@@ -733,6 +911,9 @@ impl ReceivePacket {
             chan_id_attr_on_b: packet.chan_id_on_b.into(),
             channel_ordering_attr: channel_ordering.into(),
             conn_id_attr_on_b: dst_connection_id.into(),
+            maybe_next_seq_recv_attr_on_b: matches!(channel_ordering, Order::Ordered)
+                .then(|| packet.seq_on_a.increment().into()),
+            relayer_attr_on_b: relayer.into(),
         }
     }
 
@@ -776,6 +957,20 @@ impl ReceivePacket {
         &self.conn_id_attr_on_b.connection_id
     }
 
+    /// Returns the next sequence to receive on the channel, which follows the
+    /// sequence of the received packet, if the channel is ordered.
+    pub fn next_seq_recv_on_b(&self) -> Option<&Sequence> {
+        self.maybe_next_seq_recv_attr_on_b
+            .as_ref()
+            .map(|attr| &attr.next_sequence_recv)
+    }
+
+    /// Returns the relayer of the packet, i.e. the signer of the message
+    /// receiving it.
+    pub fn relayer(&self) -> &Signer {
+        &self.relayer_attr_on_b.relayer
+    }
+
     pub fn event_type(&self) -> &str {
         RECEIVE_PACKET_EVENT
     }
@@ -785,7 +980,7 @@ impl TryFrom<ReceivePacket> for abci::Event {
     type Error = ChannelError;
 
     fn try_from(v: ReceivePacket) -> Result<Self, Self::Error> {
-        let mut attributes = Vec::with_capacity(11);
+        let mut attributes = Vec::with_capacity(13);
         attributes.append(&mut v.packet_data_attr.try_into()?);
         attributes.push(v.timeout_height_attr_on_b.into());
         attributes.push(v.timeout_timestamp_attr_on_b.into());
@@ -796,6 +991,10 @@ impl TryFrom<ReceivePacket> for abci::Event {
         attributes.push(v.chan_id_attr_on_b.into());
         attributes.push(v.channel_ordering_attr.into());
         attributes.push(v.conn_id_attr_on_b.into());
+        if let Some(attr) = v.maybe_next_seq_recv_attr_on_b {
+            attributes.push(attr.into());
+        }
+        attributes.push(v.relayer_attr_on_b.into());
 
         Ok(abci::Event {
             kind: RECEIVE_PACKET_EVENT.to_string(),
@@ -829,6 +1028,7 @@ pub struct WriteAcknowledgement {
     chan_id_attr_on_b: DstChannelIdAttribute,
     acknowledgement: AcknowledgementAttribute,
     conn_id_attr_on_b: PacketConnectionIdAttribute,
+    maybe_ack_success_attr_on_b: Option<AcknowledgementSuccessAttribute>,
 }
 
 impl WriteAcknowledgement {
@@ -846,6 +1046,9 @@ impl WriteAcknowledgement {
             chan_id_attr_on_a: packet.chan_id_on_a.into(),
             port_id_attr_on_b: packet.port_id_on_b.into(),
             chan_id_attr_on_b: packet.chan_id_on_b.into(),
+            maybe_ack_success_attr_on_b: AcknowledgementSuccessAttribute::from_acknowledgement(
+                &acknowledgement,
+            ),
             acknowledgement: acknowledgement.into(),
             conn_id_attr_on_b: conn_id_on_b.into(),
         }
@@ -891,6 +1094,14 @@ impl WriteAcknowledgement {
         &self.conn_id_attr_on_b.connection_id
     }
 
+    /// Returns whether the acknowledgement is successful, if it follows the
+    /// standard JSON envelope.
+    pub fn ack_success(&self) -> Option<bool> {
+        self.maybe_ack_success_attr_on_b
+            .as_ref()
+            .map(|attr| attr.success)
+    }
+
     pub fn event_type(&self) -> &str {
         WRITE_ACK_EVENT
     }
@@ -900,7 +1111,7 @@ impl TryFrom<WriteAcknowledgement> for abci::Event {
     type Error = ChannelError;
 
     fn try_from(v: WriteAcknowledgement) -> Result<Self, Self::Error> {
-        let mut attributes = Vec::with_capacity(11);
+        let mut attributes = Vec::with_capacity(12);
         attributes.append(&mut v.packet_data.try_into()?);
         attributes.push(v.timeout_height_attr_on_b.into());
         attributes.push(v.timeout_timestamp_attr_on_b.into());
@@ -911,6 +1122,9 @@ impl TryFrom<WriteAcknowledgement> for abci::Event {
         attributes.push(v.chan_id_attr_on_b.into());
         attributes.append(&mut v.acknowledgement.try_into()?);
         attributes.push(v.conn_id_attr_on_b.into());
+        if let Some(attr) = v.maybe_ack_success_attr_on_b {
+            attributes.push(attr.into());
+        }
 
         Ok(abci::Event {
             kind: WRITE_ACK_EVENT.to_string(),
@@ -943,10 +1157,19 @@ pub struct AcknowledgePacket {
     chan_id_attr_on_b: DstChannelIdAttribute,
     channel_ordering_attr: ChannelOrderingAttribute,
     conn_id_attr_on_a: PacketConnectionIdAttribute,
+    maybe_next_seq_ack_attr_on_a: Option<NextSequenceAckAttribute>,
+    maybe_ack_success_attr_on_a: Option<AcknowledgementSuccessAttribute>,
+    relayer_attr_on_a: RelayerAttribute,
 }
 
 impl AcknowledgePacket {
-    pub fn new(packet: Packet, channel_ordering: Order, src_connection_id: ConnectionId) -> Self {
+    pub fn new(
+        packet: Packet,
+        channel_ordering: Order,
+        src_connection_id: ConnectionId,
+        acknowledgement: &Acknowledgement,
+        relayer: Signer,
+    ) -> Self {
         Self {
             timeout_height_attr_on_b: packet.timeout_height_on_b.into(),
             timeout_timestamp_attr_on_b: packet.timeout_timestamp_on_b.into(),
@@ -957,6 +1180,12 @@ impl AcknowledgePacket {
             chan_id_attr_on_b: packet.chan_id_on_b.into(),
             channel_ordering_attr: channel_ordering.into(),
             conn_id_attr_on_a: src_connection_id.into(),
+            maybe_next_seq_ack_attr_on_a: matches!(channel_ordering, Order::Ordered)
+                .then(|| packet.seq_on_a.increment().into()),
+            maybe_ack_success_attr_on_a: AcknowledgementSuccessAttribute::from_acknowledgement(
+                acknowledgement,
+            ),
+            relayer_attr_on_a: relayer.into(),
         }
     }
 
@@ -996,6 +1225,28 @@ impl AcknowledgePacket {
         &self.conn_id_attr_on_a.connection_id
     }
 
+    /// Returns the next sequence to acknowledge on the channel, which follows
+    /// the sequence of the acknowledged packet, if the channel is ordered.
+    pub fn next_seq_ack_on_a(&self) -> Option<&Sequence> {
+        self.maybe_next_seq_ack_attr_on_a
+            .as_ref()
+            .map(|attr| &attr.next_sequence_ack)
+    }
+
+    /// Returns whether the acknowledgement is successful, if it follows the
+    /// standard JSON envelope.
+    pub fn ack_success(&self) -> Option<bool> {
+        self.maybe_ack_success_attr_on_a
+            .as_ref()
+            .map(|attr| attr.success)
+    }
+
+    /// Returns the relayer of the acknowledgement, i.e. the signer of the
+    /// message acknowledging the packet.
+    pub fn relayer(&self) -> &Signer {
+        &self.relayer_attr_on_a.relayer
+    }
+
     pub fn event_type(&self) -> &str {
         ACK_PACKET_EVENT
     }
@@ -1005,19 +1256,28 @@ impl TryFrom<AcknowledgePacket> for abci::Event {
     type Error = ChannelError;
 
     fn try_from(v: AcknowledgePacket) -> Result<Self, Self::Error> {
+        let mut attributes = vec![
+            v.timeout_height_attr_on_b.into(),
+            v.timeout_timestamp_attr_on_b.into(),
+            v.seq_on_a.into(),
+            v.port_id_attr_on_a.into(),
+            v.chan_id_attr_on_a.into(),
+            v.port_id_attr_on_b.into(),
+            v.chan_id_attr_on_b.into(),
+            v.channel_ordering_attr.into(),
+            v.conn_id_attr_on_a.into(),
+        ];
+        if let Some(attr) = v.maybe_next_seq_ack_attr_on_a {
+            attributes.push(attr.into());
+        }
+        if let Some(attr) = v.maybe_ack_success_attr_on_a {
+            attributes.push(attr.into());
+        }
+        attributes.push(v.relayer_attr_on_a.into());
+
         Ok(abci::Event {
             kind: ACK_PACKET_EVENT.to_string(),
-            attributes: vec![
-                v.timeout_height_attr_on_b.into(),
-                v.timeout_timestamp_attr_on_b.into(),
-                v.seq_on_a.into(),
-                v.port_id_attr_on_a.into(),
-                v.chan_id_attr_on_a.into(),
-                v.port_id_attr_on_b.into(),
-                v.chan_id_attr_on_b.into(),
-                v.channel_ordering_attr.into(),
-                v.conn_id_attr_on_a.into(),
-            ],
+            attributes,
         })
     }
 }
@@ -1120,6 +1380,7 @@ impl TryFrom<TimeoutPacket> for abci::Event {
 
 #[cfg(test)]
 mod tests {
+    use rstest::rstest;
     use tendermint::abci::Event as AbciEvent;
 
     use super::*;
@@ -1264,4 +1525,154 @@ mod tests {
             }
         }
     }
+
+    fn attribute_value(event: &AbciEvent, key: &str) -> Option<String> {
+        event
+            .attributes
+            .iter()
+            .find(|attr| attr.key_str().ok() == Some(key))
+            .map(|attr| attr.value_str().unwrap().to_string())
+    }
+
+    #[rstest]
+    #[case(Order::Ordered, Some("8"))]
+    #[case(Order::Unordered, None)]
+    fn ibc_to_abci_packet_next_sequences(
+        #[case] ordering: Order,
+        #[case] expected_next_seq: Option<&str>,
+    ) {
+        let packet = Packet {
+            seq_on_a: 7.into(),
+            port_id_on_a: PortId::transfer(),
+            chan_id_on_a: ChannelId::zero(),
+            port_id_on_b: PortId::transfer(),
+            chan_id_on_b: ChannelId::new(1),
+            data: b"data".to_vec(),
+            timeout_height_on_b: TimeoutHeight::no_timeout(),
+            timeout_timestamp_on_b: Timestamp::none(),
+        };
+
+        let send: AbciEvent = SendPacket::new(packet.clone(), ordering, ConnectionId::zero())
+            .try_into()
+            .unwrap();
+        assert_eq!(
+            attribute_value(&send, "packet_next_sequence_send").as_deref(),
+            Some("8")
+        );
+
+        let recv: AbciEvent = ReceivePacket::new(
+            packet.clone(),
+            ordering,
+            ConnectionId::zero(),
+            "relayer_b".to_string().into(),
+        )
+        .try_into()
+        .unwrap();
+        assert_eq!(
+            attribute_value(&recv, "packet_next_sequence_recv").as_deref(),
+            expected_next_seq
+        );
+
+        let ack: AbciEvent = AcknowledgePacket::new(
+            packet,
+            ordering,
+            ConnectionId::zero(),
+            &b"ack".to_vec().try_into().unwrap(),
+            "relayer_a".to_string().into(),
+        )
+        .try_into()
+        .unwrap();
+        assert_eq!(
+            attribute_value(&ack, "packet_next_sequence_ack").as_deref(),
+            expected_next_seq
+        );
+    }
+
+    #[rstest]
+    #[case::success(br#"{"result":"AQ=="}"#, Some("true"))]
+    #[case::error(
+        br#"{"error":"ABCI code: 1: error handling packet: see events for details"}"#,
+        Some("false")
+    )]
+    #[case::non_standard(b"ack", None)]
+    fn ibc_to_abci_packet_ack_success(#[case] ack: &[u8], #[case] expected_success: Option<&str>) {
+        let packet = Packet {
+            seq_on_a: 7.into(),
+            port_id_on_a: PortId::transfer(),
+            chan_id_on_a: ChannelId::zero(),
+            port_id_on_b: PortId::transfer(),
+            chan_id_on_b: ChannelId::new(1),
+            data: b"data".to_vec(),
+            timeout_height_on_b: TimeoutHeight::no_timeout(),
+            timeout_timestamp_on_b: Timestamp::none(),
+        };
+        let ack = Acknowledgement::try_from(ack.to_vec()).unwrap();
+
+        let write_ack =
+            WriteAcknowledgement::new(packet.clone(), ack.clone(), ConnectionId::zero());
+        assert_eq!(
+            write_ack.ack_success(),
+            expected_success.map(|success| success == "true")
+        );
+        let write_ack: AbciEvent = write_ack.try_into().unwrap();
+        assert_eq!(
+            attribute_value(&write_ack, "packet_ack_success").as_deref(),
+            expected_success
+        );
+
+        let ack: AbciEvent = AcknowledgePacket::new(
+            packet,
+            Order::Unordered,
+            ConnectionId::zero(),
+            &ack,
+            "relayer_a".to_string().into(),
+        )
+        .try_into()
+        .unwrap();
+        assert_eq!(
+            attribute_value(&ack, "packet_ack_success").as_deref(),
+            expected_success
+        );
+    }
+
+    #[test]
+    fn ibc_to_abci_packet_relayer() {
+        let packet = Packet {
+            seq_on_a: 7.into(),
+            port_id_on_a: PortId::transfer(),
+            chan_id_on_a: ChannelId::zero(),
+            port_id_on_b: PortId::transfer(),
+            chan_id_on_b: ChannelId::new(1),
+            data: b"data".to_vec(),
+            timeout_height_on_b: TimeoutHeight::no_timeout(),
+            timeout_timestamp_on_b: Timestamp::none(),
+        };
+
+        let recv = ReceivePacket::new(
+            packet.clone(),
+            Order::Unordered,
+            ConnectionId::zero(),
+            "relayer_b".to_string().into(),
+        );
+        assert_eq!(recv.relayer().as_ref(), "relayer_b");
+        let recv: AbciEvent = recv.try_into().unwrap();
+        assert_eq!(
+            attribute_value(&recv, "relayer").as_deref(),
+            Some("relayer_b")
+        );
+
+        let ack = AcknowledgePacket::new(
+            packet,
+            Order::Unordered,
+            ConnectionId::zero(),
+            &b"ack".to_vec().try_into().unwrap(),
+            "relayer_a".to_string().into(),
+        );
+        assert_eq!(ack.relayer().as_ref(), "relayer_a");
+        let ack: AbciEvent = ack.try_into().unwrap();
+        assert_eq!(
+            attribute_value(&ack, "relayer").as_deref(),
+            Some("relayer_a")
+        );
+    }
 }
@@ -6,11 +6,11 @@ use core::str;
 use derive_more::From;
 use ibc_core_host_types::identifiers::{ChannelId, ConnectionId, PortId, Sequence};
 use ibc_primitives::prelude::*;
-use ibc_primitives::Timestamp;
+use ibc_primitives::{Signer, Timestamp};
 use subtle_encoding::hex;
 use tendermint::abci;
 
-use crate::acknowledgement::Acknowledgement;
+use crate::acknowledgement::{Acknowledgement, AcknowledgementStatus};
 use crate::channel::Order;
 use crate::error::ChannelError;
 use crate::timeout::TimeoutHeight;
@@ -27,7 +27,13 @@ const PKT_TIMEOUT_HEIGHT_ATTRIBUTE_KEY: &str = "packet_timeout_height";
 const PKT_TIMEOUT_TIMESTAMP_ATTRIBUTE_KEY: &str = "packet_timeout_timestamp";
 const PKT_ACK_ATTRIBUTE_KEY: &str = "packet_ack";
 const PKT_ACK_HEX_ATTRIBUTE_KEY: &str = "packet_ack_hex";
+const PKT_ACK_SUCCESS_ATTRIBUTE_KEY: &str = "packet_ack_success";
+const PKT_RELAYER_ATTRIBUTE_KEY: &str = "relayer";
 const PKT_CONNECTION_ID_ATTRIBUTE_KEY: &str = "packet_connection";
+const PKT_NEXT_SEQ_SEND_ATTRIBUTE_KEY: &str = "packet_next_sequence_send";
+const PKT_NEXT_SEQ_RECV_ATTRIBUTE_KEY: &str = "packet_next_sequence_recv";
+const PKT_NEXT_SEQ_ACK_ATTRIBUTE_KEY: &str = "packet_next_sequence_ack";
+const PKT_DELETED_ACKS_ATTRIBUTE_KEY: &str = "deleted_packet_acks";
 
 #[cfg_attr(
     feature = "parity-scale-codec",
@@ -150,6 +156,90 @@ impl From<SequenceAttribute> for abci::EventAttribute {
     }
 }
 
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, From, PartialEq, Eq)]
+pub struct NextSequenceSendAttribute {
+    pub next_sequence_send: Sequence,
+}
+
+impl From<NextSequenceSendAttribute> for abci::EventAttribute {
+    fn from(attr: NextSequenceSendAttribute) -> Self {
+        (
+            PKT_NEXT_SEQ_SEND_ATTRIBUTE_KEY,
+            attr.next_sequence_send.to_string(),
+        )
+            .into()
+    }
+}
+
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, From, PartialEq, Eq)]
+pub struct NextSequenceRecvAttribute {
+    pub next_sequence_recv: Sequence,
+}
+
+impl From<NextSequenceRecvAttribute> for abci::EventAttribute {
+    fn from(attr: NextSequenceRecvAttribute) -> Self {
+        (
+            PKT_NEXT_SEQ_RECV_ATTRIBUTE_KEY,
+            attr.next_sequence_recv.to_string(),
+        )
+            .into()
+    }
+}
+
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, From, PartialEq, Eq)]
+pub struct NextSequenceAckAttribute {
+    pub next_sequence_ack: Sequence,
+}
+
+impl From<NextSequenceAckAttribute> for abci::EventAttribute {
+    fn from(attr: NextSequenceAckAttribute) -> Self {
+        (
+            PKT_NEXT_SEQ_ACK_ATTRIBUTE_KEY,
+            attr.next_sequence_ack.to_string(),
+        )
+            .into()
+    }
+}
+
 #[cfg_attr(
     feature = "parity-scale-codec",
     derive(
@@ -338,3 +428,93 @@ impl TryFrom<AcknowledgementAttribute> for Vec<abci::EventAttribute> {
         Ok(tags)
     }
 }
+
+/// Whether an acknowledgement following the standard JSON envelope, i.e.
+/// `{"result":"<base64>"}` or `{"error":"<string>"}`, is successful.
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, From, PartialEq, Eq)]
+pub struct AcknowledgementSuccessAttribute {
+    pub success: bool,
+}
+
+impl AcknowledgementSuccessAttribute {
+    /// Returns the attribute of the given acknowledgement, if it follows the
+    /// standard JSON envelope.
+    pub fn from_acknowledgement(acknowledgement: &Acknowledgement) -> Option<Self> {
+        AcknowledgementStatus::try_from(acknowledgement)
+            .ok()
+            .map(|status| status.is_successful().into())
+    }
+}
+
+impl From<AcknowledgementSuccessAttribute> for abci::EventAttribute {
+    fn from(attr: AcknowledgementSuccessAttribute) -> Self {
+        (PKT_ACK_SUCCESS_ATTRIBUTE_KEY, attr.success.to_string()).into()
+    }
+}
+
+/// The relayer of a packet datagram, i.e. the signer of the message
+/// submitting it.
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, From, PartialEq, Eq)]
+pub struct RelayerAttribute {
+    pub relayer: Signer,
+}
+
+impl From<RelayerAttribute> for abci::EventAttribute {
+    fn from(attr: RelayerAttribute) -> Self {
+        (PKT_RELAYER_ATTRIBUTE_KEY, attr.relayer.to_string()).into()
+    }
+}
+
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, From, PartialEq, Eq)]
+pub struct DeletedAcknowledgementsAttribute {
+    pub deleted_acks: u64,
+}
+
+impl From<DeletedAcknowledgementsAttribute> for abci::EventAttribute {
+    fn from(attr: DeletedAcknowledgementsAttribute) -> Self {
+        (
+            PKT_DELETED_ACKS_ATTRIBUTE_KEY,
+            attr.deleted_acks.to_string(),
+        )
+            .into()
+    }
+}
@@ -26,10 +26,18 @@ pub mod timeout;
 
 pub mod acknowledgement;
 pub mod commitment;
+#[cfg(feature = "v2")]
+pub mod v2;
 mod version;
-pub use version::Version;
+#[cfg(feature = "serde")]
+pub use version::{FeeVersion, MiddlewareVersion, FEE_VERSION};
+pub use version::{Version, MAX_VERSION_LENGTH};
 
 /// Re-exports ICS-04 proto types from the `ibc-proto` crate
+///
+/// The domain types of this crate convert from and into the `v1` protos. The
+/// `v2` channel protos are not part of the version of `ibc-proto` used by
+/// `ibc-rs` yet, and will be re-exported here along with their conversions.
 pub mod proto {
     pub use ibc_proto::ibc::core::channel::*;
 }
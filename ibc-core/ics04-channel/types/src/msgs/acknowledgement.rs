@@ -1,7 +1,8 @@
 use ibc_core_client_types::Height;
 use ibc_core_commitment_types::commitment::CommitmentProofBytes;
+use ibc_core_host_types::identifiers::IdentifierMode;
 use ibc_primitives::prelude::*;
-use ibc_primitives::Signer;
+use ibc_primitives::{Msg, Signer};
 use ibc_proto::ibc::core::channel::v1::MsgAcknowledgement as RawMsgAcknowledgement;
 use ibc_proto::Protobuf;
 
@@ -20,6 +21,11 @@ pub const ACKNOWLEDGEMENT_TYPE_URL: &str = "/ibc.core.channel.v1.MsgAcknowledgem
 )]
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "builder",
+    derive(typed_builder::TypedBuilder),
+    builder(field_defaults(setter(into)))
+)]
 pub struct MsgAcknowledgement {
     pub packet: Packet,
     pub acknowledgement: Acknowledgement,
@@ -30,6 +36,28 @@ pub struct MsgAcknowledgement {
     pub signer: Signer,
 }
 
+impl MsgAcknowledgement {
+    /// Performs the stateless validation of the message, without access to the host state.
+    pub fn validate_basic(&self) -> Result<(), PacketError> {
+        self.packet.validate_basic()?;
+        if self.proof_acked_on_b.is_empty() {
+            return Err(PacketError::InvalidProof);
+        }
+        if self.signer.is_empty() {
+            return Err(PacketError::InvalidSigner {
+                reason: "signer cannot be empty".to_string(),
+            });
+        }
+        Ok(())
+    }
+}
+
+impl Msg for MsgAcknowledgement {
+    fn signer(&self) -> &Signer {
+        &self.signer
+    }
+}
+
 impl Protobuf<RawMsgAcknowledgement> for MsgAcknowledgement {}
 
 impl TryFrom<RawMsgAcknowledgement> for MsgAcknowledgement {
@@ -37,10 +65,11 @@ impl TryFrom<RawMsgAcknowledgement> for MsgAcknowledgement {
 
     fn try_from(raw_msg: RawMsgAcknowledgement) -> Result<Self, Self::Error> {
         Ok(MsgAcknowledgement {
-            packet: raw_msg
-                .packet
-                .ok_or(PacketError::MissingPacket)?
-                .try_into()?,
+            packet: Packet::try_from_raw_with_modes(
+                raw_msg.packet.ok_or(PacketError::MissingPacket)?,
+                IdentifierMode::Exact,
+                IdentifierMode::Lenient,
+            )?,
             acknowledgement: raw_msg.acknowledgement.try_into()?,
             proof_acked_on_b: raw_msg
                 .proof_acked
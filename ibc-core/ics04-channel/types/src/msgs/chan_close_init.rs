@@ -1,6 +1,6 @@
 use ibc_core_host_types::identifiers::{ChannelId, PortId};
 use ibc_primitives::prelude::*;
-use ibc_primitives::Signer;
+use ibc_primitives::{Msg, Signer};
 use ibc_proto::ibc::core::channel::v1::MsgChannelCloseInit as RawMsgChannelCloseInit;
 use ibc_proto::Protobuf;
 
@@ -18,12 +18,35 @@ pub const CHAN_CLOSE_INIT_TYPE_URL: &str = "/ibc.core.channel.v1.MsgChannelClose
 )]
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "builder",
+    derive(typed_builder::TypedBuilder),
+    builder(field_defaults(setter(into)))
+)]
 pub struct MsgChannelCloseInit {
     pub port_id_on_a: PortId,
     pub chan_id_on_a: ChannelId,
     pub signer: Signer,
 }
 
+impl MsgChannelCloseInit {
+    /// Performs the stateless validation of the message, without access to the host state.
+    pub fn validate_basic(&self) -> Result<(), ChannelError> {
+        if self.signer.is_empty() {
+            return Err(ChannelError::InvalidSigner {
+                reason: "signer cannot be empty".to_string(),
+            });
+        }
+        Ok(())
+    }
+}
+
+impl Msg for MsgChannelCloseInit {
+    fn signer(&self) -> &Signer {
+        &self.signer
+    }
+}
+
 impl Protobuf<RawMsgChannelCloseInit> for MsgChannelCloseInit {}
 
 impl TryFrom<RawMsgChannelCloseInit> for MsgChannelCloseInit {
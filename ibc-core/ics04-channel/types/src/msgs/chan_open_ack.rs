@@ -1,8 +1,8 @@
 use ibc_core_client_types::Height;
 use ibc_core_commitment_types::commitment::CommitmentProofBytes;
-use ibc_core_host_types::identifiers::{ChannelId, PortId};
+use ibc_core_host_types::identifiers::{ChannelId, IdentifierMode, PortId};
 use ibc_primitives::prelude::*;
-use ibc_primitives::Signer;
+use ibc_primitives::{Msg, Signer};
 use ibc_proto::ibc::core::channel::v1::MsgChannelOpenAck as RawMsgChannelOpenAck;
 use ibc_proto::Protobuf;
 
@@ -20,6 +20,11 @@ pub const CHAN_OPEN_ACK_TYPE_URL: &str = "/ibc.core.channel.v1.MsgChannelOpenAck
 )]
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "builder",
+    derive(typed_builder::TypedBuilder),
+    builder(field_defaults(setter(into)))
+)]
 pub struct MsgChannelOpenAck {
     pub port_id_on_a: PortId,
     pub chan_id_on_a: ChannelId,
@@ -30,6 +35,28 @@ pub struct MsgChannelOpenAck {
     pub signer: Signer,
 }
 
+impl MsgChannelOpenAck {
+    /// Performs the stateless validation of the message, without access to the host state.
+    pub fn validate_basic(&self) -> Result<(), ChannelError> {
+        if self.proof_chan_end_on_b.is_empty() {
+            return Err(ChannelError::InvalidProof);
+        }
+        if self.signer.is_empty() {
+            return Err(ChannelError::InvalidSigner {
+                reason: "signer cannot be empty".to_string(),
+            });
+        }
+        self.version_on_b.validate_basic()?;
+        Ok(())
+    }
+}
+
+impl Msg for MsgChannelOpenAck {
+    fn signer(&self) -> &Signer {
+        &self.signer
+    }
+}
+
 impl Protobuf<RawMsgChannelOpenAck> for MsgChannelOpenAck {}
 
 impl TryFrom<RawMsgChannelOpenAck> for MsgChannelOpenAck {
@@ -39,7 +66,10 @@ impl TryFrom<RawMsgChannelOpenAck> for MsgChannelOpenAck {
         Ok(MsgChannelOpenAck {
             port_id_on_a: raw_msg.port_id.parse()?,
             chan_id_on_a: raw_msg.channel_id.parse()?,
-            chan_id_on_b: raw_msg.counterparty_channel_id.parse()?,
+            chan_id_on_b: ChannelId::from_str_with_mode(
+                &raw_msg.counterparty_channel_id,
+                IdentifierMode::Lenient,
+            )?,
             version_on_b: raw_msg.counterparty_version.into(),
             proof_chan_end_on_b: raw_msg
                 .proof_try
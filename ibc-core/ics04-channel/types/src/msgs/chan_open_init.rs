@@ -1,6 +1,6 @@
 use ibc_core_host_types::identifiers::{ConnectionId, PortId};
 use ibc_primitives::prelude::*;
-use ibc_primitives::Signer;
+use ibc_primitives::{Msg, Signer};
 use ibc_proto::ibc::core::channel::v1::MsgChannelOpenInit as RawMsgChannelOpenInit;
 use ibc_proto::Protobuf;
 
@@ -20,6 +20,11 @@ pub const CHAN_OPEN_INIT_TYPE_URL: &str = "/ibc.core.channel.v1.MsgChannelOpenIn
 )]
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "builder",
+    derive(typed_builder::TypedBuilder),
+    builder(field_defaults(setter(into)))
+)]
 pub struct MsgChannelOpenInit {
     pub port_id_on_a: PortId,
     pub connection_hops_on_a: Vec<ConnectionId>,
@@ -37,6 +42,30 @@ impl MsgChannelOpenInit {
     pub fn verify_connection_hops_length(&self) -> Result<(), ChannelError> {
         verify_connection_hops_length(&self.connection_hops_on_a, 1)
     }
+
+    /// Performs the stateless validation of the message, without access to the host state.
+    pub fn validate_basic(&self) -> Result<(), ChannelError> {
+        self.verify_connection_hops_length()?;
+        if self.ordering == Order::None {
+            return Err(ChannelError::InvalidOrderType {
+                expected: "Channel ordering cannot be None".to_string(),
+                actual: self.ordering.to_string(),
+            });
+        }
+        if self.signer.is_empty() {
+            return Err(ChannelError::InvalidSigner {
+                reason: "signer cannot be empty".to_string(),
+            });
+        }
+        self.version_proposal.validate_basic()?;
+        Ok(())
+    }
+}
+
+impl Msg for MsgChannelOpenInit {
+    fn signer(&self) -> &Signer {
+        &self.signer
+    }
 }
 
 impl Protobuf<RawMsgChannelOpenInit> for MsgChannelOpenInit {}
@@ -2,7 +2,7 @@ use ibc_core_client_types::Height;
 use ibc_core_commitment_types::commitment::CommitmentProofBytes;
 use ibc_core_host_types::identifiers::{ChannelId, ConnectionId, PortId};
 use ibc_primitives::prelude::*;
-use ibc_primitives::Signer;
+use ibc_primitives::{Msg, Signer};
 use ibc_proto::ibc::core::channel::v1::MsgChannelOpenTry as RawMsgChannelOpenTry;
 use ibc_proto::Protobuf;
 
@@ -22,6 +22,11 @@ pub const CHAN_OPEN_TRY_TYPE_URL: &str = "/ibc.core.channel.v1.MsgChannelOpenTry
 )]
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "builder",
+    derive(typed_builder::TypedBuilder),
+    builder(field_defaults(setter(into)))
+)]
 pub struct MsgChannelOpenTry {
     pub port_id_on_b: PortId,
     pub connection_hops_on_b: Vec<ConnectionId>,
@@ -35,6 +40,7 @@ pub struct MsgChannelOpenTry {
 
     #[deprecated(since = "0.22.0")]
     /// Only kept here for proper conversion to/from the raw type
+    #[cfg_attr(feature = "builder", builder(default = Version::empty(), setter(skip)))]
     pub version_proposal: Version,
 }
 
@@ -45,6 +51,33 @@ impl MsgChannelOpenTry {
     pub fn verify_connection_hops_length(&self) -> Result<(), ChannelError> {
         verify_connection_hops_length(&self.connection_hops_on_b, 1)
     }
+
+    /// Performs the stateless validation of the message, without access to the host state.
+    pub fn validate_basic(&self) -> Result<(), ChannelError> {
+        self.verify_connection_hops_length()?;
+        if self.ordering == Order::None {
+            return Err(ChannelError::InvalidOrderType {
+                expected: "Channel ordering cannot be None".to_string(),
+                actual: self.ordering.to_string(),
+            });
+        }
+        if self.proof_chan_end_on_a.is_empty() {
+            return Err(ChannelError::InvalidProof);
+        }
+        if self.signer.is_empty() {
+            return Err(ChannelError::InvalidSigner {
+                reason: "signer cannot be empty".to_string(),
+            });
+        }
+        self.version_supported_on_a.validate_basic()?;
+        Ok(())
+    }
+}
+
+impl Msg for MsgChannelOpenTry {
+    fn signer(&self) -> &Signer {
+        &self.signer
+    }
 }
 
 impl Protobuf<RawMsgChannelOpenTry> for MsgChannelOpenTry {}
@@ -1,5 +1,19 @@
 //! Message definitions for all ICS4 domain types: channel open & close handshake datagrams, as well
 //! as packets.
+//!
+//! With the `builder` feature enabled, each message can also be constructed
+//! through its `builder()`, whose `build()` method only compiles once all the
+//! required fields of the message are set, e.g.:
+//!
+//! ```ignore
+//! let msg = MsgRecvPacket::builder()
+//!     .packet(packet)
+//!     .proof_commitment_on_a(proof)
+//!     .proof_height_on_a(proof_height)
+//!     .signer(signer)
+//!     .build();
+//! msg.validate_basic()?;
+//! ```
 
 mod acknowledgement;
 mod chan_close_confirm;
@@ -24,10 +38,13 @@ pub use chan_open_init::*;
 pub use chan_open_try::*;
 use ibc_core_host_types::identifiers::*;
 use ibc_primitives::prelude::*;
+use ibc_primitives::{Msg, Signer};
 pub use recv_packet::*;
 pub use timeout::*;
 pub use timeout_on_close::*;
 
+use crate::error::{ChannelError, PacketError};
+
 /// All channel messages
 #[cfg_attr(
     feature = "borsh",
@@ -44,6 +61,33 @@ pub enum ChannelMsg {
     CloseConfirm(MsgChannelCloseConfirm),
 }
 
+impl ChannelMsg {
+    /// Performs the stateless validation of the wrapped message.
+    pub fn validate_basic(&self) -> Result<(), ChannelError> {
+        match self {
+            ChannelMsg::OpenInit(msg) => msg.validate_basic(),
+            ChannelMsg::OpenTry(msg) => msg.validate_basic(),
+            ChannelMsg::OpenAck(msg) => msg.validate_basic(),
+            ChannelMsg::OpenConfirm(msg) => msg.validate_basic(),
+            ChannelMsg::CloseInit(msg) => msg.validate_basic(),
+            ChannelMsg::CloseConfirm(msg) => msg.validate_basic(),
+        }
+    }
+}
+
+impl Msg for ChannelMsg {
+    fn signer(&self) -> &Signer {
+        match self {
+            ChannelMsg::OpenInit(msg) => msg.signer(),
+            ChannelMsg::OpenTry(msg) => msg.signer(),
+            ChannelMsg::OpenAck(msg) => msg.signer(),
+            ChannelMsg::OpenConfirm(msg) => msg.signer(),
+            ChannelMsg::CloseInit(msg) => msg.signer(),
+            ChannelMsg::CloseConfirm(msg) => msg.signer(),
+        }
+    }
+}
+
 /// All packet messages
 #[cfg_attr(
     feature = "borsh",
@@ -58,6 +102,29 @@ pub enum PacketMsg {
     TimeoutOnClose(MsgTimeoutOnClose),
 }
 
+impl PacketMsg {
+    /// Performs the stateless validation of the wrapped message.
+    pub fn validate_basic(&self) -> Result<(), PacketError> {
+        match self {
+            PacketMsg::Recv(msg) => msg.validate_basic(),
+            PacketMsg::Ack(msg) => msg.validate_basic(),
+            PacketMsg::Timeout(msg) => msg.validate_basic(),
+            PacketMsg::TimeoutOnClose(msg) => msg.validate_basic(),
+        }
+    }
+}
+
+impl Msg for PacketMsg {
+    fn signer(&self) -> &Signer {
+        match self {
+            PacketMsg::Recv(msg) => msg.signer(),
+            PacketMsg::Ack(msg) => msg.signer(),
+            PacketMsg::Timeout(msg) => msg.signer(),
+            PacketMsg::TimeoutOnClose(msg) => msg.signer(),
+        }
+    }
+}
+
 pub fn channel_msg_to_port_id(msg: &ChannelMsg) -> &PortId {
     match msg {
         ChannelMsg::OpenInit(msg) => &msg.port_id_on_a,
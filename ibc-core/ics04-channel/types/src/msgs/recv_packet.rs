@@ -1,7 +1,8 @@
 use ibc_core_client_types::Height;
 use ibc_core_commitment_types::commitment::CommitmentProofBytes;
+use ibc_core_host_types::identifiers::IdentifierMode;
 use ibc_primitives::prelude::*;
-use ibc_primitives::Signer;
+use ibc_primitives::{Msg, Signer};
 use ibc_proto::ibc::core::channel::v1::MsgRecvPacket as RawMsgRecvPacket;
 use ibc_proto::Protobuf;
 
@@ -19,6 +20,11 @@ pub const RECV_PACKET_TYPE_URL: &str = "/ibc.core.channel.v1.MsgRecvPacket";
 )]
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "builder",
+    derive(typed_builder::TypedBuilder),
+    builder(field_defaults(setter(into)))
+)]
 pub struct MsgRecvPacket {
     /// The packet to be received
     pub packet: Packet,
@@ -30,6 +36,28 @@ pub struct MsgRecvPacket {
     pub signer: Signer,
 }
 
+impl MsgRecvPacket {
+    /// Performs the stateless validation of the message, without access to the host state.
+    pub fn validate_basic(&self) -> Result<(), PacketError> {
+        self.packet.validate_basic()?;
+        if self.proof_commitment_on_a.is_empty() {
+            return Err(PacketError::InvalidProof);
+        }
+        if self.signer.is_empty() {
+            return Err(PacketError::InvalidSigner {
+                reason: "signer cannot be empty".to_string(),
+            });
+        }
+        Ok(())
+    }
+}
+
+impl Msg for MsgRecvPacket {
+    fn signer(&self) -> &Signer {
+        &self.signer
+    }
+}
+
 impl Protobuf<RawMsgRecvPacket> for MsgRecvPacket {}
 
 impl TryFrom<RawMsgRecvPacket> for MsgRecvPacket {
@@ -37,10 +65,11 @@ impl TryFrom<RawMsgRecvPacket> for MsgRecvPacket {
 
     fn try_from(raw_msg: RawMsgRecvPacket) -> Result<Self, Self::Error> {
         Ok(MsgRecvPacket {
-            packet: raw_msg
-                .packet
-                .ok_or(PacketError::MissingPacket)?
-                .try_into()?,
+            packet: Packet::try_from_raw_with_modes(
+                raw_msg.packet.ok_or(PacketError::MissingPacket)?,
+                IdentifierMode::Lenient,
+                IdentifierMode::Exact,
+            )?,
             proof_commitment_on_a: raw_msg
                 .proof_commitment
                 .try_into()
@@ -1,8 +1,8 @@
 use ibc_core_client_types::Height;
 use ibc_core_commitment_types::commitment::CommitmentProofBytes;
-use ibc_core_host_types::identifiers::Sequence;
+use ibc_core_host_types::identifiers::{IdentifierMode, Sequence};
 use ibc_primitives::prelude::*;
-use ibc_primitives::Signer;
+use ibc_primitives::{Msg, Signer};
 use ibc_proto::ibc::core::channel::v1::MsgTimeout as RawMsgTimeout;
 use ibc_proto::Protobuf;
 
@@ -21,6 +21,11 @@ pub const TIMEOUT_TYPE_URL: &str = "/ibc.core.channel.v1.MsgTimeout";
 )]
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "builder",
+    derive(typed_builder::TypedBuilder),
+    builder(field_defaults(setter(into)))
+)]
 pub struct MsgTimeout {
     pub packet: Packet,
     pub next_seq_recv_on_b: Sequence,
@@ -29,6 +34,31 @@ pub struct MsgTimeout {
     pub signer: Signer,
 }
 
+impl MsgTimeout {
+    /// Performs the stateless validation of the message, without access to the host state.
+    pub fn validate_basic(&self) -> Result<(), PacketError> {
+        self.packet.validate_basic()?;
+        if self.next_seq_recv_on_b.is_zero() {
+            return Err(PacketError::ZeroPacketSequence);
+        }
+        if self.proof_unreceived_on_b.is_empty() {
+            return Err(PacketError::InvalidProof);
+        }
+        if self.signer.is_empty() {
+            return Err(PacketError::InvalidSigner {
+                reason: "signer cannot be empty".to_string(),
+            });
+        }
+        Ok(())
+    }
+}
+
+impl Msg for MsgTimeout {
+    fn signer(&self) -> &Signer {
+        &self.signer
+    }
+}
+
 impl Protobuf<RawMsgTimeout> for MsgTimeout {}
 
 impl TryFrom<RawMsgTimeout> for MsgTimeout {
@@ -39,10 +69,11 @@ impl TryFrom<RawMsgTimeout> for MsgTimeout {
             return Err(PacketError::ZeroPacketSequence);
         }
         Ok(MsgTimeout {
-            packet: raw_msg
-                .packet
-                .ok_or(PacketError::MissingPacket)?
-                .try_into()?,
+            packet: Packet::try_from_raw_with_modes(
+                raw_msg.packet.ok_or(PacketError::MissingPacket)?,
+                IdentifierMode::Exact,
+                IdentifierMode::Lenient,
+            )?,
             next_seq_recv_on_b: Sequence::from(raw_msg.next_sequence_recv),
             proof_unreceived_on_b: raw_msg
                 .proof_unreceived
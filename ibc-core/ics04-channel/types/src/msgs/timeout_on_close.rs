@@ -1,8 +1,8 @@
 use ibc_core_client_types::Height;
 use ibc_core_commitment_types::commitment::CommitmentProofBytes;
-use ibc_core_host_types::identifiers::Sequence;
+use ibc_core_host_types::identifiers::{IdentifierMode, Sequence};
 use ibc_primitives::prelude::*;
-use ibc_primitives::Signer;
+use ibc_primitives::{Msg, Signer};
 use ibc_proto::ibc::core::channel::v1::MsgTimeoutOnClose as RawMsgTimeoutOnClose;
 use ibc_proto::Protobuf;
 
@@ -20,6 +20,11 @@ pub const TIMEOUT_ON_CLOSE_TYPE_URL: &str = "/ibc.core.channel.v1.MsgTimeoutOnCl
 )]
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "builder",
+    derive(typed_builder::TypedBuilder),
+    builder(field_defaults(setter(into)))
+)]
 pub struct MsgTimeoutOnClose {
     pub packet: Packet,
     pub next_seq_recv_on_b: Sequence,
@@ -29,6 +34,34 @@ pub struct MsgTimeoutOnClose {
     pub signer: Signer,
 }
 
+impl MsgTimeoutOnClose {
+    /// Performs the stateless validation of the message, without access to the host state.
+    pub fn validate_basic(&self) -> Result<(), PacketError> {
+        self.packet.validate_basic()?;
+        if self.next_seq_recv_on_b.is_zero() {
+            return Err(PacketError::ZeroPacketSequence);
+        }
+        if self.proof_unreceived_on_b.is_empty() {
+            return Err(PacketError::InvalidProof);
+        }
+        if self.proof_close_on_b.is_empty() {
+            return Err(PacketError::InvalidProof);
+        }
+        if self.signer.is_empty() {
+            return Err(PacketError::InvalidSigner {
+                reason: "signer cannot be empty".to_string(),
+            });
+        }
+        Ok(())
+    }
+}
+
+impl Msg for MsgTimeoutOnClose {
+    fn signer(&self) -> &Signer {
+        &self.signer
+    }
+}
+
 impl Protobuf<RawMsgTimeoutOnClose> for MsgTimeoutOnClose {}
 
 impl TryFrom<RawMsgTimeoutOnClose> for MsgTimeoutOnClose {
@@ -46,10 +79,11 @@ impl TryFrom<RawMsgTimeoutOnClose> for MsgTimeoutOnClose {
         }
 
         Ok(MsgTimeoutOnClose {
-            packet: raw_msg
-                .packet
-                .ok_or(PacketError::MissingPacket)?
-                .try_into()?,
+            packet: Packet::try_from_raw_with_modes(
+                raw_msg.packet.ok_or(PacketError::MissingPacket)?,
+                IdentifierMode::Exact,
+                IdentifierMode::Lenient,
+            )?,
             next_seq_recv_on_b: Sequence::from(raw_msg.next_sequence_recv),
             proof_unreceived_on_b: raw_msg
                 .proof_unreceived
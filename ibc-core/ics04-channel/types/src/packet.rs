@@ -1,10 +1,13 @@
 //! Defines the packet type
 use ibc_core_client_types::Height;
-use ibc_core_host_types::identifiers::{ChannelId, PortId, Sequence};
+use ibc_core_host_types::identifiers::{ChannelId, IdentifierMode, PortId, Sequence};
 use ibc_primitives::prelude::*;
 use ibc_primitives::Expiry::Expired;
 use ibc_primitives::Timestamp;
-use ibc_proto::ibc::core::channel::v1::{Packet as RawPacket, PacketState as RawPacketState};
+use ibc_proto::ibc::core::channel::v1::{
+    Packet as RawPacket, PacketId as RawPacketId, PacketState as RawPacketState,
+};
+use ibc_proto::Protobuf;
 
 use super::timeout::TimeoutHeight;
 use crate::error::PacketError;
@@ -145,6 +148,59 @@ impl Packet {
 
         height_timed_out || timestamp_timed_out
     }
+
+    /// Performs the stateless validation of the packet, enforcing the same
+    /// invariants as its decoding from the raw type.
+    pub fn validate_basic(&self) -> Result<(), PacketError> {
+        if self.seq_on_a.is_zero() {
+            return Err(PacketError::ZeroPacketSequence);
+        }
+        if self.data.is_empty() {
+            return Err(PacketError::ZeroPacketData);
+        }
+        if !self.timeout_height_on_b.is_set() && !self.timeout_timestamp_on_b.is_set() {
+            return Err(PacketError::MissingTimeout);
+        }
+        Ok(())
+    }
+}
+
+/// A packet an application requests to send from one of its callbacks, e.g.
+/// while receiving another packet, which the core handler sends once the
+/// callback returns.
+///
+/// Unlike [`Packet`], it carries neither a sequence, which the core handler
+/// assigns from the next sequence to send on the channel, nor the counterparty
+/// port and channel, which are those of the channel it is sent on.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct OutgoingPacket {
+    pub port_id_on_a: PortId,
+    pub chan_id_on_a: ChannelId,
+    pub data: Vec<u8>,
+    pub timeout_height_on_b: TimeoutHeight,
+    pub timeout_timestamp_on_b: Timestamp,
+}
+
+impl OutgoingPacket {
+    /// Builds the packet sent with the given sequence to the `port_id_on_b`
+    /// port and `chan_id_on_b` channel of the counterparty.
+    pub fn into_packet(
+        self,
+        seq_on_a: Sequence,
+        port_id_on_b: PortId,
+        chan_id_on_b: ChannelId,
+    ) -> Packet {
+        Packet {
+            seq_on_a,
+            port_id_on_a: self.port_id_on_a,
+            chan_id_on_a: self.chan_id_on_a,
+            port_id_on_b,
+            chan_id_on_b,
+            data: self.data,
+            timeout_height_on_b: self.timeout_height_on_b,
+            timeout_timestamp_on_b: self.timeout_timestamp_on_b,
+        }
+    }
 }
 
 /// Custom debug output to omit the packet data
@@ -168,6 +224,24 @@ impl TryFrom<RawPacket> for Packet {
     type Error = PacketError;
 
     fn try_from(raw_pkt: RawPacket) -> Result<Self, Self::Error> {
+        Packet::try_from_raw_with_modes(raw_pkt, IdentifierMode::Exact, IdentifierMode::Exact)
+    }
+}
+
+impl Packet {
+    /// Decodes a packet, parsing its source and destination channel
+    /// identifiers with the given strictness.
+    ///
+    /// Decoding with [`TryFrom`] parses both identifiers in the
+    /// [`IdentifierMode::Exact`] mode. The packet messages parse the
+    /// identifier assigned by the counterparty in the
+    /// [`IdentifierMode::Lenient`] mode, since it must match the one the
+    /// channel handshake recorded.
+    pub fn try_from_raw_with_modes(
+        raw_pkt: RawPacket,
+        mode_on_a: IdentifierMode,
+        mode_on_b: IdentifierMode,
+    ) -> Result<Self, PacketError> {
         if Sequence::from(raw_pkt.sequence).is_zero() {
             return Err(PacketError::ZeroPacketSequence);
         }
@@ -200,9 +274,9 @@ impl TryFrom<RawPacket> for Packet {
         Ok(Packet {
             seq_on_a: Sequence::from(raw_pkt.sequence),
             port_id_on_a: raw_pkt.source_port.parse()?,
-            chan_id_on_a: raw_pkt.source_channel.parse()?,
+            chan_id_on_a: ChannelId::from_str_with_mode(&raw_pkt.source_channel, mode_on_a)?,
             port_id_on_b: raw_pkt.destination_port.parse()?,
-            chan_id_on_b: raw_pkt.destination_channel.parse()?,
+            chan_id_on_b: ChannelId::from_str_with_mode(&raw_pkt.destination_channel, mode_on_b)?,
             data: raw_pkt.data,
             timeout_height_on_b: packet_timeout_height,
             timeout_timestamp_on_b,
@@ -225,6 +299,86 @@ impl From<Packet> for RawPacket {
     }
 }
 
+/// Identifies a packet by its source port, source channel and sequence.
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct PacketId {
+    pub port_id: PortId,
+    pub channel_id: ChannelId,
+    pub sequence: Sequence,
+}
+
+impl PacketId {
+    pub fn new(port_id: PortId, channel_id: ChannelId, sequence: Sequence) -> Self {
+        Self {
+            port_id,
+            channel_id,
+            sequence,
+        }
+    }
+}
+
+impl From<&Packet> for PacketId {
+    fn from(packet: &Packet) -> Self {
+        Self::new(
+            packet.port_id_on_a.clone(),
+            packet.chan_id_on_a.clone(),
+            packet.seq_on_a,
+        )
+    }
+}
+
+impl core::fmt::Display for PacketId {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> Result<(), core::fmt::Error> {
+        write!(
+            f,
+            "seq:{}, path:{}/{}",
+            self.sequence, self.channel_id, self.port_id,
+        )
+    }
+}
+
+impl Protobuf<RawPacketId> for PacketId {}
+
+impl TryFrom<RawPacketId> for PacketId {
+    type Error = PacketError;
+
+    fn try_from(raw: RawPacketId) -> Result<Self, Self::Error> {
+        if Sequence::from(raw.sequence).is_zero() {
+            return Err(PacketError::ZeroPacketSequence);
+        }
+
+        Ok(Self {
+            port_id: raw.port_id.parse()?,
+            channel_id: raw.channel_id.parse()?,
+            sequence: Sequence::from(raw.sequence),
+        })
+    }
+}
+
+impl From<PacketId> for RawPacketId {
+    fn from(packet_id: PacketId) -> Self {
+        Self {
+            port_id: packet_id.port_id.to_string(),
+            channel_id: packet_id.channel_id.to_string(),
+            sequence: packet_id.sequence.value(),
+        }
+    }
+}
+
 /// The packet state type.
 ///
 /// Each application defines the structure of the `data` field.
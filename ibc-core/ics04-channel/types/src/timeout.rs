@@ -1,12 +1,16 @@
 //! Types and utilities pertaining to packet timeouts.
 
 use core::fmt::{Display, Error as FmtError, Formatter};
+use core::time::Duration;
 
 use ibc_core_client_types::error::ClientError;
 use ibc_core_client_types::Height;
 use ibc_primitives::prelude::*;
+use ibc_primitives::{Timestamp, ZERO_DURATION};
 use ibc_proto::ibc::core::client::v1::Height as RawHeight;
 
+use crate::error::PacketError;
+
 /// Indicates a consensus height on the destination chain after which the packet
 /// will no longer be processed, and will instead count as having timed-out.
 ///
@@ -45,10 +49,16 @@ impl TimeoutHeight {
         }
     }
 
+    /// Returns a timeout height which never expires.
     pub fn no_timeout() -> Self {
         Self::Never
     }
 
+    /// Returns the timeout height `delta` blocks after `current`.
+    pub fn at_relative(delta: u64, current: Height) -> Self {
+        Self::At(current.add(delta))
+    }
+
     /// Revision number to be used in packet commitment computation
     pub fn commitment_revision_number(&self) -> u64 {
         match self {
@@ -142,6 +152,227 @@ impl Display for TimeoutHeight {
     }
 }
 
+/// The timeout of a packet on the destination chain, expressed as a height,
+/// a timestamp, or both, in which case the packet times out as soon as either
+/// of them is reached.
+///
+/// A packet must have at least one of them set, as a packet that never times
+/// out could otherwise never be cleaned up from an unordered channel if it is
+/// not received.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Timeout {
+    height: TimeoutHeight,
+    timestamp: Timestamp,
+}
+
+impl Timeout {
+    /// Creates a timeout, checking that at least one of `height` and
+    /// `timestamp` is set.
+    pub fn new(height: TimeoutHeight, timestamp: Timestamp) -> Result<Self, PacketError> {
+        let timeout = Self { height, timestamp };
+        timeout.validate()?;
+        Ok(timeout)
+    }
+
+    /// Creates a timeout at the given height, without timestamp.
+    pub fn at_height(height: Height) -> Self {
+        Self {
+            height: TimeoutHeight::At(height),
+            timestamp: Timestamp::none(),
+        }
+    }
+
+    /// Creates a timeout at the given timestamp, without height.
+    pub fn at_timestamp(timestamp: Timestamp) -> Result<Self, PacketError> {
+        Self::new(TimeoutHeight::Never, timestamp)
+    }
+
+    /// Creates a timeout `duration` after the `current` timestamp of the
+    /// destination chain, without height.
+    pub fn at_relative(duration: Duration, current: Timestamp) -> Result<Self, PacketError> {
+        let timestamp = (current + duration).map_err(PacketError::TimestampOverflow)?;
+        Self::at_timestamp(timestamp)
+    }
+
+    /// Creates a timeout `delta` blocks after the `current` height of the
+    /// destination chain, without timestamp.
+    pub fn at_relative_height(delta: u64, current: Height) -> Self {
+        Self::at_height(current.add(delta))
+    }
+
+    /// Returns the timeout height.
+    pub fn height(&self) -> TimeoutHeight {
+        self.height
+    }
+
+    /// Returns the timeout timestamp, which is not set if the timeout is only
+    /// in terms of height.
+    pub fn timestamp(&self) -> Timestamp {
+        self.timestamp
+    }
+
+    /// Checks that at least one of the timeout height and timestamp is set.
+    pub fn validate(&self) -> Result<(), PacketError> {
+        if !self.height.is_set() && !self.timestamp.is_set() {
+            return Err(PacketError::MissingTimeout);
+        }
+        Ok(())
+    }
+}
+
+/// The margins from which [`Timeout::recommended`] computes the timeout of a
+/// packet, out of the latest known state of the destination chain.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TimeoutMargins {
+    /// The time left to relay the packet before it times out.
+    pub relay_period: Duration,
+    /// The maximum drift between the clocks of the host and destination
+    /// chains.
+    pub max_clock_drift: Duration,
+    /// The shortest expected time between two blocks of the destination
+    /// chain, from which its height is estimated. A zero block time leaves
+    /// the timeout height unset.
+    pub expected_block_time: Duration,
+}
+
+impl Timeout {
+    /// Returns the earliest timeout leaving `margins.relay_period` to relay the
+    /// packet, given the current `host_timestamp` and the latest height and
+    /// timestamp of the destination chain known to its client on the host.
+    ///
+    /// The destination chain may have produced blocks since its latest known
+    /// state, and its clock may be ahead of the host's by up to the maximum
+    /// clock drift: both are accounted for, so that the packet does not time
+    /// out before the relay period elapses.
+    pub fn recommended(
+        latest_height: Height,
+        latest_timestamp: Timestamp,
+        host_timestamp: Timestamp,
+        margins: &TimeoutMargins,
+    ) -> Result<Self, PacketError> {
+        let window = margins.relay_period.saturating_add(margins.max_clock_drift);
+
+        let timestamp = (host_timestamp + window).map_err(PacketError::TimestampOverflow)?;
+
+        let height = match margins.expected_block_time.as_nanos() {
+            0 => TimeoutHeight::Never,
+            block_time => {
+                let elapsed = host_timestamp
+                    .duration_since(&latest_timestamp)
+                    .unwrap_or(ZERO_DURATION)
+                    .saturating_add(window)
+                    .as_nanos();
+                // Rounds up, as the timeout must not be reached before the
+                // end of the window.
+                let delta = u64::try_from((elapsed + block_time - 1) / block_time)
+                    .unwrap_or(u64::MAX)
+                    .min(u64::MAX - latest_height.revision_height());
+
+                TimeoutHeight::at_relative(delta, latest_height)
+            }
+        };
+
+        Self::new(height, timestamp)
+    }
+}
+
+impl From<Timeout> for (TimeoutHeight, Timestamp) {
+    fn from(timeout: Timeout) -> Self {
+        (timeout.height, timeout.timestamp)
+    }
+}
+
+impl Display for Timeout {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), FmtError> {
+        match (self.height.is_set(), self.timestamp.is_set()) {
+            (true, true) => write!(f, "height {}, timestamp {}", self.height, self.timestamp),
+            (false, true) => write!(f, "timestamp {}", self.timestamp),
+            _ => write!(f, "height {}", self.height),
+        }
+    }
+}
+
+#[cfg(test)]
+mod timeout_tests {
+    use super::*;
+
+    #[test]
+    fn test_timeout_requires_height_or_timestamp() {
+        assert!(matches!(
+            Timeout::new(TimeoutHeight::no_timeout(), Timestamp::none()),
+            Err(PacketError::MissingTimeout)
+        ));
+        assert!(matches!(
+            Timeout::at_timestamp(Timestamp::none()),
+            Err(PacketError::MissingTimeout)
+        ));
+
+        let height = Height::new(0, 10).expect("valid height");
+        let timeout = Timeout::at_relative_height(5, height);
+        assert_eq!(
+            timeout.height(),
+            TimeoutHeight::At(Height::new(0, 15).expect("valid height"))
+        );
+        assert!(!timeout.timestamp().is_set());
+    }
+
+    #[test]
+    fn test_timeout_at_relative() {
+        let current = Timestamp::from_nanoseconds(1_000).expect("valid timestamp");
+        let timeout =
+            Timeout::at_relative(Duration::from_nanos(500), current).expect("valid timeout");
+
+        assert_eq!(timeout.height(), TimeoutHeight::Never);
+        assert_eq!(timeout.timestamp().nanoseconds(), 1_500);
+
+        let (height, timestamp) = timeout.into();
+        assert_eq!(
+            Timeout::new(height, timestamp).expect("valid timeout"),
+            timeout
+        );
+    }
+
+    #[test]
+    fn test_recommended_timeout() {
+        let latest_height = Height::new(0, 100).expect("valid height");
+        let latest_timestamp =
+            Timestamp::from_nanoseconds(1_000_000_000_000).expect("valid timestamp");
+        let host_timestamp = (latest_timestamp + Duration::from_secs(30)).expect("no overflow");
+        let margins = TimeoutMargins {
+            relay_period: Duration::from_secs(600),
+            max_clock_drift: Duration::from_secs(5),
+            expected_block_time: Duration::from_secs(2),
+        };
+
+        let timeout =
+            Timeout::recommended(latest_height, latest_timestamp, host_timestamp, &margins)
+                .expect("valid timeout");
+
+        assert_eq!(
+            timeout.timestamp(),
+            (host_timestamp + Duration::from_secs(605)).expect("no overflow")
+        );
+        // 635 seconds of 2-second blocks, rounded up.
+        assert_eq!(
+            timeout.height(),
+            TimeoutHeight::At(Height::new(0, 418).expect("valid height"))
+        );
+
+        let timeout = Timeout::recommended(
+            latest_height,
+            latest_timestamp,
+            host_timestamp,
+            &TimeoutMargins {
+                expected_block_time: ZERO_DURATION,
+                ..margins
+            },
+        )
+        .expect("valid timeout");
+
+        assert_eq!(timeout.height(), TimeoutHeight::Never);
+    }
+}
+
 #[cfg(feature = "serde")]
 mod tests {
     use serde::{Deserialize, Serialize};
@@ -0,0 +1,134 @@
+//! Defines the IBC v2 packet commitment
+use ibc_core_commitment_types::proto::ics23::{HostFunctionsManager, HostFunctionsProvider};
+use ibc_primitives::prelude::*;
+
+use super::packet::{Packet, Payload};
+use crate::commitment::PacketCommitment;
+
+/// The version byte prefixing the hash input of IBC v2 packet commitments,
+/// distinguishing them from IBC v1 ones.
+const PACKET_COMMITMENT_VERSION: u8 = 2;
+
+/// Computes the commitment of an IBC v2 packet, following the current ibc-go
+/// implementation:
+///
+/// ```text
+/// sha256(
+///     0x02
+///     || sha256(destination_client)
+///     || sha256(timeout_timestamp)
+///     || sha256(payload_hash_1 || ... || payload_hash_n)
+/// )
+/// ```
+///
+/// where the hash of each payload is:
+///
+/// ```text
+/// sha256(
+///     sha256(source_port)
+///     || sha256(destination_port)
+///     || sha256(version)
+///     || sha256(encoding)
+///     || sha256(value)
+/// )
+/// ```
+pub fn compute_packet_commitment(packet: &Packet) -> PacketCommitment {
+    compute_packet_commitment_with::<HostFunctionsManager>(packet)
+}
+
+/// Same as [`compute_packet_commitment`], hashing with the SHA-256
+/// implementation of the given hash functions.
+pub fn compute_packet_commitment_with<H: HostFunctionsProvider>(
+    packet: &Packet,
+) -> PacketCommitment {
+    let payloads_hash = H::sha2_256(
+        &packet
+            .payloads
+            .iter()
+            .flat_map(compute_payload_hash::<H>)
+            .collect::<Vec<_>>(),
+    );
+
+    let mut hash_input = Vec::with_capacity(1 + 32 * 3);
+    hash_input.push(PACKET_COMMITMENT_VERSION);
+    hash_input.extend(H::sha2_256(packet.destination_client.as_bytes()));
+    hash_input.extend(H::sha2_256(&packet.timeout_timestamp.to_be_bytes()));
+    hash_input.extend(payloads_hash);
+
+    H::sha2_256(&hash_input).to_vec().into()
+}
+
+fn compute_payload_hash<H: HostFunctionsProvider>(payload: &Payload) -> [u8; 32] {
+    let hash_input: Vec<u8> = [
+        payload.source_port.as_bytes(),
+        payload.destination_port.as_bytes(),
+        payload.version.as_bytes(),
+        payload.encoding.as_bytes(),
+        payload.value.as_slice(),
+    ]
+    .into_iter()
+    .flat_map(H::sha2_256)
+    .collect();
+
+    H::sha2_256(&hash_input)
+}
+
+#[cfg(test)]
+mod tests {
+    use ibc_core_host_types::identifiers::{ClientId, PortId, Sequence};
+    use rstest::rstest;
+
+    use super::*;
+    use crate::v2::error::PacketError;
+
+    fn dummy_packet() -> Packet {
+        Packet {
+            sequence: Sequence::from(1),
+            source_client: ClientId::new("07-tendermint", 0).expect("valid client id"),
+            destination_client: ClientId::new("07-tendermint", 1).expect("valid client id"),
+            timeout_timestamp: 1_700_000_000,
+            payloads: vec![Payload {
+                source_port: PortId::transfer(),
+                destination_port: PortId::transfer(),
+                version: "ics20-1".to_string(),
+                encoding: "application/json".to_string(),
+                value: b"{}".to_vec(),
+            }],
+        }
+    }
+
+    #[test]
+    fn commitment_binds_packet_fields() {
+        let packet = dummy_packet();
+        let commitment = compute_packet_commitment(&packet);
+
+        assert_eq!(commitment, compute_packet_commitment(&dummy_packet()));
+
+        let mut other = packet.clone();
+        other.timeout_timestamp += 1;
+        assert_ne!(commitment, compute_packet_commitment(&other));
+
+        let mut other = packet;
+        other.payloads[0].value = b"[]".to_vec();
+        assert_ne!(commitment, compute_packet_commitment(&other));
+    }
+
+    #[rstest]
+    #[case::zero_sequence(|p: &mut Packet| p.sequence = Sequence::from(0))]
+    #[case::zero_timeout(|p: &mut Packet| p.timeout_timestamp = 0)]
+    #[case::no_payloads(|p: &mut Packet| p.payloads.clear())]
+    #[case::no_encoding(|p: &mut Packet| p.payloads[0].encoding.clear())]
+    fn invalid_packet(#[case] tamper: fn(&mut Packet)) {
+        let mut packet = dummy_packet();
+        assert!(packet.validate_basic().is_ok());
+
+        tamper(&mut packet);
+        assert!(matches!(
+            packet.validate_basic(),
+            Err(PacketError::ZeroPacketSequence
+                | PacketError::ZeroTimeoutTimestamp
+                | PacketError::EmptyPayloads
+                | PacketError::EmptyPayloadField { .. })
+        ));
+    }
+}
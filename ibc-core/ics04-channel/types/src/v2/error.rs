@@ -0,0 +1,51 @@
+//! Defines the IBC v2 packet error type
+use displaydoc::Display;
+use ibc_core_host_types::error::IdentifierError;
+use ibc_primitives::prelude::*;
+use ibc_primitives::AbciError;
+
+#[derive(Debug, Display)]
+pub enum PacketError {
+    /// packet sequence cannot be 0
+    ZeroPacketSequence,
+    /// packet timeout timestamp cannot be 0
+    ZeroTimeoutTimestamp,
+    /// packet must carry at least one payload
+    EmptyPayloads,
+    /// invalid identifier: `{0}`
+    InvalidIdentifier(IdentifierError),
+    /// payload `{field}` cannot be empty
+    EmptyPayloadField { field: String },
+}
+
+impl AbciError for PacketError {
+    fn codespace(&self) -> &'static str {
+        "packet_v2"
+    }
+
+    fn code(&self) -> u32 {
+        match self {
+            Self::ZeroPacketSequence => 2,
+            Self::ZeroTimeoutTimestamp => 3,
+            Self::EmptyPayloads => 4,
+            Self::InvalidIdentifier(_) => 5,
+            Self::EmptyPayloadField { .. } => 6,
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for PacketError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match &self {
+            Self::InvalidIdentifier(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<IdentifierError> for PacketError {
+    fn from(err: IdentifierError) -> Self {
+        Self::InvalidIdentifier(err)
+    }
+}
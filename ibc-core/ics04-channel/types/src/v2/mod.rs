@@ -0,0 +1,15 @@
+//! Preview of the domain types of the IBC v2 protocol, available behind the
+//! `v2` feature.
+//!
+//! IBC v2 packets are routed between clients, without connection or channel
+//! handshakes, and carry a list of payloads, each addressed to an application
+//! port. The specification is not final yet, hence these types are subject to
+//! breaking changes, and do not convert from and into protos, which are not
+//! part of the version of `ibc-proto` used by `ibc-rs` yet.
+
+mod commitment;
+pub mod error;
+mod packet;
+
+pub use commitment::*;
+pub use packet::*;
@@ -0,0 +1,115 @@
+//! Defines the IBC v2 packet and payload types
+use ibc_core_host_types::identifiers::{ClientId, PortId, Sequence};
+use ibc_primitives::prelude::*;
+use ibc_primitives::Timestamp;
+
+use super::error::PacketError;
+
+/// The data sent to an application of the counterparty chain within a
+/// [`Packet`].
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Payload {
+    /// The port of the sending application.
+    pub source_port: PortId,
+    /// The port of the receiving application.
+    pub destination_port: PortId,
+    /// The version of the application, e.g. `ics20-1`.
+    pub version: String,
+    /// The encoding of `value`, e.g. `application/json`.
+    pub encoding: String,
+    /// The application data, encoded with `encoding`.
+    #[cfg_attr(
+        feature = "serde",
+        serde(serialize_with = "ibc_core_commitment_types::serializer::ser_hex_upper")
+    )]
+    pub value: Vec<u8>,
+}
+
+impl Payload {
+    /// Checks that the version and the encoding of the payload are set.
+    pub fn validate_basic(&self) -> Result<(), PacketError> {
+        for (field, value) in [("version", &self.version), ("encoding", &self.encoding)] {
+            if value.is_empty() {
+                return Err(PacketError::EmptyPayloadField {
+                    field: field.to_string(),
+                });
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// The IBC v2 packet type, routed from the client `source_client` of the
+/// sending chain to the client `destination_client` of the receiving chain.
+///
+/// Unlike with IBC v1, clients are the only routing information, and the
+/// packet is sequenced per source client rather than per channel.
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Packet {
+    pub sequence: Sequence,
+    /// The identifier of the client of the receiving chain on the sending
+    /// chain.
+    pub source_client: ClientId,
+    /// The identifier of the client of the sending chain on the receiving
+    /// chain.
+    pub destination_client: ClientId,
+    /// The timeout timestamp of the packet on the receiving chain, in
+    /// seconds since the Unix epoch.
+    pub timeout_timestamp: u64,
+    pub payloads: Vec<Payload>,
+}
+
+impl Packet {
+    /// Checks that the sequence and the timeout of the packet are set, and
+    /// that it carries valid payloads.
+    pub fn validate_basic(&self) -> Result<(), PacketError> {
+        if self.sequence.is_zero() {
+            return Err(PacketError::ZeroPacketSequence);
+        }
+
+        if self.timeout_timestamp == 0 {
+            return Err(PacketError::ZeroTimeoutTimestamp);
+        }
+
+        if self.payloads.is_empty() {
+            return Err(PacketError::EmptyPayloads);
+        }
+
+        self.payloads.iter().try_for_each(Payload::validate_basic)
+    }
+
+    /// Checks whether the packet is timed-out relative to the current
+    /// timestamp `dst_chain_ts` of the receiving chain.
+    pub fn timed_out(&self, dst_chain_ts: &Timestamp) -> bool {
+        dst_chain_ts.nanoseconds() / 1_000_000_000 >= self.timeout_timestamp
+    }
+}
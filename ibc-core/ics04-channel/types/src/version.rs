@@ -7,14 +7,27 @@ use core::fmt::{Display, Error as FmtError, Formatter};
 use core::str::FromStr;
 
 use ibc_primitives::prelude::*;
+use ibc_primitives::utils::is_valid_json;
+#[cfg(feature = "serde")]
+use serde::de::DeserializeOwned;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 
 use super::error::ChannelError;
 
+/// The maximum length, in bytes, of a channel version.
+pub const MAX_VERSION_LENGTH: usize = 8192;
+
+/// The version of the fee middleware (ICS-29).
+#[cfg(feature = "serde")]
+pub const FEE_VERSION: &str = "ics29-1";
+
 /// The version field for a `ChannelEnd`.
 ///
-/// This field is opaque to the core IBC protocol.
-/// No explicit validation is necessary, and the
-/// spec (v1) currently allows empty strings.
+/// This field is opaque to the core IBC protocol, and the spec (v1) allows
+/// empty strings. The versions exchanged during the channel handshake are
+/// only checked by [`Version::validate_basic`] to be reasonably sized and,
+/// if they look like JSON, well-formed.
 #[cfg_attr(
     feature = "parity-scale-codec",
     derive(
@@ -49,6 +62,29 @@ impl Version {
         &self.0
     }
 
+    /// Checks that the version is at most [`MAX_VERSION_LENGTH`] bytes long,
+    /// and that it is valid JSON if it looks like a JSON object or array, as
+    /// the versions wrapped by middlewares are.
+    ///
+    /// The characters of the version are not restricted, as in ibc-go.
+    pub fn validate_basic(&self) -> Result<(), ChannelError> {
+        if self.0.len() > MAX_VERSION_LENGTH {
+            return Err(ChannelError::InvalidVersionLength {
+                length: self.0.len() as u64,
+                max_length: MAX_VERSION_LENGTH as u64,
+            });
+        }
+
+        if self.0.starts_with(['{', '[']) && !is_valid_json(&self.0) {
+            return Err(ChannelError::InvalidVersionEncoding {
+                version: self.clone(),
+                description: "version is not valid JSON".to_string(),
+            });
+        }
+
+        Ok(())
+    }
+
     pub fn verify_is_expected(&self, expected: Version) -> Result<(), ChannelError> {
         if self != &expected {
             return Err(ChannelError::VersionNotSupported {
@@ -58,6 +94,97 @@ impl Version {
         }
         Ok(())
     }
+
+    /// Creates a version holding the JSON encoding of `metadata`, as done by
+    /// middlewares to carry their own version alongside the one of the
+    /// wrapped application.
+    #[cfg(feature = "serde")]
+    pub fn from_metadata<T: Serialize>(metadata: &T) -> Result<Self, ChannelError> {
+        serde_json::to_string(metadata).map(Self::new).map_err(|e| {
+            ChannelError::InvalidVersionEncoding {
+                version: Self::empty(),
+                description: e.to_string(),
+            }
+        })
+    }
+
+    /// Decodes the JSON-encoded metadata held by the version.
+    #[cfg(feature = "serde")]
+    pub fn metadata<T: DeserializeOwned>(&self) -> Result<T, ChannelError> {
+        serde_json::from_str(self.as_str()).map_err(|e| ChannelError::InvalidVersionEncoding {
+            version: self.clone(),
+            description: e.to_string(),
+        })
+    }
+
+    /// Unwraps the version of the middleware `M` from the version.
+    ///
+    /// Returns `None` if the version is not a `M`, meaning that the middleware
+    /// is not used by the channel and that the whole version belongs to the
+    /// wrapped application.
+    #[cfg(feature = "serde")]
+    pub fn unwrap_middleware<M: MiddlewareVersion>(&self) -> Option<M> {
+        self.metadata().ok()
+    }
+}
+
+/// A JSON-encoded version, in which a middleware wraps the version of the
+/// application beneath it.
+///
+/// During the channel handshake, a middleware unwraps the version with
+/// [`Version::unwrap_middleware`], checks its own part, passes the
+/// [`app_version`](Self::app_version) down to the wrapped application, and
+/// wraps the version picked by the application back with
+/// [`with_app_version`](Self::with_app_version). Since the application
+/// version may itself be wrapped by another middleware, this composes
+/// through middleware stacks.
+#[cfg(feature = "serde")]
+pub trait MiddlewareVersion: Serialize + DeserializeOwned {
+    /// Returns the version of the wrapped application.
+    fn app_version(&self) -> &Version;
+
+    /// Replaces the version of the wrapped application.
+    fn with_app_version(self, app_version: Version) -> Self;
+
+    /// Encodes the version as a channel [`Version`].
+    fn to_version(&self) -> Result<Version, ChannelError> {
+        Version::from_metadata(self)
+    }
+}
+
+/// The version of a channel using the fee middleware (ICS-29), e.g.
+/// `{"fee_version":"ics29-1","app_version":"ics20-1"}`.
+#[cfg(feature = "serde")]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FeeVersion {
+    pub fee_version: String,
+    pub app_version: Version,
+}
+
+#[cfg(feature = "serde")]
+impl FeeVersion {
+    /// Wraps `app_version` with the current version of the fee middleware.
+    pub fn new(app_version: Version) -> Self {
+        Self {
+            fee_version: FEE_VERSION.to_string(),
+            app_version,
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl MiddlewareVersion for FeeVersion {
+    fn app_version(&self) -> &Version {
+        &self.app_version
+    }
+
+    fn with_app_version(self, app_version: Version) -> Self {
+        Self {
+            app_version,
+            ..self
+        }
+    }
 }
 
 impl From<String> for Version {
@@ -79,3 +206,76 @@ impl Display for Version {
         write!(f, "{}", self.0)
     }
 }
+
+#[cfg(all(test, feature = "serde"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fee_version_wrapping() {
+        let app_version = Version::new("ics20-1".to_string());
+        let version = FeeVersion::new(app_version.clone())
+            .to_version()
+            .expect("never fails");
+
+        assert_eq!(
+            version.as_str(),
+            r#"{"fee_version":"ics29-1","app_version":"ics20-1"}"#
+        );
+
+        let fee_version = version
+            .unwrap_middleware::<FeeVersion>()
+            .expect("a fee version");
+        assert_eq!(fee_version.app_version(), &app_version);
+
+        let picked = Version::new("ics20-2".to_string());
+        let fee_version = fee_version.with_app_version(picked.clone());
+        assert_eq!(fee_version, FeeVersion::new(picked));
+    }
+
+    #[test]
+    fn test_version_metadata_round_trip() {
+        let fee_version = FeeVersion::new(Version::new("ics20-1".to_string()));
+        let version = Version::from_metadata(&fee_version).expect("never fails");
+
+        assert_eq!(
+            version.metadata::<FeeVersion>().expect("a fee version"),
+            fee_version
+        );
+        assert!(Version::new("ics20-1".to_string())
+            .metadata::<FeeVersion>()
+            .is_err());
+    }
+
+    #[test]
+    fn test_unwrap_middleware_of_plain_version() {
+        let version = Version::new("ics20-1".to_string());
+
+        assert!(version.unwrap_middleware::<FeeVersion>().is_none());
+        assert!(Version::empty().unwrap_middleware::<FeeVersion>().is_none());
+    }
+
+    #[test]
+    fn test_nested_middleware_versions() {
+        let inner = FeeVersion::new(Version::new("ics20-1".to_string()))
+            .to_version()
+            .expect("never fails");
+        let outer = FeeVersion::new(inner.clone())
+            .to_version()
+            .expect("never fails");
+
+        let unwrapped = outer
+            .unwrap_middleware::<FeeVersion>()
+            .expect("a fee version");
+        assert_eq!(unwrapped.app_version(), &inner);
+        assert_eq!(
+            unwrapped
+                .app_version()
+                .unwrap_middleware::<FeeVersion>()
+                .expect("a fee version")
+                .app_version()
+                .as_str(),
+            "ics20-1"
+        );
+    }
+}
@@ -3,8 +3,9 @@
 use core::fmt;
 
 use ibc_primitives::prelude::*;
+use ibc_primitives::utils::constant_time_eq;
 use ibc_primitives::ToVec;
-use ibc_proto::ibc::core::commitment::v1::MerkleProof as RawMerkleProof;
+use ibc_proto::ibc::core::commitment::v1::{MerklePath, MerkleProof as RawMerkleProof};
 use ibc_proto::Protobuf;
 use subtle_encoding::{Encoding, Hex};
 
@@ -14,7 +15,7 @@ use crate::error::CommitmentError;
 /// Encodes a commitment root; most often a Merkle tree root hash.
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "serde", serde(transparent))]
-#[derive(Clone, PartialEq, Eq)]
+#[derive(Clone, Eq)]
 pub struct CommitmentRoot {
     #[cfg_attr(
         feature = "serde",
@@ -23,6 +24,14 @@ pub struct CommitmentRoot {
     bytes: Vec<u8>,
 }
 
+/// Compares the roots in constant time, as the roots carried by the headers
+/// submitted by relayers are checked against the stored ones.
+impl PartialEq for CommitmentRoot {
+    fn eq(&self, other: &Self) -> bool {
+        constant_time_eq(&self.bytes, &other.bytes)
+    }
+}
+
 impl fmt::Debug for CommitmentRoot {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let hex = Hex::upper_case()
@@ -160,6 +169,49 @@ impl CommitmentPrefix {
     pub fn empty() -> Self {
         Self { bytes: Vec::new() }
     }
+
+    /// Returns the prefix as the string it is prepended to commitment paths
+    /// as, failing if it is not valid UTF-8.
+    pub fn as_str(&self) -> Result<&str, CommitmentError> {
+        core::str::from_utf8(&self.bytes)
+            .map_err(|_| CommitmentError::NonUtf8CommitmentPrefix(self.bytes.clone()))
+    }
+
+    /// Checks that the prefix can be applied to commitment paths, i.e. that it
+    /// is neither empty nor invalid UTF-8, as are the store keys prefixing the
+    /// commitments of standard hosts such as Cosmos SDK chains.
+    pub fn validate(&self) -> Result<(), CommitmentError> {
+        if self.bytes.is_empty() {
+            return Err(CommitmentError::EmptyCommitmentPrefix);
+        }
+
+        self.as_str().map(|_| ())
+    }
+
+    /// Composes the Merkle path of the commitment at `path`, by prepending the
+    /// prefix to it as ibc-go does with `ApplyPrefix`.
+    pub fn apply(&self, path: Vec<String>) -> Result<MerklePath, CommitmentError> {
+        self.validate()?;
+
+        let mut key_path = Vec::with_capacity(path.len() + 1);
+        key_path.push(self.as_str()?.to_string());
+        key_path.extend(path);
+
+        Ok(MerklePath { key_path })
+    }
+
+    /// Checks that the prefix is the `expected` one, e.g. the prefix a
+    /// counterparty claims for a host against the prefix of that host.
+    pub fn verify_matches(&self, expected: &Self) -> Result<(), CommitmentError> {
+        if self != expected {
+            return Err(CommitmentError::MismatchedCommitmentPrefix {
+                expected: expected.clone(),
+                actual: self.clone(),
+            });
+        }
+
+        Ok(())
+    }
 }
 
 impl TryFrom<Vec<u8>> for CommitmentPrefix {
@@ -193,3 +245,56 @@ impl serde::Serialize for CommitmentPrefix {
         format!("{self:?}").serialize(serializer)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case(b"ibc".to_vec(), vec!["clients/07-tendermint-0"], vec!["ibc", "clients/07-tendermint-0"])]
+    #[case(b"mock".to_vec(), vec!["a", "b"], vec!["mock", "a", "b"])]
+    #[case(b"ibc".to_vec(), vec![], vec!["ibc"])]
+    fn apply_prefix(#[case] prefix: Vec<u8>, #[case] path: Vec<&str>, #[case] expected: Vec<&str>) {
+        let prefix = CommitmentPrefix::try_from(prefix).unwrap();
+        let merkle_path = prefix
+            .apply(path.into_iter().map(String::from).collect())
+            .unwrap();
+
+        assert_eq!(merkle_path.key_path, expected);
+    }
+
+    #[test]
+    fn invalid_prefix() {
+        assert!(matches!(
+            CommitmentPrefix::empty().apply(vec!["path".to_string()]),
+            Err(CommitmentError::EmptyCommitmentPrefix)
+        ));
+
+        let prefix = CommitmentPrefix::try_from(vec![0xff, 0xfe]).unwrap();
+        assert!(matches!(
+            prefix.validate(),
+            Err(CommitmentError::NonUtf8CommitmentPrefix(bytes)) if bytes == [0xff, 0xfe]
+        ));
+        assert!(prefix.apply(vec!["path".to_string()]).is_err());
+    }
+
+    #[test]
+    fn verify_prefix_matches() {
+        let ibc = CommitmentPrefix::try_from(b"ibc".to_vec()).unwrap();
+        let mock = CommitmentPrefix::try_from(b"mock".to_vec()).unwrap();
+
+        assert!(ibc.verify_matches(&ibc.clone()).is_ok());
+
+        let err = mock.verify_matches(&ibc).unwrap_err();
+        assert!(matches!(
+            err,
+            CommitmentError::MismatchedCommitmentPrefix { .. }
+        ));
+        assert_eq!(
+            err.to_string(),
+            "mismatched commitment prefix: expected `ibc`, actual `mock`"
+        );
+    }
+}
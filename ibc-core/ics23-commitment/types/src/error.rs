@@ -3,10 +3,19 @@
 use displaydoc::Display;
 use ibc_primitives::prelude::*;
 
+use crate::commitment::CommitmentPrefix;
+
 #[derive(Debug, Display)]
 pub enum CommitmentError {
     /// empty commitment prefix
     EmptyCommitmentPrefix,
+    /// commitment prefix is not valid UTF-8: `{0:?}`
+    NonUtf8CommitmentPrefix(Vec<u8>),
+    /// mismatched commitment prefix: expected `{expected:?}`, actual `{actual:?}`
+    MismatchedCommitmentPrefix {
+        expected: CommitmentPrefix,
+        actual: CommitmentPrefix,
+    },
     /// empty merkle proof
     EmptyMerkleProof,
     /// empty merkle root
@@ -37,6 +46,12 @@ pub enum CommitmentError {
     InvalidHashOp(i32),
     /// invalid length operation: `{0}`
     InvalidLengthOp(i32),
+    /// invalid child order: `{0:?}`
+    InvalidChildOrder(Vec<i32>),
+    /// missing leaf spec
+    MissingLeafSpec,
+    /// missing inner spec
+    MissingInnerSpec,
 }
 
 #[cfg(feature = "std")]
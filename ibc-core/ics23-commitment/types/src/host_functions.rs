@@ -0,0 +1,110 @@
+//! Defines the [`HostFunctions`] trait, abstracting the cryptographic
+//! primitives used to compute and verify commitments, so that hosts can
+//! substitute accelerated or precompiled implementations of them. Available
+//! behind the `host-functions` feature, which pulls in the pure-Rust
+//! signature verification of [`HostFunctionsManager`].
+
+use ibc_proto::ics23::{HostFunctionsManager, HostFunctionsProvider};
+
+/// The cryptographic primitives a host provides to IBC verification code.
+///
+/// The hash functions are those of the ICS-23 [`HostFunctionsProvider`],
+/// which also back the verification of Merkle proofs, while the signature
+/// verification functions cover the keys used by Cosmos SDK accounts and
+/// validators.
+///
+/// [`HostFunctionsManager`] implements this trait with pure-Rust
+/// implementations, and is the one used by default. Hosts such as Substrate
+/// runtimes or zkVMs may instead provide an implementation delegating to
+/// their own host calls or precompiles.
+pub trait HostFunctions: HostFunctionsProvider {
+    /// Returns whether `signature` is a valid Ed25519 signature of `message`
+    /// under the 32-byte `public_key`.
+    fn ed25519_verify(public_key: &[u8], message: &[u8], signature: &[u8]) -> bool;
+
+    /// Returns whether `signature` is a valid secp256k1 ECDSA signature, in
+    /// its 64-byte compact form with a low `s`, of the SHA-256 digest of
+    /// `message` under the SEC1-encoded `public_key`.
+    fn secp256k1_verify(public_key: &[u8], message: &[u8], signature: &[u8]) -> bool;
+}
+
+impl HostFunctions for HostFunctionsManager {
+    fn ed25519_verify(public_key: &[u8], message: &[u8], signature: &[u8]) -> bool {
+        let (Ok(public_key), Ok(signature)) = (
+            ed25519_consensus::VerificationKey::try_from(public_key),
+            ed25519_consensus::Signature::try_from(signature),
+        ) else {
+            return false;
+        };
+
+        public_key.verify(&signature, message).is_ok()
+    }
+
+    fn secp256k1_verify(public_key: &[u8], message: &[u8], signature: &[u8]) -> bool {
+        use k256::ecdsa::signature::Verifier;
+
+        let (Ok(public_key), Ok(signature)) = (
+            k256::ecdsa::VerifyingKey::from_sec1_bytes(public_key),
+            k256::ecdsa::Signature::from_slice(signature),
+        ) else {
+            return false;
+        };
+
+        // Signatures with a high `s` are malleable, and rejected by the
+        // verifying key.
+        public_key.verify(message, &signature).is_ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ed25519_verify() {
+        let signing_key = ed25519_consensus::SigningKey::from([7; 32]);
+        let public_key = signing_key.verification_key();
+        let signature = signing_key.sign(b"message");
+
+        assert!(HostFunctionsManager::ed25519_verify(
+            public_key.as_bytes(),
+            b"message",
+            &signature.to_bytes()
+        ));
+        assert!(!HostFunctionsManager::ed25519_verify(
+            public_key.as_bytes(),
+            b"other message",
+            &signature.to_bytes()
+        ));
+        assert!(!HostFunctionsManager::ed25519_verify(
+            &[0; 3],
+            b"message",
+            &signature.to_bytes()
+        ));
+    }
+
+    #[test]
+    fn secp256k1_verify() {
+        use k256::ecdsa::signature::Signer;
+
+        let signing_key = k256::ecdsa::SigningKey::from_slice(&[7; 32]).expect("valid key");
+        let public_key = signing_key.verifying_key().to_encoded_point(true);
+        let signature: k256::ecdsa::Signature = signing_key.sign(b"message");
+
+        assert!(HostFunctionsManager::secp256k1_verify(
+            public_key.as_bytes(),
+            b"message",
+            &signature.to_bytes()
+        ));
+        assert!(!HostFunctionsManager::secp256k1_verify(
+            public_key.as_bytes(),
+            b"other message",
+            &signature.to_bytes()
+        ));
+        assert!(!HostFunctionsManager::secp256k1_verify(
+            public_key.as_bytes(),
+            b"message",
+            &[0; 64]
+        ));
+    }
+}
@@ -17,6 +17,8 @@ extern crate std;
 
 pub mod commitment;
 pub mod error;
+#[cfg(feature = "host-functions")]
+pub mod host_functions;
 pub mod merkle;
 pub mod specs;
 
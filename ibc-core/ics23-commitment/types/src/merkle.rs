@@ -13,10 +13,14 @@ use crate::commitment::{CommitmentPrefix, CommitmentRoot};
 use crate::error::CommitmentError;
 use crate::specs::ProofSpecs;
 
-pub fn apply_prefix(prefix: &CommitmentPrefix, mut path: Vec<String>) -> MerklePath {
-    let mut key_path: Vec<String> = vec![format!("{prefix:?}")];
-    key_path.append(&mut path);
-    MerklePath { key_path }
+/// Composes the Merkle path of the commitment at `path` under `prefix`.
+///
+/// See [`CommitmentPrefix::apply`].
+pub fn apply_prefix(
+    prefix: &CommitmentPrefix,
+    path: Vec<String>,
+) -> Result<MerklePath, CommitmentError> {
+    prefix.apply(path)
 }
 
 impl From<CommitmentRoot> for MerkleRoot {
@@ -13,6 +13,10 @@ use crate::error::CommitmentError;
 #[derive(Clone, Debug, PartialEq)]
 pub struct ProofSpecs(Vec<ProofSpec>);
 
+/// The maximum depth of the proofs allowed by a proof spec, as that of the
+/// proofs of sparse Merkle trees keyed by 256-bit hashes.
+pub const MAX_PROOF_SPEC_DEPTH: i32 = 256;
+
 impl ProofSpecs {
     /// Returns the specification for Cosmos-SDK proofs
     pub fn cosmos() -> Self {
@@ -24,30 +28,34 @@ impl ProofSpecs {
         .expect("should convert successfully")
     }
 
+    /// Returns the specification for the proofs of chains storing their state
+    /// in sparse Merkle trees, whose roots are committed to as in Cosmos-SDK.
+    pub fn smt() -> Self {
+        vec![
+            ics23::smt_spec(),        // Format of sparse merkle tree proofs
+            ics23::tendermint_spec(), // Format of proofs-tendermint (crypto/ merkle SimpleProof)
+        ]
+        .try_into()
+        .expect("should convert successfully")
+    }
+
     pub fn is_empty(&self) -> bool {
         self.0.is_empty()
     }
 
+    /// Checks that the specs can be used to verify proofs.
+    ///
+    /// On top of the checks performed on decoding, every spec must have both a
+    /// leaf spec and an inner spec, a depth of at most
+    /// [`MAX_PROOF_SPEC_DEPTH`], and a child order listing each child exactly
+    /// once, since such specs would otherwise fail every verification, or
+    /// even panic while verifying.
     pub fn validate(&self) -> Result<(), CommitmentError> {
         if self.is_empty() {
             return Err(CommitmentError::EmptyProofSpecs);
         }
         for proof_spec in &self.0 {
-            // A non-positive `min_depth` or `max_depth` indicates no limit on the respective bound.
-            // For simplicity, negative values for `min_depth` and `max_depth` are not allowed
-            // and only `0` is used to indicate no limit. When `min_depth` and `max_depth` are both positive,
-            // `max_depth` must be greater than or equal to `min_depth` to ensure a valid range.
-            if proof_spec.0.max_depth < 0
-                || proof_spec.0.min_depth < 0
-                || (0 < proof_spec.0.min_depth
-                    && 0 < proof_spec.0.max_depth
-                    && proof_spec.0.max_depth < proof_spec.0.min_depth)
-            {
-                return Err(CommitmentError::InvalidDepthRange(
-                    proof_spec.0.min_depth,
-                    proof_spec.0.max_depth,
-                ));
-            }
+            proof_spec.validate()?;
         }
         Ok(())
     }
@@ -82,19 +90,7 @@ struct ProofSpec(RawProofSpec);
 impl TryFrom<RawProofSpec> for ProofSpec {
     type Error = CommitmentError;
     fn try_from(spec: RawProofSpec) -> Result<Self, CommitmentError> {
-        // A non-positive `min_depth` or `max_depth` indicates no limit on the respective bound.
-        // For simplicity, negative values for `min_depth` and `max_depth` are not allowed
-        // and only `0` is used to indicate no limit. When `min_depth` and `max_depth` are both positive,
-        // `max_depth` must be greater than or equal to `min_depth` to ensure a valid range.
-        if spec.max_depth < 0
-            || spec.min_depth < 0
-            || (0 < spec.min_depth && 0 < spec.max_depth && spec.max_depth < spec.min_depth)
-        {
-            return Err(CommitmentError::InvalidDepthRange(
-                spec.min_depth,
-                spec.max_depth,
-            ));
-        }
+        check_depth_range(spec.min_depth, spec.max_depth)?;
 
         let leaf_spec = spec
             .leaf_spec
@@ -117,6 +113,60 @@ impl TryFrom<RawProofSpec> for ProofSpec {
     }
 }
 
+impl ProofSpec {
+    fn validate(&self) -> Result<(), CommitmentError> {
+        check_depth_range(self.0.min_depth, self.0.max_depth)?;
+
+        let leaf_spec = self
+            .0
+            .leaf_spec
+            .as_ref()
+            .ok_or(CommitmentError::MissingLeafSpec)?;
+        check_leaf_op(leaf_spec)?;
+
+        let inner_spec = self
+            .0
+            .inner_spec
+            .as_ref()
+            .ok_or(CommitmentError::MissingInnerSpec)?;
+        check_inner_spec(inner_spec)?;
+
+        // Every child must be listed exactly once in the child order, as
+        // the position of a child in an inner node is derived from it.
+        let invalid_child_order =
+            || CommitmentError::InvalidChildOrder(inner_spec.child_order.clone());
+        if inner_spec.child_order.is_empty() {
+            return Err(invalid_child_order());
+        }
+        let mut seen = vec![false; inner_spec.child_order.len()];
+        for &child in &inner_spec.child_order {
+            match usize::try_from(child).ok().and_then(|i| seen.get_mut(i)) {
+                Some(seen) if !*seen => *seen = true,
+                _ => return Err(invalid_child_order()),
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Checks the depth range of a proof spec.
+fn check_depth_range(min_depth: i32, max_depth: i32) -> Result<(), CommitmentError> {
+    // A non-positive `min_depth` or `max_depth` indicates no limit on the respective bound.
+    // For simplicity, negative values for `min_depth` and `max_depth` are not allowed
+    // and only `0` is used to indicate no limit. When `min_depth` and `max_depth` are both positive,
+    // `max_depth` must be greater than or equal to `min_depth` to ensure a valid range.
+    if max_depth < 0
+        || min_depth < 0
+        || (0 < min_depth && 0 < max_depth && max_depth < min_depth)
+        || MAX_PROOF_SPEC_DEPTH < min_depth
+        || MAX_PROOF_SPEC_DEPTH < max_depth
+    {
+        return Err(CommitmentError::InvalidDepthRange(min_depth, max_depth));
+    }
+    Ok(())
+}
+
 impl From<ProofSpec> for RawProofSpec {
     fn from(spec: ProofSpec) -> Self {
         spec.0
@@ -130,19 +180,24 @@ struct LeafOp(RawLeafOp);
 impl TryFrom<RawLeafOp> for LeafOp {
     type Error = CommitmentError;
     fn try_from(leaf_op: RawLeafOp) -> Result<Self, Self::Error> {
-        let _ = HashOp::try_from(leaf_op.hash)
-            .map_err(|_| CommitmentError::InvalidHashOp(leaf_op.hash))?;
-        let _ = HashOp::try_from(leaf_op.prehash_key)
-            .map_err(|_| CommitmentError::InvalidHashOp(leaf_op.prehash_key))?;
-        let _ = HashOp::try_from(leaf_op.prehash_value)
-            .map_err(|_| CommitmentError::InvalidHashOp(leaf_op.prehash_value))?;
-        let _ = LengthOp::try_from(leaf_op.length)
-            .map_err(|_| CommitmentError::InvalidLengthOp(leaf_op.length))?;
+        check_leaf_op(&leaf_op)?;
 
         Ok(Self(leaf_op))
     }
 }
 
+fn check_leaf_op(leaf_op: &RawLeafOp) -> Result<(), CommitmentError> {
+    let _ =
+        HashOp::try_from(leaf_op.hash).map_err(|_| CommitmentError::InvalidHashOp(leaf_op.hash))?;
+    let _ = HashOp::try_from(leaf_op.prehash_key)
+        .map_err(|_| CommitmentError::InvalidHashOp(leaf_op.prehash_key))?;
+    let _ = HashOp::try_from(leaf_op.prehash_value)
+        .map_err(|_| CommitmentError::InvalidHashOp(leaf_op.prehash_value))?;
+    let _ = LengthOp::try_from(leaf_op.length)
+        .map_err(|_| CommitmentError::InvalidLengthOp(leaf_op.length))?;
+    Ok(())
+}
+
 impl From<LeafOp> for RawLeafOp {
     fn from(leaf_op: LeafOp) -> Self {
         leaf_op.0
@@ -156,21 +211,7 @@ struct InnerSpec(RawInnerSpec);
 impl TryFrom<RawInnerSpec> for InnerSpec {
     type Error = CommitmentError;
     fn try_from(inner_spec: RawInnerSpec) -> Result<Self, CommitmentError> {
-        if inner_spec.child_size <= 0 {
-            return Err(CommitmentError::InvalidChildSize(inner_spec.child_size));
-        }
-
-        // Negative prefix lengths are not allowed and the maximum prefix length must
-        // be greater than or equal to the minimum prefix length.
-        if inner_spec.min_prefix_length < 0
-            || inner_spec.max_prefix_length < 0
-            || inner_spec.max_prefix_length < inner_spec.min_prefix_length
-        {
-            return Err(CommitmentError::InvalidPrefixLengthRange(
-                inner_spec.min_prefix_length,
-                inner_spec.max_prefix_length,
-            ));
-        }
+        check_inner_spec(&inner_spec)?;
 
         Ok(Self(RawInnerSpec {
             child_order: inner_spec.child_order,
@@ -183,6 +224,25 @@ impl TryFrom<RawInnerSpec> for InnerSpec {
     }
 }
 
+fn check_inner_spec(inner_spec: &RawInnerSpec) -> Result<(), CommitmentError> {
+    if inner_spec.child_size <= 0 {
+        return Err(CommitmentError::InvalidChildSize(inner_spec.child_size));
+    }
+
+    // Negative prefix lengths are not allowed and the maximum prefix length must
+    // be greater than or equal to the minimum prefix length.
+    if inner_spec.min_prefix_length < 0
+        || inner_spec.max_prefix_length < 0
+        || inner_spec.max_prefix_length < inner_spec.min_prefix_length
+    {
+        return Err(CommitmentError::InvalidPrefixLengthRange(
+            inner_spec.min_prefix_length,
+            inner_spec.max_prefix_length,
+        ));
+    }
+    Ok(())
+}
+
 impl From<InnerSpec> for RawInnerSpec {
     fn from(inner_spec: InnerSpec) -> Self {
         inner_spec.0
@@ -196,6 +256,52 @@ mod tests {
 
     use super::*;
 
+    #[rstest]
+    #[case(ProofSpecs::cosmos())]
+    #[case(ProofSpecs::smt())]
+    fn test_proof_specs_presets(#[case] specs: ProofSpecs) {
+        specs.validate().expect("valid preset");
+
+        let raw_specs: Vec<RawProofSpec> = specs.clone().into();
+        assert_eq!(
+            ProofSpecs::try_from(raw_specs).expect("valid preset"),
+            specs
+        );
+    }
+
+    #[rstest]
+    #[case::missing_leaf_spec(|spec: &mut RawProofSpec| spec.leaf_spec = None)]
+    #[case::missing_inner_spec(|spec: &mut RawProofSpec| spec.inner_spec = None)]
+    #[case::absurd_max_depth(|spec: &mut RawProofSpec| spec.max_depth = MAX_PROOF_SPEC_DEPTH + 1)]
+    #[case::absurd_min_depth(|spec: &mut RawProofSpec| spec.min_depth = i32::MAX)]
+    #[case::zero_child_size(|spec: &mut RawProofSpec| {
+        spec.inner_spec.as_mut().unwrap().child_size = 0
+    })]
+    #[case::empty_child_order(|spec: &mut RawProofSpec| {
+        spec.inner_spec.as_mut().unwrap().child_order = vec![]
+    })]
+    #[case::duplicate_child(|spec: &mut RawProofSpec| {
+        spec.inner_spec.as_mut().unwrap().child_order = vec![0, 0]
+    })]
+    #[case::unknown_child(|spec: &mut RawProofSpec| {
+        spec.inner_spec.as_mut().unwrap().child_order = vec![0, 2]
+    })]
+    #[case::invalid_leaf_hash(|spec: &mut RawProofSpec| {
+        spec.leaf_spec.as_mut().unwrap().hash = -1
+    })]
+    fn test_proof_specs_validate_rejects(#[case] tamper: fn(&mut RawProofSpec)) {
+        let mut raw_spec = ics23::iavl_spec();
+        tamper(&mut raw_spec);
+
+        // Specs bypassing the checks performed on decoding, e.g. when
+        // deserialized, are rejected all the same.
+        let specs = ProofSpecs(vec![
+            ProofSpec(raw_spec),
+            ProofSpec(ics23::tendermint_spec()),
+        ]);
+        assert!(specs.validate().is_err());
+    }
+
     #[rstest]
     #[case(0, 0)]
     #[case(2, 2)]
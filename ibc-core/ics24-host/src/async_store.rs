@@ -0,0 +1,219 @@
+//! Provides an asynchronous counterpart of [`IbcStore`], available behind the
+//! `async` feature, for hosts whose IBC state is backed by remote services,
+//! e.g. RPC endpoints or databases.
+//!
+//! Handlers read the state synchronously, through a [`PrefetchStore`] filled
+//! with the values fetched from an [`AsyncIbcStore`]. Since the paths read by
+//! a handler depend on the values it reads, the store records the paths read
+//! without having been fetched so far, so that the handler can be run again
+//! once they are fetched, until it reads no missing path. See the
+//! `ibc-core-handler` crate for the entry points doing so.
+
+use alloc::collections::BTreeSet;
+use core::cell::RefCell;
+
+use async_trait::async_trait;
+use ibc_core_host_types::path::Path;
+use ibc_primitives::prelude::*;
+
+use crate::store::IbcStore;
+
+/// An asynchronous key-value store holding the IBC state of the host, keyed
+/// by [`Path`].
+#[async_trait]
+pub trait AsyncIbcStore: Send + Sync {
+    /// Returns the value stored under `path`, if any.
+    async fn get(&self, path: &Path) -> Option<Vec<u8>>;
+
+    /// Stores `value` under `path`, overwriting any previous value.
+    async fn set(&mut self, path: Path, value: Vec<u8>);
+
+    /// Deletes the value stored under `path`, if any.
+    async fn delete(&mut self, path: &Path);
+}
+
+/// A snapshot of the values fetched from an [`AsyncIbcStore`], on top of
+/// which handlers read and write synchronously.
+///
+/// Reading a path which was not fetched yet returns `None`, as if no value
+/// were stored under it, and records the path as missing. The writes are
+/// kept in the snapshot until they are [flushed](Self::flush) to the
+/// asynchronous store.
+#[derive(Debug, Default)]
+pub struct PrefetchStore {
+    fetched: BTreeMap<Path, Option<Vec<u8>>>,
+    missing: RefCell<BTreeSet<Path>>,
+    writes: BTreeMap<Path, Option<Vec<u8>>>,
+}
+
+impl PrefetchStore {
+    /// Creates an empty snapshot.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Marks `path` as missing, for its value to be fetched along with the
+    /// paths read without having been fetched, unless it is fetched already.
+    pub fn prefetch(&mut self, path: Path) {
+        if !self.fetched.contains_key(&path) {
+            self.missing.get_mut().insert(path);
+        }
+    }
+
+    /// Returns `true` if paths were read without having been fetched since
+    /// the last call to [`fetch_missing`](Self::fetch_missing).
+    pub fn has_missing(&self) -> bool {
+        !self.missing.borrow().is_empty()
+    }
+
+    /// Returns the paths read without having been fetched since the last call
+    /// to [`fetch_missing`](Self::fetch_missing).
+    pub fn missing(&self) -> Vec<Path> {
+        self.missing.borrow().iter().cloned().collect()
+    }
+
+    /// Fetches the values of the missing paths from `store`.
+    pub async fn fetch_missing<S>(&mut self, store: &S)
+    where
+        S: AsyncIbcStore + ?Sized,
+    {
+        for path in core::mem::take(self.missing.get_mut()) {
+            let value = store.get(&path).await;
+            self.fetched.insert(path, value);
+        }
+    }
+
+    /// Applies the writes made to the snapshot to `store`.
+    pub async fn flush<S>(self, store: &mut S)
+    where
+        S: AsyncIbcStore + ?Sized,
+    {
+        for (path, value) in self.writes {
+            match value {
+                Some(value) => store.set(path, value).await,
+                None => store.delete(&path).await,
+            }
+        }
+    }
+}
+
+impl IbcStore for PrefetchStore {
+    fn get(&self, path: &Path) -> Option<Vec<u8>> {
+        if let Some(value) = self.writes.get(path) {
+            return value.clone();
+        }
+
+        match self.fetched.get(path) {
+            Some(value) => value.clone(),
+            None => {
+                self.missing.borrow_mut().insert(path.clone());
+                None
+            }
+        }
+    }
+
+    fn set(&mut self, path: Path, value: Vec<u8>) {
+        self.writes.insert(path, Some(value));
+    }
+
+    fn delete(&mut self, path: &Path) {
+        self.writes.insert(path.clone(), None);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::future::Future;
+    use core::pin::pin;
+    use core::task::{Context, Poll};
+    use std::sync::Arc;
+    use std::task::{Wake, Waker};
+
+    use ibc_core_host_types::identifiers::{ChannelId, PortId};
+    use ibc_core_host_types::path::ReceiptPath;
+
+    use super::*;
+
+    struct NoopWaker;
+
+    impl Wake for NoopWaker {
+        fn wake(self: Arc<Self>) {}
+    }
+
+    /// Polls a future which never waits to completion.
+    fn block_on<F: Future>(future: F) -> F::Output {
+        let waker = Waker::from(Arc::new(NoopWaker));
+        let mut cx = Context::from_waker(&waker);
+        let mut future = pin!(future);
+
+        loop {
+            if let Poll::Ready(output) = future.as_mut().poll(&mut cx) {
+                return output;
+            }
+        }
+    }
+
+    #[derive(Default)]
+    struct MemStore(BTreeMap<Path, Vec<u8>>);
+
+    #[async_trait]
+    impl AsyncIbcStore for MemStore {
+        async fn get(&self, path: &Path) -> Option<Vec<u8>> {
+            self.0.get(path).cloned()
+        }
+
+        async fn set(&mut self, path: Path, value: Vec<u8>) {
+            self.0.insert(path, value);
+        }
+
+        async fn delete(&mut self, path: &Path) {
+            self.0.remove(path);
+        }
+    }
+
+    fn receipt_path(seq: u64) -> Path {
+        ReceiptPath::new(&PortId::transfer(), &ChannelId::zero(), seq.into()).into()
+    }
+
+    #[test]
+    fn test_prefetch_store_fetches_missing_paths() {
+        let mut remote = MemStore::default();
+        remote.0.insert(receipt_path(1), vec![1]);
+
+        let mut snapshot = PrefetchStore::new();
+
+        // Unfetched paths read as missing values, whether they are stored or
+        // not.
+        assert_eq!(IbcStore::get(&snapshot, &receipt_path(1)), None);
+        assert_eq!(IbcStore::get(&snapshot, &receipt_path(2)), None);
+        assert_eq!(snapshot.missing(), vec![receipt_path(1), receipt_path(2)]);
+
+        block_on(snapshot.fetch_missing(&remote));
+
+        assert!(!snapshot.has_missing());
+        assert_eq!(IbcStore::get(&snapshot, &receipt_path(1)), Some(vec![1]));
+        assert_eq!(IbcStore::get(&snapshot, &receipt_path(2)), None);
+        assert!(!snapshot.has_missing());
+    }
+
+    #[test]
+    fn test_prefetch_store_flushes_writes() {
+        let mut remote = MemStore::default();
+        remote.0.insert(receipt_path(1), vec![1]);
+
+        let mut snapshot = PrefetchStore::new();
+        IbcStore::delete(&mut snapshot, &receipt_path(1));
+        IbcStore::set(&mut snapshot, receipt_path(2), vec![2]);
+
+        // Written paths are read from the snapshot without being fetched.
+        assert_eq!(IbcStore::get(&snapshot, &receipt_path(2)), Some(vec![2]));
+        assert!(!snapshot.has_missing());
+
+        block_on(snapshot.flush(&mut remote));
+
+        assert_eq!(
+            remote.0.into_iter().collect::<Vec<_>>(),
+            vec![(receipt_path(2), vec![2])]
+        );
+    }
+}
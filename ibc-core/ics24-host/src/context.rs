@@ -1,7 +1,8 @@
 use core::time::Duration;
 
-use ibc_core_channel_types::channel::ChannelEnd;
+use ibc_core_channel_types::channel::{ChannelEnd, Counterparty};
 use ibc_core_channel_types::commitment::{AcknowledgementCommitment, PacketCommitment};
+use ibc_core_channel_types::error::ChannelError;
 use ibc_core_channel_types::packet::Receipt;
 use ibc_core_client_context::prelude::*;
 use ibc_core_client_types::Height;
@@ -10,7 +11,7 @@ use ibc_core_connection_types::version::{pick_version, Version as ConnectionVers
 use ibc_core_connection_types::ConnectionEnd;
 use ibc_core_handler_types::error::ContextError;
 use ibc_core_handler_types::events::IbcEvent;
-use ibc_core_host_types::identifiers::{ConnectionId, Sequence};
+use ibc_core_host_types::identifiers::{ConnectionId, PortId, Sequence};
 use ibc_core_host_types::path::{
     AckPath, ChannelEndPath, ClientConnectionPath, CommitmentPath, ConnectionPath, ReceiptPath,
     SeqAckPath, SeqRecvPath, SeqSendPath,
@@ -19,11 +20,14 @@ use ibc_primitives::prelude::*;
 use ibc_primitives::{Signer, Timestamp};
 
 use crate::utils::calculate_block_delay;
+use crate::HostParams;
 
 /// Context to be implemented by the host that provides all "read-only" methods.
 ///
 /// Trait used for the top-level `validate` entrypoint in the `ibc-core` crate.
-pub trait ValidationContext {
+/// The parameters of the IBC module of the host are provided through its
+/// [`HostParams`] supertrait.
+pub trait ValidationContext: HostParams {
     type V: ClientValidationContext;
     /// The client state type for the host chain.
     type HostClientState: ClientStateValidation<Self::V>;
@@ -71,6 +75,8 @@ pub trait ValidationContext {
     fn commitment_prefix(&self) -> CommitmentPrefix;
 
     /// Returns a counter on how many connections have been created thus far.
+    /// The value of this counter should increase only via method
+    /// `ExecutionContext::increase_connection_counter`.
     fn connection_counter(&self) -> Result<u64, ContextError>;
 
     /// Function required by ICS-03. Returns the list of all possible versions that the connection
@@ -121,13 +127,50 @@ pub trait ValidationContext {
         ack_path: &AckPath,
     ) -> Result<AcknowledgementCommitment, ContextError>;
 
+    /// Returns the sequences of all the packet acknowledgements stored for
+    /// the given channel end, which are deleted once both ends of the channel
+    /// are closed.
+    ///
+    /// Returns none by default, in which case the acknowledgements of closed
+    /// channels are never deleted.
+    fn packet_acknowledgement_sequences(
+        &self,
+        _channel_end_path: &ChannelEndPath,
+    ) -> Result<Vec<Sequence>, ContextError> {
+        Ok(Vec::new())
+    }
+
     /// Returns a counter on the number of channel ids have been created thus far.
     /// The value of this counter should increase only via method
     /// `ExecutionContext::increase_channel_counter`.
     fn channel_counter(&self) -> Result<u64, ContextError>;
 
-    /// Returns the maximum expected time per block
-    fn max_expected_time_per_block(&self) -> Duration;
+    /// Returns `true` if the host froze the given channel end, in which case
+    /// its packets are no longer sent, received nor acknowledged until the
+    /// host unfreezes it, e.g. to quarantine a compromised counterparty.
+    ///
+    /// Returns `false` by default, in which case channels are never frozen.
+    fn is_channel_frozen(&self, _channel_end_path: &ChannelEndPath) -> Result<bool, ContextError> {
+        Ok(false)
+    }
+
+    /// Validates that `signer` may open a channel bound to the port `port_id`
+    /// of the host, over the given connection hops and with the given
+    /// `counterparty`, as checked upon `MsgChannelOpenInit` before calling
+    /// the module bound to the port, e.g. so that arbitrary accounts cannot
+    /// open channels into application ports.
+    ///
+    /// Any signer may open channels by default. Hosts rejecting a signer
+    /// typically return a `ChannelError::UnauthorizedChannelOpen`.
+    fn authorize_chan_open_init(
+        &self,
+        _signer: &Signer,
+        _port_id: &PortId,
+        _connection_hops: &[ConnectionId],
+        _counterparty: &Counterparty,
+    ) -> Result<(), ContextError> {
+        Ok(())
+    }
 
     /// Calculates the block delay period using the connection's delay period and the maximum
     /// expected time per block.
@@ -151,6 +194,9 @@ pub trait ExecutionContext: ValidationContext {
 
     /// Called upon client creation.
     /// Increases the counter which keeps track of how many clients have been created.
+    ///
+    /// Must fail instead of wrapping around on overflow, see
+    /// [`IdentifierCounter::next`](crate::IdentifierCounter::next).
     fn increase_client_counter(&mut self) -> Result<(), ContextError>;
 
     /// Stores the given connection_end at path
@@ -169,6 +215,9 @@ pub trait ExecutionContext: ValidationContext {
 
     /// Called upon connection identifier creation (Init or Try process).
     /// Increases the counter which keeps track of how many connections have been created.
+    ///
+    /// Must fail instead of wrapping around on overflow, see
+    /// [`IdentifierCounter::next`](crate::IdentifierCounter::next).
     fn increase_connection_counter(&mut self) -> Result<(), ContextError>;
 
     /// Stores the given packet commitment at the given store path
@@ -231,8 +280,23 @@ pub trait ExecutionContext: ValidationContext {
 
     /// Called upon channel identifier creation (Init or Try message processing).
     /// Increases the counter which keeps track of how many channels have been created.
+    ///
+    /// Must fail instead of wrapping around on overflow, see
+    /// [`IdentifierCounter::next`](crate::IdentifierCounter::next).
     fn increase_channel_counter(&mut self) -> Result<(), ContextError>;
 
+    /// Stores whether the given channel end is frozen, as returned by
+    /// [`ValidationContext::is_channel_frozen`].
+    ///
+    /// Fails by default, for hosts whose channels are never frozen.
+    fn store_channel_frozen(
+        &mut self,
+        _channel_end_path: &ChannelEndPath,
+        _frozen: bool,
+    ) -> Result<(), ContextError> {
+        Err(ChannelError::UnsupportedChannelFreeze.into())
+    }
+
     /// Emit the given IBC event
     fn emit_ibc_event(&mut self, event: IbcEvent) -> Result<(), ContextError>;
 
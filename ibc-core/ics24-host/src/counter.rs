@@ -0,0 +1,85 @@
+//! Defines the identifier counters maintained by the host.
+
+use ibc_core_channel_types::error::ChannelError;
+use ibc_core_client_types::error::ClientError;
+use ibc_core_connection_types::error::ConnectionError;
+use ibc_core_handler_types::error::ContextError;
+
+use crate::ValidationContext;
+
+/// The counters from which the identifiers of new clients, connections and
+/// channels are derived.
+///
+/// A counter holds the number of identifiers allocated so far, which is also
+/// the sequence number of the next identifier to be allocated. Counters never
+/// wrap around: incrementing a counter past `u64::MAX` fails with the
+/// `CounterOverflow` error of the corresponding ICS.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum IdentifierCounter {
+    Client,
+    Connection,
+    Channel,
+}
+
+impl IdentifierCounter {
+    /// Returns the current value of the counter, as stored by `ctx`.
+    pub fn get<Ctx>(self, ctx: &Ctx) -> Result<u64, ContextError>
+    where
+        Ctx: ValidationContext,
+    {
+        match self {
+            Self::Client => ctx.client_counter(),
+            Self::Connection => ctx.connection_counter(),
+            Self::Channel => ctx.channel_counter(),
+        }
+    }
+
+    /// Returns the value following `counter`, or the `CounterOverflow` error
+    /// of the corresponding ICS if `counter` is already `u64::MAX`.
+    ///
+    /// Hosts should use it when implementing the `increase_*_counter` methods
+    /// of the [`ExecutionContext`](crate::ExecutionContext).
+    pub fn next(self, counter: u64) -> Result<u64, ContextError> {
+        counter.checked_add(1).ok_or_else(|| self.overflow())
+    }
+
+    fn overflow(self) -> ContextError {
+        match self {
+            Self::Client => ClientError::CounterOverflow.into(),
+            Self::Connection => ConnectionError::CounterOverflow.into(),
+            Self::Channel => ChannelError::CounterOverflow.into(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case(IdentifierCounter::Client)]
+    #[case(IdentifierCounter::Connection)]
+    #[case(IdentifierCounter::Channel)]
+    fn test_next_counter(#[case] counter: IdentifierCounter) {
+        assert_eq!(counter.next(0).expect("no overflow"), 1);
+        assert_eq!(counter.next(u64::MAX - 1).expect("no overflow"), u64::MAX);
+
+        let err = counter.next(u64::MAX).expect_err("overflow");
+        let is_overflow = match counter {
+            IdentifierCounter::Client => {
+                matches!(err, ContextError::ClientError(ClientError::CounterOverflow))
+            }
+            IdentifierCounter::Connection => matches!(
+                err,
+                ContextError::ConnectionError(ConnectionError::CounterOverflow)
+            ),
+            IdentifierCounter::Channel => matches!(
+                err,
+                ContextError::ChannelError(ChannelError::CounterOverflow)
+            ),
+        };
+        assert!(is_overflow);
+    }
+}
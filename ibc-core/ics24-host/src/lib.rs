@@ -14,14 +14,28 @@
     rust_2018_idioms
 )]
 
+extern crate alloc;
+
 #[cfg(any(test, feature = "std"))]
 extern crate std;
 
 pub(crate) mod utils;
+pub use utils::calculate_block_delay;
 
 mod context;
 pub use context::*;
 
+mod counter;
+pub use counter::IdentifierCounter;
+
+mod params;
+pub use params::{AllowedClients, HostParams};
+
+pub mod store;
+
+#[cfg(feature = "async")]
+pub mod async_store;
+
 /// Re-exports ICS-24 data structures from `ibc-core-host-types` crate.
 pub mod types {
     #[doc(inline)]
@@ -0,0 +1,87 @@
+use core::time::Duration;
+
+use ibc_core_client_types::Height;
+use ibc_core_host_types::identifiers::{ClientType, IdentifierMode};
+use ibc_primitives::prelude::*;
+
+/// The client types allowed to be created on the host.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub enum AllowedClients {
+    /// Any client type is allowed, as with the `*` wildcard of ibc-go.
+    #[default]
+    All,
+    /// Only the listed client types are allowed.
+    Only(Vec<ClientType>),
+}
+
+impl AllowedClients {
+    /// Returns `true` if clients of the given type may be created.
+    pub fn is_allowed(&self, client_type: &ClientType) -> bool {
+        match self {
+            Self::All => true,
+            Self::Only(client_types) => client_types.contains(client_type),
+        }
+    }
+}
+
+/// The parameters of the IBC module of the host, read by the handlers at
+/// validation time.
+///
+/// Hosts typically back these with the parameters of their IBC module, which
+/// governance may update, rather than with fixed values.
+pub trait HostParams {
+    /// Returns the maximum expected time per block, from which the block
+    /// delay of a connection is derived from its time delay.
+    fn max_expected_time_per_block(&self) -> Duration;
+
+    /// Returns the client types allowed to be created on the host.
+    ///
+    /// By default, any client type is allowed.
+    fn allowed_clients(&self) -> AllowedClients {
+        AllowedClients::All
+    }
+
+    /// Returns the height of the upgrade plan scheduled on the host and not
+    /// executed yet, if any.
+    ///
+    /// By default, no upgrade is scheduled.
+    fn upgrade_plan_height(&self) -> Option<Height> {
+        None
+    }
+
+    /// Returns the maximum amount of gas the callbacks of an application,
+    /// e.g. of the callbacks middleware, may consume, if bounded.
+    ///
+    /// By default, callbacks are not bounded by the host.
+    fn max_callback_gas(&self) -> Option<u64> {
+        None
+    }
+
+    /// Returns the strictness with which the connection and channel
+    /// identifiers assigned by counterparties are validated during the
+    /// handshakes.
+    ///
+    /// By default, only identifiers of the form `{prefix}-{counter}` are
+    /// accepted. Hosts opt in to counterparties using aliases by returning
+    /// [`IdentifierMode::Lenient`].
+    fn counterparty_identifier_mode(&self) -> IdentifierMode {
+        IdentifierMode::Exact
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn allowed_clients() {
+        let tendermint = ClientType::new("07-tendermint").expect("valid client type");
+        let mock = ClientType::new("9999-mock").expect("valid client type");
+
+        assert!(AllowedClients::All.is_allowed(&mock));
+
+        let allowed = AllowedClients::Only(vec![tendermint.clone()]);
+        assert!(allowed.is_allowed(&tendermint));
+        assert!(!allowed.is_allowed(&mock));
+    }
+}
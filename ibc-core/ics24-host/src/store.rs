@@ -0,0 +1,1059 @@
+//! Provides a key-value store abstraction with write-batch semantics, along
+//! with an adapter that implements the [`ValidationContext`] and
+//! [`ExecutionContext`] traits over it.
+//!
+//! Hosts that back their IBC state with an [`IbcStore`] and their client
+//! states with a [`ClientStore`] can execute handlers atomically by running
+//! them against a [`WriteBatch`] and a [`ClientWriteBatch`], and then either
+//! committing or discarding both depending on the outcome:
+//!
+//! ```ignore
+//! let mut ctx = StoreContext::new(
+//!     WriteBatch::new(&mut store),
+//!     ClientWriteBatch::new(clients),
+//!     host,
+//! );
+//!
+//! let result = dispatch(&mut ctx, &mut router, msg);
+//!
+//! let (batch, clients, host) = ctx.into_parts();
+//! let clients = match result {
+//!     Ok(()) => {
+//!         batch.commit();
+//!         clients.commit()
+//!     }
+//!     Err(_) => {
+//!         batch.discard();
+//!         clients.discard()
+//!     }
+//! };
+//! ```
+
+use alloc::collections::BTreeSet;
+use core::time::Duration;
+
+use ibc_core_channel_types::channel::ChannelEnd;
+use ibc_core_channel_types::commitment::{AcknowledgementCommitment, PacketCommitment};
+use ibc_core_channel_types::error::{ChannelError, PacketError};
+use ibc_core_channel_types::packet::Receipt;
+use ibc_core_client_context::prelude::*;
+use ibc_core_client_types::error::ClientError;
+use ibc_core_client_types::Height;
+use ibc_core_commitment_types::commitment::CommitmentPrefix;
+use ibc_core_connection_types::error::ConnectionError;
+use ibc_core_connection_types::proto::v1::ClientPaths;
+use ibc_core_connection_types::ConnectionEnd;
+use ibc_core_handler_types::error::ContextError;
+use ibc_core_handler_types::events::IbcEvent;
+use ibc_core_host_types::error::HostError;
+use ibc_core_host_types::identifiers::{ClientId, ConnectionId, IdentifierMode, Sequence};
+use ibc_core_host_types::path::{
+    AckPath, ChannelEndPath, ClientConnectionPath, ClientConsensusStatePath, ClientStatePath,
+    CommitmentPath, ConnectionPath, NextChannelSequencePath, NextClientSequencePath,
+    NextConnectionSequencePath, Path, ReceiptPath, SeqAckPath, SeqRecvPath, SeqSendPath,
+};
+use ibc_primitives::prelude::*;
+use ibc_primitives::proto::Protobuf;
+use ibc_primitives::{Signer, Timestamp};
+use prost::Message;
+
+use crate::{AllowedClients, ExecutionContext, HostParams, IdentifierCounter, ValidationContext};
+
+/// A key-value store holding the IBC state of the host, keyed by [`Path`].
+pub trait IbcStore {
+    /// Returns the value stored under `path`, if any.
+    fn get(&self, path: &Path) -> Option<Vec<u8>>;
+
+    /// Stores `value` under `path`, overwriting any previous value.
+    fn set(&mut self, path: Path, value: Vec<u8>);
+
+    /// Deletes the value stored under `path`, if any.
+    fn delete(&mut self, path: &Path);
+}
+
+/// A batch of writes on top of an [`IbcStore`].
+///
+/// Reads go through the batch first, so they observe the writes made since
+/// the batch was created. The writes only reach the underlying store once
+/// [`commit`](Self::commit) is called, and are dropped otherwise.
+pub struct WriteBatch<'a, S> {
+    store: &'a mut S,
+    writes: BTreeMap<Path, Option<Vec<u8>>>,
+}
+
+impl<'a, S> WriteBatch<'a, S>
+where
+    S: IbcStore,
+{
+    /// Creates an empty batch of writes on top of `store`.
+    pub fn new(store: &'a mut S) -> Self {
+        Self {
+            store,
+            writes: BTreeMap::new(),
+        }
+    }
+
+    /// Applies the writes of the batch to the underlying store.
+    pub fn commit(self) {
+        for (path, value) in self.writes {
+            match value {
+                Some(value) => self.store.set(path, value),
+                None => self.store.delete(&path),
+            }
+        }
+    }
+
+    /// Drops the writes of the batch, leaving the underlying store untouched.
+    pub fn discard(self) {}
+
+    /// Returns the underlying store, without the writes of the batch.
+    pub fn store(&self) -> &S {
+        self.store
+    }
+}
+
+impl<S> IbcStore for WriteBatch<'_, S>
+where
+    S: IbcStore,
+{
+    fn get(&self, path: &Path) -> Option<Vec<u8>> {
+        match self.writes.get(path) {
+            Some(value) => value.clone(),
+            None => self.store.get(path),
+        }
+    }
+
+    fn set(&mut self, path: Path, value: Vec<u8>) {
+        self.writes.insert(path, Some(value));
+    }
+
+    fn delete(&mut self, path: &Path) {
+        self.writes.insert(path.clone(), None);
+    }
+}
+
+/// A copy-on-write overlay of an [`IbcStore`], which the writes made on top
+/// of it never reach.
+///
+/// Reads go through the overlay first, so they observe the writes made since
+/// the overlay was created, as with a [`WriteBatch`]. The writes are instead
+/// handed over by [`into_writes`](Self::into_writes), e.g. to predict the
+/// state transition of a message without applying it.
+pub struct StoreOverlay<'a, S> {
+    store: &'a S,
+    writes: BTreeMap<Path, Option<Vec<u8>>>,
+}
+
+impl<'a, S> StoreOverlay<'a, S>
+where
+    S: IbcStore,
+{
+    /// Creates an empty overlay on top of `store`.
+    pub fn new(store: &'a S) -> Self {
+        Self {
+            store,
+            writes: BTreeMap::new(),
+        }
+    }
+
+    /// Returns the writes made on top of the store, by path, a `None` value
+    /// deleting the value stored under the path.
+    pub fn into_writes(self) -> BTreeMap<Path, Option<Vec<u8>>> {
+        self.writes
+    }
+}
+
+impl<S> IbcStore for StoreOverlay<'_, S>
+where
+    S: IbcStore,
+{
+    fn get(&self, path: &Path) -> Option<Vec<u8>> {
+        match self.writes.get(path) {
+            Some(value) => value.clone(),
+            None => self.store.get(path),
+        }
+    }
+
+    fn set(&mut self, path: Path, value: Vec<u8>) {
+        self.writes.insert(path, Some(value));
+    }
+
+    fn delete(&mut self, path: &Path) {
+        self.writes.insert(path.clone(), None);
+    }
+}
+
+/// A store holding the client and consensus states of the host, along with
+/// the metadata of the client updates.
+///
+/// Unlike the rest of the IBC state, these are stored as the client types of
+/// the host, which are opaque to a [`StoreContext`], rather than as encoded
+/// values of an [`IbcStore`]. Their writes are buffered by a
+/// [`ClientWriteBatch`] instead, which is also the client context the client
+/// states are validated and executed against.
+pub trait ClientStore: Sized {
+    /// The client state type of the clients hosted by the store.
+    type ClientState: ClientStateExecution<ClientWriteBatch<Self>> + Clone;
+    /// The consensus state type of the clients hosted by the store.
+    type ConsensusState: ConsensusState + Clone;
+
+    /// See [`ClientValidationContext::client_state`].
+    fn client_state(&self, client_id: &ClientId) -> Result<Self::ClientState, ContextError>;
+
+    /// See [`ClientValidationContext::consensus_state`].
+    fn consensus_state(
+        &self,
+        client_cons_state_path: &ClientConsensusStatePath,
+    ) -> Result<Self::ConsensusState, ContextError>;
+
+    /// See [`ExtClientValidationContext::consensus_state_heights`].
+    fn consensus_state_heights(&self, client_id: &ClientId) -> Result<Vec<Height>, ContextError>;
+
+    /// See [`ClientValidationContext::client_update_meta`].
+    fn client_update_meta(
+        &self,
+        client_id: &ClientId,
+        height: &Height,
+    ) -> Result<(Timestamp, Height), ContextError>;
+
+    /// See [`ValidationContext::host_height`].
+    fn host_height(&self) -> Result<Height, ContextError>;
+
+    /// See [`ValidationContext::host_timestamp`].
+    fn host_timestamp(&self) -> Result<Timestamp, ContextError>;
+
+    /// See [`ExtClientValidationContext::evidence_age_limits`].
+    fn evidence_age_limits(&self) -> Option<EvidenceAgeLimits> {
+        None
+    }
+
+    /// Stores the state of the client `client_id`, overwriting any previous
+    /// state.
+    fn set_client_state(&mut self, client_id: ClientId, client_state: Self::ClientState);
+
+    /// Stores the consensus state under `consensus_state_path`, overwriting
+    /// any previous state.
+    fn set_consensus_state(
+        &mut self,
+        consensus_state_path: ClientConsensusStatePath,
+        consensus_state: Self::ConsensusState,
+    );
+
+    /// Deletes the consensus state stored under `consensus_state_path`, if
+    /// any.
+    fn delete_consensus_state(&mut self, consensus_state_path: &ClientConsensusStatePath);
+
+    /// Stores the time and height of the host at which the client `client_id`
+    /// was updated to `height`.
+    fn set_update_meta(
+        &mut self,
+        client_id: ClientId,
+        height: Height,
+        host_timestamp: Timestamp,
+        host_height: Height,
+    );
+
+    /// Deletes the time and height of the host at which the client
+    /// `client_id` was updated to `height`, if any.
+    fn delete_update_meta(&mut self, client_id: &ClientId, height: &Height);
+}
+
+/// A batch of writes on top of a [`ClientStore`], which implements the
+/// client contexts over it.
+///
+/// As with a [`WriteBatch`], reads go through the batch first, and the
+/// writes only reach the underlying store once [`commit`](Self::commit) is
+/// called.
+pub struct ClientWriteBatch<C>
+where
+    C: ClientStore,
+{
+    clients: C,
+    client_states: BTreeMap<ClientId, C::ClientState>,
+    consensus_states: BTreeMap<ClientConsensusStatePath, Option<C::ConsensusState>>,
+    update_metas: BTreeMap<(ClientId, Height), Option<(Timestamp, Height)>>,
+}
+
+impl<C> ClientWriteBatch<C>
+where
+    C: ClientStore,
+{
+    /// Creates an empty batch of writes on top of `clients`.
+    pub fn new(clients: C) -> Self {
+        Self {
+            clients,
+            client_states: BTreeMap::new(),
+            consensus_states: BTreeMap::new(),
+            update_metas: BTreeMap::new(),
+        }
+    }
+
+    /// Applies the writes of the batch to the underlying store, and returns
+    /// the store.
+    pub fn commit(self) -> C {
+        let mut clients = self.clients;
+
+        for (client_id, client_state) in self.client_states {
+            clients.set_client_state(client_id, client_state);
+        }
+
+        for (path, consensus_state) in self.consensus_states {
+            match consensus_state {
+                Some(consensus_state) => clients.set_consensus_state(path, consensus_state),
+                None => clients.delete_consensus_state(&path),
+            }
+        }
+
+        for ((client_id, height), update_meta) in self.update_metas {
+            match update_meta {
+                Some((host_timestamp, host_height)) => {
+                    clients.set_update_meta(client_id, height, host_timestamp, host_height)
+                }
+                None => clients.delete_update_meta(&client_id, &height),
+            }
+        }
+
+        clients
+    }
+
+    /// Drops the writes of the batch, and returns the untouched underlying
+    /// store.
+    pub fn discard(self) -> C {
+        self.clients
+    }
+}
+
+impl<C> ClientValidationContext for ClientWriteBatch<C>
+where
+    C: ClientStore,
+{
+    type ClientStateRef = C::ClientState;
+    type ConsensusStateRef = C::ConsensusState;
+
+    fn client_state(&self, client_id: &ClientId) -> Result<Self::ClientStateRef, ContextError> {
+        match self.client_states.get(client_id) {
+            Some(client_state) => Ok(client_state.clone()),
+            None => self.clients.client_state(client_id),
+        }
+    }
+
+    fn consensus_state(
+        &self,
+        client_cons_state_path: &ClientConsensusStatePath,
+    ) -> Result<Self::ConsensusStateRef, ContextError> {
+        match self.consensus_states.get(client_cons_state_path) {
+            Some(Some(consensus_state)) => Ok(consensus_state.clone()),
+            Some(None) => Err(ClientError::ConsensusStateNotFound {
+                client_id: client_cons_state_path.client_id.clone(),
+                height: Height::new(
+                    client_cons_state_path.revision_number,
+                    client_cons_state_path.revision_height,
+                )?,
+            }
+            .into()),
+            None => self.clients.consensus_state(client_cons_state_path),
+        }
+    }
+
+    fn client_update_meta(
+        &self,
+        client_id: &ClientId,
+        height: &Height,
+    ) -> Result<(Timestamp, Height), ContextError> {
+        match self.update_metas.get(&(client_id.clone(), *height)) {
+            Some(Some(update_meta)) => Ok(*update_meta),
+            Some(None) => Err(ClientError::UpdateMetaDataNotFound {
+                client_id: client_id.clone(),
+                height: *height,
+            }
+            .into()),
+            None => self.clients.client_update_meta(client_id, height),
+        }
+    }
+}
+
+impl<C> ClientExecutionContext for ClientWriteBatch<C>
+where
+    C: ClientStore,
+{
+    type ClientStateMut = C::ClientState;
+
+    fn store_client_state(
+        &mut self,
+        client_state_path: ClientStatePath,
+        client_state: Self::ClientStateRef,
+    ) -> Result<(), ContextError> {
+        self.client_states.insert(client_state_path.0, client_state);
+        Ok(())
+    }
+
+    fn store_consensus_state(
+        &mut self,
+        consensus_state_path: ClientConsensusStatePath,
+        consensus_state: Self::ConsensusStateRef,
+    ) -> Result<(), ContextError> {
+        self.consensus_states
+            .insert(consensus_state_path, Some(consensus_state));
+        Ok(())
+    }
+
+    fn delete_consensus_state(
+        &mut self,
+        consensus_state_path: ClientConsensusStatePath,
+    ) -> Result<(), ContextError> {
+        self.consensus_states.insert(consensus_state_path, None);
+        Ok(())
+    }
+
+    fn store_update_meta(
+        &mut self,
+        client_id: ClientId,
+        height: Height,
+        host_timestamp: Timestamp,
+        host_height: Height,
+    ) -> Result<(), ContextError> {
+        self.update_metas
+            .insert((client_id, height), Some((host_timestamp, host_height)));
+        Ok(())
+    }
+
+    fn delete_update_meta(
+        &mut self,
+        client_id: ClientId,
+        height: Height,
+    ) -> Result<(), ContextError> {
+        self.update_metas.insert((client_id, height), None);
+        Ok(())
+    }
+}
+
+impl<C> ExtClientValidationContext for ClientWriteBatch<C>
+where
+    C: ClientStore,
+{
+    fn host_timestamp(&self) -> Result<Timestamp, ContextError> {
+        self.clients.host_timestamp()
+    }
+
+    fn host_height(&self) -> Result<Height, ContextError> {
+        self.clients.host_height()
+    }
+
+    fn consensus_state_heights(&self, client_id: &ClientId) -> Result<Vec<Height>, ContextError> {
+        let mut heights: BTreeSet<Height> = self
+            .clients
+            .consensus_state_heights(client_id)?
+            .into_iter()
+            .collect();
+
+        for (path, consensus_state) in &self.consensus_states {
+            if &path.client_id != client_id {
+                continue;
+            }
+
+            let height = Height::new(path.revision_number, path.revision_height)?;
+            match consensus_state {
+                Some(_) => heights.insert(height),
+                None => heights.remove(&height),
+            };
+        }
+
+        Ok(heights.into_iter().collect())
+    }
+
+    fn next_consensus_state(
+        &self,
+        client_id: &ClientId,
+        height: &Height,
+    ) -> Result<Option<Self::ConsensusStateRef>, ContextError> {
+        let next_height = self
+            .consensus_state_heights(client_id)?
+            .into_iter()
+            .find(|h| h > height);
+
+        next_height
+            .map(|h| {
+                self.consensus_state(&ClientConsensusStatePath::new(
+                    client_id.clone(),
+                    h.revision_number(),
+                    h.revision_height(),
+                ))
+            })
+            .transpose()
+    }
+
+    fn prev_consensus_state(
+        &self,
+        client_id: &ClientId,
+        height: &Height,
+    ) -> Result<Option<Self::ConsensusStateRef>, ContextError> {
+        let prev_height = self
+            .consensus_state_heights(client_id)?
+            .into_iter()
+            .rev()
+            .find(|h| h < height);
+
+        prev_height
+            .map(|h| {
+                self.consensus_state(&ClientConsensusStatePath::new(
+                    client_id.clone(),
+                    h.revision_number(),
+                    h.revision_height(),
+                ))
+            })
+            .transpose()
+    }
+
+    fn evidence_age_limits(&self) -> Option<EvidenceAgeLimits> {
+        self.clients.evidence_age_limits()
+    }
+}
+
+/// The host-specific parts of a [`StoreContext`], which cannot be derived
+/// from the contents of an [`IbcStore`] nor of a [`ClientStore`].
+///
+/// The parameters of the host are provided through its [`HostParams`]
+/// supertrait.
+pub trait StoreHost: HostParams {
+    /// The store of the client and consensus states of the host.
+    type Clients: ClientStore;
+    /// The client state type for the host chain.
+    type HostClientState: ClientStateValidation<ClientWriteBatch<Self::Clients>>;
+    /// The consensus state type for the host chain.
+    type HostConsensusState: ConsensusState;
+
+    /// See [`ValidationContext::host_consensus_state`].
+    fn host_consensus_state(
+        &self,
+        height: &Height,
+    ) -> Result<Self::HostConsensusState, ContextError>;
+
+    /// See [`ValidationContext::validate_self_client`].
+    fn validate_self_client(
+        &self,
+        client_state_of_host_on_counterparty: Self::HostClientState,
+    ) -> Result<(), ContextError>;
+
+    /// See [`ValidationContext::commitment_prefix`].
+    fn commitment_prefix(&self) -> CommitmentPrefix;
+
+    /// See [`ValidationContext::validate_message_signer`].
+    fn validate_message_signer(&self, signer: &Signer) -> Result<(), ContextError>;
+
+    /// See [`ValidationContext::is_channel_frozen`].
+    fn is_channel_frozen(&self, _channel_end_path: &ChannelEndPath) -> Result<bool, ContextError> {
+        Ok(false)
+    }
+
+    /// See [`ExecutionContext::store_channel_frozen`].
+    fn store_channel_frozen(
+        &mut self,
+        _channel_end_path: &ChannelEndPath,
+        _frozen: bool,
+    ) -> Result<(), ContextError> {
+        Err(ChannelError::UnsupportedChannelFreeze.into())
+    }
+
+    /// See [`ExecutionContext::emit_ibc_event`].
+    fn emit_ibc_event(&mut self, event: IbcEvent) -> Result<(), ContextError>;
+
+    /// See [`ExecutionContext::log_message`].
+    fn log_message(&mut self, message: String) -> Result<(), ContextError>;
+}
+
+/// Implements [`ValidationContext`] and [`ExecutionContext`] by storing the
+/// connection, channel and packet states in an [`IbcStore`], the client and
+/// consensus states in the [`ClientWriteBatch`] of a [`ClientStore`], and
+/// delegating everything else to a [`StoreHost`].
+///
+/// Values are encoded as follows:
+/// - connection and channel ends: their Protobuf encoding,
+/// - connection ids of a client: the Protobuf encoding of [`ClientPaths`],
+/// - sequences and counters: their big-endian `u64` encoding,
+/// - packet commitments and acknowledgements: their raw bytes,
+/// - packet receipts: a single `1` byte.
+///
+/// Missing counters are treated as zero.
+pub struct StoreContext<S, H>
+where
+    H: StoreHost,
+{
+    store: S,
+    clients: ClientWriteBatch<H::Clients>,
+    host: H,
+}
+
+impl<S, H> StoreContext<S, H>
+where
+    S: IbcStore,
+    H: StoreHost,
+{
+    /// Creates a context storing the IBC state in `store`, and the client
+    /// states in `clients`.
+    pub fn new(store: S, clients: ClientWriteBatch<H::Clients>, host: H) -> Self {
+        Self {
+            store,
+            clients,
+            host,
+        }
+    }
+
+    /// Returns the store, the client write batch and the host of the context.
+    pub fn into_parts(self) -> (S, ClientWriteBatch<H::Clients>, H) {
+        (self.store, self.clients, self.host)
+    }
+
+    fn get_u64(&self, path: Path) -> Option<Result<u64, ContextError>> {
+        self.store.get(&path).map(|bytes| {
+            let bytes: [u8; 8] =
+                bytes
+                    .try_into()
+                    .map_err(|bytes: Vec<u8>| HostError::FailedToDecode {
+                        path: path.clone(),
+                        description: format!("expected 8 bytes, got {}", bytes.len()),
+                    })?;
+            Ok(u64::from_be_bytes(bytes))
+        })
+    }
+
+    fn get_counter(&self, path: impl Into<Path>) -> Result<u64, ContextError> {
+        self.get_u64(path.into()).unwrap_or(Ok(0))
+    }
+
+    fn increase_counter(
+        &mut self,
+        path: impl Into<Path>,
+        counter: IdentifierCounter,
+    ) -> Result<(), ContextError> {
+        let path = path.into();
+        let value = counter.next(self.get_u64(path.clone()).unwrap_or(Ok(0))?)?;
+        self.store.set(path, value.to_be_bytes().to_vec());
+        Ok(())
+    }
+
+    fn get_sequence<F>(&self, path: impl Into<Path>, missing: F) -> Result<Sequence, ContextError>
+    where
+        F: FnOnce() -> PacketError,
+    {
+        match self.get_u64(path.into()) {
+            Some(seq) => Ok(Sequence::from(seq?)),
+            None => Err(missing().into()),
+        }
+    }
+}
+
+impl<S, H> HostParams for StoreContext<S, H>
+where
+    H: StoreHost,
+{
+    fn max_expected_time_per_block(&self) -> Duration {
+        self.host.max_expected_time_per_block()
+    }
+
+    fn allowed_clients(&self) -> AllowedClients {
+        self.host.allowed_clients()
+    }
+
+    fn upgrade_plan_height(&self) -> Option<Height> {
+        self.host.upgrade_plan_height()
+    }
+
+    fn max_callback_gas(&self) -> Option<u64> {
+        self.host.max_callback_gas()
+    }
+
+    fn counterparty_identifier_mode(&self) -> IdentifierMode {
+        self.host.counterparty_identifier_mode()
+    }
+}
+
+impl<S, H> ValidationContext for StoreContext<S, H>
+where
+    S: IbcStore,
+    H: StoreHost,
+{
+    type V = ClientWriteBatch<H::Clients>;
+    type HostClientState = H::HostClientState;
+    type HostConsensusState = H::HostConsensusState;
+
+    fn get_client_validation_context(&self) -> &Self::V {
+        &self.clients
+    }
+
+    fn host_height(&self) -> Result<Height, ContextError> {
+        self.clients.clients.host_height()
+    }
+
+    fn host_timestamp(&self) -> Result<Timestamp, ContextError> {
+        self.clients.clients.host_timestamp()
+    }
+
+    fn host_consensus_state(
+        &self,
+        height: &Height,
+    ) -> Result<Self::HostConsensusState, ContextError> {
+        self.host.host_consensus_state(height)
+    }
+
+    fn client_counter(&self) -> Result<u64, ContextError> {
+        self.get_counter(NextClientSequencePath)
+    }
+
+    fn connection_end(&self, conn_id: &ConnectionId) -> Result<ConnectionEnd, ContextError> {
+        let path = Path::from(ConnectionPath::new(conn_id));
+        let bytes = self
+            .store
+            .get(&path)
+            .ok_or_else(|| ConnectionError::ConnectionNotFound {
+                connection_id: conn_id.clone(),
+            })?;
+
+        ConnectionEnd::decode_vec(&bytes).map_err(|e| {
+            HostError::FailedToDecode {
+                path,
+                description: e.to_string(),
+            }
+            .into()
+        })
+    }
+
+    fn validate_self_client(
+        &self,
+        client_state_of_host_on_counterparty: Self::HostClientState,
+    ) -> Result<(), ContextError> {
+        self.host
+            .validate_self_client(client_state_of_host_on_counterparty)
+    }
+
+    fn commitment_prefix(&self) -> CommitmentPrefix {
+        self.host.commitment_prefix()
+    }
+
+    fn connection_counter(&self) -> Result<u64, ContextError> {
+        self.get_counter(NextConnectionSequencePath)
+    }
+
+    fn channel_end(&self, channel_end_path: &ChannelEndPath) -> Result<ChannelEnd, ContextError> {
+        let path = Path::from(channel_end_path.clone());
+        let bytes = self
+            .store
+            .get(&path)
+            .ok_or_else(|| ChannelError::ChannelNotFound {
+                port_id: channel_end_path.0.clone(),
+                channel_id: channel_end_path.1.clone(),
+            })?;
+
+        ChannelEnd::decode_vec(&bytes).map_err(|e| {
+            HostError::FailedToDecode {
+                path,
+                description: e.to_string(),
+            }
+            .into()
+        })
+    }
+
+    fn get_next_sequence_send(
+        &self,
+        seq_send_path: &SeqSendPath,
+    ) -> Result<Sequence, ContextError> {
+        self.get_sequence(seq_send_path.clone(), || PacketError::MissingNextSendSeq {
+            port_id: seq_send_path.0.clone(),
+            channel_id: seq_send_path.1.clone(),
+        })
+    }
+
+    fn get_next_sequence_recv(
+        &self,
+        seq_recv_path: &SeqRecvPath,
+    ) -> Result<Sequence, ContextError> {
+        self.get_sequence(seq_recv_path.clone(), || PacketError::MissingNextRecvSeq {
+            port_id: seq_recv_path.0.clone(),
+            channel_id: seq_recv_path.1.clone(),
+        })
+    }
+
+    fn get_next_sequence_ack(&self, seq_ack_path: &SeqAckPath) -> Result<Sequence, ContextError> {
+        self.get_sequence(seq_ack_path.clone(), || PacketError::MissingNextAckSeq {
+            port_id: seq_ack_path.0.clone(),
+            channel_id: seq_ack_path.1.clone(),
+        })
+    }
+
+    fn get_packet_commitment(
+        &self,
+        commitment_path: &CommitmentPath,
+    ) -> Result<PacketCommitment, ContextError> {
+        self.store
+            .get(&commitment_path.clone().into())
+            .map(PacketCommitment::from)
+            .ok_or_else(|| {
+                PacketError::PacketCommitmentNotFound {
+                    sequence: commitment_path.sequence,
+                }
+                .into()
+            })
+    }
+
+    fn get_packet_receipt(&self, receipt_path: &ReceiptPath) -> Result<Receipt, ContextError> {
+        self.store
+            .get(&receipt_path.clone().into())
+            .map(|_| Receipt::Ok)
+            .ok_or_else(|| {
+                PacketError::PacketReceiptNotFound {
+                    sequence: receipt_path.sequence,
+                }
+                .into()
+            })
+    }
+
+    fn get_packet_acknowledgement(
+        &self,
+        ack_path: &AckPath,
+    ) -> Result<AcknowledgementCommitment, ContextError> {
+        self.store
+            .get(&ack_path.clone().into())
+            .map(AcknowledgementCommitment::from)
+            .ok_or_else(|| {
+                PacketError::PacketAcknowledgementNotFound {
+                    sequence: ack_path.sequence,
+                }
+                .into()
+            })
+    }
+
+    fn channel_counter(&self) -> Result<u64, ContextError> {
+        self.get_counter(NextChannelSequencePath)
+    }
+
+    fn is_channel_frozen(&self, channel_end_path: &ChannelEndPath) -> Result<bool, ContextError> {
+        self.host.is_channel_frozen(channel_end_path)
+    }
+
+    fn validate_message_signer(&self, signer: &Signer) -> Result<(), ContextError> {
+        self.host.validate_message_signer(signer)
+    }
+}
+
+impl<S, H> ExecutionContext for StoreContext<S, H>
+where
+    S: IbcStore,
+    H: StoreHost,
+{
+    type E = ClientWriteBatch<H::Clients>;
+
+    fn get_client_execution_context(&mut self) -> &mut Self::E {
+        &mut self.clients
+    }
+
+    fn increase_client_counter(&mut self) -> Result<(), ContextError> {
+        self.increase_counter(NextClientSequencePath, IdentifierCounter::Client)
+    }
+
+    fn store_connection(
+        &mut self,
+        connection_path: &ConnectionPath,
+        connection_end: ConnectionEnd,
+    ) -> Result<(), ContextError> {
+        self.store
+            .set(connection_path.clone().into(), connection_end.encode_vec());
+        Ok(())
+    }
+
+    fn store_connection_to_client(
+        &mut self,
+        client_connection_path: &ClientConnectionPath,
+        conn_id: ConnectionId,
+    ) -> Result<(), ContextError> {
+        let path = Path::from(client_connection_path.clone());
+
+        let mut client_paths = match self.store.get(&path) {
+            Some(bytes) => {
+                ClientPaths::decode(bytes.as_slice()).map_err(|e| HostError::FailedToDecode {
+                    path: path.clone(),
+                    description: e.to_string(),
+                })?
+            }
+            None => ClientPaths::default(),
+        };
+        client_paths.paths.push(conn_id.to_string());
+
+        self.store.set(path, client_paths.encode_to_vec());
+        Ok(())
+    }
+
+    fn increase_connection_counter(&mut self) -> Result<(), ContextError> {
+        self.increase_counter(NextConnectionSequencePath, IdentifierCounter::Connection)
+    }
+
+    fn store_packet_commitment(
+        &mut self,
+        commitment_path: &CommitmentPath,
+        commitment: PacketCommitment,
+    ) -> Result<(), ContextError> {
+        self.store
+            .set(commitment_path.clone().into(), commitment.into_vec());
+        Ok(())
+    }
+
+    fn delete_packet_commitment(
+        &mut self,
+        commitment_path: &CommitmentPath,
+    ) -> Result<(), ContextError> {
+        self.store.delete(&commitment_path.clone().into());
+        Ok(())
+    }
+
+    fn store_packet_receipt(
+        &mut self,
+        receipt_path: &ReceiptPath,
+        receipt: Receipt,
+    ) -> Result<(), ContextError> {
+        match receipt {
+            Receipt::Ok => self.store.set(receipt_path.clone().into(), vec![1u8]),
+        }
+        Ok(())
+    }
+
+    fn store_packet_acknowledgement(
+        &mut self,
+        ack_path: &AckPath,
+        ack_commitment: AcknowledgementCommitment,
+    ) -> Result<(), ContextError> {
+        self.store
+            .set(ack_path.clone().into(), ack_commitment.into_vec());
+        Ok(())
+    }
+
+    fn delete_packet_acknowledgement(&mut self, ack_path: &AckPath) -> Result<(), ContextError> {
+        self.store.delete(&ack_path.clone().into());
+        Ok(())
+    }
+
+    fn store_channel(
+        &mut self,
+        channel_end_path: &ChannelEndPath,
+        channel_end: ChannelEnd,
+    ) -> Result<(), ContextError> {
+        self.store
+            .set(channel_end_path.clone().into(), channel_end.encode_vec());
+        Ok(())
+    }
+
+    fn store_next_sequence_send(
+        &mut self,
+        seq_send_path: &SeqSendPath,
+        seq: Sequence,
+    ) -> Result<(), ContextError> {
+        self.store.set(seq_send_path.clone().into(), seq.to_vec());
+        Ok(())
+    }
+
+    fn store_next_sequence_recv(
+        &mut self,
+        seq_recv_path: &SeqRecvPath,
+        seq: Sequence,
+    ) -> Result<(), ContextError> {
+        self.store.set(seq_recv_path.clone().into(), seq.to_vec());
+        Ok(())
+    }
+
+    fn store_next_sequence_ack(
+        &mut self,
+        seq_ack_path: &SeqAckPath,
+        seq: Sequence,
+    ) -> Result<(), ContextError> {
+        self.store.set(seq_ack_path.clone().into(), seq.to_vec());
+        Ok(())
+    }
+
+    fn increase_channel_counter(&mut self) -> Result<(), ContextError> {
+        self.increase_counter(NextChannelSequencePath, IdentifierCounter::Channel)
+    }
+
+    fn store_channel_frozen(
+        &mut self,
+        channel_end_path: &ChannelEndPath,
+        frozen: bool,
+    ) -> Result<(), ContextError> {
+        self.host.store_channel_frozen(channel_end_path, frozen)
+    }
+
+    fn emit_ibc_event(&mut self, event: IbcEvent) -> Result<(), ContextError> {
+        self.host.emit_ibc_event(event)
+    }
+
+    fn log_message(&mut self, message: String) -> Result<(), ContextError> {
+        self.host.log_message(message)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ibc_core_host_types::identifiers::{ChannelId, PortId};
+
+    use super::*;
+
+    #[derive(Default)]
+    struct MemStore(BTreeMap<Path, Vec<u8>>);
+
+    impl IbcStore for MemStore {
+        fn get(&self, path: &Path) -> Option<Vec<u8>> {
+            self.0.get(path).cloned()
+        }
+
+        fn set(&mut self, path: Path, value: Vec<u8>) {
+            self.0.insert(path, value);
+        }
+
+        fn delete(&mut self, path: &Path) {
+            self.0.remove(path);
+        }
+    }
+
+    fn receipt_path(seq: u64) -> Path {
+        ReceiptPath::new(&PortId::transfer(), &ChannelId::zero(), seq.into()).into()
+    }
+
+    #[test]
+    fn test_write_batch_commit_and_discard() {
+        let mut store = MemStore::default();
+        store.set(receipt_path(1), vec![1]);
+
+        let mut batch = WriteBatch::new(&mut store);
+        batch.set(receipt_path(2), vec![1]);
+        batch.delete(&receipt_path(1));
+        assert_eq!(batch.get(&receipt_path(1)), None);
+        assert_eq!(batch.get(&receipt_path(2)), Some(vec![1]));
+        batch.discard();
+
+        assert_eq!(store.get(&receipt_path(1)), Some(vec![1]));
+        assert_eq!(store.get(&receipt_path(2)), None);
+
+        let mut batch = WriteBatch::new(&mut store);
+        batch.set(receipt_path(2), vec![1]);
+        batch.delete(&receipt_path(1));
+        batch.commit();
+
+        assert_eq!(store.get(&receipt_path(1)), None);
+        assert_eq!(store.get(&receipt_path(2)), Some(vec![1]));
+    }
+
+    #[test]
+    fn test_store_overlay_writes() {
+        let mut store = MemStore::default();
+        store.set(receipt_path(1), vec![1]);
+
+        let mut overlay = StoreOverlay::new(&store);
+        overlay.set(receipt_path(2), vec![1]);
+        overlay.delete(&receipt_path(1));
+        assert_eq!(overlay.get(&receipt_path(1)), None);
+        assert_eq!(overlay.get(&receipt_path(2)), Some(vec![1]));
+
+        assert_eq!(
+            overlay.into_writes().into_iter().collect::<Vec<_>>(),
+            vec![(receipt_path(1), None), (receipt_path(2), Some(vec![1]))]
+        );
+        assert_eq!(store.get(&receipt_path(1)), Some(vec![1]));
+        assert_eq!(store.get(&receipt_path(2)), None);
+    }
+}
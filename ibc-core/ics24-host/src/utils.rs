@@ -1,20 +1,31 @@
 use core::time::Duration;
 
+/// Calculates the number of blocks that must elapse on the host chain for the
+/// given `delay_period_time` to be considered passed, based on the host's
+/// `max_expected_time_per_block` parameter.
+///
+/// The result is rounded up, i.e. `ceil(delay_period_time /
+/// max_expected_time_per_block)`, and is computed with nanosecond precision to
+/// match ibc-go's `getBlockDelay`. A zero `max_expected_time_per_block`
+/// disables the block delay.
 pub fn calculate_block_delay(
     delay_period_time: &Duration,
     max_expected_time_per_block: &Duration,
 ) -> u64 {
-    let delay_period_time = delay_period_time.as_secs();
-    let max_expected_time_per_block = max_expected_time_per_block.as_secs();
+    let delay_period_time = delay_period_time.as_nanos();
+    let max_expected_time_per_block = max_expected_time_per_block.as_nanos();
     if max_expected_time_per_block == 0 {
         return 0;
     }
-    if delay_period_time % max_expected_time_per_block == 0 {
-        return delay_period_time / max_expected_time_per_block;
-    }
 
-    // TODO: Use `u64::div_ceil` here instead
-    (delay_period_time / max_expected_time_per_block) + 1
+    // TODO: Use `u128::div_ceil` here instead once the MSRV allows it
+    let block_delay = if delay_period_time % max_expected_time_per_block == 0 {
+        delay_period_time / max_expected_time_per_block
+    } else {
+        (delay_period_time / max_expected_time_per_block) + 1
+    };
+
+    u64::try_from(block_delay).unwrap_or(u64::MAX)
 }
 
 #[cfg(test)]
@@ -44,4 +55,21 @@ mod tests {
             expected
         );
     }
+
+    #[rstest]
+    #[case::sub_second_block_time(Duration::from_secs(10), Duration::from_millis(500), 20)]
+    #[case::sub_second_remainder(Duration::from_millis(1500), Duration::from_secs(1), 2)]
+    #[case::sub_second_delay(Duration::from_millis(1), Duration::from_secs(5), 1)]
+    #[case::nanos(Duration::from_nanos(7), Duration::from_nanos(2), 4)]
+    #[case::saturates(Duration::MAX, Duration::from_nanos(1), u64::MAX)]
+    fn test_calculate_block_delay_nanos_precision(
+        #[case] delay_period_time: Duration,
+        #[case] max_expected_time_per_block: Duration,
+        #[case] expected: u64,
+    ) {
+        assert_eq!(
+            calculate_block_delay(&delay_period_time, &max_expected_time_per_block),
+            expected
+        );
+    }
 }
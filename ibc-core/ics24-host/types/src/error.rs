@@ -1,5 +1,8 @@
 use displaydoc::Display;
 use ibc_primitives::prelude::*;
+use ibc_primitives::AbciError;
+
+use crate::path::Path;
 
 #[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[derive(Debug, Display)]
@@ -20,3 +23,28 @@ pub enum IdentifierError {
 
 #[cfg(feature = "std")]
 impl std::error::Error for IdentifierError {}
+
+/// Errors raised by the host when accessing its IBC state.
+#[derive(Debug, Display)]
+pub enum HostError {
+    /// failed to decode the value stored under `{path}`: `{description}`
+    FailedToDecode { path: Path, description: String },
+    /// the state changes were committed, but their events and logs failed to be written: `{description}`
+    OutputsNotWritten { description: String },
+}
+
+impl AbciError for HostError {
+    fn codespace(&self) -> &'static str {
+        "host"
+    }
+
+    fn code(&self) -> u32 {
+        match self {
+            Self::FailedToDecode { .. } => 2,
+            Self::OutputsNotWritten { .. } => 3,
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for HostError {}
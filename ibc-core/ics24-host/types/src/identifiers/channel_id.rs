@@ -5,7 +5,10 @@ use derive_more::Into;
 use ibc_primitives::prelude::*;
 
 use crate::error::IdentifierError;
-use crate::validate::validate_channel_identifier;
+use crate::identifiers::IdentifierMode;
+use crate::validate::{
+    validate_channel_identifier, validate_channel_identifier_with_mode, validate_named_u64_index,
+};
 
 const CHANNEL_ID_PREFIX: &str = "channel";
 
@@ -27,6 +30,9 @@ const CHANNEL_ID_PREFIX: &str = "channel";
 pub struct ChannelId(String);
 
 impl ChannelId {
+    /// The maximum length of channel identifiers, as specified in ICS-24.
+    pub const MAX_LEN: usize = 64;
+
     /// Builds a new channel identifier. Like client and connection identifiers, channel ids are
     /// deterministically formed from two elements: a prefix `prefix`, and a monotonically
     /// increasing `counter`, separated by a dash "-".
@@ -48,6 +54,35 @@ impl ChannelId {
         CHANNEL_ID_PREFIX
     }
 
+    /// Parses an identifier with the given strictness, accepting identifiers
+    /// which are not of the form `channel-{counter}` in the
+    /// [`IdentifierMode::Lenient`] mode.
+    ///
+    /// Parsing with [`FromStr`] is equivalent to parsing in the
+    /// [`IdentifierMode::Exact`] mode.
+    ///
+    /// ```
+    /// # use ibc_core_host_types::identifiers::{ChannelId, IdentifierMode};
+    /// let chan_id = ChannelId::from_str_with_mode("07-tendermint-0", IdentifierMode::Lenient);
+    /// assert_eq!(chan_id.expect("valid identifier").counter(), None);
+    ///
+    /// let chan_id = ChannelId::from_str_with_mode("07-tendermint-0", IdentifierMode::Exact);
+    /// assert!(chan_id.is_err());
+    ///
+    /// assert_eq!(ChannelId::new(27).counter(), Some(27));
+    /// ```
+    pub fn from_str_with_mode(s: &str, mode: IdentifierMode) -> Result<Self, IdentifierError> {
+        validate_channel_identifier_with_mode(s, mode).map(|_| Self(s.to_string()))
+    }
+
+    /// Returns the counter of the identifier, if it is of the form
+    /// `channel-{counter}`.
+    pub fn counter(&self) -> Option<u64> {
+        validate_named_u64_index(&self.0, Self::prefix()).ok()?;
+
+        self.0[Self::prefix().len() + 1..].parse().ok()
+    }
+
     /// Get this identifier as a borrowed `&str`
     pub fn as_str(&self) -> &str {
         &self.0
@@ -3,8 +3,11 @@ use core::str::FromStr;
 use derive_more::Into;
 use ibc_primitives::prelude::*;
 
+use super::ClientType;
 use crate::error::IdentifierError;
-use crate::validate::{validate_client_identifier, validate_client_type};
+use crate::validate::{
+    validate_client_identifier, validate_client_type, validate_client_type_prefix,
+};
 
 #[cfg_attr(
     feature = "parity-scale-codec",
@@ -24,6 +27,9 @@ use crate::validate::{validate_client_identifier, validate_client_type};
 pub struct ClientId(String);
 
 impl ClientId {
+    /// The maximum length of client identifiers, as specified in ICS-24.
+    pub const MAX_LEN: usize = 64;
+
     /// Builds a new client identifier.
     ///
     /// Client identifiers are deterministically formed from two elements:
@@ -52,11 +58,46 @@ impl ClientId {
         let client_id = format!("{client_type}-{counter}");
         if cfg!(debug_assertions) {
             validate_client_type(client_type).expect("valid client type");
-            validate_client_identifier(&client_id).expect("valid client id");
+            validate_client_type_prefix(&client_id).expect("valid client id");
         }
         Self(client_id)
     }
 
+    /// Returns the client type from which this identifier was formed, failing
+    /// if it is not of the canonical `{client_type}-{counter}` format.
+    ///
+    /// ```
+    /// # use ibc_core_host_types::identifiers::ClientId;
+    /// # use std::str::FromStr;
+    /// let client_id = ClientId::from_str("07-tendermint-14").unwrap();
+    /// assert_eq!(client_id.client_type().unwrap().as_str(), "07-tendermint");
+    /// assert!(ClientId::from_str("clientidtwo").unwrap().client_type().is_err());
+    /// ```
+    pub fn client_type(&self) -> Result<ClientType, IdentifierError> {
+        self.split()
+            .and_then(|(client_type, _)| ClientType::new(client_type))
+    }
+
+    /// Returns the counter from which this identifier was formed, failing if
+    /// it is not of the canonical `{client_type}-{counter}` format.
+    pub fn counter(&self) -> Result<u64, IdentifierError> {
+        self.split().map(|(_, counter)| counter)
+    }
+
+    fn split(&self) -> Result<(&str, u64), IdentifierError> {
+        validate_client_type_prefix(self.as_str())?;
+
+        let (client_type, counter) = self
+            .0
+            .rsplit_once('-')
+            .expect("validated client id has a client type prefix");
+        let counter = counter
+            .parse()
+            .expect("validated client id has a u64 counter");
+
+        Ok((client_type, counter))
+    }
+
     /// Get this identifier as a borrowed `&str`
     pub fn as_str(&self) -> &str {
         &self.0
@@ -76,6 +117,14 @@ impl FromStr for ClientId {
     }
 }
 
+impl TryFrom<String> for ClientId {
+    type Error = IdentifierError;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        validate_client_identifier(&s).map(|_| Self(s))
+    }
+}
+
 /// Equality check against string literal (satisfies &ClientId == &str).
 /// ```
 /// use core::str::FromStr;
@@ -89,3 +138,34 @@ impl PartialEq<str> for ClientId {
         self.as_str().eq(other)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case::tendermint("07-tendermint", 0)]
+    #[case::wasm("08-wasm", 1234)]
+    #[case::dashed_client_type("10-solo-machine", 7)]
+    #[case::u64_max("07-tendermint", u64::MAX)]
+    fn client_id_round_trip(#[case] client_type: &str, #[case] counter: u64) {
+        let client_type = ClientType::from_str(client_type).unwrap();
+        let client_id = client_type.build_client_id(counter);
+
+        assert_eq!(client_id.client_type().unwrap(), client_type);
+        assert_eq!(client_id.counter().unwrap(), counter);
+    }
+
+    #[rstest]
+    #[case::non_canonical("clientidtwo")]
+    #[case::zero_padded_counter("07-tendermint-01")]
+    #[case::non_numeric_counter("07-tendermint-a")]
+    fn client_id_without_client_type(#[case] client_id: &str) {
+        let client_id = ClientId::from_str(client_id).unwrap();
+
+        assert!(client_id.client_type().is_err());
+        assert!(client_id.counter().is_err());
+    }
+}
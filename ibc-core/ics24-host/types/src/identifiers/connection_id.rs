@@ -5,7 +5,11 @@ use derive_more::Into;
 use ibc_primitives::prelude::*;
 
 use crate::error::IdentifierError;
-use crate::validate::validate_connection_identifier;
+use crate::identifiers::IdentifierMode;
+use crate::validate::{
+    validate_connection_identifier, validate_connection_identifier_with_mode,
+    validate_named_u64_index,
+};
 
 const CONNECTION_ID_PREFIX: &str = "connection";
 
@@ -27,6 +31,9 @@ const CONNECTION_ID_PREFIX: &str = "connection";
 pub struct ConnectionId(String);
 
 impl ConnectionId {
+    /// The maximum length of connection identifiers, as specified in ICS-24.
+    pub const MAX_LEN: usize = 64;
+
     /// Builds a new connection identifier. Connection identifiers are deterministically formed from
     /// two elements: a prefix `prefix`, and a monotonically increasing `counter`; these are
     /// separated by a dash "-". The prefix is currently determined statically (see
@@ -47,6 +54,24 @@ impl ConnectionId {
         CONNECTION_ID_PREFIX
     }
 
+    /// Parses an identifier with the given strictness, accepting identifiers
+    /// which are not of the form `connection-{counter}` in the
+    /// [`IdentifierMode::Lenient`] mode.
+    ///
+    /// Parsing with [`FromStr`] is equivalent to parsing in the
+    /// [`IdentifierMode::Exact`] mode.
+    pub fn from_str_with_mode(s: &str, mode: IdentifierMode) -> Result<Self, IdentifierError> {
+        validate_connection_identifier_with_mode(s, mode).map(|_| Self(s.to_string()))
+    }
+
+    /// Returns the counter of the identifier, if it is of the form
+    /// `connection-{counter}`.
+    pub fn counter(&self) -> Option<u64> {
+        validate_named_u64_index(&self.0, Self::prefix()).ok()?;
+
+        self.0[Self::prefix().len() + 1..].parse().ok()
+    }
+
     /// Get this identifier as a borrowed `&str`
     pub fn as_str(&self) -> &str {
         &self.0
@@ -5,6 +5,7 @@ mod channel_id;
 mod client_id;
 mod client_type;
 mod connection_id;
+mod mode;
 mod port_id;
 mod sequence;
 
@@ -13,5 +14,6 @@ pub use channel_id::ChannelId;
 pub use client_id::ClientId;
 pub use client_type::ClientType;
 pub use connection_id::ConnectionId;
+pub use mode::IdentifierMode;
 pub use port_id::PortId;
 pub use sequence::Sequence;
@@ -0,0 +1,16 @@
+/// The strictness with which connection and channel identifiers are parsed.
+///
+/// Identifiers generated by ibc-go and ibc-rs are of the form
+/// `{prefix}-{counter}`, which is all the exact mode accepts. The lenient mode
+/// accepts any identifier made of valid characters and of valid length, as
+/// specified by ICS-24, e.g. the aliases which counterparties supporting
+/// channel upgradability or client-based routing may use.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum IdentifierMode {
+    /// Only accepts identifiers of the form `{prefix}-{counter}`.
+    #[default]
+    Exact,
+    /// Accepts any identifier made of valid characters and of valid length.
+    Lenient,
+}
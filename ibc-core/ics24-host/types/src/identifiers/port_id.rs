@@ -27,6 +27,9 @@ const TRANSFER_PORT_ID: &str = "transfer";
 pub struct PortId(String);
 
 impl PortId {
+    /// The maximum length of port identifiers, as specified in ICS-24.
+    pub const MAX_LEN: usize = 128;
+
     pub fn new(id: String) -> Result<Self, IdentifierError> {
         Self::from_str(&id)
     }
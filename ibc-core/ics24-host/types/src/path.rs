@@ -696,6 +696,209 @@ impl Path {
     pub fn into_bytes(self) -> Vec<u8> {
         self.to_string().into_bytes()
     }
+
+    /// The maximum length, in bytes, of any path.
+    pub const MAX_LEN: usize = max_len(&[
+        NextClientSequencePath::MAX_LEN,
+        NextConnectionSequencePath::MAX_LEN,
+        NextChannelSequencePath::MAX_LEN,
+        ClientStatePath::MAX_LEN,
+        ClientConsensusStatePath::MAX_LEN,
+        ClientUpdateTimePath::MAX_LEN,
+        ClientUpdateHeightPath::MAX_LEN,
+        ClientConnectionPath::MAX_LEN,
+        ConnectionPath::MAX_LEN,
+        PortPath::MAX_LEN,
+        ChannelEndPath::MAX_LEN,
+        SeqSendPath::MAX_LEN,
+        SeqRecvPath::MAX_LEN,
+        SeqAckPath::MAX_LEN,
+        CommitmentPath::MAX_LEN,
+        AckPath::MAX_LEN,
+        ReceiptPath::MAX_LEN,
+        UpgradeClientPath::MAX_LEN,
+    ]);
+
+    /// Returns the maximum length, in bytes, of the paths of the same kind as
+    /// this one, whatever their identifiers, sequences and heights.
+    pub fn max_len(&self) -> usize {
+        match self {
+            Path::NextClientSequence(_) => NextClientSequencePath::MAX_LEN,
+            Path::NextConnectionSequence(_) => NextConnectionSequencePath::MAX_LEN,
+            Path::NextChannelSequence(_) => NextChannelSequencePath::MAX_LEN,
+            Path::ClientState(_) => ClientStatePath::MAX_LEN,
+            Path::ClientConsensusState(_) => ClientConsensusStatePath::MAX_LEN,
+            Path::ClientUpdateTime(_) => ClientUpdateTimePath::MAX_LEN,
+            Path::ClientUpdateHeight(_) => ClientUpdateHeightPath::MAX_LEN,
+            Path::ClientConnection(_) => ClientConnectionPath::MAX_LEN,
+            Path::Connection(_) => ConnectionPath::MAX_LEN,
+            Path::Ports(_) => PortPath::MAX_LEN,
+            Path::ChannelEnd(_) => ChannelEndPath::MAX_LEN,
+            Path::SeqSend(_) => SeqSendPath::MAX_LEN,
+            Path::SeqRecv(_) => SeqRecvPath::MAX_LEN,
+            Path::SeqAck(_) => SeqAckPath::MAX_LEN,
+            Path::Commitment(_) => CommitmentPath::MAX_LEN,
+            Path::Ack(_) => AckPath::MAX_LEN,
+            Path::Receipt(_) => ReceiptPath::MAX_LEN,
+            Path::UpgradeClient(_) => UpgradeClientPath::MAX_LEN,
+        }
+    }
+}
+
+// The maximum lengths, in bytes, of the paths of each kind, as needed by hosts
+// whose store requires the length of keys to be bounded, such as sparse
+// Merkle trees of a fixed depth.
+//
+// The bounds follow from the maximum lengths of the identifiers, and from the
+// 20 digits of `u64::MAX` for sequences and heights. Note that, with the
+// longest identifiers allowed by ICS-24, the paths of channel ends, sequences
+// and packets exceed 128 bytes, and so do the paths under consensus states and
+// the port paths. Hosts which only open channels with the identifiers they
+// generate, such as `channel-{u64}`, get shorter paths in practice.
+
+/// The maximum length of a `u64` formatted in decimal, as in paths.
+const MAX_U64_LEN: usize = 20;
+
+/// The maximum length of a height formatted in a path, as
+/// `{revision_number}-{revision_height}`.
+const MAX_HEIGHT_LEN: usize = 2 * MAX_U64_LEN + 1;
+
+/// Returns the greatest of the given lengths.
+const fn max_len(lens: &[usize]) -> usize {
+    let mut max = 0;
+    let mut i = 0;
+    while i < lens.len() {
+        if lens[i] > max {
+            max = lens[i];
+        }
+        i += 1;
+    }
+    max
+}
+
+/// Returns the maximum length of a path of the form
+/// `{prefix}/ports/{port_id}/channels/{channel_id}`.
+const fn max_channel_path_len(prefix: &str) -> usize {
+    prefix.len()
+        + 1
+        + PORT_PREFIX.len()
+        + 1
+        + PortId::MAX_LEN
+        + 1
+        + CHANNEL_PREFIX.len()
+        + 1
+        + ChannelId::MAX_LEN
+}
+
+/// Returns the maximum length of a path of the form
+/// `{prefix}/ports/{port_id}/channels/{channel_id}/sequences/{sequence}`.
+const fn max_packet_path_len(prefix: &str) -> usize {
+    max_channel_path_len(prefix) + 1 + SEQUENCE_PREFIX.len() + 1 + MAX_U64_LEN
+}
+
+impl NextClientSequencePath {
+    /// The maximum length, in bytes, of the path.
+    pub const MAX_LEN: usize = NEXT_CLIENT_SEQUENCE.len();
+}
+
+impl NextConnectionSequencePath {
+    /// The maximum length, in bytes, of the path.
+    pub const MAX_LEN: usize = NEXT_CONNECTION_SEQUENCE.len();
+}
+
+impl NextChannelSequencePath {
+    /// The maximum length, in bytes, of the path.
+    pub const MAX_LEN: usize = NEXT_CHANNEL_SEQUENCE.len();
+}
+
+impl ClientStatePath {
+    /// The maximum length, in bytes, of the path.
+    pub const MAX_LEN: usize = CLIENT_PREFIX.len() + 1 + ClientId::MAX_LEN + 1 + CLIENT_STATE.len();
+}
+
+impl ClientConsensusStatePath {
+    /// The maximum length, in bytes, of the path.
+    pub const MAX_LEN: usize = CLIENT_PREFIX.len()
+        + 1
+        + ClientId::MAX_LEN
+        + 1
+        + CONSENSUS_STATE_PREFIX.len()
+        + 1
+        + MAX_HEIGHT_LEN;
+}
+
+impl ClientUpdateTimePath {
+    /// The maximum length, in bytes, of the path.
+    pub const MAX_LEN: usize = ClientConsensusStatePath::MAX_LEN + 1 + PROCESSED_TIME.len();
+}
+
+impl ClientUpdateHeightPath {
+    /// The maximum length, in bytes, of the path.
+    pub const MAX_LEN: usize = ClientConsensusStatePath::MAX_LEN + 1 + PROCESSED_HEIGHT.len();
+}
+
+impl ClientConnectionPath {
+    /// The maximum length, in bytes, of the path.
+    pub const MAX_LEN: usize =
+        CLIENT_PREFIX.len() + 1 + ClientId::MAX_LEN + 1 + CONNECTION_PREFIX.len();
+}
+
+impl ConnectionPath {
+    /// The maximum length, in bytes, of the path.
+    pub const MAX_LEN: usize = CONNECTION_PREFIX.len() + 1 + ConnectionId::MAX_LEN;
+}
+
+impl PortPath {
+    /// The maximum length, in bytes, of the path.
+    pub const MAX_LEN: usize = PORT_PREFIX.len() + 1 + PortId::MAX_LEN;
+}
+
+impl ChannelEndPath {
+    /// The maximum length, in bytes, of the path.
+    pub const MAX_LEN: usize = max_channel_path_len(CHANNEL_END_PREFIX);
+}
+
+impl SeqSendPath {
+    /// The maximum length, in bytes, of the path.
+    pub const MAX_LEN: usize = max_channel_path_len(NEXT_SEQ_SEND_PREFIX);
+}
+
+impl SeqRecvPath {
+    /// The maximum length, in bytes, of the path.
+    pub const MAX_LEN: usize = max_channel_path_len(NEXT_SEQ_RECV_PREFIX);
+}
+
+impl SeqAckPath {
+    /// The maximum length, in bytes, of the path.
+    pub const MAX_LEN: usize = max_channel_path_len(NEXT_SEQ_ACK_PREFIX);
+}
+
+impl CommitmentPath {
+    /// The maximum length, in bytes, of the path.
+    pub const MAX_LEN: usize = max_packet_path_len(PACKET_COMMITMENT_PREFIX);
+}
+
+impl AckPath {
+    /// The maximum length, in bytes, of the path.
+    pub const MAX_LEN: usize = max_packet_path_len(PACKET_ACK_PREFIX);
+}
+
+impl ReceiptPath {
+    /// The maximum length, in bytes, of the path.
+    pub const MAX_LEN: usize = max_packet_path_len(PACKET_RECEIPT_PREFIX);
+}
+
+impl UpgradeClientPath {
+    /// The maximum length, in bytes, of the paths of both upgraded client
+    /// states and consensus states.
+    pub const MAX_LEN: usize = UPGRADED_IBC_STATE.len()
+        + 1
+        + MAX_U64_LEN
+        + 1
+        + max_len(&[
+            UPGRADED_CLIENT_STATE.len(),
+            UPGRADED_CLIENT_CONSENSUS_STATE.len(),
+        ]);
 }
 
 #[derive(Debug, displaydoc::Display)]
@@ -1192,6 +1395,67 @@ mod tests {
         assert!(Path::from_str(path_str).is_err());
     }
 
+    #[test]
+    fn test_max_path_lengths() {
+        use crate::identifiers::IdentifierMode;
+
+        let client_id = ClientId::from_str(&"a".repeat(ClientId::MAX_LEN)).expect("no error");
+        let connection_id = ConnectionId::from_str_with_mode(
+            &"a".repeat(ConnectionId::MAX_LEN),
+            IdentifierMode::Lenient,
+        )
+        .expect("no error");
+        let port_id = PortId::new("a".repeat(PortId::MAX_LEN)).expect("no error");
+        let channel_id =
+            ChannelId::from_str_with_mode(&"a".repeat(ChannelId::MAX_LEN), IdentifierMode::Lenient)
+                .expect("no error");
+        let sequence = Sequence::from(u64::MAX);
+
+        let longest_paths: Vec<Path> = vec![
+            NextClientSequencePath.into(),
+            NextConnectionSequencePath.into(),
+            NextChannelSequencePath.into(),
+            ClientStatePath::new(client_id.clone()).into(),
+            ClientConsensusStatePath::new(client_id.clone(), u64::MAX, u64::MAX).into(),
+            ClientUpdateTimePath::new(client_id.clone(), u64::MAX, u64::MAX).into(),
+            ClientUpdateHeightPath::new(client_id.clone(), u64::MAX, u64::MAX).into(),
+            ClientConnectionPath::new(client_id).into(),
+            ConnectionPath::new(&connection_id).into(),
+            PortPath(port_id.clone()).into(),
+            ChannelEndPath::new(&port_id, &channel_id).into(),
+            SeqSendPath::new(&port_id, &channel_id).into(),
+            SeqRecvPath::new(&port_id, &channel_id).into(),
+            SeqAckPath::new(&port_id, &channel_id).into(),
+            CommitmentPath::new(&port_id, &channel_id, sequence).into(),
+            AckPath::new(&port_id, &channel_id, sequence).into(),
+            ReceiptPath::new(&port_id, &channel_id, sequence).into(),
+            UpgradeClientPath::UpgradedClientState(u64::MAX).into(),
+            UpgradeClientPath::UpgradedClientConsensusState(u64::MAX).into(),
+        ];
+
+        for path in longest_paths {
+            let len = path.to_string().len();
+
+            assert!(len <= Path::MAX_LEN);
+
+            // The bounds are tight, except for the upgraded client states,
+            // whose leaf is shorter than the one of upgraded consensus states.
+            if matches!(
+                path,
+                Path::UpgradeClient(UpgradeClientPath::UpgradedClientState(_))
+            ) {
+                assert!(len < path.max_len(), "{path}");
+            } else {
+                assert_eq!(len, path.max_len(), "{path}");
+            }
+        }
+
+        assert_eq!(Path::MAX_LEN, CommitmentPath::MAX_LEN);
+        assert_eq!(Path::MAX_LEN, 251);
+        assert_eq!(ClientStatePath::MAX_LEN, 84);
+        assert_eq!(ConnectionPath::MAX_LEN, 76);
+    }
+
     #[test]
     fn test_parse_client_paths_fn() {
         let path = "clients/07-tendermint-0/clientState";
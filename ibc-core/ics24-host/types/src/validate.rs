@@ -1,7 +1,7 @@
 use ibc_primitives::prelude::*;
 
 use crate::error::IdentifierError as Error;
-use crate::identifiers::{ChannelId, ConnectionId};
+use crate::identifiers::{ChannelId, ClientId, ConnectionId, IdentifierMode, PortId};
 
 const VALID_SPECIAL_CHARS: &str = "._+-#[]<>";
 
@@ -10,12 +10,13 @@ const VALID_SPECIAL_CHARS: &str = "._+-#[]<>";
 /// spec.
 pub fn validate_identifier_chars(id: &str) -> Result<(), Error> {
     // Check that the identifier comprises only valid characters:
-    // - Alphanumeric
+    // - ASCII alphanumeric, as ibc-go does, since any other alphanumeric
+    //   character would be rejected by its counterparties
     // - `.`, `_`, `+`, `-`, `#`
     // - `[`, `]`, `<`, `>`
     if !id
         .chars()
-        .all(|c| c.is_alphanumeric() || VALID_SPECIAL_CHARS.contains(c))
+        .all(|c| c.is_ascii_alphanumeric() || VALID_SPECIAL_CHARS.contains(c))
     {
         return Err(Error::InvalidCharacter { id: id.into() });
     }
@@ -85,7 +86,7 @@ pub fn validate_named_u64_index(id: &str, name: &str) -> Result<(), Error> {
 /// Default validator function for the Client types.
 pub fn validate_client_type(id: &str) -> Result<(), Error> {
     validate_identifier_chars(id)?;
-    validate_prefix_length(id, 9, 64)
+    validate_prefix_length(id, 9, ClientId::MAX_LEN as u64)
 }
 
 /// Default validator function for Client identifiers.
@@ -94,7 +95,25 @@ pub fn validate_client_type(id: &str) -> Result<(), Error> {
 /// the ICS-24 spec.
 pub fn validate_client_identifier(id: &str) -> Result<(), Error> {
     validate_identifier_chars(id)?;
-    validate_identifier_length(id, 9, 64)
+    validate_identifier_length(id, 9, ClientId::MAX_LEN as u64)
+}
+
+/// Checks if the client identifier is of the canonical `{client_type}-{u64}`
+/// format, as the ones generated upon client creation, with a valid client
+/// type.
+///
+/// Client identifiers are only required to be valid ICS-24 identifiers, so
+/// that e.g. `clientidtwo` is a valid client identifier, yet not a canonical
+/// one.
+pub fn validate_client_type_prefix(id: &str) -> Result<(), Error> {
+    validate_client_identifier(id)?;
+
+    let (client_type, _) = id
+        .rsplit_once('-')
+        .ok_or_else(|| Error::InvalidPrefix { prefix: id.into() })?;
+
+    validate_client_type(client_type)?;
+    validate_named_u64_index(id, client_type)
 }
 
 /// Default validator function for Connection identifiers.
@@ -102,9 +121,20 @@ pub fn validate_client_identifier(id: &str) -> Result<(), Error> {
 /// A valid connection identifier must be between 10-64 characters as specified
 /// in the ICS-24 spec.
 pub fn validate_connection_identifier(id: &str) -> Result<(), Error> {
+    validate_connection_identifier_with_mode(id, IdentifierMode::Exact)
+}
+
+/// Validator function for Connection identifiers, only checking that they are
+/// of the form `connection-{u64}` in the [`IdentifierMode::Exact`] mode.
+pub fn validate_connection_identifier_with_mode(
+    id: &str,
+    mode: IdentifierMode,
+) -> Result<(), Error> {
     validate_identifier_chars(id)?;
-    validate_identifier_length(id, 10, 64)?;
-    validate_named_u64_index(id, ConnectionId::prefix())?;
+    validate_identifier_length(id, 10, ConnectionId::MAX_LEN as u64)?;
+    if mode == IdentifierMode::Exact {
+        validate_named_u64_index(id, ConnectionId::prefix())?;
+    }
     Ok(())
 }
 
@@ -114,7 +144,7 @@ pub fn validate_connection_identifier(id: &str) -> Result<(), Error> {
 /// ICS-24 spec.
 pub fn validate_port_identifier(id: &str) -> Result<(), Error> {
     validate_identifier_chars(id)?;
-    validate_identifier_length(id, 2, 128)
+    validate_identifier_length(id, 2, PortId::MAX_LEN as u64)
 }
 
 /// Default validator function for Channel identifiers.
@@ -122,9 +152,17 @@ pub fn validate_port_identifier(id: &str) -> Result<(), Error> {
 /// A valid channel identifier must be between 8-64 characters as specified in
 /// the ICS-24 spec.
 pub fn validate_channel_identifier(id: &str) -> Result<(), Error> {
+    validate_channel_identifier_with_mode(id, IdentifierMode::Exact)
+}
+
+/// Validator function for Channel identifiers, only checking that they are of
+/// the form `channel-{u64}` in the [`IdentifierMode::Exact`] mode.
+pub fn validate_channel_identifier_with_mode(id: &str, mode: IdentifierMode) -> Result<(), Error> {
     validate_identifier_chars(id)?;
-    validate_identifier_length(id, 8, 64)?;
-    validate_named_u64_index(id, ChannelId::prefix())?;
+    validate_identifier_length(id, 8, ChannelId::MAX_LEN as u64)?;
+    if mode == IdentifierMode::Exact {
+        validate_named_u64_index(id, ChannelId::prefix())?;
+    }
     Ok(())
 }
 
@@ -218,6 +256,38 @@ mod tests {
         validate_channel_identifier("channel-18446744073709551616").expect_err("failure");
     }
 
+    #[rstest]
+    #[case::canonical("channel-7", true, true)]
+    #[case::client_id("07-tendermint-0", false, true)]
+    #[case::alias("channel-to-osmosis", false, true)]
+    #[case::too_short("chan-0", false, false)]
+    #[case::invalid_chars("channel/0", false, false)]
+    fn parse_channel_id_with_mode(#[case] id: &str, #[case] exact: bool, #[case] lenient: bool) {
+        assert_eq!(
+            validate_channel_identifier_with_mode(id, IdentifierMode::Exact).is_ok(),
+            exact
+        );
+        assert_eq!(
+            validate_channel_identifier_with_mode(id, IdentifierMode::Lenient).is_ok(),
+            lenient
+        );
+    }
+
+    #[rstest]
+    #[case::canonical("connection-7", true, true)]
+    #[case::alias("connection-to-osmosis", false, true)]
+    #[case::too_short("conn-0", false, false)]
+    fn parse_connection_id_with_mode(#[case] id: &str, #[case] exact: bool, #[case] lenient: bool) {
+        assert_eq!(
+            validate_connection_identifier_with_mode(id, IdentifierMode::Exact).is_ok(),
+            exact
+        );
+        assert_eq!(
+            validate_connection_identifier_with_mode(id, IdentifierMode::Lenient).is_ok(),
+            lenient
+        );
+    }
+
     #[test]
     fn parse_invalid_client_id_min() {
         // invalid min client id
@@ -266,6 +336,22 @@ mod tests {
         assert!(id.is_err())
     }
 
+    #[rstest]
+    #[case::tendermint("07-tendermint-0", true)]
+    #[case::wasm("08-wasm-1234", true)]
+    #[case::dashed_client_type("10-solo-machine-7", true)]
+    #[case::u64_max("07-tendermint-18446744073709551615", true)]
+    #[case::non_canonical("clientidtwo", false)]
+    #[case::zero_padded_counter("07-tendermint-01", false)]
+    #[case::non_numeric_counter("07-tendermint-a", false)]
+    #[case::empty_counter("07-tendermint-", false)]
+    #[case::short_client_type("short-123", false)]
+    #[case::u64_max_plus_1("07-tendermint-18446744073709551616", false)]
+    fn test_client_type_prefix_validation(#[case] id: &str, #[case] success: bool) {
+        let result = validate_client_type_prefix(id);
+        assert_eq!(result.is_ok(), success, "{id}: {result:?}");
+    }
+
     #[test]
     fn parse_healthy_client_type() {
         let id = validate_client_type("07-tendermint");
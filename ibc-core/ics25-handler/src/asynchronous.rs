@@ -0,0 +1,134 @@
+//! Defines the entry point for hosts whose IBC state is stored in an
+//! [`AsyncIbcStore`], available behind the `async` feature.
+//!
+//! The message is run against a [`PrefetchStore`], fetching the paths read by
+//! the handler from the asynchronous store between runs, until the handler
+//! reads no missing path. Since the paths read on execution may differ from
+//! those read on validation, the writes of each execution are buffered, and
+//! only applied once it read no missing path. They are then flushed to the
+//! asynchronous store.
+//!
+//! Client and consensus states, along with the state of the applications, are
+//! accessed synchronously, through a [`ClientStore`] and the [`Router`]
+//! respectively, as with a [`StoreContext`].
+//!
+//! No asynchronous variants of [`ValidationContext`] and [`ExecutionContext`]
+//! are provided, as they would require asynchronous counterparts of every
+//! handler and light client. Hosts instead implement the asynchronous store,
+//! keyed by path, against which the synchronous handlers are run again until
+//! they read no missing path.
+//!
+//! [`ValidationContext`]: ibc_core_host::ValidationContext
+//! [`ExecutionContext`]: ibc_core_host::ExecutionContext
+
+use ibc_core_client::types::error::ClientError;
+use ibc_core_handler_types::error::ContextError;
+use ibc_core_handler_types::msgs::MsgEnvelope;
+use ibc_core_host::async_store::{AsyncIbcStore, PrefetchStore};
+use ibc_core_host::store::{ClientStore, ClientWriteBatch, StoreContext, StoreHost, WriteBatch};
+use ibc_core_host::types::error::HostError;
+use ibc_core_host::types::path::{
+    NextChannelSequencePath, NextClientSequencePath, NextConnectionSequencePath,
+};
+use ibc_core_router::router::Router;
+use ibc_primitives::prelude::*;
+use ibc_primitives::proto::Any;
+
+use crate::buffer::BufferedHost;
+use crate::entrypoint::{execute, validate};
+
+/// Entrypoint which performs both validation and message execution against
+/// the IBC state stored in `store`, and the client states stored in
+/// `clients`, which are handed back along with the result.
+///
+/// Validation and execution are run again whenever they read a missing path,
+/// once it is fetched. Nothing is written until a run reads no missing path:
+/// the writes to the IBC and client states and the outputs of the host are
+/// buffered, and each execution runs against a clone of `router`, which only
+/// replaces it once the execution read no missing path. The clones must
+/// hence not share the state of the applications, which would otherwise be
+/// written to on every execution.
+///
+/// The writes are dropped on error, in which case the clients are handed back
+/// untouched. Writing the events and logs to `host` may only fail once the
+/// writes are committed, in which case they are still flushed, and a
+/// [`HostError::OutputsNotWritten`] is returned.
+pub async fn dispatch_async<S, H, R>(
+    store: &mut S,
+    mut clients: H::Clients,
+    host: &mut H,
+    router: &mut R,
+    msg: MsgEnvelope,
+) -> (H::Clients, Result<(), ContextError>)
+where
+    S: AsyncIbcStore + ?Sized,
+    H: StoreHost,
+    R: Router + Clone,
+    <<H::Clients as ClientStore>::ClientState as TryFrom<Any>>::Error: Into<ClientError>,
+    <H::HostClientState as TryFrom<Any>>::Error: Into<ClientError>,
+{
+    let mut snapshot = PrefetchStore::new();
+    // The identifier counters are only read on execution by some handlers,
+    // hence they are fetched beforehand to spare a run.
+    snapshot.prefetch(NextClientSequencePath.into());
+    snapshot.prefetch(NextConnectionSequencePath.into());
+    snapshot.prefetch(NextChannelSequencePath.into());
+
+    loop {
+        snapshot.fetch_missing(store).await;
+
+        // Validation does not write, hence the batches are simply dropped.
+        let ctx = StoreContext::new(
+            WriteBatch::new(&mut snapshot),
+            ClientWriteBatch::new(clients),
+            BufferedHost::new(host),
+        );
+        let result = validate(&ctx, &*router, msg.clone());
+        let (_, client_batch, _) = ctx.into_parts();
+        clients = client_batch.discard();
+
+        if snapshot.has_missing() {
+            continue;
+        }
+        if let Err(e) = result {
+            return (clients, Err(e));
+        }
+
+        let mut branch = router.clone();
+        let mut ctx = StoreContext::new(
+            WriteBatch::new(&mut snapshot),
+            ClientWriteBatch::new(clients),
+            BufferedHost::new(host),
+        );
+        let result = execute(&mut ctx, &mut branch, msg.clone());
+        let (batch, client_batch, buffered_host) = ctx.into_parts();
+
+        if batch.store().has_missing() {
+            batch.discard();
+            clients = client_batch.discard();
+            continue;
+        }
+
+        match result {
+            Ok(()) => {
+                batch.commit();
+                clients = client_batch.commit();
+                *router = branch;
+                let outputs = buffered_host.commit().map_err(|e| {
+                    HostError::OutputsNotWritten {
+                        description: e.to_string(),
+                    }
+                    .into()
+                });
+
+                snapshot.flush(store).await;
+
+                return (clients, outputs);
+            }
+            Err(e) => {
+                batch.discard();
+                return (client_batch.discard(), Err(e));
+            }
+        }
+    }
+}
@@ -0,0 +1,489 @@
+//! Defines the execution context and store host adapters that withhold the
+//! events and logs emitted by handlers until their execution succeeds.
+
+use core::time::Duration;
+
+use ibc_core_channel::types::channel::{ChannelEnd, Counterparty};
+use ibc_core_channel::types::commitment::{AcknowledgementCommitment, PacketCommitment};
+use ibc_core_channel::types::packet::Receipt;
+use ibc_core_client::types::Height;
+use ibc_core_commitment_types::commitment::CommitmentPrefix;
+use ibc_core_connection::types::version::Version as ConnectionVersion;
+use ibc_core_connection::types::ConnectionEnd;
+use ibc_core_handler_types::error::ContextError;
+use ibc_core_handler_types::events::IbcEvent;
+use ibc_core_host::store::StoreHost;
+use ibc_core_host::types::identifiers::{ConnectionId, IdentifierMode, PortId, Sequence};
+use ibc_core_host::types::path::{
+    AckPath, ChannelEndPath, ClientConnectionPath, CommitmentPath, ConnectionPath, ReceiptPath,
+    SeqAckPath, SeqRecvPath, SeqSendPath,
+};
+use ibc_core_host::{AllowedClients, ExecutionContext, HostParams, ValidationContext};
+use ibc_primitives::prelude::*;
+use ibc_primitives::{Signer, Timestamp};
+
+/// An event or a log message, in the order it was emitted.
+enum Output {
+    Event(IbcEvent),
+    Log(String),
+}
+
+/// Wraps an [`ExecutionContext`], delegating all methods to it except for
+/// [`ExecutionContext::emit_ibc_event`] and [`ExecutionContext::log_message`],
+/// whose outputs are buffered.
+///
+/// The buffered outputs are only forwarded to the wrapped context once
+/// [`commit`](Self::commit) is called, and are discarded otherwise, so that a
+/// failed handler does not leak the events and logs written before it failed.
+pub(crate) struct BufferedContext<'a, Ctx> {
+    inner: &'a mut Ctx,
+    outputs: Vec<Output>,
+}
+
+impl<'a, Ctx> BufferedContext<'a, Ctx>
+where
+    Ctx: ExecutionContext,
+{
+    /// Begins buffering the events and logs emitted through `inner`.
+    pub(crate) fn begin(inner: &'a mut Ctx) -> Self {
+        Self {
+            inner,
+            outputs: Vec::new(),
+        }
+    }
+
+    /// Forwards the buffered events and logs to the wrapped context, in the
+    /// order they were emitted.
+    pub(crate) fn commit(self) -> Result<(), ContextError> {
+        for output in self.outputs {
+            match output {
+                Output::Event(event) => self.inner.emit_ibc_event(event)?,
+                Output::Log(message) => self.inner.log_message(message)?,
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Discards the buffered events and logs.
+    pub(crate) fn rollback(self) {}
+}
+
+impl<Ctx> HostParams for BufferedContext<'_, Ctx>
+where
+    Ctx: HostParams,
+{
+    fn max_expected_time_per_block(&self) -> Duration {
+        self.inner.max_expected_time_per_block()
+    }
+
+    fn allowed_clients(&self) -> AllowedClients {
+        self.inner.allowed_clients()
+    }
+
+    fn upgrade_plan_height(&self) -> Option<Height> {
+        self.inner.upgrade_plan_height()
+    }
+
+    fn max_callback_gas(&self) -> Option<u64> {
+        self.inner.max_callback_gas()
+    }
+
+    fn counterparty_identifier_mode(&self) -> IdentifierMode {
+        self.inner.counterparty_identifier_mode()
+    }
+}
+
+impl<Ctx> ValidationContext for BufferedContext<'_, Ctx>
+where
+    Ctx: ValidationContext,
+{
+    type V = Ctx::V;
+    type HostClientState = Ctx::HostClientState;
+    type HostConsensusState = Ctx::HostConsensusState;
+
+    fn get_client_validation_context(&self) -> &Self::V {
+        self.inner.get_client_validation_context()
+    }
+
+    fn host_height(&self) -> Result<Height, ContextError> {
+        self.inner.host_height()
+    }
+
+    fn host_timestamp(&self) -> Result<Timestamp, ContextError> {
+        self.inner.host_timestamp()
+    }
+
+    fn host_consensus_state(
+        &self,
+        height: &Height,
+    ) -> Result<Self::HostConsensusState, ContextError> {
+        self.inner.host_consensus_state(height)
+    }
+
+    fn client_counter(&self) -> Result<u64, ContextError> {
+        self.inner.client_counter()
+    }
+
+    fn connection_end(&self, conn_id: &ConnectionId) -> Result<ConnectionEnd, ContextError> {
+        self.inner.connection_end(conn_id)
+    }
+
+    fn validate_self_client(
+        &self,
+        client_state_of_host_on_counterparty: Self::HostClientState,
+    ) -> Result<(), ContextError> {
+        self.inner
+            .validate_self_client(client_state_of_host_on_counterparty)
+    }
+
+    fn commitment_prefix(&self) -> CommitmentPrefix {
+        self.inner.commitment_prefix()
+    }
+
+    fn connection_counter(&self) -> Result<u64, ContextError> {
+        self.inner.connection_counter()
+    }
+
+    fn get_compatible_versions(&self) -> Vec<ConnectionVersion> {
+        self.inner.get_compatible_versions()
+    }
+
+    fn pick_version(
+        &self,
+        counterparty_candidate_versions: &[ConnectionVersion],
+    ) -> Result<ConnectionVersion, ContextError> {
+        self.inner.pick_version(counterparty_candidate_versions)
+    }
+
+    fn channel_end(&self, channel_end_path: &ChannelEndPath) -> Result<ChannelEnd, ContextError> {
+        self.inner.channel_end(channel_end_path)
+    }
+
+    fn get_next_sequence_send(
+        &self,
+        seq_send_path: &SeqSendPath,
+    ) -> Result<Sequence, ContextError> {
+        self.inner.get_next_sequence_send(seq_send_path)
+    }
+
+    fn get_next_sequence_recv(
+        &self,
+        seq_recv_path: &SeqRecvPath,
+    ) -> Result<Sequence, ContextError> {
+        self.inner.get_next_sequence_recv(seq_recv_path)
+    }
+
+    fn get_next_sequence_ack(&self, seq_ack_path: &SeqAckPath) -> Result<Sequence, ContextError> {
+        self.inner.get_next_sequence_ack(seq_ack_path)
+    }
+
+    fn get_packet_commitment(
+        &self,
+        commitment_path: &CommitmentPath,
+    ) -> Result<PacketCommitment, ContextError> {
+        self.inner.get_packet_commitment(commitment_path)
+    }
+
+    fn get_packet_receipt(&self, receipt_path: &ReceiptPath) -> Result<Receipt, ContextError> {
+        self.inner.get_packet_receipt(receipt_path)
+    }
+
+    fn get_packet_acknowledgement(
+        &self,
+        ack_path: &AckPath,
+    ) -> Result<AcknowledgementCommitment, ContextError> {
+        self.inner.get_packet_acknowledgement(ack_path)
+    }
+
+    fn packet_acknowledgement_sequences(
+        &self,
+        channel_end_path: &ChannelEndPath,
+    ) -> Result<Vec<Sequence>, ContextError> {
+        self.inner
+            .packet_acknowledgement_sequences(channel_end_path)
+    }
+
+    fn channel_counter(&self) -> Result<u64, ContextError> {
+        self.inner.channel_counter()
+    }
+
+    fn is_channel_frozen(&self, channel_end_path: &ChannelEndPath) -> Result<bool, ContextError> {
+        self.inner.is_channel_frozen(channel_end_path)
+    }
+
+    fn authorize_chan_open_init(
+        &self,
+        signer: &Signer,
+        port_id: &PortId,
+        connection_hops: &[ConnectionId],
+        counterparty: &Counterparty,
+    ) -> Result<(), ContextError> {
+        self.inner
+            .authorize_chan_open_init(signer, port_id, connection_hops, counterparty)
+    }
+
+    fn block_delay(&self, delay_period_time: &Duration) -> u64 {
+        self.inner.block_delay(delay_period_time)
+    }
+
+    fn validate_message_signer(&self, signer: &Signer) -> Result<(), ContextError> {
+        self.inner.validate_message_signer(signer)
+    }
+}
+
+impl<Ctx> ExecutionContext for BufferedContext<'_, Ctx>
+where
+    Ctx: ExecutionContext,
+{
+    type E = Ctx::E;
+
+    fn get_client_execution_context(&mut self) -> &mut Self::E {
+        self.inner.get_client_execution_context()
+    }
+
+    fn increase_client_counter(&mut self) -> Result<(), ContextError> {
+        self.inner.increase_client_counter()
+    }
+
+    fn store_connection(
+        &mut self,
+        connection_path: &ConnectionPath,
+        connection_end: ConnectionEnd,
+    ) -> Result<(), ContextError> {
+        self.inner.store_connection(connection_path, connection_end)
+    }
+
+    fn store_connection_to_client(
+        &mut self,
+        client_connection_path: &ClientConnectionPath,
+        conn_id: ConnectionId,
+    ) -> Result<(), ContextError> {
+        self.inner
+            .store_connection_to_client(client_connection_path, conn_id)
+    }
+
+    fn increase_connection_counter(&mut self) -> Result<(), ContextError> {
+        self.inner.increase_connection_counter()
+    }
+
+    fn store_packet_commitment(
+        &mut self,
+        commitment_path: &CommitmentPath,
+        commitment: PacketCommitment,
+    ) -> Result<(), ContextError> {
+        self.inner
+            .store_packet_commitment(commitment_path, commitment)
+    }
+
+    fn delete_packet_commitment(
+        &mut self,
+        commitment_path: &CommitmentPath,
+    ) -> Result<(), ContextError> {
+        self.inner.delete_packet_commitment(commitment_path)
+    }
+
+    fn store_packet_receipt(
+        &mut self,
+        receipt_path: &ReceiptPath,
+        receipt: Receipt,
+    ) -> Result<(), ContextError> {
+        self.inner.store_packet_receipt(receipt_path, receipt)
+    }
+
+    fn store_packet_acknowledgement(
+        &mut self,
+        ack_path: &AckPath,
+        ack_commitment: AcknowledgementCommitment,
+    ) -> Result<(), ContextError> {
+        self.inner
+            .store_packet_acknowledgement(ack_path, ack_commitment)
+    }
+
+    fn delete_packet_acknowledgement(&mut self, ack_path: &AckPath) -> Result<(), ContextError> {
+        self.inner.delete_packet_acknowledgement(ack_path)
+    }
+
+    fn store_channel(
+        &mut self,
+        channel_end_path: &ChannelEndPath,
+        channel_end: ChannelEnd,
+    ) -> Result<(), ContextError> {
+        self.inner.store_channel(channel_end_path, channel_end)
+    }
+
+    fn store_next_sequence_send(
+        &mut self,
+        seq_send_path: &SeqSendPath,
+        seq: Sequence,
+    ) -> Result<(), ContextError> {
+        self.inner.store_next_sequence_send(seq_send_path, seq)
+    }
+
+    fn store_next_sequence_recv(
+        &mut self,
+        seq_recv_path: &SeqRecvPath,
+        seq: Sequence,
+    ) -> Result<(), ContextError> {
+        self.inner.store_next_sequence_recv(seq_recv_path, seq)
+    }
+
+    fn store_next_sequence_ack(
+        &mut self,
+        seq_ack_path: &SeqAckPath,
+        seq: Sequence,
+    ) -> Result<(), ContextError> {
+        self.inner.store_next_sequence_ack(seq_ack_path, seq)
+    }
+
+    fn increase_channel_counter(&mut self) -> Result<(), ContextError> {
+        self.inner.increase_channel_counter()
+    }
+
+    fn store_channel_frozen(
+        &mut self,
+        channel_end_path: &ChannelEndPath,
+        frozen: bool,
+    ) -> Result<(), ContextError> {
+        self.inner.store_channel_frozen(channel_end_path, frozen)
+    }
+
+    fn emit_ibc_event(&mut self, event: IbcEvent) -> Result<(), ContextError> {
+        self.outputs.push(Output::Event(event));
+        Ok(())
+    }
+
+    fn log_message(&mut self, message: String) -> Result<(), ContextError> {
+        self.outputs.push(Output::Log(message));
+        Ok(())
+    }
+}
+
+/// Wraps a [`StoreHost`], delegating all methods to it except for
+/// [`StoreHost::emit_ibc_event`] and [`StoreHost::log_message`], whose
+/// outputs are buffered until [`commit`](Self::commit) is called.
+pub(crate) struct BufferedHost<'a, H> {
+    inner: &'a mut H,
+    outputs: Vec<Output>,
+}
+
+impl<'a, H> BufferedHost<'a, H>
+where
+    H: StoreHost,
+{
+    pub(crate) fn new(inner: &'a mut H) -> Self {
+        Self {
+            inner,
+            outputs: Vec::new(),
+        }
+    }
+
+    /// Forwards the buffered events and logs to the wrapped host, in the
+    /// order they were emitted.
+    #[cfg(feature = "async")]
+    pub(crate) fn commit(self) -> Result<(), ContextError> {
+        for output in self.outputs {
+            match output {
+                Output::Event(event) => self.inner.emit_ibc_event(event)?,
+                Output::Log(message) => self.inner.log_message(message)?,
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Returns the buffered events and logs, which never reach the wrapped
+    /// host.
+    pub(crate) fn into_outputs(self) -> (Vec<IbcEvent>, Vec<String>) {
+        let mut events = Vec::new();
+        let mut logs = Vec::new();
+
+        for output in self.outputs {
+            match output {
+                Output::Event(event) => events.push(event),
+                Output::Log(message) => logs.push(message),
+            }
+        }
+
+        (events, logs)
+    }
+}
+
+impl<H> HostParams for BufferedHost<'_, H>
+where
+    H: StoreHost,
+{
+    fn max_expected_time_per_block(&self) -> Duration {
+        self.inner.max_expected_time_per_block()
+    }
+
+    fn allowed_clients(&self) -> AllowedClients {
+        self.inner.allowed_clients()
+    }
+
+    fn upgrade_plan_height(&self) -> Option<Height> {
+        self.inner.upgrade_plan_height()
+    }
+
+    fn max_callback_gas(&self) -> Option<u64> {
+        self.inner.max_callback_gas()
+    }
+
+    fn counterparty_identifier_mode(&self) -> IdentifierMode {
+        self.inner.counterparty_identifier_mode()
+    }
+}
+
+impl<H> StoreHost for BufferedHost<'_, H>
+where
+    H: StoreHost,
+{
+    type Clients = H::Clients;
+    type HostClientState = H::HostClientState;
+    type HostConsensusState = H::HostConsensusState;
+
+    fn host_consensus_state(
+        &self,
+        height: &Height,
+    ) -> Result<Self::HostConsensusState, ContextError> {
+        self.inner.host_consensus_state(height)
+    }
+
+    fn validate_self_client(
+        &self,
+        client_state_of_host_on_counterparty: Self::HostClientState,
+    ) -> Result<(), ContextError> {
+        self.inner
+            .validate_self_client(client_state_of_host_on_counterparty)
+    }
+
+    fn commitment_prefix(&self) -> CommitmentPrefix {
+        self.inner.commitment_prefix()
+    }
+
+    fn validate_message_signer(&self, signer: &Signer) -> Result<(), ContextError> {
+        self.inner.validate_message_signer(signer)
+    }
+
+    fn is_channel_frozen(&self, channel_end_path: &ChannelEndPath) -> Result<bool, ContextError> {
+        self.inner.is_channel_frozen(channel_end_path)
+    }
+
+    fn store_channel_frozen(
+        &mut self,
+        channel_end_path: &ChannelEndPath,
+        frozen: bool,
+    ) -> Result<(), ContextError> {
+        self.inner.store_channel_frozen(channel_end_path, frozen)
+    }
+
+    fn emit_ibc_event(&mut self, event: IbcEvent) -> Result<(), ContextError> {
+        self.outputs.push(Output::Event(event));
+        Ok(())
+    }
+
+    fn log_message(&mut self, message: String) -> Result<(), ContextError> {
+        self.outputs.push(Output::Log(message));
+        Ok(())
+    }
+}
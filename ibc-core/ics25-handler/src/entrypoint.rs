@@ -20,14 +20,21 @@ use ibc_core_connection::types::msgs::ConnectionMsg;
 use ibc_core_handler_types::error::ContextError;
 use ibc_core_handler_types::msgs::MsgEnvelope;
 use ibc_core_host::{ExecutionContext, ValidationContext};
+use ibc_core_router::metrics::HandlerStage;
 use ibc_core_router::router::Router;
 use ibc_core_router::types::error::RouterError;
 use ibc_primitives::proto::Any;
 
+use crate::buffer::BufferedContext;
+use crate::intercept::{after_execute, before_execute};
+use crate::msg_type::{msg_type, packet_lifecycle_step};
+#[cfg(feature = "tracing")]
+use crate::span::{handler_call_span, handler_span};
+
 /// Entrypoint which performs both validation and message execution
 pub fn dispatch<Ctx>(
     ctx: &mut Ctx,
-    router: &mut impl Router,
+    router: &mut (impl Router + ?Sized),
     msg: MsgEnvelope,
 ) -> Result<(), ContextError>
 where
@@ -48,12 +55,54 @@ where
 /// That is, the state transition of message `i` must be applied before
 /// message `i+1` is validated. This is equivalent to calling
 /// `dispatch()` on each successively.
-pub fn validate<Ctx>(ctx: &Ctx, router: &impl Router, msg: MsgEnvelope) -> Result<(), ContextError>
+///
+/// Validation only reads from `ctx` and `router`, so that hosts may validate
+/// independent messages concurrently, e.g. across the worker threads of a
+/// block builder, by sharing a `Sync` context and router between them. The
+/// router may be given as a trait object.
+pub fn validate<Ctx>(
+    ctx: &Ctx,
+    router: &(impl Router + ?Sized),
+    msg: MsgEnvelope,
+) -> Result<(), ContextError>
 where
     Ctx: ValidationContext,
     <<Ctx::V as ClientValidationContext>::ClientStateRef as TryFrom<Any>>::Error: Into<ClientError>,
     <Ctx::HostClientState as TryFrom<Any>>::Error: Into<ClientError>,
 {
+    #[cfg(feature = "tracing")]
+    let _span = handler_span(HandlerStage::Validate, &msg).entered();
+
+    let msg_type = msg_type(&msg);
+    let metrics = router.metrics_sink();
+    let start = metrics.and_then(|metrics| metrics.now());
+
+    let result = validate_impl(ctx, router, msg);
+
+    if let Some(metrics) = metrics {
+        metrics.message_processed(msg_type, HandlerStage::Validate, result.is_ok());
+
+        if let (Some(start), Some(end)) = (start, metrics.now()) {
+            metrics.validation_latency(msg_type, end.saturating_sub(start));
+        }
+    }
+
+    result
+}
+
+pub(crate) fn validate_impl<Ctx>(
+    ctx: &Ctx,
+    router: &(impl Router + ?Sized),
+    msg: MsgEnvelope,
+) -> Result<(), ContextError>
+where
+    Ctx: ValidationContext,
+    <<Ctx::V as ClientValidationContext>::ClientStateRef as TryFrom<Any>>::Error: Into<ClientError>,
+    <Ctx::HostClientState as TryFrom<Any>>::Error: Into<ClientError>,
+{
+    #[cfg(feature = "tracing")]
+    let _span = handler_call_span(HandlerStage::Validate, &msg).entered();
+
     match msg {
         MsgEnvelope::Client(msg) => match msg {
             ClientMsg::CreateClient(msg) => create_client::validate(ctx, msg),
@@ -124,21 +173,72 @@ where
 /// Entrypoint which only performs message execution
 pub fn execute<Ctx>(
     ctx: &mut Ctx,
-    router: &mut impl Router,
+    router: &mut (impl Router + ?Sized),
     msg: MsgEnvelope,
 ) -> Result<(), ContextError>
 where
     Ctx: ExecutionContext,
     <<Ctx::E as ClientExecutionContext>::ClientStateMut as TryFrom<Any>>::Error: Into<ClientError>,
 {
+    #[cfg(feature = "tracing")]
+    let _span = handler_span(HandlerStage::Execute, &msg).entered();
+
+    let msg_type = msg_type(&msg);
+    // The packet is only needed by the metrics sink, if any, once the
+    // message has been consumed by the handler.
+    let packet_step = router
+        .metrics_sink()
+        .and_then(|_| packet_lifecycle_step(&msg))
+        .map(|(step, packet)| (step, packet.clone()));
+    let intercepted = before_execute(&*router, &msg);
+
+    // The events and logs emitted while executing the message only reach the
+    // host once the execution succeeds.
+    let mut buffered_ctx = BufferedContext::begin(ctx);
+
+    let result = match execute_impl(&mut buffered_ctx, router, msg) {
+        Ok(()) => buffered_ctx.commit(),
+        Err(e) => {
+            buffered_ctx.rollback();
+            Err(e)
+        }
+    };
+
+    if let Some(metrics) = router.metrics_sink() {
+        metrics.message_processed(msg_type, HandlerStage::Execute, result.is_ok());
+
+        if let Some((step, packet)) = packet_step.filter(|_| result.is_ok()) {
+            metrics.packet_lifecycle_step(step, &packet);
+        }
+    }
+
+    if let Some(intercepted) = intercepted {
+        after_execute(&*router, intercepted, result.is_ok());
+    }
+
+    result
+}
+
+pub(crate) fn execute_impl<Ctx>(
+    ctx: &mut Ctx,
+    router: &mut (impl Router + ?Sized),
+    msg: MsgEnvelope,
+) -> Result<(), ContextError>
+where
+    Ctx: ExecutionContext,
+    <<Ctx::E as ClientExecutionContext>::ClientStateMut as TryFrom<Any>>::Error: Into<ClientError>,
+{
+    #[cfg(feature = "tracing")]
+    let _span = handler_call_span(HandlerStage::Execute, &msg).entered();
+
     match msg {
         MsgEnvelope::Client(msg) => match msg {
             ClientMsg::CreateClient(msg) => create_client::execute(ctx, msg),
             ClientMsg::UpdateClient(msg) => {
-                update_client::execute(ctx, MsgUpdateOrMisbehaviour::UpdateClient(msg))
+                update_client::execute(ctx, MsgUpdateOrMisbehaviour::UpdateClient(msg)).map(|_| ())
             }
             ClientMsg::Misbehaviour(msg) => {
-                update_client::execute(ctx, MsgUpdateOrMisbehaviour::Misbehaviour(msg))
+                update_client::execute(ctx, MsgUpdateOrMisbehaviour::Misbehaviour(msg)).map(|_| ())
             }
             ClientMsg::UpgradeClient(msg) => upgrade_client::execute(ctx, msg),
             ClientMsg::RecoverClient(_msg) => {
@@ -180,12 +280,13 @@ where
                 .ok_or(RouterError::UnknownPort {
                     port_id: port_id.clone(),
                 })?;
+            let redact_error_acks = router.redact_error_acknowledgements();
             let module = router
                 .get_route_mut(&module_id)
                 .ok_or(RouterError::ModuleNotFound)?;
 
             match msg {
-                PacketMsg::Recv(msg) => recv_packet_execute(ctx, module, msg),
+                PacketMsg::Recv(msg) => recv_packet_execute(ctx, module, msg, redact_error_acks),
                 PacketMsg::Ack(msg) => acknowledgement_packet_execute(ctx, module, msg),
                 PacketMsg::Timeout(msg) => {
                     timeout_packet_execute(ctx, module, TimeoutMsgType::Timeout(msg))
@@ -0,0 +1,78 @@
+//! Calls the packet interceptor of the router, if any, around the execution
+//! of the packet messages.
+
+use ibc_core_channel::types::msgs::{packet_msg_to_port_id, PacketMsg};
+use ibc_core_handler_types::msgs::MsgEnvelope;
+use ibc_core_router::router::Router;
+use ibc_core_router::types::module::ModuleId;
+
+/// A packet message whose execution is intercepted, along with the module its
+/// callbacks are routed to.
+pub(crate) struct InterceptedPacket {
+    module_id: ModuleId,
+    msg: PacketMsg,
+}
+
+/// Calls the packet interceptor of `router` before `msg` is executed, if `msg`
+/// is a packet message routed to a module.
+///
+/// Returns the message to call the interceptor with once it is executed, the
+/// message itself being consumed by the handler.
+pub(crate) fn before_execute(
+    router: &(impl Router + ?Sized),
+    msg: &MsgEnvelope,
+) -> Option<InterceptedPacket> {
+    let interceptor = router.packet_interceptor()?;
+
+    let MsgEnvelope::Packet(msg) = msg else {
+        return None;
+    };
+
+    let module_id = router.lookup_module(packet_msg_to_port_id(msg))?;
+
+    match msg {
+        PacketMsg::Recv(msg) => interceptor.before_recv_packet(&module_id, &msg.packet),
+        PacketMsg::Ack(msg) => {
+            interceptor.before_acknowledgement_packet(&module_id, &msg.packet, &msg.acknowledgement)
+        }
+        PacketMsg::Timeout(msg) => interceptor.before_timeout_packet(&module_id, &msg.packet),
+        PacketMsg::TimeoutOnClose(msg) => {
+            interceptor.before_timeout_packet(&module_id, &msg.packet)
+        }
+    }
+
+    Some(InterceptedPacket {
+        module_id,
+        msg: msg.clone(),
+    })
+}
+
+/// Calls the packet interceptor of `router` once the intercepted message is
+/// executed.
+pub(crate) fn after_execute(
+    router: &(impl Router + ?Sized),
+    intercepted: InterceptedPacket,
+    succeeded: bool,
+) {
+    let Some(interceptor) = router.packet_interceptor() else {
+        return;
+    };
+
+    let InterceptedPacket { module_id, msg } = intercepted;
+
+    match msg {
+        PacketMsg::Recv(msg) => interceptor.after_recv_packet(&module_id, &msg.packet, succeeded),
+        PacketMsg::Ack(msg) => interceptor.after_acknowledgement_packet(
+            &module_id,
+            &msg.packet,
+            &msg.acknowledgement,
+            succeeded,
+        ),
+        PacketMsg::Timeout(msg) => {
+            interceptor.after_timeout_packet(&module_id, &msg.packet, succeeded)
+        }
+        PacketMsg::TimeoutOnClose(msg) => {
+            interceptor.after_timeout_packet(&module_id, &msg.packet, succeeded)
+        }
+    }
+}
@@ -8,7 +8,23 @@
 //! to the context (e.g. [`ExecutionContext`](ibc_core_host::ExecutionContext))
 //! while processing `M`. If the transaction containing `M` consists of multiple
 //! messages, then typically the state modifications from all messages is
-//! expected to be rolled back as well.
+//! expected to be rolled back as well. The events and logs emitted while
+//! executing `M`, however, are only handed over to the context once its
+//! execution succeeds, and are discarded otherwise.
+//!
+//! With the `tracing` feature enabled, the validation and execution of each
+//! message are covered by the `ibc_validate` and `ibc_execute` spans, which
+//! carry the type of the message along with the identifiers it applies to,
+//! and the calls to the handlers by the `ibc_handler` span, whichever entry
+//! point the message is handled through.
+//!
+//! With the `async` feature enabled, hosts whose IBC state is stored in an
+//! asynchronous store may process messages through the
+//! [`dispatch_async`](asynchronous::dispatch_async) entry point.
+//!
+//! Hosts storing their IBC state in an [`IbcStore`](ibc_core_host::store::IbcStore)
+//! may predict the writes and the events of a message, without applying them,
+//! through the [`simulate`](simulate::simulate) entry point.
 #![no_std]
 #![forbid(unsafe_code)]
 #![cfg_attr(not(test), deny(clippy::unwrap_used))]
@@ -24,7 +40,15 @@
 #[cfg(any(test, feature = "std"))]
 extern crate std;
 
+#[cfg(feature = "async")]
+pub mod asynchronous;
+mod buffer;
 pub mod entrypoint;
+mod intercept;
+mod msg_type;
+pub mod simulate;
+#[cfg(feature = "tracing")]
+mod span;
 
 /// Re-export IBC handler types from `ibc-core-handler-types` crate.
 pub mod types {
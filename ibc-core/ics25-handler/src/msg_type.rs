@@ -0,0 +1,26 @@
+//! Defines helpers describing the messages handled by the entry points, for
+//! the purpose of observability.
+
+use ibc_core_channel::types::msgs::PacketMsg;
+use ibc_core_channel::types::packet::Packet;
+use ibc_core_handler_types::msgs::MsgEnvelope;
+use ibc_core_router::metrics::PacketLifecycleStep;
+
+/// Returns the type of `msg`, as reported in spans and metrics.
+pub(crate) fn msg_type(msg: &MsgEnvelope) -> &'static str {
+    msg.msg_type().name()
+}
+
+/// Returns the step of the lifecycle of a packet that `msg` completes, along
+/// with the packet, if `msg` is a packet message.
+pub(crate) fn packet_lifecycle_step(msg: &MsgEnvelope) -> Option<(PacketLifecycleStep, &Packet)> {
+    match msg {
+        MsgEnvelope::Packet(msg) => Some(match msg {
+            PacketMsg::Recv(msg) => (PacketLifecycleStep::Received, &msg.packet),
+            PacketMsg::Ack(msg) => (PacketLifecycleStep::Acknowledged, &msg.packet),
+            PacketMsg::Timeout(msg) => (PacketLifecycleStep::TimedOut, &msg.packet),
+            PacketMsg::TimeoutOnClose(msg) => (PacketLifecycleStep::TimedOut, &msg.packet),
+        }),
+        _ => None,
+    }
+}
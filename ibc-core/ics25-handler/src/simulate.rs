@@ -0,0 +1,90 @@
+//! Defines the [`simulate`] entry point, predicting the state transition of a
+//! message without applying it, e.g. for RPC nodes to estimate the fees of a
+//! transaction or to run pre-flight checks on it.
+
+use ibc_core_client::types::error::ClientError;
+use ibc_core_handler_types::error::ContextError;
+use ibc_core_handler_types::events::IbcEvent;
+use ibc_core_handler_types::msgs::MsgEnvelope;
+use ibc_core_host::store::{
+    ClientStore, ClientWriteBatch, IbcStore, StoreContext, StoreHost, StoreOverlay,
+};
+use ibc_core_host::types::path::Path;
+use ibc_core_router::router::Router;
+use ibc_primitives::prelude::*;
+use ibc_primitives::proto::Any;
+
+use crate::buffer::BufferedHost;
+use crate::entrypoint::{execute_impl, validate_impl};
+
+/// The state transition a message would cause, as predicted by [`simulate`].
+#[derive(Clone, Debug)]
+pub struct Simulation {
+    /// The writes the message would make to the [`IbcStore`], by path, a
+    /// `None` value deleting the value stored under the path.
+    ///
+    /// The writes to the client and consensus states, which are not stored in
+    /// the [`IbcStore`], are not part of the prediction.
+    pub writes: BTreeMap<Path, Option<Vec<u8>>>,
+    /// The events the message would emit, in the order they were emitted.
+    pub events: Vec<IbcEvent>,
+    /// The log messages the message would emit, in the order they were
+    /// emitted.
+    pub logs: Vec<String>,
+}
+
+/// Entrypoint which validates and executes the message as [`dispatch`] does,
+/// against a copy-on-write overlay of the IBC state stored in `store`, and
+/// returns the writes and the events it would make without committing any of
+/// them.
+///
+/// Neither `store`, which is only read, nor `host`, which never receives the
+/// events and logs, are written to. The writes to the client and consensus
+/// states are buffered in a [`ClientWriteBatch`] on top of `clients`, which
+/// is discarded rather than committed, so that these writes are neither
+/// applied nor predicted.
+///
+/// The state of the applications is however out of the scope of the
+/// simulation: the modules of `router` write it directly, as they do when
+/// the message is dispatched. Hosts are hence expected either to simulate
+/// client and connection messages only, which do not reach the modules, or
+/// to route the channel and packet messages to modules running against a
+/// branch of their state, e.g. a cached branch of their stores as Cosmos-SDK
+/// does to simulate transactions.
+///
+/// The metrics sink and the packet interceptor of `router`, if any, are not
+/// called, the message not being processed for real.
+///
+/// [`dispatch`]: crate::entrypoint::dispatch
+pub fn simulate<S, H>(
+    store: &S,
+    clients: H::Clients,
+    host: &mut H,
+    router: &mut (impl Router + ?Sized),
+    msg: MsgEnvelope,
+) -> Result<Simulation, ContextError>
+where
+    S: IbcStore,
+    H: StoreHost,
+    <<H::Clients as ClientStore>::ClientState as TryFrom<Any>>::Error: Into<ClientError>,
+    <H::HostClientState as TryFrom<Any>>::Error: Into<ClientError>,
+{
+    let mut ctx = StoreContext::new(
+        StoreOverlay::new(store),
+        ClientWriteBatch::new(clients),
+        BufferedHost::new(host),
+    );
+
+    validate_impl(&ctx, router, msg.clone())?;
+    execute_impl(&mut ctx, router, msg)?;
+
+    let (overlay, clients, host) = ctx.into_parts();
+    clients.discard();
+    let (events, logs) = host.into_outputs();
+
+    Ok(Simulation {
+        writes: overlay.into_writes(),
+        events,
+        logs,
+    })
+}
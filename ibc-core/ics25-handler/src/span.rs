@@ -0,0 +1,156 @@
+//! Defines the tracing spans entered while handling a message, available
+//! behind the `tracing` feature.
+
+use ibc_core_channel::types::msgs::{ChannelMsg, PacketMsg};
+use ibc_core_channel::types::packet::Packet;
+use ibc_core_client::types::msgs::ClientMsg;
+use ibc_core_connection::types::msgs::ConnectionMsg;
+use ibc_core_handler_types::msgs::MsgEnvelope;
+use ibc_core_router::metrics::HandlerStage;
+use tracing::field::{display, Empty};
+use tracing::Span;
+
+use crate::msg_type::msg_type;
+
+/// Returns the span covering the given `stage` of the handling of `msg`.
+///
+/// The span carries the type of the message and, depending on it, the
+/// identifiers of the client, connection and channel it applies to on the
+/// host, along with the sequence of the packet, if any.
+pub(crate) fn handler_span(stage: HandlerStage, msg: &MsgEnvelope) -> Span {
+    let span = match stage {
+        HandlerStage::Validate => tracing::info_span!(
+            "ibc_validate",
+            msg_type = msg_type(msg),
+            client_id = Empty,
+            connection_id = Empty,
+            port_id = Empty,
+            channel_id = Empty,
+            sequence = Empty,
+        ),
+        HandlerStage::Execute => tracing::info_span!(
+            "ibc_execute",
+            msg_type = msg_type(msg),
+            client_id = Empty,
+            connection_id = Empty,
+            port_id = Empty,
+            channel_id = Empty,
+            sequence = Empty,
+        ),
+    };
+
+    match msg {
+        MsgEnvelope::Client(msg) => {
+            let client_id = match msg {
+                ClientMsg::CreateClient(_) => None,
+                ClientMsg::UpdateClient(msg) => Some(&msg.client_id),
+                ClientMsg::Misbehaviour(msg) => Some(&msg.client_id),
+                ClientMsg::UpgradeClient(msg) => Some(&msg.client_id),
+                ClientMsg::RecoverClient(msg) => Some(&msg.subject_client_id),
+            };
+            if let Some(client_id) = client_id {
+                span.record("client_id", display(client_id));
+            }
+        }
+        MsgEnvelope::Connection(msg) => match msg {
+            ConnectionMsg::OpenInit(msg) => {
+                span.record("client_id", display(&msg.client_id_on_a));
+            }
+            ConnectionMsg::OpenTry(msg) => {
+                span.record("client_id", display(&msg.client_id_on_b));
+            }
+            ConnectionMsg::OpenAck(msg) => {
+                span.record("connection_id", display(&msg.conn_id_on_a));
+            }
+            ConnectionMsg::OpenConfirm(msg) => {
+                span.record("connection_id", display(&msg.conn_id_on_b));
+            }
+        },
+        MsgEnvelope::Channel(msg) => {
+            let (port_id, channel_id) = match msg {
+                ChannelMsg::OpenInit(msg) => (&msg.port_id_on_a, None),
+                ChannelMsg::OpenTry(msg) => (&msg.port_id_on_b, None),
+                ChannelMsg::OpenAck(msg) => (&msg.port_id_on_a, Some(&msg.chan_id_on_a)),
+                ChannelMsg::OpenConfirm(msg) => (&msg.port_id_on_b, Some(&msg.chan_id_on_b)),
+                ChannelMsg::CloseInit(msg) => (&msg.port_id_on_a, Some(&msg.chan_id_on_a)),
+                ChannelMsg::CloseConfirm(msg) => (&msg.port_id_on_b, Some(&msg.chan_id_on_b)),
+            };
+            span.record("port_id", display(port_id));
+            if let Some(channel_id) = channel_id {
+                span.record("channel_id", display(channel_id));
+            }
+        }
+        MsgEnvelope::Packet(msg) => {
+            // Received packets are handled on their destination chain, while
+            // the others are handled on their source chain.
+            let (packet, on_destination) = match msg {
+                PacketMsg::Recv(msg) => (&msg.packet, true),
+                PacketMsg::Ack(msg) => (&msg.packet, false),
+                PacketMsg::Timeout(msg) => (&msg.packet, false),
+                PacketMsg::TimeoutOnClose(msg) => (&msg.packet, false),
+            };
+            record_packet(&span, packet, on_destination);
+        }
+    }
+
+    span
+}
+
+/// Returns the span covering the call to the handler of `msg` at the given
+/// `stage`, whichever entry point the message is handled through.
+///
+/// The span carries the name of the handler, and is nested in the span of
+/// [`handler_span`] when the message is handled through the `validate`,
+/// `execute` or `dispatch` entry points.
+pub(crate) fn handler_call_span(stage: HandlerStage, msg: &MsgEnvelope) -> Span {
+    let stage = match stage {
+        HandlerStage::Validate => "validate",
+        HandlerStage::Execute => "execute",
+    };
+
+    tracing::debug_span!("ibc_handler", handler = handler_name(msg), stage)
+}
+
+/// Returns the name of the handler of `msg`.
+fn handler_name(msg: &MsgEnvelope) -> &'static str {
+    match msg {
+        MsgEnvelope::Client(msg) => match msg {
+            ClientMsg::CreateClient(_) => "create_client",
+            ClientMsg::UpdateClient(_) | ClientMsg::Misbehaviour(_) => "update_client",
+            ClientMsg::UpgradeClient(_) => "upgrade_client",
+            ClientMsg::RecoverClient(_) => "recover_client",
+        },
+        MsgEnvelope::Connection(msg) => match msg {
+            ConnectionMsg::OpenInit(_) => "conn_open_init",
+            ConnectionMsg::OpenTry(_) => "conn_open_try",
+            ConnectionMsg::OpenAck(_) => "conn_open_ack",
+            ConnectionMsg::OpenConfirm(_) => "conn_open_confirm",
+        },
+        MsgEnvelope::Channel(msg) => match msg {
+            ChannelMsg::OpenInit(_) => "chan_open_init",
+            ChannelMsg::OpenTry(_) => "chan_open_try",
+            ChannelMsg::OpenAck(_) => "chan_open_ack",
+            ChannelMsg::OpenConfirm(_) => "chan_open_confirm",
+            ChannelMsg::CloseInit(_) => "chan_close_init",
+            ChannelMsg::CloseConfirm(_) => "chan_close_confirm",
+        },
+        MsgEnvelope::Packet(msg) => match msg {
+            PacketMsg::Recv(_) => "recv_packet",
+            PacketMsg::Ack(_) => "acknowledgement",
+            PacketMsg::Timeout(_) => "timeout",
+            PacketMsg::TimeoutOnClose(_) => "timeout_on_close",
+        },
+    }
+}
+
+fn record_packet(span: &Span, packet: &Packet, on_destination: bool) {
+    let (port_id, channel_id) = if on_destination {
+        (&packet.port_id_on_b, &packet.chan_id_on_b)
+    } else {
+        (&packet.port_id_on_a, &packet.chan_id_on_a)
+    };
+
+    span.record("port_id", display(port_id));
+    span.record("channel_id", display(channel_id));
+    span.record("sequence", display(&packet.seq_on_a));
+}
@@ -5,8 +5,10 @@ use displaydoc::Display;
 use ibc_core_channel_types::error::{ChannelError, PacketError};
 use ibc_core_client_types::error::ClientError;
 use ibc_core_connection_types::error::ConnectionError;
+use ibc_core_host_types::error::HostError;
 use ibc_core_router_types::error::RouterError;
 use ibc_primitives::prelude::*;
+use ibc_primitives::AbciError;
 
 /// Top-level error
 #[derive(Debug, Display, From)]
@@ -19,6 +21,8 @@ pub enum ContextError {
     ChannelError(ChannelError),
     /// ICS04 Packet error: {0}
     PacketError(PacketError),
+    /// ICS24 Host error: {0}
+    HostError(HostError),
     /// ICS26 Routing error: {0}
     RouterError(RouterError),
 }
@@ -34,6 +38,30 @@ impl From<ContextError> for ClientError {
     }
 }
 
+impl AbciError for ContextError {
+    fn codespace(&self) -> &'static str {
+        match self {
+            Self::ClientError(e) => e.codespace(),
+            Self::ConnectionError(e) => e.codespace(),
+            Self::ChannelError(e) => e.codespace(),
+            Self::PacketError(e) => e.codespace(),
+            Self::HostError(e) => e.codespace(),
+            Self::RouterError(e) => e.codespace(),
+        }
+    }
+
+    fn code(&self) -> u32 {
+        match self {
+            Self::ClientError(e) => e.code(),
+            Self::ConnectionError(e) => e.code(),
+            Self::ChannelError(e) => e.code(),
+            Self::PacketError(e) => e.code(),
+            Self::HostError(e) => e.code(),
+            Self::RouterError(e) => e.code(),
+        }
+    }
+}
+
 #[cfg(feature = "std")]
 impl std::error::Error for ContextError {
     fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
@@ -42,6 +70,7 @@ impl std::error::Error for ContextError {
             Self::ConnectionError(e) => Some(e),
             Self::ChannelError(e) => Some(e),
             Self::PacketError(e) => Some(e),
+            Self::HostError(e) => Some(e),
             Self::RouterError(e) => Some(e),
         }
     }
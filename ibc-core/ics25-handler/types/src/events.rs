@@ -49,6 +49,12 @@ impl std::error::Error for Error {
 const MESSAGE_EVENT: &str = "message";
 
 /// Events created by the IBC component of a chain, destined for a relayer.
+///
+/// Covers the events emitted by the handlers of all the messages of
+/// [`MsgEnvelope`](crate::msgs::MsgEnvelope), along with the events of the
+/// applications, as [`IbcEvent::Module`]. Each variant is serialized with the
+/// type of the event as its tag, e.g. `create_client`, which does not change
+/// with the name of the variant.
 #[cfg_attr(
     feature = "parity-scale-codec",
     derive(
@@ -64,31 +70,61 @@ const MESSAGE_EVENT: &str = "message";
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub enum IbcEvent {
+    #[cfg_attr(feature = "serde", serde(rename = "create_client"))]
     CreateClient(ClientEvents::CreateClient),
+    #[cfg_attr(feature = "serde", serde(rename = "update_client"))]
     UpdateClient(ClientEvents::UpdateClient),
+    #[cfg_attr(feature = "serde", serde(rename = "upgrade_client"))]
     UpgradeClient(ClientEvents::UpgradeClient),
+    #[cfg_attr(feature = "serde", serde(rename = "recover_client"))]
+    RecoverClient(ClientEvents::RecoverClient),
+    #[cfg_attr(feature = "serde", serde(rename = "client_misbehaviour"))]
     ClientMisbehaviour(ClientEvents::ClientMisbehaviour),
 
+    #[cfg_attr(feature = "serde", serde(rename = "connection_open_init"))]
     OpenInitConnection(ConnectionEvents::OpenInit),
+    #[cfg_attr(feature = "serde", serde(rename = "connection_open_try"))]
     OpenTryConnection(ConnectionEvents::OpenTry),
+    #[cfg_attr(feature = "serde", serde(rename = "connection_open_ack"))]
     OpenAckConnection(ConnectionEvents::OpenAck),
+    #[cfg_attr(feature = "serde", serde(rename = "connection_open_confirm"))]
     OpenConfirmConnection(ConnectionEvents::OpenConfirm),
 
+    #[cfg_attr(feature = "serde", serde(rename = "channel_open_init"))]
     OpenInitChannel(ChannelEvents::OpenInit),
+    #[cfg_attr(feature = "serde", serde(rename = "channel_open_try"))]
     OpenTryChannel(ChannelEvents::OpenTry),
+    #[cfg_attr(feature = "serde", serde(rename = "channel_open_ack"))]
     OpenAckChannel(ChannelEvents::OpenAck),
+    #[cfg_attr(feature = "serde", serde(rename = "channel_open_confirm"))]
     OpenConfirmChannel(ChannelEvents::OpenConfirm),
+    #[cfg_attr(feature = "serde", serde(rename = "channel_close_init"))]
     CloseInitChannel(ChannelEvents::CloseInit),
+    #[cfg_attr(feature = "serde", serde(rename = "channel_close_confirm"))]
     CloseConfirmChannel(ChannelEvents::CloseConfirm),
 
+    #[cfg_attr(feature = "serde", serde(rename = "send_packet"))]
     SendPacket(ChannelEvents::SendPacket),
+    #[cfg_attr(feature = "serde", serde(rename = "recv_packet"))]
     ReceivePacket(ChannelEvents::ReceivePacket),
+    #[cfg_attr(feature = "serde", serde(rename = "write_acknowledgement"))]
     WriteAcknowledgement(ChannelEvents::WriteAcknowledgement),
+    #[cfg_attr(feature = "serde", serde(rename = "acknowledge_packet"))]
     AcknowledgePacket(ChannelEvents::AcknowledgePacket),
+    #[cfg_attr(feature = "serde", serde(rename = "timeout_packet"))]
     TimeoutPacket(ChannelEvents::TimeoutPacket),
+    #[cfg_attr(feature = "serde", serde(rename = "channel_close"))]
     ChannelClosed(ChannelEvents::ChannelClosed),
-
+    #[cfg_attr(feature = "serde", serde(rename = "channel_cleanup"))]
+    ChannelCleanup(ChannelEvents::ChannelCleanup),
+    #[cfg_attr(feature = "serde", serde(rename = "channel_frozen"))]
+    ChannelFrozen(ChannelEvents::ChannelFrozen),
+    #[cfg_attr(feature = "serde", serde(rename = "channel_unfrozen"))]
+    ChannelUnfrozen(ChannelEvents::ChannelUnfrozen),
+
+    #[cfg_attr(feature = "serde", serde(rename = "module"))]
     Module(ModuleEvent),
+    #[cfg_attr(feature = "serde", serde(rename = "message"))]
     Message(MessageEvent),
 }
 
@@ -100,6 +136,7 @@ impl TryFrom<IbcEvent> for abci::Event {
             IbcEvent::CreateClient(event) => event.into(),
             IbcEvent::UpdateClient(event) => event.into(),
             IbcEvent::UpgradeClient(event) => event.into(),
+            IbcEvent::RecoverClient(event) => event.into(),
             IbcEvent::ClientMisbehaviour(event) => event.into(),
             IbcEvent::OpenInitConnection(event) => event.into(),
             IbcEvent::OpenTryConnection(event) => event.into(),
@@ -117,6 +154,9 @@ impl TryFrom<IbcEvent> for abci::Event {
             IbcEvent::AcknowledgePacket(event) => event.try_into().map_err(Error::Channel)?,
             IbcEvent::TimeoutPacket(event) => event.try_into().map_err(Error::Channel)?,
             IbcEvent::ChannelClosed(event) => event.into(),
+            IbcEvent::ChannelCleanup(event) => event.into(),
+            IbcEvent::ChannelFrozen(event) => event.into(),
+            IbcEvent::ChannelUnfrozen(event) => event.into(),
             IbcEvent::Module(event) => event.into(),
             IbcEvent::Message(event) => abci::Event {
                 kind: MESSAGE_EVENT.to_string(),
@@ -133,6 +173,7 @@ impl IbcEvent {
             IbcEvent::UpdateClient(event) => event.event_type(),
             IbcEvent::ClientMisbehaviour(event) => event.event_type(),
             IbcEvent::UpgradeClient(event) => event.event_type(),
+            IbcEvent::RecoverClient(event) => event.event_type(),
             IbcEvent::OpenInitConnection(event) => event.event_type(),
             IbcEvent::OpenTryConnection(event) => event.event_type(),
             IbcEvent::OpenAckConnection(event) => event.event_type(),
@@ -149,6 +190,9 @@ impl IbcEvent {
             IbcEvent::AcknowledgePacket(event) => event.event_type(),
             IbcEvent::TimeoutPacket(event) => event.event_type(),
             IbcEvent::ChannelClosed(event) => event.event_type(),
+            IbcEvent::ChannelCleanup(event) => event.event_type(),
+            IbcEvent::ChannelFrozen(event) => event.event_type(),
+            IbcEvent::ChannelUnfrozen(event) => event.event_type(),
             IbcEvent::Module(module_event) => module_event.kind.as_str(),
             IbcEvent::Message(_) => MESSAGE_EVENT,
         }
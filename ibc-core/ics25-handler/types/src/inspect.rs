@@ -0,0 +1,463 @@
+//! Defines the decode-only inspection of the messages routed by the handler,
+//! which classifies them and extracts their packet or client message without
+//! converting them into domain types, nor validating them.
+//!
+//! Inspecting a message is much cheaper than decoding it into a
+//! [`MsgEnvelope`], and suits tools which only need to classify IBC
+//! transactions, such as mempool filters and transaction indexers. As nothing
+//! is validated, the inspected fields must not be trusted any further.
+
+use ibc_core_channel_types::msgs::{
+    ChannelMsg, PacketMsg, ACKNOWLEDGEMENT_TYPE_URL, CHAN_CLOSE_CONFIRM_TYPE_URL,
+    CHAN_CLOSE_INIT_TYPE_URL, CHAN_OPEN_ACK_TYPE_URL, CHAN_OPEN_CONFIRM_TYPE_URL,
+    CHAN_OPEN_INIT_TYPE_URL, CHAN_OPEN_TRY_TYPE_URL, RECV_PACKET_TYPE_URL,
+    TIMEOUT_ON_CLOSE_TYPE_URL, TIMEOUT_TYPE_URL,
+};
+use ibc_core_client_types::msgs::{
+    ClientMsg, CREATE_CLIENT_TYPE_URL, RECOVER_CLIENT_TYPE_URL, SUBMIT_MISBEHAVIOUR_TYPE_URL,
+    UPDATE_CLIENT_TYPE_URL, UPGRADE_CLIENT_TYPE_URL,
+};
+use ibc_core_connection_types::msgs::{
+    ConnectionMsg, CONN_OPEN_ACK_TYPE_URL, CONN_OPEN_CONFIRM_TYPE_URL, CONN_OPEN_INIT_TYPE_URL,
+    CONN_OPEN_TRY_TYPE_URL,
+};
+use ibc_core_router_types::error::{RouterError, TypeUrlSuggestion};
+use ibc_primitives::prelude::*;
+use ibc_proto::google::protobuf::Any;
+use ibc_proto::ibc::core::channel::v1::{
+    MsgAcknowledgement as RawMsgAcknowledgement, MsgRecvPacket as RawMsgRecvPacket,
+    MsgTimeout as RawMsgTimeout, MsgTimeoutOnClose as RawMsgTimeoutOnClose, Packet as RawPacket,
+};
+#[allow(deprecated)]
+use ibc_proto::ibc::core::client::v1::{
+    MsgSubmitMisbehaviour as RawMsgSubmitMisbehaviour, MsgUpdateClient as RawMsgUpdateClient,
+};
+use prost::Message;
+
+use crate::msgs::MsgEnvelope;
+
+/// The types of the messages routed by the handler, as identified by their
+/// type URL.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum MsgType {
+    CreateClient,
+    UpdateClient,
+    UpgradeClient,
+    SubmitMisbehaviour,
+    RecoverClient,
+    ConnectionOpenInit,
+    ConnectionOpenTry,
+    ConnectionOpenAck,
+    ConnectionOpenConfirm,
+    ChannelOpenInit,
+    ChannelOpenTry,
+    ChannelOpenAck,
+    ChannelOpenConfirm,
+    ChannelCloseInit,
+    ChannelCloseConfirm,
+    RecvPacket,
+    Acknowledgement,
+    Timeout,
+    TimeoutOnClose,
+}
+
+impl MsgType {
+    /// All the message types, in the order of their declaration.
+    pub const ALL: [Self; 19] = [
+        Self::CreateClient,
+        Self::UpdateClient,
+        Self::UpgradeClient,
+        Self::SubmitMisbehaviour,
+        Self::RecoverClient,
+        Self::ConnectionOpenInit,
+        Self::ConnectionOpenTry,
+        Self::ConnectionOpenAck,
+        Self::ConnectionOpenConfirm,
+        Self::ChannelOpenInit,
+        Self::ChannelOpenTry,
+        Self::ChannelOpenAck,
+        Self::ChannelOpenConfirm,
+        Self::ChannelCloseInit,
+        Self::ChannelCloseConfirm,
+        Self::RecvPacket,
+        Self::Acknowledgement,
+        Self::Timeout,
+        Self::TimeoutOnClose,
+    ];
+
+    /// Returns the type URL of the messages of this type.
+    pub fn type_url(&self) -> &'static str {
+        match self {
+            Self::CreateClient => CREATE_CLIENT_TYPE_URL,
+            Self::UpdateClient => UPDATE_CLIENT_TYPE_URL,
+            Self::UpgradeClient => UPGRADE_CLIENT_TYPE_URL,
+            Self::SubmitMisbehaviour => SUBMIT_MISBEHAVIOUR_TYPE_URL,
+            Self::RecoverClient => RECOVER_CLIENT_TYPE_URL,
+            Self::ConnectionOpenInit => CONN_OPEN_INIT_TYPE_URL,
+            Self::ConnectionOpenTry => CONN_OPEN_TRY_TYPE_URL,
+            Self::ConnectionOpenAck => CONN_OPEN_ACK_TYPE_URL,
+            Self::ConnectionOpenConfirm => CONN_OPEN_CONFIRM_TYPE_URL,
+            Self::ChannelOpenInit => CHAN_OPEN_INIT_TYPE_URL,
+            Self::ChannelOpenTry => CHAN_OPEN_TRY_TYPE_URL,
+            Self::ChannelOpenAck => CHAN_OPEN_ACK_TYPE_URL,
+            Self::ChannelOpenConfirm => CHAN_OPEN_CONFIRM_TYPE_URL,
+            Self::ChannelCloseInit => CHAN_CLOSE_INIT_TYPE_URL,
+            Self::ChannelCloseConfirm => CHAN_CLOSE_CONFIRM_TYPE_URL,
+            Self::RecvPacket => RECV_PACKET_TYPE_URL,
+            Self::Acknowledgement => ACKNOWLEDGEMENT_TYPE_URL,
+            Self::Timeout => TIMEOUT_TYPE_URL,
+            Self::TimeoutOnClose => TIMEOUT_ON_CLOSE_TYPE_URL,
+        }
+    }
+
+    /// Returns the name of the messages of this type, as reported in the
+    /// spans and metrics of the handler.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::CreateClient => "create_client",
+            Self::UpdateClient => "update_client",
+            Self::UpgradeClient => "upgrade_client",
+            Self::SubmitMisbehaviour => "submit_misbehaviour",
+            Self::RecoverClient => "recover_client",
+            Self::ConnectionOpenInit => "connection_open_init",
+            Self::ConnectionOpenTry => "connection_open_try",
+            Self::ConnectionOpenAck => "connection_open_ack",
+            Self::ConnectionOpenConfirm => "connection_open_confirm",
+            Self::ChannelOpenInit => "channel_open_init",
+            Self::ChannelOpenTry => "channel_open_try",
+            Self::ChannelOpenAck => "channel_open_ack",
+            Self::ChannelOpenConfirm => "channel_open_confirm",
+            Self::ChannelCloseInit => "channel_close_init",
+            Self::ChannelCloseConfirm => "channel_close_confirm",
+            Self::RecvPacket => "recv_packet",
+            Self::Acknowledgement => "acknowledge_packet",
+            Self::Timeout => "timeout_packet",
+            Self::TimeoutOnClose => "timeout_on_close_packet",
+        }
+    }
+
+    /// Returns the type of the messages with the given type URL, if it is
+    /// one of the messages routed by the handler.
+    pub fn from_type_url(type_url: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|msg_type| msg_type.type_url() == type_url)
+    }
+
+    /// Returns the type URLs of all the messages routed by the handler.
+    pub fn supported_type_urls() -> impl Iterator<Item = &'static str> {
+        Self::ALL.into_iter().map(|msg_type| msg_type.type_url())
+    }
+
+    /// Returns the type of the messages whose type URL is a near miss of the
+    /// given unknown one, i.e. only differs from it by the version of its
+    /// package, e.g. `v1beta1` instead of `v1`, by the case of its letters,
+    /// or by a missing leading `/`.
+    pub fn near_miss(type_url: &str) -> Option<Self> {
+        let key = near_miss_key(type_url);
+
+        Self::ALL
+            .into_iter()
+            .find(|msg_type| near_miss_key(msg_type.type_url()) == key)
+    }
+
+    /// Returns `true` for the messages relaying a packet, which are the ones
+    /// carrying a packet.
+    pub fn is_packet(&self) -> bool {
+        matches!(
+            self,
+            Self::RecvPacket | Self::Acknowledgement | Self::Timeout | Self::TimeoutOnClose
+        )
+    }
+}
+
+/// The client message, i.e. the header or the misbehaviour evidence, carried
+/// by a `MsgUpdateClient` or a `MsgSubmitMisbehaviour`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct InspectedClientMessage {
+    /// The identifier of the updated client, as is.
+    pub client_id: String,
+    /// The client message, still encoded as by the client.
+    pub client_message: Any,
+}
+
+/// Returns the [`RouterError::UnknownMessageTypeUrl`] error of the given type
+/// URL, suggesting its [`MsgType::near_miss`], if any.
+pub(crate) fn unknown_type_url(url: String) -> RouterError {
+    let suggestion = MsgType::near_miss(&url).map(|msg_type| msg_type.type_url().to_string());
+
+    RouterError::UnknownMessageTypeUrl {
+        url,
+        suggestion: TypeUrlSuggestion(suggestion),
+    }
+}
+
+/// Returns the type URL without its leading `/` and the versions of its
+/// package, e.g. `v1` or `v1beta1`, and in lowercase, so that near misses of
+/// a type URL share its key.
+fn near_miss_key(type_url: &str) -> String {
+    type_url
+        .trim_start_matches('/')
+        .split('.')
+        .filter(|segment| !is_version_segment(segment))
+        .collect::<Vec<_>>()
+        .join(".")
+        .to_lowercase()
+}
+
+/// Returns `true` for the package versions of protobuf type URLs, such as
+/// `v1`, `v2alpha1` or `v1beta1`.
+fn is_version_segment(segment: &str) -> bool {
+    let Some(version) = segment.strip_prefix('v') else {
+        return false;
+    };
+    let (major, stability) =
+        version.split_at(version.bytes().take_while(u8::is_ascii_digit).count());
+
+    let minor = stability
+        .strip_prefix("alpha")
+        .or_else(|| stability.strip_prefix("beta"));
+
+    !major.is_empty()
+        && match minor {
+            Some(minor) => !minor.is_empty() && minor.bytes().all(|b| b.is_ascii_digit()),
+            None => stability.is_empty(),
+        }
+}
+
+impl MsgEnvelope {
+    /// Returns the type of the message.
+    pub fn msg_type(&self) -> MsgType {
+        match self {
+            Self::Client(msg) => match msg {
+                ClientMsg::CreateClient(_) => MsgType::CreateClient,
+                ClientMsg::UpdateClient(_) => MsgType::UpdateClient,
+                ClientMsg::Misbehaviour(_) => MsgType::SubmitMisbehaviour,
+                ClientMsg::UpgradeClient(_) => MsgType::UpgradeClient,
+                ClientMsg::RecoverClient(_) => MsgType::RecoverClient,
+            },
+            Self::Connection(msg) => match msg {
+                ConnectionMsg::OpenInit(_) => MsgType::ConnectionOpenInit,
+                ConnectionMsg::OpenTry(_) => MsgType::ConnectionOpenTry,
+                ConnectionMsg::OpenAck(_) => MsgType::ConnectionOpenAck,
+                ConnectionMsg::OpenConfirm(_) => MsgType::ConnectionOpenConfirm,
+            },
+            Self::Channel(msg) => match msg {
+                ChannelMsg::OpenInit(_) => MsgType::ChannelOpenInit,
+                ChannelMsg::OpenTry(_) => MsgType::ChannelOpenTry,
+                ChannelMsg::OpenAck(_) => MsgType::ChannelOpenAck,
+                ChannelMsg::OpenConfirm(_) => MsgType::ChannelOpenConfirm,
+                ChannelMsg::CloseInit(_) => MsgType::ChannelCloseInit,
+                ChannelMsg::CloseConfirm(_) => MsgType::ChannelCloseConfirm,
+            },
+            Self::Packet(msg) => match msg {
+                PacketMsg::Recv(_) => MsgType::RecvPacket,
+                PacketMsg::Ack(_) => MsgType::Acknowledgement,
+                PacketMsg::Timeout(_) => MsgType::Timeout,
+                PacketMsg::TimeoutOnClose(_) => MsgType::TimeoutOnClose,
+            },
+        }
+    }
+
+    /// Returns the type of the message wrapped in `any_msg`, from its type
+    /// URL only.
+    pub fn peek_type(any_msg: &Any) -> Result<MsgType, RouterError> {
+        MsgType::from_type_url(&any_msg.type_url)
+            .ok_or_else(|| unknown_type_url(any_msg.type_url.clone()))
+    }
+
+    /// Decodes the packet carried by the message wrapped in `any_msg`, if it
+    /// is a packet message, without validating either of them.
+    pub fn peek_packet(any_msg: &Any) -> Result<Option<RawPacket>, RouterError> {
+        let packet = match Self::peek_type(any_msg)? {
+            MsgType::RecvPacket => decode_raw::<RawMsgRecvPacket>(any_msg)?.packet,
+            MsgType::Acknowledgement => decode_raw::<RawMsgAcknowledgement>(any_msg)?.packet,
+            MsgType::Timeout => decode_raw::<RawMsgTimeout>(any_msg)?.packet,
+            MsgType::TimeoutOnClose => decode_raw::<RawMsgTimeoutOnClose>(any_msg)?.packet,
+            _ => return Ok(None),
+        };
+
+        packet
+            .map(Some)
+            .ok_or_else(|| RouterError::MalformedMessageBytes {
+                reason: "missing packet".to_string(),
+            })
+    }
+
+    /// Decodes the client message carried by the message wrapped in
+    /// `any_msg`, if it is a `MsgUpdateClient` or a `MsgSubmitMisbehaviour`,
+    /// without validating either of them.
+    #[allow(deprecated)]
+    pub fn peek_client_message(
+        any_msg: &Any,
+    ) -> Result<Option<InspectedClientMessage>, RouterError> {
+        let (client_id, client_message) = match Self::peek_type(any_msg)? {
+            MsgType::UpdateClient => {
+                let msg = decode_raw::<RawMsgUpdateClient>(any_msg)?;
+                (msg.client_id, msg.client_message)
+            }
+            MsgType::SubmitMisbehaviour => {
+                let msg = decode_raw::<RawMsgSubmitMisbehaviour>(any_msg)?;
+                (msg.client_id, msg.misbehaviour)
+            }
+            _ => return Ok(None),
+        };
+
+        let client_message = client_message.ok_or_else(|| RouterError::MalformedMessageBytes {
+            reason: "missing client message".to_string(),
+        })?;
+
+        Ok(Some(InspectedClientMessage {
+            client_id,
+            client_message,
+        }))
+    }
+}
+
+/// Decodes the raw message wrapped in `any_msg`, without converting it into
+/// its domain type.
+fn decode_raw<M>(any_msg: &Any) -> Result<M, RouterError>
+where
+    M: Message + Default,
+{
+    M::decode(any_msg.value.as_slice()).map_err(|e| RouterError::MalformedMessageBytes {
+        reason: e.to_string(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn msg_type_from_type_url() {
+        for msg_type in MsgType::ALL {
+            assert_eq!(MsgType::from_type_url(msg_type.type_url()), Some(msg_type));
+        }
+        assert_eq!(MsgType::from_type_url("/cosmos.bank.v1beta1.MsgSend"), None);
+    }
+
+    #[test]
+    fn msg_type_names_are_unique() {
+        let mut names: Vec<_> = MsgType::ALL.iter().map(MsgType::name).collect();
+        names.sort_unstable();
+        names.dedup();
+        assert_eq!(names.len(), MsgType::ALL.len());
+    }
+
+    #[test]
+    fn msg_type_near_miss() {
+        assert_eq!(MsgType::supported_type_urls().count(), MsgType::ALL.len());
+        assert!(MsgType::supported_type_urls().any(|type_url| type_url == RECV_PACKET_TYPE_URL));
+
+        for type_url in [
+            "/ibc.core.channel.v1beta1.MsgRecvPacket",
+            "/ibc.core.channel.v2.MsgRecvPacket",
+            "/ibc.core.channel.v1.MsgRecvpacket",
+            "ibc.core.channel.v1.MsgRecvPacket",
+        ] {
+            assert_eq!(MsgType::near_miss(type_url), Some(MsgType::RecvPacket));
+
+            let any_msg = Any {
+                type_url: type_url.to_string(),
+                value: vec![],
+            };
+            let err = MsgEnvelope::peek_type(&any_msg).unwrap_err();
+            assert_eq!(
+                err.to_string(),
+                format!("unknown type URL `{type_url}`, did you mean `{RECV_PACKET_TYPE_URL}`?")
+            );
+        }
+
+        for type_url in [
+            "/cosmos.bank.v1beta1.MsgSend",
+            "/ibc.core.channel.v1.MsgRecvPackets",
+            "/ibc.core.channel.vbeta1.MsgRecvPacket",
+        ] {
+            assert_eq!(MsgType::near_miss(type_url), None);
+        }
+
+        let any_msg = Any {
+            type_url: "/cosmos.bank.v1beta1.MsgSend".to_string(),
+            value: vec![],
+        };
+        assert_eq!(
+            MsgEnvelope::peek_type(&any_msg).unwrap_err().to_string(),
+            "unknown type URL `/cosmos.bank.v1beta1.MsgSend`"
+        );
+    }
+
+    #[test]
+    fn peek_packet() {
+        let packet = RawPacket {
+            sequence: 1,
+            source_port: "transfer".to_string(),
+            source_channel: "channel-0".to_string(),
+            destination_port: "transfer".to_string(),
+            destination_channel: "channel-1".to_string(),
+            data: b"data".to_vec(),
+            ..Default::default()
+        };
+        // The packet is not validated, and its proof not even decoded.
+        let any_msg = Any {
+            type_url: RECV_PACKET_TYPE_URL.to_string(),
+            value: RawMsgRecvPacket {
+                packet: Some(packet.clone()),
+                proof_commitment: vec![0xff],
+                ..Default::default()
+            }
+            .encode_to_vec(),
+        };
+
+        assert_eq!(
+            MsgEnvelope::peek_type(&any_msg).unwrap(),
+            MsgType::RecvPacket
+        );
+        assert_eq!(MsgEnvelope::peek_packet(&any_msg).unwrap(), Some(packet));
+        assert_eq!(MsgEnvelope::peek_client_message(&any_msg).unwrap(), None);
+
+        let any_msg = Any {
+            type_url: RECV_PACKET_TYPE_URL.to_string(),
+            value: vec![0xff],
+        };
+        assert!(matches!(
+            MsgEnvelope::peek_packet(&any_msg),
+            Err(RouterError::MalformedMessageBytes { .. })
+        ));
+    }
+
+    #[test]
+    fn peek_client_message() {
+        let header = Any {
+            type_url: "/ibc.lightclients.tendermint.v1.Header".to_string(),
+            value: vec![1, 2, 3],
+        };
+        let any_msg = Any {
+            type_url: UPDATE_CLIENT_TYPE_URL.to_string(),
+            value: RawMsgUpdateClient {
+                client_id: "07-tendermint-0".to_string(),
+                client_message: Some(header.clone()),
+                signer: String::new(),
+            }
+            .encode_to_vec(),
+        };
+
+        assert_eq!(
+            MsgEnvelope::peek_client_message(&any_msg).unwrap(),
+            Some(InspectedClientMessage {
+                client_id: "07-tendermint-0".to_string(),
+                client_message: header,
+            })
+        );
+        assert_eq!(MsgEnvelope::peek_packet(&any_msg).unwrap(), None);
+
+        let any_msg = Any {
+            type_url: "/cosmos.bank.v1beta1.MsgSend".to_string(),
+            value: vec![],
+        };
+        assert!(matches!(
+            MsgEnvelope::peek_client_message(&any_msg),
+            Err(RouterError::UnknownMessageTypeUrl { .. })
+        ));
+    }
+}
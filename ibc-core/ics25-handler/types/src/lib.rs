@@ -18,4 +18,5 @@ extern crate std;
 
 pub mod error;
 pub mod events;
+pub mod inspect;
 pub mod msgs;
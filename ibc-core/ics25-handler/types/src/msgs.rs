@@ -9,8 +9,8 @@ use ibc_core_channel_types::msgs::{
 #[allow(deprecated)]
 use ibc_core_client_types::msgs::{
     ClientMsg, MsgCreateClient, MsgSubmitMisbehaviour, MsgUpdateClient, MsgUpgradeClient,
-    CREATE_CLIENT_TYPE_URL, SUBMIT_MISBEHAVIOUR_TYPE_URL, UPDATE_CLIENT_TYPE_URL,
-    UPGRADE_CLIENT_TYPE_URL,
+    CREATE_CLIENT_TYPE_URL, RECOVER_CLIENT_TYPE_URL, SUBMIT_MISBEHAVIOUR_TYPE_URL,
+    UPDATE_CLIENT_TYPE_URL, UPGRADE_CLIENT_TYPE_URL,
 };
 use ibc_core_connection_types::msgs::{
     ConnectionMsg, MsgConnectionOpenAck, MsgConnectionOpenConfirm, MsgConnectionOpenInit,
@@ -19,9 +19,14 @@ use ibc_core_connection_types::msgs::{
 };
 use ibc_core_router_types::error::RouterError;
 use ibc_primitives::prelude::*;
+use ibc_primitives::{DecodingLimits, Msg, Signer, ToVec};
+use ibc_proto::cosmos::tx::v1beta1::TxBody;
 use ibc_proto::google::protobuf::Any;
 use ibc_proto::Protobuf;
 
+use crate::error::ContextError;
+use crate::inspect::unknown_type_url;
+
 /// Enumeration of all messages that the local ICS26 module is capable of routing.
 #[cfg_attr(
     feature = "borsh",
@@ -36,173 +41,316 @@ pub enum MsgEnvelope {
     Packet(PacketMsg),
 }
 
+impl MsgEnvelope {
+    /// Performs the stateless validation of the wrapped message.
+    ///
+    /// This does not require any access to the host state, and is therefore
+    /// suitable to run ahead of the handlers, e.g. during mempool admission
+    /// (`CheckTx`).
+    pub fn validate_basic(&self) -> Result<(), ContextError> {
+        match self {
+            MsgEnvelope::Client(msg) => msg.validate_basic()?,
+            MsgEnvelope::Connection(msg) => msg.validate_basic()?,
+            MsgEnvelope::Channel(msg) => msg.validate_basic()?,
+            MsgEnvelope::Packet(msg) => msg.validate_basic()?,
+        }
+        Ok(())
+    }
+}
+
+impl Msg for MsgEnvelope {
+    fn signer(&self) -> &Signer {
+        match self {
+            MsgEnvelope::Client(msg) => msg.signer(),
+            MsgEnvelope::Connection(msg) => msg.signer(),
+            MsgEnvelope::Channel(msg) => msg.signer(),
+            MsgEnvelope::Packet(msg) => msg.signer(),
+        }
+    }
+}
+
 #[allow(deprecated)]
+impl From<MsgEnvelope> for Any {
+    fn from(msg: MsgEnvelope) -> Self {
+        match msg {
+            MsgEnvelope::Client(ClientMsg::CreateClient(msg)) => Any {
+                type_url: CREATE_CLIENT_TYPE_URL.to_string(),
+                value: msg.encode_vec(),
+            },
+            MsgEnvelope::Client(ClientMsg::UpdateClient(msg)) => Any {
+                type_url: UPDATE_CLIENT_TYPE_URL.to_string(),
+                value: msg.encode_vec(),
+            },
+            MsgEnvelope::Client(ClientMsg::Misbehaviour(msg)) => Any {
+                type_url: SUBMIT_MISBEHAVIOUR_TYPE_URL.to_string(),
+                value: msg.encode_vec(),
+            },
+            MsgEnvelope::Client(ClientMsg::UpgradeClient(msg)) => Any {
+                type_url: UPGRADE_CLIENT_TYPE_URL.to_string(),
+                value: msg.encode_vec(),
+            },
+            MsgEnvelope::Client(ClientMsg::RecoverClient(msg)) => Any {
+                type_url: RECOVER_CLIENT_TYPE_URL.to_string(),
+                value: msg.encode_vec(),
+            },
+            MsgEnvelope::Connection(ConnectionMsg::OpenInit(msg)) => Any {
+                type_url: CONN_OPEN_INIT_TYPE_URL.to_string(),
+                value: msg.encode_vec(),
+            },
+            MsgEnvelope::Connection(ConnectionMsg::OpenTry(msg)) => Any {
+                type_url: CONN_OPEN_TRY_TYPE_URL.to_string(),
+                value: msg.encode_vec(),
+            },
+            MsgEnvelope::Connection(ConnectionMsg::OpenAck(msg)) => Any {
+                type_url: CONN_OPEN_ACK_TYPE_URL.to_string(),
+                value: msg.encode_vec(),
+            },
+            MsgEnvelope::Connection(ConnectionMsg::OpenConfirm(msg)) => Any {
+                type_url: CONN_OPEN_CONFIRM_TYPE_URL.to_string(),
+                value: msg.encode_vec(),
+            },
+            MsgEnvelope::Channel(ChannelMsg::OpenInit(msg)) => Any {
+                type_url: CHAN_OPEN_INIT_TYPE_URL.to_string(),
+                value: msg.encode_vec(),
+            },
+            MsgEnvelope::Channel(ChannelMsg::OpenTry(msg)) => Any {
+                type_url: CHAN_OPEN_TRY_TYPE_URL.to_string(),
+                value: msg.encode_vec(),
+            },
+            MsgEnvelope::Channel(ChannelMsg::OpenAck(msg)) => Any {
+                type_url: CHAN_OPEN_ACK_TYPE_URL.to_string(),
+                value: msg.encode_vec(),
+            },
+            MsgEnvelope::Channel(ChannelMsg::OpenConfirm(msg)) => Any {
+                type_url: CHAN_OPEN_CONFIRM_TYPE_URL.to_string(),
+                value: msg.encode_vec(),
+            },
+            MsgEnvelope::Channel(ChannelMsg::CloseInit(msg)) => Any {
+                type_url: CHAN_CLOSE_INIT_TYPE_URL.to_string(),
+                value: msg.encode_vec(),
+            },
+            MsgEnvelope::Channel(ChannelMsg::CloseConfirm(msg)) => Any {
+                type_url: CHAN_CLOSE_CONFIRM_TYPE_URL.to_string(),
+                value: msg.encode_vec(),
+            },
+            MsgEnvelope::Packet(PacketMsg::Recv(msg)) => Any {
+                type_url: RECV_PACKET_TYPE_URL.to_string(),
+                value: msg.encode_vec(),
+            },
+            MsgEnvelope::Packet(PacketMsg::Ack(msg)) => Any {
+                type_url: ACKNOWLEDGEMENT_TYPE_URL.to_string(),
+                value: msg.encode_vec(),
+            },
+            MsgEnvelope::Packet(PacketMsg::Timeout(msg)) => Any {
+                type_url: TIMEOUT_TYPE_URL.to_string(),
+                value: msg.encode_vec(),
+            },
+            MsgEnvelope::Packet(PacketMsg::TimeoutOnClose(msg)) => Any {
+                type_url: TIMEOUT_ON_CLOSE_TYPE_URL.to_string(),
+                value: msg.encode_vec(),
+            },
+        }
+    }
+}
+
+/// Encodes the given messages into the bytes of a Cosmos SDK transaction body
+/// (`cosmos.tx.v1beta1.TxBody`), which is the part of the transaction
+/// committed to by the signature.
+pub fn encode_tx_bytes<I>(msgs: I, memo: String) -> Vec<u8>
+where
+    I: IntoIterator<Item = MsgEnvelope>,
+{
+    TxBody {
+        messages: msgs.into_iter().map(Any::from).collect(),
+        memo,
+        ..Default::default()
+    }
+    .to_vec()
+}
+
+impl MsgEnvelope {
+    /// Decodes the message wrapped in `any_msg`, once checked against the
+    /// given decoding `limits`, which guard the host against oversized or
+    /// deeply nested messages submitted by untrusted relayers.
+    ///
+    /// The `TryFrom<Any>` conversion checks messages against the default
+    /// [`DecodingLimits`].
+    pub fn decode_with_limits(any_msg: Any, limits: &DecodingLimits) -> Result<Self, RouterError> {
+        limits
+            .check_any(&any_msg)
+            .map_err(|error| RouterError::DecodingLimitExceeded {
+                type_url: any_msg.type_url.clone(),
+                error,
+            })?;
+
+        decode_msg(any_msg)
+    }
+}
+
 impl TryFrom<Any> for MsgEnvelope {
     type Error = RouterError;
 
     fn try_from(any_msg: Any) -> Result<Self, Self::Error> {
-        match any_msg.type_url.as_str() {
-            // ICS2 messages
-            CREATE_CLIENT_TYPE_URL => {
-                // Pop out the message and then wrap it in the corresponding type.
-                let domain_msg = MsgCreateClient::decode_vec(&any_msg.value).map_err(|e| {
-                    RouterError::MalformedMessageBytes {
-                        reason: e.to_string(),
-                    }
-                })?;
-                Ok(MsgEnvelope::Client(ClientMsg::CreateClient(domain_msg)))
-            }
-            UPDATE_CLIENT_TYPE_URL => {
-                let domain_msg = MsgUpdateClient::decode_vec(&any_msg.value).map_err(|e| {
-                    RouterError::MalformedMessageBytes {
-                        reason: e.to_string(),
-                    }
-                })?;
-                Ok(MsgEnvelope::Client(ClientMsg::UpdateClient(domain_msg)))
-            }
-            UPGRADE_CLIENT_TYPE_URL => {
-                let domain_msg = MsgUpgradeClient::decode_vec(&any_msg.value).map_err(|e| {
-                    RouterError::MalformedMessageBytes {
-                        reason: e.to_string(),
-                    }
-                })?;
-                Ok(MsgEnvelope::Client(ClientMsg::UpgradeClient(domain_msg)))
-            }
-            SUBMIT_MISBEHAVIOUR_TYPE_URL => {
-                let domain_msg =
-                    MsgSubmitMisbehaviour::decode_vec(&any_msg.value).map_err(|e| {
-                        RouterError::MalformedMessageBytes {
-                            reason: e.to_string(),
-                        }
-                    })?;
-                Ok(MsgEnvelope::Client(ClientMsg::Misbehaviour(domain_msg)))
-            }
+        Self::decode_with_limits(any_msg, &DecodingLimits::default())
+    }
+}
+
+#[allow(deprecated)]
+fn decode_msg(any_msg: Any) -> Result<MsgEnvelope, RouterError> {
+    match any_msg.type_url.as_str() {
+        // ICS2 messages
+        CREATE_CLIENT_TYPE_URL => {
+            // Pop out the message and then wrap it in the corresponding type.
+            let domain_msg = MsgCreateClient::decode_vec(&any_msg.value).map_err(|e| {
+                RouterError::MalformedMessageBytes {
+                    reason: e.to_string(),
+                }
+            })?;
+            Ok(MsgEnvelope::Client(ClientMsg::CreateClient(domain_msg)))
+        }
+        UPDATE_CLIENT_TYPE_URL => {
+            let domain_msg = MsgUpdateClient::decode_vec(&any_msg.value).map_err(|e| {
+                RouterError::MalformedMessageBytes {
+                    reason: e.to_string(),
+                }
+            })?;
+            Ok(MsgEnvelope::Client(ClientMsg::UpdateClient(domain_msg)))
+        }
+        UPGRADE_CLIENT_TYPE_URL => {
+            let domain_msg = MsgUpgradeClient::decode_vec(&any_msg.value).map_err(|e| {
+                RouterError::MalformedMessageBytes {
+                    reason: e.to_string(),
+                }
+            })?;
+            Ok(MsgEnvelope::Client(ClientMsg::UpgradeClient(domain_msg)))
+        }
+        SUBMIT_MISBEHAVIOUR_TYPE_URL => {
+            let domain_msg = MsgSubmitMisbehaviour::decode_vec(&any_msg.value).map_err(|e| {
+                RouterError::MalformedMessageBytes {
+                    reason: e.to_string(),
+                }
+            })?;
+            Ok(MsgEnvelope::Client(ClientMsg::Misbehaviour(domain_msg)))
+        }
 
-            // ICS03
-            CONN_OPEN_INIT_TYPE_URL => {
-                let domain_msg =
-                    MsgConnectionOpenInit::decode_vec(&any_msg.value).map_err(|e| {
-                        RouterError::MalformedMessageBytes {
-                            reason: e.to_string(),
-                        }
-                    })?;
-                Ok(MsgEnvelope::Connection(ConnectionMsg::OpenInit(domain_msg)))
-            }
-            CONN_OPEN_TRY_TYPE_URL => {
-                let domain_msg = MsgConnectionOpenTry::decode_vec(&any_msg.value).map_err(|e| {
-                    RouterError::MalformedMessageBytes {
-                        reason: e.to_string(),
-                    }
-                })?;
-                Ok(MsgEnvelope::Connection(ConnectionMsg::OpenTry(domain_msg)))
-            }
-            CONN_OPEN_ACK_TYPE_URL => {
-                let domain_msg = MsgConnectionOpenAck::decode_vec(&any_msg.value).map_err(|e| {
-                    RouterError::MalformedMessageBytes {
-                        reason: e.to_string(),
-                    }
-                })?;
-                Ok(MsgEnvelope::Connection(ConnectionMsg::OpenAck(domain_msg)))
-            }
-            CONN_OPEN_CONFIRM_TYPE_URL => {
-                let domain_msg =
-                    MsgConnectionOpenConfirm::decode_vec(&any_msg.value).map_err(|e| {
-                        RouterError::MalformedMessageBytes {
-                            reason: e.to_string(),
-                        }
-                    })?;
-                Ok(MsgEnvelope::Connection(ConnectionMsg::OpenConfirm(
-                    domain_msg,
-                )))
-            }
+        // ICS03
+        CONN_OPEN_INIT_TYPE_URL => {
+            let domain_msg = MsgConnectionOpenInit::decode_vec(&any_msg.value).map_err(|e| {
+                RouterError::MalformedMessageBytes {
+                    reason: e.to_string(),
+                }
+            })?;
+            Ok(MsgEnvelope::Connection(ConnectionMsg::OpenInit(domain_msg)))
+        }
+        CONN_OPEN_TRY_TYPE_URL => {
+            let domain_msg = MsgConnectionOpenTry::decode_vec(&any_msg.value).map_err(|e| {
+                RouterError::MalformedMessageBytes {
+                    reason: e.to_string(),
+                }
+            })?;
+            Ok(MsgEnvelope::Connection(ConnectionMsg::OpenTry(domain_msg)))
+        }
+        CONN_OPEN_ACK_TYPE_URL => {
+            let domain_msg = MsgConnectionOpenAck::decode_vec(&any_msg.value).map_err(|e| {
+                RouterError::MalformedMessageBytes {
+                    reason: e.to_string(),
+                }
+            })?;
+            Ok(MsgEnvelope::Connection(ConnectionMsg::OpenAck(domain_msg)))
+        }
+        CONN_OPEN_CONFIRM_TYPE_URL => {
+            let domain_msg = MsgConnectionOpenConfirm::decode_vec(&any_msg.value).map_err(|e| {
+                RouterError::MalformedMessageBytes {
+                    reason: e.to_string(),
+                }
+            })?;
+            Ok(MsgEnvelope::Connection(ConnectionMsg::OpenConfirm(
+                domain_msg,
+            )))
+        }
 
-            // ICS04 channel messages
-            CHAN_OPEN_INIT_TYPE_URL => {
-                let domain_msg = MsgChannelOpenInit::decode_vec(&any_msg.value).map_err(|e| {
-                    RouterError::MalformedMessageBytes {
-                        reason: e.to_string(),
-                    }
-                })?;
-                Ok(MsgEnvelope::Channel(ChannelMsg::OpenInit(domain_msg)))
-            }
-            CHAN_OPEN_TRY_TYPE_URL => {
-                let domain_msg = MsgChannelOpenTry::decode_vec(&any_msg.value).map_err(|e| {
-                    RouterError::MalformedMessageBytes {
-                        reason: e.to_string(),
-                    }
-                })?;
-                Ok(MsgEnvelope::Channel(ChannelMsg::OpenTry(domain_msg)))
-            }
-            CHAN_OPEN_ACK_TYPE_URL => {
-                let domain_msg = MsgChannelOpenAck::decode_vec(&any_msg.value).map_err(|e| {
-                    RouterError::MalformedMessageBytes {
-                        reason: e.to_string(),
-                    }
-                })?;
-                Ok(MsgEnvelope::Channel(ChannelMsg::OpenAck(domain_msg)))
-            }
-            CHAN_OPEN_CONFIRM_TYPE_URL => {
-                let domain_msg =
-                    MsgChannelOpenConfirm::decode_vec(&any_msg.value).map_err(|e| {
-                        RouterError::MalformedMessageBytes {
-                            reason: e.to_string(),
-                        }
-                    })?;
-                Ok(MsgEnvelope::Channel(ChannelMsg::OpenConfirm(domain_msg)))
-            }
-            CHAN_CLOSE_INIT_TYPE_URL => {
-                let domain_msg = MsgChannelCloseInit::decode_vec(&any_msg.value).map_err(|e| {
-                    RouterError::MalformedMessageBytes {
-                        reason: e.to_string(),
-                    }
-                })?;
-                Ok(MsgEnvelope::Channel(ChannelMsg::CloseInit(domain_msg)))
-            }
-            CHAN_CLOSE_CONFIRM_TYPE_URL => {
-                let domain_msg =
-                    MsgChannelCloseConfirm::decode_vec(&any_msg.value).map_err(|e| {
-                        RouterError::MalformedMessageBytes {
-                            reason: e.to_string(),
-                        }
-                    })?;
-                Ok(MsgEnvelope::Channel(ChannelMsg::CloseConfirm(domain_msg)))
-            }
-            // ICS04 packet messages
-            RECV_PACKET_TYPE_URL => {
-                let domain_msg = MsgRecvPacket::decode_vec(&any_msg.value).map_err(|e| {
-                    RouterError::MalformedMessageBytes {
-                        reason: e.to_string(),
-                    }
-                })?;
-                Ok(MsgEnvelope::Packet(PacketMsg::Recv(domain_msg)))
-            }
-            ACKNOWLEDGEMENT_TYPE_URL => {
-                let domain_msg = MsgAcknowledgement::decode_vec(&any_msg.value).map_err(|e| {
-                    RouterError::MalformedMessageBytes {
-                        reason: e.to_string(),
-                    }
-                })?;
-                Ok(MsgEnvelope::Packet(PacketMsg::Ack(domain_msg)))
-            }
-            TIMEOUT_TYPE_URL => {
-                let domain_msg = MsgTimeout::decode_vec(&any_msg.value).map_err(|e| {
-                    RouterError::MalformedMessageBytes {
-                        reason: e.to_string(),
-                    }
-                })?;
-                Ok(MsgEnvelope::Packet(PacketMsg::Timeout(domain_msg)))
-            }
-            TIMEOUT_ON_CLOSE_TYPE_URL => {
-                let domain_msg = MsgTimeoutOnClose::decode_vec(&any_msg.value).map_err(|e| {
-                    RouterError::MalformedMessageBytes {
-                        reason: e.to_string(),
-                    }
-                })?;
-                Ok(MsgEnvelope::Packet(PacketMsg::TimeoutOnClose(domain_msg)))
-            }
-            _ => Err(RouterError::UnknownMessageTypeUrl {
-                url: any_msg.type_url,
-            }),
+        // ICS04 channel messages
+        CHAN_OPEN_INIT_TYPE_URL => {
+            let domain_msg = MsgChannelOpenInit::decode_vec(&any_msg.value).map_err(|e| {
+                RouterError::MalformedMessageBytes {
+                    reason: e.to_string(),
+                }
+            })?;
+            Ok(MsgEnvelope::Channel(ChannelMsg::OpenInit(domain_msg)))
+        }
+        CHAN_OPEN_TRY_TYPE_URL => {
+            let domain_msg = MsgChannelOpenTry::decode_vec(&any_msg.value).map_err(|e| {
+                RouterError::MalformedMessageBytes {
+                    reason: e.to_string(),
+                }
+            })?;
+            Ok(MsgEnvelope::Channel(ChannelMsg::OpenTry(domain_msg)))
+        }
+        CHAN_OPEN_ACK_TYPE_URL => {
+            let domain_msg = MsgChannelOpenAck::decode_vec(&any_msg.value).map_err(|e| {
+                RouterError::MalformedMessageBytes {
+                    reason: e.to_string(),
+                }
+            })?;
+            Ok(MsgEnvelope::Channel(ChannelMsg::OpenAck(domain_msg)))
+        }
+        CHAN_OPEN_CONFIRM_TYPE_URL => {
+            let domain_msg = MsgChannelOpenConfirm::decode_vec(&any_msg.value).map_err(|e| {
+                RouterError::MalformedMessageBytes {
+                    reason: e.to_string(),
+                }
+            })?;
+            Ok(MsgEnvelope::Channel(ChannelMsg::OpenConfirm(domain_msg)))
+        }
+        CHAN_CLOSE_INIT_TYPE_URL => {
+            let domain_msg = MsgChannelCloseInit::decode_vec(&any_msg.value).map_err(|e| {
+                RouterError::MalformedMessageBytes {
+                    reason: e.to_string(),
+                }
+            })?;
+            Ok(MsgEnvelope::Channel(ChannelMsg::CloseInit(domain_msg)))
+        }
+        CHAN_CLOSE_CONFIRM_TYPE_URL => {
+            let domain_msg = MsgChannelCloseConfirm::decode_vec(&any_msg.value).map_err(|e| {
+                RouterError::MalformedMessageBytes {
+                    reason: e.to_string(),
+                }
+            })?;
+            Ok(MsgEnvelope::Channel(ChannelMsg::CloseConfirm(domain_msg)))
+        }
+        // ICS04 packet messages
+        RECV_PACKET_TYPE_URL => {
+            let domain_msg = MsgRecvPacket::decode_vec(&any_msg.value).map_err(|e| {
+                RouterError::MalformedMessageBytes {
+                    reason: e.to_string(),
+                }
+            })?;
+            Ok(MsgEnvelope::Packet(PacketMsg::Recv(domain_msg)))
+        }
+        ACKNOWLEDGEMENT_TYPE_URL => {
+            let domain_msg = MsgAcknowledgement::decode_vec(&any_msg.value).map_err(|e| {
+                RouterError::MalformedMessageBytes {
+                    reason: e.to_string(),
+                }
+            })?;
+            Ok(MsgEnvelope::Packet(PacketMsg::Ack(domain_msg)))
+        }
+        TIMEOUT_TYPE_URL => {
+            let domain_msg = MsgTimeout::decode_vec(&any_msg.value).map_err(|e| {
+                RouterError::MalformedMessageBytes {
+                    reason: e.to_string(),
+                }
+            })?;
+            Ok(MsgEnvelope::Packet(PacketMsg::Timeout(domain_msg)))
+        }
+        TIMEOUT_ON_CLOSE_TYPE_URL => {
+            let domain_msg = MsgTimeoutOnClose::decode_vec(&any_msg.value).map_err(|e| {
+                RouterError::MalformedMessageBytes {
+                    reason: e.to_string(),
+                }
+            })?;
+            Ok(MsgEnvelope::Packet(PacketMsg::TimeoutOnClose(domain_msg)))
         }
+        _ => Err(unknown_type_url(any_msg.type_url)),
     }
 }
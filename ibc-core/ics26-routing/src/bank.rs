@@ -0,0 +1,36 @@
+//! Defines the [`BankContext`] trait, through which IBC applications move the
+//! assets they hold on behalf of their users.
+//!
+//! ICS-20 and ICS-29 move fungible tokens, while ICS-721 moves non-fungible
+//! ones, each of them through its own context. Hosts may instead implement
+//! the movement of each kind of asset once, as a [`BankContext`], and
+//! implement the token movements of each application on top of it with the
+//! helpers the applications provide, e.g. escrowing tokens into the
+//! [`ModuleAccount`] of a channel.
+
+use ibc_core_router_types::error::BankError;
+use ibc_core_router_types::module::ModuleAccount;
+
+/// Moves the assets of type `Asset` between accounts, including the accounts
+/// owned by IBC applications.
+pub trait BankContext<Asset> {
+    type AccountId;
+
+    /// Returns the account of the host standing for the given account of an
+    /// IBC application.
+    fn module_account(&self, account: &ModuleAccount) -> Result<Self::AccountId, BankError>;
+
+    /// Creates `asset` into `account`.
+    fn mint_to(&mut self, account: &Self::AccountId, asset: &Asset) -> Result<(), BankError>;
+
+    /// Destroys `asset`, which `account` holds.
+    fn burn_from(&mut self, account: &Self::AccountId, asset: &Asset) -> Result<(), BankError>;
+
+    /// Moves `asset`, which `from` holds, into `to`.
+    fn transfer(
+        &mut self,
+        from: &Self::AccountId,
+        to: &Self::AccountId,
+        asset: &Asset,
+    ) -> Result<(), BankError>;
+}
@@ -0,0 +1,155 @@
+//! Defines the `PacketInterceptor`, through which hosts observe the execution
+//! of the packet messages routed to the modules, e.g. to log or meter them.
+//!
+//! Unlike middleware modules, which wrap a module to change its behaviour,
+//! interceptors only observe the callbacks, and cannot affect the outcome of
+//! the messages. They are thus fit for features which are not part of the
+//! consensus, and which may differ between the nodes of a chain.
+
+use ibc_core_channel_types::acknowledgement::Acknowledgement;
+use ibc_core_channel_types::packet::Packet;
+use ibc_core_router_types::module::ModuleId;
+use ibc_primitives::prelude::*;
+
+/// Called around the execution of the packet messages whose callbacks are
+/// routed to a module, along with the identifier of this module.
+///
+/// The hooks wrap the execution of the whole message by the `execute` and
+/// `dispatch` entrypoints rather than the callback alone: the `before_*` hook
+/// is called before the core handler runs, and the `after_*` hook once the
+/// message is executed, `succeeded` telling whether its execution, including
+/// the checks of the core handler, succeeded. The callback itself may thus
+/// not have run, e.g. when receiving a packet which was already received.
+///
+/// The validation of the messages, including the `*_validate` callbacks of
+/// the modules, is not intercepted.
+///
+/// The hooks are given `&self` as the router only lends the interceptor
+/// immutably, and do nothing unless overridden.
+pub trait PacketInterceptor {
+    /// Called before the message receiving `packet` is executed.
+    fn before_recv_packet(&self, _module_id: &ModuleId, _packet: &Packet) {}
+
+    /// Called once the message receiving `packet` is executed.
+    fn after_recv_packet(&self, _module_id: &ModuleId, _packet: &Packet, _succeeded: bool) {}
+
+    /// Called before the message acknowledging `packet` is executed.
+    fn before_acknowledgement_packet(
+        &self,
+        _module_id: &ModuleId,
+        _packet: &Packet,
+        _acknowledgement: &Acknowledgement,
+    ) {
+    }
+
+    /// Called once the message acknowledging `packet` is executed.
+    fn after_acknowledgement_packet(
+        &self,
+        _module_id: &ModuleId,
+        _packet: &Packet,
+        _acknowledgement: &Acknowledgement,
+        _succeeded: bool,
+    ) {
+    }
+
+    /// Called before the message timing out `packet` is executed, including
+    /// on the closure of its channel.
+    fn before_timeout_packet(&self, _module_id: &ModuleId, _packet: &Packet) {}
+
+    /// Called once the message timing out `packet` is executed.
+    fn after_timeout_packet(&self, _module_id: &ModuleId, _packet: &Packet, _succeeded: bool) {}
+}
+
+/// Calls the interceptors in turn, and in the reverse order after the
+/// callbacks, so that the first interceptor wraps all the others.
+impl<T> PacketInterceptor for Vec<T>
+where
+    T: PacketInterceptor,
+{
+    fn before_recv_packet(&self, module_id: &ModuleId, packet: &Packet) {
+        for interceptor in self {
+            interceptor.before_recv_packet(module_id, packet);
+        }
+    }
+
+    fn after_recv_packet(&self, module_id: &ModuleId, packet: &Packet, succeeded: bool) {
+        for interceptor in self.iter().rev() {
+            interceptor.after_recv_packet(module_id, packet, succeeded);
+        }
+    }
+
+    fn before_acknowledgement_packet(
+        &self,
+        module_id: &ModuleId,
+        packet: &Packet,
+        acknowledgement: &Acknowledgement,
+    ) {
+        for interceptor in self {
+            interceptor.before_acknowledgement_packet(module_id, packet, acknowledgement);
+        }
+    }
+
+    fn after_acknowledgement_packet(
+        &self,
+        module_id: &ModuleId,
+        packet: &Packet,
+        acknowledgement: &Acknowledgement,
+        succeeded: bool,
+    ) {
+        for interceptor in self.iter().rev() {
+            interceptor.after_acknowledgement_packet(module_id, packet, acknowledgement, succeeded);
+        }
+    }
+
+    fn before_timeout_packet(&self, module_id: &ModuleId, packet: &Packet) {
+        for interceptor in self {
+            interceptor.before_timeout_packet(module_id, packet);
+        }
+    }
+
+    fn after_timeout_packet(&self, module_id: &ModuleId, packet: &Packet, succeeded: bool) {
+        for interceptor in self.iter().rev() {
+            interceptor.after_timeout_packet(module_id, packet, succeeded);
+        }
+    }
+}
+
+impl<T> PacketInterceptor for Box<T>
+where
+    T: PacketInterceptor + ?Sized,
+{
+    fn before_recv_packet(&self, module_id: &ModuleId, packet: &Packet) {
+        (**self).before_recv_packet(module_id, packet)
+    }
+
+    fn after_recv_packet(&self, module_id: &ModuleId, packet: &Packet, succeeded: bool) {
+        (**self).after_recv_packet(module_id, packet, succeeded)
+    }
+
+    fn before_acknowledgement_packet(
+        &self,
+        module_id: &ModuleId,
+        packet: &Packet,
+        acknowledgement: &Acknowledgement,
+    ) {
+        (**self).before_acknowledgement_packet(module_id, packet, acknowledgement)
+    }
+
+    fn after_acknowledgement_packet(
+        &self,
+        module_id: &ModuleId,
+        packet: &Packet,
+        acknowledgement: &Acknowledgement,
+        succeeded: bool,
+    ) {
+        (**self).after_acknowledgement_packet(module_id, packet, acknowledgement, succeeded)
+    }
+
+    fn before_timeout_packet(&self, module_id: &ModuleId, packet: &Packet) {
+        (**self).before_timeout_packet(module_id, packet)
+    }
+
+    fn after_timeout_packet(&self, module_id: &ModuleId, packet: &Packet, succeeded: bool) {
+        (**self).after_timeout_packet(module_id, packet, succeeded)
+    }
+}
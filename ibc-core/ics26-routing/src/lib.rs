@@ -14,6 +14,9 @@
 #[cfg(feature = "std")]
 extern crate std;
 
+pub mod bank;
+pub mod interceptor;
+pub mod metrics;
 pub mod module;
 pub mod router;
 
@@ -0,0 +1,60 @@
+//! Defines the `MetricsSink`, through which hosts collect metrics on the
+//! handling of IBC messages.
+
+use core::time::Duration;
+
+use ibc_core_channel_types::packet::Packet;
+
+/// The stage of the handling of a message.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum HandlerStage {
+    Validate,
+    Execute,
+}
+
+/// The steps of the lifecycle of a packet observed by the handlers.
+///
+/// Packets are sent by applications, outside of the handlers; hosts can
+/// measure the duration of the lifecycle of a packet by correlating these
+/// steps with the `SendPacket` events emitted when sending it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PacketLifecycleStep {
+    /// The packet was received on its destination chain.
+    Received,
+    /// The acknowledgement of the packet was processed on its source chain.
+    Acknowledged,
+    /// The timeout of the packet was processed on its source chain.
+    TimedOut,
+}
+
+/// Receives metrics on the handling of IBC messages, independently of the
+/// metrics backend used by the host.
+///
+/// The sink is returned by [`Router::metrics_sink`](crate::router::Router::metrics_sink)
+/// and only borrowed immutably, as counters and histograms, such as those of
+/// the `prometheus` crate, are updated through `&self`. Metrics which are not
+/// overridden are not collected.
+pub trait MetricsSink {
+    /// Returns the current time, as given by a monotonic clock of the host.
+    ///
+    /// Latencies are only reported when this returns `Some`, which it does not
+    /// by default.
+    fn now(&self) -> Option<Duration> {
+        None
+    }
+
+    /// Called once a message of type `msg_type` went through the given
+    /// `stage`, whether it succeeded or not.
+    fn message_processed(&self, _msg_type: &'static str, _stage: HandlerStage, _succeeded: bool) {}
+
+    /// Called with the time taken to validate a message of type `msg_type`,
+    /// whether it succeeded or not.
+    ///
+    /// This covers the whole validation of the message, i.e. the verification
+    /// of the proofs it carries, if any, along with the reads of the host state
+    /// and the callbacks of the applications.
+    fn validation_latency(&self, _msg_type: &'static str, _latency: Duration) {}
+
+    /// Called once a packet went through the given `step` of its lifecycle.
+    fn packet_lifecycle_step(&self, _step: PacketLifecycleStep, _packet: &Packet) {}
+}
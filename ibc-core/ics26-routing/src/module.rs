@@ -4,13 +4,70 @@ use core::fmt::Debug;
 use ibc_core_channel_types::acknowledgement::Acknowledgement;
 use ibc_core_channel_types::channel::{Counterparty, Order};
 use ibc_core_channel_types::error::{ChannelError, PacketError};
-use ibc_core_channel_types::packet::Packet;
+use ibc_core_channel_types::packet::{OutgoingPacket, Packet};
 use ibc_core_channel_types::Version;
 use ibc_core_host_types::identifiers::{ChannelId, ConnectionId, PortId};
+use ibc_core_router_types::error::AppError;
 use ibc_core_router_types::module::ModuleExtras;
 use ibc_primitives::prelude::*;
 use ibc_primitives::Signer;
 
+/// The outcome of the [`Module::on_recv_packet_execute`] callback.
+#[derive(Clone, Debug)]
+pub struct OnRecvPacketResult {
+    /// The acknowledgement to write for the received packet, which is either a
+    /// success or an error acknowledgement.
+    ///
+    /// `None` signals that the application will acknowledge the packet
+    /// asynchronously, in which case the core handler does not write any
+    /// acknowledgement. The application must then later write it with
+    /// `write_acknowledgement`.
+    pub ack: Option<Acknowledgement>,
+    /// The events and logs produced by the callback.
+    pub extras: ModuleExtras,
+    /// The packets the application sends in response to the received packet,
+    /// e.g. to forward it to another chain.
+    ///
+    /// Callbacks must not reenter the core handlers, e.g. by sending packets
+    /// through a handle to the host context they keep, which would interleave
+    /// with the state changes of the packet being received. They return the
+    /// packets to send instead, which the core handler sends, in order, once
+    /// done with the received packet, each with the next sequence to send on
+    /// its channel. Failing to send any of them fails the whole message.
+    pub packets: Vec<OutgoingPacket>,
+}
+
+impl OnRecvPacketResult {
+    /// Acknowledges the packet synchronously with the given acknowledgement.
+    pub fn sync(extras: ModuleExtras, ack: Acknowledgement) -> Self {
+        Self {
+            ack: Some(ack),
+            extras,
+            packets: Vec::new(),
+        }
+    }
+
+    /// Defers the acknowledgement of the packet to a later point.
+    pub fn async_ack(extras: ModuleExtras) -> Self {
+        Self {
+            ack: None,
+            extras,
+            packets: Vec::new(),
+        }
+    }
+
+    /// Sends the given packets in response to the received packet.
+    pub fn with_packets(self, packets: Vec<OutgoingPacket>) -> Self {
+        Self { packets, ..self }
+    }
+}
+
+impl From<(ModuleExtras, Acknowledgement)> for OnRecvPacketResult {
+    fn from((extras, ack): (ModuleExtras, Acknowledgement)) -> Self {
+        Self::sync(extras, ack)
+    }
+}
+
 pub trait Module: Debug {
     fn on_chan_open_init_validate(
         &self,
@@ -119,15 +176,26 @@ pub trait Module: Debug {
     }
 
     // Note: no `on_recv_packet_validate()`
-    // the `onRecvPacket` callback always succeeds
+    // the `onRecvPacket` callback never fails the transaction
     // if any error occurs, than an "error acknowledgement"
-    // must be returned
-
+    // is written instead
+
+    /// Processes the received packet, returning the acknowledgement to write
+    /// for it, if any. See [`OnRecvPacketResult`].
+    ///
+    /// Returning an [`AppError`] does not revert the transaction: the core
+    /// handler writes a standardized error acknowledgement carrying the ABCI
+    /// error code, with the error description included only if the router
+    /// does not [redact](crate::router::Router::redact_error_acknowledgements)
+    /// it. The core handler cannot discard the state changes of the
+    /// application, which must therefore be atomic: it either commits all
+    /// of its writes and succeeds, or none of them and fails, e.g. by staging
+    /// its writes in a cache it only flushes on success.
     fn on_recv_packet_execute(
         &mut self,
         packet: &Packet,
         relayer: &Signer,
-    ) -> (ModuleExtras, Acknowledgement);
+    ) -> Result<OnRecvPacketResult, AppError>;
 
     fn on_acknowledgement_packet_validate(
         &self,
@@ -3,9 +3,16 @@
 use ibc_core_host_types::identifiers::PortId;
 use ibc_core_router_types::module::ModuleId;
 
+use crate::interceptor::PacketInterceptor;
+use crate::metrics::MetricsSink;
 use crate::module::Module;
 
 /// Router as defined in ICS-26, which binds modules to ports.
+///
+/// The trait is object safe, and the entry points accept `dyn Router`. Since
+/// routes are only borrowed immutably on validation, a router which is `Sync`,
+/// such as one holding `Send + Sync` modules, can be shared by threads
+/// validating messages concurrently.
 pub trait Router {
     /// Returns a reference to a `Module` registered against the specified `ModuleId`
     fn get_route(&self, module_id: &ModuleId) -> Option<&dyn Module>;
@@ -15,4 +22,29 @@ pub trait Router {
 
     /// Return the module_id associated with a given port_id
     fn lookup_module(&self, port_id: &PortId) -> Option<ModuleId>;
+
+    /// Returns whether the error acknowledgements written on behalf of
+    /// failing `on_recv_packet_execute` callbacks omit the error description,
+    /// disclosing the ABCI error code only.
+    ///
+    /// Defaults to `true`, matching ibc-go. Error descriptions are not
+    /// guaranteed to be deterministic, so they should only be disclosed on
+    /// chains, such as testnets, where this is not a concern.
+    fn redact_error_acknowledgements(&self) -> bool {
+        true
+    }
+
+    /// Returns the sink receiving metrics on the handling of messages, if
+    /// any. Defaults to `None`.
+    fn metrics_sink(&self) -> Option<&dyn MetricsSink> {
+        None
+    }
+
+    /// Returns the interceptor called around the execution of the packet
+    /// messages routed to the modules, if any. Defaults to `None`.
+    ///
+    /// Several interceptors may be registered at once as a `Vec` of them.
+    fn packet_interceptor(&self) -> Option<&dyn PacketInterceptor> {
+        None
+    }
 }
@@ -0,0 +1,19 @@
+//! Defines the [`PacketDataCodec`] trait, through which IBC applications
+//! encode the data of the packets they send, and decode the data of the
+//! packets they receive, acknowledge or time out.
+
+use ibc_primitives::prelude::*;
+
+/// Encodes and decodes the packet data `T` of an IBC application.
+///
+/// Applications default to the encoding of their specification, typically
+/// JSON, and let hosts swap it for another one, e.g. protobuf, without forking
+/// them. Both ends of a channel must of course agree on the codec in use.
+pub trait PacketDataCodec<T> {
+    /// Encodes `data` into the bytes carried by a packet.
+    fn encode(&self, data: &T) -> Vec<u8>;
+
+    /// Decodes the bytes carried by a packet, returning `None` if they are
+    /// not a valid encoding of `T`.
+    fn decode(&self, bytes: &[u8]) -> Option<T>;
+}
@@ -1,19 +1,116 @@
+use core::fmt::{Display as FmtDisplay, Error as FmtError, Formatter};
+
 use displaydoc::Display;
 use ibc_core_host_types::identifiers::PortId;
 use ibc_primitives::prelude::*;
+use ibc_primitives::{AbciError, DecodingLimitError};
 
 /// Error type for the router module.
 #[derive(Debug, Display)]
 pub enum RouterError {
-    /// unknown type URL `{url}`
-    UnknownMessageTypeUrl { url: String },
+    /// unknown type URL `{url}`{suggestion}
+    UnknownMessageTypeUrl {
+        url: String,
+        suggestion: TypeUrlSuggestion,
+    },
     /// the message is malformed and cannot be decoded error: `{reason}`
     MalformedMessageBytes { reason: String },
+    /// message of type URL `{type_url}` exceeds the decoding limits: {error}
+    DecodingLimitExceeded {
+        type_url: String,
+        error: DecodingLimitError,
+    },
     /// port `{port_id}` is unknown
     UnknownPort { port_id: PortId },
     /// module not found
     ModuleNotFound,
 }
 
+impl AbciError for RouterError {
+    fn codespace(&self) -> &'static str {
+        "routing"
+    }
+
+    fn code(&self) -> u32 {
+        match self {
+            Self::UnknownMessageTypeUrl { .. } => 2,
+            Self::MalformedMessageBytes { .. } => 3,
+            Self::DecodingLimitExceeded { .. } => 4,
+            Self::UnknownPort { .. } => 5,
+            Self::ModuleNotFound => 6,
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for RouterError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match &self {
+            Self::DecodingLimitExceeded { error, .. } => Some(error),
+            _ => None,
+        }
+    }
+}
+
+/// The supported type URL closest to an unknown one, if any, which is
+/// suggested by the message of [`RouterError::UnknownMessageTypeUrl`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct TypeUrlSuggestion(pub Option<String>);
+
+impl FmtDisplay for TypeUrlSuggestion {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), FmtError> {
+        match &self.0 {
+            Some(type_url) => write!(f, ", did you mean `{type_url}`?"),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Error raised while moving the assets held on behalf of IBC applications.
+#[derive(Debug, Display)]
+pub enum BankError {
+    /// account `{account}` holds insufficient funds to move `{asset}`
+    InsufficientFunds { account: String, asset: String },
+    /// moving `{asset}` overflows the balance of account `{account}`
+    BalanceOverflow { account: String, asset: String },
+    /// invalid account `{account}`
+    InvalidAccount { account: String },
+    /// other error: `{0}`
+    Other(String),
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for BankError {}
+
+/// Error raised by an application callback, along with the ABCI error code and
+/// codespace under which the failure is reported to the host.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AppError {
+    pub codespace: String,
+    pub code: u32,
+    pub description: String,
+}
+
+impl AppError {
+    pub fn new(codespace: impl ToString, code: u32, description: impl ToString) -> Self {
+        Self {
+            codespace: codespace.to_string(),
+            code,
+            description: description.to_string(),
+        }
+    }
+}
+
+impl FmtDisplay for AppError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), FmtError> {
+        write!(
+            f,
+            "codespace `{}`, code `{}`: {}",
+            self.codespace, self.code, self.description
+        )
+    }
+}
+
 #[cfg(feature = "std")]
-impl std::error::Error for RouterError {}
+impl std::error::Error for AppError {}
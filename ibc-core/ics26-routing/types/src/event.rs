@@ -1,4 +1,9 @@
+use core::fmt::{Display, Error as FmtError, Formatter};
+
+use base64::prelude::BASE64_STANDARD;
+use base64::Engine;
 use ibc_primitives::prelude::*;
+use subtle_encoding::hex;
 use tendermint::abci;
 
 /// The event type emitted by IBC applications
@@ -32,6 +37,9 @@ impl From<ModuleEvent> for abci::Event {
 }
 
 ///  A single key/value pair in a [`ModuleEvent`]
+///
+/// The attribute is indexed by the host if `index` is set, which it is not
+/// by default.
 #[cfg_attr(
     feature = "parity-scale-codec",
     derive(
@@ -48,20 +56,189 @@ impl From<ModuleEvent> for abci::Event {
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct ModuleEventAttribute {
     pub key: String,
-    pub value: String,
+    pub value: ModuleEventValue,
+    pub index: bool,
 }
 
-impl<K: ToString, V: ToString> From<(K, V)> for ModuleEventAttribute {
-    fn from((k, v): (K, V)) -> Self {
+impl ModuleEventAttribute {
+    /// Builds a non-indexed attribute with the given typed value.
+    pub fn new(key: impl ToString, value: impl Into<ModuleEventValue>) -> Self {
+        Self {
+            key: key.to_string(),
+            value: value.into(),
+            index: false,
+        }
+    }
+
+    /// Marks the attribute to be indexed by the host.
+    pub fn indexed(self) -> Self {
         Self {
-            key: k.to_string(),
-            value: v.to_string(),
+            index: true,
+            ..self
         }
     }
 }
 
+/// Builds a non-indexed attribute with the string representation of the
+/// value.
+impl<K: ToString, V: ToString> From<(K, V)> for ModuleEventAttribute {
+    fn from((k, v): (K, V)) -> Self {
+        Self::new(k, ModuleEventValue::String(v.to_string()))
+    }
+}
+
 impl From<ModuleEventAttribute> for abci::EventAttribute {
     fn from(attr: ModuleEventAttribute) -> Self {
-        (attr.key, attr.value).into()
+        (attr.key, attr.value.to_string(), attr.index).into()
+    }
+}
+
+/// The value of a [`ModuleEventAttribute`], converted into its string
+/// representation in ABCI events.
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ModuleEventValue {
+    String(String),
+    Int(i64),
+    UInt(u64),
+    Bool(bool),
+    Bytes {
+        bytes: Vec<u8>,
+        encoding: BytesEncoding,
+    },
+}
+
+impl ModuleEventValue {
+    /// Builds a value of raw bytes, represented in hexadecimal.
+    pub fn hex(bytes: impl Into<Vec<u8>>) -> Self {
+        Self::Bytes {
+            bytes: bytes.into(),
+            encoding: BytesEncoding::Hex,
+        }
+    }
+
+    /// Builds a value of raw bytes, represented in base64.
+    pub fn base64(bytes: impl Into<Vec<u8>>) -> Self {
+        Self::Bytes {
+            bytes: bytes.into(),
+            encoding: BytesEncoding::Base64,
+        }
+    }
+}
+
+impl Display for ModuleEventValue {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), FmtError> {
+        match self {
+            Self::String(value) => write!(f, "{value}"),
+            Self::Int(value) => write!(f, "{value}"),
+            Self::UInt(value) => write!(f, "{value}"),
+            Self::Bool(value) => write!(f, "{value}"),
+            Self::Bytes { bytes, encoding } => {
+                let encoded = match encoding {
+                    // Hexadecimal encoding only outputs ASCII characters.
+                    BytesEncoding::Hex => String::from_utf8_lossy(&hex::encode(bytes)).into_owned(),
+                    BytesEncoding::Base64 => BASE64_STANDARD.encode(bytes),
+                };
+                write!(f, "{encoded}")
+            }
+        }
+    }
+}
+
+impl From<String> for ModuleEventValue {
+    fn from(value: String) -> Self {
+        Self::String(value)
+    }
+}
+
+impl From<&str> for ModuleEventValue {
+    fn from(value: &str) -> Self {
+        Self::String(value.to_string())
+    }
+}
+
+impl From<i64> for ModuleEventValue {
+    fn from(value: i64) -> Self {
+        Self::Int(value)
+    }
+}
+
+impl From<u64> for ModuleEventValue {
+    fn from(value: u64) -> Self {
+        Self::UInt(value)
+    }
+}
+
+impl From<bool> for ModuleEventValue {
+    fn from(value: bool) -> Self {
+        Self::Bool(value)
+    }
+}
+
+/// The encoding of the bytes of a [`ModuleEventValue`].
+#[cfg_attr(
+    feature = "parity-scale-codec",
+    derive(
+        parity_scale_codec::Encode,
+        parity_scale_codec::Decode,
+        scale_info::TypeInfo
+    )
+)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BytesEncoding {
+    Hex,
+    Base64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn abci_attribute_values() {
+        let attributes = [
+            (ModuleEventAttribute::from(("key", "value")), "value", false),
+            (ModuleEventAttribute::new("key", -1i64), "-1", false),
+            (
+                ModuleEventAttribute::new("key", 42u64).indexed(),
+                "42",
+                true,
+            ),
+            (ModuleEventAttribute::new("key", true), "true", false),
+            (
+                ModuleEventAttribute::new("key", ModuleEventValue::hex([0xab, 0x01])),
+                "ab01",
+                false,
+            ),
+            (
+                ModuleEventAttribute::new("key", ModuleEventValue::base64(*b"ibc")),
+                "aWJj",
+                false,
+            ),
+        ];
+
+        for (attribute, value, index) in attributes {
+            assert_eq!(
+                abci::EventAttribute::from(attribute),
+                ("key", value, index).into()
+            );
+        }
     }
 }
@@ -1,6 +1,7 @@
 use alloc::borrow::Borrow;
 use core::fmt::{Debug, Display, Error as FmtError, Formatter};
 
+use ibc_core_host_types::identifiers::{ChannelId, PortId};
 use ibc_primitives::prelude::*;
 
 use crate::event::ModuleEvent;
@@ -44,6 +45,43 @@ impl Borrow<str> for ModuleId {
     }
 }
 
+/// An account owned by an IBC application rather than by a user, which holds
+/// the assets the application moves on behalf of its users.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum ModuleAccount {
+    /// The account of the module itself, e.g. holding the fees escrowed by
+    /// ICS-29 for incentivized packets.
+    Module(ModuleId),
+    /// The account escrowing the native assets sent over the given channel,
+    /// as ICS-20 and ICS-721 do.
+    Escrow {
+        port_id: PortId,
+        channel_id: ChannelId,
+    },
+}
+
+impl ModuleAccount {
+    pub fn escrow(port_id: &PortId, channel_id: &ChannelId) -> Self {
+        Self::Escrow {
+            port_id: port_id.clone(),
+            channel_id: channel_id.clone(),
+        }
+    }
+}
+
+impl Display for ModuleAccount {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), FmtError> {
+        match self {
+            Self::Module(module_id) => write!(f, "{module_id}"),
+            Self::Escrow {
+                port_id,
+                channel_id,
+            } => write!(f, "{port_id}/{channel_id}"),
+        }
+    }
+}
+
 /// Logs and events produced during module callbacks
 #[cfg_attr(
     feature = "parity-scale-codec",
@@ -35,6 +35,12 @@ pub(crate) fn impl_ClientStateCommon(
         quote! {validate_proof_height(cs, proof_height)},
         imports,
     );
+    let validate_impl = delegate_call_in_match(
+        client_state_enum_name,
+        enum_variants.iter(),
+        quote! {validate(cs)},
+        imports,
+    );
     let verify_upgrade_client_impl = delegate_call_in_match(
         client_state_enum_name,
         enum_variants.iter(),
@@ -91,6 +97,12 @@ pub(crate) fn impl_ClientStateCommon(
                 }
             }
 
+            fn validate(&self) -> core::result::Result<(), #ClientError> {
+                match self {
+                    #(#validate_impl),*
+                }
+            }
+
             fn verify_upgrade_client(
                 &self,
                 upgraded_client_state: #Any,
@@ -29,6 +29,14 @@ pub(crate) fn impl_ClientStateValidation(
         imports,
     );
 
+    let check_for_duplicate_header_impl = delegate_call_in_match(
+        client_state_enum_name,
+        enum_variants.iter(),
+        opts,
+        quote! { check_for_duplicate_header(cs, ctx, client_id, header) },
+        imports,
+    );
+
     let status_impl = delegate_call_in_match(
         client_state_enum_name,
         enum_variants.iter(),
@@ -87,6 +95,17 @@ pub(crate) fn impl_ClientStateValidation(
                 }
             }
 
+            fn check_for_duplicate_header(
+                &self,
+                ctx: &#V,
+                client_id: &#ClientId,
+                header: #Any,
+            ) -> core::result::Result<bool, #ClientError> {
+                match self {
+                    #(#check_for_duplicate_header_impl),*
+                }
+            }
+
             fn status(
                 &self,
                 ctx: &#V,
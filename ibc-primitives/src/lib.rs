@@ -16,6 +16,7 @@ extern crate alloc;
 #[cfg(feature = "std")]
 extern crate std;
 
+mod macros;
 pub mod prelude;
 pub mod utils;
 
@@ -25,6 +26,9 @@ pub use traits::*;
 mod types;
 pub use types::*;
 
+#[cfg(feature = "cosmrs")]
+pub mod tx;
+
 // Helper module for serializing and deserializing types through the `String`
 // primarily used by IBC applications.
 #[cfg(feature = "serde")]
@@ -34,3 +38,8 @@ pub mod proto {
     pub use ibc_proto::google::protobuf::{Any, Duration, Timestamp};
     pub use ibc_proto::Protobuf;
 }
+
+/// Re-exports the `ibc-proto` crate, at the exact version from and into which
+/// the domain types of `ibc-rs` convert, so that downstream crates need not
+/// depend on a matching version of it.
+pub use ibc_proto;
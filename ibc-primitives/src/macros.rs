@@ -0,0 +1,97 @@
+/// Implements [`Protobuf<Raw>`](crate::proto::Protobuf) for a domain type
+/// mirroring its raw type field by field, along with the conversions between
+/// the two.
+///
+/// Each field of the domain type is converted into the field of the same name
+/// of the raw type with `From`. Conversely, fields listed by name only are
+/// converted from the raw type with `From`, while fields listed along with
+/// their domain type are converted with `TryFrom`, mapping the error with the
+/// given function, if any, or else with `From`, into the error of the domain
+/// type. The second form is required as soon as one field is fallible.
+///
+/// ```
+/// # use ibc_primitives::impl_raw_conversions;
+/// # use ibc_primitives::Signer;
+/// # #[derive(Clone, PartialEq, prost::Message)]
+/// # pub struct RawMsgPing {
+/// #     #[prost(string, tag = "1")]
+/// #     pub signer: String,
+/// #     #[prost(uint64, tag = "2")]
+/// #     pub counter: u64,
+/// # }
+/// #[derive(Clone, Debug, PartialEq, Eq)]
+/// pub struct MsgPing {
+///     pub signer: Signer,
+///     pub counter: u32,
+/// }
+///
+/// #[derive(Debug, displaydoc::Display)]
+/// pub enum Error {
+///     /// invalid counter: `{reason}`
+///     InvalidCounter { reason: String },
+/// }
+///
+/// impl_raw_conversions! {
+///     MsgPing => RawMsgPing, Error = Error {
+///         signer,
+///         counter: u32 => |e| Error::InvalidCounter { reason: e.to_string() },
+///     }
+/// }
+/// ```
+#[macro_export]
+macro_rules! impl_raw_conversions {
+    (@field $value:expr) => {
+        ::core::convert::From::from($value)
+    };
+    (@field $value:expr, $ty:ty) => {
+        <$ty as ::core::convert::TryFrom<_>>::try_from($value)?
+    };
+    (@field $value:expr, $ty:ty, $map_err:expr) => {
+        <$ty as ::core::convert::TryFrom<_>>::try_from($value).map_err($map_err)?
+    };
+    (@into_raw $domain:ty => $raw:ty { $($field:ident),* }) => {
+        impl ::core::convert::From<$domain> for $raw {
+            fn from(value: $domain) -> Self {
+                Self {
+                    $($field: ::core::convert::From::from(value.$field),)*
+                }
+            }
+        }
+    };
+    ($domain:ty => $raw:ty { $($field:ident),* $(,)? }) => {
+        impl $crate::proto::Protobuf<$raw> for $domain {}
+
+        impl ::core::convert::From<$raw> for $domain {
+            fn from(raw: $raw) -> Self {
+                Self {
+                    $($field: $crate::impl_raw_conversions!(@field raw.$field),)*
+                }
+            }
+        }
+
+        $crate::impl_raw_conversions!(@into_raw $domain => $raw { $($field),* });
+    };
+    (
+        $domain:ty => $raw:ty, Error = $error:ty {
+            $($field:ident $(: $ty:ty $(=> $map_err:expr)?)?),* $(,)?
+        }
+    ) => {
+        impl $crate::proto::Protobuf<$raw> for $domain {}
+
+        impl ::core::convert::TryFrom<$raw> for $domain {
+            type Error = $error;
+
+            fn try_from(raw: $raw) -> ::core::result::Result<Self, Self::Error> {
+                ::core::result::Result::Ok(Self {
+                    $(
+                        $field: $crate::impl_raw_conversions!(
+                            @field raw.$field $(, $ty $(, $map_err)?)?
+                        ),
+                    )*
+                })
+            }
+        }
+
+        $crate::impl_raw_conversions!(@into_raw $domain => $raw { $($field),* });
+    };
+}
@@ -0,0 +1,19 @@
+/// Errors reported under a stable ABCI error code, so that the results of
+/// failed transactions are machine-readable by wallets and relayers across
+/// node versions.
+///
+/// As in Cosmos-SDK, codes are only unique within their codespace, and start
+/// at `2`, `0` meaning success and `1` being reserved for internal errors.
+/// The code of an error variant is never reassigned: new variants are given
+/// new codes, and the codes of removed variants are not reused.
+///
+/// Errors wrapping the error of another component, e.g. a connection error
+/// caused by a client error, report the code and codespace of the latter.
+pub trait AbciError {
+    /// Returns the codespace of the error, i.e. the name of the component
+    /// raising it.
+    fn codespace(&self) -> &'static str;
+
+    /// Returns the code of the error within its codespace.
+    fn code(&self) -> u32;
+}
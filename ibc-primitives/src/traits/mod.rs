@@ -1,3 +1,7 @@
+mod error;
+mod msg;
 mod proto;
 
+pub use error::*;
+pub use msg::*;
 pub use proto::*;
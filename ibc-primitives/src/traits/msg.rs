@@ -0,0 +1,12 @@
+use crate::Signer;
+
+/// Messages signed by a single account, which submits them to the host.
+///
+/// Exposing the signer of every message, and of the enumerations wrapping
+/// them, lets hosts authenticate or authorize messages generically, e.g. in
+/// the ante handler of the transactions carrying them, without matching on
+/// each of their types.
+pub trait Msg {
+    /// Returns the account which signed the message.
+    fn signer(&self) -> &Signer;
+}
@@ -0,0 +1,87 @@
+//! Conversions between the messages of `ibc-rs` and the transaction types of
+//! [`cosmrs`], so that the messages can be embedded into the body of a Cosmos
+//! SDK transaction, to be signed with `cosmrs`, and extracted back from it.
+//!
+//! Both crates represent messages as a Protobuf `Any`, but each through its
+//! own type:
+//!
+//! ```ignore
+//! let body = tx_body([MsgEnvelope::from(msg)], "", 0u32.into());
+//! let sign_doc = SignDoc::new(&body, &auth_info, &chain_id, account_number)?;
+//!
+//! let msgs = tx_body_messages(&body)
+//!     .map(MsgEnvelope::try_from)
+//!     .collect::<Result<Vec<_>, _>>()?;
+//! ```
+
+use tendermint::block::Height;
+
+use crate::prelude::*;
+use crate::proto::Any;
+
+/// Converts a message into the `Any` of `cosmrs`.
+pub fn to_cosmrs_any(msg: impl Into<Any>) -> cosmrs::Any {
+    let any = msg.into();
+
+    cosmrs::Any {
+        type_url: any.type_url,
+        value: any.value,
+    }
+}
+
+/// Converts the `Any` of `cosmrs` into that of `ibc-rs`, from which messages
+/// are decoded.
+pub fn from_cosmrs_any(any: cosmrs::Any) -> Any {
+    Any {
+        type_url: any.type_url,
+        value: any.value,
+    }
+}
+
+/// Builds the body of a transaction holding the given messages, in order.
+pub fn tx_body<M>(
+    msgs: impl IntoIterator<Item = M>,
+    memo: impl Into<String>,
+    timeout_height: Height,
+) -> cosmrs::tx::Body
+where
+    M: Into<Any>,
+{
+    cosmrs::tx::Body::new(msgs.into_iter().map(to_cosmrs_any), memo, timeout_height)
+}
+
+/// Returns the messages of the body of a transaction, in order.
+pub fn tx_body_messages(body: &cosmrs::tx::Body) -> impl Iterator<Item = Any> + '_ {
+    body.messages.iter().cloned().map(from_cosmrs_any)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tx_body_round_trip() {
+        let msgs = vec![
+            Any {
+                type_url: "/ibc.core.client.v1.MsgUpdateClient".to_string(),
+                value: vec![1, 2, 3],
+            },
+            Any {
+                type_url: "/ibc.core.channel.v1.MsgRecvPacket".to_string(),
+                value: vec![4, 5],
+            },
+        ];
+
+        let body = tx_body(msgs.clone(), "memo", 42u32.into());
+
+        assert_eq!(body.memo, "memo");
+        assert_eq!(body.timeout_height, 42u32.into());
+        assert_eq!(body.messages[0].type_url, msgs[0].type_url);
+        assert_eq!(tx_body_messages(&body).collect::<Vec<_>>(), msgs);
+
+        let decoded =
+            cosmrs::tx::Body::try_from(cosmrs::proto::cosmos::tx::v1beta1::TxBody::from(body))
+                .expect("valid tx body");
+        assert_eq!(tx_body_messages(&decoded).collect::<Vec<_>>(), msgs);
+    }
+}
@@ -0,0 +1,206 @@
+use displaydoc::Display;
+use ibc_proto::google::protobuf::Any;
+
+/// The default maximum size of an encoded `Any` value, in bytes, matching the
+/// default maximum size of a transaction in CometBFT.
+pub const DEFAULT_MAX_DECODING_SIZE: usize = 1024 * 1024;
+
+/// The default maximum nesting depth of the messages in an encoded `Any`
+/// value, which leaves ample room for the deepest IBC messages, such as
+/// Tendermint headers or batched ICS-23 proofs.
+pub const DEFAULT_MAX_DECODING_DEPTH: u32 = 32;
+
+/// Limits checked before decoding untrusted `Any` values, e.g. submitted by
+/// relayers, bounding the memory and stack used to decode them.
+///
+/// The nesting depth of a value is computed from its protobuf wire format
+/// only, since the schema of the value is not known beforehand: every
+/// length-delimited field whose content is a well-formed message counts as a
+/// nested message, including `bytes` fields carrying encoded messages, such
+/// as proofs.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DecodingLimits {
+    /// The maximum size of the encoded value, in bytes.
+    pub max_size: usize,
+    /// The maximum nesting depth of the messages in the encoded value, the
+    /// value itself being at depth 1.
+    pub max_depth: u32,
+}
+
+impl Default for DecodingLimits {
+    fn default() -> Self {
+        Self {
+            max_size: DEFAULT_MAX_DECODING_SIZE,
+            max_depth: DEFAULT_MAX_DECODING_DEPTH,
+        }
+    }
+}
+
+impl DecodingLimits {
+    pub fn new(max_size: usize, max_depth: u32) -> Self {
+        Self {
+            max_size,
+            max_depth,
+        }
+    }
+
+    /// Checks the encoded value of `any` against the limits.
+    pub fn check_any(&self, any: &Any) -> Result<(), DecodingLimitError> {
+        self.check(&any.value)
+    }
+
+    /// Checks the given encoded message against the limits.
+    pub fn check(&self, value: &[u8]) -> Result<(), DecodingLimitError> {
+        if value.len() > self.max_size {
+            return Err(DecodingLimitError::TooLarge {
+                size: value.len(),
+                max_size: self.max_size,
+            });
+        }
+
+        // Values which are not well-formed messages are left to the decoder
+        // to reject.
+        match nesting_depth(value, self.max_depth) {
+            Some(depth) if depth > self.max_depth => Err(DecodingLimitError::TooDeep {
+                max_depth: self.max_depth,
+            }),
+            _ => Ok(()),
+        }
+    }
+}
+
+#[derive(Debug, Display)]
+pub enum DecodingLimitError {
+    /// encoded size of `{size}` bytes exceeds the limit of `{max_size}` bytes
+    TooLarge { size: usize, max_size: usize },
+    /// nesting depth exceeds the limit of `{max_depth}`
+    TooDeep { max_depth: u32 },
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for DecodingLimitError {}
+
+/// Returns the nesting depth of `buf` parsed as a protobuf message, or `None`
+/// if it is not a well-formed message.
+///
+/// Fields are only descended into up to `budget` levels below `buf`, so that
+/// the returned depth is capped at `budget + 1` and the stack used is bounded.
+fn nesting_depth(mut buf: &[u8], budget: u32) -> Option<u32> {
+    let mut depth = 1;
+
+    while !buf.is_empty() {
+        let key = decode_varint(&mut buf)?;
+        if key >> 3 == 0 || key >> 3 > u64::from(u32::MAX >> 3) {
+            return None;
+        }
+
+        match key & 0x7 {
+            // varint
+            0 => {
+                decode_varint(&mut buf)?;
+            }
+            // 64-bit
+            1 => buf = buf.get(8..)?,
+            // length-delimited
+            2 => {
+                let len = usize::try_from(decode_varint(&mut buf)?).ok()?;
+                let field = buf.get(..len)?;
+                buf = &buf[len..];
+
+                if budget > 0 {
+                    if let Some(field_depth) = nesting_depth(field, budget - 1) {
+                        depth = depth.max(field_depth + 1);
+                    }
+                }
+            }
+            // 32-bit
+            5 => buf = buf.get(4..)?,
+            // groups are deprecated, and not used by IBC messages
+            _ => return None,
+        }
+    }
+
+    Some(depth)
+}
+
+fn decode_varint(buf: &mut &[u8]) -> Option<u64> {
+    let mut value = 0u64;
+
+    for shift in (0..64).step_by(7) {
+        let (&byte, rest) = buf.split_first()?;
+        *buf = rest;
+        value |= u64::from(byte & 0x7f) << shift;
+        if byte & 0x80 == 0 {
+            return Some(value);
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use prost::Message;
+
+    use super::*;
+    use crate::prelude::*;
+
+    /// Returns an encoded message of the given nesting depth, made of nested
+    /// `Any`s.
+    fn nested_value(depth: u32) -> Vec<u8> {
+        // A message with a single varint field.
+        let mut value = vec![0x08, 0x01];
+        for _ in 1..depth {
+            value = Any {
+                type_url: "/google.protobuf.Any".to_string(),
+                value,
+            }
+            .encode_to_vec();
+        }
+        value
+    }
+
+    #[test]
+    fn check_depth() {
+        let limits = DecodingLimits::default();
+
+        for depth in [1, 5, DEFAULT_MAX_DECODING_DEPTH] {
+            assert!(limits.check(&nested_value(depth)).is_ok());
+        }
+
+        for depth in [DEFAULT_MAX_DECODING_DEPTH + 1, 1000] {
+            assert!(matches!(
+                limits.check(&nested_value(depth)),
+                Err(DecodingLimitError::TooDeep { .. })
+            ));
+        }
+    }
+
+    #[test]
+    fn check_size() {
+        let limits = DecodingLimits::new(16, DEFAULT_MAX_DECODING_DEPTH);
+        let any = Any {
+            type_url: String::new(),
+            value: vec![0; 17],
+        };
+
+        assert!(matches!(
+            limits.check_any(&any),
+            Err(DecodingLimitError::TooLarge {
+                size: 17,
+                max_size: 16
+            })
+        ));
+        assert!(limits.check(&[0; 16]).is_ok());
+    }
+
+    #[test]
+    fn check_malformed_message() {
+        // Malformed messages are left to the decoder to reject.
+        let value = vec![0xff; 10];
+
+        assert!(DecodingLimits::default().check(&value).is_ok());
+        assert!(Any::decode(value.as_slice()).is_err());
+    }
+}
@@ -1,5 +1,7 @@
+mod decoding;
 mod signer;
 mod timestamp;
 
+pub use decoding::*;
 pub use signer::*;
 pub use timestamp::*;
@@ -20,12 +20,25 @@ use crate::prelude::*;
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Display)]
 pub struct Signer(String);
 
+impl Signer {
+    /// Returns `true` if the signer address is empty.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
 impl From<String> for Signer {
     fn from(s: String) -> Self {
         Self(s)
     }
 }
 
+impl From<Signer> for String {
+    fn from(signer: Signer) -> Self {
+        signer.0
+    }
+}
+
 impl AsRef<str> for Signer {
     fn as_ref(&self) -> &str {
         self.0.as_str()
@@ -0,0 +1,34 @@
+//! Comparison utilities.
+
+use core::hint::black_box;
+
+/// Returns whether the two byte slices are equal, in a time independent of
+/// their contents, though not of their lengths.
+///
+/// Used to compare the commitments checked on verification, so that the time
+/// taken to reject a forged commitment does not disclose how many of its
+/// leading bytes match the expected one.
+pub fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+
+    let diff = a.iter().zip(b).fold(0, |diff, (x, y)| diff | (x ^ y));
+
+    // Prevents the compiler from short-circuiting the fold above.
+    black_box(diff) == 0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_constant_time_eq() {
+        assert!(constant_time_eq(b"", b""));
+        assert!(constant_time_eq(b"commitment", b"commitment"));
+        assert!(!constant_time_eq(b"commitment", b"commitmenT"));
+        assert!(!constant_time_eq(b"Commitment", b"commitment"));
+        assert!(!constant_time_eq(b"commitment", b"commitments"));
+    }
+}
@@ -0,0 +1,610 @@
+//! JSON encoding utilities, matching the encodings of ibc-go.
+
+use core::fmt::Write;
+
+use crate::prelude::*;
+
+/// Escapes a string the same way Go's `encoding/json` does, which is what
+/// ibc-go relies on when serializing acknowledgements and packet data.
+/// Notably, `<`, `>` and `&` are HTML-escaped, as are the U+2028 and U+2029
+/// line terminators.
+pub fn escape_json_str(value: &str, out: &mut String) {
+    for c in value.chars() {
+        match c {
+            '"' => out.push_str(r#"\""#),
+            '\\' => out.push_str(r"\\"),
+            '\n' => out.push_str(r"\n"),
+            '\r' => out.push_str(r"\r"),
+            '\t' => out.push_str(r"\t"),
+            '\u{08}' => out.push_str(r"\b"),
+            '\u{0c}' => out.push_str(r"\f"),
+            '<' | '>' | '&' | '\u{2028}' | '\u{2029}' | '\u{00}'..='\u{1f}' => {
+                // Writing into a `String` never fails.
+                let _ = write!(out, r"\u{:04x}", c as u32);
+            }
+            c => out.push(c),
+        }
+    }
+}
+
+/// Encodes a flat JSON object of strings byte-for-byte as ibc-go encodes the
+/// packet data of its applications, i.e. as `sdk.MustSortJSON` re-encodes the
+/// proto3 JSON of a message: the keys are sorted, the fields with empty values
+/// are omitted, and the strings are escaped by [`escape_json_str`].
+#[derive(Clone, Debug, Default)]
+pub struct SortedJsonObject {
+    fields: Vec<(&'static str, String)>,
+}
+
+impl SortedJsonObject {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a string field, unless `value` is empty.
+    pub fn string(mut self, key: &'static str, value: &str) -> Self {
+        if !value.is_empty() {
+            let mut json = String::with_capacity(value.len() + 2);
+            push_json_str(value, &mut json);
+            self.fields.push((key, json));
+        }
+        self
+    }
+
+    /// Adds an array of strings field, unless `values` is empty.
+    pub fn strings(
+        mut self,
+        key: &'static str,
+        values: impl IntoIterator<Item = impl AsRef<str>>,
+    ) -> Self {
+        let mut json = String::from("[");
+        for (i, value) in values.into_iter().enumerate() {
+            if i > 0 {
+                json.push(',');
+            }
+            push_json_str(value.as_ref(), &mut json);
+        }
+        json.push(']');
+
+        if json.len() > 2 {
+            self.fields.push((key, json));
+        }
+        self
+    }
+
+    /// Returns the encoded object.
+    pub fn encode(mut self) -> String {
+        self.fields.sort_by_key(|(key, _)| *key);
+
+        let mut json = String::from("{");
+        for (i, (key, value)) in self.fields.iter().enumerate() {
+            if i > 0 {
+                json.push(',');
+            }
+            push_json_str(key, &mut json);
+            json.push(':');
+            json.push_str(value);
+        }
+        json.push('}');
+
+        json
+    }
+}
+
+/// Pushes `value` as an escaped JSON string, quotes included.
+fn push_json_str(value: &str, out: &mut String) {
+    out.push('"');
+    escape_json_str(value, out);
+    out.push('"');
+}
+
+/// The maximum nesting depth of the arrays and objects accepted by
+/// [`is_valid_json`].
+pub const MAX_JSON_DEPTH: usize = 64;
+
+/// Returns whether `value` is a single valid JSON value, surrounded by
+/// whitespace at most, whose arrays and objects are nested at most
+/// [`MAX_JSON_DEPTH`] levels deep.
+///
+/// Only the syntax is checked, without allocating nor decoding the value.
+pub fn is_valid_json(value: &str) -> bool {
+    let mut parser = JsonParser::new(value, true);
+
+    parser.value(0, false).is_some() && parser.end()
+}
+
+/// Decodes `value` if it is valid JSON, as checked by [`is_valid_json`], in
+/// which case its arrays and objects are nested at most [`MAX_JSON_DEPTH`]
+/// levels deep.
+///
+/// Escaped lone surrogates are decoded as U+FFFD, as with Go's
+/// `encoding/json`.
+pub fn parse_json(value: &str) -> Option<JsonValue> {
+    let mut parser = JsonParser::new(value, true);
+
+    let value = parser.value(0, true)?;
+    parser.end().then_some(value)
+}
+
+/// Decodes `value` if it is a valid JSON object, as checked by
+/// [`is_valid_json`], whose members are all strings, returning the members in
+/// the order they are written.
+///
+/// Unlike [`parse_json`], escaped lone surrogates are rejected.
+pub fn parse_json_string_object(value: &str) -> Option<Vec<(String, String)>> {
+    let mut parser = JsonParser::new(value, false);
+
+    let JsonValue::Object(members) = parser.value(0, true)? else {
+        return None;
+    };
+    if !parser.end() {
+        return None;
+    }
+
+    members
+        .into_iter()
+        .map(|(key, value)| match value {
+            JsonValue::String(value) => Some((key, value)),
+            _ => None,
+        })
+        .collect()
+}
+
+/// A JSON value decoded by [`parse_json`].
+///
+/// Numbers are kept as they are written, and the members of objects in the
+/// order they are written.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum JsonValue {
+    Null,
+    Bool(bool),
+    Number(String),
+    String(String),
+    Array(Vec<JsonValue>),
+    Object(Vec<(String, JsonValue)>),
+}
+
+impl JsonValue {
+    /// Returns the value of the member `key` of an object, the last one if
+    /// the key is duplicated, as with Go's `encoding/json`.
+    pub fn get(&self, key: &str) -> Option<&JsonValue> {
+        match self {
+            Self::Object(members) => members
+                .iter()
+                .rev()
+                .find(|(member_key, _)| member_key == key)
+                .map(|(_, value)| value),
+            _ => None,
+        }
+    }
+
+    /// Returns the string, if the value is one.
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Self::String(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Encodes the value compactly, as Go's `encoding/json` re-encodes the
+    /// values it decoded into maps: the keys of objects are sorted and
+    /// deduplicated, and the strings are escaped by [`escape_json_str`].
+    pub fn encode(&self) -> String {
+        let mut out = String::new();
+        self.encode_into(&mut out);
+        out
+    }
+
+    fn encode_into(&self, out: &mut String) {
+        match self {
+            Self::Null => out.push_str("null"),
+            Self::Bool(value) => out.push_str(if *value { "true" } else { "false" }),
+            Self::Number(value) => out.push_str(value),
+            Self::String(value) => push_json_str(value, out),
+            Self::Array(values) => {
+                out.push('[');
+                for (i, value) in values.iter().enumerate() {
+                    if i > 0 {
+                        out.push(',');
+                    }
+                    value.encode_into(out);
+                }
+                out.push(']');
+            }
+            Self::Object(members) => {
+                let members: BTreeMap<&str, &JsonValue> = members
+                    .iter()
+                    .map(|(key, value)| (key.as_str(), value))
+                    .collect();
+
+                out.push('{');
+                for (i, (key, value)) in members.into_iter().enumerate() {
+                    if i > 0 {
+                        out.push(',');
+                    }
+                    push_json_str(key, out);
+                    out.push(':');
+                    value.encode_into(out);
+                }
+                out.push('}');
+            }
+        }
+    }
+}
+
+/// A parser of JSON, as specified by RFC 8259, decoding the values it consumes
+/// only if asked to, so that checking the syntax does not allocate.
+struct JsonParser<'a> {
+    input: &'a str,
+    pos: usize,
+    /// Whether escaped lone surrogates are decoded as U+FFFD rather than
+    /// rejected.
+    lossy: bool,
+}
+
+impl<'a> JsonParser<'a> {
+    fn new(input: &'a str, lossy: bool) -> Self {
+        Self {
+            input,
+            pos: 0,
+            lossy,
+        }
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.input.as_bytes().get(self.pos).copied()
+    }
+
+    fn eat(&mut self, byte: u8) -> bool {
+        if self.peek() == Some(byte) {
+            self.pos += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn skip_whitespace(&mut self) {
+        while matches!(self.peek(), Some(b' ' | b'\t' | b'\n' | b'\r')) {
+            self.pos += 1;
+        }
+    }
+
+    /// Returns whether only whitespace is left.
+    fn end(&mut self) -> bool {
+        self.skip_whitespace();
+        self.pos == self.input.len()
+    }
+
+    /// Consumes a value, which is decoded only if `decode` is set, an empty
+    /// value of the same kind being returned otherwise.
+    fn value(&mut self, depth: usize, decode: bool) -> Option<JsonValue> {
+        self.skip_whitespace();
+        match self.peek()? {
+            b'{' => self.object(depth + 1, decode),
+            b'[' => self.array(depth + 1, decode),
+            b'"' => {
+                let mut value = String::new();
+                self.string_into(decode.then_some(&mut value))
+                    .then_some(JsonValue::String(value))
+            }
+            b'-' | b'0'..=b'9' => {
+                let start = self.pos;
+                if !self.number() {
+                    return None;
+                }
+                Some(JsonValue::Number(if decode {
+                    self.input[start..self.pos].to_string()
+                } else {
+                    String::new()
+                }))
+            }
+            b't' => self.literal(b"true").then_some(JsonValue::Bool(true)),
+            b'f' => self.literal(b"false").then_some(JsonValue::Bool(false)),
+            b'n' => self.literal(b"null").then_some(JsonValue::Null),
+            _ => None,
+        }
+    }
+
+    fn object(&mut self, depth: usize, decode: bool) -> Option<JsonValue> {
+        if depth > MAX_JSON_DEPTH {
+            return None;
+        }
+        self.pos += 1;
+
+        let mut members = Vec::new();
+        self.skip_whitespace();
+        if self.eat(b'}') {
+            return Some(JsonValue::Object(members));
+        }
+        loop {
+            let mut key = String::new();
+            self.skip_whitespace();
+            if !self.string_into(decode.then_some(&mut key)) {
+                return None;
+            }
+            self.skip_whitespace();
+            if !self.eat(b':') {
+                return None;
+            }
+            let value = self.value(depth, decode)?;
+            if decode {
+                members.push((key, value));
+            }
+            self.skip_whitespace();
+            if self.eat(b'}') {
+                return Some(JsonValue::Object(members));
+            }
+            if !self.eat(b',') {
+                return None;
+            }
+        }
+    }
+
+    fn array(&mut self, depth: usize, decode: bool) -> Option<JsonValue> {
+        if depth > MAX_JSON_DEPTH {
+            return None;
+        }
+        self.pos += 1;
+
+        let mut values = Vec::new();
+        self.skip_whitespace();
+        if self.eat(b']') {
+            return Some(JsonValue::Array(values));
+        }
+        loop {
+            let value = self.value(depth, decode)?;
+            if decode {
+                values.push(value);
+            }
+            self.skip_whitespace();
+            if self.eat(b']') {
+                return Some(JsonValue::Array(values));
+            }
+            if !self.eat(b',') {
+                return None;
+            }
+        }
+    }
+
+    /// Consumes a string, decoding it into `out`, if any.
+    fn string_into(&mut self, mut out: Option<&mut String>) -> bool {
+        if !self.eat(b'"') {
+            return false;
+        }
+        let mut start = self.pos;
+        loop {
+            match self.peek() {
+                Some(b'"') => {
+                    if let Some(out) = out.as_deref_mut() {
+                        out.push_str(&self.input[start..self.pos]);
+                    }
+                    self.pos += 1;
+                    return true;
+                }
+                Some(b'\\') => {
+                    if let Some(out) = out.as_deref_mut() {
+                        out.push_str(&self.input[start..self.pos]);
+                    }
+                    self.pos += 1;
+                    let Some(byte) = self.peek() else {
+                        return false;
+                    };
+                    self.pos += 1;
+                    let unescaped = match byte {
+                        b'"' => '"',
+                        b'\\' => '\\',
+                        b'/' => '/',
+                        b'b' => '\u{08}',
+                        b'f' => '\u{0c}',
+                        b'n' => '\n',
+                        b'r' => '\r',
+                        b't' => '\t',
+                        b'u' => match self.unicode_escape(out.is_some()) {
+                            Some(c) => c,
+                            None => return false,
+                        },
+                        _ => return false,
+                    };
+                    if let Some(out) = out.as_deref_mut() {
+                        out.push(unescaped);
+                    }
+                    start = self.pos;
+                }
+                // Control characters must be escaped.
+                Some(0x00..=0x1f) | None => return false,
+                Some(_) => self.pos += 1,
+            }
+        }
+    }
+
+    /// Consumes the code unit of a `\u` escape, whose `\u` was consumed,
+    /// along with the escaped low surrogate following a high surrogate when
+    /// `decode` is set.
+    ///
+    /// Any code unit is valid syntax, U+FFFD being returned unless `decode`
+    /// is set.
+    fn unicode_escape(&mut self, decode: bool) -> Option<char> {
+        let high = self.hex4()?;
+
+        if !decode {
+            return Some(char::REPLACEMENT_CHARACTER);
+        }
+
+        if (0xd800..0xdc00).contains(&high) && self.input[self.pos..].starts_with("\\u") {
+            let pos = self.pos;
+            self.pos += 2;
+            let low = self.hex4()?;
+            if (0xdc00..0xe000).contains(&low) {
+                return char::from_u32(0x10000 + ((high - 0xd800) << 10) + (low - 0xdc00));
+            }
+            self.pos = pos;
+        }
+
+        char::from_u32(high).or(self.lossy.then_some(char::REPLACEMENT_CHARACTER))
+    }
+
+    fn hex4(&mut self) -> Option<u32> {
+        let digits = self.input.get(self.pos..self.pos + 4)?;
+        if !digits.bytes().all(|byte| byte.is_ascii_hexdigit()) {
+            return None;
+        }
+        self.pos += 4;
+        u32::from_str_radix(digits, 16).ok()
+    }
+
+    fn number(&mut self) -> bool {
+        self.eat(b'-');
+        if !self.eat(b'0') && !self.digits() {
+            return false;
+        }
+        if self.eat(b'.') && !self.digits() {
+            return false;
+        }
+        if self.eat(b'e') || self.eat(b'E') {
+            if !self.eat(b'+') {
+                self.eat(b'-');
+            }
+            if !self.digits() {
+                return false;
+            }
+        }
+        true
+    }
+
+    /// Consumes one or more digits, returning whether there was any.
+    fn digits(&mut self) -> bool {
+        let start = self.pos;
+        while matches!(self.peek(), Some(b'0'..=b'9')) {
+            self.pos += 1;
+        }
+        self.pos > start
+    }
+
+    fn literal(&mut self, literal: &[u8]) -> bool {
+        if self.input.as_bytes()[self.pos..].starts_with(literal) {
+            self.pos += literal.len();
+            true
+        } else {
+            false
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sorted_json_object() {
+        let json = SortedJsonObject::new()
+            .string("sender", "cosmos1sender")
+            .string("memo", "")
+            .strings("ids", ["a", "b"])
+            .strings("uris", Vec::<&str>::new())
+            .string("amount", "<1 & \"2\">")
+            .encode();
+
+        assert_eq!(
+            json,
+            r#"{"amount":"\u003c1 \u0026 \"2\"\u003e","ids":["a","b"],"sender":"cosmos1sender"}"#
+        );
+        assert_eq!(SortedJsonObject::new().encode(), "{}");
+    }
+
+    #[test]
+    fn test_is_valid_json() {
+        for valid in [
+            r#"{"fee_version":"ics29-1","app_version":"ics20-1"}"#,
+            r#" { "a" : [1, -2.5e+3, true, false, null, "\u00e9\n"] } "#,
+            "[]",
+            "0",
+        ] {
+            assert!(is_valid_json(valid), "{valid}");
+        }
+
+        for invalid in [
+            "",
+            "{",
+            r#"{"a":}"#,
+            r#"{"a":1,}"#,
+            "[1 2]",
+            "01",
+            "1.",
+            r#""\x""#,
+            "\"\n\"",
+            "tru",
+            "{} {}",
+        ] {
+            assert!(!is_valid_json(invalid), "{invalid}");
+        }
+
+        let nested = |depth| "[".repeat(depth) + &"]".repeat(depth);
+        assert!(is_valid_json(&nested(MAX_JSON_DEPTH)));
+        assert!(!is_valid_json(&nested(MAX_JSON_DEPTH + 1)));
+    }
+
+    #[test]
+    fn test_parse_json_string_object() {
+        assert_eq!(
+            parse_json_string_object(r#" { "b" : "\u00e9\"\n\ud83d\ude00", "a": "" } "#),
+            Some(vec![
+                ("b".to_string(), "\u{e9}\"\n\u{1f600}".to_string()),
+                ("a".to_string(), String::new()),
+            ])
+        );
+        assert_eq!(parse_json_string_object("{}"), Some(vec![]));
+
+        for invalid in [
+            "",
+            "[]",
+            r#"{"a":1}"#,
+            r#"{"a":"b",}"#,
+            r#"{"a":"\ud83d"}"#,
+            r#"{"a":"\udc00"}"#,
+            r#"{"a":"b"} {}"#,
+        ] {
+            assert_eq!(parse_json_string_object(invalid), None, "{invalid}");
+        }
+        // Lone surrogates are valid syntax nonetheless.
+        assert!(is_valid_json(r#"{"a":"\ud83d"}"#));
+    }
+
+    #[test]
+    fn test_parse_json() {
+        let value = parse_json(
+            r#" {"b": [1, -2.5e+3, true, null], "a": {"c": "\u00e9\"\n\ud83d\ude00\udc00"}, "b": false} "#,
+        )
+        .expect("valid JSON");
+
+        assert_eq!(value.get("b"), Some(&JsonValue::Bool(false)));
+        assert_eq!(
+            value
+                .get("a")
+                .and_then(|a| a.get("c"))
+                .and_then(JsonValue::as_str),
+            Some("\u{e9}\"\n\u{1f600}\u{fffd}")
+        );
+        assert_eq!(value.get("c"), None);
+
+        let JsonValue::Object(members) = &value else {
+            panic!("expected an object, got {value:?}");
+        };
+        assert_eq!(
+            members[0].1,
+            JsonValue::Array(vec![
+                JsonValue::Number("1".to_string()),
+                JsonValue::Number("-2.5e+3".to_string()),
+                JsonValue::Bool(true),
+                JsonValue::Null,
+            ])
+        );
+
+        assert_eq!(value.encode(), r#"{"a":{"c":"é\"\n😀�"},"b":false}"#);
+        assert_eq!(
+            parse_json(" [ ] ").map(|value| value.encode()),
+            Some("[]".to_string())
+        );
+        assert_eq!(parse_json(r#"{"a":}"#), None);
+    }
+}
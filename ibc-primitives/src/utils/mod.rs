@@ -1,4 +1,8 @@
 //! Contains various internally-used utilities.
+pub mod eq;
+pub mod json;
 pub mod pretty;
 
+pub use eq::*;
+pub use json::*;
 pub use pretty::*;
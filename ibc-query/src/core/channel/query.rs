@@ -3,7 +3,7 @@
 use ibc::core::client::context::ClientValidationContext;
 use ibc::core::host::types::path::{
     AckPath, ChannelEndPath, ClientConsensusStatePath, ClientStatePath, CommitmentPath, Path,
-    ReceiptPath, SeqRecvPath, SeqSendPath,
+    ReceiptPath, SeqAckPath, SeqRecvPath, SeqSendPath,
 };
 use ibc::core::host::{ConsensusStateRef, ValidationContext};
 use ibc::primitives::prelude::format;
@@ -13,14 +13,15 @@ use super::{
     QueryChannelClientStateRequest, QueryChannelClientStateResponse,
     QueryChannelConsensusStateRequest, QueryChannelConsensusStateResponse, QueryChannelRequest,
     QueryChannelResponse, QueryChannelsRequest, QueryChannelsResponse,
-    QueryConnectionChannelsRequest, QueryConnectionChannelsResponse,
-    QueryNextSequenceReceiveRequest, QueryNextSequenceReceiveResponse,
-    QueryNextSequenceSendRequest, QueryNextSequenceSendResponse, QueryPacketAcknowledgementRequest,
-    QueryPacketAcknowledgementResponse, QueryPacketAcknowledgementsRequest,
-    QueryPacketAcknowledgementsResponse, QueryPacketCommitmentRequest,
-    QueryPacketCommitmentResponse, QueryPacketCommitmentsRequest, QueryPacketCommitmentsResponse,
-    QueryPacketReceiptRequest, QueryPacketReceiptResponse, QueryUnreceivedAcksRequest,
-    QueryUnreceivedAcksResponse, QueryUnreceivedPacketsRequest, QueryUnreceivedPacketsResponse,
+    QueryConnectionChannelsRequest, QueryConnectionChannelsResponse, QueryNextSequenceAckRequest,
+    QueryNextSequenceAckResponse, QueryNextSequenceReceiveRequest,
+    QueryNextSequenceReceiveResponse, QueryNextSequenceSendRequest, QueryNextSequenceSendResponse,
+    QueryPacketAcknowledgementRequest, QueryPacketAcknowledgementResponse,
+    QueryPacketAcknowledgementsRequest, QueryPacketAcknowledgementsResponse,
+    QueryPacketCommitmentRequest, QueryPacketCommitmentResponse, QueryPacketCommitmentsRequest,
+    QueryPacketCommitmentsResponse, QueryPacketReceiptRequest, QueryPacketReceiptResponse,
+    QueryUnreceivedAcksRequest, QueryUnreceivedAcksResponse, QueryUnreceivedPacketsRequest,
+    QueryUnreceivedPacketsResponse,
 };
 use crate::core::client::IdentifiedClientState;
 use crate::core::context::{ProvableContext, QueryContext};
@@ -476,3 +477,36 @@ where
         proof_height,
     ))
 }
+
+/// Queries for the next sequence acknowledge associated with a channel
+pub fn query_next_sequence_ack<I>(
+    ibc_ctx: &I,
+    request: &QueryNextSequenceAckRequest,
+) -> Result<QueryNextSequenceAckResponse, QueryError>
+where
+    I: ValidationContext + ProvableContext,
+{
+    let next_seq_ack_path = SeqAckPath::new(&request.port_id, &request.channel_id);
+
+    let next_sequence_ack = ibc_ctx.get_next_sequence_ack(&next_seq_ack_path)?;
+
+    let proof_height = match request.query_height {
+        Some(height) => height,
+        None => ibc_ctx.host_height()?,
+    };
+
+    let proof = ibc_ctx
+        .get_proof(proof_height, &Path::SeqAck(next_seq_ack_path))
+        .ok_or_else(|| {
+            QueryError::proof_not_found(format!(
+                "Next sequence ack proof not found for channel {}",
+                request.channel_id
+            ))
+        })?;
+
+    Ok(QueryNextSequenceAckResponse::new(
+        next_sequence_ack,
+        proof,
+        proof_height,
+    ))
+}
@@ -408,3 +408,17 @@ impl TryFrom<RawQueryNextSequenceSendRequest> for QueryNextSequenceSendRequest {
         })
     }
 }
+
+/// Defines the request type for querying the next sequence acknowledge number
+/// for the specified channel.
+///
+/// It has no gRPC counterpart, and lets relayers recover the state of ordered
+/// channels along with the next sequences to send and receive.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct QueryNextSequenceAckRequest {
+    pub port_id: PortId,
+    pub channel_id: ChannelId,
+    pub query_height: Option<Height>,
+}
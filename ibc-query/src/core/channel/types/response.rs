@@ -5,7 +5,7 @@ use ibc::core::channel::types::channel::{ChannelEnd, IdentifiedChannelEnd};
 use ibc::core::channel::types::commitment::{AcknowledgementCommitment, PacketCommitment};
 use ibc::core::channel::types::packet::PacketState;
 use ibc::core::client::types::Height;
-use ibc::core::host::types::identifiers::{ClientId, Sequence};
+use ibc::core::host::types::identifiers::{ChannelId, ClientId, PortId, Sequence};
 use ibc::core::primitives::proto::Any;
 use ibc::primitives::prelude::*;
 use ibc::primitives::proto::Protobuf;
@@ -736,3 +736,36 @@ impl From<QueryNextSequenceSendResponse> for RawQueryNextSequenceSendResponse {
         }
     }
 }
+
+/// Defines the response type when querying the next sequence to be
+/// acknowledged on a channel.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct QueryNextSequenceAckResponse {
+    pub next_sequence_ack: Sequence,
+    pub proof: Proof,
+    pub proof_height: Height,
+}
+
+impl QueryNextSequenceAckResponse {
+    pub fn new(next_sequence_ack: Sequence, proof: Proof, proof_height: Height) -> Self {
+        Self {
+            next_sequence_ack,
+            proof,
+            proof_height,
+        }
+    }
+}
+
+/// The next sequences to send, receive and acknowledge on a channel.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct IdentifiedNextSequences {
+    pub port_id: PortId,
+    pub channel_id: ChannelId,
+    pub next_sequence_send: Sequence,
+    pub next_sequence_recv: Sequence,
+    pub next_sequence_ack: Sequence,
+}
@@ -162,7 +162,8 @@ where
     ))
 }
 
-/// Queries for the heights of all the consensus states of a given client id.
+/// Queries for the heights of all the consensus states of a given client id,
+/// in ascending order, paginated if the request says so.
 pub fn query_consensus_state_heights<I>(
     ibc_ctx: &I,
     request: &QueryConsensusStateHeightsRequest,
@@ -170,12 +171,21 @@ pub fn query_consensus_state_heights<I>(
 where
     I: QueryContext,
 {
-    let consensus_state_heights = ibc_ctx.consensus_state_heights(&request.client_id)?;
+    let mut consensus_state_heights = ibc_ctx.consensus_state_heights(&request.client_id)?;
+
+    consensus_state_heights.sort();
+
+    let (consensus_state_heights, pagination) = match &request.pagination {
+        Some(page_request) => {
+            let (page, page_response) = page_request.paginate(consensus_state_heights)?;
+            (page, Some(page_response))
+        }
+        None => (consensus_state_heights, None),
+    };
 
     Ok(QueryConsensusStateHeightsResponse::new(
         consensus_state_heights,
-        // no support for pagination yet
-        None,
+        pagination,
     ))
 }
 
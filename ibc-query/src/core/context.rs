@@ -6,10 +6,12 @@ use ibc::core::client::types::Height;
 use ibc::core::connection::types::IdentifiedConnectionEnd;
 use ibc::core::handler::types::error::ContextError;
 use ibc::core::host::types::identifiers::{ClientId, ConnectionId, Sequence};
-use ibc::core::host::types::path::{ChannelEndPath, Path};
+use ibc::core::host::types::path::{ChannelEndPath, Path, SeqAckPath, SeqRecvPath, SeqSendPath};
 use ibc::core::host::{ClientStateRef, ConsensusStateRef, ValidationContext};
 use ibc::core::primitives::prelude::*;
 
+use crate::core::channel::IdentifiedNextSequences;
+
 /// Context to be implemented by the host to provide proofs in query responses
 pub trait ProvableContext {
     /// Returns the proof for the given path at the given height.
@@ -49,6 +51,31 @@ pub trait QueryContext: ProvableContext + ValidationContext {
     /// Returns the list of all channel ends.
     fn channel_ends(&self) -> Result<Vec<IdentifiedChannelEnd>, ContextError>;
 
+    /// Returns the next sequences to send, receive and acknowledge on all the
+    /// channel ends, from which relayers recover the state of ordered channels
+    /// after a restart.
+    fn next_sequences(&self) -> Result<Vec<IdentifiedNextSequences>, ContextError> {
+        self.channel_ends()?
+            .into_iter()
+            .map(|chan| {
+                let next_sequence_send = self
+                    .get_next_sequence_send(&SeqSendPath::new(&chan.port_id, &chan.channel_id))?;
+                let next_sequence_recv = self
+                    .get_next_sequence_recv(&SeqRecvPath::new(&chan.port_id, &chan.channel_id))?;
+                let next_sequence_ack =
+                    self.get_next_sequence_ack(&SeqAckPath::new(&chan.port_id, &chan.channel_id))?;
+
+                Ok(IdentifiedNextSequences {
+                    port_id: chan.port_id,
+                    channel_id: chan.channel_id,
+                    next_sequence_send,
+                    next_sequence_recv,
+                    next_sequence_ack,
+                })
+            })
+            .collect()
+    }
+
     // Packet queries
 
     /// Returns the list of all packet commitments for the given channel end.
@@ -2,3 +2,4 @@ pub mod channel;
 pub mod client;
 pub mod connection;
 pub mod context;
+pub mod path;
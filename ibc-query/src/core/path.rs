@@ -0,0 +1,167 @@
+//! Provides the [`query`] facade, answering queries of the values stored under
+//! IBC store paths, as `abci_query` does for the keys of the IBC store.
+
+use ibc::core::channel::types::channel::ChannelEnd;
+use ibc::core::channel::types::commitment::{AcknowledgementCommitment, PacketCommitment};
+use ibc::core::channel::types::error::PacketError;
+use ibc::core::channel::types::packet::Receipt;
+use ibc::core::client::context::ClientValidationContext;
+use ibc::core::client::types::Height;
+use ibc::core::connection::types::proto::v1::ClientPaths;
+use ibc::core::connection::types::ConnectionEnd;
+use ibc::core::handler::types::error::ContextError;
+use ibc::core::host::types::identifiers::{ConnectionId, Sequence};
+use ibc::core::host::types::path::Path;
+use ibc::core::host::{ClientStateRef, ConsensusStateRef};
+use ibc::primitives::prelude::*;
+use ibc::primitives::proto::{Any, Protobuf};
+use ibc::primitives::ToVec;
+
+use crate::core::context::QueryContext;
+use crate::error::QueryError;
+
+/// The value stored under an IBC path.
+#[derive(Clone, Debug)]
+pub enum PathValue {
+    ClientState(Any),
+    ConsensusState(Any),
+    Connection(ConnectionEnd),
+    ClientConnections(Vec<ConnectionId>),
+    Channel(ChannelEnd),
+    /// The next sequence to send, receive or acknowledge on a channel.
+    NextSequence(Sequence),
+    /// The number of clients, connections or channels created so far.
+    Counter(u64),
+    PacketCommitment(PacketCommitment),
+    PacketReceipt(Receipt),
+    PacketAcknowledgement(AcknowledgementCommitment),
+}
+
+impl PathValue {
+    /// Encodes the value as it is stored by ibc-go, which is what the proofs
+    /// of the value are checked against.
+    pub fn into_bytes(self) -> Vec<u8> {
+        match self {
+            Self::ClientState(any) | Self::ConsensusState(any) => any.to_vec(),
+            Self::Connection(conn_end) => conn_end.encode_vec(),
+            Self::ClientConnections(conn_ids) => ClientPaths {
+                paths: conn_ids.iter().map(ToString::to_string).collect(),
+            }
+            .to_vec(),
+            Self::Channel(chan_end) => chan_end.encode_vec(),
+            Self::NextSequence(seq) => u64::from(seq).to_be_bytes().to_vec(),
+            Self::Counter(counter) => counter.to_be_bytes().to_vec(),
+            Self::PacketCommitment(commitment) => commitment.into_vec(),
+            Self::PacketReceipt(Receipt::Ok) => vec![1],
+            Self::PacketAcknowledgement(ack_commitment) => ack_commitment.into_vec(),
+        }
+    }
+}
+
+/// The response of a [`query`].
+#[derive(Clone, Debug)]
+pub struct PathQueryResponse {
+    /// The value stored under the path, or `None` if the path is the one of
+    /// a packet commitment, receipt or acknowledgement which is not stored.
+    pub value: Option<PathValue>,
+    /// The proof of the value, or of its absence, if it was requested.
+    pub proof: Option<Vec<u8>>,
+    /// The height of the proof.
+    pub proof_height: Height,
+}
+
+/// Queries for the value stored under the given path, along with the proof of
+/// it at the given height, or at the height of the host if none is given, if
+/// `prove` is set.
+///
+/// As with the other queries, the value is the one currently stored, whatever
+/// the height of the proof. The paths of the client update times and heights,
+/// of the ports and of the upgraded clients are not supported.
+pub fn query<I>(
+    ibc_ctx: &I,
+    path: &Path,
+    height: Option<Height>,
+    prove: bool,
+) -> Result<PathQueryResponse, QueryError>
+where
+    I: QueryContext,
+    ClientStateRef<I>: Into<Any>,
+    ConsensusStateRef<I>: Into<Any>,
+{
+    let value = match path {
+        Path::NextClientSequence(_) => Some(PathValue::Counter(ibc_ctx.client_counter()?)),
+        Path::NextConnectionSequence(_) => Some(PathValue::Counter(ibc_ctx.connection_counter()?)),
+        Path::NextChannelSequence(_) => Some(PathValue::Counter(ibc_ctx.channel_counter()?)),
+        Path::ClientState(client_state_path) => Some(PathValue::ClientState(
+            ibc_ctx
+                .get_client_validation_context()
+                .client_state(&client_state_path.0)?
+                .into(),
+        )),
+        Path::ClientConsensusState(consensus_state_path) => Some(PathValue::ConsensusState(
+            ibc_ctx
+                .get_client_validation_context()
+                .consensus_state(consensus_state_path)?
+                .into(),
+        )),
+        Path::ClientConnection(client_connection_path) => Some(PathValue::ClientConnections(
+            ibc_ctx.client_connection_ends(&client_connection_path.0)?,
+        )),
+        Path::Connection(connection_path) => Some(PathValue::Connection(
+            ibc_ctx.connection_end(&connection_path.0)?,
+        )),
+        Path::ChannelEnd(channel_end_path) => {
+            Some(PathValue::Channel(ibc_ctx.channel_end(channel_end_path)?))
+        }
+        Path::SeqSend(seq_send_path) => Some(PathValue::NextSequence(
+            ibc_ctx.get_next_sequence_send(seq_send_path)?,
+        )),
+        Path::SeqRecv(seq_recv_path) => Some(PathValue::NextSequence(
+            ibc_ctx.get_next_sequence_recv(seq_recv_path)?,
+        )),
+        Path::SeqAck(seq_ack_path) => Some(PathValue::NextSequence(
+            ibc_ctx.get_next_sequence_ack(seq_ack_path)?,
+        )),
+        Path::Commitment(commitment_path) => match ibc_ctx.get_packet_commitment(commitment_path) {
+            Ok(commitment) => Some(PathValue::PacketCommitment(commitment)),
+            Err(ContextError::PacketError(PacketError::PacketCommitmentNotFound { .. })) => None,
+            Err(e) => return Err(e.into()),
+        },
+        // Receipt only has one enum
+        // Unreceived packets are not stored
+        Path::Receipt(receipt_path) => ibc_ctx
+            .get_packet_receipt(receipt_path)
+            .ok()
+            .map(PathValue::PacketReceipt),
+        Path::Ack(ack_path) => match ibc_ctx.get_packet_acknowledgement(ack_path) {
+            Ok(ack_commitment) => Some(PathValue::PacketAcknowledgement(ack_commitment)),
+            Err(ContextError::PacketError(PacketError::PacketAcknowledgementNotFound {
+                ..
+            })) => None,
+            Err(e) => return Err(e.into()),
+        },
+        Path::ClientUpdateTime(_)
+        | Path::ClientUpdateHeight(_)
+        | Path::Ports(_)
+        | Path::UpgradeClient(_) => return Err(QueryError::unsupported_path(path)),
+    };
+
+    let proof_height = match height {
+        Some(height) => height,
+        None => ibc_ctx.host_height()?,
+    };
+
+    let proof = if prove {
+        Some(ibc_ctx.get_proof(proof_height, path).ok_or_else(|| {
+            QueryError::proof_not_found(format!("Proof not found for path: {path}"))
+        })?)
+    } else {
+        None
+    };
+
+    Ok(PathQueryResponse {
+        value,
+        proof,
+        proof_height,
+    })
+}
@@ -18,6 +18,21 @@ pub enum QueryError {
     ProofNotFound(String),
     /// Missing field: {0}
     MissingField(String),
+    /// Invalid page key: {0}
+    InvalidPageKey(String),
+    /// Unsupported path: {0}
+    UnsupportedPath(String),
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for QueryError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match &self {
+            Self::ContextError(e) => Some(e),
+            Self::IdentifierError(e) => Some(e),
+            _ => None,
+        }
+    }
 }
 
 impl QueryError {
@@ -28,6 +43,14 @@ impl QueryError {
     pub fn missing_field<T: ToString>(description: T) -> Self {
         Self::MissingField(description.to_string())
     }
+
+    pub fn invalid_page_key<T: ToString>(description: T) -> Self {
+        Self::InvalidPageKey(description.to_string())
+    }
+
+    pub fn unsupported_path<T: ToString>(path: T) -> Self {
+        Self::UnsupportedPath(path.to_string())
+    }
 }
 
 impl From<QueryError> for Status {
@@ -36,7 +59,9 @@ impl From<QueryError> for Status {
             QueryError::ContextError(ctx_err) => Self::internal(ctx_err.to_string()),
             QueryError::IdentifierError(id_err) => Self::internal(id_err.to_string()),
             QueryError::ProofNotFound(description) => Self::not_found(description),
-            QueryError::MissingField(description) => Self::invalid_argument(description),
+            QueryError::MissingField(description)
+            | QueryError::InvalidPageKey(description)
+            | QueryError::UnsupportedPath(description) => Self::invalid_argument(description),
         }
     }
 }
@@ -3,8 +3,14 @@ use ibc_proto::cosmos::base::query::v1beta1::{
     PageRequest as RawPageRequest, PageResponse as RawPageResponse,
 };
 
+use crate::error::QueryError;
+
 pub type Proof = Vec<u8>;
 
+/// The number of results in a page when the [`PageRequest`] leaves its limit
+/// unset, matching the default of the Cosmos SDK.
+pub const DEFAULT_PAGE_LIMIT: u64 = 100;
+
 #[derive(Clone, Debug, Default)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
@@ -36,6 +42,63 @@ impl PageRequest {
             ..Default::default()
         }
     }
+
+    /// Returns the page of `items` designated by this request, along with the
+    /// [`PageResponse`] to return with it.
+    ///
+    /// The items are paginated in the given order, or in the reverse order if
+    /// the request says so. The key of a page is the big-endian encoding of
+    /// the offset of its first item, as returned in the `next_key` of the
+    /// previous page.
+    pub fn paginate<T>(&self, mut items: Vec<T>) -> Result<(Vec<T>, PageResponse), QueryError> {
+        let total = items.len() as u64;
+
+        let offset = if self.key.is_empty() {
+            self.offset
+        } else {
+            <[u8; 8]>::try_from(self.key.as_slice())
+                .map(u64::from_be_bytes)
+                .map_err(|_| {
+                    QueryError::invalid_page_key(format!(
+                        "expected 8 bytes, got {}",
+                        self.key.len()
+                    ))
+                })?
+        };
+
+        let limit = if self.limit == 0 {
+            DEFAULT_PAGE_LIMIT
+        } else {
+            self.limit
+        };
+
+        if self.reverse {
+            items.reverse();
+        }
+
+        let page = items
+            .into_iter()
+            .skip(usize::try_from(offset).unwrap_or(usize::MAX))
+            .take(usize::try_from(limit).unwrap_or(usize::MAX))
+            .collect();
+
+        let end = offset.saturating_add(limit);
+
+        let response = PageResponse {
+            next_key: if end < total {
+                end.to_be_bytes().to_vec()
+            } else {
+                Vec::new()
+            },
+            total: if self.count_total && self.key.is_empty() {
+                total
+            } else {
+                0
+            },
+        };
+
+        Ok((page, response))
+    }
 }
 
 impl From<PageRequest> for RawPageRequest {
@@ -1,13 +1,20 @@
 use core::fmt::Debug;
 use core::time::Duration;
 
+use basecoin_store::avl::get_proof_spec as basecoin_proof_spec;
 use basecoin_store::context::ProvableStore;
 use basecoin_store::impls::InMemoryStore;
+use ibc::clients::tendermint::client_state::verify_membership;
 use ibc::core::channel::types::channel::ChannelEnd;
-use ibc::core::channel::types::commitment::PacketCommitment;
+use ibc::core::channel::types::commitment::{AcknowledgementCommitment, PacketCommitment};
 use ibc::core::client::context::client_state::ClientStateValidation;
 use ibc::core::client::context::{ClientExecutionContext, ClientValidationContext};
+use ibc::core::client::types::error::ClientError;
 use ibc::core::client::types::Height;
+use ibc::core::commitment_types::commitment::{CommitmentProofBytes, CommitmentRoot};
+use ibc::core::commitment_types::merkle::MerkleProof;
+use ibc::core::commitment_types::proto::ics23::HostFunctionsManager;
+use ibc::core::commitment_types::specs::ProofSpecs;
 use ibc::core::connection::types::ConnectionEnd;
 use ibc::core::entrypoint::{dispatch, execute, validate};
 use ibc::core::handler::types::error::ContextError;
@@ -15,12 +22,14 @@ use ibc::core::handler::types::events::IbcEvent;
 use ibc::core::handler::types::msgs::MsgEnvelope;
 use ibc::core::host::types::identifiers::{ChannelId, ClientId, ConnectionId, PortId, Sequence};
 use ibc::core::host::types::path::{
-    ChannelEndPath, ClientConsensusStatePath, ClientStatePath, CommitmentPath, ConnectionPath,
-    SeqAckPath, SeqRecvPath, SeqSendPath,
+    AckPath, ChannelEndPath, ClientConsensusStatePath, ClientStatePath, CommitmentPath,
+    ConnectionPath, Path, SeqAckPath, SeqRecvPath, SeqSendPath, UpgradeClientPath,
 };
 use ibc::core::host::{ExecutionContext, ValidationContext};
 use ibc::primitives::prelude::*;
-use ibc::primitives::Timestamp;
+use ibc::primitives::proto::{Any, Protobuf};
+use ibc::primitives::{Timestamp, ToVec};
+use ibc_proto::ibc::core::commitment::v1::MerkleProof as RawMerkleProof;
 
 use super::testapp::ibc::core::types::{LightClientState, MockIbcStore};
 use crate::fixtures::core::context::TestContextConfig;
@@ -42,6 +51,15 @@ where
     /// This is where the IBC store root is stored at IBC commitment prefix.
     pub multi_store: S,
 
+    /// The store of the upgrade module of the host chain.
+    /// This is where the upgraded client and consensus states are written, and
+    /// its root is stored in the multi store at [`UPGRADE_STORE_KEY`].
+    pub upgrade_store: S,
+
+    /// The roots of the multi store committed in the blocks of the host chain,
+    /// indexed by height, against which the proofs of the context verify.
+    pub commitment_roots: BTreeMap<u64, CommitmentRoot>,
+
     /// The type of host chain underlying this mock context.
     pub host: H,
 
@@ -52,6 +70,16 @@ where
     pub ibc_router: MockRouter,
 }
 
+/// The key of the upgrade store root in the multi store, which is the prefix
+/// of the upgrade paths.
+pub const UPGRADE_STORE_KEY: &str = "upgrade";
+
+/// Returns the proof specs of the IBC store and the multi store of the
+/// contexts, which are both AVL trees.
+pub fn store_proof_specs() -> ProofSpecs {
+    vec![basecoin_proof_spec(); 2].try_into().expect("no error")
+}
+
 /// A mock store type using basecoin-storage implementations.
 pub type MockStore = InMemoryStore;
 /// A [`StoreGenericTestContext`] using [`MockStore`].
@@ -154,6 +182,9 @@ where
         // generate a genesis block
         // this is basically self.host.produce_block() but with
         // block height 1 and block timestamp `genesis_time`.
+        self.commitment_roots
+            .insert(1, multi_store_commitment.clone().into());
+
         let genesis_block =
             self.host
                 .generate_block(multi_store_commitment, 1, genesis_time, params);
@@ -199,7 +230,8 @@ where
 
     /// End the current block on the context.
     ///
-    /// This method commits the state of the IBC store and the host's multi store.
+    /// This method commits the state of the IBC and upgrade stores and the host's
+    /// multi store.
     pub fn end_block(&mut self) {
         // commit ibc store
         let ibc_store_commitment = self.ibc_store.end_block().expect("no error");
@@ -215,18 +247,35 @@ where
                 ibc_store_commitment,
             )
             .expect("no error");
+
+        // commit upgrade store
+        let upgrade_store_commitment = self.upgrade_store.commit().expect("no error");
+
+        // commit upgrade store commitment in multi store
+        self.multi_store
+            .set(
+                UPGRADE_STORE_KEY.to_owned().into(),
+                upgrade_store_commitment,
+            )
+            .expect("no error");
     }
 
     /// Commit store state to the current block of the host chain by:
     /// - Committing the state to the context's multi store.
     /// - Generating a new block with the commitment.
     /// - Adding the generated block to the host's block history.
+    /// - Recording the commitment as the root of the block.
     pub fn commit_state_to_host(&mut self, block_time: Duration, params: &H::BlockParams) {
         // commit the multi store
         let multi_store_commitment = self.multi_store.commit().expect("no error");
         // generate a new block and add it to the block history
         self.host
-            .commit_block(multi_store_commitment, block_time, params);
+            .commit_block(multi_store_commitment.clone(), block_time, params);
+        // record the root the proofs at the new height verify against
+        self.commitment_roots.insert(
+            self.host.latest_height().revision_height(),
+            multi_store_commitment.into(),
+        );
     }
 
     /// Advances the host chain height by ending the current block, producing a new block, and
@@ -249,6 +298,24 @@ where
         )
     }
 
+    /// Advances the host chain time by `duration`, producing a single block
+    /// whose timestamp is `duration` after the latest one, as the time of the
+    /// chain only moves forward with its blocks.
+    ///
+    /// As with any block produced by the context, the consensus state of the
+    /// new block is stored as a host consensus state.
+    pub fn advance_time(&mut self, duration: Duration) {
+        self.advance_block_height_with_params(duration, &Default::default())
+    }
+
+    /// Advances the host chain height by `n` blocks, produced with the default
+    /// block time.
+    pub fn produce_blocks(&mut self, n: u64) {
+        for _ in 0..n {
+            self.advance_block_height()
+        }
+    }
+
     /// Returns the latest height of the host chain.
     pub fn latest_height(&self) -> Height {
         let latest_ibc_height = self.ibc_store.host_height().expect("Never fails");
@@ -462,6 +529,149 @@ where
         self
     }
 
+    /// Bootstraps a packet acknowledgement to this context.
+    ///
+    /// This does not bootstrap any corresponding IBC channel, connection or light client.
+    pub fn with_packet_acknowledgement(
+        mut self,
+        port_id: PortId,
+        chan_id: ChannelId,
+        seq: Sequence,
+        ack_commitment: AcknowledgementCommitment,
+    ) -> Self {
+        let ack_path = AckPath::new(&port_id, &chan_id, seq);
+        self.ibc_store
+            .store_packet_acknowledgement(&ack_path, ack_commitment)
+            .expect("error writing to store");
+        self
+    }
+
+    /// Returns the root of the multi store committed in the block of the host
+    /// chain at the given height.
+    pub fn commitment_root(&self, height: &Height) -> Option<CommitmentRoot> {
+        self.commitment_roots
+            .get(&height.revision_height())
+            .cloned()
+    }
+
+    /// Verifies that `value` is stored under `path` in the IBC store at the
+    /// given height, given a proof returned by
+    /// [`ProvableContext::get_proof`](ibc_query::core::context::ProvableContext::get_proof).
+    ///
+    /// The proof is verified against the [`commitment_root`](Self::commitment_root)
+    /// at this height, by the ICS-23 verification of the Tendermint client,
+    /// with the proof specs of the stores of the context.
+    pub fn verify_membership(
+        &self,
+        height: &Height,
+        path: Path,
+        value: Vec<u8>,
+        proof: &CommitmentProofBytes,
+    ) -> Result<(), ClientError> {
+        let root = self
+            .commitment_root(height)
+            .ok_or_else(|| ClientError::Other {
+                description: format!("no commitment root at height {height}"),
+            })?;
+
+        verify_membership::<HostFunctionsManager>(
+            &store_proof_specs(),
+            &self.ibc_store.commitment_prefix(),
+            proof,
+            &root,
+            path,
+            value,
+        )
+    }
+
+    /// Schedules an upgrade of the host chain, by writing the client and
+    /// consensus states to which the counterparty chains upgrade their clients
+    /// of the host chain under the upgrade paths.
+    ///
+    /// The states are written under the next height of the host chain, and
+    /// become provable once the host chain is advanced to it. Returns this
+    /// height, to which the clients must be updated before being upgraded.
+    pub fn schedule_upgrade(
+        &mut self,
+        upgraded_client_state: AnyClientState,
+        upgraded_consensus_state: AnyConsensusState,
+    ) -> Height {
+        let upgrade_height = self.latest_height().increment();
+
+        self.upgrade_store
+            .set(
+                UpgradeClientPath::UpgradedClientState(upgrade_height.revision_height())
+                    .to_string()
+                    .into(),
+                Protobuf::<Any>::encode_vec(upgraded_client_state),
+            )
+            .expect("no error");
+        self.upgrade_store
+            .set(
+                UpgradeClientPath::UpgradedClientConsensusState(upgrade_height.revision_height())
+                    .to_string()
+                    .into(),
+                Protobuf::<Any>::encode_vec(upgraded_consensus_state),
+            )
+            .expect("no error");
+
+        upgrade_height
+    }
+
+    /// Returns the upgraded client and consensus states scheduled for the given
+    /// upgrade height, if any.
+    pub fn upgraded_states(
+        &self,
+        upgrade_height: Height,
+    ) -> Option<(AnyClientState, AnyConsensusState)> {
+        let height = upgrade_height.revision_height();
+
+        let client_state = self.upgrade_store.get(
+            height.into(),
+            &UpgradeClientPath::UpgradedClientState(height)
+                .to_string()
+                .into(),
+        )?;
+        let consensus_state = self.upgrade_store.get(
+            height.into(),
+            &UpgradeClientPath::UpgradedClientConsensusState(height)
+                .to_string()
+                .into(),
+        )?;
+
+        Some((
+            Protobuf::<Any>::decode_vec(&client_state).expect("valid client state"),
+            Protobuf::<Any>::decode_vec(&consensus_state).expect("valid consensus state"),
+        ))
+    }
+
+    /// Returns the proof of the value stored under the given upgrade path at
+    /// the given height, against the root of the multi store at that height.
+    pub fn get_upgrade_proof(
+        &self,
+        height: Height,
+        path: &UpgradeClientPath,
+    ) -> Option<CommitmentProofBytes> {
+        let path_proof = self
+            .upgrade_store
+            .get_proof(height.revision_height().into(), &path.to_string().into())?;
+
+        let upgrade_commitment_proof = self
+            .multi_store
+            .get_proof(
+                height.revision_height().into(),
+                &UPGRADE_STORE_KEY.to_owned().into(),
+            )
+            .expect("upgrade store commitment exists");
+
+        RawMerkleProof::from(MerkleProof {
+            proofs: vec![path_proof, upgrade_commitment_proof],
+        })
+        .to_vec()
+        .try_into()
+        .ok()
+    }
+
     /// Calls [`validate`] function on [`MsgEnvelope`] using the context's IBC store and router.
     pub fn validate(&mut self, msg: MsgEnvelope) -> Result<(), ContextError> {
         validate(&self.ibc_store, &self.ibc_router, msg)
@@ -502,6 +712,7 @@ where
 #[cfg(test)]
 mod tests {
     use ibc::core::client::context::consensus_state::ConsensusState;
+    use ibc_query::core::context::ProvableContext;
 
     use super::*;
     use crate::hosts::{HostConsensusState, MockHost, TendermintHost};
@@ -609,4 +820,112 @@ mod tests {
         run_tests::<MockHost>("Mock Host");
         run_tests::<TendermintHost>("Synthetic TM Host");
     }
+
+    #[test]
+    fn test_time_control() {
+        fn run_test<H>()
+        where
+            H: TestHost,
+            HostClientState<H>: ClientStateValidation<DefaultIbcStore>,
+        {
+            let mut ctx = TestContext::<H>::default();
+            let height = ctx.latest_height();
+            let timestamp = ctx.latest_timestamp();
+
+            ctx.advance_time(Duration::from_secs(3600));
+
+            assert_eq!(ctx.latest_height(), height.increment());
+            assert_eq!(
+                ctx.latest_timestamp(),
+                (timestamp + Duration::from_secs(3600)).expect("no overflow")
+            );
+            assert_eq!(
+                ctx.ibc_store.host_timestamp().expect("no error"),
+                ctx.latest_timestamp()
+            );
+
+            ctx.produce_blocks(3);
+
+            assert_eq!(ctx.latest_height(), height.add(4));
+            assert_eq!(
+                ctx.latest_timestamp(),
+                (timestamp + Duration::from_secs(3600 + 3 * DEFAULT_BLOCK_TIME_SECS))
+                    .expect("no overflow")
+            );
+            assert!(ctx
+                .ibc_store
+                .host_consensus_state(&ctx.latest_height())
+                .is_ok());
+        }
+
+        run_test::<MockHost>();
+        run_test::<TendermintHost>();
+    }
+
+    fn context_with_packet_commitment<H>() -> TestContext<H>
+    where
+        H: TestHost,
+        HostClientState<H>: ClientStateValidation<DefaultIbcStore>,
+    {
+        let mut ctx = TestContext::<H>::default().with_packet_commitment(
+            PortId::transfer(),
+            ChannelId::zero(),
+            Sequence::from(1),
+            vec![1, 2, 3].into(),
+        );
+        ctx.advance_block_height();
+        ctx
+    }
+
+    #[test]
+    fn test_store_proofs_verify() {
+        fn run_test<H>()
+        where
+            H: TestHost,
+            HostClientState<H>: ClientStateValidation<DefaultIbcStore>,
+        {
+            let ctx = context_with_packet_commitment::<H>();
+
+            let height = ctx.latest_height();
+            let path: Path =
+                CommitmentPath::new(&PortId::transfer(), &ChannelId::zero(), Sequence::from(1))
+                    .into();
+            let proof: CommitmentProofBytes = ctx
+                .ibc_store
+                .get_proof(height, &path)
+                .expect("packet commitment exists")
+                .try_into()
+                .expect("value merkle proof");
+
+            assert!(ctx
+                .verify_membership(&height, path.clone(), vec![1, 2, 3], &proof)
+                .is_ok());
+            assert!(ctx
+                .verify_membership(&height, path.clone(), vec![4, 5, 6], &proof)
+                .is_err());
+            assert!(ctx
+                .verify_membership(
+                    &height.decrement().expect("no error"),
+                    path,
+                    vec![1, 2, 3],
+                    &proof
+                )
+                .is_err());
+        }
+
+        run_test::<MockHost>();
+        run_test::<TendermintHost>();
+    }
+
+    #[test]
+    fn test_commitment_roots_deterministic() {
+        assert_eq!(
+            context_with_packet_commitment::<MockHost>().commitment_roots,
+            context_with_packet_commitment::<MockHost>().commitment_roots,
+        );
+        assert_eq!(
+            context_with_packet_commitment::<TendermintHost>().commitment_roots,
+            context_with_packet_commitment::<TendermintHost>().commitment_roots,
+        );
+    }
 }
@@ -37,8 +37,7 @@ impl From<MsgTransferConfig> for MsgTransfer {
 }
 
 pub fn extract_transfer_packet(msg: &MsgTransfer, sequence: Sequence) -> Packet {
-    let data = serde_json::to_vec(&msg.packet_data)
-        .expect("PacketData's infallible Serialize impl failed");
+    let data = msg.packet_data.encode_json().into_bytes();
 
     Packet {
         seq_on_a: sequence,
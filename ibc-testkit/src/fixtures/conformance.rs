@@ -0,0 +1,367 @@
+//! Validation vectors for the identifiers, denominations and NFT class
+//! identifiers exchanged over IBC, mirroring the validation of ibc-go, along
+//! with packet vectors mirroring the encodings and commitments of ibc-go.
+//!
+//! Each validation vector pairs an input with whether ibc-go accepts it, so
+//! that any implementation can assert its own parsing against the same
+//! tables, and interoperate with ibc-go at the parsing layer.
+
+use ibc::apps::nft_transfer::types::packet::PacketData as NftPacketData;
+use ibc::apps::transfer::types::packet::PacketData;
+use ibc::apps::transfer::types::PrefixedCoin;
+use ibc::core::channel::types::timeout::TimeoutHeight;
+use ibc::core::client::types::Height;
+use ibc::core::primitives::prelude::*;
+use ibc::core::primitives::Timestamp;
+
+/// An identifier, along with whether ibc-go accepts it.
+///
+/// ibc-go accepts identifiers made of the characters matched by
+/// `^[a-zA-Z0-9\.\_\+\-\#\[\]\<\>]+$`, within the length bounds of their kind.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct IdentifierVector {
+    pub id: String,
+    pub valid: bool,
+}
+
+impl IdentifierVector {
+    fn new(id: impl Into<String>, valid: bool) -> Self {
+        Self {
+            id: id.into(),
+            valid,
+        }
+    }
+}
+
+/// A denomination or NFT class identifier, along with the trace path and the
+/// base it is split into, or `None` if it is rejected.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TraceVector {
+    pub input: &'static str,
+    pub expected: Option<(&'static str, &'static str)>,
+}
+
+/// Vectors checking the characters allowed in all identifiers, with the
+/// identifier length bounds of `min..=max`.
+fn identifier_vectors(prefix: &str, min: usize, max: usize) -> Vec<IdentifierVector> {
+    // Truncates or pads the prefix to an identifier of the given length.
+    let padded = |len: usize| match len.checked_sub(prefix.len()) {
+        Some(padding) => format!("{prefix}{}", "0".repeat(padding)),
+        None => prefix[..len].to_string(),
+    };
+
+    vec![
+        IdentifierVector::new(padded(min), true),
+        IdentifierVector::new(padded(max), true),
+        IdentifierVector::new(padded(max + 1), false),
+        IdentifierVector::new(padded(min - 1), false),
+        IdentifierVector::new("", false),
+        IdentifierVector::new(format!("{prefix}._+-#[]<>"), true),
+        IdentifierVector::new(format!("{prefix}/0"), false),
+        IdentifierVector::new(format!("{prefix} 0"), false),
+        IdentifierVector::new(format!("{prefix}@0"), false),
+        IdentifierVector::new(format!("{prefix}\n"), false),
+        IdentifierVector::new(format!("{prefix}é"), false),
+        IdentifierVector::new(format!("{prefix}٣"), false),
+    ]
+}
+
+/// Client identifiers, of 9 to 64 characters.
+pub fn client_identifiers() -> Vec<IdentifierVector> {
+    let mut vectors = identifier_vectors("07-tendermint-", 9, 64);
+    vectors.extend([
+        IdentifierVector::new("07-tendermint-0", true),
+        IdentifierVector::new("08-wasm-18446744073709551615", true),
+        IdentifierVector::new("tendermint", true),
+    ]);
+    vectors
+}
+
+/// Connection identifiers, of 10 to 64 characters.
+///
+/// ibc-go only accepts identifiers of the form `connection-{sequence}` for the
+/// connections it opens, as ibc-rs does by default, but accepts any valid
+/// identifier otherwise.
+pub fn connection_identifiers() -> Vec<IdentifierVector> {
+    let mut vectors = identifier_vectors("connection-", 10, 64);
+    vectors.extend([
+        IdentifierVector::new("connection-0", true),
+        IdentifierVector::new("connection-18446744073709551615", true),
+        IdentifierVector::new("connection-to-osmosis", true),
+        IdentifierVector::new("conn-0", false),
+    ]);
+    vectors
+}
+
+/// Channel identifiers, of 8 to 64 characters.
+///
+/// As for connections, ibc-go only accepts identifiers of the form
+/// `channel-{sequence}` for the channels it opens.
+pub fn channel_identifiers() -> Vec<IdentifierVector> {
+    let mut vectors = identifier_vectors("channel-", 8, 64);
+    vectors.extend([
+        IdentifierVector::new("channel-0", true),
+        IdentifierVector::new("channel-18446744073709551615", true),
+        IdentifierVector::new("channel-to-osmosis", true),
+        IdentifierVector::new("chan-0", false),
+    ]);
+    vectors
+}
+
+/// Port identifiers, of 2 to 128 characters.
+pub fn port_identifiers() -> Vec<IdentifierVector> {
+    let mut vectors = identifier_vectors("transfer", 2, 128);
+    vectors.extend([
+        IdentifierVector::new("nft-transfer", true),
+        IdentifierVector::new(
+            "wasm.osmo14hj2tavq8fpesdwxxcu44rty3hh90vhujrvcmstl4zr3txmfvw9sq2r9g9",
+            true,
+        ),
+        IdentifierVector::new("icacontroller-cosmos1xyz", true),
+    ]);
+    vectors
+}
+
+/// Vectors shared by denominations and NFT class identifiers, which are
+/// traced the same way: the leading `{port-id}/{channel-id}` pairs form the
+/// trace path, and whatever remains is the base.
+const TRACES: &[TraceVector] = &[
+    TraceVector {
+        input: "base",
+        expected: Some(("", "base")),
+    },
+    TraceVector {
+        input: "transfer/channel-0/base",
+        expected: Some(("transfer/channel-0", "base")),
+    },
+    TraceVector {
+        input: "transfer/channel-0/transfer/channel-1/base",
+        expected: Some(("transfer/channel-0/transfer/channel-1", "base")),
+    },
+    TraceVector {
+        input: "transfer/channel-75/factory/stars16da2uus9zrsy83h23ur42v3lglg5rmyrpqnju4/dust",
+        expected: Some((
+            "transfer/channel-75",
+            "factory/stars16da2uus9zrsy83h23ur42v3lglg5rmyrpqnju4/dust",
+        )),
+    },
+    TraceVector {
+        input: "transfer/channel-0/gamm/pool/1",
+        expected: Some(("transfer/channel-0", "gamm/pool/1")),
+    },
+    TraceVector {
+        input: "transfer/base",
+        expected: Some(("", "transfer/base")),
+    },
+    TraceVector {
+        input: "channel-0/transfer/base",
+        expected: Some(("", "channel-0/transfer/base")),
+    },
+    TraceVector {
+        input: "",
+        expected: None,
+    },
+    TraceVector {
+        input: "   ",
+        expected: None,
+    },
+    TraceVector {
+        input: "transfer/channel-0/",
+        expected: None,
+    },
+    // ibc-go reads a lone pair as a base, but several pairs as a trace path
+    // without base.
+    TraceVector {
+        input: "transfer/channel-0",
+        expected: Some(("", "transfer/channel-0")),
+    },
+    TraceVector {
+        input: "transfer/channel-0/transfer/channel-1",
+        expected: None,
+    },
+    // ibc-go reads `/channel-0` as a trace prefix with an empty port.
+    TraceVector {
+        input: "/channel-0/base",
+        expected: None,
+    },
+];
+
+/// Fungible token denominations, as traced by ICS-20.
+pub fn denom_traces() -> Vec<TraceVector> {
+    let mut vectors = TRACES.to_vec();
+    vectors.extend([
+        TraceVector {
+            input: "uatom",
+            expected: Some(("", "uatom")),
+        },
+        TraceVector {
+            input: "transfer/channel-141/uosmo",
+            expected: Some(("transfer/channel-141", "uosmo")),
+        },
+    ]);
+    vectors
+}
+
+/// NFT class identifiers, as traced by ICS-721.
+pub fn class_id_traces() -> Vec<TraceVector> {
+    let mut vectors = TRACES.to_vec();
+    vectors.extend([
+        TraceVector {
+            input: "nft-transfer/channel-0/myclass",
+            expected: Some(("nft-transfer/channel-0", "myclass")),
+        },
+        TraceVector {
+            input: "wasm.stars1ve46fjrhcrum94c7d8yc2wsdz8cpuw73503e8qn9r44spr6dw0lsvmvtqh/channel-3/onft",
+            expected: Some((
+                "wasm.stars1ve46fjrhcrum94c7d8yc2wsdz8cpuw73503e8qn9r44spr6dw0lsvmvtqh/channel-3",
+                "onft",
+            )),
+        },
+    ]);
+    vectors
+}
+
+/// A packet, along with the bytes its data is encoded into by ibc-go, and the
+/// commitment ibc-go stores for it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PacketVector<D> {
+    pub packet_data: D,
+    pub encoded: &'static str,
+    pub timeout_height: TimeoutHeight,
+    pub timeout_timestamp: Timestamp,
+    /// The hex-encoded commitment.
+    pub commitment: &'static str,
+}
+
+const SENDER: &str = "cosmos1wxeyh7zgn4tctjzs0vtqpc6p5cxq5t2muzl7ng";
+const RECEIVER: &str = "osmo1wxeyh7zgn4tctjzs0vtqpc6p5cxq5t2mzkf0ue";
+
+/// The ICS-721 class and token data, which is base64-encoded in the packets.
+const NFT_DATA: &str =
+    r#"{"image":{"value":"binary","mime":"image/png"},"name":{"value":"Crypto Creatures"}}"#;
+
+/// The packet data of the multi-token ICS-721 packet, as encoded by the Go
+/// implementation of ICS-721.
+const NFT_MULTI_TOKEN_ENCODED: &str = r#"{"classData":"eyJpbWFnZSI6eyJ2YWx1ZSI6ImJpbmFyeSIsIm1pbWUiOiJpbWFnZS9wbmcifSwibmFtZSI6eyJ2YWx1ZSI6IkNyeXB0byBDcmVhdHVyZXMifX0=","classId":"nft-transfer/channel-0/class","classUri":"https://example.com/class","memo":"memo","receiver":"osmo1wxeyh7zgn4tctjzs0vtqpc6p5cxq5t2mzkf0ue","sender":"cosmos1wxeyh7zgn4tctjzs0vtqpc6p5cxq5t2muzl7ng","tokenData":["eyJpbWFnZSI6eyJ2YWx1ZSI6ImJpbmFyeSIsIm1pbWUiOiJpbWFnZS9wbmcifSwibmFtZSI6eyJ2YWx1ZSI6IkNyeXB0byBDcmVhdHVyZXMifX0=","eyJpbWFnZSI6eyJ2YWx1ZSI6ImJpbmFyeSIsIm1pbWUiOiJpbWFnZS9wbmcifSwibmFtZSI6eyJ2YWx1ZSI6IkNyeXB0byBDcmVhdHVyZXMifX0="],"tokenIds":["token_0","token_1"],"tokenUris":["https://example.com/token/0","https://example.com/token/1"]}"#;
+
+fn transfer_packet_data(denom: &str, amount: &str, memo: &str) -> PacketData {
+    PacketData {
+        token: PrefixedCoin {
+            denom: denom.parse().expect("valid denom"),
+            amount: amount.parse().expect("valid amount"),
+        },
+        sender: SENDER.to_string().into(),
+        receiver: RECEIVER.to_string().into(),
+        memo: memo.into(),
+    }
+}
+
+/// ICS-20 packets, whose data ibc-go encodes with sorted keys, omitting an
+/// empty memo and HTML-escaping the strings.
+pub fn transfer_packets() -> Vec<PacketVector<PacketData>> {
+    let height = |revision_number, revision_height| {
+        TimeoutHeight::At(Height::new(revision_number, revision_height).expect("valid height"))
+    };
+    let timestamp =
+        |nanoseconds| Timestamp::from_nanoseconds(nanoseconds).expect("valid timestamp");
+
+    vec![
+        PacketVector {
+            packet_data: transfer_packet_data("uatom", "100", ""),
+            encoded: r#"{"amount":"100","denom":"uatom","receiver":"osmo1wxeyh7zgn4tctjzs0vtqpc6p5cxq5t2mzkf0ue","sender":"cosmos1wxeyh7zgn4tctjzs0vtqpc6p5cxq5t2muzl7ng"}"#,
+            timeout_height: height(1, 100),
+            timeout_timestamp: Timestamp::none(),
+            commitment: "57ce8492200c8cd81d4ae28f68598a51a30906df2510dfeeea0ef42c659e5201",
+        },
+        PacketVector {
+            packet_data: transfer_packet_data(
+                "uatom",
+                "1000000",
+                r#"{"forward":{"receiver":"osmo1wxeyh7zgn4tctjzs0vtqpc6p5cxq5t2mzkf0ue","port":"transfer","channel":"channel-0"}}"#,
+            ),
+            encoded: r#"{"amount":"1000000","denom":"uatom","memo":"{\"forward\":{\"receiver\":\"osmo1wxeyh7zgn4tctjzs0vtqpc6p5cxq5t2mzkf0ue\",\"port\":\"transfer\",\"channel\":\"channel-0\"}}","receiver":"osmo1wxeyh7zgn4tctjzs0vtqpc6p5cxq5t2mzkf0ue","sender":"cosmos1wxeyh7zgn4tctjzs0vtqpc6p5cxq5t2muzl7ng"}"#,
+            timeout_height: TimeoutHeight::Never,
+            timeout_timestamp: timestamp(1_700_000_000_000_000_000),
+            commitment: "b1364e4cc5e941db49a68bf92d5a1a0b8a9169d0e49f36c09b54a5a777770055",
+        },
+        PacketVector {
+            packet_data: transfer_packet_data(
+                "transfer/channel-141/uosmo",
+                "18446744073709551616",
+                "a <b> & c",
+            ),
+            encoded: r#"{"amount":"18446744073709551616","denom":"transfer/channel-141/uosmo","memo":"a \u003cb\u003e \u0026 c","receiver":"osmo1wxeyh7zgn4tctjzs0vtqpc6p5cxq5t2mzkf0ue","sender":"cosmos1wxeyh7zgn4tctjzs0vtqpc6p5cxq5t2muzl7ng"}"#,
+            timeout_height: height(4, 123_456),
+            timeout_timestamp: timestamp(1_700_000_000_000_000_000),
+            commitment: "e4b760e93ed288bf671d3c0a853fed895d2bef688d33e3cf1cc2fafbcde3ceff",
+        },
+    ]
+}
+
+/// ICS-721 packets, whose data the Go implementation of ICS-721 encodes with
+/// sorted keys, omitting the fields which are not set.
+pub fn nft_transfer_packets() -> Vec<PacketVector<NftPacketData>> {
+    let single_token = NftPacketData::new(
+        "class".parse().expect("valid class ID"),
+        None,
+        None,
+        vec!["token_0".to_string()]
+            .try_into()
+            .expect("valid token IDs"),
+        vec![],
+        vec![],
+        SENDER.to_string().into(),
+        RECEIVER.to_string().into(),
+        "".into(),
+    )
+    .expect("valid packet data");
+
+    let multi_token = NftPacketData::new(
+        "nft-transfer/channel-0/class"
+            .parse()
+            .expect("valid class ID"),
+        Some(
+            "https://example.com/class"
+                .parse()
+                .expect("valid class URI"),
+        ),
+        Some(NFT_DATA.parse().expect("valid class data")),
+        vec!["token_0".to_string(), "token_1".to_string()]
+            .try_into()
+            .expect("valid token IDs"),
+        vec![
+            "https://example.com/token/0"
+                .parse()
+                .expect("valid token URI"),
+            "https://example.com/token/1"
+                .parse()
+                .expect("valid token URI"),
+        ],
+        vec![
+            NFT_DATA.parse().expect("valid token data"),
+            NFT_DATA.parse().expect("valid token data"),
+        ],
+        SENDER.to_string().into(),
+        RECEIVER.to_string().into(),
+        "memo".into(),
+    )
+    .expect("valid packet data");
+
+    vec![
+        PacketVector {
+            packet_data: single_token,
+            encoded: r#"{"classId":"class","receiver":"osmo1wxeyh7zgn4tctjzs0vtqpc6p5cxq5t2mzkf0ue","sender":"cosmos1wxeyh7zgn4tctjzs0vtqpc6p5cxq5t2muzl7ng","tokenIds":["token_0"]}"#,
+            timeout_height: TimeoutHeight::At(Height::new(1, 100).expect("valid height")),
+            timeout_timestamp: Timestamp::none(),
+            commitment: "9a0bbb5a8308591df54a5270bdd8d6c9ec3a313b11e741f6d14d1c68ee7f1dd4",
+        },
+        PacketVector {
+            packet_data: multi_token,
+            encoded: NFT_MULTI_TOKEN_ENCODED,
+            timeout_height: TimeoutHeight::Never,
+            timeout_timestamp: Timestamp::from_nanoseconds(1_700_000_000_000_000_000)
+                .expect("valid timestamp"),
+            commitment: "13e1850edd6ecb314f8a3840289dbcfdfdad10a131ae3c459d603215cdcb1eee",
+        },
+    ]
+}
@@ -66,6 +66,28 @@ mod test {
                 },
                 want_pass: false,
             },
+            Test {
+                name: "Dst channel, counterparty alias".to_string(),
+                raw: RawMsgAcknowledgement {
+                    packet: default_raw_msg.packet.clone().map(|packet| RawPacket {
+                        destination_channel: "channel-to-osmosis".to_string(),
+                        ..packet
+                    }),
+                    ..default_raw_msg.clone()
+                },
+                want_pass: true,
+            },
+            Test {
+                name: "Bad src channel, not of the form channel-{N}".to_string(),
+                raw: RawMsgAcknowledgement {
+                    packet: default_raw_msg.packet.clone().map(|packet| RawPacket {
+                        source_channel: "channel-to-osmosis".to_string(),
+                        ..packet
+                    }),
+                    ..default_raw_msg.clone()
+                },
+                want_pass: false,
+            },
             Test {
                 name: "Empty signer".to_string(),
                 raw: RawMsgAcknowledgement {
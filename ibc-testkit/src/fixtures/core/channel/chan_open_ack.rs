@@ -101,6 +101,22 @@ mod tests {
                 },
                 want_pass: true,
             },
+            Test {
+                name: "[Counterparty] Alias channel identifier".to_string(),
+                raw: RawMsgChannelOpenAck {
+                    counterparty_channel_id: "channel-to-osmosis".to_string(),
+                    ..default_raw_msg.clone()
+                },
+                want_pass: true,
+            },
+            Test {
+                name: "Bad channel, not of the form channel-{N}".to_string(),
+                raw: RawMsgChannelOpenAck {
+                    channel_id: "channel-to-osmosis".to_string(),
+                    ..default_raw_msg.clone()
+                },
+                want_pass: false,
+            },
             Test {
                 name: "[Counterparty] Bad channel, name too short".to_string(),
                 raw: RawMsgChannelOpenAck {
@@ -57,9 +57,27 @@ pub fn dummy_raw_channel_end(state: i32, channel_id: Option<u64>) -> RawChannel
 mod tests {
     use core::str::FromStr;
 
-    use ibc::core::channel::types::channel::ChannelEnd;
+    use ibc::core::channel::types::channel::{ChannelEnd, Counterparty};
 
     use super::*;
+
+    #[test]
+    fn counterparty_verify_channel_id_matches() {
+        let counterparty = Counterparty::try_new(PortId::transfer(), None).unwrap();
+        assert!(counterparty
+            .verify_channel_id_matches(&ChannelId::new(1))
+            .is_ok());
+
+        let counterparty =
+            Counterparty::try_new(PortId::transfer(), Some(ChannelId::new(0))).unwrap();
+        assert!(counterparty
+            .verify_channel_id_matches(&ChannelId::new(0))
+            .is_ok());
+        assert!(counterparty
+            .verify_channel_id_matches(&ChannelId::new(1))
+            .is_err());
+    }
+
     #[test]
     fn channel_end_try_from_raw() {
         let raw_channel_end = dummy_raw_channel_end(2, Some(0));
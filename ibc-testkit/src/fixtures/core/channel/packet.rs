@@ -166,6 +166,14 @@ mod tests {
                 },
                 want_pass: true,
             },
+            Test {
+                name: "Bad src channel, not of the form channel-{N}".to_string(),
+                raw: RawPacket {
+                    source_channel: "channel-to-osmosis".to_string(),
+                    ..default_raw_packet.clone()
+                },
+                want_pass: false,
+            },
             Test {
                 name: "Bad src channel, name too short".to_string(),
                 raw: RawPacket {
@@ -190,6 +198,14 @@ mod tests {
                 },
                 want_pass: true,
             },
+            Test {
+                name: "Bad dst channel, not of the form channel-{N}".to_string(),
+                raw: RawPacket {
+                    destination_channel: "channel-to-osmosis".to_string(),
+                    ..default_raw_packet.clone()
+                },
+                want_pass: false,
+            },
             Test {
                 name: "Bad dst channel, name too short".to_string(),
                 raw: RawPacket {
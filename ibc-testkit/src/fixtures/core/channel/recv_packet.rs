@@ -47,6 +47,7 @@ pub fn dummy_raw_msg_recv_packet(height: u64) -> RawMsgRecvPacket {
 #[cfg(test)]
 mod test {
     use ibc::core::channel::types::error::PacketError;
+    use ibc::core::channel::types::proto::v1::Packet as RawPacket;
     use ibc::primitives::prelude::*;
 
     use super::*;
@@ -83,6 +84,28 @@ mod test {
                 },
                 want_pass: false,
             },
+            Test {
+                name: "Src channel, counterparty alias".to_string(),
+                raw: RawMsgRecvPacket {
+                    packet: default_raw_msg.packet.clone().map(|packet| RawPacket {
+                        source_channel: "channel-to-osmosis".to_string(),
+                        ..packet
+                    }),
+                    ..default_raw_msg.clone()
+                },
+                want_pass: true,
+            },
+            Test {
+                name: "Bad dst channel, not of the form channel-{N}".to_string(),
+                raw: RawMsgRecvPacket {
+                    packet: default_raw_msg.packet.clone().map(|packet| RawPacket {
+                        destination_channel: "channel-to-osmosis".to_string(),
+                        ..packet
+                    }),
+                    ..default_raw_msg.clone()
+                },
+                want_pass: false,
+            },
             Test {
                 name: "Empty signer".to_string(),
                 raw: RawMsgRecvPacket {
@@ -45,6 +45,7 @@ mod tests {
             "consensus_height",
             "consensus_heights",
             "header",
+            "update_kind",
         ];
 
         let expected_values = vec![
@@ -53,6 +54,7 @@ mod tests {
             "0-5",
             "0-5,0-7",
             "0a102f6962632e6d6f636b2e48656164657212040a021005",
+            "new_header",
         ];
 
         let tests: Vec<Test> = vec![
@@ -83,6 +85,12 @@ mod tests {
                 expected_keys: expected_keys[0..3].to_vec(),
                 expected_values: expected_values[0..3].to_vec(),
             },
+            Test {
+                event_kind: RECOVER_CLIENT_EVENT,
+                event: RecoverClient::new(client_id.clone(), client_type.clone()).into(),
+                expected_keys: vec!["subject_client_id", "client_type"],
+                expected_values: expected_values[0..2].to_vec(),
+            },
             Test {
                 event_kind: CLIENT_MISBEHAVIOUR_EVENT,
                 event: ClientMisbehaviour::new(client_id, client_type).into(),
@@ -112,4 +120,34 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn parse_update_client_consensus_heights() {
+        let consensus_heights = vec![
+            Height::new(0, 5).unwrap(),
+            Height::new(0, 7).unwrap(),
+            Height::new(1, 1).unwrap(),
+        ];
+        let event: AbciEvent = UpdateClient::new(
+            ClientId::new("07-tendermint", 0).unwrap(),
+            ClientType::from_str("07-tendermint").unwrap(),
+            consensus_heights[0],
+            consensus_heights.clone(),
+            vec![],
+        )
+        .into();
+
+        let value = event
+            .attributes
+            .iter()
+            .find(|attr| attr.key_str().ok() == Some(CONSENSUS_HEIGHTS_ATTRIBUTE_KEY))
+            .and_then(|attr| attr.value_str().ok())
+            .unwrap();
+        assert_eq!(value, "0-5,0-7,1-1");
+        assert_eq!(parse_consensus_heights(value).unwrap(), consensus_heights);
+
+        assert!(parse_consensus_heights("").unwrap().is_empty());
+        assert!(parse_consensus_heights("0-5,").is_err());
+        assert!(parse_consensus_heights("0-5;0-7").is_err());
+    }
 }
@@ -73,6 +73,22 @@ mod tests {
                 },
                 want_pass: false,
             },
+            Test {
+                name: "Bad connection id, not of the form connection-{N}".to_string(),
+                raw: RawMsgConnectionOpenAck {
+                    connection_id: "connection-to-osmosis".to_string(),
+                    ..default_ack_msg.clone()
+                },
+                want_pass: false,
+            },
+            Test {
+                name: "Counterparty connection id, alias".to_string(),
+                raw: RawMsgConnectionOpenAck {
+                    counterparty_connection_id: "connection-to-osmosis".to_string(),
+                    ..default_ack_msg.clone()
+                },
+                want_pass: true,
+            },
             Test {
                 name: "Bad version, missing version".to_string(),
                 raw: RawMsgConnectionOpenAck {
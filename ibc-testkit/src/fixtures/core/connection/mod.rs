@@ -50,3 +50,55 @@ pub fn dummy_raw_counterparty_conn(conn_id: Option<u64>) -> RawCounterparty {
         }),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use ibc::core::commitment_types::commitment::CommitmentPrefix;
+    use ibc::core::connection::types::error::ConnectionError;
+    use ibc::core::connection::types::Counterparty;
+    use ibc::core::host::types::identifiers::ClientId;
+
+    use super::*;
+
+    #[test]
+    fn counterparty_try_new() {
+        let client_id = ClientId::new("07-tendermint", 0).unwrap();
+
+        let counterparty = Counterparty::try_new(
+            client_id.clone(),
+            None,
+            CommitmentPrefix::try_from(b"ibc".to_vec()).unwrap(),
+        )
+        .unwrap();
+        assert!(counterparty
+            .verify_connection_id_matches(&ConnectionId::new(1))
+            .is_ok());
+
+        let res = Counterparty::try_new(client_id.clone(), None, CommitmentPrefix::empty());
+        assert!(matches!(res, Err(ConnectionError::Client(_))));
+
+        let res = Counterparty::try_new(
+            client_id.clone(),
+            None,
+            CommitmentPrefix::try_from(vec![0xff, 0xfe]).unwrap(),
+        );
+        assert!(matches!(
+            res,
+            Err(ConnectionError::MalformedCounterpartyPrefix { .. })
+        ));
+
+        let counterparty = Counterparty::try_new(
+            client_id,
+            Some(ConnectionId::new(0)),
+            CommitmentPrefix::try_from(b"ibc".to_vec()).unwrap(),
+        )
+        .unwrap();
+        assert!(counterparty
+            .verify_connection_id_matches(&ConnectionId::new(0))
+            .is_ok());
+        assert!(matches!(
+            counterparty.verify_connection_id_matches(&ConnectionId::new(1)),
+            Err(ConnectionError::MismatchedCounterpartyConnectionId { .. })
+        ));
+    }
+}
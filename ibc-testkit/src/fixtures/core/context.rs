@@ -58,6 +58,8 @@ where
 
         let mut context = Self {
             multi_store: Default::default(),
+            upgrade_store: Default::default(),
+            commitment_roots: Default::default(),
             host: params.host,
             ibc_store: MockIbcStore::new(
                 params.latest_height.revision_number(),
@@ -1,5 +1,6 @@
 pub mod applications;
 pub mod clients;
+pub mod conformance;
 pub mod core;
 use alloc::fmt::Debug;
 
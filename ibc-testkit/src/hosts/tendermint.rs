@@ -84,6 +84,7 @@ impl TestHost for TendermintHost {
             .max_clock_drift(params.max_clock_drift)
             .unbonding_period(params.unbonding_period)
             .proof_specs(params.proof_specs.clone())
+            .upgrade_path(params.upgrade_path.clone())
             .build()
             .into_client_state(
                 self.chain_id.clone(),
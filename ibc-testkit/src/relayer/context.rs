@@ -1,5 +1,6 @@
 use ibc::core::channel::types::packet::Packet;
 use ibc::core::client::context::client_state::ClientStateValidation;
+use ibc::core::client::types::Height;
 use ibc::core::host::types::identifiers::{ChannelId, ClientId, ConnectionId, PortId};
 use ibc::core::host::types::path::ChannelEndPath;
 use ibc::core::host::ValidationContext;
@@ -54,6 +55,12 @@ where
         &mut self.ctx_b
     }
 
+    /// Returns the two contexts, e.g. to relay between one of them and a
+    /// third context.
+    pub fn into_contexts(self) -> (TestContext<A>, TestContext<B>) {
+        (self.ctx_a, self.ctx_b)
+    }
+
     /// Creates a light client of second context on the first context.
     /// Returns the client identifier of the created client.
     pub fn create_client_on_a(&mut self, signer: Signer) -> ClientId {
@@ -86,6 +93,40 @@ where
         )
     }
 
+    /// Upgrades the client on the first context to the states scheduled by the
+    /// second context at `upgrade_height`.
+    pub fn upgrade_client_on_a(
+        &mut self,
+        client_id_on_a: ClientId,
+        upgrade_height: Height,
+        signer: Signer,
+    ) {
+        TypedRelayerOps::<A, B>::upgrade_client_on_a(
+            &mut self.ctx_a,
+            &self.ctx_b,
+            client_id_on_a,
+            upgrade_height,
+            signer,
+        )
+    }
+
+    /// Upgrades the client on the second context to the states scheduled by the
+    /// first context at `upgrade_height`.
+    pub fn upgrade_client_on_b(
+        &mut self,
+        client_id_on_b: ClientId,
+        upgrade_height: Height,
+        signer: Signer,
+    ) {
+        TypedRelayerOps::<B, A>::upgrade_client_on_a(
+            &mut self.ctx_b,
+            &self.ctx_a,
+            client_id_on_b,
+            upgrade_height,
+            signer,
+        )
+    }
+
     /// Creates a connection between the two contexts starting from the first context.
     /// Returns the connection identifiers of the created connection ends.
     pub fn create_connection_on_a(
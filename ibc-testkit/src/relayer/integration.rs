@@ -1,4 +1,5 @@
-use ibc::core::client::context::client_state::ClientStateValidation;
+use ibc::core::client::context::client_state::{ClientStateCommon, ClientStateValidation};
+use ibc::core::client::context::ClientValidationContext;
 use ibc::core::host::types::identifiers::{ChannelId, ConnectionId, PortId};
 
 use crate::context::TestContext;
@@ -162,6 +163,87 @@ where
     }
 }
 
+/// Integration test for a [`TestHost`] hosting, at once, the light clients
+/// of two other [`TestHost`]s, which may be of different client types.
+///
+/// This creates a client and a connection between `A` and `B`, and between
+/// `A` and `C`, then opens a channel between `A` and `B`, so that the client
+/// of `B` on `A` is updated after the client of `C` was created on `A`.
+pub fn ibc_multi_client_integration_test<A, B, C>()
+where
+    A: TestHost,
+    B: TestHost,
+    C: TestHost,
+    HostClientState<A>: ClientStateValidation<DefaultIbcStore>,
+    HostClientState<B>: ClientStateValidation<DefaultIbcStore>,
+    HostClientState<C>: ClientStateValidation<DefaultIbcStore>,
+{
+    let ctx_a = TestContext::<A>::default();
+    let ctx_b = TestContext::<B>::default();
+    let ctx_c = TestContext::<C>::default();
+
+    let signer = dummy_account_id();
+
+    // client and connection between A and B
+    let mut relayer = RelayerContext::new(ctx_a, ctx_b);
+
+    let client_id_of_b_on_a = relayer.create_client_on_a(signer.clone());
+    let client_id_of_a_on_b = relayer.create_client_on_b(signer.clone());
+
+    let (conn_id_of_b_on_a, conn_id_of_a_on_b) = relayer.create_connection_on_a(
+        client_id_of_b_on_a.clone(),
+        client_id_of_a_on_b,
+        signer.clone(),
+    );
+
+    let (ctx_a, ctx_b) = relayer.into_contexts();
+
+    // client and connection between A and C
+    let mut relayer = RelayerContext::new(ctx_a, ctx_c);
+
+    let client_id_of_c_on_a = relayer.create_client_on_a(signer.clone());
+    let client_id_of_a_on_c = relayer.create_client_on_b(signer.clone());
+
+    let (conn_id_of_c_on_a, _) = relayer.create_connection_on_a(
+        client_id_of_c_on_a.clone(),
+        client_id_of_a_on_c,
+        signer.clone(),
+    );
+
+    assert_eq!(conn_id_of_b_on_a, ConnectionId::new(0));
+    assert_eq!(conn_id_of_c_on_a, ConnectionId::new(1));
+
+    // A hosts both clients, each of them of the client type of its host
+    for (client_id, counter) in [(&client_id_of_b_on_a, 0), (&client_id_of_c_on_a, 1)] {
+        let client_state = relayer
+            .get_ctx_a()
+            .ibc_store()
+            .client_state(client_id)
+            .expect("client exists");
+
+        assert_eq!(
+            client_id,
+            &client_state.client_type().build_client_id(counter)
+        );
+    }
+
+    let (ctx_a, _) = relayer.into_contexts();
+
+    // channel between A and B, updating the client of B on A
+    let mut relayer = RelayerContext::new(ctx_a, ctx_b);
+
+    let (chan_id_on_a, chan_id_on_b) = relayer.create_channel_on_a(
+        conn_id_of_b_on_a,
+        PortId::transfer(),
+        conn_id_of_a_on_b,
+        PortId::transfer(),
+        signer,
+    );
+
+    assert_eq!(chan_id_on_a, ChannelId::new(0));
+    assert_eq!(chan_id_on_b, ChannelId::new(0));
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -175,4 +257,10 @@ mod tests {
         ibc_integration_test::<TendermintHost, MockHost>();
         ibc_integration_test::<TendermintHost, TendermintHost>();
     }
+
+    #[test]
+    fn ibc_multi_client_integration_test_for_mixed_hosts() {
+        ibc_multi_client_integration_test::<MockHost, TendermintHost, MockHost>();
+        ibc_multi_client_integration_test::<TendermintHost, MockHost, TendermintHost>();
+    }
 }
@@ -0,0 +1,212 @@
+//! Generates pseudo-random, but reproducible, packet traffic between two
+//! [`TestContext`](crate::context::TestContext)s, to soak-test host context
+//! implementations against the handlers.
+
+#[cfg(feature = "serde")]
+use ibc::core::client::context::client_state::ClientStateValidation;
+#[cfg(feature = "serde")]
+use ibc::core::host::types::identifiers::{ChannelId, ConnectionId, PortId};
+#[cfg(feature = "serde")]
+use ibc::primitives::Signer;
+
+#[cfg(feature = "serde")]
+use crate::hosts::{HostClientState, TestHost};
+#[cfg(feature = "serde")]
+use crate::relayer::context::RelayerContext;
+#[cfg(feature = "serde")]
+use crate::testapp::ibc::core::types::DefaultIbcStore;
+
+/// An action of the traffic between two contexts, all of them starting with a
+/// transfer packet sent from the first context to the second one.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TrafficAction {
+    /// The packet is received on the second context, and acknowledged on the
+    /// first one.
+    Transfer,
+    /// The packet is never received, and times out on the first context.
+    Timeout,
+    /// The channel is closed, and the packet times out on close on the first
+    /// context. A new channel then replaces the closed one.
+    ChannelClosure,
+}
+
+/// Generates an endless sequence of [`TrafficAction`]s from a seed, the same
+/// seed always generating the same sequence.
+///
+/// Transfers make up about six actions out of ten, timeouts three out of ten,
+/// and channel closures the remaining one.
+#[derive(Clone, Debug)]
+pub struct TrafficGenerator {
+    state: u64,
+}
+
+impl TrafficGenerator {
+    pub fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    /// Returns the next pseudo-random number, as generated by SplitMix64.
+    fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+}
+
+impl Iterator for TrafficGenerator {
+    type Item = TrafficAction;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let action = match self.next_u64() % 10 {
+            0 => TrafficAction::ChannelClosure,
+            1..=3 => TrafficAction::Timeout,
+            _ => TrafficAction::Transfer,
+        };
+
+        Some(action)
+    }
+}
+
+/// Relays the given actions between the two contexts of `relayer`, over
+/// transfer channels opened on the given connection between them.
+///
+/// Returns the identifiers, on the first and the second context, of the
+/// channel left open once all the actions are relayed.
+///
+/// Requires `serde` feature because of
+/// [`RelayerContext::send_dummy_transfer_packet_on_a`].
+#[cfg(feature = "serde")]
+pub fn relay_traffic<A, B>(
+    relayer: &mut RelayerContext<A, B>,
+    conn_id_on_a: ConnectionId,
+    conn_id_on_b: ConnectionId,
+    actions: impl IntoIterator<Item = TrafficAction>,
+    signer: Signer,
+) -> (ChannelId, ChannelId)
+where
+    A: TestHost,
+    B: TestHost,
+    HostClientState<A>: ClientStateValidation<DefaultIbcStore>,
+    HostClientState<B>: ClientStateValidation<DefaultIbcStore>,
+{
+    let (mut chan_id_on_a, mut chan_id_on_b) = relayer.create_channel_on_a(
+        conn_id_on_a.clone(),
+        PortId::transfer(),
+        conn_id_on_b.clone(),
+        PortId::transfer(),
+        signer.clone(),
+    );
+
+    for action in actions {
+        let packet = relayer.send_dummy_transfer_packet_on_a(chan_id_on_a.clone(), signer.clone());
+
+        match action {
+            TrafficAction::Transfer => relayer.submit_packet_on_b(packet, signer.clone()),
+            TrafficAction::Timeout => relayer.timeout_packet_from_a(packet, signer.clone()),
+            TrafficAction::ChannelClosure => {
+                relayer.timeout_packet_from_a_on_channel_close(packet, signer.clone());
+
+                (chan_id_on_a, chan_id_on_b) = relayer.create_channel_on_a(
+                    conn_id_on_a.clone(),
+                    PortId::transfer(),
+                    conn_id_on_b.clone(),
+                    PortId::transfer(),
+                    signer.clone(),
+                );
+            }
+        }
+    }
+
+    (chan_id_on_a, chan_id_on_b)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn traffic_generator_is_reproducible() {
+        let actions: Vec<_> = TrafficGenerator::new(42).take(100).collect();
+
+        assert_eq!(
+            actions,
+            TrafficGenerator::new(42).take(100).collect::<Vec<_>>()
+        );
+        assert_ne!(
+            actions,
+            TrafficGenerator::new(43).take(100).collect::<Vec<_>>()
+        );
+
+        for action in [
+            TrafficAction::Transfer,
+            TrafficAction::Timeout,
+            TrafficAction::ChannelClosure,
+        ] {
+            assert!(actions.contains(&action), "{action:?} is never generated");
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn relay_generated_traffic() {
+        use ibc::core::host::types::path::SeqSendPath;
+        use ibc::core::host::ValidationContext;
+
+        use crate::context::MockContext;
+        use crate::fixtures::core::signer::dummy_account_id;
+
+        let signer = dummy_account_id();
+
+        let mut relayer = RelayerContext::new(MockContext::default(), MockContext::default());
+
+        let client_id_on_a = relayer.create_client_on_a(signer.clone());
+        let client_id_on_b = relayer.create_client_on_b(signer.clone());
+
+        let (conn_id_on_a, conn_id_on_b) =
+            relayer.create_connection_on_a(client_id_on_a, client_id_on_b, signer.clone());
+
+        let actions: Vec<_> = TrafficGenerator::new(7).take(18).collect();
+
+        let (chan_id_on_a, chan_id_on_b) = relay_traffic(
+            &mut relayer,
+            conn_id_on_a,
+            conn_id_on_b,
+            actions.iter().copied(),
+            signer,
+        );
+
+        // A new channel is opened upon each channel closure.
+        let closures = actions
+            .iter()
+            .filter(|action| **action == TrafficAction::ChannelClosure)
+            .count() as u64;
+
+        assert_eq!(chan_id_on_a, ChannelId::new(closures));
+        assert_eq!(chan_id_on_b, ChannelId::new(closures));
+        assert_eq!(
+            relayer.get_ctx_a().ibc_store().channel_counter().unwrap(),
+            closures + 1
+        );
+
+        // The sequences of the packets sent over the channel left open start
+        // anew.
+        let sent_on_last_channel = actions
+            .iter()
+            .rev()
+            .take_while(|action| **action != TrafficAction::ChannelClosure)
+            .count() as u64;
+
+        assert_eq!(
+            u64::from(
+                relayer
+                    .get_ctx_a()
+                    .ibc_store()
+                    .get_next_sequence_send(&SeqSendPath::new(&PortId::transfer(), &chan_id_on_a))
+                    .unwrap()
+            ),
+            sent_on_last_channel + 1
+        );
+    }
+}
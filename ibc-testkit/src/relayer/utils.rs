@@ -14,7 +14,10 @@ use ibc::core::channel::types::timeout::TimeoutHeight;
 use ibc::core::channel::types::Version as ChannelVersion;
 use ibc::core::client::context::client_state::ClientStateValidation;
 use ibc::core::client::context::ClientValidationContext;
-use ibc::core::client::types::msgs::{ClientMsg, MsgCreateClient, MsgUpdateClient};
+use ibc::core::client::types::msgs::{
+    ClientMsg, MsgCreateClient, MsgUpdateClient, MsgUpgradeClient,
+};
+use ibc::core::client::types::Height;
 use ibc::core::connection::types::msgs::{
     ConnectionMsg, MsgConnectionOpenAck, MsgConnectionOpenConfirm, MsgConnectionOpenInit,
     MsgConnectionOpenTry,
@@ -26,7 +29,7 @@ use ibc::core::handler::types::msgs::MsgEnvelope;
 use ibc::core::host::types::identifiers::{ChannelId, ClientId, ConnectionId, PortId};
 use ibc::core::host::types::path::{
     AckPath, ChannelEndPath, ClientConsensusStatePath, ClientStatePath, CommitmentPath,
-    ConnectionPath, ReceiptPath,
+    ConnectionPath, ReceiptPath, UpgradeClientPath,
 };
 use ibc::core::host::ValidationContext;
 use ibc::primitives::Signer;
@@ -160,6 +163,55 @@ where
         TypedRelayerOps::<A, B>::update_client_on_a(ctx_a, ctx_b, client_id_on_a, signer);
     }
 
+    /// Upgrades the client of `B` on `A` to the client and consensus states
+    /// scheduled by `B` at `upgrade_height`, using
+    /// [`TestContext::schedule_upgrade`].
+    ///
+    /// The client on `A` must be updated to `upgrade_height` beforehand, as
+    /// the upgraded states are proven against the consensus state of `B` at
+    /// this height.
+    pub fn upgrade_client_on_a(
+        ctx_a: &mut TestContext<A>,
+        ctx_b: &TestContext<B>,
+        client_id_on_a: ClientId,
+        upgrade_height: Height,
+        signer: Signer,
+    ) {
+        let (upgraded_client_state, upgraded_consensus_state) = ctx_b
+            .upgraded_states(upgrade_height)
+            .expect("upgrade is scheduled");
+
+        let proof_upgrade_client = ctx_b
+            .get_upgrade_proof(
+                upgrade_height,
+                &UpgradeClientPath::UpgradedClientState(upgrade_height.revision_height()),
+            )
+            .expect("upgraded client state exists");
+
+        let proof_upgrade_consensus_state = ctx_b
+            .get_upgrade_proof(
+                upgrade_height,
+                &UpgradeClientPath::UpgradedClientConsensusState(upgrade_height.revision_height()),
+            )
+            .expect("upgraded consensus state exists");
+
+        let msg_for_a = MsgEnvelope::Client(ClientMsg::UpgradeClient(MsgUpgradeClient {
+            client_id: client_id_on_a,
+            upgraded_client_state: upgraded_client_state.into(),
+            upgraded_consensus_state: upgraded_consensus_state.into(),
+            proof_upgrade_client,
+            proof_upgrade_consensus_state,
+            signer,
+        }));
+
+        ctx_a.deliver(msg_for_a).expect("success");
+
+        let Some(IbcEvent::UpgradeClient(_)) = ctx_a.ibc_store().events.lock().last().cloned()
+        else {
+            panic!("unexpected event")
+        };
+    }
+
     /// `A` initiates a connection with the other end on `B`.
     /// Returns the connection identifier on `A`.
     pub fn connection_open_init_on_a(
@@ -0,0 +1,133 @@
+//! Defines a mock bank, moving fungible and non-fungible tokens in memory.
+
+#[cfg(feature = "serde")]
+use ibc::apps::nft_transfer::bank::NftAsset;
+use ibc::apps::transfer::types::{Amount, PrefixedCoin, PrefixedDenom};
+use ibc::core::primitives::prelude::*;
+use ibc::core::primitives::Signer;
+use ibc::core::router::bank::BankContext;
+use ibc::core::router::types::error::BankError;
+use ibc::core::router::types::module::ModuleAccount;
+
+/// A [`BankContext`] holding the balances of fungible tokens, and the owners
+/// of NFTs, of the accounts in memory.
+///
+/// The account of an application is the signer named after the
+/// [`ModuleAccount`], e.g. `transfer/channel-0` for the escrow account of the
+/// channel `channel-0` of ICS-20.
+#[derive(Debug, Default)]
+pub struct MockBank {
+    pub balances: BTreeMap<(Signer, PrefixedDenom), Amount>,
+    #[cfg(feature = "serde")]
+    pub nft_owners: BTreeMap<NftAsset, Signer>,
+}
+
+impl MockBank {
+    /// Returns the balance of `account` in `denom`, which is zero if it never
+    /// held any.
+    pub fn balance(&self, account: &Signer, denom: &PrefixedDenom) -> Amount {
+        self.balances
+            .get(&(account.clone(), denom.clone()))
+            .copied()
+            .unwrap_or_else(|| 0u64.into())
+    }
+
+    /// Returns the owner of `nft`, if it exists.
+    #[cfg(feature = "serde")]
+    pub fn nft_owner(&self, nft: &NftAsset) -> Option<&Signer> {
+        self.nft_owners.get(nft)
+    }
+}
+
+impl BankContext<PrefixedCoin> for MockBank {
+    type AccountId = Signer;
+
+    fn module_account(&self, account: &ModuleAccount) -> Result<Signer, BankError> {
+        Ok(account.to_string().into())
+    }
+
+    fn mint_to(&mut self, account: &Signer, coin: &PrefixedCoin) -> Result<(), BankError> {
+        let balance = self
+            .balance(account, &coin.denom)
+            .checked_add(coin.amount)
+            .ok_or_else(|| BankError::BalanceOverflow {
+                account: account.to_string(),
+                asset: coin.to_string(),
+            })?;
+
+        self.balances
+            .insert((account.clone(), coin.denom.clone()), balance);
+
+        Ok(())
+    }
+
+    fn burn_from(&mut self, account: &Signer, coin: &PrefixedCoin) -> Result<(), BankError> {
+        let balance = self
+            .balance(account, &coin.denom)
+            .checked_sub(coin.amount)
+            .ok_or_else(|| BankError::InsufficientFunds {
+                account: account.to_string(),
+                asset: coin.to_string(),
+            })?;
+
+        self.balances
+            .insert((account.clone(), coin.denom.clone()), balance);
+
+        Ok(())
+    }
+
+    fn transfer(
+        &mut self,
+        from: &Signer,
+        to: &Signer,
+        coin: &PrefixedCoin,
+    ) -> Result<(), BankError> {
+        BankContext::<PrefixedCoin>::burn_from(self, from, coin)?;
+        BankContext::<PrefixedCoin>::mint_to(self, to, coin)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl BankContext<NftAsset> for MockBank {
+    type AccountId = Signer;
+
+    fn module_account(&self, account: &ModuleAccount) -> Result<Signer, BankError> {
+        Ok(account.to_string().into())
+    }
+
+    fn mint_to(&mut self, account: &Signer, nft: &NftAsset) -> Result<(), BankError> {
+        if self.nft_owners.contains_key(nft) {
+            return Err(BankError::Other(format!("NFT `{nft}` already exists")));
+        }
+
+        self.nft_owners.insert(nft.clone(), account.clone());
+
+        Ok(())
+    }
+
+    fn burn_from(&mut self, account: &Signer, nft: &NftAsset) -> Result<(), BankError> {
+        if self.nft_owner(nft) != Some(account) {
+            return Err(BankError::InsufficientFunds {
+                account: account.to_string(),
+                asset: nft.to_string(),
+            });
+        }
+
+        self.nft_owners.remove(nft);
+
+        Ok(())
+    }
+
+    fn transfer(&mut self, from: &Signer, to: &Signer, nft: &NftAsset) -> Result<(), BankError> {
+        if self.nft_owner(nft) != Some(from) {
+            return Err(BankError::InsufficientFunds {
+                account: from.to_string(),
+                asset: nft.to_string(),
+            });
+        }
+
+        self.nft_owners.insert(nft.clone(), to.clone());
+
+        Ok(())
+    }
+}
@@ -1,2 +1,3 @@
+pub mod bank;
 pub mod nft_transfer;
 pub mod transfer;
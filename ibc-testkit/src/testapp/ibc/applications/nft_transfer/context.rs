@@ -1,5 +1,6 @@
 use ibc::apps::nft_transfer::context::{
     NftClassContext, NftContext, NftTransferExecutionContext, NftTransferValidationContext,
+    TokenPagination,
 };
 use ibc::apps::nft_transfer::types::error::NftTransferError;
 use ibc::apps::nft_transfer::types::{
@@ -127,6 +128,22 @@ impl NftTransferValidationContext for DummyNftTransferModule {
     ) -> Result<Self::NftClass, NftTransferError> {
         Ok(DummyNftClass::default())
     }
+
+    fn owner_of(
+        &self,
+        _class_id: &PrefixedClassId,
+        _token_id: &TokenId,
+    ) -> Result<Self::AccountId, NftTransferError> {
+        Err(NftTransferError::NftNotFound)
+    }
+
+    fn tokens_of_class(
+        &self,
+        _class_id: &PrefixedClassId,
+        _pagination: &TokenPagination,
+    ) -> Result<Vec<TokenId>, NftTransferError> {
+        Ok(vec![])
+    }
 }
 
 impl NftTransferExecutionContext for DummyNftTransferModule {
@@ -6,7 +6,8 @@ use ibc::core::channel::types::Version;
 use ibc::core::host::types::identifiers::{ChannelId, ConnectionId, PortId};
 use ibc::core::primitives::prelude::*;
 use ibc::core::primitives::Signer;
-use ibc::core::router::module::Module;
+use ibc::core::router::module::{Module, OnRecvPacketResult};
+use ibc::core::router::types::error::AppError;
 use ibc::core::router::types::module::ModuleExtras;
 
 use super::types::DummyNftTransferModule;
@@ -64,11 +65,11 @@ impl Module for DummyNftTransferModule {
         &mut self,
         _packet: &Packet,
         _relayer: &Signer,
-    ) -> (ModuleExtras, Acknowledgement) {
-        (
+    ) -> Result<OnRecvPacketResult, AppError> {
+        Ok(OnRecvPacketResult::sync(
             ModuleExtras::empty(),
             Acknowledgement::try_from(vec![1u8]).expect("Never fails"),
-        )
+        ))
     }
 
     fn on_timeout_packet_validate(
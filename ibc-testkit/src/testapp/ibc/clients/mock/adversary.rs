@@ -0,0 +1,247 @@
+//! Defines adversarial headers for the mock client, with which tests inject
+//! faulty client messages and assert how the core handlers reject them.
+
+use core::fmt::{Display, Error as FmtError, Formatter};
+
+use ibc::core::client::types::error::ClientError;
+use ibc::core::client::types::Height;
+use ibc::core::primitives::prelude::*;
+use ibc::core::primitives::Timestamp;
+use ibc::primitives::proto::{Any, Protobuf};
+
+use crate::testapp::ibc::clients::mock::header::MockHeader;
+use crate::testapp::ibc::clients::mock::proto::Header as RawMockHeader;
+
+pub const MOCK_FORGED_HEADER_TYPE_URL: &str = "/ibc.mock.ForgedHeader";
+pub const MOCK_SKIPPING_HEADER_TYPE_URL: &str = "/ibc.mock.SkippingHeader";
+pub const MOCK_FUTURE_HEADER_TYPE_URL: &str = "/ibc.mock.FutureHeader";
+pub const MOCK_CONFLICTING_HEADER_TYPE_URL: &str = "/ibc.mock.ConflictingHeader";
+
+/// The fault carried by an [`AdversarialHeader`], which determines how the
+/// mock client handles it.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum HeaderFault {
+    /// The header was not produced by the counterparty chain. The mock
+    /// client always fails to verify it.
+    Forged,
+    /// The header may skip heights. The mock client fails to verify it if its
+    /// height is past the one following the latest height of the client.
+    SkippedHeights,
+    /// The header may be ahead of the host's clock. The mock client fails to
+    /// verify it if its timestamp is after the host timestamp.
+    FutureTimestamp,
+    /// The header conflicts with the history of the counterparty chain. The
+    /// mock client verifies it, then reports it as misbehaviour and freezes.
+    Conflicting,
+}
+
+impl HeaderFault {
+    pub fn type_url(&self) -> &'static str {
+        match self {
+            Self::Forged => MOCK_FORGED_HEADER_TYPE_URL,
+            Self::SkippedHeights => MOCK_SKIPPING_HEADER_TYPE_URL,
+            Self::FutureTimestamp => MOCK_FUTURE_HEADER_TYPE_URL,
+            Self::Conflicting => MOCK_CONFLICTING_HEADER_TYPE_URL,
+        }
+    }
+
+    pub fn from_type_url(type_url: &str) -> Option<Self> {
+        match type_url {
+            MOCK_FORGED_HEADER_TYPE_URL => Some(Self::Forged),
+            MOCK_SKIPPING_HEADER_TYPE_URL => Some(Self::SkippedHeights),
+            MOCK_FUTURE_HEADER_TYPE_URL => Some(Self::FutureTimestamp),
+            MOCK_CONFLICTING_HEADER_TYPE_URL => Some(Self::Conflicting),
+            _ => None,
+        }
+    }
+}
+
+/// A [`MockHeader`] carrying a [`HeaderFault`], submitted to the mock client
+/// in place of a regular header.
+///
+/// The fault is encoded in the type URL, so that an adversarial header is
+/// encoded as a regular header under a different type URL.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct AdversarialHeader {
+    pub header: MockHeader,
+    pub fault: HeaderFault,
+}
+
+impl AdversarialHeader {
+    pub fn new(header: MockHeader, fault: HeaderFault) -> Self {
+        Self { header, fault }
+    }
+
+    /// Returns a forged header at the given height.
+    pub fn forged(height: Height) -> Self {
+        Self::new(
+            MockHeader::new(height).with_current_timestamp(),
+            HeaderFault::Forged,
+        )
+    }
+
+    /// Returns a header skipping the given number of heights past the
+    /// `latest_height` of the client.
+    pub fn skipping_heights(latest_height: Height, skipped: u64) -> Self {
+        Self::new(
+            MockHeader::new(latest_height.add(skipped).increment()).with_current_timestamp(),
+            HeaderFault::SkippedHeights,
+        )
+    }
+
+    /// Returns a header at the given height, with the given timestamp which
+    /// is expected to be ahead of the host's clock.
+    pub fn future_timestamp(height: Height, timestamp: Timestamp) -> Self {
+        Self::new(
+            MockHeader::new(height).with_timestamp(timestamp),
+            HeaderFault::FutureTimestamp,
+        )
+    }
+
+    /// Returns a header at the given height conflicting with the history of
+    /// the counterparty chain.
+    pub fn conflicting(height: Height) -> Self {
+        Self::new(
+            MockHeader::new(height).with_current_timestamp(),
+            HeaderFault::Conflicting,
+        )
+    }
+
+    pub fn height(&self) -> Height {
+        self.header.height()
+    }
+
+    /// Checks the header against the latest height of the client and the
+    /// host timestamp, failing as prescribed by its fault.
+    pub fn verify(
+        &self,
+        latest_height: Height,
+        host_timestamp: Timestamp,
+    ) -> Result<(), ClientError> {
+        match self.fault {
+            HeaderFault::Forged => Err(ClientError::HeaderVerificationFailure {
+                reason: format!("forged header at height {}", self.height()),
+            }),
+            HeaderFault::SkippedHeights if self.height() > latest_height.increment() => {
+                Err(ClientError::HeaderVerificationFailure {
+                    reason: format!(
+                        "header at height {} skips heights past the latest height {latest_height}",
+                        self.height()
+                    ),
+                })
+            }
+            HeaderFault::FutureTimestamp
+                if self.header.timestamp.nanoseconds() > host_timestamp.nanoseconds() =>
+            {
+                Err(ClientError::HeaderVerificationFailure {
+                    reason: format!(
+                        "header timestamp {} is after the host timestamp {host_timestamp}",
+                        self.header.timestamp
+                    ),
+                })
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+impl Display for AdversarialHeader {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), FmtError> {
+        write!(
+            f,
+            "AdversarialHeader {{ header: {}, fault: {:?} }}",
+            self.header, self.fault
+        )
+    }
+}
+
+impl Protobuf<Any> for AdversarialHeader {}
+
+impl TryFrom<Any> for AdversarialHeader {
+    type Error = ClientError;
+
+    fn try_from(raw: Any) -> Result<Self, Self::Error> {
+        let fault = HeaderFault::from_type_url(&raw.type_url).ok_or_else(|| {
+            ClientError::UnknownHeaderType {
+                header_type: raw.type_url.clone(),
+            }
+        })?;
+        let header = Protobuf::<RawMockHeader>::decode_vec(&raw.value).map_err(|e| {
+            ClientError::InvalidRawHeader {
+                reason: e.to_string(),
+            }
+        })?;
+
+        Ok(Self { header, fault })
+    }
+}
+
+impl From<AdversarialHeader> for Any {
+    fn from(header: AdversarialHeader) -> Self {
+        Self {
+            type_url: header.fault.type_url().to_string(),
+            value: Protobuf::<RawMockHeader>::encode_vec(header.header),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case(HeaderFault::Forged)]
+    #[case(HeaderFault::SkippedHeights)]
+    #[case(HeaderFault::FutureTimestamp)]
+    #[case(HeaderFault::Conflicting)]
+    fn encode_any(#[case] fault: HeaderFault) {
+        let header = AdversarialHeader::new(
+            MockHeader::new(Height::new(1, 10).expect("Never fails")),
+            fault,
+        );
+        let any: Any = header.into();
+
+        assert_eq!(any.type_url, fault.type_url());
+        assert_eq!(
+            AdversarialHeader::try_from(any).expect("Never fails"),
+            header
+        );
+    }
+
+    #[test]
+    fn verify_faults() {
+        let latest_height = Height::new(0, 10).expect("Never fails");
+        let host_timestamp = Timestamp::now();
+
+        assert!(AdversarialHeader::forged(latest_height.increment())
+            .verify(latest_height, host_timestamp)
+            .is_err());
+
+        assert!(AdversarialHeader::skipping_heights(latest_height, 0)
+            .verify(latest_height, host_timestamp)
+            .is_ok());
+        assert!(AdversarialHeader::skipping_heights(latest_height, 5)
+            .verify(latest_height, host_timestamp)
+            .is_err());
+
+        let future = (host_timestamp + core::time::Duration::from_secs(60)).expect("Never fails");
+        assert!(
+            AdversarialHeader::future_timestamp(latest_height.increment(), host_timestamp)
+                .verify(latest_height, host_timestamp)
+                .is_ok()
+        );
+        assert!(
+            AdversarialHeader::future_timestamp(latest_height.increment(), future)
+                .verify(latest_height, host_timestamp)
+                .is_err()
+        );
+
+        assert!(AdversarialHeader::conflicting(latest_height.increment())
+            .verify(latest_height, host_timestamp)
+            .is_ok());
+    }
+}
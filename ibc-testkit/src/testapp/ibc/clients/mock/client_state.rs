@@ -14,6 +14,7 @@ use ibc::core::primitives::prelude::*;
 use ibc::core::primitives::Timestamp;
 use ibc::primitives::proto::{Any, Protobuf};
 
+use crate::testapp::ibc::clients::mock::adversary::{AdversarialHeader, HeaderFault};
 use crate::testapp::ibc::clients::mock::client_state::client_type as mock_client_type;
 use crate::testapp::ibc::clients::mock::consensus_state::MockConsensusState;
 use crate::testapp::ibc::clients::mock::header::{MockHeader, MOCK_HEADER_TYPE_URL};
@@ -233,7 +234,7 @@ where
 {
     fn verify_client_message(
         &self,
-        _ctx: &V,
+        ctx: &V,
         _client_id: &ClientId,
         client_message: Any,
     ) -> Result<(), ClientError> {
@@ -244,6 +245,10 @@ where
             MOCK_MISBEHAVIOUR_TYPE_URL => {
                 let _misbehaviour = Misbehaviour::try_from(client_message)?;
             }
+            type_url if HeaderFault::from_type_url(type_url).is_some() => {
+                let header = AdversarialHeader::try_from(client_message)?;
+                header.verify(self.latest_height(), ctx.host_timestamp()?)?;
+            }
             _ => {}
         }
 
@@ -268,12 +273,40 @@ where
 
                 Ok(header_heights_equal && headers_are_in_future)
             }
-            header_type => Err(ClientError::UnknownHeaderType {
-                header_type: header_type.to_owned(),
-            }),
+            type_url => match HeaderFault::from_type_url(type_url) {
+                Some(fault) => Ok(fault == HeaderFault::Conflicting),
+                None => Err(ClientError::UnknownHeaderType {
+                    header_type: type_url.to_owned(),
+                }),
+            },
         }
     }
 
+    fn check_for_duplicate_header(
+        &self,
+        ctx: &V,
+        client_id: &ClientId,
+        header: Any,
+    ) -> Result<bool, ClientError> {
+        let header = match HeaderFault::from_type_url(&header.type_url) {
+            Some(_) => AdversarialHeader::try_from(header)?.header,
+            None => MockHeader::try_from(header)?,
+        };
+        let path_at_header_height = ClientConsensusStatePath::new(
+            client_id.clone(),
+            header.height().revision_number(),
+            header.height().revision_height(),
+        );
+
+        let Ok(existing_consensus_state) = ctx.consensus_state(&path_at_header_height) else {
+            return Ok(false);
+        };
+        let existing_consensus_state =
+            MockConsensusState::try_from(existing_consensus_state).map_err(Into::into)?;
+
+        Ok(existing_consensus_state == MockConsensusState::new(header))
+    }
+
     fn status(&self, ctx: &V, client_id: &ClientId) -> Result<Status, ClientError> {
         if self.is_frozen() {
             return Ok(Status::Frozen);
@@ -351,7 +384,12 @@ where
         client_id: &ClientId,
         header: Any,
     ) -> Result<Vec<Height>, ClientError> {
-        let header = MockHeader::try_from(header)?;
+        // Adversarial headers reaching this point were verified, and update
+        // the client as regular headers do.
+        let header = match HeaderFault::from_type_url(&header.type_url) {
+            Some(_) => AdversarialHeader::try_from(header)?.header,
+            None => MockHeader::try_from(header)?,
+        };
         let header_height = header.height;
 
         let new_client_state = Self::new(header);
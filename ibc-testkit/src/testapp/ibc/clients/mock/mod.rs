@@ -1,4 +1,5 @@
 //! Definitions of ibc mock types used in testing.
+pub mod adversary;
 pub mod client_state;
 pub mod consensus_state;
 pub mod header;
@@ -3,11 +3,12 @@ use core::fmt::Debug;
 use basecoin_store::context::{ProvableStore, Store};
 use basecoin_store::types::Height as StoreHeight;
 use ibc::core::client::context::{
-    ClientExecutionContext, ClientValidationContext, ExtClientValidationContext,
+    ClientExecutionContext, ClientValidationContext, EvidenceAgeLimits, ExtClientValidationContext,
 };
 use ibc::core::client::types::error::ClientError;
 use ibc::core::client::types::Height;
 use ibc::core::handler::types::error::ContextError;
+use ibc::core::host::store::{ClientStore, ClientWriteBatch};
 use ibc::core::host::types::identifiers::{ChannelId, ClientId, PortId};
 use ibc::core::host::types::path::{
     ClientConsensusStatePath, ClientStatePath, ClientUpdateHeightPath, ClientUpdateTimePath, Path,
@@ -46,6 +47,19 @@ where
     }
 }
 
+impl<C> MockClientContext for ClientWriteBatch<C>
+where
+    C: ClientStore,
+{
+    fn host_timestamp(&self) -> Result<Timestamp, ContextError> {
+        ExtClientValidationContext::host_timestamp(self)
+    }
+
+    fn host_height(&self) -> Result<Height, ContextError> {
+        ExtClientValidationContext::host_height(self)
+    }
+}
+
 impl<S> ExtClientValidationContext for MockIbcStore<S>
 where
     S: ProvableStore + Debug,
@@ -150,6 +164,10 @@ where
 
         Ok(consensus_state)
     }
+
+    fn evidence_age_limits(&self) -> Option<EvidenceAgeLimits> {
+        self.evidence_age_limits
+    }
 }
 
 impl<S> ClientValidationContext for MockIbcStore<S>
@@ -5,7 +5,7 @@ use core::time::Duration;
 
 use basecoin_store::context::{ProvableStore, Store};
 use basecoin_store::types::Height as StoreHeight;
-use ibc::core::channel::types::channel::{ChannelEnd, IdentifiedChannelEnd};
+use ibc::core::channel::types::channel::{ChannelEnd, Counterparty, IdentifiedChannelEnd};
 use ibc::core::channel::types::commitment::{AcknowledgementCommitment, PacketCommitment};
 use ibc::core::channel::types::error::{ChannelError, PacketError};
 use ibc::core::channel::types::packet::{PacketState, Receipt};
@@ -18,22 +18,44 @@ use ibc::core::connection::types::error::ConnectionError;
 use ibc::core::connection::types::{ConnectionEnd, IdentifiedConnectionEnd};
 use ibc::core::handler::types::error::ContextError;
 use ibc::core::handler::types::events::IbcEvent;
-use ibc::core::host::types::identifiers::{ClientId, ConnectionId, Sequence};
+use ibc::core::host::types::identifiers::{
+    ClientId, ConnectionId, IdentifierMode, PortId, Sequence,
+};
 use ibc::core::host::types::path::{
     AckPath, ChannelEndPath, ClientConnectionPath, CommitmentPath, ConnectionPath,
     NextChannelSequencePath, NextClientSequencePath, NextConnectionSequencePath, Path, ReceiptPath,
     SeqAckPath, SeqRecvPath, SeqSendPath,
 };
-use ibc::core::host::{ClientStateRef, ConsensusStateRef, ExecutionContext, ValidationContext};
+use ibc::core::host::{
+    AllowedClients, ClientStateRef, ConsensusStateRef, ExecutionContext, HostParams,
+    IdentifierCounter, ValidationContext,
+};
 use ibc::core::primitives::prelude::*;
 use ibc::core::primitives::{Signer, Timestamp};
 use ibc::primitives::ToVec;
 use ibc_proto::ibc::core::commitment::v1::MerkleProof as RawMerkleProof;
 use ibc_query::core::context::{ProvableContext, QueryContext};
 
-use super::types::{MockIbcStore, DEFAULT_BLOCK_TIME_SECS};
+use super::types::MockIbcStore;
 use crate::testapp::ibc::clients::{AnyClientState, AnyConsensusState};
 
+impl<S> HostParams for MockIbcStore<S>
+where
+    S: ProvableStore + Debug,
+{
+    fn max_expected_time_per_block(&self) -> Duration {
+        self.max_expected_time_per_block
+    }
+
+    fn allowed_clients(&self) -> AllowedClients {
+        self.allowed_clients.clone()
+    }
+
+    fn counterparty_identifier_mode(&self) -> IdentifierMode {
+        self.counterparty_identifier_mode
+    }
+}
+
 impl<S> ValidationContext for MockIbcStore<S>
 where
     S: ProvableStore + Debug,
@@ -242,6 +264,17 @@ where
             })?)
     }
 
+    fn packet_acknowledgement_sequences(
+        &self,
+        channel_end_path: &ChannelEndPath,
+    ) -> Result<Vec<Sequence>, ContextError> {
+        Ok(self
+            .packet_acknowledgements(channel_end_path, Vec::new().into_iter())?
+            .into_iter()
+            .map(|ack| ack.seq)
+            .collect())
+    }
+
     /// Returns a counter of the number of channel ids that have been created thus far.
     /// The value of this counter should increase only via the
     /// `ChannelKeeper::increase_channel_counter` method.
@@ -254,9 +287,28 @@ where
             })?)
     }
 
-    /// Returns the maximum expected time per block
-    fn max_expected_time_per_block(&self) -> Duration {
-        Duration::from_secs(DEFAULT_BLOCK_TIME_SECS)
+    fn is_channel_frozen(&self, channel_end_path: &ChannelEndPath) -> Result<bool, ContextError> {
+        Ok(self.frozen_channels.lock().contains(channel_end_path))
+    }
+
+    fn authorize_chan_open_init(
+        &self,
+        signer: &Signer,
+        port_id: &PortId,
+        _connection_hops: &[ConnectionId],
+        counterparty: &Counterparty,
+    ) -> Result<(), ContextError> {
+        match self.channel_openers.lock().get(port_id) {
+            Some(openers) if !openers.contains(signer) => {
+                Err(ChannelError::UnauthorizedChannelOpen {
+                    signer: signer.clone(),
+                    port_id: port_id.clone(),
+                    counterparty_port_id: counterparty.port_id.clone(),
+                }
+                .into())
+            }
+            _ => Ok(()),
+        }
     }
 
     fn validate_message_signer(&self, _signer: &Signer) -> Result<(), ContextError> {
@@ -657,7 +709,10 @@ where
             })?;
 
         self.client_counter
-            .set(NextClientSequencePath, current_sequence + 1)
+            .set(
+                NextClientSequencePath,
+                IdentifierCounter::Client.next(current_sequence)?,
+            )
             .map_err(|e| ClientError::Other {
                 description: format!("client counter update failed: {e:?}"),
             })?;
@@ -709,7 +764,10 @@ where
             })?;
 
         self.conn_counter
-            .set(NextConnectionSequencePath, current_sequence + 1)
+            .set(
+                NextConnectionSequencePath,
+                IdentifierCounter::Connection.next(current_sequence)?,
+            )
             .map_err(|e| ConnectionError::Other {
                 description: format!("connection counter update failed: {e:?}"),
             })?;
@@ -818,7 +876,10 @@ where
             })?;
 
         self.channel_counter
-            .set(NextChannelSequencePath, current_sequence + 1)
+            .set(
+                NextChannelSequencePath,
+                IdentifierCounter::Channel.next(current_sequence)?,
+            )
             .map_err(|e| ChannelError::Other {
                 description: format!("channel counter update failed: {e:?}"),
             })?;
@@ -826,6 +887,20 @@ where
         Ok(())
     }
 
+    fn store_channel_frozen(
+        &mut self,
+        channel_end_path: &ChannelEndPath,
+        frozen: bool,
+    ) -> Result<(), ContextError> {
+        let mut frozen_channels = self.frozen_channels.lock();
+        if frozen {
+            frozen_channels.insert(channel_end_path.clone());
+        } else {
+            frozen_channels.remove(channel_end_path);
+        }
+        Ok(())
+    }
+
     fn emit_ibc_event(&mut self, event: IbcEvent) -> Result<(), ContextError> {
         self.events.lock().push(event);
         Ok(())
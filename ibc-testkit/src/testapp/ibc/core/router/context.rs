@@ -9,7 +9,9 @@ use super::types::MockRouter;
 
 impl Router for MockRouter {
     fn get_route(&self, module_id: &ModuleId) -> Option<&dyn Module> {
-        self.router.get(module_id).map(Arc::as_ref)
+        self.router
+            .get(module_id)
+            .map(|module| module.as_ref() as &dyn Module)
     }
     fn get_route_mut(&mut self, module_id: &ModuleId) -> Option<&mut dyn Module> {
         // NOTE: The following:
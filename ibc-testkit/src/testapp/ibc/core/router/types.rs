@@ -8,9 +8,11 @@ use ibc::core::router::types::module::ModuleId;
 
 use crate::testapp::ibc::applications::transfer::types::DummyTransferModule;
 
+/// A router holding `Send + Sync` modules, so that it can be shared by
+/// threads validating messages concurrently.
 #[derive(Debug, Default)]
 pub struct MockRouter {
-    pub router: BTreeMap<ModuleId, Arc<dyn Module>>,
+    pub router: BTreeMap<ModuleId, Arc<dyn Module + Send + Sync>>,
 
     /// Maps ports to the the module that owns it
     pub port_to_module: BTreeMap<PortId, ModuleId>,
@@ -36,7 +38,7 @@ impl MockRouter {
     pub fn add_route(
         &mut self,
         module_id: ModuleId,
-        module: impl Module + 'static,
+        module: impl Module + Send + Sync + 'static,
     ) -> Result<(), String> {
         match self.router.insert(module_id, Arc::new(module)) {
             None => Ok(()),
@@ -1,7 +1,9 @@
 //! Implementation of a global context mock. Used in testing handlers of all IBC modules.
 
+use alloc::collections::BTreeSet;
 use alloc::sync::Arc;
 use core::fmt::Debug;
+use core::time::Duration;
 
 use basecoin_store::context::{ProvableStore, Store};
 use basecoin_store::impls::SharedStore;
@@ -9,18 +11,20 @@ use basecoin_store::types::{BinStore, JsonStore, ProtobufStore, TypedSet, TypedS
 use ibc::core::channel::types::channel::ChannelEnd;
 use ibc::core::channel::types::commitment::{AcknowledgementCommitment, PacketCommitment};
 use ibc::core::client::context::client_state::ClientStateValidation;
+use ibc::core::client::context::EvidenceAgeLimits;
 use ibc::core::client::types::Height;
 use ibc::core::connection::types::ConnectionEnd;
 use ibc::core::handler::types::events::IbcEvent;
-use ibc::core::host::types::identifiers::{ConnectionId, Sequence};
+use ibc::core::host::types::identifiers::{ConnectionId, IdentifierMode, PortId, Sequence};
 use ibc::core::host::types::path::{
     AckPath, ChannelEndPath, ClientConnectionPath, ClientConsensusStatePath, ClientStatePath,
     ClientUpdateHeightPath, ClientUpdateTimePath, CommitmentPath, ConnectionPath,
     NextChannelSequencePath, NextClientSequencePath, NextConnectionSequencePath, ReceiptPath,
     SeqAckPath, SeqRecvPath, SeqSendPath,
 };
+use ibc::core::host::AllowedClients;
 use ibc::core::primitives::prelude::*;
-use ibc::core::primitives::Timestamp;
+use ibc::core::primitives::{Signer, Timestamp};
 use ibc_proto::google::protobuf::Any;
 use ibc_proto::ibc::core::channel::v1::Channel as RawChannelEnd;
 use ibc_proto::ibc::core::client::v1::Height as RawHeight;
@@ -93,6 +97,19 @@ where
     pub events: Arc<Mutex<Vec<IbcEvent>>>,
     /// message logs
     pub logs: Arc<Mutex<Vec<String>>>,
+    /// The channel ends frozen by the host
+    pub frozen_channels: Arc<Mutex<BTreeSet<ChannelEndPath>>>,
+    /// The signers allowed to open channels on each port, any signer being
+    /// allowed to open channels on the ports not listed
+    pub channel_openers: Arc<Mutex<BTreeMap<PortId, Vec<Signer>>>>,
+    /// The host parameter used to derive the connection block delay
+    pub max_expected_time_per_block: Duration,
+    /// The host parameter restricting the client types that may be created
+    pub allowed_clients: AllowedClients,
+    /// The host parameter setting the strictness of counterparty identifiers
+    pub counterparty_identifier_mode: IdentifierMode,
+    /// The limits past which misbehaviour evidence is expired, if set
+    pub evidence_age_limits: Option<EvidenceAgeLimits>,
 }
 
 impl<S> MockIbcStore<S>
@@ -140,6 +157,12 @@ where
             packet_ack_store: TypedStore::new(shared_store.clone()),
             events: Arc::new(Mutex::new(Vec::new())),
             logs: Arc::new(Mutex::new(Vec::new())),
+            frozen_channels: Arc::new(Mutex::new(BTreeSet::new())),
+            channel_openers: Arc::new(Mutex::new(BTreeMap::new())),
+            max_expected_time_per_block: Duration::from_secs(DEFAULT_BLOCK_TIME_SECS),
+            allowed_clients: AllowedClients::All,
+            counterparty_identifier_mode: IdentifierMode::Exact,
+            evidence_age_limits: None,
             store: shared_store,
         }
     }
@@ -205,10 +228,10 @@ mod tests {
     use ibc::core::channel::types::error::{ChannelError, PacketError};
     use ibc::core::channel::types::packet::Packet;
     use ibc::core::channel::types::Version;
-    use ibc::core::host::types::identifiers::{ChannelId, PortId};
-    use ibc::core::primitives::Signer;
-    use ibc::core::router::module::Module;
+    use ibc::core::host::types::identifiers::ChannelId;
+    use ibc::core::router::module::{Module, OnRecvPacketResult};
     use ibc::core::router::router::Router;
+    use ibc::core::router::types::error::AppError;
     use ibc::core::router::types::module::{ModuleExtras, ModuleId};
 
     use super::*;
@@ -276,13 +299,13 @@ mod tests {
                 &mut self,
                 _packet: &Packet,
                 _relayer: &Signer,
-            ) -> (ModuleExtras, Acknowledgement) {
+            ) -> Result<OnRecvPacketResult, AppError> {
                 self.counter += 1;
 
-                (
+                Ok(OnRecvPacketResult::sync(
                     ModuleExtras::empty(),
                     Acknowledgement::try_from(vec![1u8]).expect("Never fails"),
-                )
+                ))
             }
 
             fn on_timeout_packet_validate(
@@ -376,11 +399,11 @@ mod tests {
                 &mut self,
                 _packet: &Packet,
                 _relayer: &Signer,
-            ) -> (ModuleExtras, Acknowledgement) {
-                (
+            ) -> Result<OnRecvPacketResult, AppError> {
+                Ok(OnRecvPacketResult::sync(
                     ModuleExtras::empty(),
                     Acknowledgement::try_from(vec![1u8]).expect("Never fails"),
-                )
+                ))
             }
 
             fn on_timeout_packet_validate(
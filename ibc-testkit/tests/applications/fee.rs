@@ -0,0 +1,305 @@
+use std::collections::BTreeMap;
+
+use ibc::apps::fee::bank::{escrow_packet_fee, pay_out_fees};
+use ibc::apps::fee::context::{FeeExecutionContext, FeeValidationContext};
+use ibc::apps::fee::handler::{
+    add_packet_fee_execute, distribute_packet_fees_on_ack_execute,
+    distribute_packet_fees_on_timeout_execute, forward_relayer,
+    register_counterparty_payee_execute, take_packet_fees_execute,
+};
+use ibc::apps::fee::types::error::FeeError;
+use ibc::apps::fee::types::events::DistributeFeeEvent;
+use ibc::apps::fee::types::{
+    Fee, FeePayout, IdentifiedPacketFees, PacketFee, PacketFees, PacketRelayers,
+};
+use ibc::apps::transfer::types::{PrefixedCoin, PrefixedDenom};
+use ibc::core::channel::types::packet::PacketId;
+use ibc::core::host::types::identifiers::{ChannelId, PortId, Sequence};
+use ibc::core::primitives::Signer;
+use ibc::core::router::bank::BankContext;
+use ibc::core::router::types::event::ModuleEvent;
+use ibc_testkit::testapp::ibc::applications::bank::MockBank;
+use rstest::*;
+
+/// A fee store backed by a map ordered by packet identifier, hence by
+/// sequence within a channel.
+#[derive(Default)]
+struct MockFeeStore {
+    packet_fees: BTreeMap<PacketId, PacketFees>,
+    counterparty_payees: BTreeMap<(ChannelId, Signer), Signer>,
+}
+
+impl FeeValidationContext for MockFeeStore {
+    fn packet_fees(&self, packet_id: &PacketId) -> Result<Option<PacketFees>, FeeError> {
+        Ok(self.packet_fees.get(packet_id).cloned())
+    }
+
+    fn incentivized_packets_for_channel(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<Vec<IdentifiedPacketFees>, FeeError> {
+        Ok(self
+            .packet_fees
+            .iter()
+            .filter(|(packet_id, _)| {
+                &packet_id.port_id == port_id && &packet_id.channel_id == channel_id
+            })
+            .map(|(packet_id, packet_fees)| {
+                IdentifiedPacketFees::new(packet_id.clone(), packet_fees.clone())
+            })
+            .collect())
+    }
+
+    fn counterparty_payee(
+        &self,
+        channel_id: &ChannelId,
+        relayer: &Signer,
+    ) -> Result<Option<Signer>, FeeError> {
+        Ok(self
+            .counterparty_payees
+            .get(&(channel_id.clone(), relayer.clone()))
+            .cloned())
+    }
+}
+
+impl FeeExecutionContext for MockFeeStore {
+    fn store_packet_fees(
+        &mut self,
+        packet_id: PacketId,
+        packet_fees: PacketFees,
+    ) -> Result<(), FeeError> {
+        self.packet_fees.insert(packet_id, packet_fees);
+        Ok(())
+    }
+
+    fn delete_packet_fees(&mut self, packet_id: &PacketId) -> Result<(), FeeError> {
+        self.packet_fees.remove(packet_id);
+        Ok(())
+    }
+
+    fn store_counterparty_payee(
+        &mut self,
+        channel_id: ChannelId,
+        relayer: Signer,
+        counterparty_payee: Signer,
+    ) -> Result<(), FeeError> {
+        self.counterparty_payees
+            .insert((channel_id, relayer), counterparty_payee);
+        Ok(())
+    }
+}
+
+fn packet_id(channel_id: u64, sequence: u64) -> PacketId {
+    PacketId::new(
+        PortId::transfer(),
+        ChannelId::new(channel_id),
+        Sequence::from(sequence),
+    )
+}
+
+fn packet_fee(amount: u64) -> PacketFee {
+    let coin: PrefixedCoin = format!("{amount}stake").parse().expect("valid coin");
+
+    PacketFee::new(
+        Fee {
+            recv_fee: vec![coin.clone()],
+            ack_fee: vec![coin.clone()],
+            timeout_fee: vec![coin],
+        },
+        Signer::from("cosmos1refund".to_string()),
+    )
+}
+
+#[fixture]
+fn store() -> MockFeeStore {
+    let mut store = MockFeeStore::default();
+
+    // Incentivizes the packets out of order, and one of them twice.
+    for (channel_id, sequence, amount) in [(0, 2, 20), (1, 1, 30), (0, 1, 10), (0, 2, 5)] {
+        add_packet_fee_execute(
+            &mut store,
+            packet_id(channel_id, sequence),
+            packet_fee(amount),
+        )
+        .expect("storing never fails");
+    }
+
+    store
+}
+
+#[rstest]
+fn test_add_packet_fee(store: MockFeeStore) {
+    assert_eq!(
+        store.packet_fees(&packet_id(0, 2)).expect("no error"),
+        Some(PacketFees::new(vec![packet_fee(20), packet_fee(5)]))
+    );
+    assert_eq!(store.packet_fees(&packet_id(0, 3)).expect("no error"), None);
+}
+
+#[rstest]
+fn test_incentivized_packets_for_channel(store: MockFeeStore) {
+    let packets = store
+        .incentivized_packets_for_channel(&PortId::transfer(), &ChannelId::new(0))
+        .expect("no error");
+
+    assert_eq!(
+        packets,
+        vec![
+            IdentifiedPacketFees::new(packet_id(0, 1), PacketFees::new(vec![packet_fee(10)])),
+            IdentifiedPacketFees::new(
+                packet_id(0, 2),
+                PacketFees::new(vec![packet_fee(20), packet_fee(5)])
+            ),
+        ]
+    );
+}
+
+#[rstest]
+fn test_take_packet_fees(mut store: MockFeeStore) {
+    assert_eq!(
+        take_packet_fees_execute(&mut store, &packet_id(1, 1)).expect("incentivized packet"),
+        PacketFees::new(vec![packet_fee(30)])
+    );
+    assert_eq!(store.packet_fees(&packet_id(1, 1)).expect("no error"), None);
+
+    // The fees of a packet can only be claimed once.
+    assert!(matches!(
+        take_packet_fees_execute(&mut store, &packet_id(1, 1)),
+        Err(FeeError::PacketFeesNotFound { .. })
+    ));
+}
+
+#[rstest]
+fn test_distribute_packet_fees_on_ack(mut store: MockFeeStore) {
+    let coins = |amount: u64| vec![format!("{amount}stake").parse().expect("valid coin")];
+    let signer = |address: &str| Signer::from(address.to_string());
+
+    let relayers = PacketRelayers::new(Some(signer("cosmos1forward")), signer("cosmos1reverse"));
+    assert_eq!(
+        distribute_packet_fees_on_ack_execute(&mut store, &packet_id(0, 2), &relayers)
+            .expect("incentivized packet"),
+        vec![
+            FeePayout::new(signer("cosmos1forward"), coins(20)),
+            FeePayout::new(signer("cosmos1reverse"), coins(20)),
+            FeePayout::new(signer("cosmos1refund"), coins(20)),
+            FeePayout::new(signer("cosmos1forward"), coins(5)),
+            FeePayout::new(signer("cosmos1reverse"), coins(5)),
+            FeePayout::new(signer("cosmos1refund"), coins(5)),
+        ]
+    );
+    assert_eq!(store.packet_fees(&packet_id(0, 2)).expect("no error"), None);
+
+    // The receive fees are refunded when the forward relayer is unknown.
+    let relayers = PacketRelayers::new(None, signer("cosmos1reverse"));
+    assert_eq!(
+        distribute_packet_fees_on_ack_execute(&mut store, &packet_id(0, 1), &relayers)
+            .expect("incentivized packet"),
+        vec![
+            FeePayout::new(signer("cosmos1refund"), coins(10)),
+            FeePayout::new(signer("cosmos1reverse"), coins(10)),
+            FeePayout::new(signer("cosmos1refund"), coins(10)),
+        ]
+    );
+}
+
+#[rstest]
+fn test_distribute_packet_fees_on_timeout(mut store: MockFeeStore) {
+    let coins = |amounts: &[u64]| -> Vec<PrefixedCoin> {
+        amounts
+            .iter()
+            .map(|amount| format!("{amount}stake").parse().expect("valid coin"))
+            .collect()
+    };
+    let signer = |address: &str| Signer::from(address.to_string());
+
+    // The receive and acknowledgement fees are refunded, since the packet
+    // was never received.
+    assert_eq!(
+        distribute_packet_fees_on_timeout_execute(
+            &mut store,
+            &packet_id(0, 2),
+            &signer("cosmos1timeout")
+        )
+        .expect("incentivized packet"),
+        vec![
+            FeePayout::new(signer("cosmos1timeout"), coins(&[20])),
+            FeePayout::new(signer("cosmos1refund"), coins(&[20, 20])),
+            FeePayout::new(signer("cosmos1timeout"), coins(&[5])),
+            FeePayout::new(signer("cosmos1refund"), coins(&[5, 5])),
+        ]
+    );
+    assert_eq!(store.packet_fees(&packet_id(0, 2)).expect("no error"), None);
+}
+
+#[rstest]
+fn test_register_counterparty_payee(mut store: MockFeeStore) {
+    let signer = |address: &str| Signer::from(address.to_string());
+    let relayer = signer("cosmos1relayer");
+
+    assert_eq!(
+        forward_relayer(&store, &ChannelId::new(0), &relayer).expect("no error"),
+        None
+    );
+
+    register_counterparty_payee_execute(
+        &mut store,
+        ChannelId::new(0),
+        relayer.clone(),
+        signer("osmo1payee"),
+    )
+    .expect("valid payee");
+
+    assert_eq!(
+        forward_relayer(&store, &ChannelId::new(0), &relayer).expect("no error"),
+        Some(signer("osmo1payee"))
+    );
+    // The counterparty payee is registered for the given channel only.
+    assert_eq!(
+        forward_relayer(&store, &ChannelId::new(1), &relayer).expect("no error"),
+        None
+    );
+
+    assert!(matches!(
+        register_counterparty_payee_execute(&mut store, ChannelId::new(0), relayer, signer("")),
+        Err(FeeError::EmptyCounterpartyPayee)
+    ));
+}
+
+#[rstest]
+fn test_escrow_and_pay_out_fees(mut store: MockFeeStore) {
+    let mut bank = MockBank::default();
+    let signer = |address: &str| Signer::from(address.to_string());
+    let stake: PrefixedDenom = "stake".parse().expect("valid denom");
+    let fee_account = signer("feeibc");
+
+    let payer = signer("cosmos1payer");
+    bank.mint_to(&payer, &"100stake".parse().expect("valid coin"))
+        .expect("no error");
+
+    // Each of the receive, acknowledgement and timeout fees is escrowed.
+    let fee = packet_fee(10);
+    escrow_packet_fee(&mut bank, &payer, &fee).expect("no error");
+    add_packet_fee_execute(&mut store, packet_id(2, 1), fee).expect("no error");
+    assert_eq!(bank.balance(&payer, &stake), 70u64.into());
+    assert_eq!(bank.balance(&fee_account, &stake), 30u64.into());
+
+    let relayers = PacketRelayers::new(Some(signer("cosmos1forward")), signer("cosmos1reverse"));
+    let payouts = distribute_packet_fees_on_ack_execute(&mut store, &packet_id(2, 1), &relayers)
+        .expect("incentivized packet");
+    let events = pay_out_fees(&mut bank, &payouts).expect("no error");
+
+    // A `distribute_fee` event is emitted for each payout.
+    assert_eq!(
+        events,
+        payouts
+            .into_iter()
+            .map(|payout| ModuleEvent::from(DistributeFeeEvent::from(payout)))
+            .collect::<Vec<_>>()
+    );
+    assert!(events.iter().all(|event| event.kind == "distribute_fee"));
+    assert_eq!(bank.balance(&fee_account, &stake), 0u64.into());
+    for recipient in ["cosmos1forward", "cosmos1reverse", "cosmos1refund"] {
+        assert_eq!(bank.balance(&signer(recipient), &stake), 10u64.into());
+    }
+}
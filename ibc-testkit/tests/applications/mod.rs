@@ -1,3 +1,4 @@
+pub mod fee;
 #[cfg(feature = "serde")]
 pub mod nft_transfer;
 #[cfg(feature = "serde")]
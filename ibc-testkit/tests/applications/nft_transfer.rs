@@ -1,13 +1,45 @@
+use ibc::apps::nft_transfer::bank::{escrow_nft, unescrow_nft, NftAsset};
+use ibc::apps::nft_transfer::context::{
+    NftTransferExecutionContext, NftTransferPolicy, NftTransferValidationContext,
+    NonTransferablePolicy, TokenPagination,
+};
+use ibc::apps::nft_transfer::handler::{
+    process_recv_packet_execute, resolve_class_id, send_nft_transfer,
+};
 use ibc::apps::nft_transfer::module::{
     on_chan_open_init_execute, on_chan_open_init_validate, on_chan_open_try_execute,
     on_chan_open_try_validate,
 };
-use ibc::apps::nft_transfer::types::VERSION;
-use ibc::core::channel::types::channel::{Counterparty, Order};
+use ibc::apps::nft_transfer::types::error::NftTransferError;
+use ibc::apps::nft_transfer::types::msgs::transfer::MsgTransfer;
+use ibc::apps::nft_transfer::types::packet::PacketData;
+use ibc::apps::nft_transfer::types::{
+    ClassData, ClassUri, Memo, PrefixedClassId, TokenData, TokenId, TokenUri, VERSION,
+};
+use ibc::core::channel::types::channel::{ChannelEnd, Counterparty, Order, State};
+use ibc::core::channel::types::packet::Packet;
+use ibc::core::channel::types::timeout::TimeoutHeight;
 use ibc::core::channel::types::Version;
-use ibc::core::host::types::identifiers::{ChannelId, ConnectionId, PortId};
+use ibc::core::client::types::Height;
+use ibc::core::commitment_types::commitment::CommitmentPrefix;
+use ibc::core::connection::types::version::Version as ConnectionVersion;
+use ibc::core::connection::types::{
+    ConnectionEnd, Counterparty as ConnectionCounterparty, State as ConnectionState,
+};
+use ibc::core::handler::types::events::IbcEvent;
+use ibc::core::host::types::identifiers::{ChannelId, ClientId, ConnectionId, PortId};
 use ibc::core::primitives::prelude::*;
-use ibc_testkit::testapp::ibc::applications::nft_transfer::types::DummyNftTransferModule;
+use ibc::core::primitives::{Signer, Timestamp, ZERO_DURATION};
+use ibc::core::router::bank::BankContext;
+use ibc::core::router::types::error::BankError;
+use ibc_testkit::context::MockContext;
+use ibc_testkit::hosts::MockHost;
+use ibc_testkit::testapp::ibc::applications::bank::MockBank;
+use ibc_testkit::testapp::ibc::applications::nft_transfer::types::{
+    DummyNft, DummyNftClass, DummyNftTransferModule,
+};
+use ibc_testkit::testapp::ibc::core::types::LightClientState;
+use rstest::rstest;
 
 fn get_defaults() -> (
     DummyNftTransferModule,
@@ -133,3 +165,664 @@ fn test_on_chan_open_try_counterparty_incorrect_version() {
 
     assert!(res.is_err());
 }
+
+/// An NFT transfer module recording the owners of its NFTs, the NFTs it
+/// escrows and burns, and the class traces it stores.
+#[derive(Default)]
+struct RecordingNftTransferModule {
+    owners: Vec<(PrefixedClassId, TokenId, Signer)>,
+    escrowed: Vec<(PrefixedClassId, TokenId)>,
+    burned: Vec<(PrefixedClassId, TokenId)>,
+    class_traces: Vec<(String, PrefixedClassId)>,
+    /// The data of its classes, instead of the one of [`DummyNftClass`].
+    class_data: Option<ClassData>,
+    transfer_policy: Option<NonTransferablePolicy>,
+}
+
+impl RecordingNftTransferModule {
+    /// The account holding the escrowed NFTs.
+    fn escrow_account() -> Signer {
+        "escrow".to_string().into()
+    }
+
+    fn with_nfts(class_id: &PrefixedClassId, token_ids: &[&str], owner: &str) -> Self {
+        Self {
+            owners: token_ids
+                .iter()
+                .map(|token_id| {
+                    (
+                        class_id.clone(),
+                        token_id.parse().unwrap(),
+                        owner.to_string().into(),
+                    )
+                })
+                .collect(),
+            ..Default::default()
+        }
+    }
+}
+
+impl NftTransferValidationContext for RecordingNftTransferModule {
+    type AccountId = Signer;
+    type Nft = DummyNft;
+    type NftClass = DummyNftClass;
+
+    fn get_port(&self) -> Result<PortId, NftTransferError> {
+        Ok(PortId::transfer())
+    }
+
+    fn can_send_nft(&self) -> Result<(), NftTransferError> {
+        Ok(())
+    }
+
+    fn can_receive_nft(&self) -> Result<(), NftTransferError> {
+        Ok(())
+    }
+
+    fn create_or_update_class_validate(
+        &self,
+        _class_id: &PrefixedClassId,
+        _class_uri: Option<&ClassUri>,
+        _class_data: Option<&ClassData>,
+    ) -> Result<(), NftTransferError> {
+        Ok(())
+    }
+
+    fn escrow_nft_validate(
+        &self,
+        _from_account: &Signer,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+        _class_id: &PrefixedClassId,
+        _token_id: &TokenId,
+        _memo: &Memo,
+    ) -> Result<(), NftTransferError> {
+        Ok(())
+    }
+
+    fn unescrow_nft_validate(
+        &self,
+        _to_account: &Signer,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+        _class_id: &PrefixedClassId,
+        _token_id: &TokenId,
+    ) -> Result<(), NftTransferError> {
+        Ok(())
+    }
+
+    fn mint_nft_validate(
+        &self,
+        _account: &Signer,
+        _class_id: &PrefixedClassId,
+        _token_id: &TokenId,
+        _token_uri: Option<&TokenUri>,
+        _token_data: Option<&TokenData>,
+    ) -> Result<(), NftTransferError> {
+        Ok(())
+    }
+
+    fn burn_nft_validate(
+        &self,
+        _account: &Signer,
+        _class_id: &PrefixedClassId,
+        _token_id: &TokenId,
+        _memo: &Memo,
+    ) -> Result<(), NftTransferError> {
+        Ok(())
+    }
+
+    fn class_hash_string(&self, class_id: &PrefixedClassId) -> Option<String> {
+        Some(class_id.to_string().replace('/', "_").to_uppercase())
+    }
+
+    fn get_class_trace(&self, hash: &str) -> Result<Option<PrefixedClassId>, NftTransferError> {
+        Ok(self
+            .class_traces
+            .iter()
+            .find(|(stored_hash, _)| stored_hash == hash)
+            .map(|(_, class_id)| class_id.clone()))
+    }
+
+    fn get_nft(
+        &self,
+        _class_id: &PrefixedClassId,
+        _token_id: &TokenId,
+    ) -> Result<DummyNft, NftTransferError> {
+        Ok(DummyNft::default())
+    }
+
+    fn get_nft_class(
+        &self,
+        _class_id: &PrefixedClassId,
+    ) -> Result<DummyNftClass, NftTransferError> {
+        let mut nft_class = DummyNftClass::default();
+        if self.class_data.is_some() {
+            nft_class.class_data = self.class_data.clone();
+        }
+        Ok(nft_class)
+    }
+
+    fn owner_of(
+        &self,
+        class_id: &PrefixedClassId,
+        token_id: &TokenId,
+    ) -> Result<Signer, NftTransferError> {
+        self.owners
+            .iter()
+            .find(|(owned_class_id, owned_token_id, _)| {
+                owned_class_id == class_id && owned_token_id == token_id
+            })
+            .map(|(_, _, owner)| owner.clone())
+            .ok_or(NftTransferError::NftNotFound)
+    }
+
+    fn tokens_of_class(
+        &self,
+        class_id: &PrefixedClassId,
+        pagination: &TokenPagination,
+    ) -> Result<Vec<TokenId>, NftTransferError> {
+        let mut token_ids: Vec<_> = self
+            .owners
+            .iter()
+            .filter(|(owned_class_id, token_id, _)| {
+                owned_class_id == class_id
+                    && pagination
+                        .start_after
+                        .as_ref()
+                        .map_or(true, |start_after| token_id > start_after)
+            })
+            .map(|(_, token_id, _)| token_id.clone())
+            .collect();
+        token_ids.sort();
+        if let Some(limit) = pagination.limit {
+            token_ids.truncate(usize::try_from(limit).unwrap_or(usize::MAX));
+        }
+        Ok(token_ids)
+    }
+
+    fn transfer_policy(&self) -> Option<&dyn NftTransferPolicy> {
+        self.transfer_policy
+            .as_ref()
+            .map(|policy| policy as &dyn NftTransferPolicy)
+    }
+}
+
+impl NftTransferExecutionContext for RecordingNftTransferModule {
+    fn create_or_update_class_execute(
+        &self,
+        _class_id: &PrefixedClassId,
+        _class_uri: Option<&ClassUri>,
+        _class_data: Option<&ClassData>,
+    ) -> Result<(), NftTransferError> {
+        Ok(())
+    }
+
+    fn escrow_nft_execute(
+        &mut self,
+        _from_account: &Signer,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+        class_id: &PrefixedClassId,
+        token_id: &TokenId,
+        _memo: &Memo,
+    ) -> Result<(), NftTransferError> {
+        for (owned_class_id, owned_token_id, owner) in &mut self.owners {
+            if owned_class_id == class_id && owned_token_id == token_id {
+                *owner = Self::escrow_account();
+            }
+        }
+        self.escrowed.push((class_id.clone(), token_id.clone()));
+        Ok(())
+    }
+
+    fn unescrow_nft_execute(
+        &mut self,
+        _to_account: &Signer,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+        _class_id: &PrefixedClassId,
+        _token_id: &TokenId,
+    ) -> Result<(), NftTransferError> {
+        Ok(())
+    }
+
+    fn store_class_trace_execute(
+        &mut self,
+        class_id: &PrefixedClassId,
+        hash: &str,
+    ) -> Result<(), NftTransferError> {
+        self.class_traces.push((hash.to_string(), class_id.clone()));
+        Ok(())
+    }
+
+    fn mint_nft_execute(
+        &mut self,
+        _account: &Signer,
+        _class_id: &PrefixedClassId,
+        _token_id: &TokenId,
+        _token_uri: Option<&TokenUri>,
+        _token_data: Option<&TokenData>,
+    ) -> Result<(), NftTransferError> {
+        Ok(())
+    }
+
+    fn burn_nft_execute(
+        &mut self,
+        _account: &Signer,
+        class_id: &PrefixedClassId,
+        token_id: &TokenId,
+        _memo: &Memo,
+    ) -> Result<(), NftTransferError> {
+        self.owners.retain(|(owned_class_id, owned_token_id, _)| {
+            owned_class_id != class_id || owned_token_id != token_id
+        });
+        self.burned.push((class_id.clone(), token_id.clone()));
+        Ok(())
+    }
+}
+
+/// Returns a context with an open channel `channel-0` on the transfer port,
+/// on which NFTs can be sent.
+fn send_nft_transfer_context() -> MockContext {
+    let client_id = ClientId::new("07-tendermint", 0).expect("no error");
+
+    let conn_end_on_a = ConnectionEnd::new(
+        ConnectionState::Open,
+        client_id.clone(),
+        ConnectionCounterparty::new(
+            client_id.clone(),
+            Some(ConnectionId::zero()),
+            CommitmentPrefix::try_from(vec![0]).expect("no error"),
+        ),
+        ConnectionVersion::compatibles(),
+        ZERO_DURATION,
+    )
+    .unwrap();
+
+    let chan_end_on_a = ChannelEnd::new(
+        State::Open,
+        Order::Unordered,
+        Counterparty::new(PortId::transfer(), Some(ChannelId::new(1))),
+        vec![ConnectionId::zero()],
+        Version::new(VERSION.to_string()),
+    )
+    .unwrap();
+
+    MockContext::default()
+        .with_light_client(
+            &client_id,
+            LightClientState::<MockHost>::with_latest_height(Height::new(0, 5).unwrap()),
+        )
+        .with_connection(ConnectionId::zero(), conn_end_on_a)
+        .with_channel(PortId::transfer(), ChannelId::zero(), chan_end_on_a)
+        .with_send_sequence(PortId::transfer(), ChannelId::zero(), 1.into())
+}
+
+/// NFTs of classes originating from the host are escrowed, while vouchers of
+/// classes received over the channel are burned.
+#[rstest]
+#[case::escrow("class_0", true)]
+#[case::burn("transfer/channel-0/class_0", false)]
+fn test_send_nft_transfer(#[case] class_id: &str, #[case] escrowed: bool) {
+    let mut ctx = send_nft_transfer_context();
+
+    let class_id: PrefixedClassId = class_id.parse().unwrap();
+    let mut module =
+        RecordingNftTransferModule::with_nfts(&class_id, &["token_0", "token_1"], "sender");
+    let token_ids = vec!["token_0".to_string(), "token_1".to_string()];
+    let packet_data = PacketData::new(
+        class_id.clone(),
+        None,
+        None,
+        token_ids.clone().try_into().unwrap(),
+        vec![],
+        vec![],
+        "sender".to_string().into(),
+        "receiver".to_string().into(),
+        "".into(),
+    )
+    .unwrap();
+    let msg = MsgTransfer {
+        port_id_on_a: PortId::transfer(),
+        chan_id_on_a: ChannelId::zero(),
+        packet_data,
+        timeout_height_on_b: Height::new(0, 10).unwrap().into(),
+        timeout_timestamp_on_b: Timestamp::none(),
+    };
+
+    send_nft_transfer(&mut ctx.ibc_store, &mut module, msg).unwrap();
+
+    let transferred: Vec<_> = token_ids
+        .iter()
+        .map(|token_id| (class_id.clone(), token_id.parse().unwrap()))
+        .collect();
+    let pagination = TokenPagination::default();
+    if escrowed {
+        assert_eq!(module.escrowed, transferred);
+        assert!(module.burned.is_empty());
+        for (class_id, token_id) in &transferred {
+            assert_eq!(
+                module.owner_of(class_id, token_id).unwrap(),
+                RecordingNftTransferModule::escrow_account()
+            );
+        }
+        assert_eq!(
+            module
+                .tokens_of_class(&class_id, &pagination)
+                .unwrap()
+                .len(),
+            2
+        );
+    } else {
+        assert_eq!(module.burned, transferred);
+        assert!(module.escrowed.is_empty());
+        assert!(module
+            .tokens_of_class(&class_id, &pagination)
+            .unwrap()
+            .is_empty());
+    }
+
+    // The packet is sent to the counterparty channel, carrying the metadata
+    // of the class and of the tokens held by the host.
+    let Some(IbcEvent::SendPacket(send_packet_event)) = ctx
+        .get_events()
+        .into_iter()
+        .find(|event| matches!(event, IbcEvent::SendPacket(_)))
+    else {
+        panic!("no send packet event")
+    };
+    assert_eq!(send_packet_event.chan_id_on_b(), &ChannelId::new(1));
+    assert_eq!(u64::from(*send_packet_event.seq_on_a()), 1);
+
+    let sent_data: PacketData = serde_json::from_slice(send_packet_event.packet_data()).unwrap();
+    let nft = DummyNft::default();
+    let nft_class = DummyNftClass::default();
+    assert_eq!(sent_data.class_id, class_id);
+    assert_eq!(sent_data.class_uri, nft_class.class_uri);
+    assert_eq!(sent_data.class_data, nft_class.class_data);
+    assert_eq!(
+        sent_data.token_uris,
+        Some(vec![nft.token_uri.clone().unwrap(); 2])
+    );
+    assert_eq!(
+        sent_data.token_data,
+        Some(vec![nft.token_data.clone().unwrap(); 2])
+    );
+}
+
+/// NFTs can only be sent by their owner.
+#[test]
+fn test_send_nft_transfer_invalid_owner() {
+    let mut ctx = send_nft_transfer_context();
+
+    let class_id: PrefixedClassId = "class_0".parse().unwrap();
+    let mut module = RecordingNftTransferModule::with_nfts(&class_id, &["token_0"], "owner");
+
+    let packet_data = PacketData::new(
+        class_id.clone(),
+        None,
+        None,
+        vec!["token_0".to_string()].try_into().unwrap(),
+        vec![],
+        vec![],
+        "sender".to_string().into(),
+        "receiver".to_string().into(),
+        "".into(),
+    )
+    .unwrap();
+    let msg = MsgTransfer {
+        port_id_on_a: PortId::transfer(),
+        chan_id_on_a: ChannelId::zero(),
+        packet_data,
+        timeout_height_on_b: Height::new(0, 10).unwrap().into(),
+        timeout_timestamp_on_b: Timestamp::none(),
+    };
+
+    let res = send_nft_transfer(&mut ctx.ibc_store, &mut module, msg);
+
+    assert!(
+        matches!(res, Err(NftTransferError::InvalidOwner { ref sender }) if sender == "sender"),
+        "{res:?}"
+    );
+    assert!(module.escrowed.is_empty());
+}
+
+/// NFTs of classes flagged as soulbound cannot be sent, while the other ones
+/// are accepted by the same policy.
+#[rstest]
+#[case::soulbound(r#"{"soulbound":{"value":"true"}}"#, false)]
+#[case::transferable(r#"{"soulbound":{"value":"false"}}"#, true)]
+#[case::not_ics721(r#"{"soulbound":true}"#, true)]
+fn test_send_nft_transfer_policy(#[case] class_data: &str, #[case] transferable: bool) {
+    let mut ctx = send_nft_transfer_context();
+
+    let class_id: PrefixedClassId = "class_0".parse().unwrap();
+    let mut module = RecordingNftTransferModule {
+        class_data: Some(class_data.parse().unwrap()),
+        transfer_policy: Some(NonTransferablePolicy::new("soulbound")),
+        ..RecordingNftTransferModule::with_nfts(&class_id, &["token_0"], "sender")
+    };
+
+    let packet_data = PacketData::new(
+        class_id.clone(),
+        None,
+        None,
+        vec!["token_0".to_string()].try_into().unwrap(),
+        vec![],
+        vec![],
+        "sender".to_string().into(),
+        "receiver".to_string().into(),
+        "".into(),
+    )
+    .unwrap();
+    let msg = MsgTransfer {
+        port_id_on_a: PortId::transfer(),
+        chan_id_on_a: ChannelId::zero(),
+        packet_data,
+        timeout_height_on_b: Height::new(0, 10).unwrap().into(),
+        timeout_timestamp_on_b: Timestamp::none(),
+    };
+
+    let res = send_nft_transfer(&mut ctx.ibc_store, &mut module, msg);
+
+    if transferable {
+        assert!(res.is_ok(), "{res:?}");
+        assert_eq!(module.escrowed.len(), 1);
+    } else {
+        assert!(
+            matches!(
+                res,
+                Err(NftTransferError::NonTransferable { ref class_id, ref token_id })
+                    if class_id.to_string() == "class_0" && token_id.as_ref() == "token_0"
+            ),
+            "{res:?}"
+        );
+        assert!(module.escrowed.is_empty());
+        assert!(ctx.get_events().is_empty());
+    }
+}
+
+/// Vouchers are not minted for received NFTs flagged as soulbound by their
+/// token data, the packet being acknowledged with an error.
+#[test]
+fn test_recv_packet_rejects_soulbound_nft() {
+    let mut module = RecordingNftTransferModule {
+        transfer_policy: Some(NonTransferablePolicy::new("soulbound")),
+        ..Default::default()
+    };
+
+    let data = PacketData::new(
+        "class_0".parse().unwrap(),
+        None,
+        None,
+        vec!["token_0".to_string(), "token_1".to_string()]
+            .try_into()
+            .unwrap(),
+        vec![
+            "http://example.com/0".parse().unwrap(),
+            "http://example.com/1".parse().unwrap(),
+        ],
+        vec![
+            r#"{"name":{"value":"token_0"}}"#.parse().unwrap(),
+            r#"{"soulbound":{"value":"true"}}"#.parse().unwrap(),
+        ],
+        "sender".to_string().into(),
+        "receiver".to_string().into(),
+        "".into(),
+    )
+    .unwrap();
+    let packet = Packet {
+        seq_on_a: 1.into(),
+        port_id_on_a: PortId::transfer(),
+        chan_id_on_a: ChannelId::new(1),
+        port_id_on_b: PortId::transfer(),
+        chan_id_on_b: ChannelId::zero(),
+        data: serde_json::to_vec(&data).unwrap(),
+        timeout_height_on_b: TimeoutHeight::no_timeout(),
+        timeout_timestamp_on_b: Timestamp::none(),
+    };
+
+    let err = process_recv_packet_execute(&mut module, &packet, data).unwrap_err();
+    let (_, nft_error) = *err;
+
+    assert!(
+        matches!(
+            nft_error,
+            NftTransferError::NonTransferable { ref token_id, .. } if token_id.as_ref() == "token_1"
+        ),
+        "{nft_error:?}"
+    );
+    assert!(module.class_traces.is_empty());
+}
+
+/// The tokens of a class are paginated in ascending order of their IDs.
+#[test]
+fn test_tokens_of_class_pagination() {
+    let class_id: PrefixedClassId = "class_0".parse().unwrap();
+    let module = RecordingNftTransferModule::with_nfts(
+        &class_id,
+        &["token_2", "token_0", "token_3", "token_1"],
+        "owner",
+    );
+
+    let mut pagination = TokenPagination {
+        start_after: None,
+        limit: Some(3),
+    };
+    let mut pages = vec![];
+    loop {
+        let page = module.tokens_of_class(&class_id, &pagination).unwrap();
+        let Some(last) = page.last() else {
+            break;
+        };
+        pagination.start_after = Some(last.clone());
+        pages.push(page);
+    }
+
+    let page_ids: Vec<Vec<_>> = pages
+        .iter()
+        .map(|page| page.iter().map(ToString::to_string).collect())
+        .collect();
+    assert_eq!(
+        page_ids,
+        vec![vec!["token_0", "token_1", "token_2"], vec!["token_3"]]
+    );
+    assert!(module
+        .tokens_of_class(&"class_1".parse().unwrap(), &TokenPagination::default())
+        .unwrap()
+        .is_empty());
+}
+
+/// The class trace of vouchers is stored on the first receipt of their class,
+/// and their hashed class ID resolves to it.
+#[test]
+fn test_recv_packet_stores_class_trace() {
+    let mut module = RecordingNftTransferModule::default();
+
+    let data = PacketData::new(
+        "class_0".parse().unwrap(),
+        None,
+        None,
+        vec!["token_0".to_string()].try_into().unwrap(),
+        vec![],
+        vec![],
+        "sender".to_string().into(),
+        "receiver".to_string().into(),
+        "".into(),
+    )
+    .unwrap();
+    let packet = Packet {
+        seq_on_a: 1.into(),
+        port_id_on_a: PortId::transfer(),
+        chan_id_on_a: ChannelId::new(1),
+        port_id_on_b: PortId::transfer(),
+        chan_id_on_b: ChannelId::zero(),
+        data: serde_json::to_vec(&data).unwrap(),
+        timeout_height_on_b: TimeoutHeight::no_timeout(),
+        timeout_timestamp_on_b: Timestamp::none(),
+    };
+
+    let voucher_class_id: PrefixedClassId = "transfer/channel-0/class_0".parse().unwrap();
+    let hash = module.class_hash_string(&voucher_class_id).unwrap();
+
+    for _ in 0..2 {
+        let extras = process_recv_packet_execute(&mut module, &packet, data.clone()).unwrap();
+        let class_trace_event = extras
+            .events
+            .iter()
+            .find(|event| event.kind == "class_trace")
+            .expect("class trace event");
+        assert!(class_trace_event
+            .attributes
+            .iter()
+            .any(|attr| attr.key == "trace_hash" && attr.value.to_string() == hash));
+    }
+
+    assert_eq!(
+        module.class_traces,
+        vec![(hash.clone(), voucher_class_id.clone())]
+    );
+    assert_eq!(
+        resolve_class_id(&module, &format!("ibc/{hash}")).unwrap(),
+        voucher_class_id
+    );
+    assert_eq!(
+        resolve_class_id(&module, "transfer/channel-0/class_0").unwrap(),
+        voucher_class_id
+    );
+    assert!(matches!(
+        resolve_class_id(&module, "ibc/UNKNOWN"),
+        Err(NftTransferError::ClassTraceNotFound { .. })
+    ));
+}
+
+#[test]
+fn test_bank_escrow_and_unescrow_nft() {
+    let mut bank = MockBank::default();
+    let nft = NftAsset::new(
+        "myclass".parse().expect("valid class id"),
+        "mytoken".parse().expect("valid token id"),
+    );
+    let alice = Signer::from("cosmos1alice".to_string());
+    let bob = Signer::from("cosmos1bob".to_string());
+    let escrow = Signer::from("nft-transfer/channel-0".to_string());
+    let port_id: PortId = "nft-transfer".parse().expect("valid port id");
+
+    bank.mint_to(&alice, &nft).expect("no error");
+    assert!(matches!(bank.mint_to(&bob, &nft), Err(BankError::Other(_))));
+
+    escrow_nft(&mut bank, &alice, &port_id, &ChannelId::zero(), &nft).expect("no error");
+    assert_eq!(bank.nft_owner(&nft), Some(&escrow));
+
+    assert!(matches!(
+        escrow_nft(&mut bank, &alice, &port_id, &ChannelId::zero(), &nft),
+        Err(NftTransferError::Bank(BankError::InsufficientFunds { .. }))
+    ));
+
+    unescrow_nft(&mut bank, &bob, &port_id, &ChannelId::zero(), &nft).expect("no error");
+    assert_eq!(bank.nft_owner(&nft), Some(&bob));
+
+    bank.burn_from(&bob, &nft).expect("no error");
+    assert_eq!(bank.nft_owner(&nft), None);
+}
@@ -1,13 +1,29 @@
+use ibc::apps::transfer::bank::{escrow_coins, unescrow_coins};
+use ibc::apps::transfer::context::{
+    ReceiverResolver, TokenTransferExecutionContext, TokenTransferValidationContext,
+};
+use ibc::apps::transfer::handler::process_recv_packet_execute;
+use ibc::apps::transfer::invariants::{
+    check_total_escrow, EscrowInvariantViolation, TokenTransferInvariantContext,
+};
 use ibc::apps::transfer::module::{
     on_chan_open_init_execute, on_chan_open_init_validate, on_chan_open_try_execute,
     on_chan_open_try_validate,
 };
-use ibc::apps::transfer::types::VERSION;
+use ibc::apps::transfer::types::error::TokenTransferError;
+use ibc::apps::transfer::types::packet::PacketData;
+use ibc::apps::transfer::types::{Amount, Memo, PrefixedCoin, PrefixedDenom, VERSION};
 use ibc::core::channel::types::channel::{Counterparty, Order};
+use ibc::core::channel::types::packet::Packet;
+use ibc::core::channel::types::timeout::TimeoutHeight;
 use ibc::core::channel::types::Version;
 use ibc::core::host::types::identifiers::{ChannelId, ConnectionId, PortId};
 use ibc::core::primitives::prelude::*;
+use ibc::core::primitives::{Signer, Timestamp};
+use ibc::core::router::bank::BankContext;
+use ibc::core::router::types::error::BankError;
 use ibc::cosmos_host::utils::cosmos_adr028_escrow_address;
+use ibc_testkit::testapp::ibc::applications::bank::MockBank;
 use ibc_testkit::testapp::ibc::applications::transfer::types::DummyTransferModule;
 use subtle_encoding::bech32;
 
@@ -166,3 +182,291 @@ fn test_on_chan_open_try_counterparty_incorrect_version() {
 
     assert!(res.is_err());
 }
+
+/// A transfer module whose accounts use the `osmo` bech32 prefix, recording
+/// the accounts it mints tokens to.
+#[derive(Default)]
+struct PrefixRewritingTransferModule {
+    minted_to: Vec<Signer>,
+}
+
+impl ReceiverResolver for PrefixRewritingTransferModule {
+    type AccountId = Signer;
+
+    fn resolve_receiver(
+        &self,
+        _packet: &Packet,
+        receiver: &Signer,
+    ) -> Result<Signer, TokenTransferError> {
+        let (_, data) = bech32::decode(receiver.as_ref())
+            .map_err(|_| TokenTransferError::ParseAccountFailure)?;
+
+        Ok(bech32::encode("osmo", data).into())
+    }
+}
+
+impl TokenTransferValidationContext for PrefixRewritingTransferModule {
+    type AccountId = Signer;
+
+    fn get_port(&self) -> Result<PortId, TokenTransferError> {
+        Ok(PortId::transfer())
+    }
+
+    fn can_send_coins(&self) -> Result<(), TokenTransferError> {
+        Ok(())
+    }
+
+    fn can_receive_coins(&self) -> Result<(), TokenTransferError> {
+        Ok(())
+    }
+
+    fn escrow_coins_validate(
+        &self,
+        _from_account: &Signer,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+        _coin: &PrefixedCoin,
+        _memo: &Memo,
+    ) -> Result<(), TokenTransferError> {
+        Ok(())
+    }
+
+    fn unescrow_coins_validate(
+        &self,
+        _to_account: &Signer,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+        _coin: &PrefixedCoin,
+    ) -> Result<(), TokenTransferError> {
+        Ok(())
+    }
+
+    fn mint_coins_validate(
+        &self,
+        _account: &Signer,
+        _coin: &PrefixedCoin,
+    ) -> Result<(), TokenTransferError> {
+        Ok(())
+    }
+
+    fn burn_coins_validate(
+        &self,
+        _account: &Signer,
+        _coin: &PrefixedCoin,
+        _memo: &Memo,
+    ) -> Result<(), TokenTransferError> {
+        Ok(())
+    }
+
+    fn receiver_resolver(&self) -> Option<&dyn ReceiverResolver<AccountId = Signer>> {
+        Some(self)
+    }
+}
+
+impl TokenTransferExecutionContext for PrefixRewritingTransferModule {
+    fn escrow_coins_execute(
+        &mut self,
+        _from_account: &Signer,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+        _coin: &PrefixedCoin,
+        _memo: &Memo,
+    ) -> Result<(), TokenTransferError> {
+        Ok(())
+    }
+
+    fn unescrow_coins_execute(
+        &mut self,
+        _to_account: &Signer,
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+        _coin: &PrefixedCoin,
+    ) -> Result<(), TokenTransferError> {
+        Ok(())
+    }
+
+    fn mint_coins_execute(
+        &mut self,
+        account: &Signer,
+        _coin: &PrefixedCoin,
+    ) -> Result<(), TokenTransferError> {
+        self.minted_to.push(account.clone());
+        Ok(())
+    }
+
+    fn burn_coins_execute(
+        &mut self,
+        _account: &Signer,
+        _coin: &PrefixedCoin,
+        _memo: &Memo,
+    ) -> Result<(), TokenTransferError> {
+        Ok(())
+    }
+}
+
+#[test]
+fn test_recv_packet_resolves_receiver() {
+    let receiver = bech32::encode("cosmos", [7u8; 20]);
+    let data = PacketData {
+        token: "100uatom".parse().unwrap(),
+        sender: "sender".to_string().into(),
+        receiver: receiver.into(),
+        memo: "".into(),
+    };
+    let packet = Packet {
+        seq_on_a: 1.into(),
+        port_id_on_a: PortId::transfer(),
+        chan_id_on_a: ChannelId::new(0),
+        port_id_on_b: PortId::transfer(),
+        chan_id_on_b: ChannelId::new(1),
+        data: serde_json::to_vec(&data).unwrap(),
+        timeout_height_on_b: TimeoutHeight::no_timeout(),
+        timeout_timestamp_on_b: Timestamp::none(),
+    };
+
+    let mut ctx = PrefixRewritingTransferModule::default();
+    process_recv_packet_execute(&mut ctx, &packet, data).unwrap();
+
+    assert_eq!(
+        ctx.minted_to,
+        vec![Signer::from(bech32::encode("osmo", [7u8; 20]))]
+    );
+}
+
+#[test]
+fn test_bank_escrow_and_unescrow_coins() {
+    let mut bank = MockBank::default();
+    let coin =
+        |amount: u64| -> PrefixedCoin { format!("{amount}uatom").parse().expect("valid coin") };
+    let denom: PrefixedDenom = "uatom".parse().expect("valid denom");
+    let alice = Signer::from("cosmos1alice".to_string());
+    let bob = Signer::from("cosmos1bob".to_string());
+    let escrow = Signer::from("transfer/channel-0".to_string());
+
+    bank.mint_to(&alice, &coin(100)).expect("no error");
+
+    escrow_coins(
+        &mut bank,
+        &alice,
+        &PortId::transfer(),
+        &ChannelId::zero(),
+        &coin(60),
+    )
+    .expect("no error");
+    assert_eq!(bank.balance(&alice, &denom), 40u64.into());
+    assert_eq!(bank.balance(&escrow, &denom), 60u64.into());
+
+    assert!(matches!(
+        escrow_coins(
+            &mut bank,
+            &alice,
+            &PortId::transfer(),
+            &ChannelId::zero(),
+            &coin(50),
+        ),
+        Err(TokenTransferError::Bank(
+            BankError::InsufficientFunds { .. }
+        ))
+    ));
+
+    unescrow_coins(
+        &mut bank,
+        &bob,
+        &PortId::transfer(),
+        &ChannelId::zero(),
+        &coin(60),
+    )
+    .expect("no error");
+    assert_eq!(bank.balance(&escrow, &denom), 0u64.into());
+    assert_eq!(bank.balance(&bob, &denom), 60u64.into());
+
+    bank.burn_from(&bob, &coin(60)).expect("no error");
+    assert_eq!(bank.balance(&bob, &denom), 0u64.into());
+}
+
+/// Tracks the vouchers in circulation of the native tokens escrowed over the
+/// channels `channel-0` and `channel-1`, as the host would on every escrow
+/// and unescrow.
+#[derive(Default)]
+struct EscrowTracker {
+    bank: MockBank,
+    outstanding_vouchers: BTreeMap<PrefixedDenom, Amount>,
+}
+
+impl EscrowTracker {
+    fn escrow_accounts() -> [Signer; 2] {
+        [
+            Signer::from("transfer/channel-0".to_string()),
+            Signer::from("transfer/channel-1".to_string()),
+        ]
+    }
+}
+
+impl TokenTransferInvariantContext for EscrowTracker {
+    fn escrowed_denoms(&self) -> Result<Vec<PrefixedDenom>, TokenTransferError> {
+        Ok(self.outstanding_vouchers.keys().cloned().collect())
+    }
+
+    fn total_escrow_balance(&self, denom: &PrefixedDenom) -> Result<Amount, TokenTransferError> {
+        Ok(Self::escrow_accounts()
+            .iter()
+            .map(|account| self.bank.balance(account, denom))
+            .fold(0u64.into(), |total: Amount, balance| {
+                total.checked_add(balance).expect("no overflow")
+            }))
+    }
+
+    fn total_outstanding_vouchers(
+        &self,
+        denom: &PrefixedDenom,
+    ) -> Result<Amount, TokenTransferError> {
+        Ok(self
+            .outstanding_vouchers
+            .get(denom)
+            .copied()
+            .unwrap_or_else(|| 0u64.into()))
+    }
+}
+
+#[test]
+fn test_check_total_escrow() {
+    let mut tracker = EscrowTracker::default();
+    let coin =
+        |amount: u64| -> PrefixedCoin { format!("{amount}uatom").parse().expect("valid coin") };
+    let denom: PrefixedDenom = "uatom".parse().expect("valid denom");
+    let alice = Signer::from("cosmos1alice".to_string());
+
+    tracker.bank.mint_to(&alice, &coin(100)).expect("no error");
+
+    for channel_id in [ChannelId::new(0), ChannelId::new(1)] {
+        escrow_coins(
+            &mut tracker.bank,
+            &alice,
+            &PortId::transfer(),
+            &channel_id,
+            &coin(30),
+        )
+        .expect("no error");
+    }
+    tracker
+        .outstanding_vouchers
+        .insert(denom.clone(), 60u64.into());
+
+    assert!(check_total_escrow(&tracker).expect("no error").is_empty());
+
+    // Tokens leaving an escrow account without their vouchers being sent
+    // back break the invariant.
+    tracker
+        .bank
+        .transfer(&EscrowTracker::escrow_accounts()[1], &alice, &coin(10))
+        .expect("no error");
+
+    assert_eq!(
+        check_total_escrow(&tracker).expect("no error"),
+        vec![EscrowInvariantViolation {
+            denom,
+            escrow_balance: 50u64.into(),
+            outstanding_vouchers: 60u64.into(),
+        }]
+    );
+}
@@ -0,0 +1,147 @@
+use core::str::FromStr;
+
+#[cfg(feature = "serde")]
+use ibc::apps::nft_transfer::types::packet::PacketData as NftPacketData;
+use ibc::apps::nft_transfer::types::PrefixedClassId;
+#[cfg(feature = "serde")]
+use ibc::apps::transfer::types::packet::PacketData as TransferPacketData;
+use ibc::apps::transfer::types::PrefixedDenom;
+use ibc::core::channel::types::commitment::compute_packet_commitment;
+use ibc::core::host::types::identifiers::{
+    ChannelId, ClientId, ConnectionId, IdentifierMode, PortId,
+};
+use ibc_testkit::fixtures::conformance::{
+    channel_identifiers, class_id_traces, client_identifiers, connection_identifiers, denom_traces,
+    nft_transfer_packets, port_identifiers, transfer_packets, IdentifierVector, PacketVector,
+};
+
+#[test]
+fn test_client_identifiers_conformance() {
+    for IdentifierVector { id, valid } in client_identifiers() {
+        assert_eq!(ClientId::from_str(&id).is_ok(), valid, "{id:?}");
+    }
+}
+
+#[test]
+fn test_connection_identifiers_conformance() {
+    for IdentifierVector { id, valid } in connection_identifiers() {
+        let lenient = ConnectionId::from_str_with_mode(&id, IdentifierMode::Lenient);
+        assert_eq!(lenient.is_ok(), valid, "{id:?}");
+        // Connection identifiers are parsed in the exact mode.
+        assert_eq!(
+            ConnectionId::from_str(&id).is_ok(),
+            lenient.is_ok_and(|id| id.counter().is_some()),
+            "{id:?}"
+        );
+    }
+}
+
+#[test]
+fn test_channel_identifiers_conformance() {
+    for IdentifierVector { id, valid } in channel_identifiers() {
+        let lenient = ChannelId::from_str_with_mode(&id, IdentifierMode::Lenient);
+        assert_eq!(lenient.is_ok(), valid, "{id:?}");
+        // Channel identifiers are parsed in the exact mode.
+        assert_eq!(
+            ChannelId::from_str(&id).is_ok(),
+            lenient.is_ok_and(|id| id.counter().is_some()),
+            "{id:?}"
+        );
+    }
+}
+
+#[test]
+fn test_port_identifiers_conformance() {
+    for IdentifierVector { id, valid } in port_identifiers() {
+        assert_eq!(PortId::from_str(&id).is_ok(), valid, "{id:?}");
+    }
+}
+
+#[test]
+fn test_denom_traces_conformance() {
+    for vector in denom_traces() {
+        let parsed = PrefixedDenom::from_str(vector.input).ok().map(|denom| {
+            (
+                denom.trace_path.to_string(),
+                denom.base_denom.as_str().to_string(),
+            )
+        });
+
+        assert_eq!(
+            parsed
+                .as_ref()
+                .map(|(path, base)| (path.as_str(), base.as_str())),
+            vector.expected,
+            "{:?}",
+            vector.input
+        );
+    }
+}
+
+#[test]
+fn test_class_id_traces_conformance() {
+    for vector in class_id_traces() {
+        let parsed = PrefixedClassId::from_str(vector.input)
+            .ok()
+            .map(|class_id| {
+                (
+                    class_id.trace_path.to_string(),
+                    class_id.base_class_id.as_ref().to_string(),
+                )
+            });
+
+        assert_eq!(
+            parsed
+                .as_ref()
+                .map(|(path, base)| (path.as_str(), base.as_str())),
+            vector.expected,
+            "{:?}",
+            vector.input
+        );
+    }
+}
+
+/// Asserts the commitment of the packet data encoded by ibc-go is the one
+/// stored by ibc-go.
+fn assert_packet_commitment<D>(vector: &PacketVector<D>) {
+    let commitment = compute_packet_commitment(
+        vector.encoded.as_bytes(),
+        &vector.timeout_height,
+        &vector.timeout_timestamp,
+    );
+    let commitment: String = commitment
+        .as_ref()
+        .iter()
+        .map(|byte| format!("{byte:02x}"))
+        .collect();
+
+    assert_eq!(commitment, vector.commitment, "{:?}", vector.encoded);
+}
+
+#[test]
+fn test_transfer_packets_conformance() {
+    for vector in transfer_packets() {
+        assert_eq!(vector.packet_data.encode_json(), vector.encoded);
+        assert_packet_commitment(&vector);
+
+        #[cfg(feature = "serde")]
+        assert_eq!(
+            serde_json::from_str::<TransferPacketData>(vector.encoded).expect("valid packet data"),
+            vector.packet_data
+        );
+    }
+}
+
+#[test]
+fn test_nft_transfer_packets_conformance() {
+    for vector in nft_transfer_packets() {
+        assert_eq!(vector.packet_data.encode_json(), vector.encoded);
+        assert_packet_commitment(&vector);
+
+        #[cfg(feature = "serde")]
+        assert_eq!(
+            serde_json::from_str::<NftPacketData>(vector.encoded).expect("valid packet data"),
+            vector.packet_data
+        );
+    }
+}
@@ -0,0 +1,203 @@
+use ibc::core::channel::types::msgs::{
+    MsgAcknowledgement, MsgChannelOpenInit, MsgChannelOpenTry, MsgRecvPacket, MsgTimeout,
+};
+use ibc::core::channel::types::proto::v1::{
+    MsgAcknowledgement as RawMsgAcknowledgement, MsgChannelOpenInit as RawMsgChannelOpenInit,
+    MsgChannelOpenTry as RawMsgChannelOpenTry, MsgRecvPacket as RawMsgRecvPacket,
+    MsgTimeout as RawMsgTimeout,
+};
+use ibc::core::client::types::msgs::{MsgCreateClient, MsgUpdateClient};
+use ibc::core::client::types::proto::v1::{
+    MsgCreateClient as RawMsgCreateClient, MsgUpdateClient as RawMsgUpdateClient,
+};
+use ibc::core::client::types::Height;
+use ibc::core::connection::types::msgs::{MsgConnectionOpenInit, MsgConnectionOpenTry};
+use ibc::core::connection::types::proto::v1::{
+    MsgConnectionOpenInit as RawMsgConnectionOpenInit,
+    MsgConnectionOpenTry as RawMsgConnectionOpenTry,
+};
+use ibc::core::host::types::identifiers::ClientId;
+use ibc_testkit::fixtures::core::channel::{
+    dummy_raw_msg_acknowledgement, dummy_raw_msg_chan_open_init, dummy_raw_msg_chan_open_try,
+    dummy_raw_msg_recv_packet, dummy_raw_msg_timeout,
+};
+use ibc_testkit::fixtures::core::connection::{dummy_msg_conn_open_init, dummy_msg_conn_open_try};
+use ibc_testkit::fixtures::core::signer::dummy_account_id;
+use ibc_testkit::testapp::ibc::clients::mock::client_state::MockClientState;
+use ibc_testkit::testapp::ibc::clients::mock::consensus_state::MockConsensusState;
+use ibc_testkit::testapp::ibc::clients::mock::header::MockHeader;
+
+#[test]
+fn create_client_builder_round_trip() {
+    let header = MockHeader::new(Height::new(0, 42).expect("no error"));
+    let msg = MsgCreateClient::builder()
+        .client_state(MockClientState::new(header))
+        .consensus_state(MockConsensusState::new(header))
+        .signer(dummy_account_id())
+        .build();
+
+    assert_eq!(
+        msg,
+        MsgCreateClient::new(
+            MockClientState::new(header).into(),
+            MockConsensusState::new(header).into(),
+            dummy_account_id(),
+        )
+    );
+    assert_eq!(
+        MsgCreateClient::try_from(RawMsgCreateClient::from(msg.clone())).expect("no error"),
+        msg
+    );
+}
+
+#[test]
+fn update_client_builder_round_trip() {
+    let msg = MsgUpdateClient::builder()
+        .client_id("9999-mock-0".parse::<ClientId>().expect("no error"))
+        .client_message(MockHeader::new(Height::new(0, 43).expect("no error")))
+        .signer(dummy_account_id())
+        .build();
+
+    assert_eq!(
+        MsgUpdateClient::try_from(RawMsgUpdateClient::from(msg.clone())).expect("no error"),
+        msg
+    );
+}
+
+#[test]
+fn conn_open_init_builder_round_trip() {
+    let expected = dummy_msg_conn_open_init();
+    let msg = MsgConnectionOpenInit::builder()
+        .client_id_on_a(expected.client_id_on_a.clone())
+        .counterparty(expected.counterparty.clone())
+        .version(expected.version.clone())
+        .delay_period(expected.delay_period)
+        .signer(expected.signer.clone())
+        .build();
+
+    assert_eq!(msg, expected);
+    assert_eq!(
+        MsgConnectionOpenInit::try_from(RawMsgConnectionOpenInit::from(msg.clone()))
+            .expect("no error"),
+        msg
+    );
+}
+
+#[test]
+fn conn_open_try_builder_round_trip() {
+    let fixture = dummy_msg_conn_open_try(10, 5);
+    let msg = MsgConnectionOpenTry::builder()
+        .client_id_on_b(fixture.client_id_on_b.clone())
+        .client_state_of_b_on_a(fixture.client_state_of_b_on_a.clone())
+        .counterparty(fixture.counterparty.clone())
+        .versions_on_a(fixture.versions_on_a.clone())
+        .proof_conn_end_on_a(fixture.proof_conn_end_on_a.clone())
+        .proof_client_state_of_b_on_a(fixture.proof_client_state_of_b_on_a.clone())
+        .proof_consensus_state_of_b_on_a(fixture.proof_consensus_state_of_b_on_a.clone())
+        .proofs_height_on_a(fixture.proofs_height_on_a)
+        .consensus_height_of_b_on_a(fixture.consensus_height_of_b_on_a)
+        .delay_period(fixture.delay_period)
+        .signer(fixture.signer.clone())
+        .build();
+
+    assert_eq!(
+        MsgConnectionOpenTry::try_from(RawMsgConnectionOpenTry::from(msg.clone()))
+            .expect("no error"),
+        msg
+    );
+}
+
+#[test]
+fn chan_open_init_builder_round_trip() {
+    let expected =
+        MsgChannelOpenInit::try_from(dummy_raw_msg_chan_open_init(None)).expect("no error");
+    let msg = MsgChannelOpenInit::builder()
+        .port_id_on_a(expected.port_id_on_a.clone())
+        .connection_hops_on_a(expected.connection_hops_on_a.clone())
+        .port_id_on_b(expected.port_id_on_b.clone())
+        .ordering(expected.ordering)
+        .signer(expected.signer.clone())
+        .version_proposal(expected.version_proposal.clone())
+        .build();
+
+    assert_eq!(msg, expected);
+    assert_eq!(
+        MsgChannelOpenInit::try_from(RawMsgChannelOpenInit::from(msg.clone())).expect("no error"),
+        msg
+    );
+}
+
+#[test]
+fn chan_open_try_builder_round_trip() {
+    let fixture = MsgChannelOpenTry::try_from(dummy_raw_msg_chan_open_try(10)).expect("no error");
+    let msg = MsgChannelOpenTry::builder()
+        .port_id_on_b(fixture.port_id_on_b.clone())
+        .connection_hops_on_b(fixture.connection_hops_on_b.clone())
+        .port_id_on_a(fixture.port_id_on_a.clone())
+        .chan_id_on_a(fixture.chan_id_on_a.clone())
+        .version_supported_on_a(fixture.version_supported_on_a.clone())
+        .proof_chan_end_on_a(fixture.proof_chan_end_on_a.clone())
+        .proof_height_on_a(fixture.proof_height_on_a)
+        .ordering(fixture.ordering)
+        .signer(fixture.signer.clone())
+        .build();
+
+    assert_eq!(
+        MsgChannelOpenTry::try_from(RawMsgChannelOpenTry::from(msg.clone())).expect("no error"),
+        msg
+    );
+}
+
+#[test]
+fn recv_packet_builder_round_trip() {
+    let expected = MsgRecvPacket::try_from(dummy_raw_msg_recv_packet(10)).expect("no error");
+    let msg = MsgRecvPacket::builder()
+        .packet(expected.packet.clone())
+        .proof_commitment_on_a(expected.proof_commitment_on_a.clone())
+        .proof_height_on_a(expected.proof_height_on_a)
+        .signer(expected.signer.clone())
+        .build();
+
+    assert_eq!(msg, expected);
+    assert_eq!(
+        MsgRecvPacket::try_from(RawMsgRecvPacket::from(msg.clone())).expect("no error"),
+        msg
+    );
+}
+
+#[test]
+fn acknowledgement_builder_round_trip() {
+    let expected =
+        MsgAcknowledgement::try_from(dummy_raw_msg_acknowledgement(10)).expect("no error");
+    let msg = MsgAcknowledgement::builder()
+        .packet(expected.packet.clone())
+        .acknowledgement(expected.acknowledgement.clone())
+        .proof_acked_on_b(expected.proof_acked_on_b.clone())
+        .proof_height_on_b(expected.proof_height_on_b)
+        .signer(expected.signer.clone())
+        .build();
+
+    assert_eq!(msg, expected);
+    assert_eq!(
+        MsgAcknowledgement::try_from(RawMsgAcknowledgement::from(msg.clone())).expect("no error"),
+        msg
+    );
+}
+
+#[test]
+fn timeout_builder_round_trip() {
+    let expected = MsgTimeout::try_from(dummy_raw_msg_timeout(10, 5, 0)).expect("no error");
+    let msg = MsgTimeout::builder()
+        .packet(expected.packet.clone())
+        .next_seq_recv_on_b(expected.next_seq_recv_on_b)
+        .proof_unreceived_on_b(expected.proof_unreceived_on_b.clone())
+        .proof_height_on_b(expected.proof_height_on_b)
+        .signer(expected.signer.clone())
+        .build();
+
+    assert_eq!(msg, expected);
+    assert_eq!(
+        MsgTimeout::try_from(RawMsgTimeout::from(msg.clone())).expect("no error"),
+        msg
+    );
+}
@@ -0,0 +1,51 @@
+use core::error::Error;
+
+use ibc::core::channel::types::error::ChannelError;
+use ibc::core::client::types::error::{ClientError, UpgradeClientError};
+use ibc::core::handler::types::error::ContextError;
+use ibc::error::IbcError;
+use ibc::primitives::AbciError;
+
+#[test]
+fn test_ibc_error_from_context_error() {
+    let err = IbcError::from(ContextError::from(ChannelError::UnsupportedChannelFreeze));
+
+    assert!(matches!(
+        err,
+        IbcError::Channel(ChannelError::UnsupportedChannelFreeze)
+    ));
+    assert_eq!(err.codespace(), "channel");
+    assert_eq!(
+        err.code(),
+        ChannelError::UnsupportedChannelFreeze.code(),
+        "the code of the channel error is reported as is"
+    );
+    assert_eq!(
+        err.to_string(),
+        format!(
+            "ICS04 channel error: {}",
+            ChannelError::UnsupportedChannelFreeze
+        )
+    );
+}
+
+#[test]
+fn test_ibc_error_source() {
+    let err = IbcError::from(ChannelError::UnsupportedChannelFreeze);
+
+    let source = err.source().expect("the channel error is the source");
+    assert!(matches!(
+        source.downcast_ref::<ChannelError>(),
+        Some(ChannelError::UnsupportedChannelFreeze)
+    ));
+
+    let err = IbcError::from(UpgradeClientError::InvalidUpgradePlan {
+        reason: "empty".to_string(),
+    });
+
+    let source = err.source().expect("the client error is the source");
+    assert!(matches!(
+        source.downcast_ref::<ClientError>(),
+        Some(ClientError::Upgrade(_))
+    ));
+}
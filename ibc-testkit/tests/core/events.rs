@@ -0,0 +1,46 @@
+use core::str::FromStr;
+
+use ibc::core::client::types::events::{
+    ClientMisbehaviour, CreateClient, RecoverClient, UpgradeClient,
+};
+use ibc::core::client::types::Height;
+use ibc::core::handler::types::events::{IbcEvent, MessageEvent};
+use ibc::core::host::types::identifiers::ClientType;
+use ibc::core::primitives::prelude::*;
+
+fn client_events() -> Vec<IbcEvent> {
+    let client_type = ClientType::from_str("07-tendermint").unwrap();
+    let client_id = client_type.build_client_id(0);
+    let height = Height::new(0, 5).unwrap();
+
+    vec![
+        IbcEvent::CreateClient(CreateClient::new(
+            client_id.clone(),
+            client_type.clone(),
+            height,
+        )),
+        IbcEvent::UpgradeClient(UpgradeClient::new(
+            client_id.clone(),
+            client_type.clone(),
+            height,
+        )),
+        IbcEvent::RecoverClient(RecoverClient::new(client_id.clone(), client_type.clone())),
+        IbcEvent::ClientMisbehaviour(ClientMisbehaviour::new(client_id, client_type)),
+    ]
+}
+
+/// Events are serialized with their type as tag, and deserialized back.
+#[test]
+fn test_ibc_event_serde_tags() {
+    let events = client_events()
+        .into_iter()
+        .chain([IbcEvent::Message(MessageEvent::Client)]);
+
+    for event in events {
+        let value = serde_json::to_value(&event).unwrap();
+        let tags: Vec<_> = value.as_object().unwrap().keys().collect();
+        assert_eq!(tags, vec![event.event_type()]);
+
+        assert_eq!(serde_json::from_value::<IbcEvent>(value).unwrap(), event);
+    }
+}
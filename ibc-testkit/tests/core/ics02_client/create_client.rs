@@ -1,6 +1,10 @@
+use core::time::Duration;
+
 use basecoin_store::impls::InMemoryStore;
+use ibc::clients::tendermint::types::error::Error as TmClientError;
 use ibc::clients::tendermint::types::{
-    client_type as tm_client_type, ConsensusState as TmConsensusState,
+    client_type as tm_client_type, AllowUpdate, ClientState as TmClientState,
+    ConsensusState as TmConsensusState, TrustThreshold,
 };
 use ibc::core::client::context::client_state::ClientStateCommon;
 use ibc::core::client::context::ClientValidationContext;
@@ -8,12 +12,15 @@ use ibc::core::client::types::error::ClientError;
 use ibc::core::client::types::msgs::{ClientMsg, MsgCreateClient};
 use ibc::core::client::types::Height;
 use ibc::core::commitment_types::error::CommitmentError;
+use ibc::core::commitment_types::specs::ProofSpecs;
 use ibc::core::entrypoint::{execute, validate};
 use ibc::core::handler::types::error::ContextError;
 use ibc::core::handler::types::msgs::MsgEnvelope;
 use ibc::core::host::types::identifiers::ClientId;
 use ibc::core::host::types::path::{ClientConsensusStatePath, NextClientSequencePath};
-use ibc::core::host::{ClientStateRef, ValidationContext};
+use ibc::core::host::{AllowedClients, ClientStateRef, ValidationContext};
+use ibc::error::IbcError;
+use ibc::primitives::AbciError;
 use ibc_query::core::context::ProvableContext;
 use ibc_testkit::context::{MockContext, TendermintContext};
 use ibc_testkit::fixtures::clients::tendermint::{
@@ -29,6 +36,12 @@ use ibc_testkit::testapp::ibc::clients::mock::header::MockHeader;
 use ibc_testkit::testapp::ibc::clients::{AnyClientState, AnyConsensusState};
 use ibc_testkit::testapp::ibc::core::router::MockRouter;
 use ibc_testkit::testapp::ibc::core::types::{DefaultIbcStore, LightClientBuilder, MockIbcStore};
+use tendermint::crypto::default::Sha256;
+use tendermint::Time;
+use tendermint_testgen::{
+    Generator, Header as TestgenHeader, LightBlock as TestgenLightBlock,
+    Validator as TestgenValidator,
+};
 use test_log::test;
 
 #[test]
@@ -100,6 +113,118 @@ fn test_tm_create_client_ok() {
     assert_eq!(ctx.client_state(&client_id).unwrap(), expected_client_state);
 }
 
+#[test]
+fn test_tm_create_client_from_block_header_ok() {
+    let mut ctx = DefaultIbcStore::default();
+    let mut router = MockRouter::new_with_transfer();
+
+    let validators = [
+        TestgenValidator::new("1").voting_power(50),
+        TestgenValidator::new("2").voting_power(50),
+    ];
+    let next_validators = [TestgenValidator::new("1").voting_power(100)];
+
+    let light_block = TestgenLightBlock::new_default_with_header(
+        TestgenHeader::new(&validators)
+            .app_hash(vec![1; 32].try_into().expect("Never fails"))
+            .height(5)
+            .chain_id("ibc-1")
+            .next_validators(&next_validators)
+            .time(Time::from_unix_timestamp(1_700_000_000, 0).expect("Never fails")),
+    )
+    .validators(&validators)
+    .next_validators(&next_validators)
+    .generate()
+    .expect("Never fails");
+    let tm_header = &light_block.signed_header.header;
+
+    let consensus_state =
+        TmConsensusState::from_block_header::<Sha256>(tm_header, &light_block.next_validators)
+            .expect("valid light block");
+    assert_eq!(consensus_state, TmConsensusState::from(tm_header.clone()));
+
+    // The next validators must be those committed to by the header.
+    assert!(matches!(
+        TmConsensusState::from_block_header::<Sha256>(tm_header, &light_block.validators),
+        Err(TmClientError::MismatchNextValidatorsHashes { .. })
+    ));
+
+    let client_state = TmClientState::from_block_header(
+        tm_header,
+        TrustThreshold::ONE_THIRD,
+        Duration::from_secs(64000),
+        Duration::from_secs(128_000),
+        Duration::from_millis(3000),
+        ProofSpecs::cosmos(),
+        Vec::new(),
+        AllowUpdate {
+            after_expiry: false,
+            after_misbehaviour: false,
+        },
+    )
+    .expect("valid parameters");
+    assert_eq!(client_state.latest_height, Height::new(1, 5).unwrap());
+
+    let msg = MsgCreateClient::new(
+        client_state.into(),
+        consensus_state.into(),
+        dummy_account_id(),
+    );
+    let msg_envelope = MsgEnvelope::from(ClientMsg::from(msg));
+
+    let res = validate(&ctx, &router, msg_envelope.clone());
+    assert!(res.is_ok(), "tendermint client validation happy path");
+
+    let res = execute(&mut ctx, &mut router, msg_envelope);
+    assert!(res.is_ok(), "tendermint client execution happy path");
+}
+
+#[test]
+fn test_create_client_not_allowed() {
+    let mut ctx = DefaultIbcStore::default();
+    ctx.allowed_clients = AllowedClients::Only(vec![tm_client_type()]);
+    let router = MockRouter::new_with_transfer();
+    let height = Height::new(0, 42).unwrap();
+
+    let msg = MsgCreateClient::new(
+        MockClientState::new(MockHeader::new(height)).into(),
+        MockConsensusState::new(MockHeader::new(height)).into(),
+        dummy_account_id(),
+    );
+
+    let res = validate(&ctx, &router, MsgEnvelope::from(ClientMsg::from(msg)));
+
+    assert!(matches!(
+        res,
+        Err(ContextError::ClientError(
+            ClientError::ClientTypeNotAllowed { .. }
+        ))
+    ))
+}
+
+#[test]
+fn test_create_client_not_allowed_error_code() {
+    let mut ctx = DefaultIbcStore::default();
+    ctx.allowed_clients = AllowedClients::Only(vec![tm_client_type()]);
+    let router = MockRouter::new_with_transfer();
+    let height = Height::new(0, 42).unwrap();
+
+    let msg = MsgCreateClient::new(
+        MockClientState::new(MockHeader::new(height)).into(),
+        MockConsensusState::new(MockHeader::new(height)).into(),
+        dummy_account_id(),
+    );
+
+    let err = validate(&ctx, &router, MsgEnvelope::from(ClientMsg::from(msg))).unwrap_err();
+
+    // The error is reported under the code of the underlying client error,
+    // including once converted into the top-level error.
+    assert_eq!((err.codespace(), err.code()), ("client", 17));
+
+    let err = IbcError::from(err);
+    assert_eq!((err.codespace(), err.code()), ("client", 17));
+}
+
 #[test]
 fn test_invalid_frozen_tm_client_creation() {
     let signer = dummy_account_id();
@@ -131,6 +256,50 @@ fn test_invalid_frozen_tm_client_creation() {
     ))
 }
 
+#[test]
+fn test_invalid_trusting_period_tm_client_creation() {
+    let signer = dummy_account_id();
+
+    let ctx = DefaultIbcStore::default();
+
+    let router = MockRouter::new_with_transfer();
+
+    let tm_header = dummy_tendermint_header();
+
+    let tm_client_state = dummy_tm_client_state_from_header(tm_header.clone())
+        .inner()
+        .clone();
+
+    // Decoding a client state does not validate it, so that the trusting
+    // period not being smaller than the unbonding period is caught upon the
+    // creation of the client.
+    let tm_client_state = TmClientState {
+        trusting_period: tm_client_state.unbonding_period,
+        ..tm_client_state
+    };
+
+    let msg = MsgCreateClient::new(
+        tm_client_state.clone().into(),
+        TmConsensusState::from(tm_header).into(),
+        signer,
+    );
+
+    let msg_envelope = MsgEnvelope::from(ClientMsg::from(msg));
+
+    let res = validate(&ctx, &router, msg_envelope);
+
+    let expected_error = TmClientError::TrustingPeriodNotSmallerThanUnbondingPeriod {
+        trusting_period: tm_client_state.trusting_period,
+        unbonding_period: tm_client_state.unbonding_period,
+    };
+
+    assert!(matches!(
+        res,
+        Err(ContextError::ClientError(ClientError::ClientSpecific { description }))
+            if description == expected_error.to_string()
+    ))
+}
+
 #[test]
 fn test_tm_create_client_proof_verification_ok() {
     let client_id = ClientId::new("07-tendermint", 0).expect("no error");
@@ -1,6 +1,8 @@
 #[cfg(feature = "serde")]
 pub mod create_client;
+pub mod query;
 pub mod recover_client;
+pub mod registry;
 pub mod update_client;
 #[cfg(feature = "serde")]
 pub mod upgrade_client;
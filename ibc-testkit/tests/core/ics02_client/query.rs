@@ -0,0 +1,119 @@
+use ibc::core::client::types::Height;
+use ibc::core::host::types::identifiers::ClientId;
+use ibc_query::core::client::{query_consensus_state_heights, QueryConsensusStateHeightsRequest};
+use ibc_query::error::QueryError;
+use ibc_query::types::PageRequest;
+use ibc_testkit::context::MockContext;
+use ibc_testkit::hosts::MockHost;
+use ibc_testkit::testapp::ibc::clients::mock::consensus_state::MockConsensusState;
+use ibc_testkit::testapp::ibc::clients::mock::header::MockHeader;
+use ibc_testkit::testapp::ibc::core::types::LightClientState;
+use rstest::*;
+
+#[fixture]
+fn ctx() -> MockContext {
+    let client_id = ClientId::new("07-tendermint", 0).expect("no error");
+
+    (2..=5).fold(
+        MockContext::default().with_light_client(
+            &client_id,
+            LightClientState::<MockHost>::with_latest_height(Height::new(0, 1).unwrap()),
+        ),
+        |ctx, revision_height| {
+            let height = Height::new(0, revision_height).unwrap();
+            ctx.with_consensus_state(
+                &client_id,
+                height,
+                MockConsensusState::new(MockHeader::new(height)).into(),
+            )
+        },
+    )
+}
+
+fn query(
+    ctx: &MockContext,
+    pagination: Option<PageRequest>,
+) -> Result<(Vec<u64>, Vec<u8>, u64), QueryError> {
+    let request = QueryConsensusStateHeightsRequest {
+        client_id: ClientId::new("07-tendermint", 0).expect("no error"),
+        pagination,
+    };
+
+    let response = query_consensus_state_heights(&ctx.ibc_store, &request)?;
+    let pagination = response.pagination.unwrap_or_default();
+
+    Ok((
+        response
+            .consensus_state_heights
+            .iter()
+            .map(Height::revision_height)
+            .collect(),
+        pagination.next_key,
+        pagination.total,
+    ))
+}
+
+#[rstest]
+fn test_query_consensus_state_heights(ctx: MockContext) {
+    // Without pagination, all the heights are returned in ascending order.
+    assert_eq!(query(&ctx, None).unwrap(), (vec![1, 2, 3, 4, 5], vec![], 0));
+
+    let first_page = PageRequest {
+        limit: 2,
+        count_total: true,
+        ..Default::default()
+    };
+    let (heights, next_key, total) = query(&ctx, Some(first_page)).unwrap();
+    assert_eq!((heights, total), (vec![1, 2], 5));
+
+    let (heights, next_key, _) = query(
+        &ctx,
+        Some(PageRequest {
+            key: next_key,
+            limit: 2,
+            ..Default::default()
+        }),
+    )
+    .unwrap();
+    assert_eq!(heights, vec![3, 4]);
+
+    // The last page has no next key.
+    assert_eq!(
+        query(
+            &ctx,
+            Some(PageRequest {
+                key: next_key,
+                limit: 2,
+                ..Default::default()
+            }),
+        )
+        .unwrap(),
+        (vec![5], vec![], 0)
+    );
+
+    assert_eq!(
+        query(
+            &ctx,
+            Some(PageRequest {
+                offset: 1,
+                limit: 3,
+                reverse: true,
+                ..Default::default()
+            }),
+        )
+        .unwrap()
+        .0,
+        vec![4, 3, 2]
+    );
+
+    assert!(matches!(
+        query(
+            &ctx,
+            Some(PageRequest {
+                key: vec![1],
+                ..Default::default()
+            }),
+        ),
+        Err(QueryError::InvalidPageKey(_))
+    ));
+}
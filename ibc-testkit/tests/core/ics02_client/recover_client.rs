@@ -5,6 +5,7 @@ use ibc::core::client::context::ClientValidationContext;
 use ibc::core::client::handler::recover_client;
 use ibc::core::client::types::msgs::{ClientMsg, MsgCreateClient, MsgRecoverClient};
 use ibc::core::client::types::{Height, Status as ClientStatus};
+use ibc::core::handler::types::events::{IbcEvent, MessageEvent};
 use ibc::core::handler::types::msgs::MsgEnvelope;
 use ibc::core::host::types::identifiers::ClientId;
 use ibc::core::host::types::path::ClientConsensusStatePath;
@@ -164,6 +165,17 @@ fn test_recover_client_ok() {
     recover_client::execute(ctx.ibc_store_mut(), msg.clone())
         .expect("client recovery execution happy path");
 
+    let ibc_events = ctx.get_events();
+    let [.., IbcEvent::Message(MessageEvent::Client), IbcEvent::RecoverClient(recover_client_event)] =
+        ibc_events.as_slice()
+    else {
+        panic!("recover client event not emitted")
+    };
+    assert_eq!(
+        recover_client_event.subject_client_id(),
+        &msg.subject_client_id
+    );
+
     // client state is copied.
     assert_eq!(
         ctx.ibc_store()
@@ -0,0 +1,164 @@
+use ibc::clients::tendermint::client_type_entry as tm_client_type_entry;
+use ibc::clients::tendermint::types::client_type as tm_client_type;
+use ibc::core::client::registry::{ClientRegistry, ClientTypeEntry};
+use ibc::core::client::types::error::ClientError;
+use ibc::core::client::types::proto::v1::Height as RawHeight;
+use ibc::core::client::types::Height;
+use ibc::core::host::types::identifiers::ClientId;
+use ibc::primitives::proto::Any;
+use ibc::primitives::{DecodingLimits, DEFAULT_MAX_DECODING_DEPTH};
+use ibc_testkit::fixtures::clients::tendermint::dummy_tm_client_state_from_raw;
+use ibc_testkit::testapp::ibc::clients::mock::client_state::{
+    client_type as mock_client_type, MockClientState, MOCK_CLIENT_STATE_TYPE_URL,
+};
+use ibc_testkit::testapp::ibc::clients::mock::consensus_state::{
+    MockConsensusState, MOCK_CONSENSUS_STATE_TYPE_URL,
+};
+use ibc_testkit::testapp::ibc::clients::mock::header::MockHeader;
+use ibc_testkit::testapp::ibc::clients::{AnyClientState, AnyConsensusState};
+use rstest::*;
+
+#[fixture]
+fn registry() -> ClientRegistry<AnyClientState, AnyConsensusState> {
+    ClientRegistry::new()
+        .with_client_type(tm_client_type(), tm_client_type_entry())
+        .with_client_type(
+            mock_client_type(),
+            ClientTypeEntry {
+                client_state_type_url: MOCK_CLIENT_STATE_TYPE_URL,
+                consensus_state_type_url: MOCK_CONSENSUS_STATE_TYPE_URL,
+                decode_client_state: |any| MockClientState::try_from(any).map(Into::into),
+                decode_consensus_state: |any| MockConsensusState::try_from(any).map(Into::into),
+            },
+        )
+}
+
+fn mock_header() -> MockHeader {
+    MockHeader::new(Height::new(0, 42).expect("Never fails"))
+}
+
+#[rstest]
+fn test_registry_decodes_client_states(
+    registry: ClientRegistry<AnyClientState, AnyConsensusState>,
+) {
+    let mock_client_state: AnyClientState = MockClientState::new(mock_header()).into();
+    let tm_client_state: AnyClientState = dummy_tm_client_state_from_raw(RawHeight {
+        revision_number: 0,
+        revision_height: 0,
+    })
+    .expect("Never fails")
+    .into();
+
+    for (client_type, client_state) in [
+        (mock_client_type(), mock_client_state),
+        (tm_client_type(), tm_client_state),
+    ] {
+        let any = Any::from(client_state.clone());
+
+        assert_eq!(
+            registry.client_type_of_client_state(&any.type_url),
+            Some(&client_type)
+        );
+        assert_eq!(
+            registry
+                .decode_client_state(&client_type, any.clone())
+                .expect("registered client type"),
+            client_state
+        );
+        assert_eq!(
+            registry
+                .decode_any_client_state(any)
+                .expect("registered type URL"),
+            client_state
+        );
+    }
+}
+
+#[rstest]
+fn test_registry_decodes_consensus_states(
+    registry: ClientRegistry<AnyClientState, AnyConsensusState>,
+) {
+    let consensus_state: AnyConsensusState = MockConsensusState::new(mock_header()).into();
+    let any = Any::from(consensus_state.clone());
+
+    assert_eq!(
+        registry.client_type_of_consensus_state(&any.type_url),
+        Some(&mock_client_type())
+    );
+    assert_eq!(
+        registry
+            .decode_consensus_state(&mock_client_type(), any.clone())
+            .expect("registered client type"),
+        consensus_state
+    );
+    assert_eq!(
+        registry
+            .decode_any_consensus_state(any)
+            .expect("registered type URL"),
+        consensus_state
+    );
+}
+
+#[rstest]
+fn test_registry_rejects_unknown_states(
+    registry: ClientRegistry<AnyClientState, AnyConsensusState>,
+) {
+    let any = Any::from(AnyClientState::from(MockClientState::new(mock_header())));
+
+    // The type URL of the state must match the one of the given client type.
+    assert!(matches!(
+        registry.decode_client_state(&tm_client_type(), any.clone()),
+        Err(ClientError::UnknownClientStateType { .. })
+    ));
+
+    let registry = ClientRegistry::<AnyClientState, AnyConsensusState>::new()
+        .with_client_type(tm_client_type(), tm_client_type_entry());
+
+    assert!(matches!(
+        registry.decode_client_state(&mock_client_type(), any.clone()),
+        Err(ClientError::UnknownClientType { .. })
+    ));
+    assert!(matches!(
+        registry.decode_any_client_state(any),
+        Err(ClientError::UnknownClientStateType { .. })
+    ));
+}
+
+#[rstest]
+fn test_registry_resolves_client_types_of_clients(
+    registry: ClientRegistry<AnyClientState, AnyConsensusState>,
+) {
+    for client_type in [mock_client_type(), tm_client_type()] {
+        assert_eq!(
+            registry
+                .client_type_of_client(&client_type.build_client_id(7))
+                .expect("registered client type"),
+            client_type
+        );
+    }
+
+    assert!(matches!(
+        registry.client_type_of_client(&"clientidtwo".parse::<ClientId>().expect("valid id")),
+        Err(ClientError::InvalidClientIdentifier(_))
+    ));
+    assert!(matches!(
+        registry.client_type_of_client(&"08-wasm-0".parse::<ClientId>().expect("valid id")),
+        Err(ClientError::UnknownClientType { .. })
+    ));
+}
+
+#[rstest]
+fn test_registry_checks_decoding_limits(
+    registry: ClientRegistry<AnyClientState, AnyConsensusState>,
+) {
+    let any = Any::from(AnyClientState::from(MockClientState::new(mock_header())));
+    let registry = registry.with_decoding_limits(DecodingLimits::new(
+        any.value.len() - 1,
+        DEFAULT_MAX_DECODING_DEPTH,
+    ));
+
+    assert!(matches!(
+        registry.decode_any_client_state(any),
+        Err(ClientError::DecodingLimitExceeded { .. })
+    ));
+}
@@ -3,19 +3,25 @@ use core::str::FromStr;
 use core::time::Duration;
 
 use basecoin_store::context::ProvableStore;
-use ibc::clients::tendermint::client_state::ClientState;
+use ibc::clients::tendermint::client_state::{
+    check_misbehaviour_age, select_trusted_height, ClientState,
+};
+use ibc::clients::tendermint::evidence::ClientUpdateProposal;
 use ibc::clients::tendermint::types::proto::v1::{ClientState as RawTmClientState, Fraction};
 use ibc::clients::tendermint::types::{
-    client_type as tm_client_type, ClientState as TmClientState, Header as TmHeader,
-    Misbehaviour as TmMisbehaviour,
+    client_type as tm_client_type, ClientState as TmClientState,
+    ConsensusState as TmConsensusState, Header as TmHeader, Misbehaviour as TmMisbehaviour,
 };
 use ibc::core::client::context::client_state::ClientStateValidation;
-use ibc::core::client::context::ClientValidationContext;
-use ibc::core::client::types::msgs::{ClientMsg, MsgUpdateClient};
+use ibc::core::client::context::{ClientValidationContext, EvidenceAgeLimits};
+use ibc::core::client::handler::update_client;
+use ibc::core::client::types::error::ClientError;
+use ibc::core::client::types::msgs::{ClientMsg, MsgUpdateClient, MsgUpdateOrMisbehaviour};
 use ibc::core::client::types::proto::v1::Height as RawHeight;
-use ibc::core::client::types::Height;
+use ibc::core::client::types::{Height, UpdateKind};
 use ibc::core::commitment_types::specs::ProofSpecs;
 use ibc::core::entrypoint::{execute, validate};
+use ibc::core::handler::types::error::ContextError;
 use ibc::core::handler::types::events::{IbcEvent, MessageEvent};
 use ibc::core::handler::types::msgs::MsgEnvelope;
 use ibc::core::host::types::identifiers::{ChainId, ClientId, ClientType};
@@ -33,12 +39,13 @@ use ibc_testkit::hosts::{
     HostClientState, MockHost, TendermintHost, TestBlock, TestHeader, TestHost,
 };
 use ibc_testkit::relayer::error::RelayerError;
+use ibc_testkit::testapp::ibc::clients::mock::adversary::AdversarialHeader;
 use ibc_testkit::testapp::ibc::clients::mock::client_state::{
     client_type as mock_client_type, MockClientState,
 };
 use ibc_testkit::testapp::ibc::clients::mock::header::MockHeader;
 use ibc_testkit::testapp::ibc::clients::mock::misbehaviour::Misbehaviour as MockMisbehaviour;
-use ibc_testkit::testapp::ibc::clients::AnyConsensusState;
+use ibc_testkit::testapp::ibc::clients::{AnyClientState, AnyConsensusState};
 use ibc_testkit::testapp::ibc::core::router::MockRouter;
 use ibc_testkit::testapp::ibc::core::types::{
     DefaultIbcStore, LightClientBuilder, LightClientState, MockIbcStore,
@@ -397,6 +404,75 @@ fn test_update_synthetic_tendermint_client_adjacent_ok() {
     assert_eq!(client_state.latest_height(), latest_header_height);
 }
 
+#[rstest]
+fn test_verify_tendermint_client_update_proposal() {
+    let client_id = tm_client_type().build_client_id(0);
+    let client_height = Height::new(1, 20).unwrap();
+    let update_height = Height::new(1, 21).unwrap();
+    let chain_id_b = ChainId::new("mockgaiaB-1").unwrap();
+
+    let ctx_b = TestContextConfig::builder()
+        .host(TendermintHost::builder().chain_id(chain_id_b).build())
+        .latest_height(update_height)
+        .build::<TendermintContext>();
+
+    let ctx = TestContextConfig::builder()
+        .host(
+            MockHost::builder()
+                .chain_id(ChainId::new("mockgaiaA-1").unwrap())
+                .build(),
+        )
+        .latest_height(Height::new(1, 1).unwrap())
+        .build::<MockContext>()
+        .with_light_client(
+            &client_id,
+            LightClientBuilder::init()
+                .context(&ctx_b)
+                .consensus_heights([client_height])
+                .build(),
+        );
+
+    let AnyClientState::Tendermint(tm_client_state) =
+        ctx.ibc_store.client_state(&client_id).unwrap()
+    else {
+        panic!("unexpected client state type");
+    };
+
+    let mut block = ctx_b.host_block(&update_height).unwrap().into_header();
+    block.set_trusted_height(client_height);
+    let header = TmHeader::from(block);
+
+    let proposal = ClientUpdateProposal::from_context(
+        &ctx.ibc_store,
+        &client_id,
+        tm_client_state.inner().clone(),
+        header.clone(),
+    )
+    .unwrap();
+
+    assert_eq!(proposal.trusted_height(), client_height);
+    assert_eq!(proposal.height(), update_height);
+
+    let now = ctx.ibc_store.host_timestamp().unwrap();
+
+    assert_eq!(
+        proposal.verify(now).unwrap(),
+        TmConsensusState::from(header)
+    );
+
+    // The trusted consensus state expires past the trusting period.
+    let expired = (now + tm_client_state.inner().trusting_period).unwrap();
+
+    assert!(proposal.verify(expired).is_err());
+
+    // The trusted consensus state must commit to the trusted validator set
+    // of the header.
+    let mut tampered = proposal;
+    tampered.trusted_consensus_state.next_validators_hash = tendermint::Hash::None;
+
+    assert!(tampered.verify(now).is_err());
+}
+
 #[rstest]
 fn test_update_synthetic_tendermint_client_validator_change_ok() {
     let client_id = tm_client_type().build_client_id(0);
@@ -942,6 +1018,81 @@ fn test_update_synthetic_tendermint_client_non_adjacent_ok() {
     assert_eq!(client_state.latest_height(), latest_header_height);
 }
 
+#[rstest]
+fn test_update_synthetic_tendermint_client_non_adjacent_selected_trusted_height_ok() {
+    let client_id = tm_client_type().build_client_id(0);
+    let update_height = Height::new(1, 25).unwrap();
+    let chain_id_b = ChainId::new("mockgaiaB-1").unwrap();
+
+    let ctx_b = TestContextConfig::builder()
+        .host(TendermintHost::builder().chain_id(chain_id_b).build())
+        .latest_height(update_height)
+        .build::<TendermintContext>();
+
+    let mut ctx = TestContextConfig::builder()
+        .host(
+            MockHost::builder()
+                .chain_id(ChainId::new("mockgaiaA-1").unwrap())
+                .build(),
+        )
+        .latest_height(Height::new(1, 1).unwrap())
+        .build::<MockContext>()
+        .with_light_client(
+            &client_id,
+            LightClientBuilder::init()
+                .context(&ctx_b)
+                .consensus_heights([
+                    Height::new(1, 5).unwrap(),
+                    Height::new(1, 10).unwrap(),
+                    Height::new(1, 20).unwrap(),
+                ])
+                .build(),
+        );
+
+    let AnyClientState::Tendermint(tm_client_state) =
+        ctx.ibc_store.client_state(&client_id).unwrap()
+    else {
+        panic!("unexpected client state type");
+    };
+
+    let select = |target: Height| {
+        select_trusted_height(tm_client_state.inner(), &ctx.ibc_store, &client_id, &target).unwrap()
+    };
+
+    assert_eq!(select(Height::new(1, 5).unwrap()), None);
+    assert_eq!(
+        select(Height::new(1, 15).unwrap()),
+        Some(Height::new(1, 10).unwrap())
+    );
+    assert_eq!(select(Height::new(2, 15).unwrap()), None);
+
+    let trusted_height = select(update_height).expect("trusted height exists");
+    assert_eq!(trusted_height, Height::new(1, 20).unwrap());
+
+    let mut router = MockRouter::new_with_transfer();
+
+    let mut block = ctx_b.host_block(&update_height).unwrap().into_header();
+    block.set_trusted_height(trusted_height);
+
+    let msg = MsgUpdateClient {
+        client_id: client_id.clone(),
+        client_message: block.into(),
+        signer: dummy_account_id(),
+    };
+
+    let msg_envelope = MsgEnvelope::from(ClientMsg::from(msg));
+
+    let res = validate(&ctx.ibc_store, &router, msg_envelope.clone());
+    assert!(res.is_ok(), "result: {res:?}");
+
+    let res = execute(&mut ctx.ibc_store, &mut router, msg_envelope);
+    assert!(res.is_ok(), "result: {res:?}");
+
+    let client_state = ctx.ibc_store.client_state(&client_id).unwrap();
+
+    assert_eq!(client_state.latest_height(), update_height);
+}
+
 #[rstest]
 fn test_update_synthetic_tendermint_client_duplicate_ok() {
     let client_id = tm_client_type().build_client_id(0);
@@ -1150,6 +1301,40 @@ fn test_update_client_events(fixture: Fixture) {
     assert_eq!(update_client_event.consensus_height(), &height);
     assert_eq!(update_client_event.consensus_heights(), &vec![height]);
     assert_eq!(update_client_event.header(), &header.to_vec());
+    assert_eq!(update_client_event.update_kind(), &UpdateKind::NewHeader);
+}
+
+#[rstest]
+fn test_update_client_duplicate_header(fixture: Fixture) {
+    let Fixture { mut ctx, .. } = fixture;
+
+    let client_id = ClientId::new("07-tendermint", 0).expect("no error");
+    let height = Height::new(0, 46).unwrap();
+    let header: Any = MockHeader::new(height)
+        .with_timestamp(Timestamp::now())
+        .into();
+    let msg = MsgUpdateClient {
+        client_id,
+        client_message: header,
+        signer: dummy_account_id(),
+    };
+
+    // The first update installs the header, while submitting it again, e.g.
+    // by another relayer, leaves the client unchanged.
+    for expected_update_kind in [UpdateKind::NewHeader, UpdateKind::NoOp] {
+        let update_kind = update_client::execute(
+            &mut ctx.ibc_store,
+            MsgUpdateOrMisbehaviour::UpdateClient(msg.clone()),
+        )
+        .expect("update succeeds");
+        assert_eq!(update_kind, expected_update_kind);
+
+        let Some(IbcEvent::UpdateClient(update_client_event)) = ctx.get_events().pop() else {
+            panic!("UpdateClient event is expected")
+        };
+        assert_eq!(update_client_event.update_kind(), &expected_update_kind);
+        assert_eq!(update_client_event.consensus_height(), &height);
+    }
 }
 
 fn ensure_misbehaviour<S: ProvableStore + Debug>(
@@ -1231,6 +1416,158 @@ fn test_client_update_misbehaviour_nonexisting_client(fixture: Fixture) {
     assert!(res.is_err());
 }
 
+/// Returns a `MsgEnvelope` with the `client_message` field set to the given
+/// `AdversarialHeader`.
+fn msg_update_client_adversarial(client_id: &ClientId, header: AdversarialHeader) -> MsgEnvelope {
+    let msg = MsgUpdateClient {
+        client_id: client_id.clone(),
+        client_message: header.into(),
+        signer: dummy_account_id(),
+    };
+
+    MsgEnvelope::from(ClientMsg::from(msg))
+}
+
+/// Tests that adversarial headers are rejected by the mock client, leaving
+/// the client state untouched.
+#[rstest]
+fn test_update_client_adversarial_header_fail(fixture: Fixture) {
+    let Fixture { ctx, router } = fixture;
+
+    let client_id = ClientId::new("07-tendermint", 0).expect("no error");
+    let latest_height = ctx.light_client_latest_height(&client_id);
+    let host_timestamp = ctx.ibc_store.host_timestamp().unwrap();
+    let future_timestamp = (host_timestamp + Duration::from_secs(3600)).unwrap();
+
+    let headers = [
+        AdversarialHeader::forged(latest_height.increment()),
+        AdversarialHeader::skipping_heights(latest_height, 10),
+        AdversarialHeader::future_timestamp(latest_height.increment(), future_timestamp),
+    ];
+
+    for header in headers {
+        let msg_envelope = msg_update_client_adversarial(&client_id, header);
+
+        let res = validate(&ctx.ibc_store, &router, msg_envelope);
+        assert!(
+            matches!(
+                res,
+                Err(ContextError::ClientError(
+                    ClientError::HeaderVerificationFailure { .. }
+                ))
+            ),
+            "{header} must be rejected, got: {res:?}"
+        );
+        assert_eq!(ctx.light_client_latest_height(&client_id), latest_height);
+    }
+}
+
+/// Tests that adversarial headers whose fault is not exhibited are accepted
+/// by the mock client, which updates the client as with regular headers.
+#[rstest]
+fn test_update_client_adversarial_header_ok(fixture: Fixture) {
+    let Fixture {
+        mut ctx,
+        mut router,
+    } = fixture;
+
+    let client_id = ClientId::new("07-tendermint", 0).expect("no error");
+    let latest_height = ctx.light_client_latest_height(&client_id);
+    let host_timestamp = ctx.ibc_store.host_timestamp().unwrap();
+
+    let header = AdversarialHeader::future_timestamp(latest_height.increment(), host_timestamp);
+    let msg_envelope = msg_update_client_adversarial(&client_id, header);
+
+    let res = validate(&ctx.ibc_store, &router, msg_envelope.clone());
+    assert!(res.is_ok(), "validation happy path");
+
+    let res = execute(&mut ctx.ibc_store, &mut router, msg_envelope);
+    assert!(res.is_ok(), "execution happy path");
+
+    assert_eq!(
+        ctx.ibc_store.client_state(&client_id).unwrap(),
+        MockClientState::new(header.header).into()
+    );
+}
+
+/// Tests that a conflicting adversarial header freezes the mock client, and
+/// that regular headers are rejected by the frozen client.
+#[rstest]
+fn test_update_client_conflicting_header_freezes(fixture: Fixture) {
+    let Fixture {
+        mut ctx,
+        mut router,
+    } = fixture;
+
+    let client_id = ClientId::new("07-tendermint", 0).expect("no error");
+    let latest_height = ctx.light_client_latest_height(&client_id);
+
+    let msg_envelope = msg_update_client_adversarial(
+        &client_id,
+        AdversarialHeader::conflicting(latest_height.increment()),
+    );
+
+    let res = validate(&ctx.ibc_store, &router, msg_envelope.clone());
+    assert!(res.is_ok());
+
+    let res = execute(&mut ctx.ibc_store, &mut router, msg_envelope);
+    assert!(res.is_ok());
+
+    ensure_misbehaviour(&ctx.ibc_store, &client_id, &mock_client_type());
+
+    let msg = MsgUpdateClient {
+        client_id: client_id.clone(),
+        client_message: MockHeader::new(latest_height.increment())
+            .with_current_timestamp()
+            .into(),
+        signer: dummy_account_id(),
+    };
+    let res = validate(
+        &ctx.ibc_store,
+        &router,
+        MsgEnvelope::from(ClientMsg::from(msg)),
+    );
+    assert!(matches!(
+        res,
+        Err(ContextError::ClientError(
+            ClientError::ClientNotActive { .. }
+        ))
+    ));
+}
+
+/// Tests that a client stored frozen rejects regular headers.
+#[rstest]
+fn test_update_frozen_client_fail(fixture: Fixture) {
+    let Fixture { ctx, router } = fixture;
+
+    let client_id = ClientId::new("07-tendermint", 0).expect("no error");
+    let latest_height = ctx.light_client_latest_height(&client_id);
+
+    let AnyClientState::Mock(client_state) = ctx.ibc_store.client_state(&client_id).unwrap() else {
+        panic!("mock client state is expected")
+    };
+    let ctx = ctx.with_client_state(&client_id, client_state.frozen().into());
+
+    let msg = MsgUpdateClient {
+        client_id,
+        client_message: MockHeader::new(latest_height.increment())
+            .with_current_timestamp()
+            .into(),
+        signer: dummy_account_id(),
+    };
+    let res = validate(
+        &ctx.ibc_store,
+        &router,
+        MsgEnvelope::from(ClientMsg::from(msg)),
+    );
+    assert!(matches!(
+        res,
+        Err(ContextError::ClientError(
+            ClientError::ClientNotActive { .. }
+        ))
+    ));
+}
+
 /// Tests misbehaviour handling for the synthetic Tendermint client.
 /// Misbehaviour evidence consists of equivocal headers.
 #[rstest]
@@ -1390,6 +1727,137 @@ fn test_misbehaviour_synthetic_tendermint_bft_time() {
     ensure_misbehaviour(&ctx_a.ibc_store, &client_id, &tm_client_type());
 }
 
+#[rstest]
+fn test_misbehaviour_evidence_age_limits() {
+    let client_id = tm_client_type().build_client_id(0);
+    let misbehaviour_height = Height::new(1, 21).unwrap();
+    let evidence_timestamp = Timestamp::from_nanoseconds(1_700_000_000_000_000_000).unwrap();
+
+    let host = TendermintHost::builder()
+        .chain_id(ChainId::new("mockgaiaB-1").unwrap())
+        .build();
+    let [header1, header2]: [TmHeader; 2] = [1, 2].map(|app_hash| {
+        let mut tm_block = host
+            .generate_block(
+                vec![app_hash; 32],
+                misbehaviour_height.revision_height(),
+                evidence_timestamp,
+                &Default::default(),
+            )
+            .into_header();
+        tm_block.set_trusted_height(Height::new(1, 20).unwrap());
+        tm_block.into()
+    });
+    let misbehaviour = TmMisbehaviour::new(client_id, header1, header2);
+
+    let limits = EvidenceAgeLimits {
+        max_age_num_blocks: 100,
+        max_age_duration: Duration::from_secs(3600),
+    };
+    let later = |secs: u64| {
+        Timestamp::from_nanoseconds(evidence_timestamp.nanoseconds() + secs * 1_000_000_000)
+            .unwrap()
+    };
+
+    // Evidence is only expired once it exceeds both limits.
+    for (latest_height, host_timestamp, expired) in [
+        (21, later(0), false),
+        (200, later(0), false),
+        (21, later(7200), false),
+        (121, later(3601), false),
+        (122, later(3601), true),
+    ] {
+        let res = check_misbehaviour_age(
+            &misbehaviour,
+            Height::new(1, latest_height).unwrap(),
+            host_timestamp,
+            &limits,
+        );
+        assert_eq!(res.is_err(), expired, "latest height {latest_height}");
+    }
+}
+
+/// The evidence age limits set by the host take precedence over the unbonding
+/// period of the client.
+#[rstest]
+fn test_misbehaviour_host_evidence_age_limits() {
+    let client_id = tm_client_type().build_client_id(0);
+    let client_height = Height::new(1, 30).unwrap();
+    let trusted_height = Height::new(1, 20).unwrap();
+    let misbehaviour_height = Height::new(1, 21).unwrap();
+    let chain_id_b = ChainId::new("mockgaiaB-1").unwrap();
+
+    let ctx_b = TestContextConfig::builder()
+        .host(
+            TendermintHost::builder()
+                .chain_id(chain_id_b.clone())
+                .build(),
+        )
+        .latest_height(client_height)
+        .build::<TendermintContext>();
+
+    let mut ctx_a = TestContextConfig::builder()
+        .host(
+            MockHost::builder()
+                .chain_id(ChainId::new("mockgaiaA-1").unwrap())
+                .build(),
+        )
+        .latest_height(Height::new(1, 1).unwrap())
+        .build::<MockContext>()
+        .with_light_client(
+            &client_id,
+            LightClientBuilder::init()
+                .context(&ctx_b)
+                .consensus_heights([trusted_height, client_height])
+                .build(),
+        );
+
+    let router_a = MockRouter::new_with_transfer();
+
+    // The evidence is 9 blocks behind the latest height of the client, and
+    // about as many block times behind the host.
+    let trusted_timestamp = ctx_b.host_block(&trusted_height).unwrap().timestamp();
+    let [header1, header2]: [TmHeader; 2] = [1, 2].map(|app_hash| {
+        let mut tm_block = TendermintHost::builder()
+            .chain_id(chain_id_b.clone())
+            .build()
+            .generate_block(
+                vec![app_hash; 32],
+                misbehaviour_height.revision_height(),
+                (trusted_timestamp + Duration::from_secs(1)).unwrap(),
+                &Default::default(),
+            )
+            .into_header();
+        tm_block.set_trusted_height(trusted_height);
+        tm_block.into()
+    });
+
+    let msg_envelope = MsgEnvelope::from(ClientMsg::from(MsgUpdateClient {
+        client_id: client_id.clone(),
+        client_message: TmMisbehaviour::new(client_id, header1, header2).into(),
+        signer: dummy_account_id(),
+    }));
+
+    let is_expired = |res: &Result<(), ContextError>| {
+        matches!(
+            res,
+            Err(ContextError::ClientError(ClientError::ClientSpecific { description }))
+                if description.contains("evidence is expired")
+        )
+    };
+
+    let res = validate(&ctx_a.ibc_store, &router_a, msg_envelope.clone());
+    assert!(!is_expired(&res), "result: {res:?}");
+
+    ctx_a.ibc_store.evidence_age_limits = Some(EvidenceAgeLimits {
+        max_age_num_blocks: 5,
+        max_age_duration: Duration::from_secs(10),
+    });
+
+    let res = validate(&ctx_a.ibc_store, &router_a, msg_envelope);
+    assert!(is_expired(&res), "result: {res:?}");
+}
+
 #[rstest]
 fn test_expired_client() {
     let chain_id_b = ChainId::new("mockgaiaB-1").unwrap();
@@ -1519,7 +1987,14 @@ fn test_client_update_max_clock_drift() {
     let msg_envelope = MsgEnvelope::from(ClientMsg::from(msg));
 
     let res = validate(&ctx_a.ibc_store, &router_a, msg_envelope);
-    assert!(res.is_err());
+    assert!(
+        matches!(
+            &res,
+            Err(ContextError::ClientError(ClientError::ClientSpecific { description }))
+                if description.contains("from the future")
+        ),
+        "result: {res:?}"
+    );
 }
 
 /// Builds a `ClientMsg::UpdateClient` for a client with id `client_id` running on the `dest`
@@ -7,18 +7,24 @@ use ibc::core::entrypoint::{execute, validate};
 use ibc::core::handler::types::error::ContextError;
 use ibc::core::handler::types::events::{IbcEvent, MessageEvent};
 use ibc::core::handler::types::msgs::MsgEnvelope;
-use ibc::core::host::types::path::ClientConsensusStatePath;
-use ibc_testkit::context::MockContext;
+use ibc::core::host::types::identifiers::{ChainId, ClientId};
+use ibc::core::host::types::path::{
+    ClientConsensusStatePath, UpgradeClientPath, UPGRADED_IBC_STATE,
+};
+use ibc_testkit::context::{MockContext, TendermintContext, UPGRADE_STORE_KEY};
 use ibc_testkit::fixtures::clients::tendermint::{
-    dummy_tendermint_header, dummy_tm_client_state_from_header,
+    dummy_tendermint_header, dummy_tm_client_state_from_header, ClientStateConfig,
 };
 use ibc_testkit::fixtures::core::client::dummy_msg_upgrade_client;
+use ibc_testkit::fixtures::core::context::TestContextConfig;
+use ibc_testkit::fixtures::core::signer::dummy_account_id;
 use ibc_testkit::fixtures::{Expect, Fixture};
-use ibc_testkit::hosts::MockHost;
+use ibc_testkit::hosts::{MockHost, TendermintHost, TestBlock, TestHeader};
+use ibc_testkit::relayer::utils::TypedRelayerOps;
 use ibc_testkit::testapp::ibc::clients::mock::client_state::client_type as mock_client_type;
 use ibc_testkit::testapp::ibc::clients::{AnyClientState, AnyConsensusState};
 use ibc_testkit::testapp::ibc::core::router::MockRouter;
-use ibc_testkit::testapp::ibc::core::types::LightClientState;
+use ibc_testkit::testapp::ibc::core::types::{LightClientBuilder, LightClientState};
 
 enum Ctx {
     Default,
@@ -170,3 +176,136 @@ fn upgrade_client_fail_unknown_upgraded_client_state() {
     });
     upgrade_client_validate(&fxt, Expect::Failure(Some(expected_err)));
 }
+
+/// Bootstraps a client of a Tendermint chain `B` on a chain `A`, and schedules
+/// an upgrade of `B` to a new revision, after which the client on `A` is
+/// updated to the upgrade height.
+///
+/// Returns the contexts, the identifier of the client on `A` and the upgrade
+/// height.
+fn synthetic_tendermint_upgrade() -> (MockContext, TendermintContext, ClientId, Height) {
+    let chain_id_b = ChainId::new("mockgaiaB-1").unwrap();
+
+    let mut ctx_b = TestContextConfig::builder()
+        .host(TendermintHost::builder().chain_id(chain_id_b).build())
+        .build::<TendermintContext>();
+
+    let client_id_on_a = client_type().build_client_id(0);
+
+    let light_client_of_b = LightClientBuilder::init()
+        .context(&ctx_b)
+        .params(
+            ClientStateConfig::builder()
+                .upgrade_path(vec![
+                    UPGRADE_STORE_KEY.to_owned(),
+                    UPGRADED_IBC_STATE.to_owned(),
+                ])
+                .build(),
+        )
+        .build::<LightClientState<TendermintHost>>();
+
+    let mut ctx_a = MockContext::default().with_light_client(&client_id_on_a, light_client_of_b);
+
+    let upgraded_chain_id_b = ChainId::new("mockgaiaB-2").unwrap();
+    let upgraded_client_state = ClientStateConfig::default()
+        .into_client_state(upgraded_chain_id_b, Height::new(2, 1).unwrap())
+        .unwrap();
+    let upgraded_consensus_state = ctx_b
+        .query_latest_block()
+        .unwrap()
+        .into_header()
+        .into_consensus_state();
+
+    let upgrade_height = ctx_b.schedule_upgrade(
+        upgraded_client_state.into(),
+        upgraded_consensus_state.into(),
+    );
+
+    ctx_b.advance_block_height();
+    assert_eq!(ctx_b.latest_height(), upgrade_height);
+
+    TypedRelayerOps::<MockHost, TendermintHost>::update_client_on_a_with_sync(
+        &mut ctx_a,
+        &mut ctx_b,
+        client_id_on_a.clone(),
+        dummy_account_id(),
+    );
+
+    (ctx_a, ctx_b, client_id_on_a, upgrade_height)
+}
+
+/// Tests the upgrade of a client of a synthetic Tendermint chain, with the
+/// upgraded states proven against the consensus state at the upgrade height.
+#[test]
+fn upgrade_client_synthetic_tendermint_ok() {
+    let (mut ctx_a, ctx_b, client_id_on_a, upgrade_height) = synthetic_tendermint_upgrade();
+
+    TypedRelayerOps::<MockHost, TendermintHost>::upgrade_client_on_a(
+        &mut ctx_a,
+        &ctx_b,
+        client_id_on_a.clone(),
+        upgrade_height,
+        dummy_account_id(),
+    );
+
+    let (upgraded_client_state, _) = ctx_b.upgraded_states(upgrade_height).unwrap();
+
+    let client_state = ctx_a.ibc_store.client_state(&client_id_on_a).unwrap();
+    assert_eq!(
+        client_state.latest_height(),
+        upgraded_client_state.latest_height()
+    );
+
+    let consensus_state = ctx_a
+        .ibc_store
+        .consensus_state(&ClientConsensusStatePath::new(
+            client_id_on_a,
+            upgraded_client_state.latest_height().revision_number(),
+            upgraded_client_state.latest_height().revision_height(),
+        ));
+    assert!(consensus_state.is_ok());
+}
+
+/// Tests that an upgraded client state which differs from the one scheduled by
+/// the counterparty chain fails the verification of the upgrade proofs.
+#[test]
+fn upgrade_client_synthetic_tendermint_fail_tampered_client_state() {
+    let (ctx_a, ctx_b, client_id_on_a, upgrade_height) = synthetic_tendermint_upgrade();
+
+    let (_, upgraded_consensus_state) = ctx_b.upgraded_states(upgrade_height).unwrap();
+    let tampered_client_state = ClientStateConfig::default()
+        .into_client_state(
+            ChainId::new("mockgaiaC-2").unwrap(),
+            Height::new(2, 1).unwrap(),
+        )
+        .unwrap();
+
+    let msg = MsgUpgradeClient {
+        client_id: client_id_on_a,
+        upgraded_client_state: tampered_client_state.into(),
+        upgraded_consensus_state: upgraded_consensus_state.into(),
+        proof_upgrade_client: ctx_b
+            .get_upgrade_proof(
+                upgrade_height,
+                &UpgradeClientPath::UpgradedClientState(upgrade_height.revision_height()),
+            )
+            .unwrap(),
+        proof_upgrade_consensus_state: ctx_b
+            .get_upgrade_proof(
+                upgrade_height,
+                &UpgradeClientPath::UpgradedClientConsensusState(upgrade_height.revision_height()),
+            )
+            .unwrap(),
+        signer: dummy_account_id(),
+    };
+
+    let res = validate(
+        &ctx_a.ibc_store,
+        &MockRouter::new_with_transfer(),
+        MsgEnvelope::from(ClientMsg::from(msg)),
+    );
+    assert!(matches!(
+        res,
+        Err(ContextError::ClientError(ClientError::Ics23Verification(_)))
+    ));
+}
@@ -9,7 +9,7 @@ use ibc::core::entrypoint::{execute, validate};
 use ibc::core::handler::types::error::ContextError;
 use ibc::core::handler::types::events::{IbcEvent, MessageEvent};
 use ibc::core::handler::types::msgs::MsgEnvelope;
-use ibc::core::host::types::identifiers::{ChainId, ClientId};
+use ibc::core::host::types::identifiers::{ChainId, ClientId, ConnectionId};
 use ibc::core::host::ValidationContext;
 use ibc::core::primitives::prelude::*;
 use ibc::core::primitives::ZERO_DURATION;
@@ -26,6 +26,7 @@ enum Ctx {
     New,
     NewWithConnection,
     NewWithConnectionEndOpen,
+    NewWithMismatchedCounterparty,
     DefaultWithConnection,
 }
 
@@ -59,6 +60,21 @@ fn conn_open_ack_fixture(ctx: Ctx) -> Fixture<MsgConnectionOpenAck> {
     let mut conn_end_open = default_conn_end.clone();
     conn_end_open.set_state(State::Open); // incorrect field
 
+    // A connection end whose counterparty connection id differs from the one
+    // of the Ack msg.
+    let conn_end_mismatched = ConnectionEnd::new(
+        State::Init,
+        client_id.clone(),
+        Counterparty::new(
+            client_id.clone(),
+            Some(ConnectionId::new(42)),
+            CommitmentPrefix::try_from(b"ibc".to_vec()).unwrap(),
+        ),
+        vec![msg.version.clone()],
+        ZERO_DURATION,
+    )
+    .unwrap();
+
     let ctx_default = MockContext::default();
     let ctx_new = TestContextConfig::builder()
         .host(
@@ -99,6 +115,15 @@ fn conn_open_ack_fixture(ctx: Ctx) -> Fixture<MsgConnectionOpenAck> {
                 .with_connection(conn_id, conn_end_open)
                 .ibc_store
         }
+        Ctx::NewWithMismatchedCounterparty => {
+            ctx_new
+                .with_light_client(
+                    &client_id,
+                    LightClientState::<MockHost>::with_latest_height(proof_height),
+                )
+                .with_connection(conn_id, conn_end_mismatched)
+                .ibc_store
+        }
     };
 
     Fixture { ctx, msg }
@@ -139,6 +164,13 @@ fn conn_open_ack_validate(fxt: &Fixture<MsgConnectionOpenAck>, expect: Expect) {
             expected: _,
             actual: _,
         }) => {}
+        ContextError::ConnectionError(ConnectionError::MismatchedCounterpartyConnectionId {
+            expected,
+            actual,
+        }) => {
+            assert_eq!(expected, ConnectionId::new(42));
+            assert_eq!(actual, fxt.msg.conn_id_on_b);
+        }
         _ => unreachable!(),
     }
 }
@@ -212,3 +244,14 @@ fn conn_open_ack_connection_mismatch() {
     });
     conn_open_ack_validate(&fxt, Expect::Failure(Some(expected_err)));
 }
+
+#[test]
+fn conn_open_ack_counterparty_mismatch() {
+    let fxt = conn_open_ack_fixture(Ctx::NewWithMismatchedCounterparty);
+    let expected_err =
+        ContextError::ConnectionError(ConnectionError::MismatchedCounterpartyConnectionId {
+            expected: ConnectionId::new(42),
+            actual: fxt.msg.conn_id_on_b.clone(),
+        });
+    conn_open_ack_validate(&fxt, Expect::Failure(Some(expected_err)));
+}
@@ -1,23 +1,30 @@
+use core::time::Duration;
+
+use ibc::core::channel::handler::{freeze_channel, unfreeze_channel};
 use ibc::core::channel::types::channel::{ChannelEnd, Counterparty, Order, State};
 use ibc::core::channel::types::commitment::{compute_packet_commitment, PacketCommitment};
+use ibc::core::channel::types::error::ChannelError;
 use ibc::core::channel::types::msgs::{MsgAcknowledgement, PacketMsg};
 use ibc::core::channel::types::Version;
 use ibc::core::client::types::Height;
 use ibc::core::commitment_types::commitment::CommitmentPrefix;
+use ibc::core::connection::types::error::ConnectionError;
 use ibc::core::connection::types::version::Version as ConnectionVersion;
 use ibc::core::connection::types::{
     ConnectionEnd, Counterparty as ConnectionCounterparty, State as ConnectionState,
 };
 use ibc::core::entrypoint::{execute, validate};
+use ibc::core::handler::types::error::ContextError;
 use ibc::core::handler::types::events::{IbcEvent, MessageEvent};
 use ibc::core::handler::types::msgs::MsgEnvelope;
 use ibc::core::host::types::identifiers::{ChannelId, ClientId, ConnectionId, PortId};
+use ibc::core::host::types::path::ChannelEndPath;
 use ibc::core::primitives::*;
 use ibc_testkit::context::MockContext;
 use ibc_testkit::fixtures::core::channel::dummy_raw_msg_acknowledgement;
 use ibc_testkit::hosts::MockHost;
 use ibc_testkit::testapp::ibc::core::router::MockRouter;
-use ibc_testkit::testapp::ibc::core::types::LightClientState;
+use ibc_testkit::testapp::ibc::core::types::{LightClientState, DEFAULT_BLOCK_TIME_SECS};
 use rstest::*;
 use test_log::test;
 
@@ -184,6 +191,125 @@ fn ack_success_happy_path(fixture: Fixture) {
     )
 }
 
+#[rstest]
+fn ack_fail_frozen_channel(fixture: Fixture) {
+    let Fixture {
+        ctx,
+        router,
+        msg,
+        packet_commitment,
+        conn_end_on_a,
+        chan_end_on_a_unordered,
+        ..
+    } = fixture;
+    let mut ctx = ctx
+        .with_channel(
+            PortId::transfer(),
+            ChannelId::zero(),
+            chan_end_on_a_unordered,
+        )
+        .with_connection(ConnectionId::zero(), conn_end_on_a)
+        .with_packet_commitment(
+            msg.packet.port_id_on_a.clone(),
+            msg.packet.chan_id_on_a.clone(),
+            msg.packet.seq_on_a,
+            packet_commitment,
+        );
+
+    let chan_end_path_on_a =
+        ChannelEndPath::new(&msg.packet.port_id_on_a, &msg.packet.chan_id_on_a);
+    let msg_envelope = MsgEnvelope::from(PacketMsg::from(msg));
+
+    freeze_channel(&mut ctx.ibc_store, &chan_end_path_on_a).unwrap();
+
+    let res = validate(&ctx.ibc_store, &router, msg_envelope.clone());
+
+    assert!(
+        matches!(
+            res,
+            Err(ContextError::ChannelError(
+                ChannelError::FrozenChannel { .. }
+            ))
+        ),
+        "Validation fails because the channel is frozen. res: {res:?}"
+    );
+
+    unfreeze_channel(&mut ctx.ibc_store, &chan_end_path_on_a).unwrap();
+
+    let res = validate(&ctx.ibc_store, &router, msg_envelope);
+
+    assert!(
+        res.is_ok(),
+        "Validation succeeds once the channel is unfrozen. err: {res:?}"
+    );
+}
+
+#[rstest]
+#[case::delay_period_not_passed(0, false)]
+#[case::delay_period_passed(2, true)]
+fn ack_validate_delay_period(
+    fixture: Fixture,
+    #[case] elapsed_blocks: u64,
+    #[case] succeeds: bool,
+) {
+    let Fixture {
+        ctx,
+        router,
+        msg,
+        packet_commitment,
+        conn_end_on_a,
+        chan_end_on_a_unordered,
+        client_height,
+        ..
+    } = fixture;
+
+    let conn_end_on_a = ConnectionEnd::new(
+        ConnectionState::Open,
+        conn_end_on_a.client_id().clone(),
+        conn_end_on_a.counterparty().clone(),
+        conn_end_on_a.versions().to_vec(),
+        Duration::from_secs(2 * DEFAULT_BLOCK_TIME_SECS),
+    )
+    .unwrap();
+
+    let mut ctx = ctx
+        .with_light_client(
+            &ClientId::new("07-tendermint", 0).expect("no error"),
+            LightClientState::<MockHost>::with_latest_height(client_height),
+        )
+        .with_channel(
+            PortId::transfer(),
+            ChannelId::zero(),
+            chan_end_on_a_unordered,
+        )
+        .with_connection(ConnectionId::zero(), conn_end_on_a)
+        .with_packet_commitment(
+            msg.packet.port_id_on_a.clone(),
+            msg.packet.chan_id_on_a.clone(),
+            msg.packet.seq_on_a,
+            packet_commitment,
+        );
+
+    for _ in 0..elapsed_blocks {
+        ctx.advance_block_height();
+    }
+
+    let msg_envelope = MsgEnvelope::from(PacketMsg::from(msg));
+
+    let res = validate(&ctx.ibc_store, &router, msg_envelope);
+
+    if succeeds {
+        assert!(res.is_ok(), "validation should succeed. err: {res:?}");
+    } else {
+        assert!(matches!(
+            res,
+            Err(ContextError::ConnectionError(
+                ConnectionError::NotEnoughTimeElapsed { .. }
+            ))
+        ));
+    }
+}
+
 #[rstest]
 fn ack_unordered_chan_execute(fixture: Fixture) {
     let Fixture {
@@ -1,4 +1,5 @@
 use ibc::core::channel::types::channel::{ChannelEnd, Counterparty, Order, State as ChannelState};
+use ibc::core::channel::types::commitment::AcknowledgementCommitment;
 use ibc::core::channel::types::msgs::{ChannelMsg, MsgChannelCloseConfirm};
 use ibc::core::channel::types::Version;
 use ibc::core::connection::types::version::Version as ConnectionVersion;
@@ -9,6 +10,7 @@ use ibc::core::entrypoint::{execute, validate};
 use ibc::core::handler::types::events::{IbcEvent, MessageEvent};
 use ibc::core::handler::types::msgs::MsgEnvelope;
 use ibc::core::host::types::identifiers::ConnectionId;
+use ibc::core::host::types::path::{AckPath, CommitmentPath};
 use ibc::core::host::ValidationContext;
 use ibc::core::primitives::*;
 use ibc_testkit::context::MockContext;
@@ -140,3 +142,94 @@ fn test_chan_close_confirm_execute() {
 
     assert!(matches!(ibc_events[1], IbcEvent::CloseConfirmChannel(_)));
 }
+
+#[test]
+fn test_chan_close_confirm_execute_deletes_acknowledgements() {
+    let client_id = mock_client_type().build_client_id(24);
+    let conn_id = ConnectionId::new(2);
+    let default_context = MockContext::default();
+    let client_consensus_state_height = default_context.ibc_store.host_height().unwrap();
+
+    let conn_end = ConnectionEnd::new(
+        ConnectionState::Open,
+        client_id.clone(),
+        ConnectionCounterparty::try_from(dummy_raw_counterparty_conn(Some(0))).unwrap(),
+        ConnectionVersion::compatibles(),
+        ZERO_DURATION,
+    )
+    .unwrap();
+
+    let msg_chan_close_confirm = MsgChannelCloseConfirm::try_from(
+        dummy_raw_msg_chan_close_confirm(client_consensus_state_height.revision_height()),
+    )
+    .unwrap();
+
+    let msg_envelope = MsgEnvelope::from(ChannelMsg::from(msg_chan_close_confirm.clone()));
+
+    let port_id = msg_chan_close_confirm.port_id_on_b.clone();
+    let chan_id = msg_chan_close_confirm.chan_id_on_b.clone();
+
+    let chan_end = ChannelEnd::new(
+        ChannelState::Open,
+        Order::Unordered,
+        Counterparty::new(port_id.clone(), Some(chan_id.clone())),
+        vec![conn_id.clone()],
+        Version::empty(),
+    )
+    .unwrap();
+
+    let ack_commitment = AcknowledgementCommitment::from(vec![0; 32]);
+
+    let mut context = default_context
+        .with_light_client(
+            &client_id,
+            LightClientState::<MockHost>::with_latest_height(client_consensus_state_height),
+        )
+        .with_connection(conn_id, conn_end)
+        .with_channel(port_id.clone(), chan_id.clone(), chan_end)
+        .with_packet_acknowledgement(
+            port_id.clone(),
+            chan_id.clone(),
+            1.into(),
+            ack_commitment.clone(),
+        )
+        .with_packet_acknowledgement(port_id.clone(), chan_id.clone(), 3.into(), ack_commitment)
+        .with_packet_commitment(
+            port_id.clone(),
+            chan_id.clone(),
+            2.into(),
+            vec![0; 32].into(),
+        );
+
+    let mut router = MockRouter::new_with_transfer();
+
+    let res = execute(&mut context.ibc_store, &mut router, msg_envelope);
+
+    assert!(res.is_ok(), "Execution success: happy path");
+
+    for seq in [1, 3] {
+        assert!(context
+            .ibc_store
+            .get_packet_acknowledgement(&AckPath::new(&port_id, &chan_id, seq.into()))
+            .is_err());
+    }
+
+    // The packets in flight can still be timed out.
+    assert!(context
+        .ibc_store
+        .get_packet_commitment(&CommitmentPath::new(&port_id, &chan_id, 2.into()))
+        .is_ok());
+
+    let ibc_events = context.get_events();
+
+    assert_eq!(ibc_events.len(), 3);
+
+    let IbcEvent::ChannelCleanup(cleanup) = &ibc_events[0] else {
+        panic!("unexpected event: {:?}", ibc_events[0]);
+    };
+    assert_eq!(cleanup.port_id(), &port_id);
+    assert_eq!(cleanup.chan_id(), &chan_id);
+    assert_eq!(cleanup.deleted_acks(), 2);
+
+    assert!(matches!(ibc_events[2], IbcEvent::CloseConfirmChannel(_)));
+}
@@ -1,5 +1,6 @@
 use ibc::apps::transfer::types::MODULE_ID_STR;
 use ibc::core::channel::types::channel::{ChannelEnd, Counterparty, Order, State};
+use ibc::core::channel::types::error::ChannelError;
 use ibc::core::channel::types::msgs::{ChannelMsg, MsgChannelOpenAck};
 use ibc::core::client::types::Height;
 use ibc::core::connection::types::version::Version as ConnectionVersion;
@@ -7,9 +8,10 @@ use ibc::core::connection::types::{
     ConnectionEnd, Counterparty as ConnectionCounterparty, State as ConnectionState,
 };
 use ibc::core::entrypoint::{execute, validate};
+use ibc::core::handler::types::error::ContextError;
 use ibc::core::handler::types::events::{IbcEvent, MessageEvent};
 use ibc::core::handler::types::msgs::MsgEnvelope;
-use ibc::core::host::types::identifiers::{ClientId, ConnectionId};
+use ibc::core::host::types::identifiers::{ChannelId, ClientId, ConnectionId, IdentifierMode};
 use ibc::core::primitives::*;
 use ibc::core::router::types::module::ModuleId;
 use ibc_testkit::context::MockContext;
@@ -256,3 +258,162 @@ fn chan_open_ack_fail_channel_wrong_state(fixture: Fixture) {
         "Validation fails because channel is in the wrong state"
     )
 }
+
+#[rstest]
+fn chan_open_ack_fail_counterparty_channel_id_mismatch(fixture: Fixture) {
+    let Fixture {
+        context,
+        router,
+        msg,
+        client_id_on_a,
+        conn_id_on_a,
+        conn_end_on_a,
+        proof_height,
+        ..
+    } = fixture;
+
+    let mismatched_chan_end = ChannelEnd::new(
+        State::Init,
+        Order::Unordered,
+        Counterparty::new(msg.port_id_on_a.clone(), Some(ChannelId::new(42))),
+        vec![conn_id_on_a.clone()],
+        msg.version_on_b.clone(),
+    )
+    .unwrap();
+    let context = context
+        .with_light_client(
+            &client_id_on_a,
+            LightClientState::<MockHost>::with_latest_height(Height::new(0, proof_height).unwrap()),
+        )
+        .with_connection(conn_id_on_a, conn_end_on_a)
+        .with_channel(
+            msg.port_id_on_a.clone(),
+            msg.chan_id_on_a.clone(),
+            mismatched_chan_end,
+        );
+
+    let msg_envelope = MsgEnvelope::from(ChannelMsg::from(msg));
+
+    let res = validate(&context.ibc_store, &router, msg_envelope);
+
+    assert!(
+        matches!(
+            res,
+            Err(ContextError::ChannelError(
+                ChannelError::InvalidChannelId { .. }
+            ))
+        ),
+        "Validation fails because the counterparty channel id does not match"
+    )
+}
+
+#[rstest]
+fn chan_open_ack_fail_connection_not_open(fixture: Fixture) {
+    let Fixture {
+        context,
+        router,
+        msg,
+        client_id_on_a,
+        conn_id_on_a,
+        chan_end_on_a,
+        proof_height,
+        ..
+    } = fixture;
+
+    let conn_end_on_a = ConnectionEnd::new(
+        ConnectionState::TryOpen,
+        client_id_on_a.clone(),
+        ConnectionCounterparty::try_from(dummy_raw_counterparty_conn(Some(0))).unwrap(),
+        ConnectionVersion::compatibles(),
+        ZERO_DURATION,
+    )
+    .unwrap();
+    let context = context
+        .with_light_client(
+            &client_id_on_a,
+            LightClientState::<MockHost>::with_latest_height(Height::new(0, proof_height).unwrap()),
+        )
+        .with_connection(conn_id_on_a.clone(), conn_end_on_a)
+        .with_channel(
+            msg.port_id_on_a.clone(),
+            msg.chan_id_on_a.clone(),
+            chan_end_on_a,
+        );
+
+    let msg_envelope = MsgEnvelope::from(ChannelMsg::from(msg));
+
+    let res = validate(&context.ibc_store, &router, msg_envelope);
+
+    assert!(
+        matches!(
+            res,
+            Err(ContextError::ChannelError(ChannelError::ConnectionHopNotOpen {
+                ref connection_id,
+                state: ConnectionState::TryOpen,
+            })) if connection_id == &conn_id_on_a
+        ),
+        "Validation fails because the connection hop is not open"
+    )
+}
+
+#[rstest]
+#[case::exact(IdentifierMode::Exact, false)]
+#[case::lenient(IdentifierMode::Lenient, true)]
+fn chan_open_ack_counterparty_alias(
+    fixture: Fixture,
+    #[case] mode: IdentifierMode,
+    #[case] want_pass: bool,
+) {
+    let Fixture {
+        context,
+        router,
+        mut msg,
+        client_id_on_a,
+        conn_id_on_a,
+        conn_end_on_a,
+        proof_height,
+        ..
+    } = fixture;
+
+    msg.chan_id_on_b = ChannelId::from_str_with_mode("channel-to-osmosis", IdentifierMode::Lenient)
+        .expect("valid identifier");
+
+    let chan_end_on_a = ChannelEnd::new(
+        State::Init,
+        Order::Unordered,
+        Counterparty::new(msg.port_id_on_a.clone(), None),
+        vec![conn_id_on_a.clone()],
+        msg.version_on_b.clone(),
+    )
+    .unwrap();
+    let mut context = context
+        .with_light_client(
+            &client_id_on_a,
+            LightClientState::<MockHost>::with_latest_height(Height::new(0, proof_height).unwrap()),
+        )
+        .with_connection(conn_id_on_a, conn_end_on_a)
+        .with_channel(
+            msg.port_id_on_a.clone(),
+            msg.chan_id_on_a.clone(),
+            chan_end_on_a,
+        );
+    context.ibc_store.counterparty_identifier_mode = mode;
+
+    let msg_envelope = MsgEnvelope::from(ChannelMsg::from(msg));
+
+    let res = validate(&context.ibc_store, &router, msg_envelope);
+
+    if want_pass {
+        assert!(res.is_ok(), "the host opted in to counterparty aliases");
+    } else {
+        assert!(
+            matches!(
+                res,
+                Err(ContextError::ChannelError(ChannelError::InvalidIdentifier(
+                    _
+                )))
+            ),
+            "the host only accepts canonical counterparty identifiers"
+        );
+    }
+}
@@ -1,16 +1,27 @@
+use ibc::apps::transfer::types::VERSION;
 use ibc::clients::tendermint::types::client_type as tm_client_type;
-use ibc::core::channel::types::msgs::{ChannelMsg, MsgChannelOpenInit};
+use ibc::core::channel::types::error::ChannelError;
+use ibc::core::channel::types::msgs::{ChannelMsg, MsgChannelOpenInit, CHAN_OPEN_INIT_TYPE_URL};
+use ibc::core::channel::types::{Version, MAX_VERSION_LENGTH};
 use ibc::core::client::types::Height;
 use ibc::core::connection::types::version::Version as ConnectionVersion;
 use ibc::core::connection::types::{ConnectionEnd, State as ConnectionState};
 use ibc::core::entrypoint::{execute, validate};
+use ibc::core::handler::types::error::ContextError;
 use ibc::core::handler::types::events::{IbcEvent, MessageEvent};
 use ibc::core::handler::types::msgs::MsgEnvelope;
-use ibc::core::host::types::identifiers::ConnectionId;
+use ibc::core::host::types::identifiers::{ChannelId, ConnectionId, PortId};
+use ibc::core::host::types::path::ChannelEndPath;
 use ibc::core::host::ValidationContext;
+use ibc::core::primitives::proto::Any;
+use ibc::core::primitives::{
+    AbciError, DecodingLimits, DEFAULT_MAX_DECODING_DEPTH, DEFAULT_MAX_DECODING_SIZE,
+};
+use ibc::core::router::types::error::RouterError;
 use ibc_testkit::context::MockContext;
 use ibc_testkit::fixtures::core::channel::dummy_raw_msg_chan_open_init;
 use ibc_testkit::fixtures::core::connection::dummy_msg_conn_open_init;
+use ibc_testkit::fixtures::core::signer::dummy_account_id;
 use ibc_testkit::hosts::MockHost;
 use ibc_testkit::testapp::ibc::core::router::MockRouter;
 use ibc_testkit::testapp::ibc::core::types::{DefaultIbcStore, LightClientState};
@@ -110,6 +121,42 @@ fn chan_open_init_execute_happy_path(fixture: Fixture) {
     assert!(matches!(ibc_events[1], IbcEvent::OpenInitChannel(_)));
 }
 
+#[rstest]
+fn chan_open_init_execute_stores_module_version(fixture: Fixture) {
+    let Fixture {
+        mut ctx,
+        mut router,
+        msg,
+        ..
+    } = fixture;
+
+    // The proposed version is empty, for which the transfer module picks its
+    // own version.
+    let res = execute(&mut ctx.ibc_store, &mut router, msg);
+
+    assert!(res.is_ok(), "Execution succeeds; good parameters");
+
+    let version = Version::new(VERSION.to_string());
+
+    let chan_end_on_a = ctx
+        .ibc_store
+        .channel_end(&ChannelEndPath::new(
+            &PortId::transfer(),
+            &ChannelId::zero(),
+        ))
+        .unwrap();
+
+    assert_eq!(chan_end_on_a.version(), &version);
+
+    let ibc_events = ctx.get_events();
+
+    let IbcEvent::OpenInitChannel(event) = &ibc_events[1] else {
+        panic!("unexpected event: {:?}", ibc_events[1]);
+    };
+
+    assert_eq!(event.version_on_a(), &version);
+}
+
 #[rstest]
 fn chan_open_init_fail_no_connection(fixture: Fixture) {
     let Fixture { router, msg, .. } = fixture;
@@ -121,3 +168,156 @@ fn chan_open_init_fail_no_connection(fixture: Fixture) {
         "Validation fails because no connection exists in the context"
     )
 }
+
+#[rstest]
+fn chan_open_init_fail_empty_signer(fixture: Fixture) {
+    let Fixture { ctx, router, .. } = fixture;
+
+    let mut msg = MsgChannelOpenInit::try_from(dummy_raw_msg_chan_open_init(None)).unwrap();
+    msg.signer = String::new().into();
+
+    let msg_envelope = MsgEnvelope::from(ChannelMsg::from(msg));
+
+    assert!(
+        msg_envelope.validate_basic().is_err(),
+        "Stateless validation fails because the signer is empty"
+    );
+
+    let res = validate(&ctx.ibc_store, &router, msg_envelope);
+
+    assert!(res.is_err(), "Validation fails because the signer is empty")
+}
+
+#[rstest]
+fn chan_open_init_fail_unauthorized_signer(fixture: Fixture) {
+    let Fixture { ctx, router, .. } = fixture;
+
+    let msg = MsgChannelOpenInit::try_from(dummy_raw_msg_chan_open_init(None)).unwrap();
+
+    // Only another signer may open channels on the port.
+    ctx.ibc_store
+        .channel_openers
+        .lock()
+        .insert(msg.port_id_on_a.clone(), vec![dummy_account_id()]);
+
+    let res = validate(
+        &ctx.ibc_store,
+        &router,
+        MsgEnvelope::from(ChannelMsg::from(msg.clone())),
+    );
+
+    assert!(
+        matches!(
+            res,
+            Err(ContextError::ChannelError(
+                ChannelError::UnauthorizedChannelOpen { .. }
+            ))
+        ),
+        "Validation fails because the signer may not open channels on the port. res: {res:?}"
+    );
+
+    // The signer of the message is then allowed to.
+    ctx.ibc_store
+        .channel_openers
+        .lock()
+        .insert(msg.port_id_on_a.clone(), vec![msg.signer.clone()]);
+
+    let res = validate(
+        &ctx.ibc_store,
+        &router,
+        MsgEnvelope::from(ChannelMsg::from(msg)),
+    );
+
+    assert!(
+        res.is_ok(),
+        "Validation succeeds; authorized signer. res: {res:?}"
+    );
+}
+
+/// Versions which are oversized, or look like JSON but are not valid JSON,
+/// are rejected before reaching the application.
+#[rstest]
+#[case::oversized("a".repeat(MAX_VERSION_LENGTH + 1), 27)]
+#[case::invalid_json(r#"{"fee_version":"ics29-1","#.to_string(), 13)]
+fn chan_open_init_fail_invalid_version(
+    fixture: Fixture,
+    #[case] version: String,
+    #[case] code: u32,
+) {
+    let Fixture { ctx, router, .. } = fixture;
+
+    let mut msg = MsgChannelOpenInit::try_from(dummy_raw_msg_chan_open_init(None)).unwrap();
+    msg.version_proposal = Version::new(version);
+
+    let err = msg.validate_basic().unwrap_err();
+    assert_eq!(err.code(), code, "{err}");
+
+    let res = validate(
+        &ctx.ibc_store,
+        &router,
+        MsgEnvelope::from(ChannelMsg::from(msg)),
+    );
+
+    assert!(
+        res.is_err(),
+        "Validation fails because the version is invalid"
+    )
+}
+
+/// The versions wrapped by middlewares are valid JSON, and pass the
+/// stateless validation.
+#[test]
+fn chan_open_init_accepts_middleware_version() {
+    let mut msg = MsgChannelOpenInit::try_from(dummy_raw_msg_chan_open_init(None)).unwrap();
+    msg.version_proposal = Version::new(format!(
+        r#"{{"fee_version":"ics29-1","app_version":"{VERSION}"}}"#
+    ));
+
+    assert!(msg.validate_basic().is_ok());
+}
+
+/// As in ibc-go, the characters of the versions are not restricted, the
+/// versions being opaque to core IBC.
+#[rstest]
+#[case::non_printable("ics20-1\n".to_string())]
+#[case::non_ascii("ics20-\u{2460}".to_string())]
+fn chan_open_init_accepts_unrestricted_version_characters(#[case] version: String) {
+    let mut msg = MsgChannelOpenInit::try_from(dummy_raw_msg_chan_open_init(None)).unwrap();
+    msg.version_proposal = Version::new(version);
+
+    assert!(msg.validate_basic().is_ok());
+}
+
+#[rstest]
+fn chan_open_init_msg_envelope_any_roundtrip(fixture: Fixture) {
+    let Fixture { msg, .. } = fixture;
+
+    let any = Any::from(msg.clone());
+
+    assert_eq!(any.type_url, CHAN_OPEN_INIT_TYPE_URL);
+    assert_eq!(MsgEnvelope::try_from(any).unwrap(), msg);
+}
+
+#[rstest]
+fn chan_open_init_msg_envelope_decoding_limits(fixture: Fixture) {
+    let Fixture { msg, .. } = fixture;
+
+    let any = Any::from(msg.clone());
+
+    assert_eq!(
+        MsgEnvelope::decode_with_limits(any.clone(), &DecodingLimits::default()).unwrap(),
+        msg
+    );
+
+    // The message exceeds both its size, and its nesting depth of channel end
+    // and counterparty.
+    for limits in [
+        DecodingLimits::new(any.value.len() - 1, DEFAULT_MAX_DECODING_DEPTH),
+        DecodingLimits::new(DEFAULT_MAX_DECODING_SIZE, 2),
+    ] {
+        assert!(matches!(
+            MsgEnvelope::decode_with_limits(any.clone(), &limits),
+            Err(RouterError::DecodingLimitExceeded { .. })
+        ));
+    }
+}
@@ -1,27 +1,54 @@
+use core::cell::RefCell;
+use core::ops::Add;
+use core::time::Duration;
+
+use ibc::core::channel::handler::{
+    freeze_channel, unfreeze_channel, write_acknowledgement, write_acknowledgement_validate,
+};
+use ibc::core::channel::types::acknowledgement::{Acknowledgement, AcknowledgementStatus};
 use ibc::core::channel::types::channel::{ChannelEnd, Counterparty, Order, State};
+use ibc::core::channel::types::commitment::compute_ack_commitment;
+use ibc::core::channel::types::error::{ChannelError, PacketError};
 use ibc::core::channel::types::msgs::{MsgRecvPacket, PacketMsg};
-use ibc::core::channel::types::packet::Packet;
+use ibc::core::channel::types::packet::{OutgoingPacket, Packet};
+use ibc::core::channel::types::timeout::TimeoutHeight;
 use ibc::core::channel::types::Version;
 use ibc::core::client::types::Height;
 use ibc::core::commitment_types::commitment::CommitmentPrefix;
+use ibc::core::connection::types::error::ConnectionError;
 use ibc::core::connection::types::version::Version as ConnectionVersion;
 use ibc::core::connection::types::{
     ConnectionEnd, Counterparty as ConnectionCounterparty, State as ConnectionState,
 };
 use ibc::core::entrypoint::{execute, validate};
+use ibc::core::handler::types::error::ContextError;
 use ibc::core::handler::types::events::{IbcEvent, MessageEvent};
 use ibc::core::handler::types::msgs::MsgEnvelope;
-use ibc::core::host::types::identifiers::{ChannelId, ClientId, ConnectionId, PortId};
+use ibc::core::host::types::identifiers::{ChannelId, ClientId, ConnectionId, PortId, Sequence};
+use ibc::core::host::types::path::{AckPath, ChannelEndPath, CommitmentPath, SeqSendPath};
+use ibc::core::host::ValidationContext;
 use ibc::core::primitives::*;
+use ibc::core::router::interceptor::PacketInterceptor;
+use ibc::core::router::module::{Module, OnRecvPacketResult};
+use ibc::core::router::router::Router;
+use ibc::core::router::types::error::AppError;
+use ibc::core::router::types::module::{ModuleExtras, ModuleId};
 use ibc_testkit::context::MockContext;
 use ibc_testkit::fixtures::core::channel::{dummy_msg_recv_packet, dummy_raw_msg_recv_packet};
 use ibc_testkit::fixtures::core::signer::dummy_account_id;
 use ibc_testkit::hosts::MockHost;
 use ibc_testkit::testapp::ibc::core::router::MockRouter;
-use ibc_testkit::testapp::ibc::core::types::LightClientState;
+use ibc_testkit::testapp::ibc::core::types::{LightClientState, DEFAULT_BLOCK_TIME_SECS};
 use rstest::*;
 use test_log::test;
 
+/// The part of the connection delay period which has not elapsed yet.
+#[derive(Debug)]
+pub enum DelayError {
+    Time,
+    Blocks,
+}
+
 pub struct Fixture {
     pub context: MockContext,
     pub router: MockRouter,
@@ -151,6 +178,154 @@ fn recv_packet_validate_happy_path(fixture: Fixture) {
     )
 }
 
+#[rstest]
+fn recv_packet_fail_frozen_channel(fixture: Fixture) {
+    let Fixture {
+        context,
+        router,
+        msg,
+        conn_end_on_b,
+        chan_end_on_b,
+        client_height,
+        host_height,
+        ..
+    } = fixture;
+
+    let packet = &msg.packet;
+    let mut context = context
+        .with_light_client(
+            &ClientId::new("07-tendermint", 0).expect("no error"),
+            LightClientState::<MockHost>::with_latest_height(client_height),
+        )
+        .with_connection(ConnectionId::zero(), conn_end_on_b)
+        .with_channel(
+            packet.port_id_on_b.clone(),
+            packet.chan_id_on_b.clone(),
+            chan_end_on_b,
+        )
+        .with_send_sequence(
+            packet.port_id_on_b.clone(),
+            packet.chan_id_on_b.clone(),
+            1.into(),
+        )
+        .advance_block_up_to_height(host_height)
+        .with_recv_sequence(
+            packet.port_id_on_b.clone(),
+            packet.chan_id_on_b.clone(),
+            packet.seq_on_a,
+        );
+
+    let chan_end_path_on_b = ChannelEndPath::new(&packet.port_id_on_b, &packet.chan_id_on_b);
+    let msg_envelope = MsgEnvelope::from(PacketMsg::from(msg.clone()));
+
+    freeze_channel(&mut context.ibc_store, &chan_end_path_on_b).unwrap();
+    // Freezing a frozen channel is a no-op.
+    freeze_channel(&mut context.ibc_store, &chan_end_path_on_b).unwrap();
+
+    let res = validate(&context.ibc_store, &router, msg_envelope.clone());
+
+    assert!(
+        matches!(
+            res,
+            Err(ContextError::ChannelError(
+                ChannelError::FrozenChannel { .. }
+            ))
+        ),
+        "Validation fails because the channel is frozen. res: {res:?}"
+    );
+
+    unfreeze_channel(&mut context.ibc_store, &chan_end_path_on_b).unwrap();
+
+    let res = validate(&context.ibc_store, &router, msg_envelope);
+
+    assert!(
+        res.is_ok(),
+        "Validation succeeds once the channel is unfrozen. err: {res:?}"
+    );
+
+    let ibc_events = context.get_events();
+
+    assert_eq!(ibc_events.len(), 2);
+    let IbcEvent::ChannelFrozen(frozen) = &ibc_events[0] else {
+        panic!("expected a ChannelFrozen event, got {:?}", ibc_events[0]);
+    };
+    assert_eq!(frozen.port_id(), &packet.port_id_on_b);
+    assert_eq!(frozen.chan_id(), &packet.chan_id_on_b);
+    assert!(matches!(&ibc_events[1], IbcEvent::ChannelUnfrozen(_)));
+}
+
+/// The connection delay period gates the packet proofs: the host must have
+/// advanced by both the delay period and the corresponding number of blocks
+/// since the client was updated to the proof height.
+#[rstest]
+#[case::not_enough_time_elapsed(&[DEFAULT_BLOCK_TIME_SECS], Some(DelayError::Time))]
+#[case::not_enough_blocks_elapsed(&[2 * DEFAULT_BLOCK_TIME_SECS], Some(DelayError::Blocks))]
+#[case::delay_period_passed(&[DEFAULT_BLOCK_TIME_SECS, DEFAULT_BLOCK_TIME_SECS], None)]
+fn recv_packet_validate_delay_period(
+    fixture: Fixture,
+    #[case] block_times_secs: &[u64],
+    #[case] expected_err: Option<DelayError>,
+) {
+    let Fixture {
+        context,
+        router,
+        msg,
+        conn_end_on_b,
+        chan_end_on_b,
+        client_height,
+        ..
+    } = fixture;
+
+    let conn_end_on_b = ConnectionEnd::new(
+        ConnectionState::Open,
+        conn_end_on_b.client_id().clone(),
+        conn_end_on_b.counterparty().clone(),
+        conn_end_on_b.versions().to_vec(),
+        Duration::from_secs(2 * DEFAULT_BLOCK_TIME_SECS),
+    )
+    .unwrap();
+
+    let packet = &msg.packet;
+    let mut context = context
+        .with_light_client(
+            &ClientId::new("07-tendermint", 0).expect("no error"),
+            LightClientState::<MockHost>::with_latest_height(client_height),
+        )
+        .with_connection(ConnectionId::zero(), conn_end_on_b)
+        .with_channel(
+            packet.port_id_on_b.clone(),
+            packet.chan_id_on_b.clone(),
+            chan_end_on_b,
+        );
+
+    for block_time_secs in block_times_secs {
+        context.advance_block_height_with_params(
+            Duration::from_secs(*block_time_secs),
+            &Default::default(),
+        );
+    }
+
+    let msg_envelope = MsgEnvelope::from(PacketMsg::from(msg));
+
+    let res = validate(&context.ibc_store, &router, msg_envelope);
+
+    match expected_err {
+        None => assert!(res.is_ok(), "validation should succeed. err: {res:?}"),
+        Some(DelayError::Time) => assert!(matches!(
+            res,
+            Err(ContextError::ConnectionError(
+                ConnectionError::NotEnoughTimeElapsed { .. }
+            ))
+        )),
+        Some(DelayError::Blocks) => assert!(matches!(
+            res,
+            Err(ContextError::ConnectionError(
+                ConnectionError::NotEnoughBlocksElapsed { .. }
+            ))
+        )),
+    }
+}
+
 #[rstest]
 fn recv_packet_timeout_expired(fixture: Fixture) {
     let Fixture {
@@ -241,3 +416,759 @@ fn recv_packet_execute_happy_path(fixture: Fixture) {
     ));
     assert!(matches!(&ibc_events[3], &IbcEvent::WriteAcknowledgement(_)));
 }
+
+#[rstest]
+fn recv_packet_write_acknowledgement_twice_fails(fixture: Fixture) {
+    let Fixture {
+        context,
+        mut router,
+        msg,
+        conn_end_on_b,
+        chan_end_on_b,
+        client_height,
+        ..
+    } = fixture;
+    let mut ctx = context
+        .with_light_client(
+            &ClientId::new("07-tendermint", 0).expect("no error"),
+            LightClientState::<MockHost>::with_latest_height(client_height),
+        )
+        .with_connection(ConnectionId::zero(), conn_end_on_b)
+        .with_channel(PortId::transfer(), ChannelId::zero(), chan_end_on_b);
+
+    let packet = msg.packet.clone();
+
+    assert!(
+        write_acknowledgement_validate(&ctx.ibc_store, &packet).is_ok(),
+        "the packet has not been acknowledged yet"
+    );
+
+    let msg_env = MsgEnvelope::from(PacketMsg::from(msg));
+
+    execute(&mut ctx.ibc_store, &mut router, msg_env).expect("success");
+
+    let ack = Acknowledgement::try_from(vec![1u8]).expect("no error");
+    let res = write_acknowledgement(&mut ctx.ibc_store, packet, ack);
+
+    assert!(
+        res.is_err(),
+        "the packet has already been acknowledged synchronously by the module"
+    );
+}
+
+/// A module forwarding the packets it receives on the same channel, the way
+/// middlewares such as packet forwarding do, by returning them from its
+/// receive callback.
+#[derive(Debug)]
+struct ForwardModule {
+    chan_id_on_a: ChannelId,
+    timeout_height_on_b: TimeoutHeight,
+}
+
+impl Module for ForwardModule {
+    fn on_chan_open_init_validate(
+        &self,
+        _order: Order,
+        _connection_hops: &[ConnectionId],
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+        _counterparty: &Counterparty,
+        version: &Version,
+    ) -> Result<Version, ChannelError> {
+        Ok(version.clone())
+    }
+
+    fn on_chan_open_init_execute(
+        &mut self,
+        _order: Order,
+        _connection_hops: &[ConnectionId],
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+        _counterparty: &Counterparty,
+        version: &Version,
+    ) -> Result<(ModuleExtras, Version), ChannelError> {
+        Ok((ModuleExtras::empty(), version.clone()))
+    }
+
+    fn on_chan_open_try_validate(
+        &self,
+        _order: Order,
+        _connection_hops: &[ConnectionId],
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+        _counterparty: &Counterparty,
+        counterparty_version: &Version,
+    ) -> Result<Version, ChannelError> {
+        Ok(counterparty_version.clone())
+    }
+
+    fn on_chan_open_try_execute(
+        &mut self,
+        _order: Order,
+        _connection_hops: &[ConnectionId],
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+        _counterparty: &Counterparty,
+        counterparty_version: &Version,
+    ) -> Result<(ModuleExtras, Version), ChannelError> {
+        Ok((ModuleExtras::empty(), counterparty_version.clone()))
+    }
+
+    fn on_recv_packet_execute(
+        &mut self,
+        packet: &Packet,
+        _relayer: &Signer,
+    ) -> Result<OnRecvPacketResult, AppError> {
+        let forwarded = OutgoingPacket {
+            port_id_on_a: packet.port_id_on_b.clone(),
+            chan_id_on_a: self.chan_id_on_a.clone(),
+            data: packet.data.clone(),
+            timeout_height_on_b: self.timeout_height_on_b,
+            timeout_timestamp_on_b: Timestamp::none(),
+        };
+
+        Ok(OnRecvPacketResult::sync(
+            ModuleExtras::empty(),
+            Acknowledgement::try_from(vec![1u8]).expect("no error"),
+        )
+        .with_packets(vec![forwarded]))
+    }
+
+    fn on_timeout_packet_validate(
+        &self,
+        _packet: &Packet,
+        _relayer: &Signer,
+    ) -> Result<(), PacketError> {
+        Ok(())
+    }
+
+    fn on_timeout_packet_execute(
+        &mut self,
+        _packet: &Packet,
+        _relayer: &Signer,
+    ) -> (ModuleExtras, Result<(), PacketError>) {
+        (ModuleExtras::empty(), Ok(()))
+    }
+
+    fn on_acknowledgement_packet_validate(
+        &self,
+        _packet: &Packet,
+        _acknowledgement: &Acknowledgement,
+        _relayer: &Signer,
+    ) -> Result<(), PacketError> {
+        Ok(())
+    }
+
+    fn on_acknowledgement_packet_execute(
+        &mut self,
+        _packet: &Packet,
+        _acknowledgement: &Acknowledgement,
+        _relayer: &Signer,
+    ) -> (ModuleExtras, Result<(), PacketError>) {
+        (ModuleExtras::empty(), Ok(()))
+    }
+}
+
+fn forward_router(chan_id_on_a: ChannelId, timeout_height_on_b: TimeoutHeight) -> MockRouter {
+    let mut router = MockRouter::default();
+
+    let module_id = ModuleId::new("forward".to_string());
+    router.scope_port_to_module(PortId::transfer(), module_id.clone());
+    router
+        .add_route(
+            module_id,
+            ForwardModule {
+                chan_id_on_a,
+                timeout_height_on_b,
+            },
+        )
+        .expect("no error");
+
+    router
+}
+
+#[rstest]
+fn recv_packet_execute_sends_forwarded_packets(fixture: Fixture) {
+    let Fixture {
+        context,
+        msg,
+        conn_end_on_b,
+        chan_end_on_b,
+        client_height,
+        ..
+    } = fixture;
+    let mut ctx = context
+        .with_light_client(
+            &ClientId::new("07-tendermint", 0).expect("no error"),
+            LightClientState::<MockHost>::with_latest_height(client_height),
+        )
+        .with_connection(ConnectionId::zero(), conn_end_on_b)
+        .with_channel(PortId::transfer(), ChannelId::zero(), chan_end_on_b)
+        .with_send_sequence(PortId::transfer(), ChannelId::zero(), 1.into());
+
+    let mut router = forward_router(ChannelId::zero(), TimeoutHeight::At(client_height.add(100)));
+
+    // Receiving each packet sends a new one from the receive callback, which
+    // is assigned the next sequence to send on the channel.
+    for seq in 1..=2 {
+        let mut msg = msg.clone();
+        msg.packet.seq_on_a = seq.into();
+
+        let msg_env = MsgEnvelope::from(PacketMsg::from(msg));
+        execute(&mut ctx.ibc_store, &mut router, msg_env).expect("success");
+
+        let sent_seq = Sequence::from(seq);
+        assert!(ctx
+            .ibc_store
+            .get_packet_commitment(&CommitmentPath::new(
+                &PortId::transfer(),
+                &ChannelId::zero(),
+                sent_seq
+            ))
+            .is_ok());
+        assert_eq!(
+            ctx.ibc_store
+                .get_next_sequence_send(&SeqSendPath::new(&PortId::transfer(), &ChannelId::zero()))
+                .expect("no error"),
+            sent_seq.increment()
+        );
+
+        // The packet is sent once the received packet is acknowledged.
+        let ibc_events = ctx.get_events();
+        let events = &ibc_events[ibc_events.len() - 6..];
+        assert!(matches!(&events[1], &IbcEvent::ReceivePacket(_)));
+        assert!(matches!(&events[3], &IbcEvent::WriteAcknowledgement(_)));
+        assert!(matches!(
+            &events[5],
+            IbcEvent::SendPacket(e) if e.seq_on_a() == &sent_seq
+        ));
+    }
+}
+
+#[rstest]
+fn recv_packet_execute_fails_on_forwarding_failure(fixture: Fixture) {
+    let Fixture {
+        context,
+        msg,
+        conn_end_on_b,
+        chan_end_on_b,
+        client_height,
+        ..
+    } = fixture;
+    let mut ctx = context
+        .with_light_client(
+            &ClientId::new("07-tendermint", 0).expect("no error"),
+            LightClientState::<MockHost>::with_latest_height(client_height),
+        )
+        .with_connection(ConnectionId::zero(), conn_end_on_b)
+        .with_channel(PortId::transfer(), ChannelId::zero(), chan_end_on_b);
+
+    // The packet is forwarded on a channel which does not exist.
+    let mut router = forward_router(ChannelId::new(1), TimeoutHeight::At(client_height.add(100)));
+
+    let msg_env = MsgEnvelope::from(PacketMsg::from(msg));
+    let res = execute(&mut ctx.ibc_store, &mut router, msg_env);
+
+    assert!(res.is_err());
+    assert!(ctx.get_events().is_empty());
+}
+
+/// The events and logs of a failed execution are discarded, including those
+/// emitted by the receive and write acknowledgement handlers before forwarding
+/// the packet fails, while those of prior executions are kept.
+#[rstest]
+fn recv_packet_execute_failure_discards_events_and_logs(fixture: Fixture) {
+    let Fixture {
+        context,
+        mut router,
+        msg,
+        conn_end_on_b,
+        chan_end_on_b,
+        client_height,
+        ..
+    } = fixture;
+    let mut ctx = context
+        .with_light_client(
+            &ClientId::new("07-tendermint", 0).expect("no error"),
+            LightClientState::<MockHost>::with_latest_height(client_height),
+        )
+        .with_connection(ConnectionId::zero(), conn_end_on_b)
+        .with_channel(PortId::transfer(), ChannelId::zero(), chan_end_on_b);
+
+    let msg_env = MsgEnvelope::from(PacketMsg::from(msg.clone()));
+    execute(&mut ctx.ibc_store, &mut router, msg_env).expect("success");
+
+    let events = ctx.get_events();
+    let logs = ctx.get_logs();
+    assert!(!events.is_empty());
+    assert!(!logs.is_empty());
+
+    // The packet is forwarded on a channel which does not exist.
+    let mut router = forward_router(ChannelId::new(1), TimeoutHeight::At(client_height.add(100)));
+
+    let mut msg = msg;
+    msg.packet.seq_on_a = 2.into();
+    let msg_env = MsgEnvelope::from(PacketMsg::from(msg));
+    let res = execute(&mut ctx.ibc_store, &mut router, msg_env);
+
+    assert!(res.is_err());
+    assert_eq!(ctx.get_events(), events);
+    assert_eq!(ctx.get_logs(), logs);
+}
+
+/// The events and logs of a successful execution are written once it is done,
+/// in the order the handlers emitted them.
+#[rstest]
+fn recv_packet_execute_writes_events_and_logs_in_order(fixture: Fixture) {
+    let Fixture {
+        context,
+        msg,
+        conn_end_on_b,
+        chan_end_on_b,
+        client_height,
+        ..
+    } = fixture;
+    let mut ctx = context
+        .with_light_client(
+            &ClientId::new("07-tendermint", 0).expect("no error"),
+            LightClientState::<MockHost>::with_latest_height(client_height),
+        )
+        .with_connection(ConnectionId::zero(), conn_end_on_b)
+        .with_channel(PortId::transfer(), ChannelId::zero(), chan_end_on_b)
+        .with_send_sequence(PortId::transfer(), ChannelId::zero(), 1.into());
+
+    let mut router = forward_router(ChannelId::zero(), TimeoutHeight::At(client_height.add(100)));
+
+    let events_before = ctx.get_events().len();
+    let logs_before = ctx.get_logs().len();
+
+    let msg_env = MsgEnvelope::from(PacketMsg::from(msg));
+    execute(&mut ctx.ibc_store, &mut router, msg_env).expect("success");
+
+    let ibc_events = ctx.get_events();
+    let events = &ibc_events[events_before..];
+    assert_eq!(events.len(), 6);
+    assert!(matches!(
+        &events[0],
+        IbcEvent::Message(MessageEvent::Channel)
+    ));
+    assert!(matches!(&events[1], IbcEvent::ReceivePacket(_)));
+    assert!(matches!(
+        &events[2],
+        IbcEvent::Message(MessageEvent::Channel)
+    ));
+    assert!(matches!(&events[3], IbcEvent::WriteAcknowledgement(_)));
+    assert!(matches!(
+        &events[4],
+        IbcEvent::Message(MessageEvent::Channel)
+    ));
+    assert!(matches!(&events[5], IbcEvent::SendPacket(_)));
+
+    assert_eq!(
+        ctx.get_logs()[logs_before..],
+        [
+            "success: packet receive".to_string(),
+            "success: packet write acknowledgement".to_string(),
+            "success: packet send".to_string(),
+        ]
+    );
+}
+
+/// A module whose receive callback always fails, without committing any state
+/// changes beforehand.
+#[derive(Debug)]
+struct FailingRecvModule;
+
+impl Module for FailingRecvModule {
+    fn on_chan_open_init_validate(
+        &self,
+        _order: Order,
+        _connection_hops: &[ConnectionId],
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+        _counterparty: &Counterparty,
+        version: &Version,
+    ) -> Result<Version, ChannelError> {
+        Ok(version.clone())
+    }
+
+    fn on_chan_open_init_execute(
+        &mut self,
+        _order: Order,
+        _connection_hops: &[ConnectionId],
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+        _counterparty: &Counterparty,
+        version: &Version,
+    ) -> Result<(ModuleExtras, Version), ChannelError> {
+        Ok((ModuleExtras::empty(), version.clone()))
+    }
+
+    fn on_chan_open_try_validate(
+        &self,
+        _order: Order,
+        _connection_hops: &[ConnectionId],
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+        _counterparty: &Counterparty,
+        counterparty_version: &Version,
+    ) -> Result<Version, ChannelError> {
+        Ok(counterparty_version.clone())
+    }
+
+    fn on_chan_open_try_execute(
+        &mut self,
+        _order: Order,
+        _connection_hops: &[ConnectionId],
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+        _counterparty: &Counterparty,
+        counterparty_version: &Version,
+    ) -> Result<(ModuleExtras, Version), ChannelError> {
+        Ok((ModuleExtras::empty(), counterparty_version.clone()))
+    }
+
+    fn on_recv_packet_execute(
+        &mut self,
+        _packet: &Packet,
+        _relayer: &Signer,
+    ) -> Result<OnRecvPacketResult, AppError> {
+        Err(AppError::new("transfer", 7, "insufficient funds in escrow"))
+    }
+
+    fn on_timeout_packet_validate(
+        &self,
+        _packet: &Packet,
+        _relayer: &Signer,
+    ) -> Result<(), PacketError> {
+        Ok(())
+    }
+
+    fn on_timeout_packet_execute(
+        &mut self,
+        _packet: &Packet,
+        _relayer: &Signer,
+    ) -> (ModuleExtras, Result<(), PacketError>) {
+        (ModuleExtras::empty(), Ok(()))
+    }
+
+    fn on_acknowledgement_packet_validate(
+        &self,
+        _packet: &Packet,
+        _acknowledgement: &Acknowledgement,
+        _relayer: &Signer,
+    ) -> Result<(), PacketError> {
+        Ok(())
+    }
+
+    fn on_acknowledgement_packet_execute(
+        &mut self,
+        _packet: &Packet,
+        _acknowledgement: &Acknowledgement,
+        _relayer: &Signer,
+    ) -> (ModuleExtras, Result<(), PacketError>) {
+        (ModuleExtras::empty(), Ok(()))
+    }
+}
+
+/// A failing receive callback does not revert the transaction: the packet is
+/// received, and acknowledged with a standardized error acknowledgement, from
+/// which the error description is redacted by default.
+#[rstest]
+fn recv_packet_execute_writes_error_ack_on_callback_failure(fixture: Fixture) {
+    let Fixture {
+        context,
+        msg,
+        conn_end_on_b,
+        chan_end_on_b,
+        client_height,
+        ..
+    } = fixture;
+    let mut ctx = context
+        .with_light_client(
+            &ClientId::new("07-tendermint", 0).expect("no error"),
+            LightClientState::<MockHost>::with_latest_height(client_height),
+        )
+        .with_connection(ConnectionId::zero(), conn_end_on_b)
+        .with_channel(PortId::transfer(), ChannelId::zero(), chan_end_on_b);
+
+    let mut router = MockRouter::default();
+    let module_id = ModuleId::new("failing".to_string());
+    router.scope_port_to_module(PortId::transfer(), module_id.clone());
+    router
+        .add_route(module_id, FailingRecvModule)
+        .expect("no error");
+
+    let packet = msg.packet.clone();
+    let msg_env = MsgEnvelope::from(PacketMsg::from(msg));
+
+    execute(&mut ctx.ibc_store, &mut router, msg_env).expect("success");
+
+    let expected_ack: Acknowledgement = AcknowledgementStatus::abci_error(7, None).into();
+    assert_eq!(
+        expected_ack.as_bytes(),
+        br#"{"error":"ABCI code: 7: error handling packet: see events for details"}"#
+    );
+
+    let ack_path = AckPath::new(&packet.port_id_on_b, &packet.chan_id_on_b, packet.seq_on_a);
+    assert_eq!(
+        ctx.ibc_store
+            .get_packet_acknowledgement(&ack_path)
+            .expect("the packet is acknowledged"),
+        compute_ack_commitment(&expected_ack)
+    );
+
+    let ibc_events = ctx.get_events();
+    assert!(matches!(
+        ibc_events.last(),
+        Some(IbcEvent::WriteAcknowledgement(e)) if e.acknowledgement() == &expected_ack
+    ));
+}
+
+/// A module acknowledging the packets it receives asynchronously.
+#[derive(Debug)]
+struct AsyncAckModule;
+
+impl Module for AsyncAckModule {
+    fn on_chan_open_init_validate(
+        &self,
+        _order: Order,
+        _connection_hops: &[ConnectionId],
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+        _counterparty: &Counterparty,
+        version: &Version,
+    ) -> Result<Version, ChannelError> {
+        Ok(version.clone())
+    }
+
+    fn on_chan_open_init_execute(
+        &mut self,
+        _order: Order,
+        _connection_hops: &[ConnectionId],
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+        _counterparty: &Counterparty,
+        version: &Version,
+    ) -> Result<(ModuleExtras, Version), ChannelError> {
+        Ok((ModuleExtras::empty(), version.clone()))
+    }
+
+    fn on_chan_open_try_validate(
+        &self,
+        _order: Order,
+        _connection_hops: &[ConnectionId],
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+        _counterparty: &Counterparty,
+        counterparty_version: &Version,
+    ) -> Result<Version, ChannelError> {
+        Ok(counterparty_version.clone())
+    }
+
+    fn on_chan_open_try_execute(
+        &mut self,
+        _order: Order,
+        _connection_hops: &[ConnectionId],
+        _port_id: &PortId,
+        _channel_id: &ChannelId,
+        _counterparty: &Counterparty,
+        counterparty_version: &Version,
+    ) -> Result<(ModuleExtras, Version), ChannelError> {
+        Ok((ModuleExtras::empty(), counterparty_version.clone()))
+    }
+
+    fn on_recv_packet_execute(
+        &mut self,
+        _packet: &Packet,
+        _relayer: &Signer,
+    ) -> Result<OnRecvPacketResult, AppError> {
+        Ok(OnRecvPacketResult::async_ack(ModuleExtras::empty()))
+    }
+
+    fn on_timeout_packet_validate(
+        &self,
+        _packet: &Packet,
+        _relayer: &Signer,
+    ) -> Result<(), PacketError> {
+        Ok(())
+    }
+
+    fn on_timeout_packet_execute(
+        &mut self,
+        _packet: &Packet,
+        _relayer: &Signer,
+    ) -> (ModuleExtras, Result<(), PacketError>) {
+        (ModuleExtras::empty(), Ok(()))
+    }
+
+    fn on_acknowledgement_packet_validate(
+        &self,
+        _packet: &Packet,
+        _acknowledgement: &Acknowledgement,
+        _relayer: &Signer,
+    ) -> Result<(), PacketError> {
+        Ok(())
+    }
+
+    fn on_acknowledgement_packet_execute(
+        &mut self,
+        _packet: &Packet,
+        _acknowledgement: &Acknowledgement,
+        _relayer: &Signer,
+    ) -> (ModuleExtras, Result<(), PacketError>) {
+        (ModuleExtras::empty(), Ok(()))
+    }
+}
+
+/// A packet acknowledged asynchronously is received without being acknowledged,
+/// until the application writes its acknowledgement.
+#[rstest]
+fn recv_packet_execute_defers_async_acknowledgement(fixture: Fixture) {
+    let Fixture {
+        context,
+        msg,
+        conn_end_on_b,
+        chan_end_on_b,
+        client_height,
+        ..
+    } = fixture;
+    let mut ctx = context
+        .with_light_client(
+            &ClientId::new("07-tendermint", 0).expect("no error"),
+            LightClientState::<MockHost>::with_latest_height(client_height),
+        )
+        .with_connection(ConnectionId::zero(), conn_end_on_b)
+        .with_channel(PortId::transfer(), ChannelId::zero(), chan_end_on_b);
+
+    let mut router = MockRouter::default();
+    let module_id = ModuleId::new("async".to_string());
+    router.scope_port_to_module(PortId::transfer(), module_id.clone());
+    router
+        .add_route(module_id, AsyncAckModule)
+        .expect("no error");
+
+    let packet = msg.packet.clone();
+    let msg_env = MsgEnvelope::from(PacketMsg::from(msg));
+
+    execute(&mut ctx.ibc_store, &mut router, msg_env).expect("success");
+
+    let ack_path = AckPath::new(&packet.port_id_on_b, &packet.chan_id_on_b, packet.seq_on_a);
+    assert!(
+        ctx.ibc_store.get_packet_acknowledgement(&ack_path).is_err(),
+        "no acknowledgement is written on receipt"
+    );
+
+    let ibc_events = ctx.get_events();
+    assert!(matches!(
+        ibc_events.last(),
+        Some(IbcEvent::ReceivePacket(_))
+    ));
+    assert!(!ibc_events
+        .iter()
+        .any(|event| matches!(event, IbcEvent::WriteAcknowledgement(_))));
+
+    let ack = Acknowledgement::try_from(vec![1u8]).expect("no error");
+    write_acknowledgement(&mut ctx.ibc_store, packet, ack.clone()).expect("success");
+
+    assert_eq!(
+        ctx.ibc_store
+            .get_packet_acknowledgement(&ack_path)
+            .expect("the packet is acknowledged"),
+        compute_ack_commitment(&ack)
+    );
+
+    let ibc_events = ctx.get_events();
+    assert!(matches!(
+        ibc_events.last(),
+        Some(IbcEvent::WriteAcknowledgement(e)) if e.acknowledgement() == &ack
+    ));
+}
+
+/// Records the receptions of packets it intercepts.
+#[derive(Default)]
+struct RecordingInterceptor {
+    calls: RefCell<Vec<(ModuleId, Sequence, Option<bool>)>>,
+}
+
+impl PacketInterceptor for RecordingInterceptor {
+    fn before_recv_packet(&self, module_id: &ModuleId, packet: &Packet) {
+        self.calls
+            .borrow_mut()
+            .push((module_id.clone(), packet.seq_on_a, None));
+    }
+
+    fn after_recv_packet(&self, module_id: &ModuleId, packet: &Packet, succeeded: bool) {
+        self.calls
+            .borrow_mut()
+            .push((module_id.clone(), packet.seq_on_a, Some(succeeded)));
+    }
+}
+
+struct InterceptingRouter {
+    router: MockRouter,
+    interceptor: RecordingInterceptor,
+}
+
+impl Router for InterceptingRouter {
+    fn get_route(&self, module_id: &ModuleId) -> Option<&dyn Module> {
+        self.router.get_route(module_id)
+    }
+
+    fn get_route_mut(&mut self, module_id: &ModuleId) -> Option<&mut dyn Module> {
+        self.router.get_route_mut(module_id)
+    }
+
+    fn lookup_module(&self, port_id: &PortId) -> Option<ModuleId> {
+        self.router.lookup_module(port_id)
+    }
+
+    fn packet_interceptor(&self) -> Option<&dyn PacketInterceptor> {
+        Some(&self.interceptor)
+    }
+}
+
+#[rstest]
+fn recv_packet_execute_is_intercepted(fixture: Fixture) {
+    let Fixture {
+        context,
+        router,
+        msg,
+        conn_end_on_b,
+        chan_end_on_b,
+        client_height,
+        ..
+    } = fixture;
+    let mut router = InterceptingRouter {
+        router,
+        interceptor: RecordingInterceptor::default(),
+    };
+    let module_id = router
+        .lookup_module(&msg.packet.port_id_on_b)
+        .expect("transfer module is routed");
+    let seq = msg.packet.seq_on_a;
+    let msg_env = MsgEnvelope::from(PacketMsg::from(msg));
+
+    // The channel does not exist yet.
+    let mut empty_ctx = MockContext::default();
+    assert!(execute(&mut empty_ctx.ibc_store, &mut router, msg_env.clone()).is_err());
+
+    let mut ctx = context
+        .with_light_client(
+            &ClientId::new("07-tendermint", 0).expect("no error"),
+            LightClientState::<MockHost>::with_latest_height(client_height),
+        )
+        .with_connection(ConnectionId::zero(), conn_end_on_b)
+        .with_channel(PortId::transfer(), ChannelId::zero(), chan_end_on_b);
+    assert!(execute(&mut ctx.ibc_store, &mut router, msg_env).is_ok());
+
+    assert_eq!(
+        router.interceptor.calls.into_inner(),
+        vec![
+            (module_id.clone(), seq, None),
+            (module_id.clone(), seq, Some(false)),
+            (module_id.clone(), seq, None),
+            (module_id, seq, Some(true)),
+        ]
+    );
+}
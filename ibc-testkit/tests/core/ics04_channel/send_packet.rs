@@ -1,8 +1,11 @@
 use core::ops::Add;
 use core::time::Duration;
 
-use ibc::core::channel::handler::send_packet;
+use ibc::core::channel::handler::{
+    freeze_channel, send_packet, send_packet_validate, unfreeze_channel,
+};
 use ibc::core::channel::types::channel::{ChannelEnd, Counterparty, Order, State};
+use ibc::core::channel::types::error::ChannelError;
 use ibc::core::channel::types::packet::Packet;
 use ibc::core::channel::types::timeout::TimeoutHeight;
 use ibc::core::channel::types::Version;
@@ -12,8 +15,10 @@ use ibc::core::connection::types::version::Version as ConnectionVersion;
 use ibc::core::connection::types::{
     ConnectionEnd, Counterparty as ConnectionCounterparty, State as ConnectionState,
 };
+use ibc::core::handler::types::error::ContextError;
 use ibc::core::handler::types::events::{IbcEvent, MessageEvent};
 use ibc::core::host::types::identifiers::{ChannelId, ClientId, ConnectionId, PortId};
+use ibc::core::host::types::path::ChannelEndPath;
 use ibc::core::primitives::*;
 use ibc_testkit::context::MockContext;
 use ibc_testkit::fixtures::core::channel::dummy_raw_packet;
@@ -206,3 +211,72 @@ fn send_packet_processing() {
         }
     }
 }
+
+#[test]
+fn send_packet_frozen_channel() {
+    let client_id = ClientId::new("07-tendermint", 0).expect("no error");
+
+    let chan_end_on_a = ChannelEnd::new(
+        State::Open,
+        Order::Unordered,
+        Counterparty::new(PortId::transfer(), Some(ChannelId::zero())),
+        vec![ConnectionId::zero()],
+        Version::new("ics20-1".to_string()),
+    )
+    .unwrap();
+
+    let conn_end_on_a = ConnectionEnd::new(
+        ConnectionState::Open,
+        client_id.clone(),
+        ConnectionCounterparty::new(
+            client_id.clone(),
+            Some(ConnectionId::zero()),
+            CommitmentPrefix::try_from(vec![0]).expect("no error"),
+        ),
+        ConnectionVersion::compatibles(),
+        ZERO_DURATION,
+    )
+    .unwrap();
+
+    let timestamp_future = Timestamp::now().add(Duration::from_secs(10)).unwrap();
+
+    let mut packet: Packet = dummy_raw_packet(10, timestamp_future.nanoseconds())
+        .try_into()
+        .unwrap();
+    packet.seq_on_a = 1.into();
+    packet.data = vec![0];
+
+    let mut ctx = MockContext::default()
+        .with_light_client(
+            &client_id,
+            LightClientState::<MockHost>::with_latest_height(Height::new(0, 5).unwrap()),
+        )
+        .with_connection(ConnectionId::zero(), conn_end_on_a)
+        .with_channel(PortId::transfer(), ChannelId::zero(), chan_end_on_a)
+        .with_send_sequence(PortId::transfer(), ChannelId::zero(), 1.into());
+
+    let chan_end_path_on_a = ChannelEndPath::new(&packet.port_id_on_a, &packet.chan_id_on_a);
+
+    freeze_channel(&mut ctx.ibc_store, &chan_end_path_on_a).unwrap();
+
+    let res = send_packet_validate(&ctx.ibc_store, &packet);
+
+    assert!(
+        matches!(
+            res,
+            Err(ContextError::ChannelError(
+                ChannelError::FrozenChannel { .. }
+            ))
+        ),
+        "Validation fails because the channel is frozen. res: {res:?}"
+    );
+
+    unfreeze_channel(&mut ctx.ibc_store, &chan_end_path_on_a).unwrap();
+
+    let res = send_packet_validate(&ctx.ibc_store, &packet);
+
+    assert!(
+        res.is_ok(),
+        "Validation succeeds once the channel is unfrozen. err: {res:?}"
+    );
+}
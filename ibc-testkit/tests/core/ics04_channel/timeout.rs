@@ -1,25 +1,39 @@
+use ibc::core::channel::handler::unreceived_packet_proof_spec;
 use ibc::core::channel::types::channel::{ChannelEnd, Counterparty, Order, State};
-use ibc::core::channel::types::commitment::{compute_packet_commitment, PacketCommitment};
+use ibc::core::channel::types::commitment::{
+    compute_packet_commitment, AcknowledgementCommitment, PacketCommitment,
+};
+use ibc::core::channel::types::error::PacketError;
 use ibc::core::channel::types::msgs::{MsgTimeout, PacketMsg};
+use ibc::core::channel::types::packet::Packet;
 use ibc::core::channel::types::Version;
+use ibc::core::client::context::consensus_state::ConsensusState;
 use ibc::core::client::context::ClientExecutionContext;
+use ibc::core::client::types::error::ClientError;
 use ibc::core::client::types::Height;
-use ibc::core::commitment_types::commitment::CommitmentPrefix;
+use ibc::core::commitment_types::commitment::{CommitmentPrefix, CommitmentProofBytes};
+use ibc::core::commitment_types::error::CommitmentError;
 use ibc::core::connection::types::version::Version as ConnectionVersion;
 use ibc::core::connection::types::{
     ConnectionEnd, Counterparty as ConnectionCounterparty, State as ConnectionState,
 };
+use ibc::core::connection::verify::verify_state;
 use ibc::core::entrypoint::{execute, validate};
+use ibc::core::handler::types::error::ContextError;
 use ibc::core::handler::types::events::{IbcEvent, MessageEvent};
 use ibc::core::handler::types::msgs::MsgEnvelope;
-use ibc::core::host::types::identifiers::{ChannelId, ClientId, ConnectionId, PortId};
-use ibc::core::host::types::path::ClientConsensusStatePath;
+use ibc::core::host::types::identifiers::{ChannelId, ClientId, ConnectionId, PortId, Sequence};
+use ibc::core::host::types::path::{
+    AckPath, ClientConsensusStatePath, Path, ReceiptPath, SeqRecvPath,
+};
+use ibc::core::host::ValidationContext;
 use ibc::core::primitives::*;
-use ibc_testkit::context::MockContext;
+use ibc_query::core::context::ProvableContext;
+use ibc_testkit::context::{MockContext, TendermintContext};
 use ibc_testkit::fixtures::core::channel::dummy_raw_msg_timeout;
-use ibc_testkit::hosts::MockHost;
+use ibc_testkit::hosts::{MockHost, TendermintHost};
 use ibc_testkit::testapp::ibc::core::router::MockRouter;
-use ibc_testkit::testapp::ibc::core::types::LightClientState;
+use ibc_testkit::testapp::ibc::core::types::{LightClientBuilder, LightClientState};
 use rstest::*;
 
 struct Fixture {
@@ -418,3 +432,179 @@ fn timeout_ordered_chan_execute(fixture: Fixture) {
     ));
     assert!(matches!(ibc_events[3], IbcEvent::ChannelClosed(_)));
 }
+
+#[rstest]
+fn timeout_ordered_chan_execute_deletes_acknowledgements(fixture: Fixture) {
+    let Fixture {
+        ctx,
+        mut router,
+        msg,
+        packet_commitment,
+        conn_end_on_a,
+        chan_end_on_a_ordered,
+        ..
+    } = fixture;
+    let mut ctx = ctx
+        .with_channel(PortId::transfer(), ChannelId::zero(), chan_end_on_a_ordered)
+        .with_connection(ConnectionId::zero(), conn_end_on_a)
+        .with_packet_commitment(
+            msg.packet.port_id_on_a.clone(),
+            msg.packet.chan_id_on_a.clone(),
+            msg.packet.seq_on_a,
+            packet_commitment,
+        )
+        .with_packet_acknowledgement(
+            PortId::transfer(),
+            ChannelId::zero(),
+            1.into(),
+            AcknowledgementCommitment::from(vec![0; 32]),
+        );
+
+    let msg_envelope = MsgEnvelope::from(PacketMsg::from(msg));
+
+    let res = execute(&mut ctx.ibc_store, &mut router, msg_envelope);
+
+    assert!(res.is_ok());
+
+    assert!(ctx
+        .ibc_store
+        .get_packet_acknowledgement(&AckPath::new(
+            &PortId::transfer(),
+            &ChannelId::zero(),
+            1.into()
+        ))
+        .is_err());
+
+    // The channel is closed, and its acknowledgements deleted, by the timeout.
+    let ibc_events = ctx.get_events();
+
+    assert_eq!(ibc_events.len(), 5);
+    assert!(matches!(ibc_events[1], IbcEvent::TimeoutPacket(_)));
+    assert!(matches!(
+        &ibc_events[2],
+        IbcEvent::ChannelCleanup(cleanup) if cleanup.deleted_acks() == 1
+    ));
+    assert!(matches!(ibc_events[4], IbcEvent::ChannelClosed(_)));
+}
+
+/// On ordered channels, packets are proven unreceived by the proof of the
+/// next sequence to be received on the counterparty, which must not be
+/// greater than their own sequences.
+#[rstest]
+fn timeout_ordered_channel_proves_next_seq_recv(fixture: Fixture) {
+    let Fixture {
+        msg, conn_end_on_a, ..
+    } = fixture;
+
+    let packet = |seq: u64| Packet {
+        seq_on_a: seq.into(),
+        ..msg.packet.clone()
+    };
+    let next_seq_recv_on_b = Sequence::from(3);
+
+    let mut ctx_b = TendermintContext::default().with_recv_sequence(
+        msg.packet.port_id_on_b.clone(),
+        msg.packet.chan_id_on_b.clone(),
+        next_seq_recv_on_b,
+    );
+
+    // Commits the next sequence to be received.
+    ctx_b.advance_block_height();
+
+    let proof_height = ctx_b.latest_height();
+    let light_client: LightClientState<TendermintHost> =
+        LightClientBuilder::init().context(&ctx_b).build();
+    let client_state = light_client.client_state;
+    let root = light_client
+        .consensus_states
+        .get(&proof_height)
+        .expect("consensus state exists")
+        .root()
+        .clone();
+    let prefix = ctx_b.ibc_store().commitment_prefix();
+
+    let proof: CommitmentProofBytes = ctx_b
+        .ibc_store()
+        .get_proof(
+            proof_height,
+            &Path::SeqRecv(SeqRecvPath::new(
+                &msg.packet.port_id_on_b,
+                &msg.packet.chan_id_on_b,
+            )),
+        )
+        .expect("proof exists")
+        .try_into()
+        .expect("value merkle proof");
+
+    let verify = |seq: u64, next_seq_recv_on_b: Sequence| -> Result<(), ContextError> {
+        let proof_spec = unreceived_packet_proof_spec(
+            &conn_end_on_a,
+            Order::Ordered,
+            &packet(seq),
+            next_seq_recv_on_b,
+            &proof,
+            proof_height,
+        )?;
+
+        verify_state(
+            &client_state,
+            &prefix,
+            proof_spec.proof,
+            &root,
+            proof_spec.path,
+            proof_spec.value,
+        )?;
+
+        Ok(())
+    };
+
+    // Both the next packet to be received and the ones after it are proven
+    // unreceived.
+    verify(3, next_seq_recv_on_b).expect("packet 3 is not received");
+    verify(5, next_seq_recv_on_b).expect("packet 5 is not received");
+
+    // The proof does not hold for any other next sequence to be received.
+    assert!(matches!(
+        verify(5, Sequence::from(4)),
+        Err(ContextError::ClientError(ClientError::Ics23Verification(
+            CommitmentError::VerificationFailure
+        )))
+    ));
+
+    // The packets before the next sequence to be received were received.
+    assert!(matches!(
+        verify(2, next_seq_recv_on_b),
+        Err(ContextError::PacketError(
+            PacketError::InvalidPacketSequence { .. }
+        ))
+    ));
+}
+
+/// On unordered channels, packets are proven unreceived by the proof of the
+/// absence of their receipts, whatever the next sequence to be received.
+#[rstest]
+fn timeout_unordered_channel_proves_receipt_absence(fixture: Fixture) {
+    let Fixture {
+        msg, conn_end_on_a, ..
+    } = fixture;
+
+    let proof_spec = unreceived_packet_proof_spec(
+        &conn_end_on_a,
+        Order::Unordered,
+        &msg.packet,
+        Sequence::from(u64::from(msg.packet.seq_on_a) + 1),
+        &msg.proof_unreceived_on_b,
+        msg.proof_height_on_b,
+    )
+    .expect("no error");
+
+    assert_eq!(
+        proof_spec.path,
+        Path::Receipt(ReceiptPath::new(
+            &msg.packet.port_id_on_b,
+            &msg.packet.chan_id_on_b,
+            msg.packet.seq_on_a,
+        ))
+    );
+    assert_eq!(proof_spec.value, None);
+}
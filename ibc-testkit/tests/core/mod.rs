@@ -1,5 +1,17 @@
+#[cfg(feature = "builder")]
+pub mod builder;
+pub mod error;
+#[cfg(feature = "serde")]
+pub mod events;
 pub mod ics02_client;
 pub mod ics03_connection;
 pub mod ics04_channel;
+pub mod query;
 #[cfg(feature = "serde")]
 pub mod router;
+pub mod shared_context;
+#[cfg(feature = "tracing")]
+pub mod span;
+pub mod store;
+pub mod validate_basic;
+pub mod verify;
@@ -0,0 +1,77 @@
+use ibc::core::commitment_types::commitment::CommitmentProofBytes;
+use ibc::core::host::types::identifiers::{ChannelId, PortId, Sequence};
+use ibc::core::host::types::path::{CommitmentPath, Path, PortPath, ReceiptPath, SeqSendPath};
+use ibc_query::core::path::{query, PathValue};
+use ibc_query::error::QueryError;
+use ibc_testkit::context::MockContext;
+use rstest::*;
+
+#[fixture]
+fn ctx() -> MockContext {
+    let mut ctx = MockContext::default()
+        .with_send_sequence(PortId::transfer(), ChannelId::zero(), Sequence::from(2))
+        .with_packet_commitment(
+            PortId::transfer(),
+            ChannelId::zero(),
+            Sequence::from(1),
+            vec![1, 2, 3].into(),
+        );
+    ctx.advance_block_height();
+    ctx
+}
+
+fn commitment_path(seq: u64) -> Path {
+    CommitmentPath::new(&PortId::transfer(), &ChannelId::zero(), Sequence::from(seq)).into()
+}
+
+#[rstest]
+fn test_query_path_with_proof(ctx: MockContext) {
+    let height = ctx.latest_height();
+
+    let response = query(&ctx.ibc_store, &commitment_path(1), None, true).unwrap();
+    assert_eq!(response.proof_height, height);
+
+    let value = response.value.expect("packet commitment exists");
+    assert!(matches!(value, PathValue::PacketCommitment(_)));
+    let value = value.into_bytes();
+    assert_eq!(value, vec![1, 2, 3]);
+
+    let proof: CommitmentProofBytes = response
+        .proof
+        .expect("proof is requested")
+        .try_into()
+        .expect("value merkle proof");
+    assert!(ctx
+        .verify_membership(&height, commitment_path(1), value, &proof)
+        .is_ok());
+}
+
+#[rstest]
+fn test_query_path_without_proof(ctx: MockContext) {
+    let seq_send_path = SeqSendPath::new(&PortId::transfer(), &ChannelId::zero()).into();
+    let response = query(&ctx.ibc_store, &seq_send_path, None, false).unwrap();
+    assert!(response.proof.is_none());
+    assert!(matches!(
+        response.value,
+        Some(PathValue::NextSequence(seq)) if seq == Sequence::from(2)
+    ));
+    assert_eq!(
+        response.value.unwrap().into_bytes(),
+        2u64.to_be_bytes().to_vec()
+    );
+
+    // The packets which were not received have no receipt.
+    let receipt_path =
+        ReceiptPath::new(&PortId::transfer(), &ChannelId::zero(), Sequence::from(1)).into();
+    let response = query(&ctx.ibc_store, &receipt_path, None, false).unwrap();
+    assert!(response.value.is_none());
+}
+
+#[rstest]
+fn test_query_unsupported_path(ctx: MockContext) {
+    let port_path = PortPath(PortId::transfer()).into();
+    assert!(matches!(
+        query(&ctx.ibc_store, &port_path, None, false),
+        Err(QueryError::UnsupportedPath(_))
+    ));
+}
@@ -1,4 +1,6 @@
+use core::cell::RefCell;
 use core::ops::Add;
+use core::time::Duration;
 
 use ibc::apps::transfer::handler::send_transfer;
 use ibc::apps::transfer::types::error::TokenTransferError;
@@ -17,11 +19,15 @@ use ibc::core::entrypoint::dispatch;
 use ibc::core::handler::types::error::ContextError;
 use ibc::core::handler::types::events::{IbcEvent, MessageEvent};
 use ibc::core::handler::types::msgs::MsgEnvelope;
-use ibc::core::host::types::identifiers::ConnectionId;
+use ibc::core::host::types::identifiers::{ConnectionId, PortId};
 use ibc::core::host::types::path::CommitmentPath;
 use ibc::core::host::ValidationContext;
 use ibc::core::primitives::prelude::*;
-use ibc::core::primitives::Timestamp;
+use ibc::core::primitives::{Msg, Signer, Timestamp};
+use ibc::core::router::metrics::{HandlerStage, MetricsSink};
+use ibc::core::router::module::Module;
+use ibc::core::router::router::Router;
+use ibc::core::router::types::module::ModuleId;
 use ibc_testkit::context::MockContext;
 use ibc_testkit::fixtures::applications::transfer::{
     extract_transfer_packet, MsgTransferConfig, PacketDataConfig,
@@ -93,11 +99,7 @@ fn routing_module_and_keepers() {
     let mut ctx = TestContextConfig::builder()
         // a future timestamp, so that submitted packets are considered from past
         // not more than 5 secs, as later dummy_raw_msg_timeout_on_close(*, 5) is used
-        .latest_timestamp(
-            Timestamp::now()
-                .add(core::time::Duration::from_secs(4))
-                .unwrap(),
-        )
+        .latest_timestamp(Timestamp::now().add(Duration::from_secs(4)).unwrap())
         .build::<MockContext>();
 
     let mut router = MockRouter::new_with_transfer();
@@ -179,10 +181,7 @@ fn routing_module_and_keepers() {
     msg_to_on_close.packet.timeout_height_on_b = msg_transfer_two.timeout_height_on_b;
     msg_to_on_close.packet.timeout_timestamp_on_b = msg_transfer_two.timeout_timestamp_on_b;
 
-    let packet_data = serde_json::to_vec(&msg_transfer_two.packet_data)
-        .expect("PacketData's infallible Serialize impl failed");
-
-    msg_to_on_close.packet.data = packet_data;
+    msg_to_on_close.packet.data = msg_transfer_two.packet_data.encode_json().into_bytes();
 
     let msg_recv_packet = MsgRecvPacket::try_from(dummy_raw_msg_recv_packet(35)).unwrap();
     let msg_ack_packet = MsgAcknowledgement::try_from(dummy_raw_msg_ack_with_packet(
@@ -446,3 +445,146 @@ fn routing_module_and_keepers() {
         }
     }
 }
+
+#[derive(Default)]
+struct RecordingSink {
+    processed: RefCell<Vec<(&'static str, HandlerStage, bool)>>,
+    validated: RefCell<Vec<&'static str>>,
+}
+
+impl MetricsSink for RecordingSink {
+    fn now(&self) -> Option<Duration> {
+        Some(Duration::ZERO)
+    }
+
+    fn message_processed(&self, msg_type: &'static str, stage: HandlerStage, succeeded: bool) {
+        self.processed
+            .borrow_mut()
+            .push((msg_type, stage, succeeded));
+    }
+
+    fn validation_latency(&self, msg_type: &'static str, _latency: Duration) {
+        self.validated.borrow_mut().push(msg_type);
+    }
+}
+
+struct RouterWithMetrics {
+    router: MockRouter,
+    sink: RecordingSink,
+}
+
+impl Router for RouterWithMetrics {
+    fn get_route(&self, module_id: &ModuleId) -> Option<&dyn Module> {
+        self.router.get_route(module_id)
+    }
+
+    fn get_route_mut(&mut self, module_id: &ModuleId) -> Option<&mut dyn Module> {
+        self.router.get_route_mut(module_id)
+    }
+
+    fn lookup_module(&self, port_id: &PortId) -> Option<ModuleId> {
+        self.router.lookup_module(port_id)
+    }
+
+    fn metrics_sink(&self) -> Option<&dyn MetricsSink> {
+        Some(&self.sink)
+    }
+}
+
+#[test]
+fn routing_reports_processed_messages() {
+    let mut ctx = MockContext::default();
+    let mut router = RouterWithMetrics {
+        router: MockRouter::new_with_transfer(),
+        sink: RecordingSink::default(),
+    };
+
+    let header = MockHeader::new(Height::new(0, 5).unwrap()).with_current_timestamp();
+    let create_client_msg = MsgCreateClient::new(
+        MockClientState::new(header).into(),
+        MockConsensusState::new(header).into(),
+        dummy_account_id(),
+    );
+    let update_client_msg = MsgUpdateClient {
+        client_id: "07-tendermint-42".parse().unwrap(),
+        client_message: MockHeader::new(Height::new(0, 6).unwrap()).into(),
+        signer: dummy_account_id(),
+    };
+
+    dispatch(
+        &mut ctx.ibc_store,
+        &mut router,
+        MsgEnvelope::Client(ClientMsg::CreateClient(create_client_msg)),
+    )
+    .expect("client creation succeeds");
+
+    dispatch(
+        &mut ctx.ibc_store,
+        &mut router,
+        MsgEnvelope::Client(ClientMsg::UpdateClient(update_client_msg)),
+    )
+    .expect_err("the client does not exist");
+
+    assert_eq!(
+        router.sink.processed.into_inner(),
+        vec![
+            ("create_client", HandlerStage::Validate, true),
+            ("create_client", HandlerStage::Execute, true),
+            ("update_client", HandlerStage::Validate, false),
+        ]
+    );
+    assert_eq!(
+        router.sink.validated.into_inner(),
+        vec!["create_client", "update_client"]
+    );
+}
+
+#[test]
+fn msg_envelope_signer() {
+    let signer = |name: &str| Signer::from(name.to_string());
+
+    let header = MockHeader::new(Height::new(0, 5).unwrap()).with_current_timestamp();
+    let create_client_msg = MsgCreateClient::new(
+        MockClientState::new(header).into(),
+        MockConsensusState::new(header).into(),
+        signer("client"),
+    );
+
+    let mut msg_conn_init = dummy_msg_conn_open_init();
+    msg_conn_init.signer = signer("connection");
+
+    let mut msg_chan_init =
+        MsgChannelOpenInit::try_from(dummy_raw_msg_chan_open_init(None)).unwrap();
+    msg_chan_init.signer = signer("channel");
+
+    let mut msg_recv_packet = MsgRecvPacket::try_from(dummy_raw_msg_recv_packet(35)).unwrap();
+    msg_recv_packet.signer = signer("packet");
+
+    let msgs: Vec<(MsgEnvelope, &str)> = vec![
+        (ClientMsg::from(create_client_msg).into(), "client"),
+        (ConnectionMsg::from(msg_conn_init).into(), "connection"),
+        (ChannelMsg::from(msg_chan_init).into(), "channel"),
+        (PacketMsg::from(msg_recv_packet).into(), "packet"),
+    ];
+
+    for (msg, expected) in msgs {
+        assert_eq!(msg.signer(), &signer(expected));
+    }
+
+    let msg_transfer = MsgTransferConfig::builder()
+        .packet_data(
+            PacketDataConfig::builder()
+                .token(
+                    BaseCoin {
+                        denom: "uatom".parse().expect("parse denom"),
+                        amount: U256::from(10).into(),
+                    }
+                    .into(),
+                )
+                .sender(signer("sender"))
+                .build(),
+        )
+        .build();
+
+    assert_eq!(msg_transfer.signer(), &signer("sender"));
+}
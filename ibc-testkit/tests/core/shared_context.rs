@@ -0,0 +1,51 @@
+use std::thread;
+
+use ibc::core::client::types::Height;
+use ibc::core::connection::types::msgs::ConnectionMsg;
+use ibc::core::entrypoint::validate;
+use ibc::core::handler::types::msgs::MsgEnvelope;
+use ibc::core::router::router::Router;
+use ibc_testkit::context::MockContext;
+use ibc_testkit::fixtures::core::connection::dummy_msg_conn_open_init;
+use ibc_testkit::hosts::MockHost;
+use ibc_testkit::testapp::ibc::core::router::MockRouter;
+use ibc_testkit::testapp::ibc::core::types::{DefaultIbcStore, LightClientState};
+use test_log::test;
+
+fn assert_send_sync<T: Send + Sync + ?Sized>() {}
+
+#[test]
+fn test_context_and_router_are_send_sync() {
+    assert_send_sync::<DefaultIbcStore>();
+    assert_send_sync::<MockRouter>();
+}
+
+#[test]
+fn test_validate_across_threads() {
+    let msg = dummy_msg_conn_open_init();
+    let ctx = MockContext::default()
+        .with_light_client(
+            &msg.client_id_on_a,
+            LightClientState::<MockHost>::with_latest_height(Height::new(0, 10).unwrap()),
+        )
+        .ibc_store;
+    let router = MockRouter::new_with_transfer();
+
+    // The context and router are only shared by reference with the workers.
+    let ctx = &ctx;
+    let router: &(dyn Router + Sync) = &router;
+
+    thread::scope(|scope| {
+        let workers: Vec<_> = (0..4)
+            .map(|_| {
+                let msg = MsgEnvelope::from(ConnectionMsg::from(msg.clone()));
+                scope.spawn(move || validate(ctx, router, msg))
+            })
+            .collect();
+
+        for worker in workers {
+            let res = worker.join().expect("worker panicked");
+            assert!(res.is_ok(), "validation failed: {res:?}");
+        }
+    });
+}
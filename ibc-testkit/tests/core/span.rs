@@ -0,0 +1,131 @@
+use core::fmt::Debug;
+use std::sync::{Arc, Mutex};
+
+use ibc::core::channel::types::msgs::{MsgRecvPacket, PacketMsg};
+use ibc::core::entrypoint::validate;
+use ibc::core::handler::types::msgs::MsgEnvelope;
+use ibc::core::host::types::identifiers::PortId;
+use ibc::core::primitives::prelude::*;
+use ibc_testkit::context::MockContext;
+use ibc_testkit::fixtures::core::channel::dummy_raw_msg_recv_packet;
+use ibc_testkit::testapp::ibc::core::router::MockRouter;
+use tracing::field::{Field, Visit};
+use tracing::span::{Attributes, Id, Record};
+use tracing::Subscriber;
+use tracing_subscriber::layer::{Context, Layer, SubscriberExt};
+use tracing_subscriber::Registry;
+
+/// A span along with the fields recorded on it.
+#[derive(Clone, Debug)]
+struct RecordedSpan {
+    id: Id,
+    name: &'static str,
+    fields: BTreeMap<String, String>,
+}
+
+impl RecordedSpan {
+    fn field(&self, name: &str) -> Option<&str> {
+        self.fields.get(name).map(String::as_str)
+    }
+}
+
+struct FieldVisitor<'a>(&'a mut BTreeMap<String, String>);
+
+impl Visit for FieldVisitor<'_> {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        self.0.insert(field.name().to_string(), value.to_string());
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn Debug) {
+        self.0
+            .insert(field.name().to_string(), format!("{value:?}"));
+    }
+}
+
+/// Records the spans created while it is the default subscriber.
+#[derive(Clone, Default)]
+struct SpanRecorder {
+    spans: Arc<Mutex<Vec<RecordedSpan>>>,
+}
+
+impl SpanRecorder {
+    fn spans(&self) -> Vec<RecordedSpan> {
+        self.spans.lock().expect("not poisoned").clone()
+    }
+}
+
+impl<S> Layer<S> for SpanRecorder
+where
+    S: Subscriber,
+{
+    fn on_new_span(&self, attrs: &Attributes<'_>, id: &Id, _ctx: Context<'_, S>) {
+        let mut fields = BTreeMap::new();
+        attrs.record(&mut FieldVisitor(&mut fields));
+
+        self.spans.lock().expect("not poisoned").push(RecordedSpan {
+            id: id.clone(),
+            name: attrs.metadata().name(),
+            fields,
+        });
+    }
+
+    fn on_record(&self, id: &Id, values: &Record<'_>, _ctx: Context<'_, S>) {
+        let mut spans = self.spans.lock().expect("not poisoned");
+
+        // Span ids may be reused once closed, the latest span being the one
+        // recorded on.
+        if let Some(span) = spans.iter_mut().rev().find(|span| &span.id == id) {
+            values.record(&mut FieldVisitor(&mut span.fields));
+        }
+    }
+}
+
+/// A message routed to a port unknown to the router fails, but is still
+/// recorded along with the identifiers it applies to.
+#[test]
+fn test_spans_record_messages_to_unknown_ports() {
+    let ctx = MockContext::default();
+    let router = MockRouter::new_with_transfer();
+
+    let mut msg = MsgRecvPacket::try_from(dummy_raw_msg_recv_packet(10)).unwrap();
+    msg.packet.port_id_on_b = PortId::new("unknown".to_string()).unwrap();
+    let packet = msg.packet.clone();
+
+    let recorder = SpanRecorder::default();
+    let subscriber = Registry::default().with(recorder.clone());
+
+    let res = tracing::subscriber::with_default(subscriber, || {
+        validate(
+            &ctx.ibc_store,
+            &router,
+            MsgEnvelope::from(PacketMsg::from(msg)),
+        )
+    });
+
+    assert!(res.is_err(), "the port is unknown");
+
+    let spans = recorder.spans();
+
+    let validate_span = spans
+        .iter()
+        .find(|span| span.name == "ibc_validate")
+        .expect("the validation is recorded");
+    assert_eq!(validate_span.field("msg_type"), Some("recv_packet"));
+    assert_eq!(validate_span.field("port_id"), Some("unknown"));
+    assert_eq!(
+        validate_span.field("channel_id"),
+        Some(packet.chan_id_on_b.as_str())
+    );
+    assert_eq!(
+        validate_span.field("sequence"),
+        Some(packet.seq_on_a.to_string().as_str())
+    );
+    assert_eq!(validate_span.field("client_id"), None);
+
+    let handler_span = spans
+        .iter()
+        .find(|span| span.name == "ibc_handler")
+        .expect("the call to the handler is recorded");
+    assert_eq!(handler_span.field("handler"), Some("recv_packet"));
+    assert_eq!(handler_span.field("stage"), Some("validate"));
+}
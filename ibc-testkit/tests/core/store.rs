@@ -0,0 +1,658 @@
+use core::time::Duration;
+use std::collections::BTreeSet;
+
+use ibc::core::channel::handler::{freeze_channel, unfreeze_channel};
+use ibc::core::channel::types::channel::{ChannelEnd, Counterparty, Order, State};
+use ibc::core::channel::types::Version;
+use ibc::core::client::context::{
+    ClientExecutionContext, ClientValidationContext, ExtClientValidationContext,
+};
+use ibc::core::client::types::error::ClientError;
+use ibc::core::client::types::msgs::{ClientMsg, MsgCreateClient};
+use ibc::core::client::types::Height;
+use ibc::core::commitment_types::commitment::CommitmentPrefix;
+use ibc::core::connection::types::msgs::ConnectionMsg;
+use ibc::core::entrypoint::dispatch;
+use ibc::core::handler::simulate::simulate;
+use ibc::core::handler::types::error::ContextError;
+use ibc::core::handler::types::events::IbcEvent;
+use ibc::core::handler::types::msgs::MsgEnvelope;
+use ibc::core::host::store::{
+    ClientStore, ClientWriteBatch, IbcStore, StoreContext, StoreHost, WriteBatch,
+};
+use ibc::core::host::types::error::HostError;
+use ibc::core::host::types::identifiers::{ChannelId, ClientId, ConnectionId, PortId};
+use ibc::core::host::types::path::{
+    ChannelEndPath, ClientConsensusStatePath, NextClientSequencePath, Path,
+};
+use ibc::core::host::{ExecutionContext, HostParams, ValidationContext};
+use ibc::core::primitives::prelude::*;
+use ibc::core::primitives::{Signer, Timestamp};
+use ibc_testkit::fixtures::core::connection::dummy_msg_conn_open_init;
+use ibc_testkit::fixtures::core::signer::dummy_account_id;
+use ibc_testkit::testapp::ibc::clients::mock::client_state::{
+    client_type as mock_client_type, MockClientState,
+};
+use ibc_testkit::testapp::ibc::clients::mock::consensus_state::MockConsensusState;
+use ibc_testkit::testapp::ibc::clients::mock::header::MockHeader;
+use ibc_testkit::testapp::ibc::core::router::MockRouter;
+
+#[derive(Default)]
+struct MemStore(BTreeMap<Path, Vec<u8>>);
+
+impl IbcStore for MemStore {
+    fn get(&self, path: &Path) -> Option<Vec<u8>> {
+        self.0.get(path).cloned()
+    }
+
+    fn set(&mut self, path: Path, value: Vec<u8>) {
+        self.0.insert(path, value);
+    }
+
+    fn delete(&mut self, path: &Path) {
+        self.0.remove(path);
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+struct MemClients {
+    client_states: BTreeMap<ClientId, MockClientState>,
+    consensus_states: BTreeMap<ClientConsensusStatePath, MockConsensusState>,
+    update_metas: BTreeMap<(ClientId, Height), (Timestamp, Height)>,
+    host_timestamp: Timestamp,
+    host_height: Height,
+}
+
+impl MemClients {
+    fn new(host_height: Height, host_timestamp: Timestamp) -> Self {
+        Self {
+            client_states: BTreeMap::new(),
+            consensus_states: BTreeMap::new(),
+            update_metas: BTreeMap::new(),
+            host_timestamp,
+            host_height,
+        }
+    }
+}
+
+impl ClientStore for MemClients {
+    type ClientState = MockClientState;
+    type ConsensusState = MockConsensusState;
+
+    fn client_state(&self, client_id: &ClientId) -> Result<Self::ClientState, ContextError> {
+        self.client_states.get(client_id).copied().ok_or_else(|| {
+            ClientError::ClientStateNotFound {
+                client_id: client_id.clone(),
+            }
+            .into()
+        })
+    }
+
+    fn consensus_state(
+        &self,
+        client_cons_state_path: &ClientConsensusStatePath,
+    ) -> Result<Self::ConsensusState, ContextError> {
+        self.consensus_states
+            .get(client_cons_state_path)
+            .cloned()
+            .ok_or_else(|| {
+                ClientError::ConsensusStateNotFound {
+                    client_id: client_cons_state_path.client_id.clone(),
+                    height: Height::new(
+                        client_cons_state_path.revision_number,
+                        client_cons_state_path.revision_height,
+                    )
+                    .expect("non-zero height"),
+                }
+                .into()
+            })
+    }
+
+    fn consensus_state_heights(&self, client_id: &ClientId) -> Result<Vec<Height>, ContextError> {
+        self.consensus_states
+            .keys()
+            .filter(|path| &path.client_id == client_id)
+            .map(|path| Height::new(path.revision_number, path.revision_height).map_err(Into::into))
+            .collect()
+    }
+
+    fn client_update_meta(
+        &self,
+        client_id: &ClientId,
+        height: &Height,
+    ) -> Result<(Timestamp, Height), ContextError> {
+        self.update_metas
+            .get(&(client_id.clone(), *height))
+            .copied()
+            .ok_or_else(|| {
+                ClientError::UpdateMetaDataNotFound {
+                    client_id: client_id.clone(),
+                    height: *height,
+                }
+                .into()
+            })
+    }
+
+    fn host_height(&self) -> Result<Height, ContextError> {
+        Ok(self.host_height)
+    }
+
+    fn host_timestamp(&self) -> Result<Timestamp, ContextError> {
+        Ok(self.host_timestamp)
+    }
+
+    fn set_client_state(&mut self, client_id: ClientId, client_state: Self::ClientState) {
+        self.client_states.insert(client_id, client_state);
+    }
+
+    fn set_consensus_state(
+        &mut self,
+        consensus_state_path: ClientConsensusStatePath,
+        consensus_state: Self::ConsensusState,
+    ) {
+        self.consensus_states
+            .insert(consensus_state_path, consensus_state);
+    }
+
+    fn delete_consensus_state(&mut self, consensus_state_path: &ClientConsensusStatePath) {
+        self.consensus_states.remove(consensus_state_path);
+    }
+
+    fn set_update_meta(
+        &mut self,
+        client_id: ClientId,
+        height: Height,
+        host_timestamp: Timestamp,
+        host_height: Height,
+    ) {
+        self.update_metas
+            .insert((client_id, height), (host_timestamp, host_height));
+    }
+
+    fn delete_update_meta(&mut self, client_id: &ClientId, height: &Height) {
+        self.update_metas.remove(&(client_id.clone(), *height));
+    }
+}
+
+#[derive(Default)]
+struct MemHost {
+    events: Vec<IbcEvent>,
+    logs: Vec<String>,
+    frozen_channels: BTreeSet<ChannelEndPath>,
+}
+
+impl HostParams for MemHost {
+    fn max_expected_time_per_block(&self) -> Duration {
+        Duration::from_secs(6)
+    }
+}
+
+impl StoreHost for MemHost {
+    type Clients = MemClients;
+    type HostClientState = MockClientState;
+    type HostConsensusState = MockConsensusState;
+
+    fn host_consensus_state(
+        &self,
+        height: &Height,
+    ) -> Result<Self::HostConsensusState, ContextError> {
+        Err(ClientError::MissingLocalConsensusState { height: *height }.into())
+    }
+
+    fn validate_self_client(
+        &self,
+        _client_state_of_host_on_counterparty: Self::HostClientState,
+    ) -> Result<(), ContextError> {
+        Ok(())
+    }
+
+    fn commitment_prefix(&self) -> CommitmentPrefix {
+        CommitmentPrefix::try_from(b"ibc".to_vec()).expect("non-empty prefix")
+    }
+
+    fn validate_message_signer(&self, _signer: &Signer) -> Result<(), ContextError> {
+        Ok(())
+    }
+
+    fn is_channel_frozen(&self, channel_end_path: &ChannelEndPath) -> Result<bool, ContextError> {
+        Ok(self.frozen_channels.contains(channel_end_path))
+    }
+
+    fn store_channel_frozen(
+        &mut self,
+        channel_end_path: &ChannelEndPath,
+        frozen: bool,
+    ) -> Result<(), ContextError> {
+        if frozen {
+            self.frozen_channels.insert(channel_end_path.clone());
+        } else {
+            self.frozen_channels.remove(channel_end_path);
+        }
+        Ok(())
+    }
+
+    fn emit_ibc_event(&mut self, event: IbcEvent) -> Result<(), ContextError> {
+        self.events.push(event);
+        Ok(())
+    }
+
+    fn log_message(&mut self, message: String) -> Result<(), ContextError> {
+        self.logs.push(message);
+        Ok(())
+    }
+}
+
+fn host_timestamp() -> Timestamp {
+    Timestamp::from_nanoseconds(1_700_000_000_000_000_000).expect("valid timestamp")
+}
+
+fn dummy_clients() -> MemClients {
+    MemClients::new(Height::new(0, 10).expect("no error"), host_timestamp())
+}
+
+fn dummy_msg_create_mock_client(height: Height) -> MsgEnvelope {
+    let header = MockHeader::new(height).with_timestamp(host_timestamp());
+
+    MsgEnvelope::from(ClientMsg::from(MsgCreateClient::new(
+        MockClientState::new(header).into(),
+        MockConsensusState::new(header).into(),
+        dummy_account_id(),
+    )))
+}
+
+fn consensus_state_path(client_id: &ClientId, height: u64) -> ClientConsensusStatePath {
+    ClientConsensusStatePath::new(client_id.clone(), 0, height)
+}
+
+fn mock_consensus_state(height: u64) -> MockConsensusState {
+    MockConsensusState::new(
+        MockHeader::new(Height::new(0, height).expect("no error")).with_timestamp(host_timestamp()),
+    )
+}
+
+#[test]
+fn test_store_context_buffers_client_writes() {
+    let mut store = MemStore::default();
+    let mut router = MockRouter::default();
+    let clients = dummy_clients();
+    let msg = dummy_msg_create_mock_client(Height::new(0, 42).expect("no error"));
+    let client_id = mock_client_type().build_client_id(0);
+
+    let mut ctx = StoreContext::new(
+        WriteBatch::new(&mut store),
+        ClientWriteBatch::new(clients.clone()),
+        MemHost::default(),
+    );
+    dispatch(&mut ctx, &mut router, msg.clone()).expect("create client succeeds");
+    assert!(ctx
+        .get_client_validation_context()
+        .client_state(&client_id)
+        .is_ok());
+
+    let (batch, client_batch, _) = ctx.into_parts();
+    batch.discard();
+    assert_eq!(client_batch.discard(), clients);
+
+    let mut ctx = StoreContext::new(
+        WriteBatch::new(&mut store),
+        ClientWriteBatch::new(clients),
+        MemHost::default(),
+    );
+    dispatch(&mut ctx, &mut router, msg).expect("create client succeeds");
+
+    let (batch, client_batch, _) = ctx.into_parts();
+    batch.commit();
+    let clients = client_batch.commit();
+    assert!(clients.client_states.contains_key(&client_id));
+    assert!(clients
+        .consensus_states
+        .contains_key(&consensus_state_path(&client_id, 42)));
+}
+
+#[test]
+fn test_client_write_batch_consensus_state_heights() {
+    let client_id = mock_client_type().build_client_id(0);
+
+    let mut clients = dummy_clients();
+    clients.set_consensus_state(consensus_state_path(&client_id, 3), mock_consensus_state(3));
+    clients.set_consensus_state(consensus_state_path(&client_id, 5), mock_consensus_state(5));
+
+    let mut batch = ClientWriteBatch::new(clients.clone());
+    batch
+        .store_consensus_state(consensus_state_path(&client_id, 4), mock_consensus_state(4))
+        .expect("no error");
+    batch
+        .delete_consensus_state(consensus_state_path(&client_id, 5))
+        .expect("no error");
+
+    let height = |h| Height::new(0, h).expect("no error");
+    assert_eq!(
+        batch.consensus_state_heights(&client_id).expect("no error"),
+        vec![height(3), height(4)]
+    );
+    assert_eq!(
+        batch
+            .next_consensus_state(&client_id, &height(3))
+            .expect("no error"),
+        Some(mock_consensus_state(4))
+    );
+    assert_eq!(
+        batch
+            .prev_consensus_state(&client_id, &height(5))
+            .expect("no error"),
+        Some(mock_consensus_state(4))
+    );
+    assert!(batch
+        .consensus_state(&consensus_state_path(&client_id, 5))
+        .is_err());
+
+    assert_eq!(batch.discard(), clients);
+}
+
+#[test]
+fn test_store_context_fails_to_decode_counter() {
+    let mut store = MemStore::default();
+    store.set(NextClientSequencePath.into(), vec![1, 2, 3]);
+
+    let ctx = StoreContext::new(
+        WriteBatch::new(&mut store),
+        ClientWriteBatch::new(dummy_clients()),
+        MemHost::default(),
+    );
+
+    assert!(matches!(
+        ctx.client_counter(),
+        Err(ContextError::HostError(HostError::FailedToDecode { .. }))
+    ));
+}
+
+#[test]
+fn test_store_context_freezes_channels_through_host() {
+    let mut store = MemStore::default();
+    let chan_end_path = ChannelEndPath::new(&PortId::transfer(), &ChannelId::zero());
+
+    let mut ctx = StoreContext::new(
+        WriteBatch::new(&mut store),
+        ClientWriteBatch::new(dummy_clients()),
+        MemHost::default(),
+    );
+
+    ctx.store_channel(
+        &chan_end_path,
+        ChannelEnd::new(
+            State::Open,
+            Order::Unordered,
+            Counterparty::new(PortId::transfer(), Some(ChannelId::zero())),
+            vec![ConnectionId::zero()],
+            Version::new("ics20-1".to_string()),
+        )
+        .expect("no error"),
+    )
+    .expect("no error");
+
+    freeze_channel(&mut ctx, &chan_end_path).expect("no error");
+    assert!(ctx.is_channel_frozen(&chan_end_path).expect("no error"));
+
+    unfreeze_channel(&mut ctx, &chan_end_path).expect("no error");
+    assert!(!ctx.is_channel_frozen(&chan_end_path).expect("no error"));
+
+    let (_, _, host) = ctx.into_parts();
+    assert!(matches!(
+        host.events.as_slice(),
+        [IbcEvent::ChannelFrozen(_), IbcEvent::ChannelUnfrozen(_)]
+    ));
+}
+
+#[test]
+fn test_simulate_predicts_writes_without_committing_them() {
+    let mut store = MemStore::default();
+    let mut router = MockRouter::default();
+
+    // Creates a first client for real, so that the simulation runs against a
+    // non-empty store.
+    let mut ctx = StoreContext::new(
+        WriteBatch::new(&mut store),
+        ClientWriteBatch::new(dummy_clients()),
+        MemHost::default(),
+    );
+    dispatch(
+        &mut ctx,
+        &mut router,
+        dummy_msg_create_mock_client(Height::new(0, 42).expect("no error")),
+    )
+    .expect("create client succeeds");
+    let (batch, client_batch, _) = ctx.into_parts();
+    batch.commit();
+    let clients = client_batch.commit();
+
+    let stored = store.0.clone();
+    let mut host = MemHost::default();
+    let msg = dummy_msg_create_mock_client(Height::new(0, 43).expect("no error"));
+
+    let simulation = simulate(&store, clients.clone(), &mut host, &mut router, msg.clone())
+        .expect("create client succeeds");
+
+    assert!(simulation
+        .writes
+        .contains_key(&NextClientSequencePath.into()));
+    assert!(!simulation.events.is_empty());
+    assert_eq!(store.0, stored);
+    assert!(host.events.is_empty());
+    assert!(host.logs.is_empty());
+
+    // Dispatching the message for real makes the predicted writes and emits
+    // the predicted events, the simulation having left the client counter
+    // untouched.
+    let mut ctx = StoreContext::new(
+        WriteBatch::new(&mut store),
+        ClientWriteBatch::new(clients.clone()),
+        MemHost::default(),
+    );
+    dispatch(&mut ctx, &mut router, msg).expect("create client succeeds");
+    let (batch, client_batch, host) = ctx.into_parts();
+    batch.commit();
+
+    assert_eq!(host.events, simulation.events);
+    for (path, value) in simulation.writes {
+        assert_eq!(store.get(&path), value);
+    }
+
+    let client_id = mock_client_type().build_client_id(1);
+    assert!(!clients.client_states.contains_key(&client_id));
+    assert!(client_batch.commit().client_states.contains_key(&client_id));
+}
+
+#[test]
+fn test_simulate_fails_without_writing() {
+    let store = MemStore::default();
+    let mut host = MemHost::default();
+    // The client of the connection does not exist.
+    let msg = MsgEnvelope::from(ConnectionMsg::from(dummy_msg_conn_open_init()));
+
+    assert!(simulate(
+        &store,
+        dummy_clients(),
+        &mut host,
+        &mut MockRouter::default(),
+        msg
+    )
+    .is_err());
+    assert!(store.0.is_empty());
+    assert!(host.events.is_empty());
+}
+
+#[cfg(feature = "async")]
+mod asynchronous {
+    use core::future::Future;
+    use core::pin::pin;
+    use core::task::{Context, Poll};
+    use std::sync::{Arc, Mutex};
+    use std::task::{Wake, Waker};
+
+    use async_trait::async_trait;
+    use ibc::apps::transfer::types::MODULE_ID_STR;
+    use ibc::core::channel::types::msgs::{ChannelMsg, MsgChannelOpenInit};
+    use ibc::core::connection::types::version::Version as ConnectionVersion;
+    use ibc::core::connection::types::{ConnectionEnd, State as ConnectionState};
+    use ibc::core::handler::asynchronous::dispatch_async;
+    use ibc::core::host::async_store::AsyncIbcStore;
+    use ibc::core::host::types::path::ConnectionPath;
+    use ibc::core::primitives::proto::Protobuf;
+    use ibc::core::router::module::Module;
+    use ibc::core::router::router::Router;
+    use ibc::core::router::types::module::ModuleId;
+    use ibc_testkit::fixtures::core::channel::dummy_raw_msg_chan_open_init;
+    use ibc_testkit::testapp::ibc::applications::transfer::types::DummyTransferModule;
+
+    use super::*;
+
+    struct NoopWaker;
+
+    impl Wake for NoopWaker {
+        fn wake(self: Arc<Self>) {}
+    }
+
+    /// Polls a future which never waits to completion.
+    fn block_on<F: Future>(future: F) -> F::Output {
+        let waker = Waker::from(Arc::new(NoopWaker));
+        let mut cx = Context::from_waker(&waker);
+        let mut future = pin!(future);
+
+        loop {
+            if let Poll::Ready(output) = future.as_mut().poll(&mut cx) {
+                return output;
+            }
+        }
+    }
+
+    /// An asynchronous store recording the paths fetched from it.
+    #[derive(Default)]
+    struct RemoteStore {
+        values: BTreeMap<Path, Vec<u8>>,
+        fetched: Mutex<Vec<Path>>,
+    }
+
+    #[async_trait]
+    impl AsyncIbcStore for RemoteStore {
+        async fn get(&self, path: &Path) -> Option<Vec<u8>> {
+            self.fetched
+                .lock()
+                .expect("not poisoned")
+                .push(path.clone());
+            self.values.get(path).cloned()
+        }
+
+        async fn set(&mut self, path: Path, value: Vec<u8>) {
+            self.values.insert(path, value);
+        }
+
+        async fn delete(&mut self, path: &Path) {
+            self.values.remove(path);
+        }
+    }
+
+    /// A router owning its transfer module, so that its clones do not share
+    /// the state of the module.
+    #[derive(Clone, Default)]
+    struct TransferRouter {
+        transfer: DummyTransferModule,
+    }
+
+    fn transfer_module_id() -> ModuleId {
+        ModuleId::new(MODULE_ID_STR.to_string())
+    }
+
+    impl Router for TransferRouter {
+        fn get_route(&self, module_id: &ModuleId) -> Option<&dyn Module> {
+            (module_id == &transfer_module_id()).then_some(&self.transfer as &dyn Module)
+        }
+
+        fn get_route_mut(&mut self, module_id: &ModuleId) -> Option<&mut dyn Module> {
+            (module_id == &transfer_module_id()).then_some(&mut self.transfer as &mut dyn Module)
+        }
+
+        fn lookup_module(&self, port_id: &PortId) -> Option<ModuleId> {
+            (port_id == &PortId::transfer()).then(transfer_module_id)
+        }
+    }
+
+    #[test]
+    fn test_dispatch_async_fetches_missing_paths() {
+        let client_id = mock_client_type().build_client_id(0);
+        let client_height = Height::new(0, 10).expect("no error");
+        let header = MockHeader::new(client_height).with_timestamp(host_timestamp());
+
+        let mut clients = dummy_clients();
+        clients.set_client_state(client_id.clone(), MockClientState::new(header));
+        clients.set_consensus_state(
+            consensus_state_path(&client_id, 10),
+            MockConsensusState::new(header),
+        );
+
+        let msg_conn_init = dummy_msg_conn_open_init();
+        let conn_end = ConnectionEnd::new(
+            ConnectionState::Init,
+            client_id,
+            msg_conn_init.counterparty,
+            ConnectionVersion::compatibles(),
+            msg_conn_init.delay_period,
+        )
+        .expect("no error");
+        let conn_path = Path::from(ConnectionPath::new(&ConnectionId::zero()));
+
+        let mut remote = RemoteStore::default();
+        remote
+            .values
+            .insert(conn_path.clone(), conn_end.encode_vec());
+
+        let msg = MsgEnvelope::from(ChannelMsg::from(
+            MsgChannelOpenInit::try_from(dummy_raw_msg_chan_open_init(None)).expect("no error"),
+        ));
+        let mut host = MemHost::default();
+        let mut router = TransferRouter::default();
+
+        let (returned_clients, result) = block_on(dispatch_async(
+            &mut remote,
+            clients.clone(),
+            &mut host,
+            &mut router,
+            msg,
+        ));
+        result.expect("channel open init succeeds");
+
+        // The connection end was not prefetched: it was fetched once read,
+        // and the message run again.
+        let fetched = remote.fetched.lock().expect("not poisoned").clone();
+        assert_eq!(fetched.iter().filter(|path| **path == conn_path).count(), 1);
+
+        assert!(remote
+            .values
+            .contains_key(&ChannelEndPath::new(&PortId::transfer(), &ChannelId::zero()).into()));
+        assert_eq!(returned_clients, clients);
+        assert!(!host.events.is_empty());
+    }
+
+    #[test]
+    fn test_dispatch_async_discards_writes_on_error() {
+        let mut remote = RemoteStore::default();
+        let msg = MsgEnvelope::from(ChannelMsg::from(
+            MsgChannelOpenInit::try_from(dummy_raw_msg_chan_open_init(None)).expect("no error"),
+        ));
+        let mut host = MemHost::default();
+        let mut router = TransferRouter::default();
+
+        // The connection end is missing from the remote store.
+        let (returned_clients, result) = block_on(dispatch_async(
+            &mut remote,
+            dummy_clients(),
+            &mut host,
+            &mut router,
+            msg,
+        ));
+        assert!(result.is_err());
+
+        assert!(remote.values.is_empty());
+        assert_eq!(returned_clients, dummy_clients());
+        assert!(host.events.is_empty());
+    }
+}
@@ -0,0 +1,260 @@
+use ibc::core::channel::types::channel::Order;
+use ibc::core::channel::types::error::{ChannelError, PacketError};
+use ibc::core::channel::types::msgs::{
+    MsgAcknowledgement, MsgChannelOpenInit, MsgChannelOpenTry, MsgRecvPacket, MsgTimeout,
+    MsgTimeoutOnClose,
+};
+use ibc::core::channel::types::timeout::TimeoutHeight;
+use ibc::core::client::types::error::ClientError;
+use ibc::core::client::types::Height;
+use ibc::core::connection::types::error::ConnectionError;
+use ibc::core::connection::types::Counterparty as ConnectionCounterparty;
+use ibc::core::host::types::identifiers::{ClientId, Sequence};
+use ibc::core::primitives::proto::Any;
+use ibc::core::primitives::Timestamp;
+use ibc_testkit::fixtures::core::channel::{
+    dummy_raw_msg_acknowledgement, dummy_raw_msg_chan_open_init, dummy_raw_msg_chan_open_try,
+    dummy_raw_msg_recv_packet, dummy_raw_msg_timeout, dummy_raw_msg_timeout_on_close,
+};
+use ibc_testkit::fixtures::core::client::dummy_msg_upgrade_client;
+use ibc_testkit::fixtures::core::connection::{dummy_msg_conn_open_ack, dummy_msg_conn_open_try};
+use rstest::*;
+
+fn dummy_msg_recv_packet() -> MsgRecvPacket {
+    MsgRecvPacket::try_from(dummy_raw_msg_recv_packet(10)).expect("valid message")
+}
+
+#[test]
+fn valid_messages_pass_validate_basic() {
+    assert!(dummy_msg_recv_packet().validate_basic().is_ok());
+    assert!(
+        MsgAcknowledgement::try_from(dummy_raw_msg_acknowledgement(10))
+            .expect("valid message")
+            .validate_basic()
+            .is_ok()
+    );
+    assert!(MsgTimeout::try_from(dummy_raw_msg_timeout(10, 5, 0))
+        .expect("valid message")
+        .validate_basic()
+        .is_ok());
+    assert!(
+        MsgTimeoutOnClose::try_from(dummy_raw_msg_timeout_on_close(10, 5))
+            .expect("valid message")
+            .validate_basic()
+            .is_ok()
+    );
+    assert!(dummy_msg_conn_open_try(10, 5).validate_basic().is_ok());
+    assert!(dummy_msg_conn_open_ack(10, 5).validate_basic().is_ok());
+    assert!(dummy_msg_upgrade_client(
+        ClientId::new("07-tendermint", 0).expect("no error"),
+        Height::new(0, 10).expect("no error"),
+    )
+    .validate_basic()
+    .is_ok());
+}
+
+#[test]
+fn recv_packet_fail_zero_sequence() {
+    let mut msg = dummy_msg_recv_packet();
+    msg.packet.seq_on_a = Sequence::from(0);
+
+    assert!(matches!(
+        msg.validate_basic(),
+        Err(PacketError::ZeroPacketSequence)
+    ));
+}
+
+#[test]
+fn recv_packet_fail_empty_data() {
+    let mut msg = dummy_msg_recv_packet();
+    msg.packet.data = vec![];
+
+    assert!(matches!(
+        msg.validate_basic(),
+        Err(PacketError::ZeroPacketData)
+    ));
+}
+
+#[test]
+fn recv_packet_fail_missing_timeout() {
+    let mut msg = dummy_msg_recv_packet();
+    msg.packet.timeout_height_on_b = TimeoutHeight::no_timeout();
+    msg.packet.timeout_timestamp_on_b = Timestamp::none();
+
+    assert!(matches!(
+        msg.validate_basic(),
+        Err(PacketError::MissingTimeout)
+    ));
+}
+
+#[test]
+fn acknowledgement_fail_invalid_packet() {
+    let mut msg =
+        MsgAcknowledgement::try_from(dummy_raw_msg_acknowledgement(10)).expect("valid message");
+    msg.packet.data = vec![];
+
+    assert!(matches!(
+        msg.validate_basic(),
+        Err(PacketError::ZeroPacketData)
+    ));
+}
+
+#[test]
+fn timeout_fail_invalid_packet() {
+    let mut msg = MsgTimeout::try_from(dummy_raw_msg_timeout(10, 5, 0)).expect("valid message");
+    msg.packet.seq_on_a = Sequence::from(0);
+
+    assert!(matches!(
+        msg.validate_basic(),
+        Err(PacketError::ZeroPacketSequence)
+    ));
+}
+
+#[test]
+fn timeout_on_close_fail_invalid_packet() {
+    let mut msg =
+        MsgTimeoutOnClose::try_from(dummy_raw_msg_timeout_on_close(10, 5)).expect("valid message");
+    msg.packet.timeout_height_on_b = TimeoutHeight::no_timeout();
+    msg.packet.timeout_timestamp_on_b = Timestamp::none();
+
+    assert!(matches!(
+        msg.validate_basic(),
+        Err(PacketError::MissingTimeout)
+    ));
+}
+
+#[rstest]
+#[case::chan_open_init(
+    {
+        let mut msg = MsgChannelOpenInit::try_from(dummy_raw_msg_chan_open_init(None))
+            .expect("valid message");
+        msg.ordering = Order::None;
+        msg.validate_basic()
+    }
+)]
+#[case::chan_open_try(
+    {
+        let mut msg =
+            MsgChannelOpenTry::try_from(dummy_raw_msg_chan_open_try(10)).expect("valid message");
+        msg.ordering = Order::None;
+        msg.validate_basic()
+    }
+)]
+fn channel_open_fail_none_ordering(#[case] res: Result<(), ChannelError>) {
+    assert!(matches!(res, Err(ChannelError::InvalidOrderType { .. })));
+}
+
+#[test]
+fn conn_open_try_fail_missing_counterparty_connection_id() {
+    let mut msg = dummy_msg_conn_open_try(10, 5);
+    msg.counterparty = ConnectionCounterparty::new(
+        msg.counterparty.client_id().clone(),
+        None,
+        msg.counterparty.prefix().clone(),
+    );
+
+    assert!(matches!(
+        msg.validate_basic(),
+        Err(ConnectionError::InvalidCounterparty)
+    ));
+}
+
+#[test]
+fn conn_open_try_fail_empty_client_state() {
+    let mut msg = dummy_msg_conn_open_try(10, 5);
+    msg.client_state_of_b_on_a = Any::default();
+
+    assert!(matches!(
+        msg.validate_basic(),
+        Err(ConnectionError::MissingClientState)
+    ));
+}
+
+#[test]
+fn conn_open_ack_fail_empty_client_state() {
+    let mut msg = dummy_msg_conn_open_ack(10, 5);
+    msg.client_state_of_a_on_b = Any::default();
+
+    assert!(matches!(
+        msg.validate_basic(),
+        Err(ConnectionError::MissingClientState)
+    ));
+}
+
+#[test]
+fn upgrade_client_fail_empty_client_state() {
+    let mut msg = dummy_msg_upgrade_client(
+        ClientId::new("07-tendermint", 0).expect("no error"),
+        Height::new(0, 10).expect("no error"),
+    );
+    msg.upgraded_client_state = Any::default();
+
+    assert!(matches!(
+        msg.validate_basic(),
+        Err(ClientError::MissingRawClientState)
+    ));
+}
+
+#[test]
+fn upgrade_client_fail_empty_consensus_state() {
+    let mut msg = dummy_msg_upgrade_client(
+        ClientId::new("07-tendermint", 0).expect("no error"),
+        Height::new(0, 10).expect("no error"),
+    );
+    msg.upgraded_consensus_state = Any::default();
+
+    assert!(matches!(
+        msg.validate_basic(),
+        Err(ClientError::MissingRawConsensusState)
+    ));
+}
+
+#[cfg(feature = "serde")]
+mod serde {
+    use ibc::core::client::types::msgs::{MsgCreateClient, MsgUpdateClient};
+    use ibc_testkit::fixtures::core::client::{
+        dummy_raw_msg_create_client, dummy_raw_msg_update_client,
+    };
+
+    use super::*;
+
+    #[test]
+    fn create_client_fail_empty_client_state() {
+        let mut msg =
+            MsgCreateClient::try_from(dummy_raw_msg_create_client()).expect("valid message");
+        assert!(msg.validate_basic().is_ok());
+
+        msg.client_state = Any::default();
+
+        assert!(matches!(
+            msg.validate_basic(),
+            Err(ClientError::MissingRawClientState)
+        ));
+    }
+
+    #[test]
+    fn create_client_fail_empty_consensus_state() {
+        let mut msg =
+            MsgCreateClient::try_from(dummy_raw_msg_create_client()).expect("valid message");
+        msg.consensus_state = Any::default();
+
+        assert!(matches!(
+            msg.validate_basic(),
+            Err(ClientError::MissingRawConsensusState)
+        ));
+    }
+
+    #[test]
+    fn update_client_fail_empty_client_message() {
+        let mut msg =
+            MsgUpdateClient::try_from(dummy_raw_msg_update_client()).expect("valid message");
+        assert!(msg.validate_basic().is_ok());
+
+        msg.client_message = Any::default();
+
+        assert!(matches!(
+            msg.validate_basic(),
+            Err(ClientError::MissingClientMessage)
+        ));
+    }
+}
@@ -0,0 +1,133 @@
+use ibc::core::channel::types::channel::{ChannelEnd, Counterparty, Order, State};
+use ibc::core::channel::types::Version;
+use ibc::core::channel::verify::verify_channel_state;
+use ibc::core::client::context::consensus_state::ConsensusState;
+use ibc::core::client::types::error::ClientError;
+use ibc::core::commitment_types::error::CommitmentError;
+use ibc::core::connection::types::version::Version as ConnectionVersion;
+use ibc::core::connection::types::{
+    ConnectionEnd, Counterparty as ConnectionCounterparty, State as ConnectionState,
+};
+use ibc::core::connection::verify::verify_connection_state;
+use ibc::core::host::types::identifiers::{ChannelId, ClientId, ConnectionId, PortId};
+use ibc::core::host::types::path::{ChannelEndPath, ConnectionPath, Path};
+use ibc::core::host::ValidationContext;
+use ibc::core::primitives::*;
+use ibc_query::core::context::ProvableContext;
+use ibc_testkit::context::TendermintContext;
+use ibc_testkit::fixtures::core::connection::dummy_raw_counterparty_conn;
+use ibc_testkit::hosts::TendermintHost;
+use ibc_testkit::testapp::ibc::core::types::{LightClientBuilder, LightClientState};
+use test_log::test;
+
+/// Verifies the proofs of a connection end and of a channel end stored on a
+/// Tendermint chain, against a client of it, the same way as the handlers.
+#[test]
+fn test_verify_connection_and_channel_state() {
+    let conn_id = ConnectionId::zero();
+    let conn_end = ConnectionEnd::new(
+        ConnectionState::Open,
+        ClientId::new("07-tendermint", 0).expect("no error"),
+        ConnectionCounterparty::try_from(dummy_raw_counterparty_conn(Some(0))).expect("no error"),
+        ConnectionVersion::compatibles(),
+        ZERO_DURATION,
+    )
+    .expect("no error");
+
+    let port_id = PortId::transfer();
+    let chan_id = ChannelId::zero();
+    let chan_end = ChannelEnd::new(
+        State::Open,
+        Order::Unordered,
+        Counterparty::new(PortId::transfer(), Some(ChannelId::zero())),
+        vec![conn_id.clone()],
+        Version::empty(),
+    )
+    .expect("no error");
+
+    let mut ctx_tm = TendermintContext::default()
+        .with_connection(conn_id.clone(), conn_end.clone())
+        .with_channel(port_id.clone(), chan_id.clone(), chan_end.clone());
+
+    // Commits the connection and channel ends.
+    ctx_tm.advance_block_height();
+
+    let height = ctx_tm.latest_height();
+    let light_client: LightClientState<TendermintHost> =
+        LightClientBuilder::init().context(&ctx_tm).build();
+    let client_state = light_client.client_state;
+    let root = light_client
+        .consensus_states
+        .get(&height)
+        .expect("consensus state exists")
+        .root()
+        .clone();
+    let prefix = ctx_tm.ibc_store().commitment_prefix();
+
+    let proof = |path: Path| {
+        ctx_tm
+            .ibc_store()
+            .get_proof(height, &path)
+            .expect("proof exists")
+            .try_into()
+            .expect("value merkle proof")
+    };
+
+    let conn_proof = proof(Path::Connection(ConnectionPath::new(&conn_id)));
+
+    verify_connection_state(
+        &client_state,
+        &prefix,
+        &conn_proof,
+        &root,
+        &conn_id,
+        conn_end.clone(),
+    )
+    .expect("successful connection state verification");
+
+    let mut unexpected_conn_end = conn_end;
+    unexpected_conn_end.set_state(ConnectionState::TryOpen);
+
+    assert!(matches!(
+        verify_connection_state(
+            &client_state,
+            &prefix,
+            &conn_proof,
+            &root,
+            &conn_id,
+            unexpected_conn_end,
+        )
+        .expect_err("connection state verification fails"),
+        ClientError::Ics23Verification(CommitmentError::VerificationFailure)
+    ));
+
+    let chan_proof = proof(Path::ChannelEnd(ChannelEndPath::new(&port_id, &chan_id)));
+
+    verify_channel_state(
+        &client_state,
+        &prefix,
+        &chan_proof,
+        &root,
+        &port_id,
+        &chan_id,
+        chan_end.clone(),
+    )
+    .expect("successful channel state verification");
+
+    let mut unexpected_chan_end = chan_end;
+    unexpected_chan_end.set_state(State::Closed);
+
+    assert!(matches!(
+        verify_channel_state(
+            &client_state,
+            &prefix,
+            &chan_proof,
+            &root,
+            &port_id,
+            &chan_id,
+            unexpected_chan_end,
+        )
+        .expect_err("channel state verification fails"),
+        ClientError::Ics23Verification(CommitmentError::VerificationFailure)
+    ));
+}
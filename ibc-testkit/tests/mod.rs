@@ -7,5 +7,6 @@
     rust_2018_idioms
 )]
 pub mod applications;
+pub mod conformance;
 pub mod core;
 pub mod cosmwasm;
@@ -0,0 +1,174 @@
+//! Defines the top-level error of `ibc-rs`, into which the errors of the core
+//! modules, light clients and applications convert.
+
+use core::fmt::{Display, Error as FmtError, Formatter};
+
+use derive_more::From;
+
+#[cfg(feature = "std")]
+use crate::apps::nft_transfer::types::error::NftTransferError;
+use crate::apps::transfer::types::error::TokenTransferError;
+use crate::clients::tendermint::types::error::Error as TendermintClientError;
+use crate::clients::wasm_types::error::Error as WasmClientError;
+use crate::core::channel::types::error::{ChannelError, PacketError};
+use crate::core::client::types::error::{ClientError, UpgradeClientError};
+use crate::core::commitment_types::error::CommitmentError;
+use crate::core::connection::types::error::ConnectionError;
+use crate::core::handler::types::error::ContextError;
+use crate::core::host::types::error::{HostError, IdentifierError};
+use crate::core::host::types::path::PathError;
+use crate::core::router::types::error::RouterError;
+use crate::primitives::{AbciError, ParseTimestampError, TimestampOverflowError};
+
+/// Top-level error, into which all the errors of `ibc-rs` convert.
+///
+/// Hosts calling into several modules can use it as their single IBC error
+/// type. It displays the module the error originates from along with the full
+/// description of the error and implements [`core::error::Error`] with or
+/// without the `std` feature, with which it also exposes the original error
+/// as its [`source`](core::error::Error::source).
+#[derive(Debug, From)]
+pub enum IbcError {
+    /// ICS02 client error.
+    Client(ClientError),
+    /// ICS03 connection error.
+    Connection(ConnectionError),
+    /// ICS04 channel error.
+    Channel(ChannelError),
+    /// ICS04 packet error.
+    Packet(PacketError),
+    /// ICS23 commitment error.
+    Commitment(CommitmentError),
+    /// ICS24 host error.
+    Host(HostError),
+    /// ICS24 identifier error.
+    Identifier(IdentifierError),
+    /// ICS24 path error.
+    Path(PathError),
+    /// ICS26 routing error.
+    Router(RouterError),
+    /// ICS07 Tendermint client error.
+    TendermintClient(TendermintClientError),
+    /// ICS08 Wasm client error.
+    WasmClient(WasmClientError),
+    /// ICS20 token transfer error.
+    TokenTransfer(TokenTransferError),
+    /// ICS721 NFT transfer error.
+    #[cfg(feature = "std")]
+    #[from(ignore)]
+    NftTransfer(NftTransferError),
+    /// timestamp parsing error.
+    ParseTimestamp(ParseTimestampError),
+    /// timestamp overflow error.
+    TimestampOverflow(TimestampOverflowError),
+}
+
+impl Display for IbcError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), FmtError> {
+        match self {
+            Self::Client(e) => write!(f, "ICS02 client error: {e}"),
+            Self::Connection(e) => write!(f, "ICS03 connection error: {e}"),
+            Self::Channel(e) => write!(f, "ICS04 channel error: {e}"),
+            Self::Packet(e) => write!(f, "ICS04 packet error: {e}"),
+            Self::Commitment(e) => write!(f, "ICS23 commitment error: {e}"),
+            Self::Host(e) => write!(f, "ICS24 host error: {e}"),
+            Self::Identifier(e) => write!(f, "ICS24 identifier error: {e}"),
+            Self::Path(e) => write!(f, "ICS24 path error: {e}"),
+            Self::Router(e) => write!(f, "ICS26 routing error: {e}"),
+            Self::TendermintClient(e) => write!(f, "ICS07 Tendermint client error: {e}"),
+            Self::WasmClient(e) => write!(f, "ICS08 Wasm client error: {e}"),
+            Self::TokenTransfer(e) => write!(f, "ICS20 token transfer error: {e}"),
+            #[cfg(feature = "std")]
+            Self::NftTransfer(e) => write!(f, "ICS721 NFT transfer error: {e}"),
+            Self::ParseTimestamp(e) => write!(f, "timestamp parsing error: {e}"),
+            Self::TimestampOverflow(e) => write!(f, "timestamp overflow error: {e}"),
+        }
+    }
+}
+
+impl From<ContextError> for IbcError {
+    fn from(e: ContextError) -> Self {
+        match e {
+            ContextError::ClientError(e) => Self::Client(e),
+            ContextError::ConnectionError(e) => Self::Connection(e),
+            ContextError::ChannelError(e) => Self::Channel(e),
+            ContextError::PacketError(e) => Self::Packet(e),
+            ContextError::HostError(e) => Self::Host(e),
+            ContextError::RouterError(e) => Self::Router(e),
+        }
+    }
+}
+
+/// The errors of the core handlers are reported under their own codes, while
+/// the other errors, which carry no code, are reported under the `ibc`
+/// codespace, by the module they originate from.
+impl AbciError for IbcError {
+    fn codespace(&self) -> &'static str {
+        match self {
+            Self::Client(e) => e.codespace(),
+            Self::Connection(e) => e.codespace(),
+            Self::Channel(e) => e.codespace(),
+            Self::Packet(e) => e.codespace(),
+            Self::Host(e) => e.codespace(),
+            Self::Router(e) => e.codespace(),
+            _ => "ibc",
+        }
+    }
+
+    fn code(&self) -> u32 {
+        match self {
+            Self::Client(e) => e.code(),
+            Self::Connection(e) => e.code(),
+            Self::Channel(e) => e.code(),
+            Self::Packet(e) => e.code(),
+            Self::Host(e) => e.code(),
+            Self::Router(e) => e.code(),
+            Self::Commitment(_) => 2,
+            Self::Identifier(_) => 3,
+            Self::Path(_) => 4,
+            Self::TendermintClient(_) => 5,
+            Self::WasmClient(_) => 6,
+            Self::TokenTransfer(_) => 7,
+            #[cfg(feature = "std")]
+            Self::NftTransfer(_) => 8,
+            Self::ParseTimestamp(_) => 9,
+            Self::TimestampOverflow(_) => 10,
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<NftTransferError> for IbcError {
+    fn from(e: NftTransferError) -> Self {
+        Self::NftTransfer(e)
+    }
+}
+
+impl From<UpgradeClientError> for IbcError {
+    fn from(e: UpgradeClientError) -> Self {
+        Self::Client(e.into())
+    }
+}
+
+impl core::error::Error for IbcError {
+    #[cfg(feature = "std")]
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            Self::Client(e) => Some(e),
+            Self::Connection(e) => Some(e),
+            Self::Channel(e) => Some(e),
+            Self::Packet(e) => Some(e),
+            Self::Commitment(e) => Some(e),
+            Self::Host(e) => Some(e),
+            Self::Identifier(e) => Some(e),
+            Self::Path(e) => Some(e),
+            Self::Router(e) => Some(e),
+            Self::TendermintClient(e) => Some(e),
+            Self::WasmClient(e) => Some(e),
+            Self::TokenTransfer(e) => Some(e),
+            Self::NftTransfer(e) => Some(e),
+            Self::ParseTimestamp(e) => Some(e),
+            Self::TimestampOverflow(e) => Some(e),
+        }
+    }
+}
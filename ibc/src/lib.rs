@@ -36,11 +36,22 @@
 #[cfg(any(test, feature = "std"))]
 extern crate std;
 
+/// Defines the top-level error into which all the errors of `ibc-rs` convert.
+pub mod error;
+
 /// Re-exports primitive types and traits from the `ibc-primitives` crate.
 pub mod primitives {
     pub use ibc_primitives::*;
 }
 
+/// Re-exports the `ibc-proto` crate, at the exact version from and into which
+/// the domain types of `ibc-rs` convert.
+///
+/// Downstream crates should name the raw types through it, rather than
+/// depending on `ibc-proto` themselves, which would otherwise need to be bumped
+/// in lock-step with `ibc-rs`.
+pub use ibc_primitives::ibc_proto;
+
 /// Re-exports implementations of all the IBC core (TAO) modules.
 pub mod core {
     #[doc(inline)]